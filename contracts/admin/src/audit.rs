@@ -0,0 +1,83 @@
+//! Append-only audit log of admin contract actions.
+//!
+//! Each mutating admin action writes one immutable [`AuditRecord`] under a
+//! sequential persistent key. Records are TTL-bumped when written and read.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+use crate::AdminRole;
+
+/// Maximum number of records returned by one page.
+pub const MAX_AUDIT_PAGE: u32 = 50;
+
+/// Extend TTL once it drops below ~1 day (5s ledgers).
+const TTL_THRESHOLD: u32 = 17_280;
+/// Extend TTL to ~30 days.
+const TTL_EXTEND_TO: u32 = 518_400;
+
+/// A single admin action.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditRecord {
+    /// Action name, matching the event topic (e.g. `admin_added`)
+    pub action: Symbol,
+    /// Address that performed the action
+    pub actor: Address,
+    /// Address the action applied to
+    pub target: Address,
+    /// Role involved in the action
+    pub role: AdminRole,
+    /// Ledger timestamp of the action
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum AuditKey {
+    /// Number of records written so far
+    Count,
+    /// Record by index, starting at 0
+    Record(u32),
+}
+
+/// Append a record to the log.
+pub fn record(e: &Env, action: &str, actor: &Address, target: &Address, role: AdminRole) {
+    let index = count(e);
+    let key = AuditKey::Record(index);
+    let entry = AuditRecord {
+        action: Symbol::new(e, action),
+        actor: actor.clone(),
+        target: target.clone(),
+        role,
+        timestamp: e.ledger().timestamp(),
+    };
+    e.storage().persistent().set(&key, &entry);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    e.storage().instance().set(&AuditKey::Count, &(index + 1));
+}
+
+/// Number of records written so far.
+pub fn count(e: &Env) -> u32 {
+    e.storage().instance().get(&AuditKey::Count).unwrap_or(0)
+}
+
+/// Records `[offset, offset + limit)`, oldest first. `limit` is capped at
+/// [`MAX_AUDIT_PAGE`].
+pub fn page(e: &Env, offset: u32, limit: u32) -> Vec<AuditRecord> {
+    let end = offset
+        .saturating_add(limit.min(MAX_AUDIT_PAGE))
+        .min(count(e));
+    let mut out = Vec::new(e);
+    for index in offset..end {
+        let key = AuditKey::Record(index);
+        if let Some(entry) = e.storage().persistent().get::<_, AuditRecord>(&key) {
+            e.storage()
+                .persistent()
+                .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+            out.push_back(entry);
+        }
+    }
+    out
+}
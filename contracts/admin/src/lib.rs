@@ -10,7 +10,15 @@
 //! - Multiple admin addresses support
 //! - Role assignment and revocation
 //! - Self-removal protection for last admin
+//! - Per-operation permissions layered on top of roles
+//! - Open-ended, timed and per-scope pause
+//! - Pause-only guardians outside the role hierarchy
+//! - Two-step super admin handover
+//! - Timelocked super admin grants with a cancellation window
+//! - Time-limited roles with renewal and purging of expired entries
 //! - Event emission for all role changes
+//! - Paginated, append-only audit log of admin actions
+//! - Last-action tracking to find unused admin keys
 //! - Secure authorization checks
 //!
 //! ## Security
@@ -19,7 +27,30 @@
 //! - Audit trail through events
 //! - Input validation and bounds checking
 
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, Vec};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Symbol, Vec};
+
+pub mod audit;
+pub mod pausable;
+mod storage;
+
+pub use audit::AuditRecord;
+pub use pausable::{PauseInfo, PauseScope};
+
+/// Maximum number of admins returned by one page.
+pub const MAX_ADMIN_PAGE: u32 = 50;
+
+/// Maximum number of entries accepted by `add_admins_batch`.
+pub const MAX_ADMIN_BATCH: u32 = 20;
+
+/// Default delay before a super admin grant can be activated (48 hours).
+pub const DEFAULT_SUPERADMIN_GRANT_DELAY: u64 = 172_800;
+
+/// Shortest configurable super admin grant delay (1 hour).
+pub const MIN_SUPERADMIN_GRANT_DELAY: u64 = 3_600;
+
+/// Storage layout version. Version 1 keeps per-admin state in persistent
+/// storage; contracts without a version predate it.
+pub const STORAGE_VERSION: u32 = 1;
 
 /// Admin role hierarchy levels
 #[contracttype]
@@ -33,6 +64,30 @@ pub enum AdminRole {
     Operator = 1,
 }
 
+/// Individual operations an admin may be allowed to perform.
+///
+/// Each value is a single bit; an admin's permissions are stored as a `u32`
+/// mask. Permissions are checked in addition to the role hierarchy.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, Copy)]
+#[repr(u32)]
+pub enum Permission {
+    /// Add, remove, update, renew and (de)activate operators
+    ManageOperators = 1,
+    /// Add, remove, update, renew and (de)activate admins
+    ManageAdmins = 2,
+    /// Add, remove and hand over super admins
+    ManageSuperAdmins = 4,
+    /// Pause the protocol
+    Pause = 8,
+}
+
+/// Mask with every permission set.
+pub const ALL_PERMISSIONS: u32 = Permission::ManageOperators as u32
+    | Permission::ManageAdmins as u32
+    | Permission::ManageSuperAdmins as u32
+    | Permission::Pause as u32;
+
 /// Admin role information
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -47,17 +102,55 @@ pub struct AdminInfo {
     pub assigned_by: Address,
     /// Whether this admin is currently active
     pub active: bool,
+    /// Timestamp after which the role lapses (0 = permanent)
+    pub expires_at: u64,
+}
+
+/// Pending handover of a super admin role
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SuperAdminTransfer {
+    /// Super admin handing over their role
+    pub from: Address,
+    /// Address that must accept the role
+    pub to: Address,
+    /// Timestamp when the transfer was proposed
+    pub proposed_at: u64,
+}
+
+/// Timelocked grant of the super admin role
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PendingGrant {
+    /// Address that will receive the role
+    pub address: Address,
+    /// Super admin who made the grant
+    pub granted_by: Address,
+    /// Timestamp when the grant was made
+    pub granted_at: u64,
+    /// Timestamp from which the grant can be activated
+    pub effective_at: u64,
+}
+
+/// Queued reduction of the super admin grant delay
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingGrantDelay {
+    /// Delay that will apply once `effective_at` is reached
+    pub delay_secs: u64,
+    /// Timestamp from which the reduced delay applies
+    pub effective_at: u64,
 }
 
 /// Storage keys for the admin contract
 #[contracttype]
 #[derive(Clone)]
 enum DataKey {
-    /// List of all admin addresses
+    /// List of all admin addresses (persistent)
     AdminList,
-    /// Admin information by address: Address -> AdminInfo
+    /// Admin information by address: Address -> AdminInfo (persistent)
     AdminInfo(Address),
-    /// Role-based admin lists: AdminRole -> Vec<Address>
+    /// Role-based admin lists: AdminRole -> Vec<Address> (persistent)
     RoleAdmins(AdminRole),
     /// Contract initialization flag
     Initialized,
@@ -65,6 +158,22 @@ enum DataKey {
     MinAdmins,
     /// Maximum number of admins allowed
     MaxAdmins,
+    /// Pending super admin handover
+    PendingSuperAdminTransfer,
+    /// Custom permission mask by address: Address -> u32 (persistent)
+    Permissions(Address),
+    /// Storage layout version
+    StorageVersion,
+    /// Pause-only guardian flag by address: Address -> bool (persistent)
+    Guardian(Address),
+    /// Last successful mutating action by address: Address -> u64 (persistent)
+    LastActive(Address),
+    /// Pending super admin grant by address: Address -> PendingGrant (persistent)
+    PendingGrant(Address),
+    /// Delay in seconds before a super admin grant can be activated
+    SuperAdminGrantDelay,
+    /// Queued reduction of the grant delay
+    PendingSuperAdminGrantDelay,
 }
 
 #[contract]
@@ -104,6 +213,9 @@ impl AdminContract {
         e.storage().instance().set(&DataKey::Initialized, &true);
         e.storage().instance().set(&DataKey::MinAdmins, &min_admins);
         e.storage().instance().set(&DataKey::MaxAdmins, &max_admins);
+        e.storage()
+            .instance()
+            .set(&DataKey::StorageVersion, &STORAGE_VERSION);
 
         // Create initial super admin
         let admin_info = AdminInfo {
@@ -112,125 +224,207 @@ impl AdminContract {
             assigned_at: e.ledger().timestamp(),
             assigned_by: super_admin.clone(), // Self-assigned for initialization
             active: true,
+            expires_at: 0,
         };
 
         // Store admin info
-        e.storage()
-            .instance()
-            .set(&DataKey::AdminInfo(super_admin.clone()), &admin_info);
+        storage::set_admin_info(&e, &admin_info);
 
         // Initialize admin list
         let mut admin_list: Vec<Address> = Vec::new(&e);
         admin_list.push_back(super_admin.clone());
-        e.storage().instance().set(&DataKey::AdminList, &admin_list);
+        storage::set_admin_list(&e, &admin_list);
 
         // Initialize role-based admin list
         let super_admins = Vec::from_array(&e, [super_admin.clone()]);
-        e.storage()
-            .instance()
-            .set(&DataKey::RoleAdmins(AdminRole::SuperAdmin), &super_admins);
+        storage::set_role_admins(&e, AdminRole::SuperAdmin, &super_admins);
 
         // Initialize empty lists for other roles
-        e.storage().instance().set(
-            &DataKey::RoleAdmins(AdminRole::Admin),
-            &Vec::<Address>::new(&e),
-        );
-        e.storage().instance().set(
-            &DataKey::RoleAdmins(AdminRole::Operator),
-            &Vec::<Address>::new(&e),
-        );
+        storage::set_role_admins(&e, AdminRole::Admin, &Vec::<Address>::new(&e));
+        storage::set_role_admins(&e, AdminRole::Operator, &Vec::<Address>::new(&e));
 
         e.events()
             .publish((Symbol::new(&e, "admin_initialized"),), super_admin);
     }
 
+    /// Move admin state written by earlier versions from instance storage
+    /// to persistent storage.
+    ///
+    /// Run once right after upgrading a contract initialized before admin
+    /// records moved to persistent storage; until then admin lookups find
+    /// nothing. Contracts initialized by this version are already migrated.
+    ///
+    /// # Arguments
+    /// * `super_admin` - A super admin in the pre-migration state
+    ///
+    /// # Returns
+    /// The number of admin records moved
+    ///
+    /// # Panics
+    /// * If the contract is not initialized
+    /// * If storage is already migrated
+    /// * If super_admin is not an active super admin
+    ///
+    /// # Events
+    /// Emits `storage_migrated` with the number of records moved
+    pub fn migrate_storage(e: Env, super_admin: Address) -> u32 {
+        super_admin.require_auth();
+
+        if !e.storage().instance().has(&DataKey::Initialized) {
+            panic!("not initialized");
+        }
+        let version: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::StorageVersion)
+            .unwrap_or(0);
+        if version >= STORAGE_VERSION {
+            panic!("storage already migrated");
+        }
+
+        // Checked against the migrated state; a panic rolls the move back.
+        let moved = storage::migrate_from_instance(&e);
+        Self::require_super_admin(&e, &super_admin);
+
+        e.storage()
+            .instance()
+            .set(&DataKey::StorageVersion, &STORAGE_VERSION);
+
+        Self::record_action(
+            &e,
+            "storage_migrated",
+            &super_admin,
+            &e.current_contract_address(),
+            AdminRole::SuperAdmin,
+        );
+        e.events()
+            .publish((Symbol::new(&e, "storage_migrated"),), moved);
+
+        moved
+    }
+
+    /// Get the storage layout version (0 = not yet migrated).
+    pub fn get_storage_version(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::StorageVersion)
+            .unwrap_or(0)
+    }
+
     /// Add a new admin with the specified role.
     ///
     /// # Arguments
     /// * `caller` - Address of the caller making the assignment
     /// * `new_admin` - Address of the new admin to add
     /// * `role` - Role to assign to the new admin
+    /// * `expires_at` - Timestamp after which the role lapses (0 = permanent)
+    ///
+    /// Super admin grants are timelocked: instead of adding the admin, a
+    /// `PendingGrant` is created that `new_admin` activates with
+    /// `activate_pending_grant` once the grant delay has passed.
     ///
     /// # Returns
-    /// The created `AdminInfo`
+    /// The created `AdminInfo`. For a super admin grant it is not stored yet:
+    /// `active` is `false` and `assigned_at` is the earliest activation time.
     ///
     /// # Panics
     /// * If caller is not authorized to assign this role
     /// * If new_admin is already an admin
     /// * If maximum admin limit would be exceeded
     /// * If caller is trying to assign equal or higher role to themselves
+    /// * If expires_at is not in the future, or is set on a super admin
+    /// * If a super admin grant is already pending for new_admin
     ///
     /// # Events
-    /// Emits `admin_added` with the new admin information
-    pub fn add_admin(e: Env, caller: Address, new_admin: Address, role: AdminRole) -> AdminInfo {
+    /// Emits `admin_added` with the new admin information, or
+    /// `superadmin_grant_pending` with the `PendingGrant` for a super admin
+    pub fn add_admin(
+        e: Env,
+        caller: Address,
+        new_admin: Address,
+        role: AdminRole,
+        expires_at: u64,
+    ) -> AdminInfo {
         caller.require_auth();
+        pausable::require_not_paused(&e, PauseScope::Admin);
+        Self::add_admin_internal(&e, &caller, new_admin, role, expires_at)
+    }
 
-        // Verify caller authorization
-        Self::require_role_at_least(&e, &caller, Self::get_required_role_to_assign(role))
-            .unwrap_or_else(|_| panic!("insufficient privileges"));
-
-        // Check if new admin already exists
-        if e.storage()
-            .instance()
-            .has(&DataKey::AdminInfo(new_admin.clone()))
-        {
-            panic!("address is already an admin");
-        }
+    /// Add several permanent admins with a single authorization.
+    ///
+    /// The whole batch is validated before anything is written; any invalid
+    /// entry rejects every entry. Entries are added in order, and super admin
+    /// entries become pending grants as with `add_admin`.
+    ///
+    /// # Arguments
+    /// * `caller` - Address of the caller making the assignments
+    /// * `entries` - (address, role) pairs, at most `MAX_ADMIN_BATCH`
+    ///
+    /// # Returns
+    /// The `AdminInfo` of each entry, as `add_admin` returns it
+    ///
+    /// # Panics
+    /// * If entries is empty or longer than `MAX_ADMIN_BATCH`
+    /// * If an address appears more than once in the batch
+    /// * If caller is not authorized to assign any of the roles
+    /// * If any address is already an admin, a guardian or has a pending grant
+    /// * If the batch would exceed the maximum admin limit
+    ///
+    /// # Events
+    /// Emits one `admin_added` (`superadmin_grant_pending` for super admins)
+    /// per entry
+    pub fn add_admins_batch(
+        e: Env,
+        caller: Address,
+        entries: Vec<(Address, AdminRole)>,
+    ) -> Vec<AdminInfo> {
+        caller.require_auth();
+        pausable::require_not_paused(&e, PauseScope::Admin);
 
-        // Prevent self-assignment of equal or higher role
-        if caller == new_admin && Self::get_role(e.clone(), caller.clone()) >= role {
-            panic!("cannot assign equal or higher role to self");
+        if entries.is_empty() {
+            panic!("empty batch");
         }
-
-        // Check admin limit
-        let current_count = Self::get_admin_count(e.clone());
-        let max_admins: u32 = e
-            .storage()
-            .instance()
-            .get(&DataKey::MaxAdmins)
-            .unwrap_or(100);
-        if current_count >= max_admins {
-            panic!("maximum admin limit reached");
+        if entries.len() > MAX_ADMIN_BATCH {
+            panic!("batch too large");
         }
 
-        // Create admin info
-        let admin_info = AdminInfo {
-            address: new_admin.clone(),
-            role: role.clone(),
-            assigned_at: e.ledger().timestamp(),
-            assigned_by: caller.clone(),
-            active: true,
-        };
-
-        // Store admin info
-        e.storage()
-            .instance()
-            .set(&DataKey::AdminInfo(new_admin.clone()), &admin_info.clone());
-
-        // Update admin list
-        let mut admin_list: Vec<Address> = e
-            .storage()
-            .instance()
-            .get(&DataKey::AdminList)
-            .unwrap_or(Vec::new(&e));
-        admin_list.push_back(new_admin.clone());
-        e.storage().instance().set(&DataKey::AdminList, &admin_list);
+        // Validate every entry up front, tracking the admin count as
+        // `add_admin` would see it.
+        let (_, max_admins) = Self::get_config(e.clone());
+        let mut admin_count = Self::get_admin_count(e.clone());
+        let mut seen: Vec<Address> = Vec::new(&e);
+        for (address, role) in entries.iter() {
+            if seen.contains(&address) {
+                panic!("duplicate address in batch");
+            }
+            seen.push_back(address.clone());
 
-        // Update role-based admin list
-        let mut role_admins: Vec<Address> = e
-            .storage()
-            .instance()
-            .get(&DataKey::RoleAdmins(role.clone()))
-            .unwrap_or(Vec::new(&e));
-        role_admins.push_back(new_admin.clone());
-        e.storage()
-            .instance()
-            .set(&DataKey::RoleAdmins(role), &role_admins);
+            Self::require_role_at_least(&e, &caller, Self::get_required_role_to_assign(role))
+                .unwrap_or_else(|_| panic!("insufficient privileges"));
+            Self::require_permission(&e, &caller, Self::manage_permission(role));
 
-        e.events()
-            .publish((Symbol::new(&e, "admin_added"),), admin_info.clone());
+            if storage::admin_info(&e, &address).is_some() {
+                panic!("address is already an admin");
+            }
+            if storage::is_guardian(&e, &address) {
+                panic!("address is a guardian");
+            }
+            if storage::pending_grant(&e, &address).is_some() {
+                panic!("grant already pending");
+            }
+            if admin_count >= max_admins {
+                panic!("batch exceeds max_admins");
+            }
+            if role != AdminRole::SuperAdmin {
+                admin_count += 1;
+            }
+        }
 
-        admin_info
+        let mut created = Vec::new(&e);
+        for (address, role) in entries.iter() {
+            created.push_back(Self::add_admin_internal(&e, &caller, address, role, 0));
+        }
+        created
     }
 
     /// Remove an admin from the system.
@@ -249,26 +443,21 @@ impl AdminContract {
     /// Emits `admin_removed` with the removed admin information
     pub fn remove_admin(e: Env, caller: Address, admin_to_remove: Address) {
         caller.require_auth();
+        pausable::require_not_paused(&e, PauseScope::Admin);
 
         // Get admin info
-        let admin_info: AdminInfo = e
-            .storage()
-            .instance()
-            .get(&DataKey::AdminInfo(admin_to_remove.clone()))
-            .unwrap_or_else(|| panic!("admin not found"));
+        let admin_info: AdminInfo =
+            storage::admin_info(&e, &admin_to_remove).unwrap_or_else(|| panic!("admin not found"));
 
         // Verify caller authorization
         let caller_role = Self::get_role(e.clone(), caller.clone());
         if caller_role <= admin_info.role {
             panic!("insufficient privileges to remove admin");
         }
+        Self::require_permission(&e, &caller, Self::manage_permission(admin_info.role));
 
         // Check minimum admin requirements
-        let role_admins: Vec<Address> = e
-            .storage()
-            .instance()
-            .get(&DataKey::RoleAdmins(admin_info.role.clone()))
-            .unwrap_or(Vec::new(&e));
+        let role_admins: Vec<Address> = storage::role_admins(&e, admin_info.role);
 
         let min_admins: u32 = e.storage().instance().get(&DataKey::MinAdmins).unwrap_or(1);
 
@@ -278,36 +467,31 @@ impl AdminContract {
         }
 
         // Remove from admin info storage
-        e.storage()
-            .instance()
-            .remove(&DataKey::AdminInfo(admin_to_remove.clone()));
+        storage::remove_admin_info(&e, &admin_to_remove);
 
         // Remove from admin list
-        let mut admin_list: Vec<Address> = e
-            .storage()
-            .instance()
-            .get(&DataKey::AdminList)
-            .unwrap_or(Vec::new(&e));
+        let mut admin_list: Vec<Address> = storage::admin_list(&e);
         let admin_index = admin_list.iter().position(|x| x == admin_to_remove);
         if let Some(index) = admin_index {
             admin_list.remove(index.try_into().unwrap());
-            e.storage().instance().set(&DataKey::AdminList, &admin_list);
+            storage::set_admin_list(&e, &admin_list);
         }
 
         // Remove from role-based admin list
-        let mut role_admins: Vec<Address> = e
-            .storage()
-            .instance()
-            .get(&DataKey::RoleAdmins(admin_info.role.clone()))
-            .unwrap_or(Vec::new(&e));
+        let mut role_admins: Vec<Address> = storage::role_admins(&e, admin_info.role);
         let role_index = role_admins.iter().position(|x| x == admin_to_remove);
         if let Some(index) = role_index {
             role_admins.remove(index.try_into().unwrap());
-            e.storage()
-                .instance()
-                .set(&DataKey::RoleAdmins(admin_info.role.clone()), &role_admins);
+            storage::set_role_admins(&e, admin_info.role, &role_admins);
         }
 
+        Self::record_action(
+            &e,
+            "admin_removed",
+            &caller,
+            &admin_to_remove,
+            admin_info.role,
+        );
         e.events()
             .publish((Symbol::new(&e, "admin_removed"),), admin_info);
     }
@@ -318,79 +502,85 @@ impl AdminContract {
     /// * `caller` - Address of the caller making the change
     /// * `admin_address` - Address of the admin to update
     /// * `new_role` - New role to assign
+    /// * `expires_at` - Timestamp after which the new role lapses (0 = permanent)
+    ///
+    /// Promotion to super admin is timelocked like `add_admin`: a
+    /// `PendingGrant` is created and the admin keeps their current role until
+    /// they call `activate_pending_grant`.
     ///
     /// # Returns
-    /// The updated `AdminInfo`
+    /// The updated `AdminInfo`, or the unchanged one for a promotion to
+    /// super admin
     ///
     /// # Panics
     /// * If caller is not authorized to change to this role
     /// * If admin_address is not an admin
     /// * If caller is trying to assign equal or higher role to themselves
+    /// * If expires_at is not in the future, or is set on a super admin
+    /// * If a super admin grant is already pending for admin_address
     ///
     /// # Events
-    /// Emits `admin_role_updated` with the updated admin information
+    /// Emits `admin_role_updated` with the updated admin information, or
+    /// `superadmin_grant_pending` with the `PendingGrant` for a promotion
     pub fn update_admin_role(
         e: Env,
         caller: Address,
         admin_address: Address,
         new_role: AdminRole,
+        expires_at: u64,
     ) -> AdminInfo {
         caller.require_auth();
+        pausable::require_not_paused(&e, PauseScope::Admin);
 
         // Get current admin info
-        let mut admin_info: AdminInfo = e
-            .storage()
-            .instance()
-            .get(&DataKey::AdminInfo(admin_address.clone()))
-            .unwrap_or_else(|| panic!("admin not found"));
+        let mut admin_info: AdminInfo =
+            storage::admin_info(&e, &admin_address).unwrap_or_else(|| panic!("admin not found"));
 
         // Verify caller authorization
         Self::require_role_at_least(&e, &caller, Self::get_required_role_to_assign(new_role))
             .unwrap_or_else(|_| panic!("insufficient privileges"));
+        Self::require_permission(&e, &caller, Self::manage_permission(new_role));
 
         // Prevent self-assignment of equal or higher role
         if caller == admin_address && Self::get_role(e.clone(), caller.clone()) >= new_role {
             panic!("cannot assign equal or higher role to self");
         }
 
+        Self::validate_expiry(&e, new_role, expires_at);
+
+        if new_role == AdminRole::SuperAdmin && admin_info.role != AdminRole::SuperAdmin {
+            Self::queue_super_admin_grant(&e, &caller, &admin_address);
+            return admin_info;
+        }
+
         let old_role = admin_info.role.clone();
 
         // Remove from old role list
-        let mut old_role_admins: Vec<Address> = e
-            .storage()
-            .instance()
-            .get(&DataKey::RoleAdmins(old_role.clone()))
-            .unwrap_or(Vec::new(&e));
+        let mut old_role_admins: Vec<Address> = storage::role_admins(&e, old_role);
         let old_index = old_role_admins.iter().position(|x| x == admin_address);
         if let Some(index) = old_index {
             old_role_admins.remove(index.try_into().unwrap());
-            e.storage()
-                .instance()
-                .set(&DataKey::RoleAdmins(old_role.clone()), &old_role_admins);
+            storage::set_role_admins(&e, old_role, &old_role_admins);
         }
 
         // Add to new role list
-        let mut new_role_admins: Vec<Address> = e
-            .storage()
-            .instance()
-            .get(&DataKey::RoleAdmins(new_role.clone()))
-            .unwrap_or(Vec::new(&e));
+        let mut new_role_admins: Vec<Address> = storage::role_admins(&e, new_role);
         new_role_admins.push_back(admin_address.clone());
-        e.storage()
-            .instance()
-            .set(&DataKey::RoleAdmins(new_role.clone()), &new_role_admins);
+        storage::set_role_admins(&e, new_role, &new_role_admins);
 
         // Update admin info
         admin_info.role = new_role.clone();
         admin_info.assigned_at = e.ledger().timestamp();
         admin_info.assigned_by = caller.clone();
+        admin_info.expires_at = expires_at;
+
+        // A custom mask was tailored to the old role; fall back to defaults.
+        storage::remove_permissions(&e, &admin_address);
 
         // Store updated admin info
-        e.storage().instance().set(
-            &DataKey::AdminInfo(admin_address.clone()),
-            &admin_info.clone(),
-        );
+        storage::set_admin_info(&e, &admin_info);
 
+        Self::record_action(&e, "admin_role_updated", &caller, &admin_address, new_role);
         e.events().publish(
             (Symbol::new(&e, "admin_role_updated"),),
             (admin_address, old_role.clone(), new_role.clone()),
@@ -414,29 +604,32 @@ impl AdminContract {
     /// Emits `admin_deactivated` with the deactivated admin information
     pub fn deactivate_admin(e: Env, caller: Address, admin_address: Address) {
         caller.require_auth();
+        pausable::require_not_paused(&e, PauseScope::Admin);
 
-        let mut admin_info: AdminInfo = e
-            .storage()
-            .instance()
-            .get(&DataKey::AdminInfo(admin_address.clone()))
-            .unwrap_or_else(|| panic!("admin not found"));
+        let mut admin_info: AdminInfo =
+            storage::admin_info(&e, &admin_address).unwrap_or_else(|| panic!("admin not found"));
 
         // Verify caller authorization
         let caller_role = Self::get_role(e.clone(), caller.clone());
         if caller_role <= admin_info.role {
             panic!("insufficient privileges to deactivate admin");
         }
+        Self::require_permission(&e, &caller, Self::manage_permission(admin_info.role));
 
         if !admin_info.active {
             panic!("admin already deactivated");
         }
 
         admin_info.active = false;
-        e.storage().instance().set(
-            &DataKey::AdminInfo(admin_address.clone()),
-            &admin_info.clone(),
+        storage::set_admin_info(&e, &admin_info);
+
+        Self::record_action(
+            &e,
+            "admin_deactivated",
+            &caller,
+            &admin_address,
+            admin_info.role,
         );
-
         e.events()
             .publish((Symbol::new(&e, "admin_deactivated"),), admin_info);
     }
@@ -456,126 +649,1055 @@ impl AdminContract {
     /// Emits `admin_reactivated` with the reactivated admin information
     pub fn reactivate_admin(e: Env, caller: Address, admin_address: Address) {
         caller.require_auth();
+        pausable::require_not_paused(&e, PauseScope::Admin);
 
-        let mut admin_info: AdminInfo = e
-            .storage()
-            .instance()
-            .get(&DataKey::AdminInfo(admin_address.clone()))
-            .unwrap_or_else(|| panic!("admin not found"));
+        let mut admin_info: AdminInfo =
+            storage::admin_info(&e, &admin_address).unwrap_or_else(|| panic!("admin not found"));
 
         // Verify caller authorization
         let caller_role = Self::get_role(e.clone(), caller.clone());
         if caller_role <= admin_info.role {
             panic!("insufficient privileges to reactivate admin");
         }
+        Self::require_permission(&e, &caller, Self::manage_permission(admin_info.role));
 
         if admin_info.active {
             panic!("admin already active");
         }
 
         admin_info.active = true;
-        e.storage().instance().set(
-            &DataKey::AdminInfo(admin_address.clone()),
-            &admin_info.clone(),
+        storage::set_admin_info(&e, &admin_info);
+
+        Self::record_action(
+            &e,
+            "admin_reactivated",
+            &caller,
+            &admin_address,
+            admin_info.role,
         );
-
         e.events()
             .publish((Symbol::new(&e, "admin_reactivated"),), admin_info);
     }
 
-    /// Get information about a specific admin.
+    /// Extend or shorten the expiry of an admin's role.
+    ///
+    /// Follows the same rules as assigning the role: the caller must be able
+    /// to assign the admin's current role and cannot renew themselves. An
+    /// expired entry that has not been purged yet can be renewed.
     ///
     /// # Arguments
-    /// * `admin_address` - Address of the admin to query
+    /// * `caller` - Address of the caller making the change
+    /// * `admin_address` - Address of the admin to renew
+    /// * `new_expiry` - New expiry timestamp (0 = permanent)
     ///
     /// # Returns
-    /// The `AdminInfo` for the specified admin
+    /// The updated `AdminInfo`
     ///
     /// # Panics
     /// * If admin_address is not an admin
-    pub fn get_admin_info(e: Env, admin_address: Address) -> AdminInfo {
-        e.storage()
-            .instance()
-            .get(&DataKey::AdminInfo(admin_address))
-            .unwrap_or_else(|| panic!("admin not found"))
-    }
-
-    /// Check if an address is an admin and return their role.
-    ///
-    /// # Arguments
-    /// * `address` - Address to check
+    /// * If caller is not authorized to assign the admin's role
+    /// * If caller is trying to renew themselves
+    /// * If new_expiry is not in the future, or is set on a super admin
     ///
-    /// # Returns
-    /// The admin role if the address is an admin, panics otherwise
-    pub fn get_admin_role(e: Env, address: Address) -> AdminRole {
-        let admin_info: AdminInfo = e
-            .storage()
-            .instance()
-            .get(&DataKey::AdminInfo(address))
-            .unwrap_or_else(|| panic!("address is not an admin"));
-        admin_info.role
+    /// # Events
+    /// Emits `admin_renewed` with (admin_address, old_expiry, new_expiry)
+    pub fn renew_admin(
+        e: Env,
+        caller: Address,
+        admin_address: Address,
+        new_expiry: u64,
+    ) -> AdminInfo {
+        caller.require_auth();
+        pausable::require_not_paused(&e, PauseScope::Admin);
+
+        let mut admin_info: AdminInfo =
+            storage::admin_info(&e, &admin_address).unwrap_or_else(|| panic!("admin not found"));
+
+        // Verify caller authorization
+        Self::require_role_at_least(
+            &e,
+            &caller,
+            Self::get_required_role_to_assign(admin_info.role),
+        )
+        .unwrap_or_else(|_| panic!("insufficient privileges"));
+        Self::require_permission(&e, &caller, Self::manage_permission(admin_info.role));
+
+        if caller == admin_address {
+            panic!("cannot assign equal or higher role to self");
+        }
+
+        Self::validate_expiry(&e, admin_info.role, new_expiry);
+
+        let old_expiry = admin_info.expires_at;
+        admin_info.expires_at = new_expiry;
+        storage::set_admin_info(&e, &admin_info);
+
+        Self::record_action(
+            &e,
+            "admin_renewed",
+            &caller,
+            &admin_address,
+            admin_info.role,
+        );
+        e.events().publish(
+            (Symbol::new(&e, "admin_renewed"),),
+            (admin_address, old_expiry, new_expiry),
+        );
+
+        admin_info
     }
 
-    /// Check if an address is an active admin.
+    /// Remove every admin whose role has expired.
     ///
-    /// # Arguments
-    /// * `address` - Address to check
+    /// Maintenance call that anyone may invoke. Expired entries are dropped
+    /// from the admin list, their role list and admin info storage.
     ///
     /// # Returns
-    /// `true` if the address is an active admin, `false` otherwise
-    pub fn is_admin(e: Env, address: Address) -> bool {
-        match e
-            .storage()
-            .instance()
-            .get::<_, AdminInfo>(&DataKey::AdminInfo(address))
-        {
-            Some(admin_info) => admin_info.active,
-            None => false,
+    /// The number of admins purged
+    ///
+    /// # Events
+    /// Emits `admin_expired` with the purged admin information for each entry
+    pub fn purge_expired_admins(e: Env) -> u32 {
+        let admin_list = Self::get_all_admins(e.clone());
+        let mut kept: Vec<Address> = Vec::new(&e);
+        let mut purged = 0u32;
+
+        for admin in admin_list.iter() {
+            let info: Option<AdminInfo> = storage::admin_info(&e, &admin);
+            let admin_info = match info {
+                Some(admin_info) if Self::is_expired(&e, &admin_info) => admin_info,
+                _ => {
+                    kept.push_back(admin);
+                    continue;
+                }
+            };
+
+            storage::remove_admin_info(&e, &admin);
+
+            let mut role_admins: Vec<Address> = storage::role_admins(&e, admin_info.role);
+            if let Some(index) = role_admins.iter().position(|x| x == admin) {
+                role_admins.remove(index.try_into().unwrap());
+                storage::set_role_admins(&e, admin_info.role, &role_admins);
+            }
+
+            e.events()
+                .publish((Symbol::new(&e, "admin_expired"),), admin_info);
+            purged += 1;
         }
+
+        if purged > 0 {
+            storage::set_admin_list(&e, &kept);
+        }
+        purged
     }
 
-    /// Check if an address has at least the specified role level.
+    /// Propose handing a super admin role over to another address.
+    ///
+    /// The role only moves once `new_super_admin` accepts. A new proposal
+    /// replaces any pending one.
     ///
     /// # Arguments
-    /// * `address` - Address to check
-    /// * `required_role` - Minimum required role
+    /// * `current` - Super admin handing over their role
+    /// * `new_super_admin` - Address that will receive the role
     ///
     /// # Returns
-    /// `true` if the address has at least the required role, `false` otherwise
-    pub fn has_role_at_least(e: Env, address: Address, required_role: AdminRole) -> bool {
-        match e
-            .storage()
-            .instance()
-            .get::<_, AdminInfo>(&DataKey::AdminInfo(address))
-        {
-            Some(admin_info) => admin_info.active && admin_info.role >= required_role,
-            None => false,
+    /// The pending `SuperAdminTransfer`
+    ///
+    /// # Panics
+    /// * If current is not a super admin
+    /// * If new_super_admin is current or already a super admin
+    ///
+    /// # Events
+    /// Emits `super_admin_transfer_proposed` with (current, new_super_admin)
+    pub fn propose_super_admin_transfer(
+        e: Env,
+        current: Address,
+        new_super_admin: Address,
+    ) -> SuperAdminTransfer {
+        current.require_auth();
+        pausable::require_not_paused(&e, PauseScope::Admin);
+        Self::require_super_admin(&e, &current);
+        Self::require_permission(&e, &current, Permission::ManageSuperAdmins);
+
+        if current == new_super_admin {
+            panic!("cannot transfer to self");
+        }
+        if let Some(info) = storage::admin_info(&e, &new_super_admin) {
+            if info.role == AdminRole::SuperAdmin {
+                panic!("address is already a super admin");
+            }
+        }
+        if storage::is_guardian(&e, &new_super_admin) {
+            panic!("address is a guardian");
         }
-    }
 
-    /// Get all admin addresses.
-    ///
-    /// # Returns
-    /// A `Vec` of all admin addresses
-    pub fn get_all_admins(e: Env) -> Vec<Address> {
+        let transfer = SuperAdminTransfer {
+            from: current.clone(),
+            to: new_super_admin.clone(),
+            proposed_at: e.ledger().timestamp(),
+        };
         e.storage()
             .instance()
-            .get(&DataKey::AdminList)
-            .unwrap_or(Vec::new(&e))
+            .set(&DataKey::PendingSuperAdminTransfer, &transfer);
+        Self::touch(&e, &current);
+
+        e.events().publish(
+            (Symbol::new(&e, "super_admin_transfer_proposed"),),
+            (current, new_super_admin),
+        );
+
+        transfer
     }
 
-    /// Get all admins with a specific role.
+    /// Accept a pending super admin handover.
+    ///
+    /// The proposer's role moves to `new_super_admin` in one step and the
+    /// proposer is removed, so the number of super admins never changes. If
+    /// `new_super_admin` already held a lower role it is replaced.
     ///
     /// # Arguments
-    /// * `role` - Role to filter by
+    /// * `new_super_admin` - Address named in the pending transfer
     ///
     /// # Returns
-    /// A `Vec` of admin addresses with the specified role
-    pub fn get_admins_by_role(e: Env, role: AdminRole) -> Vec<Address> {
-        e.storage()
+    /// The new super admin's `AdminInfo`
+    ///
+    /// # Panics
+    /// * If there is no pending transfer
+    /// * If new_super_admin is not the proposed address
+    /// * If the proposer is no longer a super admin
+    ///
+    /// # Events
+    /// Emits `super_admin_transfer_accepted` with (from, new_super_admin)
+    pub fn accept_super_admin_transfer(e: Env, new_super_admin: Address) -> AdminInfo {
+        new_super_admin.require_auth();
+        pausable::require_not_paused(&e, PauseScope::Admin);
+
+        let transfer: SuperAdminTransfer = e
+            .storage()
+            .instance()
+            .get(&DataKey::PendingSuperAdminTransfer)
+            .unwrap_or_else(|| panic!("no pending super admin transfer"));
+        if transfer.to != new_super_admin {
+            panic!("not the proposed super admin");
+        }
+        Self::require_super_admin(&e, &transfer.from);
+
+        // Drop the new super admin from any role they held before.
+        if let Some(old_info) = storage::admin_info(&e, &new_super_admin) {
+            let mut role_admins: Vec<Address> = storage::role_admins(&e, old_info.role);
+            if let Some(index) = role_admins.iter().position(|x| x == new_super_admin) {
+                role_admins.remove(index.try_into().unwrap());
+                storage::set_role_admins(&e, old_info.role, &role_admins);
+            }
+        }
+
+        // Swap the addresses in the super admin list.
+        let mut super_admins: Vec<Address> = storage::role_admins(&e, AdminRole::SuperAdmin);
+        if let Some(index) = super_admins.iter().position(|x| x == transfer.from) {
+            super_admins.set(index.try_into().unwrap(), new_super_admin.clone());
+        }
+        storage::set_role_admins(&e, AdminRole::SuperAdmin, &super_admins);
+
+        // Swap the addresses in the admin list.
+        let mut admin_list = Self::get_all_admins(e.clone());
+        if let Some(index) = admin_list.iter().position(|x| x == transfer.from) {
+            if admin_list.contains(&new_super_admin) {
+                admin_list.remove(index.try_into().unwrap());
+            } else {
+                admin_list.set(index.try_into().unwrap(), new_super_admin.clone());
+            }
+        }
+        storage::set_admin_list(&e, &admin_list);
+
+        let admin_info = AdminInfo {
+            address: new_super_admin.clone(),
+            role: AdminRole::SuperAdmin,
+            assigned_at: e.ledger().timestamp(),
+            assigned_by: transfer.from.clone(),
+            active: true,
+            expires_at: 0,
+        };
+        storage::remove_admin_info(&e, &transfer.from);
+        storage::remove_permissions(&e, &new_super_admin);
+        storage::set_admin_info(&e, &admin_info);
+        e.storage()
+            .instance()
+            .remove(&DataKey::PendingSuperAdminTransfer);
+
+        Self::record_action(
+            &e,
+            "super_admin_transfer_accepted",
+            &transfer.from,
+            &new_super_admin,
+            AdminRole::SuperAdmin,
+        );
+        Self::touch(&e, &new_super_admin);
+        e.events().publish(
+            (Symbol::new(&e, "super_admin_transfer_accepted"),),
+            (transfer.from, new_super_admin),
+        );
+
+        admin_info
+    }
+
+    /// Cancel the pending super admin handover.
+    ///
+    /// # Arguments
+    /// * `caller` - Any super admin
+    ///
+    /// # Panics
+    /// * If caller is not a super admin
+    /// * If there is no pending transfer
+    ///
+    /// # Events
+    /// Emits `super_admin_transfer_cancelled` with (from, to)
+    pub fn cancel_super_admin_transfer(e: Env, caller: Address) {
+        caller.require_auth();
+        pausable::require_not_paused(&e, PauseScope::Admin);
+        Self::require_super_admin(&e, &caller);
+        Self::require_permission(&e, &caller, Permission::ManageSuperAdmins);
+
+        let transfer: SuperAdminTransfer = e
+            .storage()
+            .instance()
+            .get(&DataKey::PendingSuperAdminTransfer)
+            .unwrap_or_else(|| panic!("no pending super admin transfer"));
+        e.storage()
+            .instance()
+            .remove(&DataKey::PendingSuperAdminTransfer);
+        Self::touch(&e, &caller);
+
+        e.events().publish(
+            (Symbol::new(&e, "super_admin_transfer_cancelled"),),
+            (transfer.from, transfer.to),
+        );
+    }
+
+    /// Get the pending super admin handover, if any.
+    pub fn get_pending_super_admin_transfer(e: Env) -> Option<SuperAdminTransfer> {
+        e.storage()
+            .instance()
+            .get(&DataKey::PendingSuperAdminTransfer)
+    }
+
+    /// Activate a super admin grant once its delay has passed.
+    ///
+    /// If the grantee already holds a lower role it is replaced.
+    ///
+    /// # Arguments
+    /// * `address` - Grantee of the pending grant
+    ///
+    /// # Returns
+    /// The new super admin's `AdminInfo`
+    ///
+    /// # Panics
+    /// * If there is no pending grant for address
+    /// * If the grant delay has not passed yet
+    /// * If the granting super admin no longer holds the role
+    /// * If address became a super admin in the meantime
+    /// * If adding the admin would exceed the maximum admin limit
+    ///
+    /// # Events
+    /// Emits `superadmin_grant_activated` with (address, granted_by) and
+    /// `admin_added` with the new admin information
+    pub fn activate_pending_grant(e: Env, address: Address) -> AdminInfo {
+        address.require_auth();
+        pausable::require_not_paused(&e, PauseScope::Admin);
+
+        let grant =
+            storage::pending_grant(&e, &address).unwrap_or_else(|| panic!("no pending grant"));
+        if e.ledger().timestamp() < grant.effective_at {
+            panic!("grant is still timelocked");
+        }
+        if !Self::has_role_at_least(e.clone(), grant.granted_by.clone(), AdminRole::SuperAdmin) {
+            panic!("granter is no longer a super admin");
+        }
+        storage::remove_pending_grant(&e, &address);
+
+        match storage::admin_info(&e, &address) {
+            Some(old_info) => {
+                if old_info.role == AdminRole::SuperAdmin {
+                    panic!("address is already a super admin");
+                }
+                let mut role_admins = storage::role_admins(&e, old_info.role);
+                if let Some(index) = role_admins.iter().position(|x| x == address) {
+                    role_admins.remove(index.try_into().unwrap());
+                    storage::set_role_admins(&e, old_info.role, &role_admins);
+                }
+                storage::remove_permissions(&e, &address);
+            }
+            None => {
+                let (_, max_admins) = Self::get_config(e.clone());
+                if Self::get_admin_count(e.clone()) >= max_admins {
+                    panic!("maximum admin limit reached");
+                }
+                let mut admin_list = storage::admin_list(&e);
+                admin_list.push_back(address.clone());
+                storage::set_admin_list(&e, &admin_list);
+            }
+        }
+
+        let mut super_admins = storage::role_admins(&e, AdminRole::SuperAdmin);
+        super_admins.push_back(address.clone());
+        storage::set_role_admins(&e, AdminRole::SuperAdmin, &super_admins);
+
+        let admin_info = AdminInfo {
+            address: address.clone(),
+            role: AdminRole::SuperAdmin,
+            assigned_at: e.ledger().timestamp(),
+            assigned_by: grant.granted_by.clone(),
+            active: true,
+            expires_at: 0,
+        };
+        storage::set_admin_info(&e, &admin_info);
+
+        Self::record_action(
+            &e,
+            "superadmin_grant_activated",
+            &address,
+            &address,
+            AdminRole::SuperAdmin,
+        );
+        e.events().publish(
+            (Symbol::new(&e, "superadmin_grant_activated"),),
+            (address, grant.granted_by),
+        );
+        e.events()
+            .publish((Symbol::new(&e, "admin_added"),), admin_info.clone());
+
+        admin_info
+    }
+
+    /// Cancel a pending super admin grant during its delay window.
+    ///
+    /// Works while the `Admin` scope is paused, and regardless of the
+    /// caller's permission mask, so any super admin can block a grant.
+    ///
+    /// # Arguments
+    /// * `caller` - Any super admin
+    /// * `address` - Grantee of the pending grant
+    ///
+    /// # Panics
+    /// * If caller is not a super admin
+    /// * If there is no pending grant for address
+    ///
+    /// # Events
+    /// Emits `superadmin_grant_cancelled` with (address, caller)
+    pub fn cancel_pending_grant(e: Env, caller: Address, address: Address) {
+        caller.require_auth();
+        Self::require_super_admin(&e, &caller);
+
+        if storage::pending_grant(&e, &address).is_none() {
+            panic!("no pending grant");
+        }
+        storage::remove_pending_grant(&e, &address);
+
+        Self::record_action(
+            &e,
+            "superadmin_grant_cancelled",
+            &caller,
+            &address,
+            AdminRole::SuperAdmin,
+        );
+        e.events().publish(
+            (Symbol::new(&e, "superadmin_grant_cancelled"),),
+            (address, caller),
+        );
+    }
+
+    /// Get the pending super admin grant for an address, if any.
+    pub fn get_pending_grant(e: Env, address: Address) -> Option<PendingGrant> {
+        storage::pending_grant(&e, &address)
+    }
+
+    /// Set the delay before new super admin grants can be activated.
+    ///
+    /// An increase, or keeping the current value, applies at once and drops
+    /// any queued reduction. A reduction is queued and only applies once the
+    /// current delay has elapsed, so a compromised super admin cannot shorten
+    /// the window that lets the others cancel a grant. Grants already pending
+    /// keep their original activation time.
+    ///
+    /// # Arguments
+    /// * `caller` - Super admin making the change
+    /// * `delay_secs` - New delay, at least one hour
+    ///
+    /// # Panics
+    /// * If caller is not a super admin
+    /// * If delay_secs is below `MIN_SUPERADMIN_GRANT_DELAY`
+    ///
+    /// # Events
+    /// Emits `superadmin_grant_delay_updated` with the new delay, or
+    /// `superadmin_grant_delay_queued` with `(delay_secs, effective_at)` for a
+    /// reduction
+    pub fn set_superadmin_grant_delay(e: Env, caller: Address, delay_secs: u64) {
+        caller.require_auth();
+        pausable::require_not_paused(&e, PauseScope::Admin);
+        Self::require_super_admin(&e, &caller);
+
+        if delay_secs < MIN_SUPERADMIN_GRANT_DELAY {
+            panic!("grant delay below minimum");
+        }
+        let current = Self::get_superadmin_grant_delay(e.clone());
+        e.storage()
+            .instance()
+            .remove(&DataKey::PendingSuperAdminGrantDelay);
+
+        if delay_secs >= current {
+            e.storage()
+                .instance()
+                .set(&DataKey::SuperAdminGrantDelay, &delay_secs);
+            Self::record_action(
+                &e,
+                "superadmin_grant_delay_updated",
+                &caller,
+                &e.current_contract_address(),
+                AdminRole::SuperAdmin,
+            );
+            e.events().publish(
+                (Symbol::new(&e, "superadmin_grant_delay_updated"),),
+                delay_secs,
+            );
+            return;
+        }
+
+        let pending = PendingGrantDelay {
+            delay_secs,
+            effective_at: e.ledger().timestamp().saturating_add(current),
+        };
+        e.storage()
+            .instance()
+            .set(&DataKey::SuperAdminGrantDelay, &current);
+        e.storage()
+            .instance()
+            .set(&DataKey::PendingSuperAdminGrantDelay, &pending);
+        Self::record_action(
+            &e,
+            "superadmin_grant_delay_queued",
+            &caller,
+            &e.current_contract_address(),
+            AdminRole::SuperAdmin,
+        );
+        e.events().publish(
+            (Symbol::new(&e, "superadmin_grant_delay_queued"),),
+            (delay_secs, pending.effective_at),
+        );
+    }
+
+    /// Get the delay before super admin grants can be activated, including a
+    /// queued reduction whose wait has elapsed.
+    pub fn get_superadmin_grant_delay(e: Env) -> u64 {
+        let queued: Option<PendingGrantDelay> = e
+            .storage()
+            .instance()
+            .get(&DataKey::PendingSuperAdminGrantDelay);
+        match queued {
+            Some(pending) if e.ledger().timestamp() >= pending.effective_at => pending.delay_secs,
+            _ => e
+                .storage()
+                .instance()
+                .get(&DataKey::SuperAdminGrantDelay)
+                .unwrap_or(DEFAULT_SUPERADMIN_GRANT_DELAY),
+        }
+    }
+
+    /// Get the queued grant delay reduction, if it has not taken effect yet.
+    pub fn get_pending_grant_delay(e: Env) -> Option<PendingGrantDelay> {
+        e.storage()
             .instance()
-            .get(&DataKey::RoleAdmins(role))
-            .unwrap_or(Vec::new(&e))
+            .get::<_, PendingGrantDelay>(&DataKey::PendingSuperAdminGrantDelay)
+            .filter(|pending| e.ledger().timestamp() < pending.effective_at)
+    }
+
+    /// Set a custom permission mask for an admin.
+    ///
+    /// The mask replaces the role's default permissions until the admin's
+    /// role changes. Permissions never grant more than the role allows: bits
+    /// outside the role's default mask are dropped.
+    ///
+    /// # Arguments
+    /// * `caller` - Address of the caller making the change
+    /// * `admin_address` - Address of the admin to update
+    /// * `mask` - Bitwise OR of `Permission` values
+    ///
+    /// # Panics
+    /// * If admin_address is not an admin
+    /// * If caller does not hold a strictly higher role than the admin
+    /// * If caller lacks the permission to manage the admin's role
+    /// * If mask contains unknown bits
+    ///
+    /// # Events
+    /// Emits `admin_permissions_updated` with (admin_address, stored mask)
+    pub fn set_permissions(e: Env, caller: Address, admin_address: Address, mask: u32) {
+        caller.require_auth();
+        pausable::require_not_paused(&e, PauseScope::Admin);
+
+        let admin_info: AdminInfo =
+            storage::admin_info(&e, &admin_address).unwrap_or_else(|| panic!("admin not found"));
+
+        let caller_role = Self::get_role(e.clone(), caller.clone());
+        if caller_role <= admin_info.role {
+            panic!("insufficient privileges to set permissions");
+        }
+        Self::require_permission(&e, &caller, Self::manage_permission(admin_info.role));
+
+        if mask & !ALL_PERMISSIONS != 0 {
+            panic!("invalid permission mask");
+        }
+        let mask = mask & Self::get_default_permissions(admin_info.role);
+
+        storage::set_permissions(&e, &admin_address, mask);
+
+        Self::record_action(
+            &e,
+            "admin_permissions_updated",
+            &caller,
+            &admin_address,
+            admin_info.role,
+        );
+        e.events().publish(
+            (Symbol::new(&e, "admin_permissions_updated"),),
+            (admin_address, mask),
+        );
+    }
+
+    /// Get an admin's permission mask (custom mask or the role default).
+    ///
+    /// # Panics
+    /// * If admin_address is not an admin
+    pub fn get_permissions(e: Env, admin_address: Address) -> u32 {
+        let admin_info: AdminInfo =
+            storage::admin_info(&e, &admin_address).unwrap_or_else(|| panic!("admin not found"));
+        Self::permission_mask(&e, &admin_info)
+    }
+
+    /// Check if an address is an active admin holding a permission.
+    ///
+    /// # Returns
+    /// `true` if the address is active, unexpired and holds `permission`
+    pub fn has_permission(e: Env, address: Address, permission: Permission) -> bool {
+        match storage::admin_info(&e, &address) {
+            Some(admin_info) => {
+                Self::is_live(&e, &admin_info)
+                    && Self::permission_mask(&e, &admin_info) & permission as u32 != 0
+            }
+            None => false,
+        }
+    }
+
+    /// Get the default permission mask for a role.
+    pub fn get_default_permissions(role: AdminRole) -> u32 {
+        match role {
+            AdminRole::SuperAdmin => ALL_PERMISSIONS,
+            AdminRole::Admin => Permission::ManageOperators as u32 | Permission::Pause as u32,
+            AdminRole::Operator => Permission::Pause as u32,
+        }
+    }
+
+    /// Pause the protocol until explicitly unpaused.
+    ///
+    /// # Arguments
+    /// * `caller` - Guardian, or active admin holding the `Pause` permission
+    /// * `reason` - Short reason code shown to monitoring
+    /// * `detail` - Optional longer explanation
+    ///
+    /// # Returns
+    /// The recorded `PauseInfo`
+    ///
+    /// # Panics
+    /// * If caller is not a guardian, or is not an admin or lacks the
+    ///   `Pause` permission
+    ///
+    /// # Events
+    /// Emits `paused` with (caller, 0, reason)
+    pub fn pause(e: Env, caller: Address, reason: Symbol, detail: Option<String>) -> PauseInfo {
+        caller.require_auth();
+        Self::require_guardian_or_can_pause(&e, &caller);
+        Self::apply_pause(&e, &caller, 0, reason, detail)
+    }
+
+    /// Pause the protocol for `duration_secs`; the pause lifts on its own
+    /// once the ledger timestamp reaches the expiry.
+    ///
+    /// # Arguments
+    /// * `caller` - Guardian, or active admin holding the `Pause` permission
+    /// * `duration_secs` - Length of the pause in seconds
+    /// * `reason` - Short reason code shown to monitoring
+    /// * `detail` - Optional longer explanation
+    ///
+    /// # Returns
+    /// The recorded `PauseInfo`; `until` is the timestamp at which the pause lifts
+    ///
+    /// # Panics
+    /// * If caller is not a guardian, or is not an admin or lacks the
+    ///   `Pause` permission
+    /// * If duration_secs is zero
+    ///
+    /// # Events
+    /// Emits `paused` with (caller, paused_until, reason)
+    pub fn pause_for(
+        e: Env,
+        caller: Address,
+        duration_secs: u64,
+        reason: Symbol,
+        detail: Option<String>,
+    ) -> PauseInfo {
+        caller.require_auth();
+        Self::require_guardian_or_can_pause(&e, &caller);
+        if duration_secs == 0 {
+            panic!("duration must be positive");
+        }
+        let until = e
+            .ledger()
+            .timestamp()
+            .checked_add(duration_secs)
+            .unwrap_or_else(|| panic!("pause duration overflow"));
+        Self::apply_pause(&e, &caller, until, reason, detail)
+    }
+
+    /// Lift the pause, including a timed pause before it expires. Also clears
+    /// the record of a timed pause that has already lapsed.
+    ///
+    /// # Arguments
+    /// * `caller` - Admin or above holding the `Pause` permission
+    ///
+    /// # Panics
+    /// * If caller is below Admin or lacks the `Pause` permission
+    /// * If no pause is recorded
+    ///
+    /// # Events
+    /// Emits `unpaused` with the caller
+    pub fn unpause(e: Env, caller: Address) {
+        caller.require_auth();
+        let caller_role = Self::get_role(e.clone(), caller.clone());
+        if caller_role < AdminRole::Admin {
+            panic!("insufficient privileges");
+        }
+        Self::require_can_pause(&e, &caller);
+        if !pausable::unpause(&e) {
+            panic!("not paused");
+        }
+
+        Self::record_action(
+            &e,
+            "unpaused",
+            &caller,
+            &e.current_contract_address(),
+            caller_role,
+        );
+        e.events().publish((Symbol::new(&e, "unpaused"),), caller);
+    }
+
+    /// Check if everything is paused. A timed pause counts as lifted once
+    /// its expiry has passed.
+    pub fn is_paused(e: Env) -> bool {
+        pausable::is_paused(&e)
+    }
+
+    /// Pause a single scope until it is unpaused. Pausing `All` is the same
+    /// as `pause` with reason `unspecified`.
+    ///
+    /// # Arguments
+    /// * `caller` - Active admin holding the `Pause` permission
+    /// * `scope` - Scope to pause
+    ///
+    /// # Panics
+    /// * If caller is not an admin or lacks the `Pause` permission
+    ///
+    /// # Events
+    /// Emits `scope_paused` with (caller, scope)
+    pub fn pause_scope(e: Env, caller: Address, scope: PauseScope) {
+        if scope == PauseScope::All {
+            let reason = Symbol::new(&e, "unspecified");
+            Self::pause(e, caller, reason, None);
+            return;
+        }
+        caller.require_auth();
+        Self::require_can_pause(&e, &caller);
+
+        pausable::pause_scope(&e, scope);
+
+        let caller_role = Self::get_role(e.clone(), caller.clone());
+        Self::record_action(
+            &e,
+            "scope_paused",
+            &caller,
+            &e.current_contract_address(),
+            caller_role,
+        );
+        e.events()
+            .publish((Symbol::new(&e, "scope_paused"),), (caller, scope));
+    }
+
+    /// Lift the pause on a single scope. Unpausing `All` is the same as
+    /// `unpause` and leaves individually paused scopes in place.
+    ///
+    /// # Arguments
+    /// * `caller` - Admin or above holding the `Pause` permission
+    /// * `scope` - Scope to unpause
+    ///
+    /// # Panics
+    /// * If caller is below Admin or lacks the `Pause` permission
+    /// * If the scope is not paused
+    ///
+    /// # Events
+    /// Emits `scope_unpaused` with (caller, scope)
+    pub fn unpause_scope(e: Env, caller: Address, scope: PauseScope) {
+        if scope == PauseScope::All {
+            return Self::unpause(e, caller);
+        }
+        caller.require_auth();
+        let caller_role = Self::get_role(e.clone(), caller.clone());
+        if caller_role < AdminRole::Admin {
+            panic!("insufficient privileges");
+        }
+        Self::require_can_pause(&e, &caller);
+        if !pausable::unpause_scope(&e, scope) {
+            panic!("not paused");
+        }
+
+        Self::record_action(
+            &e,
+            "scope_unpaused",
+            &caller,
+            &e.current_contract_address(),
+            caller_role,
+        );
+        e.events()
+            .publish((Symbol::new(&e, "scope_unpaused"),), (caller, scope));
+    }
+
+    /// Grant or revoke the guardian capability.
+    ///
+    /// Guardians sit outside the role hierarchy: they may call `pause` and
+    /// `pause_for` and nothing else, including `unpause`. Granting needs a
+    /// super admin; any Admin or above can revoke, even while the `Admin`
+    /// scope is paused.
+    ///
+    /// # Arguments
+    /// * `caller` - Super admin to grant, Admin or above to revoke
+    /// * `address` - Address to update
+    /// * `enabled` - `true` to grant, `false` to revoke
+    ///
+    /// # Panics
+    /// * If granting and caller is not a super admin
+    /// * If granting to an address that is already an admin
+    /// * If revoking and caller is below Admin
+    ///
+    /// # Events
+    /// Emits `guardian_updated` with (address, enabled)
+    pub fn set_guardian(e: Env, caller: Address, address: Address, enabled: bool) {
+        caller.require_auth();
+        if enabled {
+            pausable::require_not_paused(&e, PauseScope::Admin);
+            Self::require_super_admin(&e, &caller);
+            if storage::admin_info(&e, &address).is_some() {
+                panic!("address is already an admin");
+            }
+        } else {
+            Self::require_role_at_least(&e, &caller, AdminRole::Admin)
+                .unwrap_or_else(|_| panic!("insufficient privileges"));
+        }
+
+        storage::set_guardian(&e, &address, enabled);
+
+        let caller_role = Self::get_role(e.clone(), caller.clone());
+        Self::record_action(&e, "guardian_updated", &caller, &address, caller_role);
+        e.events()
+            .publish((Symbol::new(&e, "guardian_updated"),), (address, enabled));
+    }
+
+    /// Check if an address holds the guardian capability.
+    pub fn is_guardian(e: Env, address: Address) -> bool {
+        storage::is_guardian(&e, &address)
+    }
+
+    /// Get the current pause of everything. `paused` is `false` once a timed
+    /// pause has lapsed; `None` when not paused or after `unpause`.
+    pub fn get_pause_info(e: Env) -> Option<PauseInfo> {
+        pausable::pause_info(&e)
+    }
+
+    /// Get the most recent pause of everything, kept after `unpause`.
+    pub fn get_last_pause_info(e: Env) -> Option<PauseInfo> {
+        pausable::last_pause_info(&e)
+    }
+
+    /// Check if operations in `scope` are paused, either on their own or
+    /// because everything is paused.
+    pub fn is_scope_paused(e: Env, scope: PauseScope) -> bool {
+        pausable::is_scope_paused(&e, scope)
+    }
+
+    /// Get the currently paused scopes; `All` is listed first when
+    /// everything is paused.
+    pub fn get_paused_scopes(e: Env) -> Vec<PauseScope> {
+        pausable::paused_scopes(&e)
+    }
+
+    /// Get the timestamp at which the current pause lifts (0 = until unpaused
+    /// or not paused).
+    pub fn get_paused_until(e: Env) -> u64 {
+        if pausable::is_paused(&e) {
+            pausable::paused_until(&e)
+        } else {
+            0
+        }
+    }
+
+    /// Get information about a specific admin.
+    ///
+    /// # Arguments
+    /// * `admin_address` - Address of the admin to query
+    ///
+    /// # Returns
+    /// The `AdminInfo` for the specified admin
+    ///
+    /// # Panics
+    /// * If admin_address is not an admin
+    pub fn get_admin_info(e: Env, admin_address: Address) -> AdminInfo {
+        storage::admin_info(&e, &admin_address).unwrap_or_else(|| panic!("admin not found"))
+    }
+
+    /// Check if an address is an admin and return their role.
+    ///
+    /// # Arguments
+    /// * `address` - Address to check
+    ///
+    /// # Returns
+    /// The admin role if the address is an admin, panics otherwise
+    ///
+    /// # Panics
+    /// * If address is not an admin
+    /// * If the admin's role has expired
+    pub fn get_admin_role(e: Env, address: Address) -> AdminRole {
+        Self::get_role(e, address)
+    }
+
+    /// Check if an address is an active admin.
+    ///
+    /// # Arguments
+    /// * `address` - Address to check
+    ///
+    /// # Returns
+    /// `true` if the address is an active admin, `false` otherwise
+    pub fn is_admin(e: Env, address: Address) -> bool {
+        match storage::admin_info(&e, &address) {
+            Some(admin_info) => Self::is_live(&e, &admin_info),
+            None => false,
+        }
+    }
+
+    /// Check if an address has at least the specified role level.
+    ///
+    /// # Arguments
+    /// * `address` - Address to check
+    /// * `required_role` - Minimum required role
+    ///
+    /// # Returns
+    /// `true` if the address has at least the required role, `false` otherwise
+    pub fn has_role_at_least(e: Env, address: Address, required_role: AdminRole) -> bool {
+        match storage::admin_info(&e, &address) {
+            Some(admin_info) => Self::is_live(&e, &admin_info) && admin_info.role >= required_role,
+            None => false,
+        }
+    }
+
+    /// Cross-contract role check.
+    ///
+    /// Stable entrypoint for other Credence contracts that delegate
+    /// authorization to this contract. Never panics: unknown, inactive and
+    /// expired addresses return `false`.
+    ///
+    /// # Returns
+    /// `true` if `address` is a live admin with at least `required_role`
+    pub fn check_role(e: Env, address: Address, required_role: AdminRole) -> bool {
+        Self::has_role_at_least(e, address, required_role)
+    }
+
+    /// Cross-contract permission check.
+    ///
+    /// Stable entrypoint for other Credence contracts that delegate
+    /// authorization to this contract. Never panics.
+    ///
+    /// # Returns
+    /// `true` if `address` is a live admin holding `permission`
+    pub fn check_permission(e: Env, address: Address, permission: Permission) -> bool {
+        Self::has_permission(e, address, permission)
+    }
+
+    /// Get all admin addresses.
+    ///
+    /// # Returns
+    /// A `Vec` of all admin addresses
+    pub fn get_all_admins(e: Env) -> Vec<Address> {
+        storage::admin_list(&e)
+    }
+
+    /// Get a page of admin records in list order.
+    ///
+    /// # Arguments
+    /// * `offset` - Index of the first admin (0-based)
+    /// * `limit` - Maximum number of admins, capped at 50
+    ///
+    /// # Returns
+    /// Up to `limit` `AdminInfo` records starting at `offset`
+    pub fn get_admins_page(e: Env, offset: u32, limit: u32) -> Vec<AdminInfo> {
+        let admin_list = Self::get_all_admins(e.clone());
+        let end = offset
+            .saturating_add(limit.min(MAX_ADMIN_PAGE))
+            .min(admin_list.len());
+        let mut page = Vec::new(&e);
+        for index in offset..end {
+            let address = admin_list.get(index).unwrap();
+            if let Some(admin_info) = storage::admin_info(&e, &address) {
+                page.push_back(admin_info);
+            }
+        }
+        page
+    }
+
+    /// Get a page of active, unexpired admin records in list order.
+    ///
+    /// `offset` counts active admins only, so pages stay contiguous when
+    /// inactive admins are interleaved.
+    ///
+    /// # Arguments
+    /// * `offset` - Number of active admins to skip
+    /// * `limit` - Maximum number of admins, capped at 50
+    ///
+    /// # Returns
+    /// Up to `limit` active `AdminInfo` records
+    pub fn get_active_admins_page(e: Env, offset: u32, limit: u32) -> Vec<AdminInfo> {
+        let limit = limit.min(MAX_ADMIN_PAGE);
+        let mut page = Vec::new(&e);
+        let mut skipped = 0u32;
+        for address in Self::get_all_admins(e.clone()).iter() {
+            if page.len() >= limit {
+                break;
+            }
+            let admin_info = match storage::admin_info(&e, &address) {
+                Some(admin_info) if Self::is_live(&e, &admin_info) => admin_info,
+                _ => continue,
+            };
+            if skipped < offset {
+                skipped += 1;
+                continue;
+            }
+            page.push_back(admin_info);
+        }
+        page
+    }
+
+    /// Get all admins with a specific role.
+    ///
+    /// # Arguments
+    /// * `role` - Role to filter by
+    ///
+    /// # Returns
+    /// A `Vec` of admin addresses with the specified role
+    pub fn get_admins_by_role(e: Env, role: AdminRole) -> Vec<Address> {
+        storage::role_admins(&e, role)
     }
 
     /// Get the total number of admins.
@@ -594,12 +1716,8 @@ impl AdminContract {
         let all_admins = Self::get_all_admins(e.clone());
         let mut active_count = 0;
         for admin in all_admins.iter() {
-            if let Some(admin_info) = e
-                .storage()
-                .instance()
-                .get::<_, AdminInfo>(&DataKey::AdminInfo(admin.clone()))
-            {
-                if admin_info.active {
+            if let Some(admin_info) = storage::admin_info(&e, &admin) {
+                if Self::is_live(&e, &admin_info) {
                     active_count += 1;
                 }
             }
@@ -607,6 +1725,110 @@ impl AdminContract {
         active_count
     }
 
+    /// Get the timestamp of an admin's last successful mutating action.
+    ///
+    /// Falls back to `assigned_at` for admins that have not acted yet.
+    /// Read-only calls never update it.
+    ///
+    /// # Panics
+    /// * If admin_address is not an admin
+    pub fn get_last_active(e: Env, admin_address: Address) -> u64 {
+        let admin_info =
+            storage::admin_info(&e, &admin_address).unwrap_or_else(|| panic!("admin not found"));
+        storage::last_active(&e, &admin_address).unwrap_or(admin_info.assigned_at)
+    }
+
+    /// Get active admins whose last action is older than `older_than_secs`.
+    ///
+    /// # Arguments
+    /// * `older_than_secs` - Age of the last action, in seconds
+    ///
+    /// # Returns
+    /// Active, unexpired admins whose last action (or assignment, if they
+    /// have not acted) predates `now - older_than_secs`
+    pub fn get_stale_admins(e: Env, older_than_secs: u64) -> Vec<Address> {
+        let cutoff = e.ledger().timestamp().saturating_sub(older_than_secs);
+        let mut stale = Vec::new(&e);
+        for address in storage::admin_list(&e).iter() {
+            let admin_info = match storage::admin_info(&e, &address) {
+                Some(admin_info) if Self::is_live(&e, &admin_info) => admin_info,
+                _ => continue,
+            };
+            let last_active = storage::last_active(&e, &address).unwrap_or(admin_info.assigned_at);
+            if last_active < cutoff {
+                stale.push_back(address);
+            }
+        }
+        stale
+    }
+
+    /// Get the number of audit records written so far.
+    pub fn get_audit_count(e: Env) -> u32 {
+        audit::count(&e)
+    }
+
+    /// Get a page of audit records, oldest first.
+    ///
+    /// # Arguments
+    /// * `offset` - Index of the first record (0-based)
+    /// * `limit` - Maximum number of records, capped at 50
+    ///
+    /// # Returns
+    /// Up to `limit` records starting at `offset`
+    pub fn get_audit_records(e: Env, offset: u32, limit: u32) -> Vec<AuditRecord> {
+        audit::page(&e, offset, limit)
+    }
+
+    /// Update the minimum and maximum number of admins.
+    ///
+    /// # Arguments
+    /// * `caller` - Super admin making the change
+    /// * `min_admins` - New minimum number of super admins to keep
+    /// * `max_admins` - New maximum number of admins
+    ///
+    /// # Panics
+    /// * If caller is not a super admin
+    /// * If min_admins is 0 or greater than max_admins
+    /// * If the current admin count exceeds max_admins
+    /// * If the current super admin count is below min_admins
+    ///
+    /// # Events
+    /// Emits `admin_limits_updated` with (min_admins, max_admins)
+    pub fn set_admin_limits(e: Env, caller: Address, min_admins: u32, max_admins: u32) {
+        caller.require_auth();
+        pausable::require_not_paused(&e, PauseScope::Admin);
+        Self::require_super_admin(&e, &caller);
+
+        if min_admins == 0 {
+            panic!("min_admins cannot be zero");
+        }
+        if min_admins > max_admins {
+            panic!("min_admins cannot be greater than max_admins");
+        }
+        if Self::get_admin_count(e.clone()) > max_admins {
+            panic!("admin count exceeds max_admins");
+        }
+        let super_admins = Self::get_admins_by_role(e.clone(), AdminRole::SuperAdmin);
+        if super_admins.len() < min_admins {
+            panic!("super admin count below min_admins");
+        }
+
+        e.storage().instance().set(&DataKey::MinAdmins, &min_admins);
+        e.storage().instance().set(&DataKey::MaxAdmins, &max_admins);
+
+        Self::record_action(
+            &e,
+            "admin_limits_updated",
+            &caller,
+            &e.current_contract_address(),
+            AdminRole::SuperAdmin,
+        );
+        e.events().publish(
+            (Symbol::new(&e, "admin_limits_updated"),),
+            (min_admins, max_admins),
+        );
+    }
+
     /// Get contract configuration.
     ///
     /// # Returns
@@ -623,13 +1845,13 @@ impl AdminContract {
 
     // Helper functions
 
-    /// Get the role of an address (panics if not admin).
+    /// Get the role of an address (panics if not admin or expired).
     pub fn get_role(e: Env, address: Address) -> AdminRole {
-        let admin_info: AdminInfo = e
-            .storage()
-            .instance()
-            .get(&DataKey::AdminInfo(address))
-            .unwrap_or_else(|| panic!("address is not an admin"));
+        let admin_info: AdminInfo =
+            storage::admin_info(&e, &address).unwrap_or_else(|| panic!("address is not an admin"));
+        if Self::is_expired(&e, &admin_info) {
+            panic!("admin role expired");
+        }
         admin_info.role
     }
 
@@ -642,6 +1864,223 @@ impl AdminContract {
         }
     }
 
+    /// Permission needed to manage admins holding a role.
+    fn manage_permission(role: AdminRole) -> Permission {
+        match role {
+            AdminRole::SuperAdmin => Permission::ManageSuperAdmins,
+            AdminRole::Admin => Permission::ManageAdmins,
+            AdminRole::Operator => Permission::ManageOperators,
+        }
+    }
+
+    /// Effective permission mask of an admin, never beyond its role default.
+    fn permission_mask(e: &Env, admin_info: &AdminInfo) -> u32 {
+        let default = Self::get_default_permissions(admin_info.role);
+        storage::permissions(e, &admin_info.address).map_or(default, |mask| mask & default)
+    }
+
+    /// Require that the caller holds a permission.
+    fn require_permission(e: &Env, caller: &Address, permission: Permission) {
+        let admin_info: AdminInfo =
+            storage::admin_info(e, caller).unwrap_or_else(|| panic!("address is not an admin"));
+        if Self::permission_mask(e, &admin_info) & permission as u32 == 0 {
+            panic!("missing permission");
+        }
+    }
+
+    /// Require that the caller is an active admin holding `Pause`.
+    fn require_can_pause(e: &Env, caller: &Address) {
+        Self::get_role(e.clone(), caller.clone());
+        if !Self::has_permission(e.clone(), caller.clone(), Permission::Pause) {
+            panic!("missing permission");
+        }
+    }
+
+    /// Require that the caller is a guardian or may pause as an admin.
+    fn require_guardian_or_can_pause(e: &Env, caller: &Address) {
+        if !storage::is_guardian(e, caller) {
+            Self::require_can_pause(e, caller);
+        }
+    }
+
+    /// Record a pause until `until` (0 = open-ended).
+    fn apply_pause(
+        e: &Env,
+        caller: &Address,
+        until: u64,
+        reason: Symbol,
+        detail: Option<String>,
+    ) -> PauseInfo {
+        let info = pausable::pause(e, caller, until, reason.clone(), detail);
+
+        // Guardians hold no role; record them at the lowest level.
+        let caller_role = if storage::is_guardian(e, caller) {
+            AdminRole::Operator
+        } else {
+            Self::get_role(e.clone(), caller.clone())
+        };
+        Self::record_action(
+            e,
+            "paused",
+            caller,
+            &e.current_contract_address(),
+            caller_role,
+        );
+        e.events()
+            .publish((Symbol::new(e, "paused"),), (caller.clone(), until, reason));
+        info
+    }
+
+    /// Record a timelocked super admin grant for `address`.
+    fn queue_super_admin_grant(e: &Env, caller: &Address, address: &Address) -> PendingGrant {
+        if storage::pending_grant(e, address).is_some() {
+            panic!("grant already pending");
+        }
+        let now = e.ledger().timestamp();
+        let grant = PendingGrant {
+            address: address.clone(),
+            granted_by: caller.clone(),
+            granted_at: now,
+            effective_at: now.saturating_add(Self::get_superadmin_grant_delay(e.clone())),
+        };
+        storage::set_pending_grant(e, &grant);
+
+        Self::record_action(
+            e,
+            "superadmin_grant_pending",
+            caller,
+            address,
+            AdminRole::SuperAdmin,
+        );
+        e.events()
+            .publish((Symbol::new(e, "superadmin_grant_pending"),), grant.clone());
+        grant
+    }
+
+    /// Add an admin once the caller is authenticated; see `add_admin`.
+    fn add_admin_internal(
+        e: &Env,
+        caller: &Address,
+        new_admin: Address,
+        role: AdminRole,
+        expires_at: u64,
+    ) -> AdminInfo {
+        // Verify caller authorization
+        Self::require_role_at_least(e, caller, Self::get_required_role_to_assign(role))
+            .unwrap_or_else(|_| panic!("insufficient privileges"));
+        Self::require_permission(e, caller, Self::manage_permission(role));
+
+        // Check if new admin already exists
+        if storage::admin_info(e, &new_admin).is_some() {
+            panic!("address is already an admin");
+        }
+        if storage::is_guardian(e, &new_admin) {
+            panic!("address is a guardian");
+        }
+
+        // Prevent self-assignment of equal or higher role
+        if *caller == new_admin && Self::get_role(e.clone(), caller.clone()) >= role {
+            panic!("cannot assign equal or higher role to self");
+        }
+
+        Self::validate_expiry(e, role, expires_at);
+
+        // Check admin limit
+        let current_count = Self::get_admin_count(e.clone());
+        let max_admins: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::MaxAdmins)
+            .unwrap_or(100);
+        if current_count >= max_admins {
+            panic!("maximum admin limit reached");
+        }
+
+        if role == AdminRole::SuperAdmin {
+            let grant = Self::queue_super_admin_grant(e, caller, &new_admin);
+            return AdminInfo {
+                address: new_admin,
+                role,
+                assigned_at: grant.effective_at,
+                assigned_by: caller.clone(),
+                active: false,
+                expires_at: 0,
+            };
+        }
+
+        // Create admin info
+        let admin_info = AdminInfo {
+            address: new_admin.clone(),
+            role: role.clone(),
+            assigned_at: e.ledger().timestamp(),
+            assigned_by: caller.clone(),
+            active: true,
+            expires_at,
+        };
+
+        // Store admin info
+        storage::set_admin_info(e, &admin_info);
+
+        // Update admin list
+        let mut admin_list: Vec<Address> = storage::admin_list(e);
+        admin_list.push_back(new_admin.clone());
+        storage::set_admin_list(e, &admin_list);
+
+        // Update role-based admin list
+        let mut role_admins: Vec<Address> = storage::role_admins(e, role);
+        role_admins.push_back(new_admin.clone());
+        storage::set_role_admins(e, role, &role_admins);
+
+        Self::record_action(e, "admin_added", caller, &new_admin, role);
+        e.events()
+            .publish((Symbol::new(e, "admin_added"),), admin_info.clone());
+
+        admin_info
+    }
+
+    /// Write an audit record and stamp the actor's last action.
+    fn record_action(e: &Env, action: &str, actor: &Address, target: &Address, role: AdminRole) {
+        audit::record(e, action, actor, target, role);
+        Self::touch(e, actor);
+    }
+
+    /// Stamp an admin's last successful action; no-op for non-admins.
+    fn touch(e: &Env, address: &Address) {
+        if storage::admin_info(e, address).is_some() {
+            storage::set_last_active(e, address, e.ledger().timestamp());
+        }
+    }
+
+    /// Require that an address is a super admin.
+    fn require_super_admin(e: &Env, address: &Address) {
+        if Self::get_role(e.clone(), address.clone()) != AdminRole::SuperAdmin {
+            panic!("not a super admin");
+        }
+    }
+
+    /// Whether an admin's role has passed its expiry.
+    fn is_expired(e: &Env, admin_info: &AdminInfo) -> bool {
+        admin_info.expires_at != 0 && e.ledger().timestamp() >= admin_info.expires_at
+    }
+
+    /// Whether an admin is active and their role has not expired.
+    fn is_live(e: &Env, admin_info: &AdminInfo) -> bool {
+        admin_info.active && !Self::is_expired(e, admin_info)
+    }
+
+    /// Validate an expiry for the given role (0 = permanent).
+    fn validate_expiry(e: &Env, role: AdminRole, expires_at: u64) {
+        if expires_at == 0 {
+            return;
+        }
+        if role == AdminRole::SuperAdmin {
+            panic!("super admin role cannot expire");
+        }
+        if expires_at <= e.ledger().timestamp() {
+            panic!("expiry must be in the future");
+        }
+    }
+
     /// Require that the caller has at least the specified role.
     fn require_role_at_least(
         e: &Env,
@@ -662,3 +2101,6 @@ mod test;
 
 #[cfg(test)]
 mod test_basic;
+
+#[cfg(test)]
+mod test_cross_contract;
@@ -0,0 +1,195 @@
+//! Pause state for the admin contract.
+//!
+//! A pause is either open-ended (`until == 0`) or lifts automatically once
+//! the ledger timestamp reaches `until`. Each pause of everything records a
+//! [`PauseInfo`] with who paused and why; the most recent one stays readable
+//! after unpausing. Besides pausing everything, individual [`PauseScope`]s can
+//! be paused until explicitly unpaused.
+
+use soroban_sdk::{contracttype, Address, Env, String, Symbol, Vec};
+
+/// Group of operations that can be paused on its own.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PauseScope {
+    /// Every scope
+    All,
+    /// Withdrawals of bonded funds
+    Withdrawals,
+    /// Slashing
+    Slashing,
+    /// Creating and revoking attestations
+    Attestations,
+    /// Admin and configuration changes
+    Admin,
+}
+
+/// Scopes that can be paused individually, in reporting order.
+const SCOPES: [PauseScope; 4] = [
+    PauseScope::Withdrawals,
+    PauseScope::Slashing,
+    PauseScope::Attestations,
+    PauseScope::Admin,
+];
+
+/// Details of a pause of everything.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseInfo {
+    /// Whether this pause is in effect right now
+    pub paused: bool,
+    /// Timestamp when the pause started
+    pub since: u64,
+    /// Timestamp at which the pause lifts (0 = until unpaused)
+    pub until: u64,
+    /// Short reason code
+    pub reason: Symbol,
+    /// Optional longer explanation
+    pub detail: Option<String>,
+    /// Address that paused
+    pub paused_by: Address,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum PauseKey {
+    /// Pause of everything currently recorded
+    Current,
+    /// Most recent pause of everything, kept after unpausing
+    Last,
+    /// Whether a single scope is paused
+    Scope(PauseScope),
+}
+
+/// Whether everything is currently paused.
+pub fn is_paused(e: &Env) -> bool {
+    match e
+        .storage()
+        .instance()
+        .get::<_, PauseInfo>(&PauseKey::Current)
+    {
+        Some(info) => is_live(e, &info),
+        None => false,
+    }
+}
+
+/// Timestamp at which the current pause lifts (0 = until unpaused).
+pub fn paused_until(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get::<_, PauseInfo>(&PauseKey::Current)
+        .map(|info| info.until)
+        .unwrap_or(0)
+}
+
+/// The recorded pause of everything, with `paused` reflecting whether it is
+/// still in effect. `None` once unpaused.
+pub fn pause_info(e: &Env) -> Option<PauseInfo> {
+    with_live_flag(e, e.storage().instance().get(&PauseKey::Current))
+}
+
+/// The most recent pause of everything, kept after unpausing.
+pub fn last_pause_info(e: &Env) -> Option<PauseInfo> {
+    let last = with_live_flag(e, e.storage().instance().get(&PauseKey::Last))?;
+    let current: Option<PauseInfo> = e.storage().instance().get(&PauseKey::Current);
+    Some(PauseInfo {
+        paused: last.paused && current.is_some(),
+        ..last
+    })
+}
+
+fn is_live(e: &Env, info: &PauseInfo) -> bool {
+    info.until == 0 || e.ledger().timestamp() < info.until
+}
+
+fn with_live_flag(e: &Env, info: Option<PauseInfo>) -> Option<PauseInfo> {
+    info.map(|info| PauseInfo {
+        paused: is_live(e, &info),
+        ..info
+    })
+}
+
+/// Whether operations in `scope` are currently paused, either on their own
+/// or because everything is paused.
+pub fn is_scope_paused(e: &Env, scope: PauseScope) -> bool {
+    if is_paused(e) {
+        return true;
+    }
+    scope != PauseScope::All
+        && e.storage()
+            .instance()
+            .get(&PauseKey::Scope(scope))
+            .unwrap_or(false)
+}
+
+/// Panic if operations in `scope` are paused.
+pub fn require_not_paused(e: &Env, scope: PauseScope) {
+    if is_scope_paused(e, scope) {
+        panic!("contract is paused");
+    }
+}
+
+/// Currently paused scopes. `All` is listed first when everything is paused.
+pub fn paused_scopes(e: &Env) -> Vec<PauseScope> {
+    let mut scopes = Vec::new(e);
+    if is_paused(e) {
+        scopes.push_back(PauseScope::All);
+    }
+    for scope in SCOPES {
+        if e.storage()
+            .instance()
+            .get(&PauseKey::Scope(scope))
+            .unwrap_or(false)
+        {
+            scopes.push_back(scope);
+        }
+    }
+    scopes
+}
+
+/// Pause a single scope until it is unpaused. `All` must go through [`pause`].
+pub fn pause_scope(e: &Env, scope: PauseScope) {
+    e.storage().instance().set(&PauseKey::Scope(scope), &true);
+}
+
+/// Lift the pause on a single scope. Returns `false` if it was not paused.
+pub fn unpause_scope(e: &Env, scope: PauseScope) -> bool {
+    let key = PauseKey::Scope(scope);
+    if !e.storage().instance().has(&key) {
+        return false;
+    }
+    e.storage().instance().remove(&key);
+    true
+}
+
+/// Pause everything until `until`, or indefinitely when `until` is 0.
+pub fn pause(
+    e: &Env,
+    paused_by: &Address,
+    until: u64,
+    reason: Symbol,
+    detail: Option<String>,
+) -> PauseInfo {
+    let info = PauseInfo {
+        paused: true,
+        since: e.ledger().timestamp(),
+        until,
+        reason,
+        detail,
+        paused_by: paused_by.clone(),
+    };
+    e.storage().instance().set(&PauseKey::Current, &info);
+    e.storage().instance().set(&PauseKey::Last, &info);
+    info
+}
+
+/// Lift the pause on everything, or clear the record of a lapsed timed
+/// pause. Returns `false` if no pause is recorded. Individually paused scopes
+/// stay paused.
+pub fn unpause(e: &Env) -> bool {
+    if !e.storage().instance().has(&PauseKey::Current) {
+        return false;
+    }
+    e.storage().instance().remove(&PauseKey::Current);
+    true
+}
@@ -0,0 +1,172 @@
+//! Persistent storage for per-admin state.
+//!
+//! Admin records, role lists, permission masks, guardians and the admin list grow with
+//! the admin population, so they live in persistent storage instead of the
+//! contract instance. Entries are TTL-bumped when written and read. Config,
+//! counters and flags stay in instance storage.
+
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{AdminInfo, AdminRole, DataKey, PendingGrant};
+
+/// Extend TTL once it drops below ~1 day (5s ledgers).
+const TTL_THRESHOLD: u32 = 17_280;
+/// Extend TTL to ~30 days.
+const TTL_EXTEND_TO: u32 = 518_400;
+
+fn bump(e: &Env, key: &DataKey) {
+    e.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+fn get<V: soroban_sdk::TryFromVal<Env, soroban_sdk::Val>>(e: &Env, key: &DataKey) -> Option<V> {
+    let value = e.storage().persistent().get(key);
+    if value.is_some() {
+        bump(e, key);
+    }
+    value
+}
+
+fn set<V: soroban_sdk::IntoVal<Env, soroban_sdk::Val>>(e: &Env, key: &DataKey, value: &V) {
+    e.storage().persistent().set(key, value);
+    bump(e, key);
+}
+
+/// Admin record for an address, if any.
+pub fn admin_info(e: &Env, address: &Address) -> Option<AdminInfo> {
+    get(e, &DataKey::AdminInfo(address.clone()))
+}
+
+/// Store an admin record under its address.
+pub fn set_admin_info(e: &Env, admin_info: &AdminInfo) {
+    set(
+        e,
+        &DataKey::AdminInfo(admin_info.address.clone()),
+        admin_info,
+    );
+}
+
+/// Drop an admin record, its custom permission mask and activity stamp.
+pub fn remove_admin_info(e: &Env, address: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::AdminInfo(address.clone()));
+    e.storage()
+        .persistent()
+        .remove(&DataKey::LastActive(address.clone()));
+    remove_permissions(e, address);
+}
+
+/// Timestamp of an admin's last successful mutating action, if any.
+pub fn last_active(e: &Env, address: &Address) -> Option<u64> {
+    get(e, &DataKey::LastActive(address.clone()))
+}
+
+/// Stamp an admin's last successful mutating action.
+pub fn set_last_active(e: &Env, address: &Address, timestamp: u64) {
+    set(e, &DataKey::LastActive(address.clone()), &timestamp);
+}
+
+/// Addresses holding a role.
+pub fn role_admins(e: &Env, role: AdminRole) -> Vec<Address> {
+    get(e, &DataKey::RoleAdmins(role)).unwrap_or(Vec::new(e))
+}
+
+/// Replace the addresses holding a role.
+pub fn set_role_admins(e: &Env, role: AdminRole, admins: &Vec<Address>) {
+    set(e, &DataKey::RoleAdmins(role), admins);
+}
+
+/// Every admin address, in insertion order.
+pub fn admin_list(e: &Env) -> Vec<Address> {
+    get(e, &DataKey::AdminList).unwrap_or(Vec::new(e))
+}
+
+/// Replace the admin list.
+pub fn set_admin_list(e: &Env, admins: &Vec<Address>) {
+    set(e, &DataKey::AdminList, admins);
+}
+
+/// Custom permission mask for an address, if one was set.
+pub fn permissions(e: &Env, address: &Address) -> Option<u32> {
+    get(e, &DataKey::Permissions(address.clone()))
+}
+
+/// Store a custom permission mask.
+pub fn set_permissions(e: &Env, address: &Address, mask: u32) {
+    set(e, &DataKey::Permissions(address.clone()), &mask);
+}
+
+/// Drop a custom permission mask, reverting to the role default.
+pub fn remove_permissions(e: &Env, address: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::Permissions(address.clone()));
+}
+
+/// Whether an address holds the guardian capability.
+pub fn is_guardian(e: &Env, address: &Address) -> bool {
+    get::<bool>(e, &DataKey::Guardian(address.clone())).unwrap_or(false)
+}
+
+/// Grant or revoke the guardian capability.
+pub fn set_guardian(e: &Env, address: &Address, enabled: bool) {
+    let key = DataKey::Guardian(address.clone());
+    if enabled {
+        set(e, &key, &true);
+    } else {
+        e.storage().persistent().remove(&key);
+    }
+}
+
+/// Pending super admin grant for an address, if any.
+pub fn pending_grant(e: &Env, address: &Address) -> Option<PendingGrant> {
+    get(e, &DataKey::PendingGrant(address.clone()))
+}
+
+/// Store a pending super admin grant under its grantee.
+pub fn set_pending_grant(e: &Env, grant: &PendingGrant) {
+    set(e, &DataKey::PendingGrant(grant.address.clone()), grant);
+}
+
+/// Drop a pending super admin grant.
+pub fn remove_pending_grant(e: &Env, address: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::PendingGrant(address.clone()));
+}
+
+/// Move instance-stored admin state written by earlier versions into
+/// persistent storage. Returns the number of admin records moved.
+pub fn migrate_from_instance(e: &Env) -> u32 {
+    let instance = e.storage().instance();
+    let admins: Vec<Address> = instance.get(&DataKey::AdminList).unwrap_or(Vec::new(e));
+    let mut moved = 0u32;
+
+    for address in admins.iter() {
+        let info_key = DataKey::AdminInfo(address.clone());
+        if let Some(admin_info) = instance.get::<_, AdminInfo>(&info_key) {
+            set_admin_info(e, &admin_info);
+            instance.remove(&info_key);
+            moved += 1;
+        }
+        let mask_key = DataKey::Permissions(address.clone());
+        if let Some(mask) = instance.get::<_, u32>(&mask_key) {
+            set_permissions(e, &address, mask);
+            instance.remove(&mask_key);
+        }
+    }
+
+    for role in [AdminRole::SuperAdmin, AdminRole::Admin, AdminRole::Operator] {
+        let key = DataKey::RoleAdmins(role);
+        if let Some(role_list) = instance.get::<_, Vec<Address>>(&key) {
+            set_role_admins(e, role, &role_list);
+            instance.remove(&key);
+        }
+    }
+
+    set_admin_list(e, &admins);
+    instance.remove(&DataKey::AdminList);
+    moved
+}
@@ -1,10 +1,11 @@
 use crate::*;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, Env, String, Symbol, Vec};
 
 #[cfg(test)]
 mod comprehensive_tests {
     use super::*;
-    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::testutils::{Address as _, Events, Ledger};
+    use soroban_sdk::{FromVal, TryFromVal};
 
     fn create_contract() -> AdminContract {
         AdminContract {}
@@ -28,6 +29,30 @@ mod comprehensive_tests {
         setup_with_limits(env, 1, 100)
     }
 
+    /// Grant super admin to `grantee` and activate it once the delay passes.
+    fn grant_super_admin(
+        env: &Env,
+        contract_address: &Address,
+        granter: &Address,
+        grantee: &Address,
+    ) {
+        env.as_contract(contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                granter.clone(),
+                grantee.clone(),
+                AdminRole::SuperAdmin,
+                0,
+            );
+        });
+        let now = env.ledger().timestamp();
+        env.ledger()
+            .with_mut(|li| li.timestamp = now + DEFAULT_SUPERADMIN_GRANT_DELAY);
+        env.as_contract(contract_address, || {
+            AdminContract::activate_pending_grant(env.clone(), grantee.clone());
+        });
+    }
+
     fn setup_multiple_admins(env: &Env) -> (Address, Address, Address, Address) {
         let (contract_address, super_admin) = setup_contract(env);
         let admin = Address::generate(env);
@@ -40,12 +65,14 @@ mod comprehensive_tests {
                 super_admin.clone(),
                 admin.clone(),
                 AdminRole::Admin,
+                0,
             );
             AdminContract::add_admin(
                 env.clone(),
                 admin.clone(),
                 operator.clone(),
                 AdminRole::Operator,
+                0,
             );
         });
 
@@ -140,6 +167,7 @@ mod comprehensive_tests {
                 super_admin.clone(),
                 new_admin.clone(),
                 AdminRole::Admin,
+                0,
             )
         });
 
@@ -161,6 +189,7 @@ mod comprehensive_tests {
                 admin.clone(),
                 new_admin.clone(),
                 AdminRole::Admin,
+                0,
             );
         });
     }
@@ -178,6 +207,7 @@ mod comprehensive_tests {
                 super_admin.clone(),
                 admin.clone(),
                 AdminRole::Admin,
+                0,
             );
         });
     }
@@ -198,6 +228,7 @@ mod comprehensive_tests {
                 super_admin.clone(),
                 admin1.clone(),
                 AdminRole::Admin,
+                0,
             );
         });
 
@@ -208,6 +239,7 @@ mod comprehensive_tests {
                 super_admin.clone(),
                 admin2.clone(),
                 AdminRole::Admin,
+                0,
             );
         });
     }
@@ -225,6 +257,7 @@ mod comprehensive_tests {
                 super_admin.clone(),
                 super_admin.clone(),
                 AdminRole::SuperAdmin,
+                0,
             );
         });
     }
@@ -286,6 +319,7 @@ mod comprehensive_tests {
                 super_admin.clone(),
                 other.clone(),
                 AdminRole::Admin,
+                0,
             );
         });
 
@@ -307,6 +341,7 @@ mod comprehensive_tests {
                 super_admin.clone(),
                 operator.clone(),
                 AdminRole::Admin,
+                0,
             );
         });
 
@@ -331,6 +366,7 @@ mod comprehensive_tests {
                 admin.clone(),
                 operator.clone(),
                 AdminRole::Admin,
+                0,
             );
         });
     }
@@ -349,6 +385,7 @@ mod comprehensive_tests {
                 super_admin.clone(),
                 non_admin.clone(),
                 AdminRole::Admin,
+                0,
             );
         });
     }
@@ -366,6 +403,7 @@ mod comprehensive_tests {
                 super_admin.clone(),
                 super_admin.clone(),
                 AdminRole::SuperAdmin,
+                0,
             );
         });
     }
@@ -387,6 +425,7 @@ mod comprehensive_tests {
                 super_admin.clone(),
                 operator.clone(),
                 AdminRole::Admin,
+                0,
             );
         });
 
@@ -596,4 +635,2573 @@ mod comprehensive_tests {
             AdminContract::get_admin_role(env.clone(), non_admin.clone())
         });
     }
+
+    fn set_time(env: &Env, timestamp: u64) {
+        env.ledger().with_mut(|li| li.timestamp = timestamp);
+    }
+
+    fn incident(env: &Env) -> Symbol {
+        Symbol::new(env, "incident")
+    }
+
+    /// Super admin plus an operator whose role expires at `expires_at`.
+    fn setup_expiring_operator(env: &Env, expires_at: u64) -> (Address, Address, Address) {
+        let (contract_address, super_admin) = setup_contract(env);
+        let operator = Address::generate(env);
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                super_admin.clone(),
+                operator.clone(),
+                AdminRole::Operator,
+                expires_at,
+            );
+        });
+        (contract_address, super_admin, operator)
+    }
+
+    #[test]
+    fn test_expired_role_is_inactive() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, _super_admin, operator) = setup_expiring_operator(&env, 2_000);
+
+        env.as_contract(&contract_address, || {
+            assert!(AdminContract::is_admin(env.clone(), operator.clone()));
+            assert!(AdminContract::has_role_at_least(
+                env.clone(),
+                operator.clone(),
+                AdminRole::Operator
+            ));
+            assert_eq!(AdminContract::get_active_admin_count(env.clone()), 2);
+        });
+
+        // Boundary: the role lapses at expires_at itself.
+        set_time(&env, 2_000);
+        env.as_contract(&contract_address, || {
+            assert!(!AdminContract::is_admin(env.clone(), operator.clone()));
+            assert!(!AdminContract::has_role_at_least(
+                env.clone(),
+                operator.clone(),
+                AdminRole::Operator
+            ));
+            assert_eq!(AdminContract::get_active_admin_count(env.clone()), 1);
+            // The entry remains until purged.
+            assert_eq!(AdminContract::get_admin_count(env.clone()), 2);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "admin role expired")]
+    fn test_get_admin_role_panics_when_expired() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, _super_admin, operator) = setup_expiring_operator(&env, 2_000);
+        set_time(&env, 2_001);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::get_admin_role(env.clone(), operator.clone())
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "admin role expired")]
+    fn test_expired_admin_cannot_assign_roles() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin) = setup_contract(&env);
+        let admin = Address::generate(&env);
+        let operator = Address::generate(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                super_admin.clone(),
+                admin.clone(),
+                AdminRole::Admin,
+                2_000,
+            );
+        });
+        set_time(&env, 2_000);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                admin.clone(),
+                operator.clone(),
+                AdminRole::Operator,
+                0,
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "expiry must be in the future")]
+    fn test_add_admin_rejects_past_expiry() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        setup_expiring_operator(&env, 1_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "super admin role cannot expire")]
+    fn test_add_admin_rejects_expiring_super_admin() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin) = setup_contract(&env);
+        let other = Address::generate(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                super_admin.clone(),
+                other.clone(),
+                AdminRole::SuperAdmin,
+                2_000,
+            );
+        });
+    }
+
+    #[test]
+    fn test_update_admin_role_sets_expiry() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin, _admin, operator) = setup_multiple_admins(&env);
+
+        let info = env.as_contract(&contract_address, || {
+            AdminContract::update_admin_role(
+                env.clone(),
+                super_admin.clone(),
+                operator.clone(),
+                AdminRole::Admin,
+                5_000,
+            )
+        });
+        assert_eq!(info.expires_at, 5_000);
+
+        set_time(&env, 5_000);
+        env.as_contract(&contract_address, || {
+            assert!(!AdminContract::is_admin(env.clone(), operator.clone()));
+        });
+    }
+
+    #[test]
+    fn test_renew_admin_extends_expired_role() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin, operator) = setup_expiring_operator(&env, 2_000);
+        set_time(&env, 3_000);
+
+        let info = env.as_contract(&contract_address, || {
+            AdminContract::renew_admin(env.clone(), super_admin.clone(), operator.clone(), 4_000)
+        });
+        assert_eq!(info.expires_at, 4_000);
+        env.as_contract(&contract_address, || {
+            assert!(AdminContract::is_admin(env.clone(), operator.clone()));
+        });
+
+        // Renewing to 0 makes the role permanent.
+        env.as_contract(&contract_address, || {
+            AdminContract::renew_admin(env.clone(), super_admin.clone(), operator.clone(), 0)
+        });
+        set_time(&env, 1_000_000);
+        env.as_contract(&contract_address, || {
+            assert!(AdminContract::is_admin(env.clone(), operator.clone()));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "insufficient privileges")]
+    fn test_renew_admin_rejects_insufficient_privileges() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin, admin, _operator) = setup_multiple_admins(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::update_admin_role(
+                env.clone(),
+                super_admin.clone(),
+                admin.clone(),
+                AdminRole::Operator,
+                2_000,
+            );
+        });
+        let other_operator = Address::generate(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                super_admin.clone(),
+                other_operator.clone(),
+                AdminRole::Operator,
+                0,
+            );
+            // An operator cannot renew another operator.
+            AdminContract::renew_admin(env.clone(), other_operator.clone(), admin.clone(), 9_000);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot assign equal or higher role to self")]
+    fn test_renew_admin_rejects_self_renewal() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin) = setup_contract(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::renew_admin(env.clone(), super_admin.clone(), super_admin.clone(), 0);
+        });
+    }
+
+    #[test]
+    fn test_purge_expired_admins() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin, admin, operator) = setup_multiple_admins(&env);
+        let temp = Address::generate(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                admin.clone(),
+                temp.clone(),
+                AdminRole::Operator,
+                2_000,
+            );
+        });
+
+        // Nothing has expired yet.
+        let purged = env.as_contract(&contract_address, || {
+            AdminContract::purge_expired_admins(env.clone())
+        });
+        assert_eq!(purged, 0);
+
+        set_time(&env, 2_000);
+        let purged = env.as_contract(&contract_address, || {
+            AdminContract::purge_expired_admins(env.clone())
+        });
+        assert_eq!(purged, 1);
+
+        env.as_contract(&contract_address, || {
+            let all = AdminContract::get_all_admins(env.clone());
+            assert_eq!(all.len(), 3);
+            assert!(all.contains(&super_admin));
+            assert!(all.contains(&admin));
+            assert!(all.contains(&operator));
+            assert!(!all.contains(&temp));
+            let operators = AdminContract::get_admins_by_role(env.clone(), AdminRole::Operator);
+            assert_eq!(operators.len(), 1);
+            assert!(!operators.contains(&temp));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "admin not found")]
+    fn test_purged_admin_info_is_removed() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, _super_admin, operator) = setup_expiring_operator(&env, 2_000);
+        set_time(&env, 2_000);
+        env.as_contract(&contract_address, || {
+            AdminContract::purge_expired_admins(env.clone());
+            AdminContract::get_admin_info(env.clone(), operator.clone());
+        });
+    }
+
+    #[test]
+    fn test_super_admin_transfer_accept() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_contract(&env);
+        let successor = Address::generate(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::propose_super_admin_transfer(
+                env.clone(),
+                super_admin.clone(),
+                successor.clone(),
+            );
+        });
+        // Nothing moves until the successor accepts.
+        env.as_contract(&contract_address, || {
+            assert!(AdminContract::is_admin(env.clone(), super_admin.clone()));
+            assert!(!AdminContract::is_admin(env.clone(), successor.clone()));
+            let pending = AdminContract::get_pending_super_admin_transfer(env.clone()).unwrap();
+            assert_eq!(pending.from, super_admin);
+            assert_eq!(pending.to, successor);
+        });
+
+        let info = env.as_contract(&contract_address, || {
+            AdminContract::accept_super_admin_transfer(env.clone(), successor.clone())
+        });
+        assert_eq!(info.role, AdminRole::SuperAdmin);
+        assert_eq!(info.assigned_by, super_admin);
+
+        env.as_contract(&contract_address, || {
+            assert!(!AdminContract::is_admin(env.clone(), super_admin.clone()));
+            assert_eq!(
+                AdminContract::get_admin_role(env.clone(), successor.clone()),
+                AdminRole::SuperAdmin
+            );
+            let super_admins =
+                AdminContract::get_admins_by_role(env.clone(), AdminRole::SuperAdmin);
+            assert_eq!(super_admins.len(), 1);
+            assert_eq!(super_admins.get(0).unwrap(), successor);
+            assert_eq!(AdminContract::get_admin_count(env.clone()), 1);
+            assert!(AdminContract::get_pending_super_admin_transfer(env.clone()).is_none());
+        });
+    }
+
+    #[test]
+    fn test_super_admin_transfer_to_existing_admin() {
+        let env = Env::default();
+        let (contract_address, super_admin, admin, operator) = setup_multiple_admins(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::propose_super_admin_transfer(
+                env.clone(),
+                super_admin.clone(),
+                admin.clone(),
+            );
+            AdminContract::accept_super_admin_transfer(env.clone(), admin.clone());
+        });
+
+        env.as_contract(&contract_address, || {
+            assert!(AdminContract::get_admins_by_role(env.clone(), AdminRole::Admin).is_empty());
+            assert_eq!(
+                AdminContract::get_admins_by_role(env.clone(), AdminRole::SuperAdmin),
+                Vec::from_array(&env, [admin.clone()])
+            );
+            let all = AdminContract::get_all_admins(env.clone());
+            assert_eq!(all.len(), 2);
+            assert!(all.contains(&admin));
+            assert!(all.contains(&operator));
+        });
+    }
+
+    #[test]
+    fn test_super_admin_transfer_cancel() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_contract(&env);
+        let successor = Address::generate(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::propose_super_admin_transfer(
+                env.clone(),
+                super_admin.clone(),
+                successor.clone(),
+            );
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::cancel_super_admin_transfer(env.clone(), super_admin.clone());
+            assert!(AdminContract::get_pending_super_admin_transfer(env.clone()).is_none());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "no pending super admin transfer")]
+    fn test_super_admin_transfer_accept_after_cancel() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_contract(&env);
+        let successor = Address::generate(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::propose_super_admin_transfer(
+                env.clone(),
+                super_admin.clone(),
+                successor.clone(),
+            );
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::cancel_super_admin_transfer(env.clone(), super_admin.clone());
+            AdminContract::accept_super_admin_transfer(env.clone(), successor.clone());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "not the proposed super admin")]
+    fn test_super_admin_transfer_overwrites_stale_proposal() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_contract(&env);
+        let stale = Address::generate(&env);
+        let successor = Address::generate(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::propose_super_admin_transfer(
+                env.clone(),
+                super_admin.clone(),
+                stale.clone(),
+            );
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::propose_super_admin_transfer(
+                env.clone(),
+                super_admin.clone(),
+                successor.clone(),
+            );
+            assert_eq!(
+                AdminContract::get_pending_super_admin_transfer(env.clone())
+                    .unwrap()
+                    .to,
+                successor
+            );
+            AdminContract::accept_super_admin_transfer(env.clone(), stale.clone());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "not a super admin")]
+    fn test_super_admin_transfer_requires_super_admin() {
+        let env = Env::default();
+        let (contract_address, _super_admin, admin, _operator) = setup_multiple_admins(&env);
+        let successor = Address::generate(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::propose_super_admin_transfer(
+                env.clone(),
+                admin.clone(),
+                successor.clone(),
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "not a super admin")]
+    fn test_super_admin_transfer_cancel_requires_super_admin() {
+        let env = Env::default();
+        let (contract_address, super_admin, admin, _operator) = setup_multiple_admins(&env);
+        let successor = Address::generate(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::propose_super_admin_transfer(
+                env.clone(),
+                super_admin.clone(),
+                successor.clone(),
+            );
+            AdminContract::cancel_super_admin_transfer(env.clone(), admin.clone());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "address is already a super admin")]
+    fn test_super_admin_transfer_rejects_existing_super_admin() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_with_limits(&env, 1, 100);
+        let other = Address::generate(&env);
+
+        grant_super_admin(&env, &contract_address, &super_admin, &other);
+        env.as_contract(&contract_address, || {
+            AdminContract::propose_super_admin_transfer(
+                env.clone(),
+                super_admin.clone(),
+                other.clone(),
+            );
+        });
+    }
+
+    #[test]
+    fn test_super_admin_transfer_keeps_min_admins() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_with_limits(&env, 2, 100);
+        let second = Address::generate(&env);
+        let successor = Address::generate(&env);
+
+        grant_super_admin(&env, &contract_address, &super_admin, &second);
+        env.as_contract(&contract_address, || {
+            AdminContract::propose_super_admin_transfer(
+                env.clone(),
+                super_admin.clone(),
+                successor.clone(),
+            );
+            AdminContract::accept_super_admin_transfer(env.clone(), successor.clone());
+            let super_admins =
+                AdminContract::get_admins_by_role(env.clone(), AdminRole::SuperAdmin);
+            assert_eq!(super_admins.len(), 2);
+            assert!(super_admins.contains(&second));
+            assert!(super_admins.contains(&successor));
+        });
+    }
+
+    #[test]
+    fn test_audit_log_records_admin_actions() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin, admin, operator) = setup_multiple_admins(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::update_admin_role(
+                env.clone(),
+                super_admin.clone(),
+                operator.clone(),
+                AdminRole::Admin,
+                0,
+            );
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::deactivate_admin(env.clone(), super_admin.clone(), admin.clone());
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::reactivate_admin(env.clone(), super_admin.clone(), admin.clone());
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::remove_admin(env.clone(), super_admin.clone(), admin.clone());
+        });
+
+        let records = env.as_contract(&contract_address, || {
+            assert_eq!(AdminContract::get_audit_count(env.clone()), 6);
+            AdminContract::get_audit_records(env.clone(), 0, 10)
+        });
+        assert_eq!(records.len(), 6);
+
+        let expected = [
+            ("admin_added", &super_admin, &admin, AdminRole::Admin),
+            ("admin_added", &admin, &operator, AdminRole::Operator),
+            (
+                "admin_role_updated",
+                &super_admin,
+                &operator,
+                AdminRole::Admin,
+            ),
+            ("admin_deactivated", &super_admin, &admin, AdminRole::Admin),
+            ("admin_reactivated", &super_admin, &admin, AdminRole::Admin),
+            ("admin_removed", &super_admin, &admin, AdminRole::Admin),
+        ];
+        for (i, (action, actor, target, role)) in expected.iter().enumerate() {
+            let record = records.get(i as u32).unwrap();
+            assert_eq!(record.action, Symbol::new(&env, action));
+            assert_eq!(&record.actor, *actor);
+            assert_eq!(&record.target, *target);
+            assert_eq!(record.role, *role);
+            assert_eq!(record.timestamp, 1_000);
+        }
+    }
+
+    #[test]
+    fn test_audit_log_pagination() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_contract(&env);
+
+        for _ in 0..60 {
+            let operator = Address::generate(&env);
+            env.as_contract(&contract_address, || {
+                AdminContract::add_admin(
+                    env.clone(),
+                    super_admin.clone(),
+                    operator.clone(),
+                    AdminRole::Operator,
+                    0,
+                );
+            });
+        }
+
+        env.as_contract(&contract_address, || {
+            assert_eq!(AdminContract::get_audit_count(env.clone()), 60);
+            // Limit is capped at 50.
+            assert_eq!(
+                AdminContract::get_audit_records(env.clone(), 0, 100).len(),
+                50
+            );
+            // The last page is truncated at the end of the log.
+            let tail = AdminContract::get_audit_records(env.clone(), 55, 10);
+            assert_eq!(tail.len(), 5);
+            let all = AdminContract::get_all_admins(env.clone());
+            assert_eq!(tail.get(4).unwrap().target, all.get(60).unwrap());
+            assert!(AdminContract::get_audit_records(env.clone(), 60, 10).is_empty());
+            assert!(AdminContract::get_audit_records(env.clone(), u32::MAX, 10).is_empty());
+        });
+    }
+
+    #[test]
+    fn test_audit_log_empty_after_initialize() {
+        let env = Env::default();
+        let (contract_address, _super_admin) = setup_contract(&env);
+        env.as_contract(&contract_address, || {
+            assert_eq!(AdminContract::get_audit_count(env.clone()), 0);
+            assert!(AdminContract::get_audit_records(env.clone(), 0, 10).is_empty());
+        });
+    }
+
+    /// Super admin plus `count` operators; returns the operators in order.
+    fn setup_operators(env: &Env, count: u32) -> (Address, Address, Vec<Address>) {
+        // Calls made via as_contract share one budget; large populations exceed it.
+        env.budget().reset_unlimited();
+        let (contract_address, super_admin) = setup_contract(env);
+        let mut operators = Vec::new(env);
+        for _ in 0..count {
+            let operator = Address::generate(env);
+            env.as_contract(&contract_address, || {
+                AdminContract::add_admin(
+                    env.clone(),
+                    super_admin.clone(),
+                    operator.clone(),
+                    AdminRole::Operator,
+                    0,
+                );
+            });
+            operators.push_back(operator);
+        }
+        (contract_address, super_admin, operators)
+    }
+
+    #[test]
+    fn test_get_admins_page() {
+        let env = Env::default();
+        let (contract_address, super_admin, operators) = setup_operators(&env, 60);
+
+        env.as_contract(&contract_address, || {
+            let first = AdminContract::get_admins_page(env.clone(), 0, 10);
+            assert_eq!(first.len(), 10);
+            assert_eq!(first.get(0).unwrap().address, super_admin);
+            assert_eq!(first.get(0).unwrap().role, AdminRole::SuperAdmin);
+            assert_eq!(first.get(1).unwrap().address, operators.get(0).unwrap());
+
+            // Limit is capped at 50.
+            assert_eq!(
+                AdminContract::get_admins_page(env.clone(), 0, 100).len(),
+                50
+            );
+
+            // Walking the pages covers every admin exactly once, in order.
+            let all = AdminContract::get_all_admins(env.clone());
+            let mut offset = 0;
+            loop {
+                let page = AdminContract::get_admins_page(env.clone(), offset, 25);
+                if page.is_empty() {
+                    break;
+                }
+                for (i, info) in page.iter().enumerate() {
+                    assert_eq!(info.address, all.get(offset + i as u32).unwrap());
+                }
+                offset += page.len();
+            }
+            assert_eq!(offset, 61);
+            assert!(AdminContract::get_admins_page(env.clone(), u32::MAX, 10).is_empty());
+        });
+    }
+
+    #[test]
+    fn test_get_active_admins_page_skips_inactive() {
+        let env = Env::default();
+        let (contract_address, super_admin, operators) = setup_operators(&env, 60);
+
+        // Deactivate every even-indexed operator.
+        for i in (0..60).step_by(2) {
+            env.as_contract(&contract_address, || {
+                AdminContract::deactivate_admin(
+                    env.clone(),
+                    super_admin.clone(),
+                    operators.get(i).unwrap(),
+                );
+            });
+        }
+
+        env.as_contract(&contract_address, || {
+            assert_eq!(AdminContract::get_active_admin_count(env.clone()), 31);
+
+            let first = AdminContract::get_active_admins_page(env.clone(), 0, 50);
+            assert_eq!(first.len(), 31);
+            assert!(first.iter().all(|info| info.active));
+            assert_eq!(first.get(1).unwrap().address, operators.get(1).unwrap());
+
+            let second = AdminContract::get_active_admins_page(env.clone(), 30, 50);
+            assert_eq!(second.len(), 1);
+            assert_eq!(second.get(0).unwrap().address, operators.get(59).unwrap());
+            assert!(AdminContract::get_active_admins_page(env.clone(), 31, 50).is_empty());
+        });
+    }
+
+    #[test]
+    fn test_get_active_admins_page_caps_limit() {
+        let env = Env::default();
+        let (contract_address, _super_admin, _operators) = setup_operators(&env, 60);
+        env.as_contract(&contract_address, || {
+            assert_eq!(
+                AdminContract::get_active_admins_page(env.clone(), 0, 100).len(),
+                50
+            );
+            assert_eq!(
+                AdminContract::get_active_admins_page(env.clone(), 50, 100).len(),
+                11
+            );
+        });
+    }
+
+    #[test]
+    fn test_default_permissions() {
+        let env = Env::default();
+        let (contract_address, super_admin, admin, operator) = setup_multiple_admins(&env);
+
+        env.as_contract(&contract_address, || {
+            assert_eq!(
+                AdminContract::get_permissions(env.clone(), super_admin.clone()),
+                ALL_PERMISSIONS
+            );
+            for permission in [
+                Permission::ManageOperators,
+                Permission::ManageAdmins,
+                Permission::ManageSuperAdmins,
+                Permission::Pause,
+            ] {
+                assert!(AdminContract::has_permission(
+                    env.clone(),
+                    super_admin.clone(),
+                    permission
+                ));
+            }
+
+            assert!(AdminContract::has_permission(
+                env.clone(),
+                admin.clone(),
+                Permission::ManageOperators
+            ));
+            assert!(AdminContract::has_permission(
+                env.clone(),
+                admin.clone(),
+                Permission::Pause
+            ));
+            assert!(!AdminContract::has_permission(
+                env.clone(),
+                admin.clone(),
+                Permission::ManageAdmins
+            ));
+
+            assert!(AdminContract::has_permission(
+                env.clone(),
+                operator.clone(),
+                Permission::Pause
+            ));
+            assert!(!AdminContract::has_permission(
+                env.clone(),
+                operator.clone(),
+                Permission::ManageOperators
+            ));
+        });
+    }
+
+    #[test]
+    fn test_set_permissions_restricts_admin() {
+        let env = Env::default();
+        let (contract_address, super_admin, admin, _operator) = setup_multiple_admins(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::set_permissions(
+                env.clone(),
+                super_admin.clone(),
+                admin.clone(),
+                Permission::ManageOperators as u32,
+            );
+            assert_eq!(
+                AdminContract::get_permissions(env.clone(), admin.clone()),
+                Permission::ManageOperators as u32
+            );
+            assert!(!AdminContract::has_permission(
+                env.clone(),
+                admin.clone(),
+                Permission::Pause
+            ));
+            assert!(AdminContract::has_permission(
+                env.clone(),
+                admin.clone(),
+                Permission::ManageOperators
+            ));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "missing permission")]
+    fn test_admin_without_manage_operators_cannot_add_operator() {
+        let env = Env::default();
+        let (contract_address, super_admin, admin, _operator) = setup_multiple_admins(&env);
+        let new_operator = Address::generate(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::set_permissions(
+                env.clone(),
+                super_admin.clone(),
+                admin.clone(),
+                Permission::Pause as u32,
+            );
+            AdminContract::add_admin(
+                env.clone(),
+                admin.clone(),
+                new_operator.clone(),
+                AdminRole::Operator,
+                0,
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "missing permission")]
+    fn test_admin_without_manage_operators_cannot_deactivate_operator() {
+        let env = Env::default();
+        let (contract_address, super_admin, admin, operator) = setup_multiple_admins(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::set_permissions(env.clone(), super_admin.clone(), admin.clone(), 0);
+            AdminContract::deactivate_admin(env.clone(), admin.clone(), operator.clone());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "missing permission")]
+    fn test_set_permissions_requires_manage_permission() {
+        let env = Env::default();
+        let (contract_address, super_admin, admin, operator) = setup_multiple_admins(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::set_permissions(
+                env.clone(),
+                super_admin.clone(),
+                admin.clone(),
+                Permission::Pause as u32,
+            );
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::set_permissions(env.clone(), admin.clone(), operator.clone(), 0);
+        });
+    }
+
+    #[test]
+    fn test_set_permissions_clamped_to_role() {
+        let env = Env::default();
+        let (contract_address, super_admin, admin, _operator) = setup_multiple_admins(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::set_permissions(
+                env.clone(),
+                super_admin.clone(),
+                admin.clone(),
+                ALL_PERMISSIONS,
+            );
+            assert_eq!(
+                AdminContract::get_permissions(env.clone(), admin.clone()),
+                AdminContract::get_default_permissions(AdminRole::Admin)
+            );
+            assert!(!AdminContract::has_permission(
+                env.clone(),
+                admin.clone(),
+                Permission::ManageAdmins
+            ));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "insufficient privileges to set permissions")]
+    fn test_set_permissions_requires_higher_role() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_contract(&env);
+        let admin = Address::generate(&env);
+        let peer = Address::generate(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                super_admin.clone(),
+                admin.clone(),
+                AdminRole::Admin,
+                0,
+            );
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                super_admin.clone(),
+                peer.clone(),
+                AdminRole::Admin,
+                0,
+            );
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::set_permissions(env.clone(), admin.clone(), peer.clone(), 0);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid permission mask")]
+    fn test_set_permissions_rejects_unknown_bits() {
+        let env = Env::default();
+        let (contract_address, super_admin, admin, _operator) = setup_multiple_admins(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::set_permissions(
+                env.clone(),
+                super_admin.clone(),
+                admin.clone(),
+                ALL_PERMISSIONS + 1,
+            );
+        });
+    }
+
+    #[test]
+    fn test_role_change_resets_permissions() {
+        let env = Env::default();
+        let (contract_address, super_admin, _admin, operator) = setup_multiple_admins(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::set_permissions(env.clone(), super_admin.clone(), operator.clone(), 0);
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::update_admin_role(
+                env.clone(),
+                super_admin.clone(),
+                operator.clone(),
+                AdminRole::Admin,
+                0,
+            );
+            assert_eq!(
+                AdminContract::get_permissions(env.clone(), operator.clone()),
+                AdminContract::get_default_permissions(AdminRole::Admin)
+            );
+        });
+    }
+
+    #[test]
+    fn test_has_permission_false_when_inactive_or_expired() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin, operator) = setup_expiring_operator(&env, 2_000);
+        let stranger = Address::generate(&env);
+
+        env.as_contract(&contract_address, || {
+            assert!(!AdminContract::has_permission(
+                env.clone(),
+                stranger.clone(),
+                Permission::Pause
+            ));
+            AdminContract::deactivate_admin(env.clone(), super_admin.clone(), operator.clone());
+            assert!(!AdminContract::has_permission(
+                env.clone(),
+                operator.clone(),
+                Permission::Pause
+            ));
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::reactivate_admin(env.clone(), super_admin.clone(), operator.clone());
+            assert!(AdminContract::has_permission(
+                env.clone(),
+                operator.clone(),
+                Permission::Pause
+            ));
+        });
+        set_time(&env, 2_000);
+        env.as_contract(&contract_address, || {
+            assert!(!AdminContract::has_permission(
+                env.clone(),
+                operator.clone(),
+                Permission::Pause
+            ));
+        });
+    }
+
+    #[test]
+    fn test_pause_and_unpause() {
+        let env = Env::default();
+        let (contract_address, _super_admin, admin, operator) = setup_multiple_admins(&env);
+
+        env.as_contract(&contract_address, || {
+            assert!(!AdminContract::is_paused(env.clone()));
+            AdminContract::pause(env.clone(), operator.clone(), incident(&env), None);
+            assert!(AdminContract::is_paused(env.clone()));
+            assert_eq!(AdminContract::get_paused_until(env.clone()), 0);
+        });
+        // An open-ended pause never lifts on its own.
+        set_time(&env, 10_000_000);
+        env.as_contract(&contract_address, || {
+            assert!(AdminContract::is_paused(env.clone()));
+            AdminContract::unpause(env.clone(), admin.clone());
+            assert!(!AdminContract::is_paused(env.clone()));
+        });
+
+        let records = env.as_contract(&contract_address, || {
+            AdminContract::get_audit_records(env.clone(), 2, 10)
+        });
+        assert_eq!(records.len(), 2);
+        assert_eq!(records.get(0).unwrap().action, Symbol::new(&env, "paused"));
+        assert_eq!(records.get(0).unwrap().actor, operator);
+        assert_eq!(
+            records.get(1).unwrap().action,
+            Symbol::new(&env, "unpaused")
+        );
+        assert_eq!(records.get(1).unwrap().actor, admin);
+    }
+
+    #[test]
+    fn test_pause_for_expires_at_boundary() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, _super_admin, _admin, operator) = setup_multiple_admins(&env);
+
+        let info = env.as_contract(&contract_address, || {
+            AdminContract::pause_for(env.clone(), operator.clone(), 3_600, incident(&env), None)
+        });
+        assert_eq!(info.until, 4_600);
+
+        set_time(&env, 4_599);
+        env.as_contract(&contract_address, || {
+            assert!(AdminContract::is_paused(env.clone()));
+            assert_eq!(AdminContract::get_paused_until(env.clone()), 4_600);
+        });
+
+        set_time(&env, 4_600);
+        env.as_contract(&contract_address, || {
+            assert!(!AdminContract::is_paused(env.clone()));
+            assert_eq!(AdminContract::get_paused_until(env.clone()), 0);
+        });
+    }
+
+    #[test]
+    fn test_unpause_clears_timed_pause_early() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, _super_admin, admin, operator) = setup_multiple_admins(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::pause_for(env.clone(), operator.clone(), 3_600, incident(&env), None);
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::unpause(env.clone(), admin.clone());
+            assert!(!AdminContract::is_paused(env.clone()));
+        });
+        // A later untimed pause is still possible.
+        env.as_contract(&contract_address, || {
+            AdminContract::pause(env.clone(), operator.clone(), incident(&env), None);
+        });
+        set_time(&env, 10_000);
+        env.as_contract(&contract_address, || {
+            assert!(AdminContract::is_paused(env.clone()));
+        });
+    }
+
+    #[test]
+    fn test_pause_event_includes_expiry() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, _super_admin, _admin, operator) = setup_multiple_admins(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::pause_for(env.clone(), operator.clone(), 60, incident(&env), None);
+        });
+        let events = env.events().all();
+        let (_, topics, data) = events.last().unwrap();
+        assert_eq!(
+            Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap(),
+            Symbol::new(&env, "paused")
+        );
+        let (who, until, reason): (Address, u64, Symbol) = FromVal::from_val(&env, &data);
+        assert_eq!(who, operator);
+        assert_eq!(until, 1_060);
+        assert_eq!(reason, incident(&env));
+    }
+
+    #[test]
+    #[should_panic(expected = "duration must be positive")]
+    fn test_pause_for_rejects_zero_duration() {
+        let env = Env::default();
+        let (contract_address, _super_admin, _admin, operator) = setup_multiple_admins(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::pause_for(env.clone(), operator.clone(), 0, incident(&env), None);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "missing permission")]
+    fn test_pause_requires_pause_permission() {
+        let env = Env::default();
+        let (contract_address, super_admin, _admin, operator) = setup_multiple_admins(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::set_permissions(env.clone(), super_admin.clone(), operator.clone(), 0);
+            AdminContract::pause(env.clone(), operator.clone(), incident(&env), None);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "address is not an admin")]
+    fn test_pause_rejects_non_admin() {
+        let env = Env::default();
+        let (contract_address, _super_admin) = setup_contract(&env);
+        let stranger = Address::generate(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::pause(env.clone(), stranger.clone(), incident(&env), None);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "insufficient privileges")]
+    fn test_operator_cannot_unpause() {
+        let env = Env::default();
+        let (contract_address, _super_admin, _admin, operator) = setup_multiple_admins(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::pause(env.clone(), operator.clone(), incident(&env), None);
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::unpause(env.clone(), operator.clone());
+        });
+    }
+
+    #[test]
+    fn test_unpause_after_expiry_clears_record() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, _super_admin, admin, operator) = setup_multiple_admins(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::pause_for(env.clone(), operator.clone(), 10, incident(&env), None);
+        });
+        set_time(&env, 1_010);
+        env.as_contract(&contract_address, || {
+            assert!(!AdminContract::get_pause_info(env.clone()).unwrap().paused);
+            AdminContract::unpause(env.clone(), admin.clone());
+            assert!(AdminContract::get_pause_info(env.clone()).is_none());
+            assert_eq!(
+                AdminContract::get_last_pause_info(env.clone())
+                    .unwrap()
+                    .until,
+                1_010
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "not paused")]
+    fn test_unpause_without_pause_rejected() {
+        let env = Env::default();
+        let (contract_address, _super_admin, admin, _operator) = setup_multiple_admins(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::unpause(env.clone(), admin.clone());
+        });
+    }
+
+    #[test]
+    fn test_pause_withdrawals_scope_only() {
+        let env = Env::default();
+        let (contract_address, super_admin, admin, operator) = setup_multiple_admins(&env);
+        let new_operator = Address::generate(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::pause_scope(env.clone(), operator.clone(), PauseScope::Withdrawals);
+            assert!(!AdminContract::is_paused(env.clone()));
+            assert!(AdminContract::is_scope_paused(
+                env.clone(),
+                PauseScope::Withdrawals
+            ));
+            assert!(!AdminContract::is_scope_paused(
+                env.clone(),
+                PauseScope::Attestations
+            ));
+            assert_eq!(
+                AdminContract::get_paused_scopes(env.clone()),
+                Vec::from_array(&env, [PauseScope::Withdrawals])
+            );
+        });
+        // Admin operations are unaffected.
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                admin.clone(),
+                new_operator.clone(),
+                AdminRole::Operator,
+                0,
+            );
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::unpause_scope(env.clone(), super_admin.clone(), PauseScope::Withdrawals);
+            assert!(AdminContract::get_paused_scopes(env.clone()).is_empty());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "contract is paused")]
+    fn test_admin_scope_pause_blocks_admin_changes() {
+        let env = Env::default();
+        let (contract_address, _super_admin, admin, operator) = setup_multiple_admins(&env);
+        let new_operator = Address::generate(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::pause_scope(env.clone(), operator.clone(), PauseScope::Admin);
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                admin.clone(),
+                new_operator.clone(),
+                AdminRole::Operator,
+                0,
+            );
+        });
+    }
+
+    #[test]
+    fn test_pause_all_covers_every_scope() {
+        let env = Env::default();
+        let (contract_address, super_admin, _admin, operator) = setup_multiple_admins(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::pause_scope(env.clone(), operator.clone(), PauseScope::Slashing);
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::pause_scope(env.clone(), operator.clone(), PauseScope::All);
+            assert!(AdminContract::is_paused(env.clone()));
+            assert!(AdminContract::is_scope_paused(
+                env.clone(),
+                PauseScope::Attestations
+            ));
+            assert_eq!(
+                AdminContract::get_paused_scopes(env.clone()),
+                Vec::from_array(&env, [PauseScope::All, PauseScope::Slashing])
+            );
+        });
+        // Lifting All leaves the individually paused scope in place.
+        env.as_contract(&contract_address, || {
+            AdminContract::unpause_scope(env.clone(), super_admin.clone(), PauseScope::All);
+            assert!(!AdminContract::is_paused(env.clone()));
+            assert_eq!(
+                AdminContract::get_paused_scopes(env.clone()),
+                Vec::from_array(&env, [PauseScope::Slashing])
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "not paused")]
+    fn test_unpause_scope_rejects_unpaused_scope() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_contract(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::unpause_scope(env.clone(), super_admin.clone(), PauseScope::Slashing);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "insufficient privileges")]
+    fn test_operator_cannot_unpause_scope() {
+        let env = Env::default();
+        let (contract_address, _super_admin, _admin, operator) = setup_multiple_admins(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::pause_scope(env.clone(), operator.clone(), PauseScope::Slashing);
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::unpause_scope(env.clone(), operator.clone(), PauseScope::Slashing);
+        });
+    }
+
+    #[test]
+    fn test_pause_info_records_reason() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, _super_admin, admin, operator) = setup_multiple_admins(&env);
+        let detail = String::from_str(&env, "oracle feed stalled");
+
+        env.as_contract(&contract_address, || {
+            assert!(AdminContract::get_pause_info(env.clone()).is_none());
+            assert!(AdminContract::get_last_pause_info(env.clone()).is_none());
+            AdminContract::pause_for(
+                env.clone(),
+                operator.clone(),
+                600,
+                incident(&env),
+                Some(detail.clone()),
+            );
+        });
+
+        let info = env.as_contract(&contract_address, || {
+            AdminContract::get_pause_info(env.clone()).unwrap()
+        });
+        assert!(info.paused);
+        assert_eq!(info.since, 1_000);
+        assert_eq!(info.until, 1_600);
+        assert_eq!(info.reason, incident(&env));
+        assert_eq!(info.detail, Some(detail));
+        assert_eq!(info.paused_by, operator);
+
+        // Unpausing clears the current info but keeps the last record.
+        set_time(&env, 1_200);
+        env.as_contract(&contract_address, || {
+            AdminContract::unpause(env.clone(), admin.clone());
+            assert!(AdminContract::get_pause_info(env.clone()).is_none());
+            let last = AdminContract::get_last_pause_info(env.clone()).unwrap();
+            assert!(!last.paused);
+            assert_eq!(last.reason, incident(&env));
+            assert_eq!(last.paused_by, operator);
+        });
+    }
+
+    #[test]
+    fn test_pause_info_after_timed_pause_lapses() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, _super_admin, _admin, operator) = setup_multiple_admins(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::pause_for(env.clone(), operator.clone(), 600, incident(&env), None);
+        });
+        set_time(&env, 1_600);
+        env.as_contract(&contract_address, || {
+            let info = AdminContract::get_pause_info(env.clone()).unwrap();
+            assert!(!info.paused);
+            assert_eq!(info.until, 1_600);
+            assert!(
+                !AdminContract::get_last_pause_info(env.clone())
+                    .unwrap()
+                    .paused
+            );
+        });
+    }
+
+    #[test]
+    fn test_last_pause_info_tracks_latest_pause() {
+        let env = Env::default();
+        let (contract_address, super_admin, admin, operator) = setup_multiple_admins(&env);
+        let maintenance = Symbol::new(&env, "maintenance");
+
+        env.as_contract(&contract_address, || {
+            AdminContract::pause(env.clone(), operator.clone(), incident(&env), None);
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::unpause(env.clone(), admin.clone());
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::pause(env.clone(), super_admin.clone(), maintenance.clone(), None);
+            let last = AdminContract::get_last_pause_info(env.clone()).unwrap();
+            assert!(last.paused);
+            assert_eq!(last.reason, maintenance);
+            assert_eq!(last.paused_by, super_admin);
+            assert_eq!(AdminContract::get_pause_info(env.clone()).unwrap(), last);
+        });
+    }
+
+    #[test]
+    fn test_set_admin_limits() {
+        let env = Env::default();
+        let (contract_address, super_admin, _admin, _operator) = setup_multiple_admins(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::set_admin_limits(env.clone(), super_admin.clone(), 1, 3);
+            assert_eq!(AdminContract::get_config(env.clone()), (1, 3));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "maximum admin limit reached")]
+    fn test_set_admin_limits_caps_additions() {
+        let env = Env::default();
+        let (contract_address, super_admin, admin, _operator) = setup_multiple_admins(&env);
+        let extra = Address::generate(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::set_admin_limits(env.clone(), super_admin.clone(), 1, 3);
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                admin.clone(),
+                extra.clone(),
+                AdminRole::Operator,
+                0,
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "admin count exceeds max_admins")]
+    fn test_set_admin_limits_rejects_max_below_count() {
+        let env = Env::default();
+        let (contract_address, super_admin, _admin, _operator) = setup_multiple_admins(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::set_admin_limits(env.clone(), super_admin.clone(), 1, 2);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "super admin count below min_admins")]
+    fn test_set_admin_limits_rejects_min_above_super_admins() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_contract(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::set_admin_limits(env.clone(), super_admin.clone(), 2, 10);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "min_admins cannot be zero")]
+    fn test_set_admin_limits_rejects_zero_min() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_contract(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::set_admin_limits(env.clone(), super_admin.clone(), 0, 10);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "min_admins cannot be greater than max_admins")]
+    fn test_set_admin_limits_rejects_min_above_max() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_contract(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::set_admin_limits(env.clone(), super_admin.clone(), 3, 2);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "not a super admin")]
+    fn test_set_admin_limits_requires_super_admin() {
+        let env = Env::default();
+        let (contract_address, _super_admin, admin, _operator) = setup_multiple_admins(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::set_admin_limits(env.clone(), admin.clone(), 1, 50);
+        });
+    }
+
+    #[test]
+    fn test_set_admin_limits_at_exact_boundaries() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_contract(&env);
+        let second = Address::generate(&env);
+        grant_super_admin(&env, &contract_address, &super_admin, &second);
+        // max == current count and min == current super admin count are allowed.
+        env.as_contract(&contract_address, || {
+            AdminContract::set_admin_limits(env.clone(), super_admin.clone(), 2, 2);
+            assert_eq!(AdminContract::get_config(env.clone()), (2, 2));
+        });
+    }
+
+    /// Register a contract holding the pre-migration layout, with every
+    /// admin key in instance storage and no storage version.
+    fn setup_legacy(env: &Env) -> (Address, Address, Address) {
+        let contract_address = env.register_contract(None, create_contract());
+        let super_admin = Address::generate(env);
+        let admin = Address::generate(env);
+        env.mock_all_auths();
+
+        env.as_contract(&contract_address, || {
+            let instance = env.storage().instance();
+            instance.set(&DataKey::Initialized, &true);
+            instance.set(&DataKey::MinAdmins, &1u32);
+            instance.set(&DataKey::MaxAdmins, &100u32);
+            for (address, role) in [
+                (super_admin.clone(), AdminRole::SuperAdmin),
+                (admin.clone(), AdminRole::Admin),
+            ] {
+                let info = AdminInfo {
+                    address: address.clone(),
+                    role,
+                    assigned_at: 0,
+                    assigned_by: super_admin.clone(),
+                    active: true,
+                    expires_at: 0,
+                };
+                instance.set(&DataKey::AdminInfo(address.clone()), &info);
+                instance.set(&DataKey::RoleAdmins(role), &Vec::from_array(env, [address]));
+            }
+            instance.set(
+                &DataKey::RoleAdmins(AdminRole::Operator),
+                &Vec::<Address>::new(env),
+            );
+            instance.set(
+                &DataKey::AdminList,
+                &Vec::from_array(env, [super_admin.clone(), admin.clone()]),
+            );
+            instance.set(
+                &DataKey::Permissions(admin.clone()),
+                &(Permission::Pause as u32),
+            );
+        });
+
+        (contract_address, super_admin, admin)
+    }
+
+    #[test]
+    fn test_initialize_uses_persistent_storage() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_contract(&env);
+        env.as_contract(&contract_address, || {
+            let key = DataKey::AdminInfo(super_admin.clone());
+            assert!(!env.storage().instance().has(&key));
+            assert!(env.storage().persistent().has(&key));
+            assert!(env.storage().persistent().has(&DataKey::AdminList));
+            assert_eq!(
+                AdminContract::get_storage_version(env.clone()),
+                STORAGE_VERSION
+            );
+        });
+    }
+
+    #[test]
+    fn test_migrate_storage_moves_legacy_state() {
+        let env = Env::default();
+        let (contract_address, super_admin, admin) = setup_legacy(&env);
+
+        env.as_contract(&contract_address, || {
+            assert_eq!(AdminContract::get_storage_version(env.clone()), 0);
+            assert!(!AdminContract::is_admin(env.clone(), super_admin.clone()));
+        });
+
+        let moved = env.as_contract(&contract_address, || {
+            AdminContract::migrate_storage(env.clone(), super_admin.clone())
+        });
+        assert_eq!(moved, 2);
+
+        let (_, topics, data) = env.events().all().last().unwrap();
+        let topic = Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap();
+        assert_eq!(topic, Symbol::new(&env, "storage_migrated"));
+        assert_eq!(u32::from_val(&env, &data), 2);
+
+        env.as_contract(&contract_address, || {
+            assert_eq!(
+                AdminContract::get_storage_version(env.clone()),
+                STORAGE_VERSION
+            );
+            assert_eq!(
+                AdminContract::get_admin_role(env.clone(), admin.clone()),
+                AdminRole::Admin
+            );
+            assert_eq!(
+                AdminContract::get_permissions(env.clone(), admin.clone()),
+                Permission::Pause as u32
+            );
+            assert_eq!(AdminContract::get_admin_count(env.clone()), 2);
+            assert_eq!(
+                AdminContract::get_admins_by_role(env.clone(), AdminRole::SuperAdmin),
+                Vec::from_array(&env, [super_admin.clone()])
+            );
+            assert_eq!(
+                AdminContract::get_admins_page(env.clone(), 1, 10)
+                    .get(0)
+                    .unwrap()
+                    .address,
+                admin
+            );
+
+            let instance = env.storage().instance();
+            assert!(!instance.has(&DataKey::AdminList));
+            assert!(!instance.has(&DataKey::AdminInfo(admin.clone())));
+            assert!(!instance.has(&DataKey::Permissions(admin.clone())));
+            assert!(!instance.has(&DataKey::RoleAdmins(AdminRole::Admin)));
+            assert!(instance.has(&DataKey::MaxAdmins));
+        });
+
+        // Migrated state is fully usable.
+        let operator = Address::generate(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                super_admin.clone(),
+                operator.clone(),
+                AdminRole::Operator,
+                0,
+            );
+            assert_eq!(AdminContract::get_admin_count(env.clone()), 3);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "storage already migrated")]
+    fn test_migrate_storage_runs_once() {
+        let env = Env::default();
+        let (contract_address, super_admin, _admin) = setup_legacy(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::migrate_storage(env.clone(), super_admin.clone());
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::migrate_storage(env.clone(), super_admin.clone());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "storage already migrated")]
+    fn test_migrate_storage_not_needed_after_initialize() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_contract(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::migrate_storage(env.clone(), super_admin.clone());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "not a super admin")]
+    fn test_migrate_storage_requires_super_admin() {
+        let env = Env::default();
+        let (contract_address, _super_admin, admin) = setup_legacy(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::migrate_storage(env.clone(), admin.clone());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "not initialized")]
+    fn test_migrate_storage_requires_initialization() {
+        let env = Env::default();
+        let contract_address = env.register_contract(None, create_contract());
+        let caller = Address::generate(&env);
+        env.mock_all_auths();
+        env.as_contract(&contract_address, || {
+            AdminContract::migrate_storage(env.clone(), caller.clone());
+        });
+    }
+
+    /// Multiple admins plus a guardian granted by the super admin.
+    fn setup_guardian(env: &Env) -> (Address, Address, Address, Address, Address) {
+        let (contract_address, super_admin, admin, operator) = setup_multiple_admins(env);
+        let guardian = Address::generate(env);
+        env.as_contract(&contract_address, || {
+            AdminContract::set_guardian(env.clone(), super_admin.clone(), guardian.clone(), true);
+        });
+        (contract_address, super_admin, admin, operator, guardian)
+    }
+
+    #[test]
+    fn test_set_guardian() {
+        let env = Env::default();
+        let (contract_address, super_admin, _admin, _operator) = setup_multiple_admins(&env);
+        let guardian = Address::generate(&env);
+
+        env.as_contract(&contract_address, || {
+            assert!(!AdminContract::is_guardian(env.clone(), guardian.clone()));
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::set_guardian(env.clone(), super_admin.clone(), guardian.clone(), true);
+        });
+
+        let (_, topics, data) = env.events().all().last().unwrap();
+        let topic = Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap();
+        assert_eq!(topic, Symbol::new(&env, "guardian_updated"));
+        let (address, enabled) = <(Address, bool)>::from_val(&env, &data);
+        assert_eq!(address, guardian);
+        assert!(enabled);
+
+        env.as_contract(&contract_address, || {
+            assert!(AdminContract::is_guardian(env.clone(), guardian.clone()));
+            assert!(!AdminContract::is_admin(env.clone(), guardian.clone()));
+        });
+    }
+
+    #[test]
+    fn test_guardian_can_pause() {
+        let env = Env::default();
+        let (contract_address, _super_admin, _admin, _operator, guardian) = setup_guardian(&env);
+
+        let info = env.as_contract(&contract_address, || {
+            AdminContract::pause(env.clone(), guardian.clone(), incident(&env), None)
+        });
+        assert_eq!(info.paused_by, guardian);
+
+        env.as_contract(&contract_address, || {
+            assert!(AdminContract::is_paused(env.clone()));
+            let count = AdminContract::get_audit_count(env.clone());
+            let record = AdminContract::get_audit_records(env.clone(), count - 1, 1)
+                .get(0)
+                .unwrap();
+            assert_eq!(record.action, Symbol::new(&env, "paused"));
+            assert_eq!(record.actor, guardian);
+        });
+    }
+
+    #[test]
+    fn test_guardian_can_pause_for() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, _super_admin, _admin, _operator, guardian) = setup_guardian(&env);
+
+        let info = env.as_contract(&contract_address, || {
+            AdminContract::pause_for(env.clone(), guardian.clone(), 600, incident(&env), None)
+        });
+        assert_eq!(info.until, 1_600);
+    }
+
+    #[test]
+    #[should_panic(expected = "address is not an admin")]
+    fn test_guardian_cannot_unpause() {
+        let env = Env::default();
+        let (contract_address, _super_admin, _admin, _operator, guardian) = setup_guardian(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::pause(env.clone(), guardian.clone(), incident(&env), None);
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::unpause(env.clone(), guardian.clone());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "address is not an admin")]
+    fn test_guardian_cannot_pause_scope() {
+        let env = Env::default();
+        let (contract_address, _super_admin, _admin, _operator, guardian) = setup_guardian(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::pause_scope(env.clone(), guardian.clone(), PauseScope::Withdrawals);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "address is not an admin")]
+    fn test_guardian_cannot_add_admin() {
+        let env = Env::default();
+        let (contract_address, _super_admin, _admin, _operator, guardian) = setup_guardian(&env);
+        let other = Address::generate(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                guardian.clone(),
+                other.clone(),
+                AdminRole::Operator,
+                0,
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "not a super admin")]
+    fn test_set_guardian_grant_requires_super_admin() {
+        let env = Env::default();
+        let (contract_address, _super_admin, admin, _operator) = setup_multiple_admins(&env);
+        let guardian = Address::generate(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::set_guardian(env.clone(), admin.clone(), guardian.clone(), true);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "address is already an admin")]
+    fn test_set_guardian_rejects_admin() {
+        let env = Env::default();
+        let (contract_address, super_admin, _admin, operator) = setup_multiple_admins(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::set_guardian(env.clone(), super_admin.clone(), operator.clone(), true);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "address is a guardian")]
+    fn test_add_admin_rejects_guardian() {
+        let env = Env::default();
+        let (contract_address, super_admin, _admin, _operator, guardian) = setup_guardian(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                super_admin.clone(),
+                guardian.clone(),
+                AdminRole::Operator,
+                0,
+            );
+        });
+    }
+
+    #[test]
+    fn test_admin_revokes_guardian_while_admin_scope_paused() {
+        let env = Env::default();
+        let (contract_address, super_admin, admin, _operator, guardian) = setup_guardian(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::pause_scope(env.clone(), super_admin.clone(), PauseScope::Admin);
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::set_guardian(env.clone(), admin.clone(), guardian.clone(), false);
+        });
+        env.as_contract(&contract_address, || {
+            assert!(!AdminContract::is_guardian(env.clone(), guardian.clone()));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "address is not an admin")]
+    fn test_revoked_guardian_cannot_pause() {
+        let env = Env::default();
+        let (contract_address, _super_admin, admin, _operator, guardian) = setup_guardian(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::set_guardian(env.clone(), admin.clone(), guardian.clone(), false);
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::pause(env.clone(), guardian.clone(), incident(&env), None);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "insufficient privileges")]
+    fn test_operator_cannot_revoke_guardian() {
+        let env = Env::default();
+        let (contract_address, _super_admin, _admin, operator, guardian) = setup_guardian(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::set_guardian(env.clone(), operator.clone(), guardian.clone(), false);
+        });
+    }
+
+    #[test]
+    fn test_last_active_defaults_to_assigned_at() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin) = setup_contract(&env);
+        let operator = Address::generate(&env);
+
+        set_time(&env, 2_000);
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                super_admin.clone(),
+                operator.clone(),
+                AdminRole::Operator,
+                0,
+            );
+        });
+
+        env.as_contract(&contract_address, || {
+            assert_eq!(
+                AdminContract::get_last_active(env.clone(), operator.clone()),
+                2_000
+            );
+            assert_eq!(
+                AdminContract::get_last_active(env.clone(), super_admin.clone()),
+                2_000
+            );
+        });
+    }
+
+    #[test]
+    fn test_last_active_tracks_each_mutating_action() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin, admin, operator) = setup_multiple_admins(&env);
+
+        set_time(&env, 5_000);
+        env.as_contract(&contract_address, || {
+            AdminContract::deactivate_admin(env.clone(), admin.clone(), operator.clone());
+        });
+        env.as_contract(&contract_address, || {
+            assert_eq!(
+                AdminContract::get_last_active(env.clone(), admin.clone()),
+                5_000
+            );
+            assert_eq!(
+                AdminContract::get_last_active(env.clone(), super_admin.clone()),
+                1_000
+            );
+        });
+
+        set_time(&env, 8_000);
+        env.as_contract(&contract_address, || {
+            AdminContract::pause(env.clone(), super_admin.clone(), incident(&env), None);
+        });
+        set_time(&env, 9_000);
+        env.as_contract(&contract_address, || {
+            AdminContract::unpause(env.clone(), admin.clone());
+        });
+        env.as_contract(&contract_address, || {
+            assert_eq!(
+                AdminContract::get_last_active(env.clone(), super_admin.clone()),
+                8_000
+            );
+            assert_eq!(
+                AdminContract::get_last_active(env.clone(), admin.clone()),
+                9_000
+            );
+        });
+    }
+
+    #[test]
+    fn test_last_active_tracks_super_admin_handover() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin) = setup_contract(&env);
+        let successor = Address::generate(&env);
+
+        set_time(&env, 2_000);
+        env.as_contract(&contract_address, || {
+            AdminContract::propose_super_admin_transfer(
+                env.clone(),
+                super_admin.clone(),
+                successor.clone(),
+            );
+        });
+        env.as_contract(&contract_address, || {
+            assert_eq!(
+                AdminContract::get_last_active(env.clone(), super_admin.clone()),
+                2_000
+            );
+        });
+
+        set_time(&env, 3_000);
+        env.as_contract(&contract_address, || {
+            AdminContract::accept_super_admin_transfer(env.clone(), successor.clone());
+        });
+        env.as_contract(&contract_address, || {
+            assert_eq!(
+                AdminContract::get_last_active(env.clone(), successor.clone()),
+                3_000
+            );
+        });
+    }
+
+    #[test]
+    fn test_read_only_calls_do_not_update_last_active() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin, admin, _operator) = setup_multiple_admins(&env);
+
+        set_time(&env, 9_000);
+        env.as_contract(&contract_address, || {
+            AdminContract::get_admin_info(env.clone(), admin.clone());
+            AdminContract::is_admin(env.clone(), admin.clone());
+            AdminContract::has_permission(env.clone(), admin.clone(), Permission::Pause);
+            AdminContract::get_permissions(env.clone(), admin.clone());
+            AdminContract::get_admins_page(env.clone(), 0, 10);
+            AdminContract::get_stale_admins(env.clone(), 0);
+            assert_eq!(
+                AdminContract::get_last_active(env.clone(), admin.clone()),
+                1_000
+            );
+            assert_eq!(
+                AdminContract::get_last_active(env.clone(), super_admin.clone()),
+                1_000
+            );
+        });
+    }
+
+    #[test]
+    fn test_get_stale_admins() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin, admin, operator) = setup_multiple_admins(&env);
+
+        set_time(&env, 10_000);
+        env.as_contract(&contract_address, || {
+            AdminContract::pause(env.clone(), operator.clone(), incident(&env), None);
+        });
+
+        env.as_contract(&contract_address, || {
+            assert_eq!(
+                AdminContract::get_stale_admins(env.clone(), 5_000),
+                Vec::from_array(&env, [super_admin.clone(), admin.clone()])
+            );
+            // A last action exactly at the cutoff is not stale.
+            assert_eq!(AdminContract::get_stale_admins(env.clone(), 9_000).len(), 0);
+        });
+    }
+
+    #[test]
+    fn test_get_stale_admins_skips_inactive() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin, admin, operator) = setup_multiple_admins(&env);
+
+        set_time(&env, 10_000);
+        env.as_contract(&contract_address, || {
+            AdminContract::deactivate_admin(env.clone(), super_admin.clone(), admin.clone());
+        });
+
+        env.as_contract(&contract_address, || {
+            assert_eq!(
+                AdminContract::get_stale_admins(env.clone(), 5_000),
+                Vec::from_array(&env, [operator.clone()])
+            );
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "admin not found")]
+    fn test_last_active_cleared_on_removal() {
+        let env = Env::default();
+        let (contract_address, super_admin, admin, _operator) = setup_multiple_admins(&env);
+        env.as_contract(&contract_address, || {
+            assert!(env
+                .storage()
+                .persistent()
+                .has(&DataKey::LastActive(admin.clone())));
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::remove_admin(env.clone(), super_admin.clone(), admin.clone());
+        });
+        env.as_contract(&contract_address, || {
+            assert!(!env
+                .storage()
+                .persistent()
+                .has(&DataKey::LastActive(admin.clone())));
+            AdminContract::get_last_active(env.clone(), admin.clone());
+        });
+    }
+
+    /// Contract at t=1_000 with a pending super admin grant for a new address.
+    fn setup_pending_grant(env: &Env) -> (Address, Address, Address) {
+        set_time(env, 1_000);
+        let (contract_address, super_admin) = setup_contract(env);
+        let grantee = Address::generate(env);
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                super_admin.clone(),
+                grantee.clone(),
+                AdminRole::SuperAdmin,
+                0,
+            );
+        });
+        (contract_address, super_admin, grantee)
+    }
+
+    #[test]
+    fn test_super_admin_grant_is_pending() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin) = setup_contract(&env);
+        let grantee = Address::generate(&env);
+
+        let info = env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                super_admin.clone(),
+                grantee.clone(),
+                AdminRole::SuperAdmin,
+                0,
+            )
+        });
+        assert!(!info.active);
+        assert_eq!(info.assigned_at, 1_000 + DEFAULT_SUPERADMIN_GRANT_DELAY);
+
+        let (_, topics, data) = env.events().all().last().unwrap();
+        let topic = Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap();
+        assert_eq!(topic, Symbol::new(&env, "superadmin_grant_pending"));
+        let grant = PendingGrant::from_val(&env, &data);
+        assert_eq!(grant.address, grantee);
+        assert_eq!(grant.granted_by, super_admin);
+        assert_eq!(grant.effective_at, 1_000 + DEFAULT_SUPERADMIN_GRANT_DELAY);
+
+        env.as_contract(&contract_address, || {
+            assert!(!AdminContract::is_admin(env.clone(), grantee.clone()));
+            assert_eq!(AdminContract::get_admin_count(env.clone()), 1);
+            let pending = AdminContract::get_pending_grant(env.clone(), grantee.clone()).unwrap();
+            assert_eq!(pending.granted_at, 1_000);
+        });
+    }
+
+    #[test]
+    fn test_activate_pending_grant_after_delay() {
+        let env = Env::default();
+        let (contract_address, super_admin, grantee) = setup_pending_grant(&env);
+
+        set_time(&env, 1_000 + DEFAULT_SUPERADMIN_GRANT_DELAY);
+        let info = env.as_contract(&contract_address, || {
+            AdminContract::activate_pending_grant(env.clone(), grantee.clone())
+        });
+        assert!(info.active);
+        assert_eq!(info.role, AdminRole::SuperAdmin);
+        assert_eq!(info.assigned_by, super_admin);
+
+        let events = env.events().all();
+        let (_, topics, data) = events.get(events.len() - 2).unwrap();
+        let topic = Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap();
+        assert_eq!(topic, Symbol::new(&env, "superadmin_grant_activated"));
+        let (address, granted_by) = <(Address, Address)>::from_val(&env, &data);
+        assert_eq!(address, grantee);
+        assert_eq!(granted_by, super_admin);
+
+        env.as_contract(&contract_address, || {
+            assert_eq!(
+                AdminContract::get_admin_role(env.clone(), grantee.clone()),
+                AdminRole::SuperAdmin
+            );
+            assert_eq!(
+                AdminContract::get_admins_by_role(env.clone(), AdminRole::SuperAdmin).len(),
+                2
+            );
+            assert!(AdminContract::get_pending_grant(env.clone(), grantee.clone()).is_none());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "grant is still timelocked")]
+    fn test_activate_pending_grant_rejects_early() {
+        let env = Env::default();
+        let (contract_address, _super_admin, grantee) = setup_pending_grant(&env);
+
+        set_time(&env, 1_000 + DEFAULT_SUPERADMIN_GRANT_DELAY - 1);
+        env.as_contract(&contract_address, || {
+            AdminContract::activate_pending_grant(env.clone(), grantee.clone());
+        });
+    }
+
+    #[test]
+    fn test_cancel_pending_grant_mid_window() {
+        let env = Env::default();
+        let (contract_address, super_admin, grantee) = setup_pending_grant(&env);
+
+        set_time(&env, 1_000 + DEFAULT_SUPERADMIN_GRANT_DELAY / 2);
+        env.as_contract(&contract_address, || {
+            AdminContract::cancel_pending_grant(env.clone(), super_admin.clone(), grantee.clone());
+        });
+
+        let (_, topics, data) = env.events().all().last().unwrap();
+        let topic = Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap();
+        assert_eq!(topic, Symbol::new(&env, "superadmin_grant_cancelled"));
+        let (address, cancelled_by) = <(Address, Address)>::from_val(&env, &data);
+        assert_eq!(address, grantee);
+        assert_eq!(cancelled_by, super_admin);
+
+        set_time(&env, 1_000 + DEFAULT_SUPERADMIN_GRANT_DELAY);
+        env.as_contract(&contract_address, || {
+            assert!(AdminContract::get_pending_grant(env.clone(), grantee.clone()).is_none());
+            assert!(!AdminContract::is_admin(env.clone(), grantee.clone()));
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "no pending grant")]
+    fn test_cancelled_grant_cannot_be_activated() {
+        let env = Env::default();
+        let (contract_address, super_admin, grantee) = setup_pending_grant(&env);
+
+        set_time(&env, 2_000);
+        env.as_contract(&contract_address, || {
+            AdminContract::cancel_pending_grant(env.clone(), super_admin.clone(), grantee.clone());
+        });
+        set_time(&env, 1_000 + DEFAULT_SUPERADMIN_GRANT_DELAY);
+        env.as_contract(&contract_address, || {
+            AdminContract::activate_pending_grant(env.clone(), grantee.clone());
+        });
+    }
+
+    #[test]
+    fn test_cancel_pending_grant_by_other_super_admin_while_paused() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin) = setup_contract(&env);
+        let second = Address::generate(&env);
+        let grantee = Address::generate(&env);
+        grant_super_admin(&env, &contract_address, &super_admin, &second);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                second.clone(),
+                grantee.clone(),
+                AdminRole::SuperAdmin,
+                0,
+            );
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::pause_scope(env.clone(), super_admin.clone(), PauseScope::Admin);
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::cancel_pending_grant(env.clone(), super_admin.clone(), grantee.clone());
+        });
+        env.as_contract(&contract_address, || {
+            assert!(AdminContract::get_pending_grant(env.clone(), grantee.clone()).is_none());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "not a super admin")]
+    fn test_cancel_pending_grant_requires_super_admin() {
+        let env = Env::default();
+        let (contract_address, super_admin, grantee) = setup_pending_grant(&env);
+        let admin = Address::generate(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                super_admin.clone(),
+                admin.clone(),
+                AdminRole::Admin,
+                0,
+            );
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::cancel_pending_grant(env.clone(), admin.clone(), grantee.clone());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "grant already pending")]
+    fn test_super_admin_grant_rejects_duplicate() {
+        let env = Env::default();
+        let (contract_address, super_admin, grantee) = setup_pending_grant(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                super_admin.clone(),
+                grantee.clone(),
+                AdminRole::SuperAdmin,
+                0,
+            );
+        });
+    }
+
+    #[test]
+    fn test_promotion_to_super_admin_is_timelocked() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin, admin, _operator) = setup_multiple_admins(&env);
+
+        let info = env.as_contract(&contract_address, || {
+            AdminContract::update_admin_role(
+                env.clone(),
+                super_admin.clone(),
+                admin.clone(),
+                AdminRole::SuperAdmin,
+                0,
+            )
+        });
+        assert_eq!(info.role, AdminRole::Admin);
+        env.as_contract(&contract_address, || {
+            assert_eq!(
+                AdminContract::get_admin_role(env.clone(), admin.clone()),
+                AdminRole::Admin
+            );
+        });
+
+        set_time(&env, 1_000 + DEFAULT_SUPERADMIN_GRANT_DELAY);
+        env.as_contract(&contract_address, || {
+            AdminContract::activate_pending_grant(env.clone(), admin.clone());
+        });
+        env.as_contract(&contract_address, || {
+            assert_eq!(
+                AdminContract::get_admin_role(env.clone(), admin.clone()),
+                AdminRole::SuperAdmin
+            );
+            assert!(
+                !AdminContract::get_admins_by_role(env.clone(), AdminRole::Admin).contains(&admin)
+            );
+            assert_eq!(AdminContract::get_admin_count(env.clone()), 3);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "granter is no longer a super admin")]
+    fn test_activate_pending_grant_requires_live_granter() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin) = setup_contract(&env);
+        let second = Address::generate(&env);
+        let successor = Address::generate(&env);
+        let grantee = Address::generate(&env);
+        grant_super_admin(&env, &contract_address, &super_admin, &second);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admin(
+                env.clone(),
+                second.clone(),
+                grantee.clone(),
+                AdminRole::SuperAdmin,
+                0,
+            );
+        });
+        // The granter hands their role over before the grant activates.
+        env.as_contract(&contract_address, || {
+            AdminContract::propose_super_admin_transfer(
+                env.clone(),
+                second.clone(),
+                successor.clone(),
+            );
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::accept_super_admin_transfer(env.clone(), successor.clone());
+        });
+
+        let now = env.ledger().timestamp();
+        set_time(&env, now + DEFAULT_SUPERADMIN_GRANT_DELAY);
+        env.as_contract(&contract_address, || {
+            AdminContract::activate_pending_grant(env.clone(), grantee.clone());
+        });
+    }
+
+    #[test]
+    fn test_set_superadmin_grant_delay() {
+        let env = Env::default();
+        let (contract_address, super_admin, grantee) = setup_pending_grant(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::set_superadmin_grant_delay(
+                env.clone(),
+                super_admin.clone(),
+                DEFAULT_SUPERADMIN_GRANT_DELAY * 2,
+            );
+        });
+        let other = Address::generate(&env);
+        env.as_contract(&contract_address, || {
+            assert_eq!(
+                AdminContract::get_superadmin_grant_delay(env.clone()),
+                DEFAULT_SUPERADMIN_GRANT_DELAY * 2
+            );
+            assert_eq!(AdminContract::get_pending_grant_delay(env.clone()), None);
+            AdminContract::add_admin(
+                env.clone(),
+                super_admin.clone(),
+                other.clone(),
+                AdminRole::SuperAdmin,
+                0,
+            );
+        });
+
+        env.as_contract(&contract_address, || {
+            // New grants use the new delay; pending ones keep theirs.
+            assert_eq!(
+                AdminContract::get_pending_grant(env.clone(), other.clone())
+                    .unwrap()
+                    .effective_at,
+                1_000 + DEFAULT_SUPERADMIN_GRANT_DELAY * 2
+            );
+            assert_eq!(
+                AdminContract::get_pending_grant(env.clone(), grantee.clone())
+                    .unwrap()
+                    .effective_at,
+                1_000 + DEFAULT_SUPERADMIN_GRANT_DELAY
+            );
+        });
+    }
+
+    #[test]
+    fn test_reduce_superadmin_grant_delay_waits_out_current_delay() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin) = setup_contract(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::set_superadmin_grant_delay(env.clone(), super_admin.clone(), 7_200);
+        });
+        let other = Address::generate(&env);
+        env.as_contract(&contract_address, || {
+            assert_eq!(
+                AdminContract::get_superadmin_grant_delay(env.clone()),
+                DEFAULT_SUPERADMIN_GRANT_DELAY
+            );
+            assert_eq!(
+                AdminContract::get_pending_grant_delay(env.clone()),
+                Some(PendingGrantDelay {
+                    delay_secs: 7_200,
+                    effective_at: 1_000 + DEFAULT_SUPERADMIN_GRANT_DELAY,
+                })
+            );
+            // A grant made during the wait still uses the old delay.
+            AdminContract::add_admin(
+                env.clone(),
+                super_admin.clone(),
+                other.clone(),
+                AdminRole::SuperAdmin,
+                0,
+            );
+            assert_eq!(
+                AdminContract::get_pending_grant(env.clone(), other.clone())
+                    .unwrap()
+                    .effective_at,
+                1_000 + DEFAULT_SUPERADMIN_GRANT_DELAY
+            );
+        });
+
+        set_time(&env, 1_000 + DEFAULT_SUPERADMIN_GRANT_DELAY);
+        env.as_contract(&contract_address, || {
+            assert_eq!(
+                AdminContract::get_superadmin_grant_delay(env.clone()),
+                7_200
+            );
+            assert_eq!(AdminContract::get_pending_grant_delay(env.clone()), None);
+        });
+    }
+
+    #[test]
+    fn test_raise_superadmin_grant_delay_drops_queued_reduction() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin) = setup_contract(&env);
+
+        env.as_contract(&contract_address, || {
+            AdminContract::set_superadmin_grant_delay(env.clone(), super_admin.clone(), 7_200);
+        });
+        env.as_contract(&contract_address, || {
+            AdminContract::set_superadmin_grant_delay(
+                env.clone(),
+                super_admin.clone(),
+                DEFAULT_SUPERADMIN_GRANT_DELAY,
+            );
+        });
+
+        set_time(&env, 1_000 + DEFAULT_SUPERADMIN_GRANT_DELAY);
+        env.as_contract(&contract_address, || {
+            assert_eq!(
+                AdminContract::get_superadmin_grant_delay(env.clone()),
+                DEFAULT_SUPERADMIN_GRANT_DELAY
+            );
+            assert_eq!(AdminContract::get_pending_grant_delay(env.clone()), None);
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "grant delay below minimum")]
+    fn test_set_superadmin_grant_delay_rejects_below_minimum() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_contract(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::set_superadmin_grant_delay(
+                env.clone(),
+                super_admin.clone(),
+                MIN_SUPERADMIN_GRANT_DELAY - 1,
+            );
+        });
+    }
+
+    #[test]
+    fn test_add_admins_batch() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_contract(&env);
+        let first = Address::generate(&env);
+        let second = Address::generate(&env);
+        let third = Address::generate(&env);
+        let entries = Vec::from_array(
+            &env,
+            [
+                (first.clone(), AdminRole::Admin),
+                (second.clone(), AdminRole::Admin),
+                (third.clone(), AdminRole::Operator),
+            ],
+        );
+
+        let created = env.as_contract(&contract_address, || {
+            AdminContract::add_admins_batch(env.clone(), super_admin.clone(), entries.clone())
+        });
+        assert_eq!(created.len(), 3);
+        assert_eq!(created.get(2).unwrap().role, AdminRole::Operator);
+
+        let added = env
+            .events()
+            .all()
+            .iter()
+            .filter(|(_, topics, _)| {
+                Symbol::try_from_val(&env, &topics.get(0).unwrap()).ok()
+                    == Some(Symbol::new(&env, "admin_added"))
+            })
+            .count();
+        assert_eq!(added, 3);
+
+        env.as_contract(&contract_address, || {
+            assert_eq!(AdminContract::get_admin_count(env.clone()), 4);
+            assert_eq!(
+                AdminContract::get_all_admins(env.clone()),
+                Vec::from_array(&env, [super_admin.clone(), first, second, third])
+            );
+        });
+    }
+
+    #[test]
+    fn test_add_admins_batch_exceeding_max_admins_is_rejected() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_with_limits(&env, 1, 3);
+        let client = AdminContractClient::new(&env, &contract_address);
+        let entries = Vec::from_array(
+            &env,
+            [
+                (Address::generate(&env), AdminRole::Admin),
+                (Address::generate(&env), AdminRole::Operator),
+                (Address::generate(&env), AdminRole::Operator),
+            ],
+        );
+
+        // The third entry would exceed max_admins, so none are added.
+        assert!(client.try_add_admins_batch(&super_admin, &entries).is_err());
+        assert_eq!(client.get_admin_count(), 1);
+        assert_eq!(client.get_audit_count(), 0);
+        assert!(!client.is_admin(&entries.get(0).unwrap().0));
+    }
+
+    #[test]
+    #[should_panic(expected = "batch exceeds max_admins")]
+    fn test_add_admins_batch_checks_max_admins() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_with_limits(&env, 1, 2);
+        let entries = Vec::from_array(
+            &env,
+            [
+                (Address::generate(&env), AdminRole::Admin),
+                (Address::generate(&env), AdminRole::Admin),
+            ],
+        );
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admins_batch(env.clone(), super_admin.clone(), entries.clone());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate address in batch")]
+    fn test_add_admins_batch_rejects_duplicate_in_batch() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_contract(&env);
+        let twice = Address::generate(&env);
+        let entries = Vec::from_array(
+            &env,
+            [
+                (twice.clone(), AdminRole::Admin),
+                (twice.clone(), AdminRole::Operator),
+            ],
+        );
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admins_batch(env.clone(), super_admin.clone(), entries.clone());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "address is already an admin")]
+    fn test_add_admins_batch_rejects_existing_admin() {
+        let env = Env::default();
+        let (contract_address, super_admin, _admin, operator) = setup_multiple_admins(&env);
+        let entries = Vec::from_array(
+            &env,
+            [
+                (Address::generate(&env), AdminRole::Operator),
+                (operator.clone(), AdminRole::Operator),
+            ],
+        );
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admins_batch(env.clone(), super_admin.clone(), entries.clone());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "insufficient privileges")]
+    fn test_add_admins_batch_checks_each_role() {
+        let env = Env::default();
+        let (contract_address, _super_admin, admin, _operator) = setup_multiple_admins(&env);
+        let entries = Vec::from_array(
+            &env,
+            [
+                (Address::generate(&env), AdminRole::Operator),
+                (Address::generate(&env), AdminRole::Admin),
+            ],
+        );
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admins_batch(env.clone(), admin.clone(), entries.clone());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "batch too large")]
+    fn test_add_admins_batch_is_capped() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_contract(&env);
+        let mut entries = Vec::new(&env);
+        for _ in 0..=MAX_ADMIN_BATCH {
+            entries.push_back((Address::generate(&env), AdminRole::Operator));
+        }
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admins_batch(env.clone(), super_admin.clone(), entries.clone());
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "empty batch")]
+    fn test_add_admins_batch_rejects_empty() {
+        let env = Env::default();
+        let (contract_address, super_admin) = setup_contract(&env);
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admins_batch(env.clone(), super_admin.clone(), Vec::new(&env));
+        });
+    }
+
+    #[test]
+    fn test_add_admins_batch_queues_super_admin_grants() {
+        let env = Env::default();
+        set_time(&env, 1_000);
+        let (contract_address, super_admin) = setup_contract(&env);
+        let council = Address::generate(&env);
+        let operator = Address::generate(&env);
+        let entries = Vec::from_array(
+            &env,
+            [
+                (council.clone(), AdminRole::SuperAdmin),
+                (operator.clone(), AdminRole::Operator),
+            ],
+        );
+
+        env.as_contract(&contract_address, || {
+            AdminContract::add_admins_batch(env.clone(), super_admin.clone(), entries.clone());
+        });
+        env.as_contract(&contract_address, || {
+            assert!(AdminContract::is_admin(env.clone(), operator.clone()));
+            assert!(!AdminContract::is_admin(env.clone(), council.clone()));
+            assert!(AdminContract::get_pending_grant(env.clone(), council.clone()).is_some());
+        });
+    }
 }
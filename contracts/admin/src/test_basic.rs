@@ -38,6 +38,7 @@ mod basic_tests {
             assigned_at: 12345,
             assigned_by: assigned_by.clone(),
             active: true,
+            expires_at: 0,
         };
 
         assert_eq!(admin_info.address, address);
@@ -45,6 +46,7 @@ mod basic_tests {
         assert_eq!(admin_info.assigned_at, 12345);
         assert_eq!(admin_info.assigned_by, assigned_by);
         assert!(admin_info.active);
+        assert_eq!(admin_info.expires_at, 0);
     }
 
     #[test]
@@ -0,0 +1,123 @@
+//! Cross-contract use of `check_role` / `check_permission` from a consumer
+//! contract that delegates authorization to the admin contract.
+
+use crate::*;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env};
+
+/// Minimal consumer contract gating one function on the admin contract.
+#[contract]
+struct GatedContract;
+
+#[contractimpl]
+impl GatedContract {
+    pub fn init(e: Env, admin_contract: Address) {
+        e.storage()
+            .instance()
+            .set(&symbol_short!("admins"), &admin_contract);
+    }
+
+    pub fn set_value(e: Env, caller: Address, value: u32) {
+        caller.require_auth();
+        let admin_contract: Address = e
+            .storage()
+            .instance()
+            .get(&symbol_short!("admins"))
+            .unwrap();
+        if !AdminContractClient::new(&e, &admin_contract).check_role(&caller, &AdminRole::Admin) {
+            panic!("insufficient admin role");
+        }
+        e.storage().instance().set(&symbol_short!("value"), &value);
+    }
+
+    pub fn pause_gated(e: Env, caller: Address) -> bool {
+        let admin_contract: Address = e
+            .storage()
+            .instance()
+            .get(&symbol_short!("admins"))
+            .unwrap();
+        AdminContractClient::new(&e, &admin_contract).check_permission(&caller, &Permission::Pause)
+    }
+
+    pub fn value(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&symbol_short!("value"))
+            .unwrap_or(0)
+    }
+}
+
+struct Setup<'a> {
+    admins: AdminContractClient<'a>,
+    gated: GatedContractClient<'a>,
+    super_admin: Address,
+    admin: Address,
+    operator: Address,
+}
+
+fn setup(env: &Env) -> Setup<'_> {
+    env.mock_all_auths();
+    let admins = AdminContractClient::new(env, &env.register_contract(None, AdminContract));
+    let gated = GatedContractClient::new(env, &env.register_contract(None, GatedContract));
+    let super_admin = Address::generate(env);
+    let admin = Address::generate(env);
+    let operator = Address::generate(env);
+
+    admins.initialize(&super_admin, &1, &100);
+    admins.add_admin(&super_admin, &admin, &AdminRole::Admin, &0);
+    admins.add_admin(&admin, &operator, &AdminRole::Operator, &0);
+    gated.init(&admins.address);
+
+    Setup {
+        admins,
+        gated,
+        super_admin,
+        admin,
+        operator,
+    }
+}
+
+#[test]
+fn test_gated_call_allowed_for_admin_role() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.gated.set_value(&s.admin, &7);
+    assert_eq!(s.gated.value(), 7);
+    s.gated.set_value(&s.super_admin, &8);
+    assert_eq!(s.gated.value(), 8);
+}
+
+#[test]
+fn test_gated_call_rejected_for_lower_role_and_strangers() {
+    let env = Env::default();
+    let s = setup(&env);
+    assert!(s.gated.try_set_value(&s.operator, &1).is_err());
+    assert!(s.gated.try_set_value(&Address::generate(&env), &1).is_err());
+    assert_eq!(s.gated.value(), 0);
+}
+
+#[test]
+fn test_gated_call_follows_admin_state_changes() {
+    let env = Env::default();
+    let s = setup(&env);
+    s.admins.deactivate_admin(&s.super_admin, &s.admin);
+    assert!(s.gated.try_set_value(&s.admin, &1).is_err());
+    s.admins.reactivate_admin(&s.super_admin, &s.admin);
+    s.gated.set_value(&s.admin, &1);
+    assert_eq!(s.gated.value(), 1);
+}
+
+#[test]
+fn test_check_role_and_permission_never_panic() {
+    let env = Env::default();
+    let s = setup(&env);
+    let stranger = Address::generate(&env);
+    assert!(!s.admins.check_role(&stranger, &AdminRole::Operator));
+    assert!(!s.admins.check_permission(&stranger, &Permission::Pause));
+    assert!(s.admins.check_role(&s.operator, &AdminRole::Operator));
+    assert!(!s.admins.check_role(&s.operator, &AdminRole::Admin));
+
+    assert!(s.gated.pause_gated(&s.operator));
+    s.admins.set_permissions(&s.admin, &s.operator, &0);
+    assert!(!s.gated.pause_gated(&s.operator));
+}
@@ -0,0 +1,102 @@
+#![no_std]
+
+//! # Credence Admin Client
+//!
+//! Lets other Credence contracts delegate authorization to a deployed
+//! `AdminContract` without depending on the admin crate itself.
+//!
+//! `AdminRole` and `Permission` mirror the admin contract's types and encode
+//! identically, so they can be passed straight to `check_role` and
+//! `check_permission`. Both entrypoints never panic; the `require_*` helpers
+//! here turn a `false` into a panic in the calling contract.
+//!
+//! ## Usage
+//! ```ignore
+//! use credence_admin_client::{require_role, AdminRole};
+//!
+//! pub fn set_config(e: Env, caller: Address, value: u32) {
+//!     caller.require_auth();
+//!     require_role(&e, &admin_contract, &caller, AdminRole::Admin);
+//!     // Admin-only logic
+//! }
+//! ```
+
+use soroban_sdk::{contractclient, contracttype, Address, Env};
+
+/// Admin role hierarchy levels, mirroring the admin contract.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AdminRole {
+    /// Can perform all operations including managing other admins
+    SuperAdmin = 3,
+    /// Can manage operators and perform most administrative tasks
+    Admin = 2,
+    /// Can perform limited operational tasks
+    Operator = 1,
+}
+
+/// Individual operations, mirroring the admin contract's permission bits.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Permission {
+    /// Add, remove, update, renew and (de)activate operators
+    ManageOperators = 1,
+    /// Add, remove, update, renew and (de)activate admins
+    ManageAdmins = 2,
+    /// Add, remove and hand over super admins
+    ManageSuperAdmins = 4,
+    /// Pause the protocol
+    Pause = 8,
+}
+
+/// Cross-contract interface exposed by the admin contract.
+#[contractclient(name = "AdminContractClient")]
+pub trait AdminContractInterface {
+    /// `true` if `address` is a live admin with at least `required_role`.
+    fn check_role(e: Env, address: Address, required_role: AdminRole) -> bool;
+
+    /// `true` if `address` is a live admin holding `permission`.
+    fn check_permission(e: Env, address: Address, permission: Permission) -> bool;
+}
+
+/// Whether `address` holds at least `role` in `admin_contract`.
+pub fn has_role(e: &Env, admin_contract: &Address, address: &Address, role: AdminRole) -> bool {
+    AdminContractClient::new(e, admin_contract).check_role(address, &role)
+}
+
+/// Whether `address` holds `permission` in `admin_contract`.
+pub fn has_permission(
+    e: &Env,
+    admin_contract: &Address,
+    address: &Address,
+    permission: Permission,
+) -> bool {
+    AdminContractClient::new(e, admin_contract).check_permission(address, &permission)
+}
+
+/// Panic unless `address` holds at least `role` in `admin_contract`.
+///
+/// # Panics
+/// Panics with "insufficient admin role".
+pub fn require_role(e: &Env, admin_contract: &Address, address: &Address, role: AdminRole) {
+    if !has_role(e, admin_contract, address, role) {
+        panic!("insufficient admin role");
+    }
+}
+
+/// Panic unless `address` holds `permission` in `admin_contract`.
+///
+/// # Panics
+/// Panics with "missing admin permission".
+pub fn require_permission(
+    e: &Env,
+    admin_contract: &Address,
+    address: &Address,
+    permission: Permission,
+) {
+    if !has_permission(e, admin_contract, address, permission) {
+        panic!("missing admin permission");
+    }
+}
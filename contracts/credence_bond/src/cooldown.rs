@@ -10,7 +10,13 @@
 //!   3. After the cooldown period elapses, the holder calls
 //!      `execute_cooldown_withdrawal` to finalize the withdrawal.
 //!   4. At any point before execution, the holder may cancel via
-//!      `cancel_cooldown`.
+//!      `cancel_cooldown`, or change the amount via `modify_cooldown_request`.
+//!      Lowering the amount keeps the original request time; raising it restarts
+//!      the cooldown from the current ledger time.
+//!   5. If governance sets a non-zero `cooldown_execution_window_secs`, the
+//!      request must be executed within that window once the cooldown elapses.
+//!      Afterwards it is expired and anyone may clear it via
+//!      `expire_cooldown_request`.
 
 use soroban_sdk::{Address, Env, Symbol};
 
@@ -53,6 +59,19 @@ pub fn can_withdraw(now: u64, request_time: u64, cooldown_period: u64) -> bool {
     now >= end
 }
 
+/// Returns `true` when the execution window following the cooldown has passed.
+/// A window of 0 disables expiry, so such requests never expire.
+#[must_use]
+pub fn is_expired(now: u64, request_time: u64, cooldown_period: u64, window: u64) -> bool {
+    if request_time == 0 || window == 0 {
+        return false;
+    }
+    let deadline = request_time
+        .saturating_add(cooldown_period)
+        .saturating_add(window);
+    now > deadline
+}
+
 /// Emit an event when a cooldown withdrawal is requested.
 pub fn emit_cooldown_requested(e: &Env, requester: &Address, amount: i128) {
     e.events().publish(
@@ -69,12 +88,29 @@ pub fn emit_cooldown_executed(e: &Env, requester: &Address, amount: i128) {
     );
 }
 
+/// Emit an event when the amount of a pending cooldown request is changed.
+pub fn emit_cooldown_modified(e: &Env, requester: &Address, old_amount: i128, new_amount: i128) {
+    e.events().publish(
+        (Symbol::new(e, "cooldown_modified"),),
+        (requester.clone(), old_amount, new_amount),
+    );
+}
+
 /// Emit an event when a cooldown withdrawal is cancelled.
 pub fn emit_cooldown_cancelled(e: &Env, requester: &Address) {
     e.events()
         .publish((Symbol::new(e, "cooldown_cancelled"),), requester.clone());
 }
 
+/// Emit an event when a stale cooldown request is cleared after its execution
+/// window has passed.
+pub fn emit_cooldown_expired(e: &Env, requester: &Address, amount: i128) {
+    e.events().publish(
+        (Symbol::new(e, "cooldown_expired"),),
+        (requester.clone(), amount),
+    );
+}
+
 /// Emit an event when the cooldown period is updated by the admin.
 pub fn emit_cooldown_period_updated(e: &Env, old_period: u64, new_period: u64) {
     e.events().publish(
@@ -2,15 +2,51 @@
 //!
 //! Charges a configurable fee when users withdraw before the lock-up period ends.
 //! Penalty is proportional to remaining lock time and is transferred to the treasury.
+//! The rate can be overridden per tier (e.g. lower for Platinum as a loyalty benefit);
+//! tiers without an override fall back to the global rate.
+//!
+//! How the rate shrinks as maturity approaches is set by a [`PenaltyCurve`]:
+//! linear proration (the default), quadratic decay, or a step schedule.
 
-use soroban_sdk::{Address, Env, Symbol};
+use soroban_sdk::{contracttype, Address, Env, Map, Symbol, Vec};
 
 use crate::math;
+use crate::BondTier;
 
 /// Storage key for treasury address.
 const KEY_TREASURY: &str = "treasury";
 /// Storage key for early exit penalty rate in basis points (e.g. 500 = 5%).
 const KEY_PENALTY_BPS: &str = "early_exit_penalty_bps";
+/// Storage key for the per-tier penalty overrides (`Map<BondTier, u32>`).
+const KEY_TIER_PENALTY_BPS: &str = "early_exit_tier_bps";
+/// Storage key for the penalty curve.
+const KEY_PENALTY_CURVE: &str = "early_exit_curve";
+
+/// Shape of the penalty over the lock period. Every curve scales the configured rate
+/// by a factor that is 1 with the full duration remaining and 0 at maturity.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PenaltyCurve {
+    /// `rate * remaining / total`.
+    Linear,
+    /// `rate * (remaining / total)^2`; decays faster near maturity.
+    Quadratic,
+    /// `(remaining_pct, share_bps)` pairs sorted by ascending `remaining_pct`, the last
+    /// being 100. The first step whose `remaining_pct` covers the remaining share of
+    /// the lock charges `rate * share_bps / 10000`.
+    Steps(Vec<(u32, u32)>),
+}
+
+/// Full early exit configuration: global rate plus per-tier overrides.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EarlyExitConfig {
+    pub treasury: Address,
+    /// Fallback rate for tiers without an override.
+    pub penalty_bps: u32,
+    pub tier_penalty_bps: Map<BondTier, u32>,
+    pub curve: PenaltyCurve,
+}
 
 /// Returns (treasury, penalty_bps). Panics if config not set.
 pub fn get_config(e: &Env) -> (Address, u32) {
@@ -27,11 +63,90 @@ pub fn get_config(e: &Env) -> (Address, u32) {
     (treasury, bps)
 }
 
-/// Set early exit config. Only admin should call (enforced by caller).
-pub fn set_config(e: &Env, treasury: Address, penalty_bps: u32) {
+fn validate_bps(penalty_bps: u32) {
     if penalty_bps > 10_000 {
         panic!("penalty_bps must be <= 10000 (100%)");
     }
+}
+
+/// Per-tier penalty overrides. Empty if none were set.
+#[must_use]
+pub fn get_tier_penalties(e: &Env) -> Map<BondTier, u32> {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_TIER_PENALTY_BPS))
+        .unwrap_or(Map::new(e))
+}
+
+/// Override the penalty rate for one tier. Only admin should call (enforced by caller).
+pub fn set_tier_penalty(e: &Env, tier: BondTier, penalty_bps: u32) {
+    validate_bps(penalty_bps);
+    let mut tiers = get_tier_penalties(e);
+    tiers.set(tier, penalty_bps);
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_TIER_PENALTY_BPS), &tiers);
+}
+
+/// Current penalty curve. Defaults to `Linear`.
+#[must_use]
+pub fn get_curve(e: &Env) -> PenaltyCurve {
+    e.storage()
+        .instance()
+        .get(&Symbol::new(e, KEY_PENALTY_CURVE))
+        .unwrap_or(PenaltyCurve::Linear)
+}
+
+/// Select the penalty curve. Only admin should call (enforced by caller).
+pub fn set_curve(e: &Env, curve: PenaltyCurve) {
+    if let PenaltyCurve::Steps(steps) = &curve {
+        validate_steps(steps);
+    }
+    e.storage()
+        .instance()
+        .set(&Symbol::new(e, KEY_PENALTY_CURVE), &curve);
+}
+
+fn validate_steps(steps: &Vec<(u32, u32)>) {
+    let mut prev_pct = 0u32;
+    for (pct, share_bps) in steps.iter() {
+        if pct <= prev_pct || pct > 100 {
+            panic!("penalty steps must have ascending remaining_pct in 1..=100");
+        }
+        if share_bps > 10_000 {
+            panic!("penalty step bps must be <= 10000");
+        }
+        prev_pct = pct;
+    }
+    if prev_pct != 100 {
+        panic!("penalty steps must end at remaining_pct 100");
+    }
+}
+
+/// Returns (treasury, penalty_bps) for `tier`, using the global rate when the tier
+/// has no override. Panics if config not set.
+pub fn get_config_for_tier(e: &Env, tier: &BondTier) -> (Address, u32) {
+    let (treasury, global_bps) = get_config(e);
+    let bps = get_tier_penalties(e)
+        .get(tier.clone())
+        .unwrap_or(global_bps);
+    (treasury, bps)
+}
+
+/// Full config including per-tier overrides. Panics if config not set.
+pub fn get_full_config(e: &Env) -> EarlyExitConfig {
+    let (treasury, penalty_bps) = get_config(e);
+    EarlyExitConfig {
+        treasury,
+        penalty_bps,
+        tier_penalty_bps: get_tier_penalties(e),
+        curve: get_curve(e),
+    }
+}
+
+/// Set early exit config. Only admin should call (enforced by caller).
+pub fn set_config(e: &Env, treasury: Address, penalty_bps: u32) {
+    validate_bps(penalty_bps);
     e.storage()
         .instance()
         .set(&Symbol::new(e, KEY_TREASURY), &treasury);
@@ -40,26 +155,53 @@ pub fn set_config(e: &Env, treasury: Address, penalty_bps: u32) {
         .set(&Symbol::new(e, KEY_PENALTY_BPS), &penalty_bps);
 }
 
-/// Calculate early exit penalty based on remaining lock time.
-/// penalty = (amount * penalty_bps / 10000) * remaining_time / total_duration
-/// Uses integer math to avoid overflow: (amount * penalty_bps / 10000) * remaining_time / total_duration
+/// Calculate early exit penalty based on remaining lock time and the curve.
+/// base = amount * penalty_bps / 10000, then
+/// - Linear: base * remaining_time / total_duration
+/// - Quadratic: base * remaining_time^2 / total_duration^2
+/// - Steps: base * share_bps / 10000 for the first step covering remaining_time
+///
+/// No penalty is charged once `remaining_time` is 0.
 #[must_use]
 pub fn calculate_penalty(
     amount: i128,
     remaining_time: u64,
     total_duration: u64,
     penalty_bps: u32,
+    curve: &PenaltyCurve,
 ) -> i128 {
-    if total_duration == 0 || penalty_bps == 0 {
+    if total_duration == 0 || penalty_bps == 0 || remaining_time == 0 {
         return 0;
     }
+    let remaining_time = remaining_time.min(total_duration);
     let base = math::bps(
         amount,
         penalty_bps,
         "early exit penalty overflow",
         "early exit penalty div-by-zero",
     );
-    let scaled = math::mul_i128(base, remaining_time as i128, "early exit penalty overflow");
+    match curve {
+        PenaltyCurve::Linear => prorate(base, remaining_time, total_duration),
+        PenaltyCurve::Quadratic => prorate(
+            prorate(base, remaining_time, total_duration),
+            remaining_time,
+            total_duration,
+        ),
+        PenaltyCurve::Steps(steps) => {
+            let share_bps = step_share_bps(steps, remaining_time, total_duration);
+            math::bps(
+                base,
+                share_bps,
+                "early exit penalty overflow",
+                "early exit penalty div-by-zero",
+            )
+        }
+    }
+}
+
+/// `value * remaining_time / total_duration`.
+fn prorate(value: i128, remaining_time: u64, total_duration: u64) -> i128 {
+    let scaled = math::mul_i128(value, remaining_time as i128, "early exit penalty overflow");
     math::div_i128(
         scaled,
         total_duration as i128,
@@ -67,6 +209,18 @@ pub fn calculate_penalty(
     )
 }
 
+/// Share of the rate for the first step with `remaining / total <= remaining_pct / 100`.
+/// Compared exactly as `remaining * 100 <= remaining_pct * total`.
+fn step_share_bps(steps: &Vec<(u32, u32)>, remaining_time: u64, total_duration: u64) -> u32 {
+    let remaining = remaining_time as u128 * 100;
+    for (pct, share_bps) in steps.iter() {
+        if remaining <= pct as u128 * total_duration as u128 {
+            return share_bps;
+        }
+    }
+    0
+}
+
 /// Emit early exit penalty event.
 pub fn emit_penalty_event(
     e: &Env,
@@ -0,0 +1,369 @@
+//! Emergency Withdrawal Mechanism
+//!
+//! Lets a bond holder's funds be released before lock-up ends when the protocol
+//! is in emergency mode. Every emergency withdrawal needs both the admin and the
+//! configured governance address to sign, charges a configurable fee that goes to
+//! the emergency treasury, and leaves an append-only audit record.
+//!
+//! The flow is:
+//!   1. Admin sets governance, treasury and fee via `set_emergency_config`.
+//!      Replacing an existing governance address also needs its signature.
+//!   2. Admin and governance jointly enable emergency mode via `set_emergency_mode`.
+//!   3. Admin and governance jointly call `emergency_withdraw`; the net amount is
+//!      transferred to the bond identity and the fee to the treasury.
+//!
+//! Governance may cap the total amount withdrawn per rolling time window via
+//! `set_emergency_rate_limit`. The window restarts automatically once
+//! `window_secs` have elapsed since it opened.
+
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+use crate::math;
+use crate::ttl::{BUMP_TARGET, BUMP_THRESHOLD};
+
+/// Maximum number of records returned by a single range query.
+pub const MAX_RECORD_RANGE: u64 = 50;
+
+/// Emergency configuration set by the admin.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyConfig {
+    /// Address that must co-sign emergency mode changes and withdrawals.
+    pub governance: Address,
+    /// Recipient of emergency withdrawal fees.
+    pub treasury: Address,
+    /// Fee charged on each emergency withdrawal, in basis points.
+    pub emergency_fee_bps: u32,
+    /// Whether emergency mode is currently active.
+    pub enabled: bool,
+}
+
+/// Audit record written for every emergency withdrawal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyWithdrawalRecord {
+    pub id: u64,
+    pub identity: Address,
+    pub gross_amount: i128,
+    pub fee_amount: i128,
+    pub net_amount: i128,
+    pub treasury: Address,
+    pub approved_admin: Address,
+    pub approved_governance: Address,
+    pub reason: Symbol,
+    pub timestamp: u64,
+}
+
+/// Governance-set cap on emergency withdrawals per time window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyRateLimit {
+    /// Maximum gross amount withdrawable within one window.
+    pub max_per_window: i128,
+    /// Window length in seconds.
+    pub window_secs: u64,
+}
+
+/// Consumption of the current emergency withdrawal window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyWindowUsage {
+    /// Ledger timestamp at which the current window opened.
+    pub window_start: u64,
+    /// Gross amount withdrawn in the current window.
+    pub used: i128,
+    /// Configured cap (0 when no limit is set).
+    pub max_per_window: i128,
+    /// Configured window length (0 when no limit is set).
+    pub window_secs: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum EmergencyKey {
+    Config,
+    RecordCounter,
+    Record(u64),
+    RateLimit,
+    Window,
+}
+
+/// Store the emergency configuration. Emergency mode starts disabled and is left
+/// as is by later updates; only `set_mode` changes it. Replacing the governance
+/// address requires the current governance to sign; admin checks are the
+/// caller's responsibility.
+///
+/// # Panics
+/// - "emergency fee bps must be <= 10000" if `emergency_fee_bps` exceeds 100%
+pub fn set_config(e: &Env, governance: Address, treasury: Address, emergency_fee_bps: u32) {
+    if emergency_fee_bps > 10_000 {
+        panic!("emergency fee bps must be <= 10000");
+    }
+    let current: Option<EmergencyConfig> = e.storage().instance().get(&EmergencyKey::Config);
+    let enabled = match current {
+        Some(current) => {
+            if current.governance != governance {
+                current.governance.require_auth();
+            }
+            current.enabled
+        }
+        None => false,
+    };
+    let cfg = EmergencyConfig {
+        governance,
+        treasury,
+        emergency_fee_bps,
+        enabled,
+    };
+    e.storage().instance().set(&EmergencyKey::Config, &cfg);
+    e.events().publish(
+        (Symbol::new(e, "emergency_config_updated"),),
+        (
+            cfg.governance,
+            cfg.treasury,
+            cfg.emergency_fee_bps,
+            cfg.enabled,
+        ),
+    );
+}
+
+/// Read the emergency configuration. Panics if unset.
+pub fn get_config(e: &Env) -> EmergencyConfig {
+    e.storage()
+        .instance()
+        .get(&EmergencyKey::Config)
+        .unwrap_or_else(|| panic!("emergency config not set"))
+}
+
+/// Returns `true` when emergency mode is configured and enabled.
+#[must_use]
+pub fn is_enabled(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get::<_, EmergencyConfig>(&EmergencyKey::Config)
+        .map(|cfg| cfg.enabled)
+        .unwrap_or(false)
+}
+
+/// Toggle emergency mode. Requires governance to match the stored config;
+/// admin checks are the caller's responsibility.
+///
+/// # Panics
+/// - "emergency config not set" if no configuration exists
+/// - "not governance" if `governance` differs from the configured address
+pub fn set_mode(e: &Env, governance: &Address, enabled: bool) {
+    let mut cfg = get_config(e);
+    if cfg.governance != *governance {
+        panic!("not governance");
+    }
+    cfg.enabled = enabled;
+    e.storage().instance().set(&EmergencyKey::Config, &cfg);
+    e.events().publish(
+        (Symbol::new(e, "emergency_mode"),),
+        (enabled, governance.clone()),
+    );
+}
+
+/// Set the per-window emergency withdrawal cap. Requires governance to match the
+/// stored config; authorization is the caller's responsibility.
+///
+/// # Panics
+/// - "not governance" if `governance` differs from the configured address
+/// - "emergency max per window must be positive" if `max_per_window` <= 0
+/// - "emergency window must be positive" if `window_secs` == 0
+pub fn set_rate_limit(e: &Env, governance: &Address, max_per_window: i128, window_secs: u64) {
+    let cfg = get_config(e);
+    if cfg.governance != *governance {
+        panic!("not governance");
+    }
+    if max_per_window <= 0 {
+        panic!("emergency max per window must be positive");
+    }
+    if window_secs == 0 {
+        panic!("emergency window must be positive");
+    }
+    let limit = EmergencyRateLimit {
+        max_per_window,
+        window_secs,
+    };
+    e.storage().instance().set(&EmergencyKey::RateLimit, &limit);
+    e.events().publish(
+        (Symbol::new(e, "emergency_rate_limit"),),
+        (max_per_window, window_secs),
+    );
+}
+
+/// Read the rate limit, if one is configured.
+pub fn get_rate_limit(e: &Env) -> Option<EmergencyRateLimit> {
+    e.storage().instance().get(&EmergencyKey::RateLimit)
+}
+
+/// Current window usage. A window whose length has elapsed reports as a fresh,
+/// empty window starting now.
+#[must_use]
+pub fn get_window_usage(e: &Env) -> EmergencyWindowUsage {
+    let now = e.ledger().timestamp();
+    let (max_per_window, window_secs) = match get_rate_limit(e) {
+        Some(limit) => (limit.max_per_window, limit.window_secs),
+        None => (0, 0),
+    };
+    let (window_start, used) = e
+        .storage()
+        .instance()
+        .get::<_, (u64, i128)>(&EmergencyKey::Window)
+        .unwrap_or((now, 0));
+    if window_secs == 0 || now >= window_start.saturating_add(window_secs) {
+        return EmergencyWindowUsage {
+            window_start: now,
+            used: 0,
+            max_per_window,
+            window_secs,
+        };
+    }
+    EmergencyWindowUsage {
+        window_start,
+        used,
+        max_per_window,
+        window_secs,
+    }
+}
+
+/// Count `amount` against the current window, rolling the window over when it
+/// has elapsed. No-op when no rate limit is configured.
+///
+/// # Panics
+/// - "emergency rate limit exceeded" if the window cap would be exceeded
+pub fn consume_window(e: &Env, amount: i128) {
+    if get_rate_limit(e).is_none() {
+        return;
+    }
+    let usage = get_window_usage(e);
+    let used = math::add_i128(usage.used, amount, "emergency window overflow");
+    if used > usage.max_per_window {
+        panic!("emergency rate limit exceeded");
+    }
+    e.storage()
+        .instance()
+        .set(&EmergencyKey::Window, &(usage.window_start, used));
+}
+
+/// Split `amount` into `(fee, net)` using the emergency fee rate.
+#[must_use]
+pub fn calculate_fee(amount: i128, emergency_fee_bps: u32) -> (i128, i128) {
+    let fee = math::bps(
+        amount,
+        emergency_fee_bps,
+        "emergency fee overflow",
+        "emergency fee div-by-zero",
+    );
+    let net = math::sub_i128(amount, fee, "emergency fee exceeds amount");
+    (fee, net)
+}
+
+/// Id of the most recent emergency record (0 if none).
+#[must_use]
+pub fn get_latest_record_id(e: &Env) -> u64 {
+    e.storage()
+        .persistent()
+        .get(&EmergencyKey::RecordCounter)
+        .unwrap_or(0)
+}
+
+/// Fetch a single emergency record and bump its TTL. Panics if the id is unknown.
+pub fn get_record(e: &Env, id: u64) -> EmergencyWithdrawalRecord {
+    let key = EmergencyKey::Record(id);
+    let storage = e.storage().persistent();
+    let record = storage
+        .get(&key)
+        .unwrap_or_else(|| panic!("emergency record not found"));
+    storage.extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+    record
+}
+
+/// Records with ids in `from_id..=to_id`, oldest first. Ids past the latest record
+/// are ignored.
+///
+/// # Panics
+/// - "invalid record range" if `from_id` is 0 or greater than `to_id`
+/// - "record range too large" if the span exceeds `MAX_RECORD_RANGE`
+pub fn get_records(e: &Env, from_id: u64, to_id: u64) -> Vec<EmergencyWithdrawalRecord> {
+    if from_id == 0 || from_id > to_id {
+        panic!("invalid record range");
+    }
+    if to_id - from_id >= MAX_RECORD_RANGE {
+        panic!("record range too large");
+    }
+    let mut records = Vec::new(e);
+    let last = to_id.min(get_latest_record_id(e));
+    let mut id = from_id;
+    while id <= last {
+        records.push_back(get_record(e, id));
+        id += 1;
+    }
+    records
+}
+
+/// Records with `timestamp >= since`, oldest first. Walks backwards from the latest
+/// id and stops at the first older record, returning at most `MAX_RECORD_RANGE`
+/// of the most recent matches.
+pub fn get_records_since(e: &Env, since: u64) -> Vec<EmergencyWithdrawalRecord> {
+    let mut records = Vec::new(e);
+    let mut id = get_latest_record_id(e);
+    while id > 0 && (records.len() as u64) < MAX_RECORD_RANGE {
+        let record = get_record(e, id);
+        if record.timestamp < since {
+            break;
+        }
+        records.push_front(record);
+        id -= 1;
+    }
+    records
+}
+
+/// Append an audit record and return it. Ids start at 1; `net_amount` is `gross - fee`.
+pub fn append_record(
+    e: &Env,
+    identity: &Address,
+    gross_amount: i128,
+    fee_amount: i128,
+    cfg: &EmergencyConfig,
+    admin: &Address,
+    reason: Symbol,
+) -> EmergencyWithdrawalRecord {
+    let id = get_latest_record_id(e)
+        .checked_add(1)
+        .expect("emergency record id overflow");
+    let record = EmergencyWithdrawalRecord {
+        id,
+        identity: identity.clone(),
+        gross_amount,
+        fee_amount,
+        net_amount: gross_amount - fee_amount,
+        treasury: cfg.treasury.clone(),
+        approved_admin: admin.clone(),
+        approved_governance: cfg.governance.clone(),
+        reason,
+        timestamp: e.ledger().timestamp(),
+    };
+    let storage = e.storage().persistent();
+    let record_key = EmergencyKey::Record(id);
+    storage.set(&record_key, &record);
+    storage.extend_ttl(&record_key, BUMP_THRESHOLD, BUMP_TARGET);
+    storage.set(&EmergencyKey::RecordCounter, &id);
+    storage.extend_ttl(&EmergencyKey::RecordCounter, BUMP_THRESHOLD, BUMP_TARGET);
+    record
+}
+
+/// Transfer `amount` of `token` from this contract to `to`, panicking with
+/// "transfer failed" if the token contract rejects the call.
+pub fn transfer_or_fail(e: &Env, token: &Address, to: &Address, amount: i128) {
+    if amount == 0 {
+        return;
+    }
+    let contract = e.current_contract_address();
+    match TokenClient::new(e, token).try_transfer(&contract, to, &amount) {
+        Ok(Ok(())) => {}
+        _ => panic!("transfer failed"),
+    }
+}
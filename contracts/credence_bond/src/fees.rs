@@ -62,8 +62,16 @@ pub fn is_fee_waived(e: &Env, amount: i128, _identity: &Address) -> bool {
 }
 
 /// Record fee to the contract's fee pool (for later transfer to treasury).
-/// In full implementation, transfer would happen here; we accumulate and emit event.
-pub fn record_fee(e: &Env, identity: &Address, amount: i128, fee: i128, treasury: &Address) {
+/// Also credits the per-identity fee ledger and the global total, and emits
+/// `fee_recorded` tagged with the charging operation (`source`).
+pub fn record_fee(
+    e: &Env,
+    identity: &Address,
+    amount: i128,
+    fee: i128,
+    treasury: &Address,
+    source: &str,
+) {
     if fee <= 0 {
         return;
     }
@@ -71,7 +79,42 @@ pub fn record_fee(e: &Env, identity: &Address, amount: i128, fee: i128, treasury
     let current: i128 = e.storage().instance().get(&key).unwrap_or(0);
     let new_total = current.checked_add(fee).expect("fee pool overflow");
     e.storage().instance().set(&key, &new_total);
+
+    let paid_key = crate::DataKey::FeePaid(identity.clone());
+    let paid: i128 = e.storage().persistent().get(&paid_key).unwrap_or(0);
+    let paid = paid.checked_add(fee).expect("fee ledger overflow");
+    e.storage().persistent().set(&paid_key, &paid);
+
+    let collected = get_total_fees_collected(e)
+        .checked_add(fee)
+        .expect("fee ledger overflow");
+    e.storage()
+        .instance()
+        .set(&crate::DataKey::TotalFeesCollected, &collected);
+
     emit_fee_event(e, identity, amount, fee, treasury);
+    e.events().publish(
+        (Symbol::new(e, "fee_recorded"), identity.clone()),
+        (amount, fee, Symbol::new(e, source)),
+    );
+}
+
+/// Total fees an identity has paid across all fee-charging operations.
+#[must_use]
+pub fn get_fees_paid(e: &Env, identity: &Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get(&crate::DataKey::FeePaid(identity.clone()))
+        .unwrap_or(0)
+}
+
+/// Total fees recorded by this contract since deployment (not reduced by remittance).
+#[must_use]
+pub fn get_total_fees_collected(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&crate::DataKey::TotalFeesCollected)
+        .unwrap_or(0)
 }
 
 /// Emit fee collection event.
@@ -27,6 +27,8 @@ pub struct SlashProposal {
     pub proposed_by: Address,
     pub proposed_at: u64,
     pub status: ProposalStatus,
+    /// Attester whose stake is slashed; `None` for a bond slash.
+    pub attester: Option<Address>,
 }
 
 fn key_next_id() -> crate::DataKey {
@@ -85,7 +87,8 @@ pub fn initialize_governance(
 }
 
 /// Create a new slash proposal. Caller must be admin or governor. Returns proposal id.
-pub fn propose_slash(e: &Env, proposer: &Address, amount: i128) -> u64 {
+/// `attester` binds the proposal to one attester's stake; `None` proposes a bond slash.
+pub fn propose_slash(e: &Env, proposer: &Address, amount: i128, attester: Option<Address>) -> u64 {
     if amount <= 0 {
         panic!("slash amount must be positive");
     }
@@ -99,6 +102,7 @@ pub fn propose_slash(e: &Env, proposer: &Address, amount: i128) -> u64 {
         proposed_by: proposer.clone(),
         proposed_at: e.ledger().timestamp(),
         status: ProposalStatus::Open,
+        attester,
     };
     e.storage().instance().set(&key_proposal(id), &proposal);
     emit_governance_event(e, "slash_proposed", id, proposer, amount);
@@ -1,3 +1,4 @@
 //! Integration tests for bond lifecycle (#47).
 
+mod test_admin_contract;
 mod test_bond_lifecycle;
@@ -0,0 +1,120 @@
+//! Integration tests for delegating bond admin duties to an admin contract (#2081).
+//!
+//! The admin contract is built against an older SDK and no wasm target is available
+//! here, so `StubAdmin` stands in for it, exposing the same `check_role` /
+//! `check_permission` interface that `credence_admin_client` calls.
+
+#![cfg(test)]
+
+use crate::test_helpers;
+use credence_admin_client::{AdminRole, Permission};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol, TryFromVal};
+
+#[contracttype]
+enum StubKey {
+    Role(Address),
+}
+
+#[contract]
+pub struct StubAdmin;
+
+#[contractimpl]
+impl StubAdmin {
+    pub fn grant(e: Env, address: Address, role: AdminRole) {
+        e.storage().instance().set(&StubKey::Role(address), &role);
+    }
+
+    pub fn check_role(e: Env, address: Address, required_role: AdminRole) -> bool {
+        e.storage()
+            .instance()
+            .get::<_, AdminRole>(&StubKey::Role(address))
+            .map(|role| role >= required_role)
+            .unwrap_or(false)
+    }
+
+    pub fn check_permission(e: Env, address: Address, permission: Permission) -> bool {
+        let role = e
+            .storage()
+            .instance()
+            .get::<_, AdminRole>(&StubKey::Role(address));
+        match (role, permission) {
+            (Some(_), Permission::Pause) => true,
+            (Some(role), _) => role >= AdminRole::Admin,
+            (None, _) => false,
+        }
+    }
+}
+
+fn setup_admin_contract(e: &Env) -> (crate::CredenceBondClient<'_>, StubAdminClient<'_>) {
+    let (client, admin, ..) = test_helpers::setup_with_token(e);
+    let stub_id = e.register(StubAdmin, ());
+    client.set_admin_contract(&admin, &stub_id);
+    (client, StubAdminClient::new(e, &stub_id))
+}
+
+#[test]
+fn test_set_admin_contract() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    assert_eq!(client.get_admin_contract(), None);
+
+    let stub_id = e.register(StubAdmin, ());
+    client.set_admin_contract(&admin, &stub_id);
+
+    let (_, topics, data) = e.events().all().last().unwrap();
+    let name = Symbol::try_from_val(&e, &topics.get(0).unwrap()).unwrap();
+    assert_eq!(name, Symbol::new(&e, "admin_contract_set"));
+    assert_eq!(Address::try_from_val(&e, &data).unwrap(), stub_id);
+    assert_eq!(client.get_admin_contract(), Some(stub_id));
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_set_admin_contract_rejects_non_admin() {
+    let e = Env::default();
+    let (client, ..) = test_helpers::setup_with_token(&e);
+    let stub_id = e.register(StubAdmin, ());
+    client.set_admin_contract(&Address::generate(&e), &stub_id);
+}
+
+#[test]
+fn test_delegated_admin_sets_trust_score_cap() {
+    let e = Env::default();
+    let (client, stub) = setup_admin_contract(&e);
+    let delegate = Address::generate(&e);
+    stub.grant(&delegate, &AdminRole::Admin);
+
+    client.set_max_trust_score(&delegate, &500);
+    assert_eq!(client.get_max_trust_score(), 500);
+}
+
+#[test]
+#[should_panic(expected = "insufficient admin role")]
+fn test_delegated_operator_rejected() {
+    let e = Env::default();
+    let (client, stub) = setup_admin_contract(&e);
+    let operator = Address::generate(&e);
+    stub.grant(&operator, &AdminRole::Operator);
+
+    client.set_max_trust_score(&operator, &500);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_unknown_caller_rejected_without_admin_contract() {
+    let e = Env::default();
+    let (client, ..) = test_helpers::setup_with_token(&e);
+    client.set_max_trust_score(&Address::generate(&e), &500);
+}
+
+#[test]
+fn test_stored_admin_unaffected_by_admin_contract() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    let stub_id = e.register(StubAdmin, ());
+    client.set_admin_contract(&admin, &stub_id);
+
+    client.set_max_trust_score(&admin, &750);
+    assert_eq!(client.get_max_trust_score(), 750);
+}
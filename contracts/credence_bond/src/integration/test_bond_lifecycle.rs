@@ -129,7 +129,7 @@ fn test_lifecycle_extend_duration() {
     let (client, admin, identity) = setup(&e);
     client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
     let before = client.get_identity_state();
-    client.extend_duration(&86400_u64);
+    client.extend_duration(&identity, &86400_u64);
     let after = client.get_identity_state();
     assert_eq!(after.bond_duration, before.bond_duration + 86400);
     assert_eq!(after.bonded_amount, before.bonded_amount);
@@ -2,6 +2,7 @@
 
 pub mod access_control;
 pub mod early_exit_penalty;
+pub mod emergency;
 mod fees;
 pub mod governance_approval;
 mod math;
@@ -9,9 +10,11 @@ mod nonce;
 mod parameters;
 
 mod rolling_bond;
-mod slash_history;
+pub mod slash_history;
 mod slashing;
 pub mod tiered_bond;
+mod ttl;
+pub mod upgrade;
 mod validation;
 mod weighted_attestation;
 
@@ -21,12 +24,12 @@ use crate::access_control::{
     add_verifier_role, is_verifier, remove_verifier_role, require_admin, require_verifier,
 };
 use soroban_sdk::{
-    contract, contractimpl, contracttype, Address, Env, IntoVal, String, Symbol, Val, Vec,
+    contract, contractimpl, contracttype, Address, BytesN, Env, IntoVal, String, Symbol, Val, Vec,
 };
 
 use soroban_sdk::token::TokenClient;
 
-pub use types::Attestation;
+pub use types::{Attestation, AttestationCategory, AttestationPayload};
 
 /// Identity tier based on bonded amount (Bronze < Silver < Gold < Platinum).
 #[contracttype]
@@ -40,6 +43,22 @@ pub enum BondTier {
 
 pub mod cooldown;
 
+/// Contract revision reported by `get_contract_info`. Bump on every release.
+pub const CONTRACT_VERSION: u32 = 1;
+
+/// `credence_treasury::FundSource::ProtocolFee`, passed to `receive_fee`.
+const FUND_SOURCE_PROTOCOL_FEE: u32 = 0;
+
+/// What a deployed contract is and which revision it runs.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractInfo {
+    pub name: Symbol,
+    pub version: u32,
+    pub token: Option<Address>,
+    pub admin: Address,
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct IdentityBond {
@@ -67,21 +86,39 @@ pub struct CooldownRequest {
     pub amount: i128,
     pub requested_at: u64,
 }
+/// One entry of a `slash_batch` call: the identity to slash, the amount, and why.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SlashParams {
+    pub identity: Address,
+    pub amount: i128,
+    pub reason: Symbol,
+}
+
 #[contracttype]
+#[derive(Clone)]
 pub enum DataKey {
     Admin,
     Bond,
     Token,
+    /// Legacy attester flag. No longer written or read for authorization; the verifier
+    /// role is the source of truth. Cleared by `migrate_legacy_attesters`.
     Attester(Address),
     Attestation(u64),
     AttestationCounter,
     SubjectAttestations(Address),
+    /// Attestation ids per (subject, category).
+    SubjectCategoryAttestations(Address, AttestationCategory),
     /// Per-identity attestation count (updated on add/revoke).
     SubjectAttestationCount(Address),
     /// Per-identity nonce for replay prevention.
     Nonce(Address),
     /// Attester stake used for weighted attestation.
     AttesterStake(Address),
+    /// Tokens actually deposited by an attester (withdrawable collateral). Persistent.
+    AttesterDeposit(Address),
+    /// Per-attester count of non-revoked attestations. Persistent.
+    AttesterActiveCount(Address),
     CooldownReq(Address),
     // Governance approval for slashing
     GovernanceNextProposalId,
@@ -94,6 +131,19 @@ pub enum DataKey {
     // Bond creation fee
     FeeTreasury,
     FeeBps,
+    // credence_treasury contract that receives remitted fees
+    TreasuryContract,
+    // Optional admin contract whose Admin-or-above holders share selected admin duties
+    AdminContract,
+    // Per-identity fee ledger (persistent) and lifetime total
+    FeePaid(Address),
+    TotalFeesCollected,
+    // Raw sum of active attestation weights per subject (persistent)
+    TrustScore(Address),
+    // Raw sum of active attestation weights per subject and category (persistent)
+    CategoryTrustScore(Address, AttestationCategory),
+    /// Rolling bond renewal history per identity (persistent).
+    RenewalInfo(Address),
 }
 
 #[contract]
@@ -124,6 +174,9 @@ impl CredenceBond {
         Symbol::new(e, "callback")
     }
 
+    /// Run `f` holding the reentrancy lock. Panics with "reentrancy detected" if the lock
+    /// is already held. A panic inside `f` reverts the invocation, including the lock write,
+    /// so no path can leave the lock set.
     fn with_reentrancy_guard<T, F: FnOnce() -> T>(e: &Env, f: F) -> T {
         if Self::check_lock(e) {
             panic!("reentrancy detected");
@@ -145,6 +198,31 @@ impl CredenceBond {
         }
     }
 
+    /// Accept the stored admin, or any Admin-or-above in the configured admin contract.
+    fn require_admin_or_delegated(e: &Env, caller: &Address) {
+        let stored_admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        if stored_admin == *caller {
+            return;
+        }
+        match e
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::AdminContract)
+        {
+            Some(admin_contract) => credence_admin_client::require_role(
+                e,
+                &admin_contract,
+                caller,
+                credence_admin_client::AdminRole::Admin,
+            ),
+            None => panic!("not admin"),
+        }
+    }
+
     /// Initialize the contract (admin).
     pub fn initialize(e: Env, admin: Address) {
         e.storage().instance().set(&DataKey::Admin, &admin);
@@ -160,6 +238,28 @@ impl CredenceBond {
         early_exit_penalty::set_config(&e, treasury, penalty_bps);
     }
 
+    /// Override the early exit penalty rate for one tier. Tiers without an override
+    /// use the global rate from `set_early_exit_config`. Only admin should call.
+    pub fn set_early_exit_penalty_for_tier(e: Env, admin: Address, tier: BondTier, bps: u32) {
+        Self::require_admin_internal(&e, &admin);
+        early_exit_penalty::set_tier_penalty(&e, tier, bps);
+    }
+
+    /// Select how the early exit penalty decays towards maturity. Only admin should call.
+    pub fn set_early_exit_penalty_curve(
+        e: Env,
+        admin: Address,
+        curve: early_exit_penalty::PenaltyCurve,
+    ) {
+        Self::require_admin_internal(&e, &admin);
+        early_exit_penalty::set_curve(&e, curve);
+    }
+
+    /// Early exit treasury, global penalty rate, per-tier overrides and curve.
+    pub fn get_early_exit_config(e: Env) -> early_exit_penalty::EarlyExitConfig {
+        early_exit_penalty::get_full_config(&e)
+    }
+
     pub fn register_attester(e: Env, attester: Address) {
         let admin: Address = e
             .storage()
@@ -169,9 +269,6 @@ impl CredenceBond {
         require_admin(&e, &admin);
         admin.require_auth();
         add_verifier_role(&e, &admin, &attester);
-        e.storage()
-            .instance()
-            .set(&DataKey::Attester(attester.clone()), &true);
         e.events()
             .publish((Symbol::new(&e, "attester_registered"),), attester);
     }
@@ -185,17 +282,38 @@ impl CredenceBond {
         require_admin(&e, &admin);
         admin.require_auth();
         remove_verifier_role(&e, &admin, &attester);
-        e.storage()
-            .instance()
-            .remove(&DataKey::Attester(attester.clone()));
         e.events()
             .publish((Symbol::new(&e, "attester_unregistered"),), attester);
     }
 
+    /// Attester status is the access_control verifier role; this is the only store
+    /// consulted when adding attestations.
     pub fn is_attester(e: Env, attester: Address) -> bool {
         is_verifier(&e, &attester)
     }
 
+    /// Migrate attesters recorded under the legacy `DataKey::Attester` flag to the
+    /// verifier role, removing the legacy entry. Admin only. Addresses without a
+    /// legacy entry are skipped. Returns the number migrated.
+    pub fn migrate_legacy_attesters(e: Env, admin: Address, attesters: Vec<Address>) -> u32 {
+        admin.require_auth();
+        require_admin(&e, &admin);
+        let mut migrated = 0u32;
+        for attester in attesters.iter() {
+            let key = DataKey::Attester(attester.clone());
+            let legacy: Option<bool> = e.storage().instance().get(&key);
+            if legacy.is_none() {
+                continue;
+            }
+            if legacy == Some(true) && !is_verifier(&e, &attester) {
+                add_verifier_role(&e, &admin, &attester);
+            }
+            e.storage().instance().remove(&key);
+            migrated += 1;
+        }
+        migrated
+    }
+
     /// Set the token contract address (admin only). Required before `create_bond`, `top_up`,
     /// and `withdraw_bond`.
     pub fn set_token(e: Env, admin: Address, token: Address) {
@@ -222,8 +340,6 @@ impl CredenceBond {
         is_rolling: bool,
         notice_period_duration: u64,
     ) -> IdentityBond {
-        // Validate bond duration is within allowed range
-        validation::validate_bond_duration(duration);
         Self::create_bond_with_rolling(
             e,
             identity,
@@ -246,6 +362,9 @@ impl CredenceBond {
         if amount < 0 {
             panic!("amount must be non-negative");
         }
+        // Validate bond amount and duration against the configured limits
+        validation::validate_bond_amount(&e, amount);
+        validation::validate_bond_duration(&e, duration);
         let token: Address = e
             .storage()
             .instance()
@@ -265,7 +384,7 @@ impl CredenceBond {
         if fee > 0 {
             let (treasury_opt, _) = fees::get_config(&e);
             if let Some(treasury) = treasury_opt {
-                fees::record_fee(&e, &identity, amount, fee, &treasury);
+                fees::record_fee(&e, &identity, amount, fee, &treasury, "create_bond");
             }
         }
 
@@ -282,6 +401,7 @@ impl CredenceBond {
         };
 
         e.storage().instance().set(&DataKey::Bond, &bond);
+        rolling_bond::clear_renewal_info(&e, &identity);
 
         let old_tier = BondTier::Bronze;
         let new_tier = tiered_bond::get_tier_for_amount(net_amount);
@@ -289,6 +409,20 @@ impl CredenceBond {
         bond
     }
 
+    /// Contract name, version, bond token (if set) and admin.
+    pub fn get_contract_info(e: Env) -> ContractInfo {
+        ContractInfo {
+            name: Symbol::new(&e, "credence_bond"),
+            version: CONTRACT_VERSION,
+            token: e.storage().instance().get(&DataKey::Token),
+            admin: e
+                .storage()
+                .instance()
+                .get(&DataKey::Admin)
+                .unwrap_or_else(|| panic!("not initialized")),
+        }
+    }
+
     pub fn get_identity_state(e: Env) -> IdentityBond {
         e.storage()
             .instance()
@@ -297,34 +431,91 @@ impl CredenceBond {
     }
 
     /// Add an attestation for a subject (only authorized attesters can call).
-    /// Requires correct nonce for replay prevention; rejects duplicate (verifier, identity, data).
+    /// Requires correct nonce for replay prevention; rejects duplicate
+    /// (verifier, identity, category, data).
     /// Weight is computed from attester stake.
     pub fn add_attestation(
         e: Env,
         attester: Address,
         subject: Address,
         attestation_data: String,
+        category: AttestationCategory,
         nonce: u64,
     ) -> Attestation {
-        attester.require_auth();
-        require_verifier(&e, &attester);
+        Self::add_attestation_internal(
+            &e,
+            attester,
+            subject,
+            AttestationPayload::Inline(attestation_data),
+            category,
+            nonce,
+        )
+    }
 
-        let is_authorized: bool = e
-            .storage()
-            .instance()
-            .get(&DataKey::Attester(attester.clone()))
-            .unwrap_or(false);
-        if !is_authorized {
-            panic!("unauthorized attester");
+    /// Add an attestation whose payload lives off-chain: only the 32-byte content hash
+    /// and a short URI are stored. Duplicates are detected on
+    /// (verifier, identity, category, hash).
+    /// Shares nonces, subject indexes, counters and trust score with `add_attestation`.
+    pub fn add_attestation_hashed(
+        e: Env,
+        attester: Address,
+        subject: Address,
+        data_hash: BytesN<32>,
+        uri: String,
+        category: AttestationCategory,
+        nonce: u64,
+    ) -> Attestation {
+        types::Attestation::validate_uri(&uri);
+        Self::add_attestation_internal(
+            &e,
+            attester,
+            subject,
+            AttestationPayload::Hashed(data_hash, uri),
+            category,
+            nonce,
+        )
+    }
+
+    /// Duplicate-detection storage key for an attestation payload.
+    fn attestation_dedup_key(
+        e: &Env,
+        verifier: &Address,
+        identity: &Address,
+        category: &AttestationCategory,
+        payload: &AttestationPayload,
+    ) -> Val {
+        match payload {
+            AttestationPayload::Inline(data) => types::AttestationDedupKey {
+                verifier: verifier.clone(),
+                identity: identity.clone(),
+                category: category.clone(),
+                attestation_data: data.clone(),
+            }
+            .into_val(e),
+            AttestationPayload::Hashed(hash, _) => types::HashedAttestationDedupKey {
+                verifier: verifier.clone(),
+                identity: identity.clone(),
+                category: category.clone(),
+                data_hash: hash.clone(),
+            }
+            .into_val(e),
         }
+    }
 
-        nonce::consume_nonce(&e, &attester, nonce);
+    fn add_attestation_internal(
+        e: &Env,
+        attester: Address,
+        subject: Address,
+        payload: AttestationPayload,
+        category: AttestationCategory,
+        nonce: u64,
+    ) -> Attestation {
+        attester.require_auth();
+        require_verifier(e, &attester);
 
-        let dedup_key = types::AttestationDedupKey {
-            verifier: attester.clone(),
-            identity: subject.clone(),
-            attestation_data: attestation_data.clone(),
-        };
+        nonce::consume_nonce(e, &attester, nonce);
+
+        let dedup_key = Self::attestation_dedup_key(e, &attester, &subject, &category, &payload);
         if e.storage().instance().has(&dedup_key) {
             panic!("duplicate attestation");
         }
@@ -334,7 +525,7 @@ impl CredenceBond {
         let next_id = id.checked_add(1).expect("attestation counter overflow");
         e.storage().instance().set(&counter_key, &next_id);
 
-        let weight = weighted_attestation::compute_weight(&e, &attester);
+        let weight = weighted_attestation::compute_weight(e, &attester);
         types::Attestation::validate_weight(weight);
 
         let attestation = Attestation {
@@ -343,8 +534,11 @@ impl CredenceBond {
             identity: subject.clone(),
             timestamp: e.ledger().timestamp(),
             weight,
-            attestation_data: attestation_data.clone(),
+            category: category.clone(),
+            payload: payload.clone(),
             revoked: false,
+            contested: false,
+            contested_at: 0,
         };
 
         e.storage()
@@ -357,20 +551,37 @@ impl CredenceBond {
             .storage()
             .instance()
             .get(&subject_key)
-            .unwrap_or(Vec::new(&e));
+            .unwrap_or(Vec::new(e));
         attestations.push_back(id);
         e.storage().instance().set(&subject_key, &attestations);
 
+        let category_key = DataKey::SubjectCategoryAttestations(subject.clone(), category.clone());
+        let mut by_category: Vec<u64> = e
+            .storage()
+            .instance()
+            .get(&category_key)
+            .unwrap_or(Vec::new(e));
+        by_category.push_back(id);
+        e.storage().instance().set(&category_key, &by_category);
+
         let count_key = DataKey::SubjectAttestationCount(subject.clone());
         let count: u32 = e.storage().instance().get(&count_key).unwrap_or(0);
         e.storage()
             .instance()
             .set(&count_key, &count.saturating_add(1));
-
-        e.events().publish(
-            (Symbol::new(&e, "attestation_added"), subject),
-            (id, attester, attestation_data, weight),
-        );
+        weighted_attestation::increase_trust_score(e, &subject, &category, weight);
+        weighted_attestation::track_active_attestation(e, &attester, true);
+
+        // Inline payloads keep the original event shape; hashed ones carry the hash.
+        let topics = (Symbol::new(e, "attestation_added"), subject);
+        match payload {
+            AttestationPayload::Inline(data) => {
+                e.events().publish(topics, (id, attester, data, weight));
+            }
+            AttestationPayload::Hashed(hash, _) => {
+                e.events().publish(topics, (id, attester, hash, weight));
+            }
+        }
 
         attestation
     }
@@ -381,7 +592,7 @@ impl CredenceBond {
         nonce::consume_nonce(&e, &attester, nonce);
 
         let key = DataKey::Attestation(attestation_id);
-        let mut attestation: Attestation = e
+        let attestation: Attestation = e
             .storage()
             .instance()
             .get(&key)
@@ -394,14 +605,27 @@ impl CredenceBond {
             panic!("attestation already revoked");
         }
 
+        Self::revoke_attestation_internal(&e, attestation);
+    }
+
+    /// Mark an attestation revoked and unwind its indexes, counters and trust score.
+    fn revoke_attestation_internal(e: &Env, mut attestation: Attestation) {
+        // Contested attestations were already removed from the trust score.
+        // Revocation settles any open contest.
+        let counted = attestation.counts_towards_trust();
         attestation.revoked = true;
-        e.storage().instance().set(&key, &attestation);
+        attestation.contested = false;
+        e.storage()
+            .instance()
+            .set(&DataKey::Attestation(attestation.id), &attestation);
 
-        let dedup_key = types::AttestationDedupKey {
-            verifier: attestation.verifier.clone(),
-            identity: attestation.identity.clone(),
-            attestation_data: attestation.attestation_data.clone(),
-        };
+        let dedup_key = Self::attestation_dedup_key(
+            e,
+            &attestation.verifier,
+            &attestation.identity,
+            &attestation.category,
+            &attestation.payload,
+        );
         e.storage().instance().remove(&dedup_key);
 
         let count_key = DataKey::SubjectAttestationCount(attestation.identity.clone());
@@ -409,14 +633,129 @@ impl CredenceBond {
         e.storage()
             .instance()
             .set(&count_key, &count.saturating_sub(1));
+        if counted {
+            weighted_attestation::decrease_trust_score(
+                e,
+                &attestation.identity,
+                &attestation.category,
+                attestation.weight,
+            );
+        }
+        weighted_attestation::track_active_attestation(e, &attestation.verifier, false);
 
         e.events().publish(
             (
-                Symbol::new(&e, "attestation_revoked"),
+                Symbol::new(e, "attestation_revoked"),
                 attestation.identity.clone(),
             ),
-            (attestation_id, attester),
+            (attestation.id, attestation.verifier),
+        );
+    }
+
+    /// Contest an attestation (only its subject). The attestation stops counting towards
+    /// the subject's trust score until the subject withdraws the contest or an admin
+    /// resolves it. Emits `attestation_contested` for arbitration to pick up.
+    pub fn contest_attestation(
+        e: Env,
+        subject: Address,
+        attestation_id: u64,
+        reason_hash: BytesN<32>,
+    ) -> Attestation {
+        subject.require_auth();
+        let mut attestation = Self::get_attestation(e.clone(), attestation_id);
+        if attestation.identity != subject {
+            panic!("only attestation subject can contest");
+        }
+        if attestation.revoked {
+            panic!("attestation already revoked");
+        }
+        if attestation.contested {
+            panic!("attestation already contested");
+        }
+
+        attestation.contested = true;
+        attestation.contested_at = e.ledger().timestamp();
+        e.storage()
+            .instance()
+            .set(&DataKey::Attestation(attestation_id), &attestation);
+        weighted_attestation::decrease_trust_score(
+            &e,
+            &subject,
+            &attestation.category,
+            attestation.weight,
+        );
+
+        e.events().publish(
+            (Symbol::new(&e, "attestation_contested"), subject),
+            (attestation_id, attestation.verifier.clone(), reason_hash),
+        );
+        attestation
+    }
+
+    /// Withdraw a contest (only the subject). The attestation counts towards the trust
+    /// score again.
+    pub fn uncontest_attestation(e: Env, subject: Address, attestation_id: u64) -> Attestation {
+        subject.require_auth();
+        let attestation = Self::get_attestation(e.clone(), attestation_id);
+        if attestation.identity != subject {
+            panic!("only attestation subject can uncontest");
+        }
+        Self::clear_contest(&e, attestation, "attestation_uncontested")
+    }
+
+    /// Resolve a contest (admin only). `uphold = true` agrees with the subject and
+    /// revokes the attestation; `uphold = false` dismisses the contest and restores
+    /// the attestation's weight to the trust score.
+    pub fn resolve_contest(
+        e: Env,
+        admin: Address,
+        attestation_id: u64,
+        uphold: bool,
+    ) -> Attestation {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        let attestation = Self::get_attestation(e.clone(), attestation_id);
+        if !attestation.contested {
+            panic!("attestation not contested");
+        }
+        let resolved = if uphold {
+            Self::revoke_attestation_internal(&e, attestation);
+            Self::get_attestation(e.clone(), attestation_id)
+        } else {
+            Self::clear_contest(&e, attestation, "attestation_contest_dismissed")
+        };
+        e.events().publish(
+            (
+                Symbol::new(&e, "contest_resolved"),
+                resolved.identity.clone(),
+            ),
+            (attestation_id, uphold, admin),
+        );
+        resolved
+    }
+
+    /// Clear the contested flag and restore the attestation's weight to the trust score.
+    fn clear_contest(e: &Env, mut attestation: Attestation, topic: &str) -> Attestation {
+        if !attestation.contested {
+            panic!("attestation not contested");
+        }
+        attestation.contested = false;
+        e.storage()
+            .instance()
+            .set(&DataKey::Attestation(attestation.id), &attestation);
+        if attestation.counts_towards_trust() {
+            weighted_attestation::increase_trust_score(
+                e,
+                &attestation.identity,
+                &attestation.category,
+                attestation.weight,
+            );
+        }
+        e.events().publish(
+            (Symbol::new(e, topic), attestation.identity.clone()),
+            attestation.id,
         );
+        attestation
     }
 
     pub fn get_attestation(e: Env, attestation_id: u64) -> Attestation {
@@ -426,6 +765,57 @@ impl CredenceBond {
             .unwrap_or_else(|| panic!("attestation not found"))
     }
 
+    /// Bump the TTL of the bond held by `identity` together with its fee ledger,
+    /// trust score, renewal history and latest slash records. Callable by anyone;
+    /// only the caller pays.
+    pub fn extend_bond_ttl(e: Env, identity: Address) {
+        let bond: IdentityBond = e
+            .storage()
+            .instance()
+            .get(&DataKey::Bond)
+            .unwrap_or_else(|| panic!("no bond"));
+        if bond.identity != identity {
+            panic!("no bond");
+        }
+        ttl::extend_instance(&e);
+        ttl::extend_persistent(&e, &DataKey::FeePaid(identity.clone()));
+        ttl::extend_persistent(&e, &DataKey::TrustScore(identity.clone()));
+        ttl::extend_persistent(&e, &DataKey::RenewalInfo(identity.clone()));
+        slash_history::extend_ttl(&e, &identity);
+    }
+
+    /// Bump the TTL of up to `limit` of `identity`'s slash records from index
+    /// `start` (at most `MAX_TTL_RANGE`), for histories longer than
+    /// `extend_bond_ttl` covers. Returns the number of records bumped. Callable by anyone.
+    pub fn extend_slash_history_ttl(e: Env, identity: Address, start: u32, limit: u32) -> u32 {
+        slash_history::extend_ttl_range(&e, &identity, start, limit)
+    }
+
+    /// Bump the TTL of an attestation and the trust scores it contributes to.
+    /// Callable by anyone.
+    pub fn extend_attestation_ttl(e: Env, attestation_id: u64) {
+        let attestation = Self::get_attestation(e.clone(), attestation_id);
+        ttl::extend_instance(&e);
+        ttl::extend_persistent(&e, &DataKey::TrustScore(attestation.identity.clone()));
+        ttl::extend_persistent(
+            &e,
+            &DataKey::CategoryTrustScore(attestation.identity, attestation.category),
+        );
+    }
+
+    /// Bump the TTL of each persistent entry in `keys` and of the contract instance.
+    /// Missing keys are skipped. Returns the number of entries bumped. Callable by anyone.
+    pub fn extend_ttl_batch(e: Env, keys: Vec<DataKey>) -> u32 {
+        ttl::extend_instance(&e);
+        let mut bumped = 0u32;
+        for key in keys.iter() {
+            if ttl::extend_persistent(&e, &key) {
+                bumped += 1;
+            }
+        }
+        bumped
+    }
+
     pub fn get_subject_attestations(e: Env, subject: Address) -> Vec<u64> {
         e.storage()
             .instance()
@@ -433,6 +823,20 @@ impl CredenceBond {
             .unwrap_or(Vec::new(&e))
     }
 
+    /// Attestation ids for a subject within one category, in insertion order.
+    /// `get_subject_attestations_by_category` is over Soroban's 32-character
+    /// function name limit, hence the shortened name.
+    pub fn get_subject_attestations_by_cat(
+        e: Env,
+        subject: Address,
+        category: AttestationCategory,
+    ) -> Vec<u64> {
+        e.storage()
+            .instance()
+            .get(&DataKey::SubjectCategoryAttestations(subject, category))
+            .unwrap_or(Vec::new(&e))
+    }
+
     pub fn get_subject_attestation_count(e: Env, subject: Address) -> u32 {
         e.storage()
             .instance()
@@ -444,11 +848,80 @@ impl CredenceBond {
         nonce::get_nonce(&e, &identity)
     }
 
-    pub fn set_attester_stake(e: Env, admin: Address, attester: Address, amount: i128) {
+    /// Admin override of an attester's stake figure, for migrating pre-deposit stakes.
+    /// Does not move tokens and does not make anything withdrawable.
+    pub fn admin_set_attester_stake(e: Env, admin: Address, attester: Address, amount: i128) {
+        admin.require_auth();
         Self::require_admin_internal(&e, &admin);
         weighted_attestation::set_attester_stake(&e, &attester, amount);
     }
 
+    /// Deposit tokens as attester stake. Transfers `amount` from the attester to the
+    /// contract and increases the stake used for attestation weight.
+    pub fn deposit_attester_stake(e: Env, attester: Address, amount: i128) -> i128 {
+        attester.require_auth();
+        if amount <= 0 {
+            panic!("amount must be positive");
+        }
+        let token: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic!("token not set"));
+        let contract = e.current_contract_address();
+        TokenClient::new(&e, &token).transfer(&attester, &contract, &amount);
+
+        let stake = weighted_attestation::credit_deposit(&e, &attester, amount);
+        e.events().publish(
+            (Symbol::new(&e, "attester_stake_deposited"), attester),
+            (amount, stake),
+        );
+        stake
+    }
+
+    /// Withdraw deposited attester stake. While the attester has active attestations
+    /// the remaining stake must stay at or above the configured minimum.
+    pub fn withdraw_attester_stake(e: Env, attester: Address, amount: i128) -> i128 {
+        attester.require_auth();
+        if amount <= 0 {
+            panic!("amount must be positive");
+        }
+        let token: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic!("token not set"));
+
+        let stake = weighted_attestation::debit_withdrawal(&e, &attester, amount);
+
+        let contract = e.current_contract_address();
+        TokenClient::new(&e, &token).transfer(&contract, &attester, &amount);
+        e.events().publish(
+            (Symbol::new(&e, "attester_stake_withdrawn"), attester),
+            (amount, stake),
+        );
+        stake
+    }
+
+    pub fn get_attester_stake(e: Env, attester: Address) -> i128 {
+        weighted_attestation::get_attester_stake(&e, &attester)
+    }
+
+    pub fn get_attester_deposit(e: Env, attester: Address) -> i128 {
+        weighted_attestation::get_attester_deposit(&e, &attester)
+    }
+
+    /// Set the stake an attester must keep while it has active attestations. Admin only.
+    pub fn set_min_attester_stake(e: Env, admin: Address, amount: i128) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        weighted_attestation::set_min_attester_stake(&e, amount);
+    }
+
+    pub fn get_min_attester_stake(e: Env) -> i128 {
+        weighted_attestation::get_min_attester_stake(&e)
+    }
+
     pub fn set_weight_config(e: Env, admin: Address, multiplier_bps: u32, max_weight: u32) {
         Self::require_admin_internal(&e, &admin);
         weighted_attestation::set_weight_config(&e, multiplier_bps, max_weight);
@@ -458,6 +931,34 @@ impl CredenceBond {
         weighted_attestation::get_weight_config(&e)
     }
 
+    /// Sum of the weights of a subject's active attestations, capped at the
+    /// configured maximum trust score.
+    pub fn get_trust_score(e: Env, subject: Address) -> u64 {
+        weighted_attestation::get_trust_score(&e, &subject)
+    }
+
+    /// Trust score restricted to attestations of one category, capped at the
+    /// configured maximum trust score.
+    pub fn get_trust_score_by_category(
+        e: Env,
+        subject: Address,
+        category: AttestationCategory,
+    ) -> u64 {
+        weighted_attestation::get_category_trust_score(&e, &subject, &category)
+    }
+
+    /// Set the trust score cap. Admin, or an Admin-or-above in the configured admin
+    /// contract (see `set_admin_contract`).
+    pub fn set_max_trust_score(e: Env, admin: Address, max_score: u64) {
+        admin.require_auth();
+        Self::require_admin_or_delegated(&e, &admin);
+        weighted_attestation::set_max_trust_score(&e, max_score);
+    }
+
+    pub fn get_max_trust_score(e: Env) -> u64 {
+        weighted_attestation::get_max_trust_score(&e)
+    }
+
     /// Withdraw from bond (no penalty). Alias for `withdraw_bond`. Use when lock-up has ended
     /// or after the notice period for rolling bonds.
     pub fn withdraw(e: Env, amount: i128) -> IdentityBond {
@@ -471,125 +972,153 @@ impl CredenceBond {
     /// 3. `amount` does not exceed the available balance (`bonded_amount - slashed_amount`).
     /// Transfers USDC to the identity owner and updates tiers.
     pub fn withdraw_bond(e: Env, amount: i128) -> IdentityBond {
-        let key = DataKey::Bond;
-        let mut bond = e
-            .storage()
-            .instance()
-            .get::<_, IdentityBond>(&key)
-            .unwrap_or_else(|| panic!("no bond"));
-
-        let now = e.ledger().timestamp();
-        let end = bond.bond_start.saturating_add(bond.bond_duration);
-
-        if bond.is_rolling {
-            if bond.withdrawal_requested_at == 0 {
-                panic!("cooldown window not elapsed; request_withdrawal first");
-            }
-            if !rolling_bond::can_withdraw_after_notice(
-                now,
-                bond.withdrawal_requested_at,
-                bond.notice_period_duration,
-            ) {
-                panic!("cooldown window not elapsed; request_withdrawal first");
+        Self::with_reentrancy_guard(&e, || {
+            let key = DataKey::Bond;
+            let mut bond = e
+                .storage()
+                .instance()
+                .get::<_, IdentityBond>(&key)
+                .unwrap_or_else(|| panic!("no bond"));
+
+            let now = e.ledger().timestamp();
+            let end = bond.bond_start.saturating_add(bond.bond_duration);
+
+            if bond.is_rolling {
+                if bond.withdrawal_requested_at == 0 {
+                    panic!("cooldown window not elapsed; request_withdrawal first");
+                }
+                if !rolling_bond::can_withdraw_after_notice(
+                    now,
+                    bond.withdrawal_requested_at,
+                    bond.notice_period_duration,
+                ) {
+                    panic!("cooldown window not elapsed; request_withdrawal first");
+                }
+            } else if now < end {
+                panic!("lock-up period not elapsed; use withdraw_early");
             }
-        } else if now < end {
-            panic!("lock-up period not elapsed; use withdraw_early");
-        }
 
-        let available = bond
-            .bonded_amount
-            .checked_sub(bond.slashed_amount)
-            .expect("slashed amount exceeds bonded amount");
+            let available = bond
+                .bonded_amount
+                .checked_sub(bond.slashed_amount)
+                .expect("slashed amount exceeds bonded amount");
 
-        if amount > available {
-            panic!("insufficient balance for withdrawal");
-        }
-
-        let token: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::Token)
-            .unwrap_or_else(|| panic!("token not set"));
-        let contract = e.current_contract_address();
-        TokenClient::new(&e, &token).transfer(&contract, &bond.identity, &amount);
-
-        let old_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
-        bond.bonded_amount = bond
-            .bonded_amount
-            .checked_sub(amount)
-            .expect("withdrawal caused underflow");
+            if amount > available {
+                panic!("insufficient balance for withdrawal");
+            }
 
-        if bond.slashed_amount > bond.bonded_amount {
-            bond.slashed_amount = bond.bonded_amount;
-        }
-        let new_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
-        tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
+            let token: Address = e
+                .storage()
+                .instance()
+                .get(&DataKey::Token)
+                .unwrap_or_else(|| panic!("token not set"));
+            let contract = e.current_contract_address();
+            TokenClient::new(&e, &token).transfer(&contract, &bond.identity, &amount);
+
+            let old_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
+            bond.bonded_amount = bond
+                .bonded_amount
+                .checked_sub(amount)
+                .expect("withdrawal caused underflow");
+
+            if bond.slashed_amount > bond.bonded_amount {
+                bond.slashed_amount = bond.bonded_amount;
+            }
+            let new_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
+            tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
 
-        e.storage().instance().set(&key, &bond);
-        bond
+            e.storage().instance().set(&key, &bond);
+            bond
+        })
     }
 
     /// Early withdrawal path (only valid before lock-up end). Applies an early exit penalty and
     /// transfers the penalty to the configured treasury.
+    ///
+    /// For rolling bonds the penalty is prorated over the notice period instead: the time
+    /// left on a pending `request_withdrawal`, or the full notice period if none was made.
+    /// Once the notice period has elapsed use `withdraw_bond`. Any pending request is
+    /// cleared.
     pub fn withdraw_early(e: Env, amount: i128) -> IdentityBond {
-        let key = DataKey::Bond;
-        let mut bond = e
-            .storage()
-            .instance()
-            .get::<_, IdentityBond>(&key)
-            .unwrap_or_else(|| panic!("no bond"));
-
-        let now = e.ledger().timestamp();
-        let end = bond.bond_start.saturating_add(bond.bond_duration);
-        if now >= end {
-            panic!("use withdraw for post lock-up");
-        }
-
-        let available = bond
-            .bonded_amount
-            .checked_sub(bond.slashed_amount)
-            .expect("slashed amount exceeds bonded amount");
-        if amount > available {
-            panic!("insufficient balance for withdrawal");
-        }
-
-        let (treasury, penalty_bps) = early_exit_penalty::get_config(&e);
-        let remaining = end.saturating_sub(now);
-        let penalty = early_exit_penalty::calculate_penalty(
-            amount,
-            remaining,
-            bond.bond_duration,
-            penalty_bps,
-        );
-        early_exit_penalty::emit_penalty_event(&e, &bond.identity, amount, penalty, &treasury);
+        Self::with_reentrancy_guard(&e, || {
+            let key = DataKey::Bond;
+            let mut bond = e
+                .storage()
+                .instance()
+                .get::<_, IdentityBond>(&key)
+                .unwrap_or_else(|| panic!("no bond"));
+
+            let now = e.ledger().timestamp();
+            let (remaining, total_duration) = if bond.is_rolling {
+                if rolling_bond::can_withdraw_after_notice(
+                    now,
+                    bond.withdrawal_requested_at,
+                    bond.notice_period_duration,
+                ) {
+                    panic!("notice period elapsed; use withdraw");
+                }
+                rolling_bond::early_exit_window(
+                    now,
+                    bond.withdrawal_requested_at,
+                    bond.notice_period_duration,
+                )
+            } else {
+                let end = bond.bond_start.saturating_add(bond.bond_duration);
+                if now >= end {
+                    panic!("use withdraw for post lock-up");
+                }
+                (end - now, bond.bond_duration)
+            };
+
+            let available = bond
+                .bonded_amount
+                .checked_sub(bond.slashed_amount)
+                .expect("slashed amount exceeds bonded amount");
+            if amount > available {
+                panic!("insufficient balance for withdrawal");
+            }
 
-        let token: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::Token)
-            .unwrap_or_else(|| panic!("token not set"));
-        let contract = e.current_contract_address();
-        let token_client = TokenClient::new(&e, &token);
-        let net_amount = amount.checked_sub(penalty).expect("penalty exceeds amount");
-        token_client.transfer(&contract, &bond.identity, &net_amount);
-        if penalty > 0 {
-            token_client.transfer(&contract, &treasury, &penalty);
-        }
-        let old_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
-        bond.bonded_amount = bond
-            .bonded_amount
-            .checked_sub(amount)
-            .expect("withdrawal caused underflow");
+            // The rate follows the tier held before this withdrawal.
+            let old_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
+            let (treasury, penalty_bps) = early_exit_penalty::get_config_for_tier(&e, &old_tier);
+            let penalty = early_exit_penalty::calculate_penalty(
+                amount,
+                remaining,
+                total_duration,
+                penalty_bps,
+                &early_exit_penalty::get_curve(&e),
+            );
+            early_exit_penalty::emit_penalty_event(&e, &bond.identity, amount, penalty, &treasury);
+
+            let token: Address = e
+                .storage()
+                .instance()
+                .get(&DataKey::Token)
+                .unwrap_or_else(|| panic!("token not set"));
+            let contract = e.current_contract_address();
+            let token_client = TokenClient::new(&e, &token);
+            let net_amount = amount.checked_sub(penalty).expect("penalty exceeds amount");
+            token_client.transfer(&contract, &bond.identity, &net_amount);
+            if penalty > 0 {
+                token_client.transfer(&contract, &treasury, &penalty);
+            }
+            bond.bonded_amount = bond
+                .bonded_amount
+                .checked_sub(amount)
+                .expect("withdrawal caused underflow");
 
-        if bond.slashed_amount > bond.bonded_amount {
-            panic!("slashed amount exceeds bonded amount");
-        }
+            if bond.slashed_amount > bond.bonded_amount {
+                panic!("slashed amount exceeds bonded amount");
+            }
+            // The early exit supersedes any pending notice.
+            bond.withdrawal_requested_at = 0;
 
-        let new_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
-        tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
+            let new_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
+            tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
 
-        e.storage().instance().set(&key, &bond);
-        bond
+            e.storage().instance().set(&key, &bond);
+            bond
+        })
     }
 
     pub fn request_withdrawal(e: Env) -> IdentityBond {
@@ -631,7 +1160,7 @@ impl CredenceBond {
             return bond;
         }
 
-        rolling_bond::apply_renewal(&mut bond, now);
+        rolling_bond::apply_renewal(&e, &mut bond, now);
         e.storage().instance().set(&key, &bond);
         e.events().publish(
             (Symbol::new(&e, "bond_renewed"),),
@@ -640,6 +1169,12 @@ impl CredenceBond {
         bond
     }
 
+    /// How many periods the identity's rolling bond has rolled through, and when it
+    /// last renewed. Zeroes if it never renewed.
+    pub fn get_renewal_info(e: Env, identity: Address) -> rolling_bond::RenewalInfo {
+        rolling_bond::get_renewal_info(&e, &identity)
+    }
+
     pub fn get_tier(e: Env) -> BondTier {
         let bond = Self::get_identity_state(e);
         tiered_bond::get_tier_for_amount(bond.bonded_amount)
@@ -649,6 +1184,18 @@ impl CredenceBond {
         slashing::slash_bond(&e, &admin, amount)
     }
 
+    /// Slash several items in one atomic call (admin only). Every item is validated
+    /// against the available balance before any is applied, and each applied item is
+    /// recorded in the slash history.
+    pub fn slash_batch(e: Env, admin: Address, items: Vec<SlashParams>) -> IdentityBond {
+        slashing::slash_batch(&e, &admin, &items)
+    }
+
+    /// Full slash history recorded for an identity, oldest first.
+    pub fn get_slash_history(e: Env, identity: Address) -> Vec<slash_history::SlashRecord> {
+        slash_history::get_slash_history(&e, &identity)
+    }
+
     pub fn initialize_governance(
         e: Env,
         admin: Address,
@@ -661,18 +1208,34 @@ impl CredenceBond {
     }
 
     pub fn propose_slash(e: Env, proposer: Address, amount: i128) -> u64 {
+        Self::require_slash_proposer(&e, &proposer);
+        governance_approval::propose_slash(&e, &proposer, amount, None)
+    }
+
+    /// Propose slashing `amount` of `attester`'s stake. The proposal can only be
+    /// executed against that attester, via `slash_attester`.
+    pub fn propose_attester_slash(
+        e: Env,
+        proposer: Address,
+        attester: Address,
+        amount: i128,
+    ) -> u64 {
+        Self::require_slash_proposer(&e, &proposer);
+        governance_approval::propose_slash(&e, &proposer, amount, Some(attester))
+    }
+
+    fn require_slash_proposer(e: &Env, proposer: &Address) {
         proposer.require_auth();
         let admin: Address = e
             .storage()
             .instance()
             .get(&DataKey::Admin)
             .unwrap_or_else(|| panic!("not initialized"));
-        let governors = governance_approval::get_governors(&e);
-        let is_governor = governors.iter().any(|g| g == proposer);
-        if proposer != admin && !is_governor {
+        let governors = governance_approval::get_governors(e);
+        let is_governor = governors.iter().any(|g| g == *proposer);
+        if *proposer != admin && !is_governor {
             panic!("not admin or governor");
         }
-        governance_approval::propose_slash(&e, &proposer, amount)
     }
 
     pub fn governance_vote(e: Env, voter: Address, proposal_id: u64, approve: bool) {
@@ -684,22 +1247,71 @@ impl CredenceBond {
         governance_approval::delegate(&e, &governor, &to);
     }
 
-    pub fn execute_slash_with_governance(
+    pub fn execute_slash_with_governance(
+        e: Env,
+        proposer: Address,
+        proposal_id: u64,
+    ) -> IdentityBond {
+        proposer.require_auth();
+        let proposal = governance_approval::get_proposal(&e, proposal_id)
+            .unwrap_or_else(|| panic!("proposal not found"));
+        if proposal.proposed_by != proposer {
+            panic!("only proposer can execute");
+        }
+        if proposal.attester.is_some() {
+            panic!("proposal targets an attester");
+        }
+        let executed = governance_approval::execute_slash_if_approved(&e, proposal_id);
+        if !executed {
+            panic!("proposal not approved");
+        }
+        slashing::slash_bond(&e, &proposer, proposal.amount)
+    }
+
+    /// Slash an attester's stake for a fraudulent attestation. Gated by the same
+    /// governance flow as bond slashes: `caller` (admin or governor) must have proposed
+    /// `proposal_id` via `propose_attester_slash` for this `attester` and at least
+    /// `amount`, and the proposal must be approved.
+    /// The slash is clamped to the attester's stake, the attestation is revoked, and
+    /// the deposited tokens backing the slash go to the fee treasury.
+    /// Returns the amount actually slashed.
+    pub fn slash_attester(
         e: Env,
-        proposer: Address,
+        caller: Address,
+        attester: Address,
+        amount: i128,
+        attestation_id: u64,
+        reason: Symbol,
         proposal_id: u64,
-    ) -> IdentityBond {
-        proposer.require_auth();
+    ) -> i128 {
+        caller.require_auth();
         let proposal = governance_approval::get_proposal(&e, proposal_id)
             .unwrap_or_else(|| panic!("proposal not found"));
-        if proposal.proposed_by != proposer {
+        if proposal.proposed_by != caller {
             panic!("only proposer can execute");
         }
-        let executed = governance_approval::execute_slash_if_approved(&e, proposal_id);
-        if !executed {
+        if proposal.attester.as_ref() != Some(&attester) {
+            panic!("proposal not for attester");
+        }
+        if amount > proposal.amount {
+            panic!("slash amount exceeds proposal");
+        }
+        let attestation: Attestation = e
+            .storage()
+            .instance()
+            .get(&DataKey::Attestation(attestation_id))
+            .unwrap_or_else(|| panic!("attestation not found"));
+        if attestation.verifier != attester {
+            panic!("attestation not issued by attester");
+        }
+        if !governance_approval::execute_slash_if_approved(&e, proposal_id) {
             panic!("proposal not approved");
         }
-        slashing::slash_bond(&e, &proposer, proposal.amount)
+
+        if !attestation.revoked {
+            Self::revoke_attestation_internal(&e, attestation);
+        }
+        slashing::slash_attester_stake(&e, &attester, amount, reason)
     }
 
     pub fn set_fee_config(e: Env, admin: Address, treasury: Address, fee_bps: u32) {
@@ -713,7 +1325,31 @@ impl CredenceBond {
         fees::get_config(&e)
     }
 
-    pub fn deposit_fees(e: Env, amount: i128) {
+    /// Total fees paid by `identity` across all fee-charging operations.
+    pub fn get_fees_paid(e: Env, identity: Address) -> i128 {
+        fees::get_fees_paid(&e, &identity)
+    }
+
+    /// Lifetime total of fees recorded by this contract.
+    pub fn get_total_fees_collected(e: Env) -> i128 {
+        fees::get_total_fees_collected(&e)
+    }
+
+    /// Pull `amount` tokens from `from` into the contract and credit the fee pool.
+    /// The pool only ever grows by tokens the contract actually received.
+    pub fn deposit_fees(e: Env, from: Address, amount: i128) {
+        from.require_auth();
+        if amount <= 0 {
+            panic!("amount must be positive");
+        }
+        let token: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .unwrap_or_else(|| panic!("token not set"));
+        let contract = e.current_contract_address();
+        TokenClient::new(&e, &token).transfer_from(&contract, &from, &contract, &amount);
+
         let key = Symbol::new(&e, "fees");
         let current: i128 = e.storage().instance().get(&key).unwrap_or(0);
         let next = current.checked_add(amount).expect("fee pool overflow");
@@ -782,7 +1418,11 @@ impl CredenceBond {
         bond
     }
 
-    pub fn extend_duration(e: Env, additional_duration: u64) -> IdentityBond {
+    /// Extend the lock-up of a bond. Only the bond owner may extend, and the resulting
+    /// total duration must stay within the configured maximum bond duration.
+    /// Emits `duration_extended` with the old and new end timestamps.
+    pub fn extend_duration(e: Env, identity: Address, additional_duration: u64) -> IdentityBond {
+        identity.require_auth();
         let key = DataKey::Bond;
         let mut bond: IdentityBond = e
             .storage()
@@ -790,17 +1430,31 @@ impl CredenceBond {
             .get(&key)
             .unwrap_or_else(|| panic!("no bond"));
 
+        if bond.identity != identity {
+            panic!("not bond owner");
+        }
+
+        let old_end = bond
+            .bond_start
+            .checked_add(bond.bond_duration)
+            .expect("bond end timestamp would overflow");
+
         bond.bond_duration = bond
             .bond_duration
             .checked_add(additional_duration)
             .expect("duration extension caused overflow");
+        validation::validate_bond_duration(&e, bond.bond_duration);
 
-        let _end_timestamp = bond
+        let new_end = bond
             .bond_start
             .checked_add(bond.bond_duration)
             .expect("bond end timestamp would overflow");
 
         e.storage().instance().set(&key, &bond);
+        e.events().publish(
+            (Symbol::new(&e, "duration_extended"), identity),
+            (old_end, new_end),
+        );
         bond
     }
 
@@ -851,6 +1505,17 @@ impl CredenceBond {
         parameters::set_slash_cooldown_secs(&e, &admin, value)
     }
 
+    /// Get the cooldown execution window in seconds (0 = requests never expire).
+    pub fn get_cooldown_exec_window_secs(e: Env) -> u64 {
+        parameters::get_cooldown_execution_window_secs(&e)
+    }
+
+    /// Set the cooldown execution window (`cooldown_execution_window_secs`).
+    /// Governance-only.
+    pub fn set_cooldown_exec_window_secs(e: Env, admin: Address, value: u64) {
+        parameters::set_cooldown_execution_window_secs(&e, &admin, value)
+    }
+
     /// Get bronze tier threshold.
     pub fn get_bronze_threshold(e: Env) -> i128 {
         parameters::get_bronze_threshold(&e)
@@ -891,149 +1556,493 @@ impl CredenceBond {
         parameters::set_platinum_threshold(&e, &admin, value)
     }
 
-    /// Withdraw the full bonded amount back to the identity (callback-based, for reentrancy tests).
-    /// Uses a reentrancy guard to prevent re-entrance during external calls.
-    pub fn withdraw_bond_full(e: Env, identity: Address) -> i128 {
-        identity.require_auth();
-        Self::acquire_lock(&e);
+    /// Get minimum bond amount.
+    pub fn get_min_bond_amount(e: Env) -> i128 {
+        parameters::get_min_bond_amount(&e)
+    }
 
-        let bond_key = DataKey::Bond;
-        let bond: IdentityBond = e
-            .storage()
-            .instance()
-            .get(&bond_key)
-            .unwrap_or_else(|| panic!("no bond"));
+    /// Set minimum bond amount. Governance-only.
+    pub fn set_min_bond_amount(e: Env, admin: Address, value: i128) {
+        parameters::set_min_bond_amount(&e, &admin, value)
+    }
 
-        if bond.identity != identity {
-            Self::release_lock(&e);
-            panic!("not bond owner");
-        }
-        if !bond.active {
-            Self::release_lock(&e);
-            panic!("bond not active");
-        }
+    /// Get maximum bond amount.
+    pub fn get_max_bond_amount(e: Env) -> i128 {
+        parameters::get_max_bond_amount(&e)
+    }
 
-        let withdraw_amount = bond.bonded_amount - bond.slashed_amount;
+    /// Set maximum bond amount. Governance-only.
+    pub fn set_max_bond_amount(e: Env, admin: Address, value: i128) {
+        parameters::set_max_bond_amount(&e, &admin, value)
+    }
 
-        // State update BEFORE external interaction (checks-effects-interactions)
-        let updated = IdentityBond {
-            identity: identity.clone(),
-            bonded_amount: 0,
-            bond_start: bond.bond_start,
-            bond_duration: bond.bond_duration,
-            slashed_amount: bond.slashed_amount,
-            active: false,
-            is_rolling: bond.is_rolling,
-            withdrawal_requested_at: bond.withdrawal_requested_at,
-            notice_period_duration: bond.notice_period_duration,
-        };
-        e.storage().instance().set(&bond_key, &updated);
-
-        // External call: invoke callback if a callback contract is registered.
-        // In production this would be a token transfer; here we use a hook for testing.
-        let cb_key = Symbol::new(&e, "callback");
-        if let Some(cb_addr) = e.storage().instance().get::<_, Address>(&cb_key) {
-            let fn_name = Symbol::new(&e, "on_withdraw");
-            let args: Vec<Val> = Vec::from_array(&e, [withdraw_amount.into_val(&e)]);
-            e.invoke_contract::<Val>(&cb_addr, &fn_name, args);
-        }
+    /// Get minimum bond duration in seconds.
+    pub fn get_min_bond_duration(e: Env) -> u64 {
+        parameters::get_min_bond_duration(&e)
+    }
+
+    /// Set minimum bond duration. Governance-only.
+    pub fn set_min_bond_duration(e: Env, admin: Address, value: u64) {
+        parameters::set_min_bond_duration(&e, &admin, value)
+    }
+
+    /// Get maximum bond duration in seconds.
+    pub fn get_max_bond_duration(e: Env) -> u64 {
+        parameters::get_max_bond_duration(&e)
+    }
+
+    /// Set maximum bond duration. Governance-only.
+    pub fn set_max_bond_duration(e: Env, admin: Address, value: u64) {
+        parameters::set_max_bond_duration(&e, &admin, value)
+    }
+
+    /// Withdraw the full bonded amount back to the identity (callback-based, for reentrancy tests).
+    /// Uses a reentrancy guard to prevent re-entrance during external calls.
+    pub fn withdraw_bond_full(e: Env, identity: Address) -> i128 {
+        Self::with_reentrancy_guard(&e, || {
+            identity.require_auth();
+
+            let bond_key = DataKey::Bond;
+            let bond: IdentityBond = e
+                .storage()
+                .instance()
+                .get(&bond_key)
+                .unwrap_or_else(|| panic!("no bond"));
+
+            if bond.identity != identity {
+                panic!("not bond owner");
+            }
+            if !bond.active {
+                panic!("bond not active");
+            }
+
+            let withdraw_amount = bond.bonded_amount - bond.slashed_amount;
+
+            // State update BEFORE external interaction (checks-effects-interactions)
+            let updated = IdentityBond {
+                identity: identity.clone(),
+                bonded_amount: 0,
+                bond_start: bond.bond_start,
+                bond_duration: bond.bond_duration,
+                slashed_amount: bond.slashed_amount,
+                active: false,
+                is_rolling: bond.is_rolling,
+                withdrawal_requested_at: bond.withdrawal_requested_at,
+                notice_period_duration: bond.notice_period_duration,
+            };
+            e.storage().instance().set(&bond_key, &updated);
+
+            // External call: invoke callback if a callback contract is registered.
+            // In production this would be a token transfer; here we use a hook for testing.
+            let cb_key = Symbol::new(&e, "callback");
+            if let Some(cb_addr) = e.storage().instance().get::<_, Address>(&cb_key) {
+                let fn_name = Symbol::new(&e, "on_withdraw");
+                let args: Vec<Val> = Vec::from_array(&e, [withdraw_amount.into_val(&e)]);
+                e.invoke_contract::<Val>(&cb_addr, &fn_name, args);
+            }
 
-        Self::release_lock(&e);
-        withdraw_amount
+            withdraw_amount
+        })
     }
 
     /// Slash a portion of a bond. Only callable by admin.
     /// Uses a reentrancy guard to prevent re-entrance during external calls.
     pub fn slash_bond(e: Env, admin: Address, slash_amount: i128) -> i128 {
+        Self::with_reentrancy_guard(&e, || {
+            admin.require_auth();
+
+            let stored_admin: Address = e
+                .storage()
+                .instance()
+                .get(&DataKey::Admin)
+                .unwrap_or_else(|| panic!("no admin"));
+            if stored_admin != admin {
+                panic!("not admin");
+            }
+
+            let bond_key = DataKey::Bond;
+            let bond: IdentityBond = e
+                .storage()
+                .instance()
+                .get(&bond_key)
+                .unwrap_or_else(|| panic!("no bond"));
+
+            if !bond.active {
+                panic!("bond not active");
+            }
+
+            let new_slashed = bond.slashed_amount + slash_amount;
+            if new_slashed > bond.bonded_amount {
+                panic!("slash exceeds bond");
+            }
+
+            // State update BEFORE external interaction
+            let updated = IdentityBond {
+                identity: bond.identity.clone(),
+                bonded_amount: bond.bonded_amount,
+                bond_start: bond.bond_start,
+                bond_duration: bond.bond_duration,
+                slashed_amount: new_slashed,
+                active: bond.active,
+                is_rolling: bond.is_rolling,
+                withdrawal_requested_at: bond.withdrawal_requested_at,
+                notice_period_duration: bond.notice_period_duration,
+            };
+            e.storage().instance().set(&bond_key, &updated);
+
+            // External call: invoke callback if registered
+            let cb_key = Symbol::new(&e, "callback");
+            if let Some(cb_addr) = e.storage().instance().get::<_, Address>(&cb_key) {
+                let fn_name = Symbol::new(&e, "on_slash");
+                let args: Vec<Val> = Vec::from_array(&e, [slash_amount.into_val(&e)]);
+                e.invoke_contract::<Val>(&cb_addr, &fn_name, args);
+            }
+
+            new_slashed
+        })
+    }
+
+    // --- Emergency Withdrawal ---
+
+    /// Configure emergency withdrawals (admin only): co-signing governance address,
+    /// fee treasury and fee rate in bps. Replacing an existing governance address
+    /// also requires its signature. Emergency mode is only changed by
+    /// `set_emergency_mode`.
+    pub fn set_emergency_config(
+        e: Env,
+        admin: Address,
+        governance: Address,
+        treasury: Address,
+        emergency_fee_bps: u32,
+    ) {
         admin.require_auth();
-        Self::acquire_lock(&e);
+        Self::require_admin_internal(&e, &admin);
+        emergency::set_config(&e, governance, treasury, emergency_fee_bps);
+    }
 
-        let stored_admin: Address = e
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("no admin"));
-        if stored_admin != admin {
-            Self::release_lock(&e);
-            panic!("not admin");
-        }
+    /// Enable or disable emergency mode. Requires both admin and governance signatures.
+    pub fn set_emergency_mode(e: Env, admin: Address, governance: Address, enabled: bool) {
+        admin.require_auth();
+        governance.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        emergency::set_mode(&e, &governance, enabled);
+    }
 
-        let bond_key = DataKey::Bond;
-        let bond: IdentityBond = e
-            .storage()
-            .instance()
-            .get(&bond_key)
-            .unwrap_or_else(|| panic!("no bond"));
+    /// Withdraw `amount` from the bond while emergency mode is active, bypassing lock-up.
+    /// Requires both admin and governance signatures. The emergency fee is sent to the
+    /// configured treasury and the remainder to the bond identity; an audit record is
+    /// written and `emergency_withdrawal` is emitted.
+    pub fn emergency_withdraw(
+        e: Env,
+        admin: Address,
+        governance: Address,
+        amount: i128,
+        reason: Symbol,
+    ) -> emergency::EmergencyWithdrawalRecord {
+        Self::with_reentrancy_guard(&e, || {
+            admin.require_auth();
+            governance.require_auth();
+            Self::require_admin_internal(&e, &admin);
+
+            let cfg = emergency::get_config(&e);
+            if cfg.governance != governance {
+                panic!("not governance");
+            }
+            if !cfg.enabled {
+                panic!("emergency mode not enabled");
+            }
+            if amount <= 0 {
+                panic!("amount must be positive");
+            }
 
-        if !bond.active {
-            Self::release_lock(&e);
-            panic!("bond not active");
-        }
+            let key = DataKey::Bond;
+            let mut bond: IdentityBond = e
+                .storage()
+                .instance()
+                .get(&key)
+                .unwrap_or_else(|| panic!("no bond"));
+            let available = bond
+                .bonded_amount
+                .checked_sub(bond.slashed_amount)
+                .expect("slashed amount exceeds bonded amount");
+            if amount > available {
+                panic!("insufficient balance for withdrawal");
+            }
+            let token: Address = e
+                .storage()
+                .instance()
+                .get(&DataKey::Token)
+                .unwrap_or_else(|| panic!("token not set"));
+
+            emergency::consume_window(&e, amount);
+            let (fee_amount, net_amount) = emergency::calculate_fee(amount, cfg.emergency_fee_bps);
+
+            // Effects before interactions.
+            let old_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
+            bond.bonded_amount = bond
+                .bonded_amount
+                .checked_sub(amount)
+                .expect("withdrawal caused underflow");
+            e.storage().instance().set(&key, &bond);
+            let new_tier = tiered_bond::get_tier_for_amount(bond.bonded_amount);
+            tiered_bond::emit_tier_change_if_needed(&e, &bond.identity, old_tier, new_tier);
+
+            let record = emergency::append_record(
+                &e,
+                &bond.identity,
+                amount,
+                fee_amount,
+                &cfg,
+                &admin,
+                reason,
+            );
+            e.events().publish(
+                (
+                    Symbol::new(&e, "emergency_withdrawal"),
+                    bond.identity.clone(),
+                ),
+                (record.id, amount, fee_amount, net_amount),
+            );
+
+            // Interactions.
+            emergency::transfer_or_fail(&e, &token, &bond.identity, net_amount);
+            emergency::transfer_or_fail(&e, &token, &cfg.treasury, fee_amount);
+
+            record
+        })
+    }
+
+    /// Cap the gross amount of emergency withdrawals per rolling window.
+    /// Requires the configured governance signature.
+    pub fn set_emergency_rate_limit(
+        e: Env,
+        governance: Address,
+        max_per_window: i128,
+        window_secs: u64,
+    ) {
+        governance.require_auth();
+        emergency::set_rate_limit(&e, &governance, max_per_window, window_secs);
+    }
 
-        let new_slashed = bond.slashed_amount + slash_amount;
-        if new_slashed > bond.bonded_amount {
-            Self::release_lock(&e);
-            panic!("slash exceeds bond");
-        }
+    /// Configured emergency rate limit, if any.
+    pub fn get_emergency_rate_limit(e: Env) -> Option<emergency::EmergencyRateLimit> {
+        emergency::get_rate_limit(&e)
+    }
 
-        // State update BEFORE external interaction
-        let updated = IdentityBond {
-            identity: bond.identity.clone(),
-            bonded_amount: bond.bonded_amount,
-            bond_start: bond.bond_start,
-            bond_duration: bond.bond_duration,
-            slashed_amount: new_slashed,
-            active: bond.active,
-            is_rolling: bond.is_rolling,
-            withdrawal_requested_at: bond.withdrawal_requested_at,
-            notice_period_duration: bond.notice_period_duration,
-        };
-        e.storage().instance().set(&bond_key, &updated);
-
-        // External call: invoke callback if registered
-        let cb_key = Symbol::new(&e, "callback");
-        if let Some(cb_addr) = e.storage().instance().get::<_, Address>(&cb_key) {
-            let fn_name = Symbol::new(&e, "on_slash");
-            let args: Vec<Val> = Vec::from_array(&e, [slash_amount.into_val(&e)]);
-            e.invoke_contract::<Val>(&cb_addr, &fn_name, args);
-        }
+    /// Emergency withdrawal consumption in the current window.
+    pub fn get_emergency_window_usage(e: Env) -> emergency::EmergencyWindowUsage {
+        emergency::get_window_usage(&e)
+    }
+
+    /// Current emergency configuration.
+    pub fn get_emergency_config(e: Env) -> emergency::EmergencyConfig {
+        emergency::get_config(&e)
+    }
+
+    /// Whether emergency mode is active.
+    pub fn is_emergency_mode(e: Env) -> bool {
+        emergency::is_enabled(&e)
+    }
+
+    /// Emergency withdrawal audit record by id.
+    pub fn get_emergency_record(e: Env, id: u64) -> emergency::EmergencyWithdrawalRecord {
+        emergency::get_record(&e, id)
+    }
+
+    /// Emergency withdrawal records with ids in `from_id..=to_id` (span capped at 50).
+    pub fn get_emergency_records(
+        e: Env,
+        from_id: u64,
+        to_id: u64,
+    ) -> Vec<emergency::EmergencyWithdrawalRecord> {
+        emergency::get_records(&e, from_id, to_id)
+    }
+
+    /// Emergency withdrawal records at or after `timestamp`, oldest first (at most 50).
+    pub fn get_emergency_records_since(
+        e: Env,
+        timestamp: u64,
+    ) -> Vec<emergency::EmergencyWithdrawalRecord> {
+        emergency::get_records_since(&e, timestamp)
+    }
+
+    /// Id of the latest emergency withdrawal record (0 if none).
+    pub fn get_latest_emergency_record_id(e: Env) -> u64 {
+        emergency::get_latest_record_id(&e)
+    }
+
+    /// Replace the contract wasm with `new_wasm_hash`, keeping all storage. Requires
+    /// admin and governance signatures. The hash must have been proposed via
+    /// `propose_upgrade` and its timelock must have elapsed.
+    pub fn upgrade(e: Env, admin: Address, governance: Address, new_wasm_hash: BytesN<32>) {
+        admin.require_auth();
+        governance.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        upgrade::execute(&e, &governance, &new_wasm_hash);
+    }
+
+    /// Propose an upgrade to `new_wasm_hash`, starting the timelock. Requires admin
+    /// and governance signatures. Replaces any earlier proposal.
+    pub fn propose_upgrade(
+        e: Env,
+        admin: Address,
+        governance: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> upgrade::PendingUpgrade {
+        admin.require_auth();
+        governance.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        upgrade::propose(&e, &governance, &new_wasm_hash)
+    }
+
+    /// Withdraw the pending upgrade proposal. Requires admin and governance signatures.
+    pub fn cancel_upgrade(e: Env, admin: Address, governance: Address) {
+        admin.require_auth();
+        governance.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        upgrade::cancel(&e, &governance);
+    }
+
+    /// Set the upgrade timelock in seconds (1 to 30 days). Increases apply at once;
+    /// reductions are queued until the current delay has elapsed. Requires admin and
+    /// governance signatures.
+    pub fn set_upgrade_delay(e: Env, admin: Address, governance: Address, delay_secs: u64) {
+        admin.require_auth();
+        governance.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        upgrade::set_delay(&e, &governance, delay_secs);
+    }
+
+    /// Current upgrade timelock in seconds.
+    pub fn get_upgrade_delay(e: Env) -> u64 {
+        upgrade::get_delay(&e)
+    }
 
-        Self::release_lock(&e);
-        new_slashed
+    /// Upgrade waiting for its timelock, if any.
+    pub fn get_pending_upgrade(e: Env) -> Option<upgrade::PendingUpgrade> {
+        upgrade::get_pending(&e)
+    }
+
+    /// Queued reduction of the upgrade timelock, if any.
+    pub fn get_pending_upgrade_delay(e: Env) -> Option<upgrade::PendingDelay> {
+        upgrade::get_pending_delay(&e)
     }
 
     /// Collect accumulated protocol fees. Only callable by admin.
     /// Uses a reentrancy guard to prevent re-entrance during external calls.
     pub fn collect_fees(e: Env, admin: Address) -> i128 {
+        Self::with_reentrancy_guard(&e, || {
+            admin.require_auth();
+
+            let stored_admin: Address = e
+                .storage()
+                .instance()
+                .get(&DataKey::Admin)
+                .unwrap_or_else(|| panic!("no admin"));
+            if stored_admin != admin {
+                panic!("not admin");
+            }
+
+            let fee_key = Symbol::new(&e, "fees");
+            let fees: i128 = e.storage().instance().get(&fee_key).unwrap_or(0);
+
+            // State update BEFORE external interaction
+            e.storage().instance().set(&fee_key, &0_i128);
+
+            // External call: invoke callback if registered
+            let cb_key = Symbol::new(&e, "callback");
+            if let Some(cb_addr) = e.storage().instance().get::<_, Address>(&cb_key) {
+                let fn_name = Symbol::new(&e, "on_collect");
+                let args: Vec<Val> = Vec::from_array(&e, [fees.into_val(&e)]);
+                e.invoke_contract::<Val>(&cb_addr, &fn_name, args);
+            }
+
+            fees
+        })
+    }
+
+    /// Set the credence_treasury contract that `remit_fees` forwards to. Admin only.
+    /// The bond contract must be registered as a depositor on that treasury.
+    pub fn set_treasury_contract(e: Env, admin: Address, treasury: Address) {
         admin.require_auth();
-        Self::acquire_lock(&e);
+        Self::require_admin_internal(&e, &admin);
+        e.storage()
+            .instance()
+            .set(&DataKey::TreasuryContract, &treasury);
+        e.events()
+            .publish((Symbol::new(&e, "treasury_contract_set"),), treasury);
+    }
 
-        let stored_admin: Address = e
-            .storage()
+    /// Get the treasury contract that receives remitted fees, if set.
+    pub fn get_treasury_contract(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::TreasuryContract)
+    }
+
+    /// Set the admin contract consulted via `check_role` when the caller is not the
+    /// stored admin. Only functions documented as delegated honour it. Admin only.
+    pub fn set_admin_contract(e: Env, admin: Address, admin_contract: Address) {
+        admin.require_auth();
+        Self::require_admin_internal(&e, &admin);
+        e.storage()
             .instance()
-            .get(&DataKey::Admin)
-            .unwrap_or_else(|| panic!("no admin"));
-        if stored_admin != admin {
-            Self::release_lock(&e);
-            panic!("not admin");
-        }
+            .set(&DataKey::AdminContract, &admin_contract);
+        e.events()
+            .publish((Symbol::new(&e, "admin_contract_set"),), admin_contract);
+    }
+
+    /// Get the configured admin contract, if any.
+    pub fn get_admin_contract(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::AdminContract)
+    }
+
+    /// Transfer the full accrued fee pool to the treasury contract and credit it via
+    /// the treasury's `receive_fee` as `ProtocolFee`. Only callable by admin.
+    /// The pool is only credited by charged fees and token-backed `deposit_fees`,
+    /// so bonded collateral is never remitted.
+    /// Returns the amount remitted (0 when nothing has accrued).
+    pub fn remit_fees(e: Env, admin: Address) -> i128 {
+        Self::with_reentrancy_guard(&e, || {
+            admin.require_auth();
+            Self::require_admin_internal(&e, &admin);
+
+            let treasury: Address = e
+                .storage()
+                .instance()
+                .get(&DataKey::TreasuryContract)
+                .unwrap_or_else(|| panic!("treasury contract not set"));
+            let token: Address = e
+                .storage()
+                .instance()
+                .get(&DataKey::Token)
+                .unwrap_or_else(|| panic!("token not set"));
+
+            let fee_key = Symbol::new(&e, "fees");
+            let fees: i128 = e.storage().instance().get(&fee_key).unwrap_or(0);
+            if fees <= 0 {
+                return 0;
+            }
 
-        let fee_key = Symbol::new(&e, "fees");
-        let fees: i128 = e.storage().instance().get(&fee_key).unwrap_or(0);
+            // State update BEFORE external interaction
+            e.storage().instance().set(&fee_key, &0_i128);
 
-        // State update BEFORE external interaction
-        e.storage().instance().set(&fee_key, &0_i128);
+            let contract = e.current_contract_address();
+            TokenClient::new(&e, &token).transfer(&contract, &treasury, &fees);
 
-        // External call: invoke callback if registered
-        let cb_key = Symbol::new(&e, "callback");
-        if let Some(cb_addr) = e.storage().instance().get::<_, Address>(&cb_key) {
-            let fn_name = Symbol::new(&e, "on_collect");
-            let args: Vec<Val> = Vec::from_array(&e, [fees.into_val(&e)]);
-            e.invoke_contract::<Val>(&cb_addr, &fn_name, args);
-        }
+            let args: Vec<Val> = Vec::from_array(
+                &e,
+                [
+                    contract.into_val(&e),
+                    fees.into_val(&e),
+                    FUND_SOURCE_PROTOCOL_FEE.into_val(&e),
+                ],
+            );
+            e.invoke_contract::<Val>(&treasury, &Symbol::new(&e, "receive_fee"), args);
 
-        Self::release_lock(&e);
-        fees
+            e.events()
+                .publish((Symbol::new(&e, "fees_remitted"),), (treasury, fees));
+
+            fees
+        })
     }
 
     // ------------------------------------------------------------------
@@ -1124,7 +2133,76 @@ impl CredenceBond {
     /// balance is insufficient at execution time.
     /// @param requester The address that originally requested the withdrawal
     pub fn execute_cooldown_withdrawal(e: Env, requester: Address) -> IdentityBond {
-        requester.require_auth();
+        Self::with_reentrancy_guard(&e, || {
+            requester.require_auth();
+
+            let req_key = DataKey::CooldownReq(requester.clone());
+            let request: CooldownRequest = e
+                .storage()
+                .instance()
+                .get(&req_key)
+                .unwrap_or_else(|| panic!("no cooldown request"));
+
+            let period = cooldown::get_cooldown_period(&e);
+            let now = e.ledger().timestamp();
+
+            if !cooldown::can_withdraw(now, request.requested_at, period) {
+                panic!("cooldown period has not elapsed");
+            }
+            let window = parameters::get_cooldown_execution_window_secs(&e);
+            if cooldown::is_expired(now, request.requested_at, period, window) {
+                panic!("cooldown request expired");
+            }
+
+            // Perform the actual withdrawal on the bond
+            let bond_key = DataKey::Bond;
+            let mut bond = e
+                .storage()
+                .instance()
+                .get::<_, IdentityBond>(&bond_key)
+                .unwrap_or_else(|| panic!("no bond"));
+
+            let available = bond
+                .bonded_amount
+                .checked_sub(bond.slashed_amount)
+                .expect("slashed amount exceeds bonded amount");
+
+            if request.amount > available {
+                panic!("insufficient balance for withdrawal");
+            }
+
+            bond.bonded_amount = bond
+                .bonded_amount
+                .checked_sub(request.amount)
+                .expect("withdrawal caused underflow");
+
+            if bond.slashed_amount > bond.bonded_amount {
+                panic!("slashed amount exceeds bonded amount after withdrawal");
+            }
+
+            e.storage().instance().set(&bond_key, &bond);
+            e.storage().instance().remove(&req_key);
+
+            // Interaction last: state is already committed above.
+            let token: Address = e
+                .storage()
+                .instance()
+                .get(&DataKey::Token)
+                .unwrap_or_else(|| panic!("token not set"));
+            let contract = e.current_contract_address();
+            TokenClient::new(&e, &token).transfer(&contract, &request.requester, &request.amount);
+
+            cooldown::emit_cooldown_executed(&e, &requester, request.amount);
+            bond
+        })
+    }
+
+    /// Clear a cooldown request whose execution window has passed. Callable by
+    /// anyone so stale requests do not linger in storage.
+    /// @param caller     Any address; must authorize the call
+    /// @param requester  The bond holder whose request has expired
+    pub fn expire_cooldown_request(e: Env, caller: Address, requester: Address) {
+        caller.require_auth();
 
         let req_key = DataKey::CooldownReq(requester.clone());
         let request: CooldownRequest = e
@@ -1134,43 +2212,60 @@ impl CredenceBond {
             .unwrap_or_else(|| panic!("no cooldown request"));
 
         let period = cooldown::get_cooldown_period(&e);
-        let now = e.ledger().timestamp();
+        let window = parameters::get_cooldown_execution_window_secs(&e);
+        if !cooldown::is_expired(e.ledger().timestamp(), request.requested_at, period, window) {
+            panic!("cooldown request not expired");
+        }
+
+        e.storage().instance().remove(&req_key);
+        cooldown::emit_cooldown_expired(&e, &requester, request.amount);
+    }
+
+    /// Change the amount of a pending cooldown request. Lowering (or keeping) the
+    /// amount preserves `requested_at`; raising it restarts the cooldown from now.
+    /// The new amount is validated against the available bond balance.
+    /// @param requester  The bond holder who made the request
+    /// @param new_amount The replacement withdrawal amount
+    pub fn modify_cooldown_request(
+        e: Env,
+        requester: Address,
+        new_amount: i128,
+    ) -> CooldownRequest {
+        requester.require_auth();
 
-        if !cooldown::can_withdraw(now, request.requested_at, period) {
-            panic!("cooldown period has not elapsed");
+        if new_amount <= 0 {
+            panic!("amount must be positive");
         }
 
-        // Perform the actual withdrawal on the bond
-        let bond_key = DataKey::Bond;
-        let mut bond = e
+        let req_key = DataKey::CooldownReq(requester.clone());
+        let mut request: CooldownRequest = e
             .storage()
             .instance()
-            .get::<_, IdentityBond>(&bond_key)
-            .unwrap_or_else(|| panic!("no bond"));
+            .get(&req_key)
+            .unwrap_or_else(|| panic!("no cooldown request"));
 
+        let bond = e
+            .storage()
+            .instance()
+            .get::<_, IdentityBond>(&DataKey::Bond)
+            .unwrap_or_else(|| panic!("no bond"));
         let available = bond
             .bonded_amount
             .checked_sub(bond.slashed_amount)
             .expect("slashed amount exceeds bonded amount");
-
-        if request.amount > available {
-            panic!("insufficient balance for withdrawal");
+        if new_amount > available {
+            panic!("amount exceeds available balance");
         }
 
-        bond.bonded_amount = bond
-            .bonded_amount
-            .checked_sub(request.amount)
-            .expect("withdrawal caused underflow");
-
-        if bond.slashed_amount > bond.bonded_amount {
-            panic!("slashed amount exceeds bonded amount after withdrawal");
+        let old_amount = request.amount;
+        if new_amount > old_amount {
+            request.requested_at = e.ledger().timestamp();
         }
+        request.amount = new_amount;
+        e.storage().instance().set(&req_key, &request);
 
-        e.storage().instance().set(&bond_key, &bond);
-        e.storage().instance().remove(&req_key);
-
-        cooldown::emit_cooldown_executed(&e, &requester, request.amount);
-        bond
+        cooldown::emit_cooldown_modified(&e, &requester, old_amount, new_amount);
+        request
     }
 
     /// Cancel a pending cooldown withdrawal request. Only the original
@@ -1255,5 +2350,32 @@ mod test_slashing;
 #[cfg(test)]
 mod test_withdraw_bond;
 
+#[cfg(test)]
+mod test_slash_batch;
+
+#[cfg(test)]
+mod test_extend_duration;
+
+#[cfg(test)]
+mod test_emergency;
+
+#[cfg(test)]
+mod test_remit_fees;
+
+#[cfg(test)]
+mod test_attester_stake;
+
+#[cfg(test)]
+mod test_slash_attester;
+
+#[cfg(test)]
+mod test_reentrancy;
+
 #[cfg(test)]
 mod test_math;
+
+#[cfg(test)]
+mod test_ttl;
+
+#[cfg(test)]
+mod test_upgrade;
@@ -7,6 +7,7 @@
 //! 1. **Fee Rates** - Protocol fees expressed as basis points (1 bps = 0.01%)
 //! 2. **Cooldown Periods** - Time delays between operations (in seconds)
 //! 3. **Tier Thresholds** - Value boundaries defining user/operation tiers (in token units)
+//! 4. **Bond Limits** - Minimum/maximum bond amount (token units) and duration (seconds)
 //!
 //! ## Governance Control
 //! All parameter updates are restricted to the governance address (contract admin).
@@ -58,6 +59,13 @@ pub const MAX_SLASH_COOLDOWN_SECS: u64 = 604_800;
 /// Default slash cooldown period in seconds (24 hours)
 pub const DEFAULT_SLASH_COOLDOWN_SECS: u64 = 86_400;
 
+/// Minimum cooldown execution window in seconds (0 = requests never expire)
+pub const MIN_COOLDOWN_EXECUTION_WINDOW_SECS: u64 = 0;
+/// Maximum cooldown execution window in seconds (30 days)
+pub const MAX_COOLDOWN_EXECUTION_WINDOW_SECS: u64 = 2_592_000;
+/// Default cooldown execution window in seconds (0 = requests never expire)
+pub const DEFAULT_COOLDOWN_EXECUTION_WINDOW_SECS: u64 = 0;
+
 /// Minimum bronze tier threshold (0 = no minimum)
 pub const MIN_BRONZE_THRESHOLD: i128 = 0;
 /// Maximum bronze tier threshold (1 million tokens)
@@ -86,6 +94,22 @@ pub const MAX_PLATINUM_THRESHOLD: i128 = 1_000_000_000_000_000;
 /// Default platinum tier threshold (100000 tokens)
 pub const DEFAULT_PLATINUM_THRESHOLD: i128 = 100_000_000_000;
 
+/// Absolute floor for the configurable minimum bond amount
+pub const MIN_BOND_AMOUNT_FLOOR: i128 = 0;
+/// Default minimum bond amount (0 = any non-negative amount)
+pub const DEFAULT_MIN_BOND_AMOUNT: i128 = 0;
+/// Default maximum bond amount (uncapped)
+pub const DEFAULT_MAX_BOND_AMOUNT: i128 = i128::MAX;
+
+/// Absolute floor for the configurable minimum bond duration (1 hour)
+pub const MIN_BOND_DURATION_FLOOR: u64 = 3_600;
+/// Absolute ceiling for the configurable maximum bond duration (10 years)
+pub const MAX_BOND_DURATION_CEILING: u64 = 315_360_000;
+/// Default minimum bond duration in seconds (1 day)
+pub const DEFAULT_MIN_BOND_DURATION: u64 = crate::validation::MIN_BOND_DURATION;
+/// Default maximum bond duration in seconds (365 days)
+pub const DEFAULT_MAX_BOND_DURATION: u64 = crate::validation::MAX_BOND_DURATION;
+
 // ============================================================================
 // Storage Keys
 // ============================================================================
@@ -97,10 +121,15 @@ pub enum ParameterKey {
     AttestationFeeBps,
     WithdrawalCooldownSecs,
     SlashCooldownSecs,
+    CooldownExecutionWindowSecs,
     BronzeThreshold,
     SilverThreshold,
     GoldThreshold,
     PlatinumThreshold,
+    MinBondAmount,
+    MaxBondAmount,
+    MinBondDuration,
+    MaxBondDuration,
 }
 
 // ============================================================================
@@ -160,6 +189,19 @@ pub fn get_slash_cooldown_secs(e: &Env) -> u64 {
         .unwrap_or(DEFAULT_SLASH_COOLDOWN_SECS)
 }
 
+/// Get the window after the cooldown elapses in which a cooldown request may
+/// be executed. Zero means requests never expire.
+///
+/// # Returns
+/// Execution window (u64) in seconds. Returns default if not set.
+#[must_use]
+pub fn get_cooldown_execution_window_secs(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&ParameterKey::CooldownExecutionWindowSecs)
+        .unwrap_or(DEFAULT_COOLDOWN_EXECUTION_WINDOW_SECS)
+}
+
 /// Get the bronze tier threshold in token units.
 ///
 /// # Returns
@@ -208,6 +250,54 @@ pub fn get_platinum_threshold(e: &Env) -> i128 {
         .unwrap_or(DEFAULT_PLATINUM_THRESHOLD)
 }
 
+/// Get the minimum bond amount in token units.
+///
+/// # Returns
+/// Minimum amount (i128). Returns default if not set.
+#[must_use]
+pub fn get_min_bond_amount(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&ParameterKey::MinBondAmount)
+        .unwrap_or(DEFAULT_MIN_BOND_AMOUNT)
+}
+
+/// Get the maximum bond amount in token units.
+///
+/// # Returns
+/// Maximum amount (i128). Returns default if not set.
+#[must_use]
+pub fn get_max_bond_amount(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get(&ParameterKey::MaxBondAmount)
+        .unwrap_or(DEFAULT_MAX_BOND_AMOUNT)
+}
+
+/// Get the minimum bond duration in seconds.
+///
+/// # Returns
+/// Minimum duration (u64) in seconds. Returns default if not set.
+#[must_use]
+pub fn get_min_bond_duration(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&ParameterKey::MinBondDuration)
+        .unwrap_or(DEFAULT_MIN_BOND_DURATION)
+}
+
+/// Get the maximum bond duration in seconds.
+///
+/// # Returns
+/// Maximum duration (u64) in seconds. Returns default if not set.
+#[must_use]
+pub fn get_max_bond_duration(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get(&ParameterKey::MaxBondDuration)
+        .unwrap_or(DEFAULT_MAX_BOND_DURATION)
+}
+
 // ============================================================================
 // Parameter Setters (Governance-Only)
 // ============================================================================
@@ -360,6 +450,46 @@ pub fn set_slash_cooldown_secs(e: &Env, admin: &Address, value: u64) {
     );
 }
 
+/// Set the cooldown execution window. Governance-only.
+///
+/// Once a cooldown has elapsed, the request must be executed within this many
+/// seconds or it expires. Zero disables expiry.
+///
+/// # Arguments
+/// * `e` - Soroban environment
+/// * `admin` - Governance address (must be contract admin)
+/// * `value` - New execution window in seconds
+///
+/// # Bounds
+/// Must be between MIN_COOLDOWN_EXECUTION_WINDOW_SECS and MAX_COOLDOWN_EXECUTION_WINDOW_SECS (0-30 days)
+///
+/// # Panics
+/// - "not admin" if caller is not the contract admin
+/// - "cooldown_execution_window_secs out of bounds" if value < min or value > max
+///
+/// # Events
+/// Emits `parameter_changed` event with old and new values
+pub fn set_cooldown_execution_window_secs(e: &Env, admin: &Address, value: u64) {
+    validate_admin(e, admin);
+
+    if !(MIN_COOLDOWN_EXECUTION_WINDOW_SECS..=MAX_COOLDOWN_EXECUTION_WINDOW_SECS).contains(&value) {
+        panic!("cooldown_execution_window_secs out of bounds");
+    }
+
+    let old_value = get_cooldown_execution_window_secs(e);
+    e.storage()
+        .instance()
+        .set(&ParameterKey::CooldownExecutionWindowSecs, &value);
+
+    emit_parameter_changed(
+        e,
+        "cooldown_execution_window_secs",
+        old_value as i128,
+        value as i128,
+        admin,
+    );
+}
+
 /// Set the bronze tier threshold. Governance-only.
 ///
 /// # Arguments
@@ -484,11 +614,147 @@ pub fn set_platinum_threshold(e: &Env, admin: &Address, value: i128) {
     emit_parameter_changed(e, "platinum_threshold", old_value, value, admin);
 }
 
+/// Set the minimum bond amount. Governance-only.
+///
+/// # Arguments
+/// * `e` - Soroban environment
+/// * `admin` - Governance address (must be contract admin)
+/// * `value` - New minimum amount in token units
+///
+/// # Bounds
+/// Must be between MIN_BOND_AMOUNT_FLOOR and the current maximum bond amount
+///
+/// # Panics
+/// - "not admin" if caller is not the contract admin
+/// - "min_bond_amount out of bounds" if value < floor or value > current max
+///
+/// # Events
+/// Emits `parameter_changed` event with old and new values
+pub fn set_min_bond_amount(e: &Env, admin: &Address, value: i128) {
+    validate_admin(e, admin);
+
+    if value < MIN_BOND_AMOUNT_FLOOR || value > get_max_bond_amount(e) {
+        panic!("min_bond_amount out of bounds");
+    }
+
+    let old_value = get_min_bond_amount(e);
+    e.storage()
+        .instance()
+        .set(&ParameterKey::MinBondAmount, &value);
+
+    emit_parameter_changed(e, "min_bond_amount", old_value, value, admin);
+}
+
+/// Set the maximum bond amount. Governance-only.
+///
+/// # Arguments
+/// * `e` - Soroban environment
+/// * `admin` - Governance address (must be contract admin)
+/// * `value` - New maximum amount in token units
+///
+/// # Bounds
+/// Must be positive and at least the current minimum bond amount
+///
+/// # Panics
+/// - "not admin" if caller is not the contract admin
+/// - "max_bond_amount out of bounds" if value <= 0 or value < current min
+///
+/// # Events
+/// Emits `parameter_changed` event with old and new values
+pub fn set_max_bond_amount(e: &Env, admin: &Address, value: i128) {
+    validate_admin(e, admin);
+
+    if value <= 0 || value < get_min_bond_amount(e) {
+        panic!("max_bond_amount out of bounds");
+    }
+
+    let old_value = get_max_bond_amount(e);
+    e.storage()
+        .instance()
+        .set(&ParameterKey::MaxBondAmount, &value);
+
+    emit_parameter_changed(e, "max_bond_amount", old_value, value, admin);
+}
+
+/// Set the minimum bond duration. Governance-only.
+///
+/// # Arguments
+/// * `e` - Soroban environment
+/// * `admin` - Governance address (must be contract admin)
+/// * `value` - New minimum duration in seconds
+///
+/// # Bounds
+/// Must be between MIN_BOND_DURATION_FLOOR (1 hour) and the current maximum bond duration
+///
+/// # Panics
+/// - "not admin" if caller is not the contract admin
+/// - "min_bond_duration out of bounds" if value < floor or value > current max
+///
+/// # Events
+/// Emits `parameter_changed` event with old and new values
+pub fn set_min_bond_duration(e: &Env, admin: &Address, value: u64) {
+    validate_admin(e, admin);
+
+    if value < MIN_BOND_DURATION_FLOOR || value > get_max_bond_duration(e) {
+        panic!("min_bond_duration out of bounds");
+    }
+
+    let old_value = get_min_bond_duration(e);
+    e.storage()
+        .instance()
+        .set(&ParameterKey::MinBondDuration, &value);
+
+    emit_parameter_changed(
+        e,
+        "min_bond_duration",
+        old_value as i128,
+        value as i128,
+        admin,
+    );
+}
+
+/// Set the maximum bond duration. Governance-only.
+///
+/// # Arguments
+/// * `e` - Soroban environment
+/// * `admin` - Governance address (must be contract admin)
+/// * `value` - New maximum duration in seconds
+///
+/// # Bounds
+/// Must be between the current minimum bond duration and MAX_BOND_DURATION_CEILING (10 years)
+///
+/// # Panics
+/// - "not admin" if caller is not the contract admin
+/// - "max_bond_duration out of bounds" if value < current min or value > ceiling
+///
+/// # Events
+/// Emits `parameter_changed` event with old and new values
+pub fn set_max_bond_duration(e: &Env, admin: &Address, value: u64) {
+    validate_admin(e, admin);
+
+    if value < get_min_bond_duration(e) || value > MAX_BOND_DURATION_CEILING {
+        panic!("max_bond_duration out of bounds");
+    }
+
+    let old_value = get_max_bond_duration(e);
+    e.storage()
+        .instance()
+        .set(&ParameterKey::MaxBondDuration, &value);
+
+    emit_parameter_changed(
+        e,
+        "max_bond_duration",
+        old_value as i128,
+        value as i128,
+        admin,
+    );
+}
+
 // ============================================================================
 // Internal Helpers
 // ============================================================================
 
-/// Validates that the caller is the authorized admin.
+/// Validates that the caller is the authorized admin and has signed.
 ///
 /// # Arguments
 /// * `e` - Soroban environment
@@ -497,6 +763,7 @@ pub fn set_platinum_threshold(e: &Env, admin: &Address, value: i128) {
 /// # Panics
 /// - "not initialized" if contract not initialized
 /// - "not admin" if caller is not the stored admin address
+/// - If `caller` has not authorized the invocation
 fn validate_admin(e: &Env, caller: &Address) {
     let stored_admin: Address = e
         .storage()
@@ -506,6 +773,7 @@ fn validate_admin(e: &Env, caller: &Address) {
     if caller != &stored_admin {
         panic!("not admin");
     }
+    caller.require_auth();
 }
 
 /// Emits a parameter change event for off-chain tracking and auditing.
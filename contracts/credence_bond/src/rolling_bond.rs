@@ -1,9 +1,22 @@
 //! Rolling Bond Type
 //!
 //! Auto-renews at period end unless withdrawal was requested with notice.
-//! Tracks withdrawal request and notice period for scoring.
+//! Tracks withdrawal request and notice period for scoring, and how many periods
+//! each identity's bond has rolled through.
 
-use crate::IdentityBond;
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{DataKey, IdentityBond};
+
+/// Renewal history for a rolling bond, kept beside the bond record.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RenewalInfo {
+    /// Whole periods the bond has rolled through.
+    pub renewal_count: u32,
+    /// Ledger timestamp of the latest renewal (0 = never renewed).
+    pub last_renewed_at: u64,
+}
 
 /// Returns true if the bond has passed its period end (bond_start + bond_duration).
 #[must_use]
@@ -26,9 +39,64 @@ pub fn can_withdraw_after_notice(
     now >= notice_end
 }
 
+/// Remaining and total time used to prorate an early exit from a rolling bond.
+/// The period end is meaningless for a bond that keeps renewing, so the penalty is
+/// measured against the notice period: the time left on a pending request, or the
+/// whole notice period if none was made.
+#[must_use]
+pub fn early_exit_window(
+    now: u64,
+    withdrawal_requested_at: u64,
+    notice_period_duration: u64,
+) -> (u64, u64) {
+    if withdrawal_requested_at == 0 {
+        return (notice_period_duration, notice_period_duration);
+    }
+    let notice_end = withdrawal_requested_at.saturating_add(notice_period_duration);
+    (notice_end.saturating_sub(now), notice_period_duration)
+}
+
+/// Whole periods elapsed since `bond_start`. A zero duration counts as one period.
+#[must_use]
+pub fn elapsed_periods(now: u64, bond_start: u64, bond_duration: u64) -> u32 {
+    if bond_duration == 0 {
+        return 1;
+    }
+    let periods = now.saturating_sub(bond_start) / bond_duration;
+    u32::try_from(periods).unwrap_or(u32::MAX)
+}
+
+#[must_use]
+pub fn get_renewal_info(e: &Env, identity: &Address) -> RenewalInfo {
+    e.storage()
+        .persistent()
+        .get(&DataKey::RenewalInfo(identity.clone()))
+        .unwrap_or(RenewalInfo {
+            renewal_count: 0,
+            last_renewed_at: 0,
+        })
+}
+
+/// Forget the renewal history, e.g. when a fresh bond is created.
+pub fn clear_renewal_info(e: &Env, identity: &Address) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::RenewalInfo(identity.clone()));
+}
+
 /// Advance bond to a new period (set bond_start to now, keep duration and rolling flag).
-/// Call when period has ended and bond is rolling.
-pub fn apply_renewal(bond: &mut IdentityBond, new_start: u64) {
+/// Call when period has ended and bond is rolling. The renewal count advances by every
+/// whole period that elapsed since the last renewal, so late calls catch up.
+pub fn apply_renewal(e: &Env, bond: &mut IdentityBond, new_start: u64) -> RenewalInfo {
+    let periods = elapsed_periods(new_start, bond.bond_start, bond.bond_duration);
+    let mut info = get_renewal_info(e, &bond.identity);
+    info.renewal_count = info.renewal_count.saturating_add(periods);
+    info.last_renewed_at = new_start;
+    e.storage()
+        .persistent()
+        .set(&DataKey::RenewalInfo(bond.identity.clone()), &info);
+
     bond.bond_start = new_start;
     bond.withdrawal_requested_at = 0; // reset withdrawal request on renewal
+    info
 }
@@ -187,7 +187,7 @@ fn test_u64_overflow_on_duration_extension() {
     client.create_bond(&identity, &1000, &86400_u64, &false, &0_u64);
 
     // Attempt to extend by u64::MAX, which should overflow
-    client.extend_duration(&u64::MAX);
+    client.extend_duration(&identity, &u64::MAX);
 }
 
 #[test]
@@ -231,7 +231,7 @@ fn test_u64_large_duration_extension() {
     assert_eq!(bond.bond_duration, duration);
 
     // Extend with another duration (should succeed as sum doesn't overflow)
-    let bond = client.extend_duration(&86400_u64);
+    let bond = client.extend_duration(&identity, &86400_u64);
     assert_eq!(bond.bond_duration, duration + 86400);
 }
 
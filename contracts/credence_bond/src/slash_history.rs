@@ -1,9 +1,24 @@
 use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
 
+/// Number of most recent slash records bumped by `extend_ttl`.
+pub const RECENT_RECORDS_BUMPED: u32 = 20;
+
+/// Maximum number of slash records bumped by one `extend_ttl_range` call.
+pub const MAX_TTL_RANGE: u32 = 50;
+
+/// What was slashed: the bond itself or an attester's stake.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SlashKind {
+    Bond,
+    Attester,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SlashRecord {
     pub identity: Address,
+    pub kind: SlashKind,
     pub slash_amount: i128,
     pub reason: Symbol,
     pub timestamp: u64,
@@ -21,6 +36,7 @@ pub enum SlashStorageKey {
 pub fn append_slash_history(
     e: &Env,
     identity: &Address,
+    kind: SlashKind,
     slash_amount: i128,
     reason: Symbol,
     total_slashed_after: i128,
@@ -31,6 +47,7 @@ pub fn append_slash_history(
 
     let record = SlashRecord {
         identity: identity.clone(),
+        kind,
         slash_amount,
         reason,
         timestamp: e.ledger().timestamp(),
@@ -44,6 +61,34 @@ pub fn append_slash_history(
     e.storage().persistent().set(&count_key, &count);
 }
 
+/// Bump the TTL of an identity's slash counter and its latest
+/// `RECENT_RECORDS_BUMPED` records. Older records are kept alive with
+/// `extend_ttl_range`.
+pub fn extend_ttl(e: &Env, identity: &Address) {
+    let start = get_slash_count(e, identity).saturating_sub(RECENT_RECORDS_BUMPED);
+    extend_ttl_range(e, identity, start, RECENT_RECORDS_BUMPED);
+}
+
+/// Bump the TTL of an identity's slash counter and of up to `limit` records
+/// from index `start`, capped at `MAX_TTL_RANGE`. Returns the number of
+/// records bumped.
+pub fn extend_ttl_range(e: &Env, identity: &Address, start: u32, limit: u32) -> u32 {
+    let count_key = SlashStorageKey::SlashCount(identity.clone());
+    if !crate::ttl::extend_persistent(e, &count_key) {
+        return 0;
+    }
+    let end = start
+        .saturating_add(limit.min(MAX_TTL_RANGE))
+        .min(get_slash_count(e, identity));
+    let mut bumped = 0u32;
+    for i in start..end {
+        if crate::ttl::extend_persistent(e, &SlashStorageKey::SlashRecord(identity.clone(), i)) {
+            bumped += 1;
+        }
+    }
+    bumped
+}
+
 #[must_use]
 pub fn get_slash_count(e: &Env, identity: &Address) -> u32 {
     let key = SlashStorageKey::SlashCount(identity.clone());
@@ -14,8 +14,22 @@
 //! - **Cumulative**: Multiple slashes accumulate (tracked in slashed_amount)
 //! - **Over-slash Protection**: Ensures slashed_amount never exceeds bonded_amount
 //! - **Withdrawals**: Affected by slashing (withdrawable = bonded - slashed)
+//!
+//! ## Attester Slashing
+//! `slash_attester_stake` penalizes an attester for a fraudulent attestation. The amount is
+//! clamped to the attester's current stake, recorded in slash history as
+//! `SlashKind::Attester`, and the deposited tokens backing it are sent to the fee treasury.
+
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use soroban_sdk::token::TokenClient;
+
+use crate::slash_history;
+use crate::weighted_attestation;
+use crate::SlashParams;
 
-use soroban_sdk::{Address, Env, Symbol};
+/// Maximum number of items accepted by a single `slash_batch` call.
+pub const MAX_SLASH_BATCH_SIZE: u32 = 20;
 
 /// Storage key for tracking accumulated slashed funds (for treasury transfer purposes).
 /// Not currently used for fund transfers in this implementation, but reserved for future use.
@@ -124,6 +138,89 @@ pub fn slash_bond(e: &Env, admin: &Address, amount: i128) -> crate::IdentityBond
     bond
 }
 
+/// NatSpec-style: Slash several items atomically, each with its own amount and reason.
+///
+/// Every item is validated before any state is written, so a single invalid entry
+/// aborts the whole batch. Each applied item appends one slash-history record and
+/// emits a `bond_slashed` event; a `slash_batch` summary event closes the batch.
+///
+/// # Arguments
+/// * `e` - Soroban environment
+/// * `admin` - Address claiming admin authority (must authorize)
+/// * `items` - Slash entries; `identity` must be the bonded identity of this contract
+///
+/// # Returns
+/// Updated `IdentityBond` after all items are applied
+///
+/// # Panics
+/// - "not admin" if caller is not the contract admin
+/// - "empty slash batch" / "slash batch too large" on size violations
+/// - "no bond for identity" if an item targets another identity
+/// - "slash amount must be positive" for zero or negative amounts
+/// - "slash exceeds available balance" if the batch total exceeds bonded - slashed
+pub fn slash_batch(e: &Env, admin: &Address, items: &Vec<SlashParams>) -> crate::IdentityBond {
+    admin.require_auth();
+    validate_admin(e, admin);
+
+    if items.is_empty() {
+        panic!("empty slash batch");
+    }
+    if items.len() > MAX_SLASH_BATCH_SIZE {
+        panic!("slash batch too large");
+    }
+
+    let key = crate::DataKey::Bond;
+    let mut bond = e
+        .storage()
+        .instance()
+        .get::<_, crate::IdentityBond>(&key)
+        .unwrap_or_else(|| panic!("no bond"));
+
+    let available = get_available_balance(bond.bonded_amount, bond.slashed_amount);
+
+    // Validate every item before applying any of them.
+    let mut batch_total: i128 = 0;
+    for item in items.iter() {
+        if item.identity != bond.identity {
+            panic!("no bond for identity");
+        }
+        if item.amount <= 0 {
+            panic!("slash amount must be positive");
+        }
+        batch_total = batch_total
+            .checked_add(item.amount)
+            .expect("slashing caused overflow");
+    }
+    if batch_total > available {
+        panic!("slash exceeds available balance");
+    }
+
+    for item in items.iter() {
+        bond.slashed_amount = bond
+            .slashed_amount
+            .checked_add(item.amount)
+            .expect("slashing caused overflow");
+        slash_history::append_slash_history(
+            e,
+            &item.identity,
+            slash_history::SlashKind::Bond,
+            item.amount,
+            item.reason.clone(),
+            bond.slashed_amount,
+        );
+        emit_slashing_event(e, &item.identity, item.amount, bond.slashed_amount);
+    }
+
+    e.storage().instance().set(&key, &bond);
+
+    e.events().publish(
+        (Symbol::new(e, "slash_batch"),),
+        (items.len(), batch_total, bond.slashed_amount),
+    );
+
+    bond
+}
+
 /// NatSpec-style: Reverts slashing (reduces slashed amount). Admin only.
 ///
 /// Used for correcting mistaken slashes or appeals.
@@ -249,6 +346,60 @@ pub fn initialize_slashed_pool(e: &Env) {
         .set(&Symbol::new(e, KEY_SLASHED_FUNDS_POOL), &0_i128);
 }
 
+/// NatSpec-style: Slashes an attester's stake and sends the backing tokens to the treasury.
+///
+/// Authorization and governance approval are enforced by the caller.
+///
+/// # Arguments
+/// * `e` - Soroban environment
+/// * `attester` - Attester being penalized
+/// * `amount` - Requested slash amount; clamped to the attester's stake
+/// * `reason` - Reason recorded in slash history
+///
+/// # Returns
+/// The amount actually slashed (after clamping).
+///
+/// # Panics
+/// - "slash amount must be positive" for zero or negative amounts
+/// - "treasury not set" if no fee treasury is configured
+pub fn slash_attester_stake(e: &Env, attester: &Address, amount: i128, reason: Symbol) -> i128 {
+    if amount <= 0 {
+        panic!("slash amount must be positive");
+    }
+    let treasury = crate::fees::get_config(e)
+        .0
+        .unwrap_or_else(|| panic!("treasury not set"));
+
+    let (slashed, backed) = weighted_attestation::debit_slash(e, attester, amount);
+
+    let total_after = slash_history::get_total_slashed_from_history(e, attester)
+        .checked_add(slashed)
+        .expect("slashing caused overflow");
+    slash_history::append_slash_history(
+        e,
+        attester,
+        slash_history::SlashKind::Attester,
+        slashed,
+        reason.clone(),
+        total_after,
+    );
+
+    if backed > 0 {
+        let token: Address = e
+            .storage()
+            .instance()
+            .get(&crate::DataKey::Token)
+            .unwrap_or_else(|| panic!("token not set"));
+        TokenClient::new(e, &token).transfer(&e.current_contract_address(), &treasury, &backed);
+    }
+
+    e.events().publish(
+        (Symbol::new(e, "attester_slashed"), attester.clone()),
+        (slashed, reason, treasury),
+    );
+    slashed
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -15,3 +15,28 @@ fn test_create_bond() {
     assert_eq!(bond.slashed_amount, 0);
     assert_eq!(bond.identity, identity);
 }
+
+#[test]
+fn test_get_contract_info() {
+    let e = Env::default();
+    let (client, admin, _identity, token, _bond_id) = test_helpers::setup_with_token(&e);
+
+    let info = client.get_contract_info();
+    assert_eq!(info.name, Symbol::new(&e, "credence_bond"));
+    assert_eq!(info.version, CONTRACT_VERSION);
+    assert_eq!(info.token, Some(token));
+    assert_eq!(info.admin, admin);
+}
+
+#[test]
+fn test_get_contract_info_without_token() {
+    let e = Env::default();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(&admin);
+
+    let info = client.get_contract_info();
+    assert_eq!(info.token, None);
+    assert_eq!(info.admin, admin);
+}
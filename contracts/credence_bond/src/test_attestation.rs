@@ -10,8 +10,8 @@
 //! 7. Edge cases and boundary conditions
 
 use crate::*;
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{Env, String};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{vec, BytesN, Env, IntoVal, String, Symbol, TryFromVal};
 
 // ============================================================================
 // ATTESTER REGISTRATION & AUTHORIZATION TESTS
@@ -114,12 +114,18 @@ fn test_add_attestation_basic() {
     let data = String::from_str(&e, "verified identity");
 
     let nonce = client.get_nonce(&attester);
-    let att = client.add_attestation(&attester, &subject, &data, &nonce);
+    let att = client.add_attestation(
+        &attester,
+        &subject,
+        &data,
+        &AttestationCategory::Identity,
+        &nonce,
+    );
 
     assert_eq!(att.id, 0);
     assert_eq!(att.verifier, attester);
     assert_eq!(att.identity, subject);
-    assert_eq!(att.attestation_data, data);
+    assert_eq!(att.payload, AttestationPayload::Inline(data));
     assert!(!att.revoked);
     assert!(att.weight >= 1);
 }
@@ -141,11 +147,29 @@ fn test_add_multiple_attestations() {
     let subject = Address::generate(&e);
 
     let n0 = client.get_nonce(&attester);
-    let att1 = client.add_attestation(&attester, &subject, &String::from_str(&e, "att1"), &n0);
+    let att1 = client.add_attestation(
+        &attester,
+        &subject,
+        &String::from_str(&e, "att1"),
+        &AttestationCategory::Identity,
+        &n0,
+    );
     let n1 = client.get_nonce(&attester);
-    let att2 = client.add_attestation(&attester, &subject, &String::from_str(&e, "att2"), &n1);
+    let att2 = client.add_attestation(
+        &attester,
+        &subject,
+        &String::from_str(&e, "att2"),
+        &AttestationCategory::Identity,
+        &n1,
+    );
     let n2 = client.get_nonce(&attester);
-    let att3 = client.add_attestation(&attester, &subject, &String::from_str(&e, "att3"), &n2);
+    let att3 = client.add_attestation(
+        &attester,
+        &subject,
+        &String::from_str(&e, "att3"),
+        &AttestationCategory::Identity,
+        &n2,
+    );
 
     assert_eq!(att1.id, 0);
     assert_eq!(att2.id, 1);
@@ -171,8 +195,20 @@ fn test_add_attestation_different_attesters() {
     let subject = Address::generate(&e);
     let data = String::from_str(&e, "verified");
 
-    let attestation1 = client.add_attestation(&att1, &subject, &data, &client.get_nonce(&att1));
-    let attestation2 = client.add_attestation(&att2, &subject, &data, &client.get_nonce(&att2));
+    let attestation1 = client.add_attestation(
+        &att1,
+        &subject,
+        &data,
+        &AttestationCategory::Identity,
+        &client.get_nonce(&att1),
+    );
+    let attestation2 = client.add_attestation(
+        &att2,
+        &subject,
+        &data,
+        &AttestationCategory::Identity,
+        &client.get_nonce(&att2),
+    );
 
     assert_eq!(attestation1.verifier, att1);
     assert_eq!(attestation2.verifier, att2);
@@ -197,8 +233,20 @@ fn test_add_attestation_different_subjects() {
     let sub2 = Address::generate(&e);
     let data = String::from_str(&e, "verified");
 
-    let att1 = client.add_attestation(&attester, &sub1, &data, &client.get_nonce(&attester));
-    let att2 = client.add_attestation(&attester, &sub2, &data, &client.get_nonce(&attester));
+    let att1 = client.add_attestation(
+        &attester,
+        &sub1,
+        &data,
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
+    let att2 = client.add_attestation(
+        &attester,
+        &sub2,
+        &data,
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
 
     assert_eq!(att1.identity, sub1);
     assert_eq!(att2.identity, sub2);
@@ -221,8 +269,14 @@ fn test_add_attestation_empty_data() {
     let subject = Address::generate(&e);
     let data = String::from_str(&e, "");
 
-    let att = client.add_attestation(&attester, &subject, &data, &client.get_nonce(&attester));
-    assert_eq!(att.attestation_data, data);
+    let att = client.add_attestation(
+        &attester,
+        &subject,
+        &data,
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
+    assert_eq!(att.payload, AttestationPayload::Inline(data));
 }
 
 // ============================================================================
@@ -245,7 +299,13 @@ fn test_unauthorized_attester_rejected() {
     let subject = Address::generate(&e);
     let data = String::from_str(&e, "should fail");
 
-    client.add_attestation(&unauthorized, &subject, &data, &0u64);
+    client.add_attestation(
+        &unauthorized,
+        &subject,
+        &data,
+        &AttestationCategory::Identity,
+        &0u64,
+    );
 }
 
 #[test]
@@ -268,6 +328,7 @@ fn test_unregistered_attester_cannot_attest() {
         &attester,
         &subject,
         &String::from_str(&e, "ok"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
 
@@ -277,6 +338,7 @@ fn test_unregistered_attester_cannot_attest() {
         &attester,
         &subject,
         &String::from_str(&e, "should fail"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
 }
@@ -302,7 +364,13 @@ fn test_revoke_attestation() {
     let subject = Address::generate(&e);
     let data = String::from_str(&e, "to revoke");
 
-    let att = client.add_attestation(&attester, &subject, &data, &client.get_nonce(&attester));
+    let att = client.add_attestation(
+        &attester,
+        &subject,
+        &data,
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
     assert!(!att.revoked);
 
     client.revoke_attestation(&attester, &att.id, &client.get_nonce(&attester));
@@ -333,6 +401,7 @@ fn test_revoke_wrong_attester() {
         &att1,
         &subject,
         &String::from_str(&e, "test"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&att1),
     );
 
@@ -359,6 +428,7 @@ fn test_revoke_twice() {
         &attester,
         &subject,
         &String::from_str(&e, "test"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
 
@@ -406,8 +476,20 @@ fn test_duplicate_attestation_rejected() {
     let subject = Address::generate(&e);
     let data = String::from_str(&e, "duplicate");
 
-    let _att1 = client.add_attestation(&attester, &subject, &data, &client.get_nonce(&attester));
-    client.add_attestation(&attester, &subject, &data, &client.get_nonce(&attester));
+    let _att1 = client.add_attestation(
+        &attester,
+        &subject,
+        &data,
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
+    client.add_attestation(
+        &attester,
+        &subject,
+        &data,
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
 }
 
 #[test]
@@ -430,12 +512,14 @@ fn test_same_attester_different_data_gets_unique_id() {
         &attester,
         &subject,
         &String::from_str(&e, "data1"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
     let att2 = client.add_attestation(
         &attester,
         &subject,
         &String::from_str(&e, "data2"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
 
@@ -462,18 +546,21 @@ fn test_same_attester_multiple_for_subject() {
         &attester,
         &subject,
         &String::from_str(&e, "1"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
     client.add_attestation(
         &attester,
         &subject,
         &String::from_str(&e, "2"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
     client.add_attestation(
         &attester,
         &subject,
         &String::from_str(&e, "3"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
 
@@ -505,6 +592,7 @@ fn test_events_published() {
         &attester,
         &subject,
         &String::from_str(&e, "test"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
     client.revoke_attestation(&attester, &att.id, &client.get_nonce(&attester));
@@ -534,13 +622,19 @@ fn test_get_attestation() {
     let subject = Address::generate(&e);
     let data = String::from_str(&e, "get test");
 
-    let original = client.add_attestation(&attester, &subject, &data, &client.get_nonce(&attester));
+    let original = client.add_attestation(
+        &attester,
+        &subject,
+        &data,
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
     let retrieved = client.get_attestation(&original.id);
 
     assert_eq!(retrieved.id, original.id);
     assert_eq!(retrieved.verifier, original.verifier);
     assert_eq!(retrieved.identity, original.identity);
-    assert_eq!(retrieved.attestation_data, original.attestation_data);
+    assert_eq!(retrieved.payload, original.payload);
 }
 
 #[test]
@@ -578,18 +672,21 @@ fn test_get_subject_attestations() {
         &attester,
         &subject,
         &String::from_str(&e, "1"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
     client.add_attestation(
         &attester,
         &subject,
         &String::from_str(&e, "2"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
     client.add_attestation(
         &attester,
         &subject,
         &String::from_str(&e, "3"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
 
@@ -635,18 +732,21 @@ fn test_get_subject_attestations_different_subjects() {
         &attester,
         &sub1,
         &String::from_str(&e, "s1_1"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
     client.add_attestation(
         &attester,
         &sub1,
         &String::from_str(&e, "s1_2"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
     client.add_attestation(
         &attester,
         &sub2,
         &String::from_str(&e, "s2_1"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
 
@@ -681,6 +781,7 @@ fn test_self_attestation() {
         &address,
         &address,
         &String::from_str(&e, "self"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&address),
     );
 
@@ -706,6 +807,7 @@ fn test_timestamp_set() {
         &attester,
         &subject,
         &String::from_str(&e, "test"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
 
@@ -729,7 +831,13 @@ fn test_revoke_preserves_data() {
     let subject = Address::generate(&e);
     let data = String::from_str(&e, "preserved");
 
-    let original = client.add_attestation(&attester, &subject, &data, &client.get_nonce(&attester));
+    let original = client.add_attestation(
+        &attester,
+        &subject,
+        &data,
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
     client.revoke_attestation(&attester, &original.id, &client.get_nonce(&attester));
 
     let revoked = client.get_attestation(&original.id);
@@ -737,7 +845,7 @@ fn test_revoke_preserves_data() {
     assert_eq!(revoked.id, original.id);
     assert_eq!(revoked.verifier, original.verifier);
     assert_eq!(revoked.identity, original.identity);
-    assert_eq!(revoked.attestation_data, original.attestation_data);
+    assert_eq!(revoked.payload, original.payload);
     assert_eq!(revoked.timestamp, original.timestamp);
     assert!(revoked.revoked);
 }
@@ -770,30 +878,35 @@ fn test_complex_scenario() {
         &att1,
         &sub1,
         &String::from_str(&e, "a1s1_1"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&att1),
     );
     let a2 = client.add_attestation(
         &att1,
         &sub1,
         &String::from_str(&e, "a1s1_2"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&att1),
     );
     let _a3 = client.add_attestation(
         &att2,
         &sub1,
         &String::from_str(&e, "a2s1"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&att2),
     );
     let _a4 = client.add_attestation(
         &att2,
         &sub2,
         &String::from_str(&e, "a2s2"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&att2),
     );
     let _a5 = client.add_attestation(
         &att3,
         &sub2,
         &String::from_str(&e, "a3s2"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&att3),
     );
 
@@ -813,3 +926,522 @@ fn test_complex_scenario() {
     let not_revoked = client.get_attestation(&a2.id);
     assert!(!not_revoked.revoked);
 }
+
+// ============================================================================
+// HASHED PAYLOAD TESTS
+// ============================================================================
+
+fn setup_attester(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    client.initialize(&Address::generate(e));
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    (client, attester, Address::generate(e))
+}
+
+#[test]
+fn test_add_attestation_hashed_stores_hash_and_uri() {
+    let e = Env::default();
+    let (client, attester, subject) = setup_attester(&e);
+    let hash = BytesN::from_array(&e, &[7; 32]);
+    let uri = String::from_str(&e, "ipfs://bafy123");
+
+    let att = client.add_attestation_hashed(
+        &attester,
+        &subject,
+        &hash,
+        &uri,
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
+    assert_eq!(
+        client.get_attestation(&att.id).payload,
+        AttestationPayload::Hashed(hash, uri)
+    );
+}
+
+#[test]
+fn test_hashed_and_inline_share_indexes_and_counters() {
+    let e = Env::default();
+    let (client, attester, subject) = setup_attester(&e);
+    let inline = client.add_attestation(
+        &attester,
+        &subject,
+        &String::from_str(&e, "kyc"),
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
+    let hashed = client.add_attestation_hashed(
+        &attester,
+        &subject,
+        &BytesN::from_array(&e, &[1; 32]),
+        &String::from_str(&e, "https://x.io/a"),
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
+
+    assert_eq!(hashed.id, inline.id + 1);
+    let ids = client.get_subject_attestations(&subject);
+    assert_eq!(ids.len(), 2);
+    assert_eq!(ids.get(1).unwrap(), hashed.id);
+    assert_eq!(client.get_subject_attestation_count(&subject), 2);
+    assert_eq!(client.get_trust_score(&subject), 2);
+
+    client.revoke_attestation(&attester, &hashed.id, &client.get_nonce(&attester));
+    assert_eq!(client.get_subject_attestation_count(&subject), 1);
+    assert_eq!(client.get_trust_score(&subject), 1);
+}
+
+#[test]
+#[should_panic(expected = "duplicate attestation")]
+fn test_hashed_duplicate_ignores_uri() {
+    let e = Env::default();
+    let (client, attester, subject) = setup_attester(&e);
+    let hash = BytesN::from_array(&e, &[9; 32]);
+    client.add_attestation_hashed(
+        &attester,
+        &subject,
+        &hash,
+        &String::from_str(&e, "ipfs://a"),
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
+    client.add_attestation_hashed(
+        &attester,
+        &subject,
+        &hash,
+        &String::from_str(&e, "ipfs://b"),
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
+}
+
+#[test]
+fn test_hashed_readd_after_revoke() {
+    let e = Env::default();
+    let (client, attester, subject) = setup_attester(&e);
+    let hash = BytesN::from_array(&e, &[9; 32]);
+    let uri = String::from_str(&e, "ipfs://a");
+    let att = client.add_attestation_hashed(
+        &attester,
+        &subject,
+        &hash,
+        &uri,
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
+    client.revoke_attestation(&attester, &att.id, &client.get_nonce(&attester));
+    let again = client.add_attestation_hashed(
+        &attester,
+        &subject,
+        &hash,
+        &uri,
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
+    assert!(!again.revoked);
+}
+
+#[test]
+#[should_panic(expected = "attestation uri must not be empty")]
+fn test_hashed_rejects_empty_uri() {
+    let e = Env::default();
+    let (client, attester, subject) = setup_attester(&e);
+    client.add_attestation_hashed(
+        &attester,
+        &subject,
+        &BytesN::from_array(&e, &[2; 32]),
+        &String::from_str(&e, ""),
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
+}
+
+// ============================================================================
+// CATEGORY TESTS
+// ============================================================================
+
+#[test]
+fn test_attestation_stores_category() {
+    let e = Env::default();
+    let (client, attester, subject) = setup_attester(&e);
+    let att = client.add_attestation(
+        &attester,
+        &subject,
+        &String::from_str(&e, "on-time"),
+        &AttestationCategory::Performance,
+        &client.get_nonce(&attester),
+    );
+    assert_eq!(
+        client.get_attestation(&att.id).category,
+        AttestationCategory::Performance
+    );
+}
+
+#[test]
+fn test_same_data_different_category_allowed() {
+    let e = Env::default();
+    let (client, attester, subject) = setup_attester(&e);
+    let data = String::from_str(&e, "vouch");
+    let a = client.add_attestation(
+        &attester,
+        &subject,
+        &data,
+        &AttestationCategory::Endorsement,
+        &client.get_nonce(&attester),
+    );
+    let b = client.add_attestation(
+        &attester,
+        &subject,
+        &data,
+        &AttestationCategory::Custom(7),
+        &client.get_nonce(&attester),
+    );
+    assert_ne!(a.id, b.id);
+    // Same category with the same data is still a duplicate.
+    assert!(client
+        .try_add_attestation(
+            &attester,
+            &subject,
+            &data,
+            &AttestationCategory::Custom(7),
+            &client.get_nonce(&attester),
+        )
+        .is_err());
+}
+
+#[test]
+fn test_attestations_by_category_index() {
+    let e = Env::default();
+    let (client, attester, subject) = setup_attester(&e);
+    let kyc = client.add_attestation(
+        &attester,
+        &subject,
+        &String::from_str(&e, "kyc"),
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
+    let perf = client.add_attestation_hashed(
+        &attester,
+        &subject,
+        &BytesN::from_array(&e, &[3; 32]),
+        &String::from_str(&e, "ipfs://report"),
+        &AttestationCategory::Performance,
+        &client.get_nonce(&attester),
+    );
+
+    let identity_ids =
+        client.get_subject_attestations_by_cat(&subject, &AttestationCategory::Identity);
+    assert_eq!(identity_ids.len(), 1);
+    assert_eq!(identity_ids.get(0).unwrap(), kyc.id);
+    let perf_ids =
+        client.get_subject_attestations_by_cat(&subject, &AttestationCategory::Performance);
+    assert_eq!(perf_ids.len(), 1);
+    assert_eq!(perf_ids.get(0).unwrap(), perf.id);
+    assert_eq!(
+        client
+            .get_subject_attestations_by_cat(&subject, &AttestationCategory::Custom(1))
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_trust_score_by_category() {
+    let e = Env::default();
+    let (client, attester, subject) = setup_attester(&e);
+    client.add_attestation(
+        &attester,
+        &subject,
+        &String::from_str(&e, "kyc"),
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
+    let endorse = client.add_attestation(
+        &attester,
+        &subject,
+        &String::from_str(&e, "vouch"),
+        &AttestationCategory::Endorsement,
+        &client.get_nonce(&attester),
+    );
+    assert_eq!(client.get_trust_score(&subject), 2);
+    assert_eq!(
+        client.get_trust_score_by_category(&subject, &AttestationCategory::Identity),
+        1
+    );
+
+    client.revoke_attestation(&attester, &endorse.id, &client.get_nonce(&attester));
+    assert_eq!(client.get_trust_score(&subject), 1);
+    assert_eq!(
+        client.get_trust_score_by_category(&subject, &AttestationCategory::Endorsement),
+        0
+    );
+    assert_eq!(
+        client.get_trust_score_by_category(&subject, &AttestationCategory::Identity),
+        1
+    );
+}
+
+// ============================================================================
+// CONTEST TESTS
+// ============================================================================
+
+fn add_kyc(e: &Env, client: &CredenceBondClient<'_>, attester: &Address, subject: &Address) -> u64 {
+    client
+        .add_attestation(
+            attester,
+            subject,
+            &String::from_str(e, "kyc"),
+            &AttestationCategory::Identity,
+            &client.get_nonce(attester),
+        )
+        .id
+}
+
+fn add_kyc_other(e: &Env, client: &CredenceBondClient<'_>, attester: &Address, subject: &Address) {
+    client.add_attestation(
+        attester,
+        subject,
+        &String::from_str(e, "kyc-2"),
+        &AttestationCategory::Identity,
+        &client.get_nonce(attester),
+    );
+}
+
+/// Like `setup_attester`, but also returns the admin for contest resolution.
+fn setup_contest(e: &Env) -> (CredenceBondClient<'_>, Address, Address, Address) {
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(e, &contract_id);
+    let admin = Address::generate(e);
+    client.initialize(&admin);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    (client, admin, attester, Address::generate(e))
+}
+
+fn reason_hash(e: &Env) -> BytesN<32> {
+    BytesN::from_array(e, &[5; 32])
+}
+
+#[test]
+fn test_contest_excludes_from_trust_score() {
+    let e = Env::default();
+    let (client, attester, subject) = setup_attester(&e);
+    let id = add_kyc(&e, &client, &attester, &subject);
+    assert_eq!(client.get_trust_score(&subject), 1);
+
+    e.ledger().with_mut(|li| li.timestamp = 500);
+    let att = client.contest_attestation(&subject, &id, &reason_hash(&e));
+    assert!(att.contested);
+    assert_eq!(att.contested_at, 500);
+    assert_eq!(client.get_trust_score(&subject), 0);
+    assert_eq!(
+        client.get_trust_score_by_category(&subject, &AttestationCategory::Identity),
+        0
+    );
+    // Still listed and still active; only the score excludes it.
+    assert_eq!(client.get_subject_attestation_count(&subject), 1);
+}
+
+#[test]
+fn test_contest_emits_event() {
+    let e = Env::default();
+    let (client, attester, subject) = setup_attester(&e);
+    let id = add_kyc(&e, &client, &attester, &subject);
+    client.contest_attestation(&subject, &id, &reason_hash(&e));
+
+    let expected_topics = vec![
+        &e,
+        Symbol::new(&e, "attestation_contested").into_val(&e),
+        subject.into_val(&e),
+    ];
+    let (_, _, data) = e
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| topics == &expected_topics)
+        .expect("attestation_contested event");
+    let (event_id, verifier, hash) = <(u64, Address, BytesN<32>)>::try_from_val(&e, &data).unwrap();
+    assert_eq!(event_id, id);
+    assert_eq!(verifier, attester);
+    assert_eq!(hash, reason_hash(&e));
+}
+
+#[test]
+fn test_uncontest_restores_trust_score() {
+    let e = Env::default();
+    let (client, attester, subject) = setup_attester(&e);
+    let id = add_kyc(&e, &client, &attester, &subject);
+    client.contest_attestation(&subject, &id, &reason_hash(&e));
+    let att = client.uncontest_attestation(&subject, &id);
+    assert!(!att.contested);
+    assert_eq!(client.get_trust_score(&subject), 1);
+}
+
+#[test]
+#[should_panic(expected = "only attestation subject can contest")]
+fn test_contest_by_non_subject_rejected() {
+    let e = Env::default();
+    let (client, attester, subject) = setup_attester(&e);
+    let id = add_kyc(&e, &client, &attester, &subject);
+    client.contest_attestation(&attester, &id, &reason_hash(&e));
+}
+
+#[test]
+#[should_panic(expected = "only attestation subject can uncontest")]
+fn test_uncontest_by_non_subject_rejected() {
+    let e = Env::default();
+    let (client, attester, subject) = setup_attester(&e);
+    let id = add_kyc(&e, &client, &attester, &subject);
+    client.contest_attestation(&subject, &id, &reason_hash(&e));
+    client.uncontest_attestation(&attester, &id);
+}
+
+#[test]
+#[should_panic(expected = "attestation already contested")]
+fn test_contest_twice_rejected() {
+    let e = Env::default();
+    let (client, attester, subject) = setup_attester(&e);
+    let id = add_kyc(&e, &client, &attester, &subject);
+    client.contest_attestation(&subject, &id, &reason_hash(&e));
+    client.contest_attestation(&subject, &id, &reason_hash(&e));
+}
+
+#[test]
+fn test_resolve_contest_uphold_revokes() {
+    let e = Env::default();
+    let (client, admin, attester, subject) = setup_contest(&e);
+    let id = add_kyc(&e, &client, &attester, &subject);
+    client.contest_attestation(&subject, &id, &reason_hash(&e));
+
+    let att = client.resolve_contest(&admin, &id, &true);
+    assert!(att.revoked);
+    assert!(!att.contested);
+    assert_eq!(client.get_trust_score(&subject), 0);
+    assert_eq!(client.get_subject_attestation_count(&subject), 0);
+}
+
+#[test]
+fn test_resolve_contest_dismiss_restores() {
+    let e = Env::default();
+    let (client, admin, attester, subject) = setup_contest(&e);
+    let id = add_kyc(&e, &client, &attester, &subject);
+    client.contest_attestation(&subject, &id, &reason_hash(&e));
+
+    let att = client.resolve_contest(&admin, &id, &false);
+    assert!(!att.revoked);
+    assert!(!att.contested);
+    assert_eq!(client.get_trust_score(&subject), 1);
+}
+
+#[test]
+fn test_revoke_contested_does_not_double_count() {
+    let e = Env::default();
+    let (client, attester, subject) = setup_attester(&e);
+    let id = add_kyc(&e, &client, &attester, &subject);
+    add_kyc_other(&e, &client, &attester, &subject);
+    client.contest_attestation(&subject, &id, &reason_hash(&e));
+    assert_eq!(client.get_trust_score(&subject), 1);
+
+    client.revoke_attestation(&attester, &id, &client.get_nonce(&attester));
+    assert_eq!(client.get_trust_score(&subject), 1);
+    assert!(!client.get_attestation(&id).contested);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_resolve_contest_non_admin() {
+    let e = Env::default();
+    let (client, attester, subject) = setup_attester(&e);
+    let id = add_kyc(&e, &client, &attester, &subject);
+    client.contest_attestation(&subject, &id, &reason_hash(&e));
+    client.resolve_contest(&attester, &id, &true);
+}
+
+#[test]
+#[should_panic(expected = "attestation not contested")]
+fn test_resolve_uncontested_rejected() {
+    let e = Env::default();
+    let (client, admin, attester, subject) = setup_contest(&e);
+    let id = add_kyc(&e, &client, &attester, &subject);
+    client.resolve_contest(&admin, &id, &false);
+}
+
+// ============================================================================
+// ATTESTER AUTHORIZATION STORE TESTS
+// ============================================================================
+
+#[test]
+fn test_register_unregister_single_store() {
+    let e = Env::default();
+    let (client, _admin, attester, subject) = setup_contest(&e);
+    client.unregister_attester(&attester);
+    assert!(!client.is_attester(&attester));
+
+    let result = client.try_add_attestation(
+        &attester,
+        &subject,
+        &String::from_str(&e, "kyc"),
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
+    assert!(result.is_err());
+    // The rejection comes from the verifier role check, which logs access_denied.
+    let denied = vec![&e, Symbol::new(&e, "access_denied").into_val(&e)];
+    assert!(e
+        .events()
+        .all()
+        .iter()
+        .any(|(_, topics, _)| topics == denied));
+
+    client.register_attester(&attester);
+    assert!(client.is_attester(&attester));
+    add_kyc(&e, &client, &attester, &subject);
+}
+
+#[test]
+fn test_legacy_attester_flag_grants_nothing_until_migrated() {
+    let e = Env::default();
+    let (client, admin, _attester, subject) = setup_contest(&e);
+    let legacy = Address::generate(&e);
+    e.as_contract(&client.address, || {
+        e.storage()
+            .instance()
+            .set(&DataKey::Attester(legacy.clone()), &true);
+    });
+    assert!(!client.is_attester(&legacy));
+    assert!(client
+        .try_add_attestation(
+            &legacy,
+            &subject,
+            &String::from_str(&e, "kyc"),
+            &AttestationCategory::Identity,
+            &0u64,
+        )
+        .is_err());
+
+    let stranger = Address::generate(&e);
+    let migrated =
+        client.migrate_legacy_attesters(&admin, &vec![&e, legacy.clone(), stranger.clone()]);
+    assert_eq!(migrated, 1);
+    assert!(client.is_attester(&legacy));
+    assert!(!client.is_attester(&stranger));
+    e.as_contract(&client.address, || {
+        assert!(!e
+            .storage()
+            .instance()
+            .has(&DataKey::Attester(legacy.clone())));
+    });
+    add_kyc(&e, &client, &legacy, &subject);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_migrate_legacy_attesters_non_admin() {
+    let e = Env::default();
+    let (client, _admin, attester, _subject) = setup_contest(&e);
+    client.migrate_legacy_attesters(&attester, &vec![&e, attester.clone()]);
+}
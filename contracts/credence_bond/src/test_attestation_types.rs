@@ -3,7 +3,10 @@
 #![cfg(test)]
 
 use crate::types::attestation::{DEFAULT_ATTESTATION_WEIGHT, MAX_ATTESTATION_WEIGHT};
-use crate::types::{Attestation, AttestationDedupKey};
+use crate::types::{
+    Attestation, AttestationCategory, AttestationDedupKey, AttestationPayload,
+    MAX_ATTESTATION_URI_LEN,
+};
 use soroban_sdk::testutils::Address as _;
 use soroban_sdk::{Env, String};
 
@@ -35,8 +38,11 @@ fn attestation_validate_accepts_valid() {
         identity: soroban_sdk::Address::generate(&e),
         timestamp: 0,
         weight: DEFAULT_ATTESTATION_WEIGHT,
-        attestation_data: String::from_str(&e, "x"),
+        category: AttestationCategory::Identity,
+        payload: AttestationPayload::Inline(String::from_str(&e, "x")),
         revoked: false,
+        contested: false,
+        contested_at: 0,
     };
     att.validate();
 }
@@ -51,8 +57,11 @@ fn attestation_validate_rejects_zero_weight() {
         identity: soroban_sdk::Address::generate(&e),
         timestamp: 0,
         weight: 0,
-        attestation_data: String::from_str(&e, "x"),
+        category: AttestationCategory::Identity,
+        payload: AttestationPayload::Inline(String::from_str(&e, "x")),
         revoked: false,
+        contested: false,
+        contested_at: 0,
     };
     att.validate();
 }
@@ -67,8 +76,11 @@ fn attestation_validate_rejects_over_max_weight() {
         identity: soroban_sdk::Address::generate(&e),
         timestamp: 0,
         weight: MAX_ATTESTATION_WEIGHT + 1,
-        attestation_data: String::from_str(&e, "x"),
+        category: AttestationCategory::Identity,
+        payload: AttestationPayload::Inline(String::from_str(&e, "x")),
         revoked: false,
+        contested: false,
+        contested_at: 0,
     };
     att.validate();
 }
@@ -85,8 +97,11 @@ fn attestation_is_active() {
         identity: identity.clone(),
         timestamp: 0,
         weight: DEFAULT_ATTESTATION_WEIGHT,
-        attestation_data: data,
+        category: AttestationCategory::Identity,
+        payload: AttestationPayload::Inline(data),
         revoked: false,
+        contested: false,
+        contested_at: 0,
     };
     assert!(att.is_active());
     let mut revoked = att.clone();
@@ -103,11 +118,13 @@ fn attestation_dedup_key_equality() {
     let k1 = AttestationDedupKey {
         verifier: v.clone(),
         identity: i.clone(),
+        category: AttestationCategory::Identity,
         attestation_data: d.clone(),
     };
     let k2 = AttestationDedupKey {
         verifier: v,
         identity: i,
+        category: AttestationCategory::Identity,
         attestation_data: d,
     };
     assert_eq!(k1, k2);
@@ -125,3 +142,18 @@ fn attestation_boundary_weight_max() {
 fn attestation_boundary_weight_min() {
     Attestation::validate_weight(1);
 }
+
+#[test]
+fn attestation_validate_uri_accepts_max_len() {
+    let e = Env::default();
+    let bytes = [b'a'; MAX_ATTESTATION_URI_LEN as usize];
+    Attestation::validate_uri(&String::from_bytes(&e, &bytes));
+}
+
+#[test]
+#[should_panic(expected = "attestation uri too long")]
+fn attestation_validate_uri_rejects_over_max_len() {
+    let e = Env::default();
+    let bytes = [b'a'; MAX_ATTESTATION_URI_LEN as usize + 1];
+    Attestation::validate_uri(&String::from_bytes(&e, &bytes));
+}
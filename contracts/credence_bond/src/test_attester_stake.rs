@@ -0,0 +1,204 @@
+//! Tests for attester stake deposits and withdrawals: token movement, the
+//! minimum-stake lock while attestations are active, and the admin override.
+
+#![cfg(test)]
+
+use crate::test_helpers;
+use crate::{AttestationCategory, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Env, String};
+
+struct Ctx<'a> {
+    client: CredenceBondClient<'a>,
+    admin: Address,
+    attester: Address,
+    token: Address,
+    contract_id: Address,
+}
+
+/// Registered attester holding 10_000 tokens.
+fn setup(e: &Env) -> Ctx<'_> {
+    let (client, admin, _identity, token, contract_id) = test_helpers::setup_with_token(e);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    StellarAssetClient::new(e, &token).mint(&attester, &10_000);
+    Ctx {
+        client,
+        admin,
+        attester,
+        token,
+        contract_id,
+    }
+}
+
+fn attest(e: &Env, c: &Ctx<'_>) -> u64 {
+    let subject = Address::generate(e);
+    c.client
+        .add_attestation(
+            &c.attester,
+            &subject,
+            &String::from_str(e, "kyc"),
+            &AttestationCategory::Identity,
+            &c.client.get_nonce(&c.attester),
+        )
+        .id
+}
+
+#[test]
+fn test_deposit_moves_tokens_and_sets_stake() {
+    let e = Env::default();
+    let c = setup(&e);
+    let token = TokenClient::new(&e, &c.token);
+    let contract_before = token.balance(&c.contract_id);
+
+    assert_eq!(c.client.deposit_attester_stake(&c.attester, &4_000), 4_000);
+    assert_eq!(c.client.deposit_attester_stake(&c.attester, &1_000), 5_000);
+
+    assert_eq!(token.balance(&c.attester), 5_000);
+    assert_eq!(token.balance(&c.contract_id), contract_before + 5_000);
+    assert_eq!(c.client.get_attester_stake(&c.attester), 5_000);
+    assert_eq!(c.client.get_attester_deposit(&c.attester), 5_000);
+}
+
+#[test]
+fn test_deposit_drives_attestation_weight() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client
+        .set_weight_config(&c.admin, &1_000_u32, &100_000_u32);
+    c.client.deposit_attester_stake(&c.attester, &5_000);
+
+    let subject = Address::generate(&e);
+    let att = c.client.add_attestation(
+        &c.attester,
+        &subject,
+        &String::from_str(&e, "kyc"),
+        &AttestationCategory::Identity,
+        &c.client.get_nonce(&c.attester),
+    );
+    assert_eq!(att.weight, 500);
+}
+
+#[test]
+fn test_withdraw_returns_tokens() {
+    let e = Env::default();
+    let c = setup(&e);
+    let token = TokenClient::new(&e, &c.token);
+    c.client.deposit_attester_stake(&c.attester, &4_000);
+
+    assert_eq!(c.client.withdraw_attester_stake(&c.attester, &1_500), 2_500);
+    assert_eq!(token.balance(&c.attester), 7_500);
+    assert_eq!(c.client.get_attester_deposit(&c.attester), 2_500);
+}
+
+#[test]
+#[should_panic(expected = "attester stake below minimum while attestations are active")]
+fn test_withdraw_below_minimum_blocked_with_active_attestation() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client.set_min_attester_stake(&c.admin, &3_000);
+    c.client.deposit_attester_stake(&c.attester, &4_000);
+    attest(&e, &c);
+    c.client.withdraw_attester_stake(&c.attester, &1_001);
+}
+
+#[test]
+fn test_withdraw_down_to_minimum_allowed_with_active_attestation() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client.set_min_attester_stake(&c.admin, &3_000);
+    c.client.deposit_attester_stake(&c.attester, &4_000);
+    attest(&e, &c);
+    assert_eq!(c.client.withdraw_attester_stake(&c.attester, &1_000), 3_000);
+}
+
+#[test]
+fn test_withdraw_all_after_revocation() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client.set_min_attester_stake(&c.admin, &3_000);
+    c.client.deposit_attester_stake(&c.attester, &4_000);
+    let id = attest(&e, &c);
+    assert!(c
+        .client
+        .try_withdraw_attester_stake(&c.attester, &4_000)
+        .is_err());
+
+    c.client
+        .revoke_attestation(&c.attester, &id, &c.client.get_nonce(&c.attester));
+    assert_eq!(c.client.withdraw_attester_stake(&c.attester, &4_000), 0);
+    assert_eq!(TokenClient::new(&e, &c.token).balance(&c.attester), 10_000);
+}
+
+#[test]
+#[should_panic(expected = "insufficient attester deposit")]
+fn test_admin_set_stake_is_not_withdrawable() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client
+        .admin_set_attester_stake(&c.admin, &c.attester, &1_000_000);
+    assert_eq!(c.client.get_attester_stake(&c.attester), 1_000_000);
+    c.client.withdraw_attester_stake(&c.attester, &1);
+}
+
+#[test]
+#[should_panic(expected = "amount must be positive")]
+fn test_deposit_rejects_zero() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client.deposit_attester_stake(&c.attester, &0);
+}
+
+#[test]
+fn test_deposit_requires_attester_auth() {
+    let e = Env::default();
+    let c = setup(&e);
+    e.set_auths(&[]);
+    assert!(c
+        .client
+        .try_deposit_attester_stake(&c.attester, &1_000)
+        .is_err());
+    assert_eq!(c.client.get_attester_stake(&c.attester), 0);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_set_min_attester_stake_non_admin() {
+    let e = Env::default();
+    let c = setup(&e);
+    let attacker = Address::generate(&e);
+    c.client.set_min_attester_stake(&attacker, &1);
+}
+
+#[test]
+fn test_attester_stake_admin_setters_require_auth() {
+    let e = Env::default();
+    let c = setup(&e);
+
+    e.set_auths(&[]);
+    assert!(c
+        .client
+        .try_admin_set_attester_stake(&c.admin, &c.attester, &1_000)
+        .is_err());
+    assert!(c.client.try_set_min_attester_stake(&c.admin, &1).is_err());
+    assert_eq!(c.client.get_attester_stake(&c.attester), 0);
+}
+
+#[test]
+fn test_attester_records_are_persistent_with_ttl() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client.deposit_attester_stake(&c.attester, &1_000);
+    attest(&e, &c);
+
+    let (deposit_ttl, count_ttl) = e.as_contract(&c.contract_id, || {
+        let storage = e.storage().persistent();
+        (
+            storage.get_ttl(&crate::DataKey::AttesterDeposit(c.attester.clone())),
+            storage.get_ttl(&crate::DataKey::AttesterActiveCount(c.attester.clone())),
+        )
+    });
+    assert!(deposit_ttl >= 518_400);
+    assert!(count_ttl >= 518_400);
+}
@@ -1,15 +1,16 @@
 //! Cooldown Window Tests
 //!
 //! Covers the full cooldown lifecycle: configuration, request, execution,
-//! cancellation, plus edge cases and authorization checks.
+//! cancellation, amount modification, expiry, plus edge cases and authorization checks.
 
 #![cfg(test)]
 
 use crate::cooldown;
 use crate::test_helpers;
 use crate::{CredenceBond, CredenceBondClient};
-use soroban_sdk::testutils::{Address as _, Ledger};
-use soroban_sdk::{Address, Env};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol, TryFromVal};
 
 fn setup(e: &Env) -> (CredenceBondClient<'_>, Address) {
     let contract_id = e.register_contract(None, CredenceBond);
@@ -205,15 +206,20 @@ fn test_execute_cooldown_withdrawal_after_period() {
     let e = Env::default();
     e.mock_all_auths();
     e.ledger().with_mut(|li| li.timestamp = 1000);
-    let (client, admin, identity) = setup_with_token(&e);
+    let (client, admin, identity, token, bond_id) = test_helpers::setup_with_token(&e);
+    let token = TokenClient::new(&e, &token);
     client.create_bond(&identity, &1000, &86400, &false, &0);
     client.set_cooldown_period(&admin, &100);
     client.request_cooldown_withdrawal(&identity, &400);
+    let identity_before = token.balance(&identity);
+    let contract_before = token.balance(&bond_id);
 
     // Advance time past the cooldown
     e.ledger().with_mut(|li| li.timestamp = 1101);
     let bond = client.execute_cooldown_withdrawal(&identity);
     assert_eq!(bond.bonded_amount, 600);
+    assert_eq!(token.balance(&identity), identity_before + 400);
+    assert_eq!(token.balance(&bond_id), contract_before - 400);
 }
 
 #[test]
@@ -267,6 +273,26 @@ fn test_execute_cooldown_with_zero_period() {
     assert_eq!(bond.bonded_amount, 700);
 }
 
+#[test]
+fn test_execute_cooldown_transfers_after_partial_slash() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin, identity, token, _bond_id) = test_helpers::setup_with_token(&e);
+    let token = TokenClient::new(&e, &token);
+    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.set_cooldown_period(&admin, &100);
+    client.request_cooldown_withdrawal(&identity, &300);
+    client.slash(&admin, &500);
+    let before = token.balance(&identity);
+
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    let bond = client.execute_cooldown_withdrawal(&identity);
+    assert_eq!(bond.bonded_amount, 700);
+    assert_eq!(bond.slashed_amount, 500);
+    assert_eq!(token.balance(&identity), before + 300);
+}
+
 #[test]
 #[should_panic(expected = "cooldown period has not elapsed")]
 fn test_execute_cooldown_too_early() {
@@ -312,6 +338,26 @@ fn test_execute_cooldown_balance_slashed_during_cooldown() {
     client.execute_cooldown_withdrawal(&identity);
 }
 
+#[test]
+fn test_execute_cooldown_rejected_moves_no_tokens() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin, identity, token, bond_id) = test_helpers::setup_with_token(&e);
+    let token = TokenClient::new(&e, &token);
+    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.set_cooldown_period(&admin, &100);
+    client.request_cooldown_withdrawal(&identity, &800);
+    client.slash(&admin, &500);
+    let identity_before = token.balance(&identity);
+    let contract_before = token.balance(&bond_id);
+
+    e.ledger().with_mut(|li| li.timestamp = 1101);
+    assert!(client.try_execute_cooldown_withdrawal(&identity).is_err());
+    assert_eq!(token.balance(&identity), identity_before);
+    assert_eq!(token.balance(&bond_id), contract_before);
+}
+
 // ---------------------------------------------------------------
 // Cancel cooldown
 // ---------------------------------------------------------------
@@ -360,6 +406,114 @@ fn test_execute_after_cancel() {
     client.execute_cooldown_withdrawal(&identity);
 }
 
+// ---------------------------------------------------------------
+// Modify cooldown request
+// ---------------------------------------------------------------
+
+#[test]
+fn test_modify_cooldown_lower_keeps_timer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin, identity) = setup_with_token(&e);
+    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.set_cooldown_period(&admin, &100);
+    client.request_cooldown_withdrawal(&identity, &500);
+
+    e.ledger().with_mut(|li| li.timestamp = 1050);
+    let req = client.modify_cooldown_request(&identity, &300);
+    assert_eq!(req.amount, 300);
+    assert_eq!(req.requested_at, 1000);
+
+    // Original deadline still applies.
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    let bond = client.execute_cooldown_withdrawal(&identity);
+    assert_eq!(bond.bonded_amount, 700);
+}
+
+#[test]
+fn test_modify_cooldown_raise_resets_timer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin, identity) = setup_with_token(&e);
+    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.set_cooldown_period(&admin, &100);
+    client.request_cooldown_withdrawal(&identity, &500);
+
+    e.ledger().with_mut(|li| li.timestamp = 1050);
+    let req = client.modify_cooldown_request(&identity, &800);
+    assert_eq!(req.amount, 800);
+    assert_eq!(req.requested_at, 1050);
+
+    // The original deadline no longer suffices.
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    assert!(client.try_execute_cooldown_withdrawal(&identity).is_err());
+
+    e.ledger().with_mut(|li| li.timestamp = 1150);
+    let bond = client.execute_cooldown_withdrawal(&identity);
+    assert_eq!(bond.bonded_amount, 200);
+}
+
+#[test]
+fn test_modify_cooldown_emits_event() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin, identity) = setup_with_token(&e);
+    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.set_cooldown_period(&admin, &100);
+    client.request_cooldown_withdrawal(&identity, &500);
+    client.modify_cooldown_request(&identity, &400);
+
+    let topics = vec![&e, Symbol::new(&e, "cooldown_modified").into_val(&e)];
+    let (_, _, data) = e
+        .events()
+        .all()
+        .iter()
+        .find(|(_, t, _)| t == &topics)
+        .expect("cooldown_modified event");
+    let (requester, old_amount, new_amount) =
+        <(Address, i128, i128)>::try_from_val(&e, &data).unwrap();
+    assert_eq!(requester, identity);
+    assert_eq!(old_amount, 500);
+    assert_eq!(new_amount, 400);
+}
+
+#[test]
+#[should_panic(expected = "amount exceeds available balance")]
+fn test_modify_cooldown_exceeds_balance() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin, identity) = setup_with_token(&e);
+    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.set_cooldown_period(&admin, &100);
+    client.request_cooldown_withdrawal(&identity, &500);
+    client.slash(&admin, &400);
+    client.modify_cooldown_request(&identity, &601);
+}
+
+#[test]
+#[should_panic(expected = "no cooldown request")]
+fn test_modify_cooldown_without_request() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, _admin, identity) = setup_with_token(&e);
+    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.modify_cooldown_request(&identity, &100);
+}
+
+#[test]
+#[should_panic(expected = "amount must be positive")]
+fn test_modify_cooldown_zero_amount() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, admin, identity) = setup_with_token(&e);
+    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.set_cooldown_period(&admin, &100);
+    client.request_cooldown_withdrawal(&identity, &500);
+    client.modify_cooldown_request(&identity, &0);
+}
+
 // ---------------------------------------------------------------
 // Query
 // ---------------------------------------------------------------
@@ -391,6 +545,103 @@ fn test_get_cooldown_request_none() {
     client.get_cooldown_request(&identity);
 }
 
+// ---------------------------------------------------------------
+// Expiry of stale requests
+// ---------------------------------------------------------------
+
+/// Bond of 1000 with a 100s cooldown and a 50s execution window; a request for
+/// 500 is made at t=1000, so it is executable in [1100, 1150].
+fn setup_expiring(e: &Env) -> (CredenceBondClient<'_>, Address) {
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin, identity) = setup_with_token(e);
+    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.set_cooldown_period(&admin, &100);
+    client.set_cooldown_exec_window_secs(&admin, &50);
+    client.request_cooldown_withdrawal(&identity, &500);
+    (client, identity)
+}
+
+#[test]
+fn test_execute_cooldown_at_window_end() {
+    let e = Env::default();
+    let (client, identity) = setup_expiring(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1150);
+    let bond = client.execute_cooldown_withdrawal(&identity);
+    assert_eq!(bond.bonded_amount, 500);
+}
+
+#[test]
+#[should_panic(expected = "cooldown request expired")]
+fn test_execute_cooldown_after_window_panics() {
+    let e = Env::default();
+    let (client, identity) = setup_expiring(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1151);
+    client.execute_cooldown_withdrawal(&identity);
+}
+
+#[test]
+fn test_zero_window_never_expires() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin, identity) = setup_with_token(&e);
+    client.create_bond(&identity, &1000, &86400, &false, &0);
+    client.set_cooldown_period(&admin, &100);
+    client.request_cooldown_withdrawal(&identity, &500);
+
+    e.ledger().with_mut(|li| li.timestamp = 1_000_000);
+    assert!(client
+        .try_expire_cooldown_request(&admin, &identity)
+        .is_err());
+    let bond = client.execute_cooldown_withdrawal(&identity);
+    assert_eq!(bond.bonded_amount, 500);
+}
+
+#[test]
+fn test_expire_cooldown_request_by_anyone() {
+    let e = Env::default();
+    let (client, identity) = setup_expiring(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1151);
+
+    let keeper = Address::generate(&e);
+    client.expire_cooldown_request(&keeper, &identity);
+
+    let topics = vec![&e, Symbol::new(&e, "cooldown_expired").into_val(&e)];
+    let (_, _, data) = e
+        .events()
+        .all()
+        .iter()
+        .find(|(_, t, _)| t == &topics)
+        .expect("cooldown_expired event");
+    let (requester, amount) = <(Address, i128)>::try_from_val(&e, &data).unwrap();
+    assert_eq!(requester, identity);
+    assert_eq!(amount, 500);
+
+    assert!(client.try_get_cooldown_request(&identity).is_err());
+    // The holder can start over with a fresh request.
+    let req = client.request_cooldown_withdrawal(&identity, &200);
+    assert_eq!(req.requested_at, 1151);
+}
+
+#[test]
+#[should_panic(expected = "cooldown request not expired")]
+fn test_expire_cooldown_request_within_window() {
+    let e = Env::default();
+    let (client, identity) = setup_expiring(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1150);
+    client.expire_cooldown_request(&identity, &identity);
+}
+
+#[test]
+#[should_panic(expected = "no cooldown request")]
+fn test_expire_cooldown_request_missing() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (client, _admin, identity) = setup_with_token(&e);
+    client.expire_cooldown_request(&identity, &identity);
+}
+
 // ---------------------------------------------------------------
 // Pure helper function tests
 // ---------------------------------------------------------------
@@ -442,6 +693,22 @@ fn test_can_withdraw_zero_period() {
     assert!(cooldown::can_withdraw(1000, 1000, 0));
 }
 
+#[test]
+fn test_is_expired_zero_window() {
+    assert!(!cooldown::is_expired(u64::MAX, 1000, 100, 0));
+}
+
+#[test]
+fn test_is_expired_boundary() {
+    assert!(!cooldown::is_expired(1150, 1000, 100, 50));
+    assert!(cooldown::is_expired(1151, 1000, 100, 50));
+}
+
+#[test]
+fn test_is_expired_no_request() {
+    assert!(!cooldown::is_expired(5000, 0, 100, 50));
+}
+
 #[test]
 fn test_saturating_add_no_overflow() {
     // u64::MAX as request_time + large period should not panic
@@ -19,6 +19,15 @@ fn setup(e: &Env) -> CredenceBondClient<'_> {
     client
 }
 
+/// Run `validate_bond_duration` inside a fresh contract context with default limits.
+fn validate(duration: u64) {
+    let e = Env::default();
+    let contract_id = e.register_contract(None, CredenceBond);
+    e.as_contract(&contract_id, || {
+        validation::validate_bond_duration(&e, duration);
+    });
+}
+
 // ────────────────────────────────────────────────────────────────
 // Unit tests for validate_bond_duration
 // ────────────────────────────────────────────────────────────────
@@ -26,67 +35,67 @@ fn setup(e: &Env) -> CredenceBondClient<'_> {
 /// Duration at the exact minimum boundary should pass.
 #[test]
 fn test_validate_duration_at_minimum() {
-    validation::validate_bond_duration(MIN_BOND_DURATION);
+    validate(MIN_BOND_DURATION);
 }
 
 /// Duration at the exact maximum boundary should pass.
 #[test]
 fn test_validate_duration_at_maximum() {
-    validation::validate_bond_duration(MAX_BOND_DURATION);
+    validate(MAX_BOND_DURATION);
 }
 
 /// Duration above minimum should pass.
 #[test]
 fn test_validate_duration_above_minimum() {
-    validation::validate_bond_duration(MIN_BOND_DURATION + 1);
+    validate(MIN_BOND_DURATION + 1);
 }
 
 /// Duration below maximum should pass.
 #[test]
 fn test_validate_duration_below_maximum() {
-    validation::validate_bond_duration(MAX_BOND_DURATION - 1);
+    validate(MAX_BOND_DURATION - 1);
 }
 
 /// Duration in the middle of the valid range should pass.
 #[test]
 fn test_validate_duration_mid_range() {
     // 30 days
-    validation::validate_bond_duration(2_592_000);
+    validate(2_592_000);
 }
 
 /// Zero duration must be rejected.
 #[test]
-#[should_panic(expected = "bond duration too short: minimum is 86400 seconds (1 day)")]
+#[should_panic(expected = "bond duration too short: minimum is 86400 seconds")]
 fn test_validate_duration_zero() {
-    validation::validate_bond_duration(0);
+    validate(0);
 }
 
 /// Duration one second below minimum must be rejected.
 #[test]
-#[should_panic(expected = "bond duration too short: minimum is 86400 seconds (1 day)")]
+#[should_panic(expected = "bond duration too short: minimum is 86400 seconds")]
 fn test_validate_duration_just_below_minimum() {
-    validation::validate_bond_duration(MIN_BOND_DURATION - 1);
+    validate(MIN_BOND_DURATION - 1);
 }
 
 /// Very small duration (1 second) must be rejected.
 #[test]
-#[should_panic(expected = "bond duration too short: minimum is 86400 seconds (1 day)")]
+#[should_panic(expected = "bond duration too short: minimum is 86400 seconds")]
 fn test_validate_duration_one_second() {
-    validation::validate_bond_duration(1);
+    validate(1);
 }
 
 /// Duration one second above maximum must be rejected.
 #[test]
-#[should_panic(expected = "bond duration too long: maximum is 31536000 seconds (365 days)")]
+#[should_panic(expected = "bond duration too long: maximum is 31536000 seconds")]
 fn test_validate_duration_just_above_maximum() {
-    validation::validate_bond_duration(MAX_BOND_DURATION + 1);
+    validate(MAX_BOND_DURATION + 1);
 }
 
 /// u64::MAX duration must be rejected.
 #[test]
-#[should_panic(expected = "bond duration too long: maximum is 31536000 seconds (365 days)")]
+#[should_panic(expected = "bond duration too long: maximum is 31536000 seconds")]
 fn test_validate_duration_u64_max() {
-    validation::validate_bond_duration(u64::MAX);
+    validate(u64::MAX);
 }
 
 // ────────────────────────────────────────────────────────────────
@@ -126,7 +135,7 @@ fn test_create_bond_typical_duration() {
 
 /// Bond creation with zero duration must be rejected.
 #[test]
-#[should_panic(expected = "bond duration too short: minimum is 86400 seconds (1 day)")]
+#[should_panic(expected = "bond duration too short: minimum is 86400 seconds")]
 fn test_create_bond_zero_duration_rejected() {
     let e = Env::default();
     e.mock_all_auths();
@@ -137,7 +146,7 @@ fn test_create_bond_zero_duration_rejected() {
 
 /// Bond creation with duration below minimum must be rejected.
 #[test]
-#[should_panic(expected = "bond duration too short: minimum is 86400 seconds (1 day)")]
+#[should_panic(expected = "bond duration too short: minimum is 86400 seconds")]
 fn test_create_bond_below_min_duration_rejected() {
     let e = Env::default();
     e.mock_all_auths();
@@ -154,7 +163,7 @@ fn test_create_bond_below_min_duration_rejected() {
 
 /// Bond creation with duration above maximum must be rejected.
 #[test]
-#[should_panic(expected = "bond duration too long: maximum is 31536000 seconds (365 days)")]
+#[should_panic(expected = "bond duration too long: maximum is 31536000 seconds")]
 fn test_create_bond_above_max_duration_rejected() {
     let e = Env::default();
     e.mock_all_auths();
@@ -182,7 +191,7 @@ fn test_create_rolling_bond_valid_duration() {
 
 /// Rolling bond creation with invalid duration must be rejected.
 #[test]
-#[should_panic(expected = "bond duration too short: minimum is 86400 seconds (1 day)")]
+#[should_panic(expected = "bond duration too short: minimum is 86400 seconds")]
 fn test_create_rolling_bond_invalid_duration_rejected() {
     let e = Env::default();
     e.mock_all_auths();
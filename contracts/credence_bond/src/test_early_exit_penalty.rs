@@ -1,14 +1,16 @@
 //! Tests for Early Exit Penalty Mechanism.
 //! Covers: penalty calculation from remaining lock time, configurable rates,
-//! penalty event emission, and security (zero/max penalty edge cases).
+//! per-tier rates, penalty curves, penalty event emission, and security (zero/max penalty edge cases).
 
 #![cfg(test)]
 
-use crate::early_exit_penalty;
+use crate::early_exit_penalty::{self, PenaltyCurve};
 use crate::test_helpers;
-use crate::{CredenceBond, CredenceBondClient};
+use crate::tiered_bond::TIER_GOLD_MAX;
+use crate::{BondTier, CredenceBond, CredenceBondClient};
 use soroban_sdk::testutils::{Address as _, Ledger};
-use soroban_sdk::{Address, Env};
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{vec, Address, Env, Vec};
 
 fn setup<'a>(
     e: &'a Env,
@@ -125,10 +127,270 @@ fn test_set_early_exit_config_invalid_bps() {
 #[test]
 fn test_calculate_penalty_unit() {
     // remaining = total -> full penalty rate applied
-    let p = early_exit_penalty::calculate_penalty(1000, 100, 100, 500);
+    let p = early_exit_penalty::calculate_penalty(1000, 100, 100, 500, &PenaltyCurve::Linear);
     assert_eq!(p, 50); // 5% of 1000
-    let p = early_exit_penalty::calculate_penalty(1000, 0, 100, 500);
+    let p = early_exit_penalty::calculate_penalty(1000, 0, 100, 500, &PenaltyCurve::Linear);
     assert_eq!(p, 0);
-    let p = early_exit_penalty::calculate_penalty(1000, 50, 100, 10000);
+    let p = early_exit_penalty::calculate_penalty(1000, 50, 100, 10000, &PenaltyCurve::Linear);
     assert_eq!(p, 500);
 }
+
+/// Withdraw 1_000_000 early at the start of the lock from a bond of `bond_amount`
+/// with Bronze at 10% and Platinum at 2%; returns the penalty sent to the treasury.
+fn tiered_penalty(bond_amount: i128) -> i128 {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let treasury = Address::generate(&e);
+    let (client, admin, identity, token, _bond_id) = test_helpers::setup_with_token(&e);
+    client.set_early_exit_config(&admin, &treasury, &500);
+    client.set_early_exit_penalty_for_tier(&admin, &BondTier::Bronze, &1_000);
+    client.set_early_exit_penalty_for_tier(&admin, &BondTier::Platinum, &200);
+    client.create_bond(&identity, &bond_amount, &86400_u64, &false, &0_u64);
+
+    client.withdraw_early(&1_000_000);
+    TokenClient::new(&e, &token).balance(&treasury)
+}
+
+#[test]
+fn test_tier_penalty_differs_between_tiers() {
+    let bronze = tiered_penalty(10_000_000);
+    let platinum = tiered_penalty(TIER_GOLD_MAX);
+    assert_eq!(bronze, 100_000); // 10% of 1_000_000
+    assert_eq!(platinum, 20_000); // 2% of 1_000_000
+}
+
+#[test]
+fn test_tier_without_override_uses_global_rate() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let treasury = Address::generate(&e);
+    let (client, admin, identity, token, _bond_id) = test_helpers::setup_with_token(&e);
+    client.set_early_exit_config(&admin, &treasury, &500);
+    client.set_early_exit_penalty_for_tier(&admin, &BondTier::Platinum, &200);
+    client.create_bond(&identity, &10_000_000_i128, &86400_u64, &false, &0_u64);
+
+    client.withdraw_early(&1_000_000);
+    assert_eq!(TokenClient::new(&e, &token).balance(&treasury), 50_000);
+}
+
+#[test]
+fn test_tier_rate_uses_tier_before_withdrawal() {
+    // Withdrawing drops the bond from Platinum to Gold; the Platinum rate still applies.
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let treasury = Address::generate(&e);
+    let (client, admin, identity, token, _bond_id) = test_helpers::setup_with_token(&e);
+    client.set_early_exit_config(&admin, &treasury, &500);
+    client.set_early_exit_penalty_for_tier(&admin, &BondTier::Platinum, &0);
+    client.create_bond(&identity, &TIER_GOLD_MAX, &86400_u64, &false, &0_u64);
+
+    let bond = client.withdraw_early(&1_000_000);
+    assert_eq!(bond.bonded_amount, TIER_GOLD_MAX - 1_000_000);
+    assert_eq!(TokenClient::new(&e, &token).balance(&treasury), 0);
+}
+
+#[test]
+fn test_get_early_exit_config_returns_tier_table() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, admin, _identity) = setup(&e, &treasury, 500);
+    client.set_early_exit_penalty_for_tier(&admin, &BondTier::Gold, &300);
+    client.set_early_exit_penalty_for_tier(&admin, &BondTier::Platinum, &100);
+    client.set_early_exit_penalty_for_tier(&admin, &BondTier::Gold, &250);
+
+    let cfg = client.get_early_exit_config();
+    assert_eq!(cfg.treasury, treasury);
+    assert_eq!(cfg.penalty_bps, 500);
+    assert_eq!(cfg.tier_penalty_bps.len(), 2);
+    assert_eq!(cfg.tier_penalty_bps.get(BondTier::Gold), Some(250));
+    assert_eq!(cfg.tier_penalty_bps.get(BondTier::Platinum), Some(100));
+    assert_eq!(cfg.tier_penalty_bps.get(BondTier::Bronze), None);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_set_early_exit_penalty_for_tier_unauthorized() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, _admin, _identity) = setup(&e, &treasury, 500);
+    client.set_early_exit_penalty_for_tier(&Address::generate(&e), &BondTier::Gold, &100);
+}
+
+#[test]
+#[should_panic(expected = "penalty_bps must be <= 10000")]
+fn test_set_early_exit_penalty_for_tier_invalid_bps() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, admin, _identity) = setup(&e, &treasury, 500);
+    client.set_early_exit_penalty_for_tier(&admin, &BondTier::Gold, &10_001);
+}
+
+// ---------------------------------------------------------------
+// Penalty curves
+// ---------------------------------------------------------------
+
+/// 25% of the rate with up to a quarter of the lock left, 50% up to half, full otherwise.
+fn steps(e: &Env) -> PenaltyCurve {
+    PenaltyCurve::Steps(vec![e, (25, 2_500), (50, 5_000), (100, 10_000)])
+}
+
+#[test]
+fn test_curve_linear_unit() {
+    let c = PenaltyCurve::Linear;
+    assert_eq!(
+        early_exit_penalty::calculate_penalty(10_000, 100, 100, 1_000, &c),
+        1_000
+    );
+    assert_eq!(
+        early_exit_penalty::calculate_penalty(10_000, 50, 100, 1_000, &c),
+        500
+    );
+    assert_eq!(
+        early_exit_penalty::calculate_penalty(10_000, 0, 100, 1_000, &c),
+        0
+    );
+}
+
+#[test]
+fn test_curve_quadratic_unit() {
+    let c = PenaltyCurve::Quadratic;
+    // Full duration remaining: full penalty.
+    assert_eq!(
+        early_exit_penalty::calculate_penalty(10_000, 100, 100, 1_000, &c),
+        1_000
+    );
+    // Half remaining: a quarter of the penalty.
+    assert_eq!(
+        early_exit_penalty::calculate_penalty(10_000, 50, 100, 1_000, &c),
+        250
+    );
+    // 10% remaining: 1% of the penalty.
+    assert_eq!(
+        early_exit_penalty::calculate_penalty(10_000, 10, 100, 1_000, &c),
+        10
+    );
+    // At maturity: nothing.
+    assert_eq!(
+        early_exit_penalty::calculate_penalty(10_000, 0, 100, 1_000, &c),
+        0
+    );
+}
+
+#[test]
+fn test_curve_steps_unit() {
+    let e = Env::default();
+    let c = steps(&e);
+    let p = |remaining| early_exit_penalty::calculate_penalty(10_000, remaining, 100, 1_000, &c);
+    assert_eq!(p(100), 1_000);
+    assert_eq!(p(51), 1_000);
+    // Step boundaries are inclusive.
+    assert_eq!(p(50), 500);
+    assert_eq!(p(26), 500);
+    assert_eq!(p(25), 250);
+    assert_eq!(p(1), 250);
+    assert_eq!(p(0), 0);
+}
+
+#[test]
+fn test_curve_steps_boundary_is_exact() {
+    // remaining/total = 1/3 is just above 33%, so the 33% step does not apply.
+    let e = Env::default();
+    let c = PenaltyCurve::Steps(vec![&e, (33, 1_000), (100, 10_000)]);
+    assert_eq!(
+        early_exit_penalty::calculate_penalty(10_000, 1, 3, 1_000, &c),
+        1_000
+    );
+    assert_eq!(
+        early_exit_penalty::calculate_penalty(10_000, 33, 100, 1_000, &c),
+        100
+    );
+}
+
+#[test]
+fn test_curves_agree_at_extremes() {
+    let e = Env::default();
+    for c in [PenaltyCurve::Linear, PenaltyCurve::Quadratic, steps(&e)] {
+        assert_eq!(
+            early_exit_penalty::calculate_penalty(10_000, 86_400, 86_400, 500, &c),
+            500
+        );
+        assert_eq!(
+            early_exit_penalty::calculate_penalty(10_000, 0, 86_400, 500, &c),
+            0
+        );
+    }
+}
+
+#[test]
+fn test_withdraw_early_uses_selected_curve() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let treasury = Address::generate(&e);
+    let (client, admin, identity, token, _bond_id) = test_helpers::setup_with_token(&e);
+    client.set_early_exit_config(&admin, &treasury, &1_000);
+    client.set_early_exit_penalty_curve(&admin, &PenaltyCurve::Quadratic);
+    client.create_bond(&identity, &10_000_000_i128, &86_400_u64, &false, &0_u64);
+
+    // Half the lock remaining: 10% * (1/2)^2 = 2.5%.
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 43_200);
+    client.withdraw_early(&1_000_000);
+    assert_eq!(TokenClient::new(&e, &token).balance(&treasury), 25_000);
+}
+
+#[test]
+fn test_get_early_exit_config_returns_curve() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, admin, _identity) = setup(&e, &treasury, 500);
+    assert_eq!(client.get_early_exit_config().curve, PenaltyCurve::Linear);
+    client.set_early_exit_penalty_curve(&admin, &steps(&e));
+    assert_eq!(client.get_early_exit_config().curve, steps(&e));
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_set_early_exit_penalty_curve_unauthorized() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, _admin, _identity) = setup(&e, &treasury, 500);
+    client.set_early_exit_penalty_curve(&Address::generate(&e), &PenaltyCurve::Quadratic);
+}
+
+#[test]
+#[should_panic(expected = "penalty steps must have ascending remaining_pct in 1..=100")]
+fn test_set_penalty_steps_unsorted() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, admin, _identity) = setup(&e, &treasury, 500);
+    let curve = PenaltyCurve::Steps(vec![&e, (50, 5_000), (25, 2_500), (100, 10_000)]);
+    client.set_early_exit_penalty_curve(&admin, &curve);
+}
+
+#[test]
+#[should_panic(expected = "penalty steps must end at remaining_pct 100")]
+fn test_set_penalty_steps_not_covering_full_range() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, admin, _identity) = setup(&e, &treasury, 500);
+    let curve = PenaltyCurve::Steps(vec![&e, (50, 5_000)]);
+    client.set_early_exit_penalty_curve(&admin, &curve);
+}
+
+#[test]
+#[should_panic(expected = "penalty steps must end at remaining_pct 100")]
+fn test_set_penalty_steps_empty() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, admin, _identity) = setup(&e, &treasury, 500);
+    client.set_early_exit_penalty_curve(&admin, &PenaltyCurve::Steps(Vec::new(&e)));
+}
+
+#[test]
+#[should_panic(expected = "penalty step bps must be <= 10000")]
+fn test_set_penalty_steps_bps_too_high() {
+    let e = Env::default();
+    let treasury = Address::generate(&e);
+    let (client, admin, _identity) = setup(&e, &treasury, 500);
+    let curve = PenaltyCurve::Steps(vec![&e, (100, 10_001)]);
+    client.set_early_exit_penalty_curve(&admin, &curve);
+}
@@ -0,0 +1,400 @@
+//! Tests for emergency withdrawals: dual authorization, emergency mode gating,
+//! token movement to identity and treasury, and audit records.
+
+#![cfg(test)]
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use soroban_sdk::testutils::storage::Persistent as _;
+use soroban_sdk::testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke};
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{contract, contractimpl, Address, Env, IntoVal, Symbol};
+
+#[contract]
+pub struct FailingToken;
+
+#[contractimpl]
+impl FailingToken {
+    pub fn transfer(_e: Env, _from: Address, _to: Address, _amount: i128) {
+        panic!("token transfer rejected");
+    }
+}
+
+struct Ctx<'a> {
+    client: CredenceBondClient<'a>,
+    admin: Address,
+    identity: Address,
+    governance: Address,
+    treasury: Address,
+    token: Address,
+    contract_id: Address,
+}
+
+/// Bond of 10_000 with a 500 bps (5%) emergency fee and emergency mode enabled.
+fn setup(e: &Env) -> Ctx<'_> {
+    let (client, admin, identity, token, contract_id) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &10_000_i128, &86400_u64, &false, &0_u64);
+    let governance = Address::generate(e);
+    let treasury = Address::generate(e);
+    client.set_emergency_config(&admin, &governance, &treasury, &500_u32);
+    client.set_emergency_mode(&admin, &governance, &true);
+    Ctx {
+        client,
+        admin,
+        identity,
+        governance,
+        treasury,
+        token,
+        contract_id,
+    }
+}
+
+fn reason(e: &Env) -> Symbol {
+    Symbol::new(e, "exploit")
+}
+
+#[test]
+fn test_emergency_withdraw_transfers_net_and_fee() {
+    let e = Env::default();
+    let c = setup(&e);
+    let token = TokenClient::new(&e, &c.token);
+
+    let identity_before = token.balance(&c.identity);
+    let contract_before = token.balance(&c.contract_id);
+    assert_eq!(contract_before, 10_000);
+
+    let record = c
+        .client
+        .emergency_withdraw(&c.admin, &c.governance, &4_000, &reason(&e));
+    assert_eq!(record.gross_amount, 4_000);
+    assert_eq!(record.fee_amount, 200);
+    assert_eq!(record.net_amount, 3_800);
+
+    assert_eq!(token.balance(&c.identity), identity_before + 3_800);
+    assert_eq!(token.balance(&c.treasury), 200);
+    assert_eq!(token.balance(&c.contract_id), contract_before - 4_000);
+    assert_eq!(c.client.get_identity_state().bonded_amount, 6_000);
+}
+
+#[test]
+fn test_emergency_withdraw_records_audit_entry() {
+    let e = Env::default();
+    let c = setup(&e);
+
+    assert_eq!(c.client.get_latest_emergency_record_id(), 0);
+    c.client
+        .emergency_withdraw(&c.admin, &c.governance, &1_000, &reason(&e));
+    c.client
+        .emergency_withdraw(&c.admin, &c.governance, &2_000, &reason(&e));
+    assert_eq!(c.client.get_latest_emergency_record_id(), 2);
+
+    let record = c.client.get_emergency_record(&2);
+    assert_eq!(record.id, 2);
+    assert_eq!(record.identity, c.identity);
+    assert_eq!(record.gross_amount, 2_000);
+    assert_eq!(record.treasury, c.treasury);
+    assert_eq!(record.approved_admin, c.admin);
+    assert_eq!(record.approved_governance, c.governance);
+    assert_eq!(record.reason, reason(&e));
+}
+
+#[test]
+fn test_emergency_withdraw_zero_fee() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client
+        .set_emergency_config(&c.admin, &c.governance, &c.treasury, &0_u32);
+    let token = TokenClient::new(&e, &c.token);
+    let identity_before = token.balance(&c.identity);
+
+    c.client
+        .emergency_withdraw(&c.admin, &c.governance, &10_000, &reason(&e));
+    assert_eq!(token.balance(&c.identity), identity_before + 10_000);
+    assert_eq!(token.balance(&c.treasury), 0);
+    assert_eq!(token.balance(&c.contract_id), 0);
+}
+
+#[test]
+fn test_emergency_withdraw_failed_transfer_reverts() {
+    let e = Env::default();
+    let c = setup(&e);
+    let token = TokenClient::new(&e, &c.token);
+    // Point the bond at a token whose transfer always reverts.
+    let failing_token = e.register(FailingToken, ());
+    c.client.set_token(&c.admin, &failing_token);
+
+    let result = c
+        .client
+        .try_emergency_withdraw(&c.admin, &c.governance, &1_000, &reason(&e));
+    assert!(result.is_err());
+    assert_eq!(c.client.get_identity_state().bonded_amount, 10_000);
+    assert_eq!(c.client.get_latest_emergency_record_id(), 0);
+    assert_eq!(token.balance(&c.contract_id), 10_000);
+    assert_eq!(token.balance(&c.treasury), 0);
+}
+
+#[test]
+#[should_panic(expected = "emergency mode not enabled")]
+fn test_emergency_withdraw_requires_emergency_mode() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client.set_emergency_mode(&c.admin, &c.governance, &false);
+    assert!(!c.client.is_emergency_mode());
+    c.client
+        .emergency_withdraw(&c.admin, &c.governance, &1_000, &reason(&e));
+}
+
+#[test]
+#[should_panic(expected = "not governance")]
+fn test_emergency_withdraw_wrong_governance() {
+    let e = Env::default();
+    let c = setup(&e);
+    let impostor = Address::generate(&e);
+    c.client
+        .emergency_withdraw(&c.admin, &impostor, &1_000, &reason(&e));
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_emergency_withdraw_non_admin() {
+    let e = Env::default();
+    let c = setup(&e);
+    let attacker = Address::generate(&e);
+    c.client
+        .emergency_withdraw(&attacker, &c.governance, &1_000, &reason(&e));
+}
+
+#[test]
+#[should_panic(expected = "insufficient balance for withdrawal")]
+fn test_emergency_withdraw_exceeds_available() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client.slash(&c.admin, &4_000);
+    c.client
+        .emergency_withdraw(&c.admin, &c.governance, &6_001, &reason(&e));
+}
+
+#[test]
+#[should_panic(expected = "emergency fee bps must be <= 10000")]
+fn test_emergency_config_rejects_fee_above_100_percent() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client
+        .set_emergency_config(&c.admin, &c.governance, &c.treasury, &10_001_u32);
+}
+
+#[test]
+fn test_emergency_window_usage_without_limit() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client
+        .emergency_withdraw(&c.admin, &c.governance, &5_000, &reason(&e));
+    let usage = c.client.get_emergency_window_usage();
+    assert_eq!(usage.used, 0);
+    assert_eq!(usage.max_per_window, 0);
+    assert!(c.client.get_emergency_rate_limit().is_none());
+}
+
+#[test]
+fn test_emergency_rate_limit_tracks_usage() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client
+        .set_emergency_rate_limit(&c.governance, &3_000, &3_600);
+
+    c.client
+        .emergency_withdraw(&c.admin, &c.governance, &1_000, &reason(&e));
+    c.client
+        .emergency_withdraw(&c.admin, &c.governance, &2_000, &reason(&e));
+
+    let usage = c.client.get_emergency_window_usage();
+    assert_eq!(usage.used, 3_000);
+    assert_eq!(usage.max_per_window, 3_000);
+    assert_eq!(usage.window_secs, 3_600);
+}
+
+#[test]
+#[should_panic(expected = "emergency rate limit exceeded")]
+fn test_emergency_rate_limit_rejects_excess() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client
+        .set_emergency_rate_limit(&c.governance, &3_000, &3_600);
+
+    c.client
+        .emergency_withdraw(&c.admin, &c.governance, &2_500, &reason(&e));
+    c.client
+        .emergency_withdraw(&c.admin, &c.governance, &501, &reason(&e));
+}
+
+#[test]
+fn test_emergency_rate_limit_window_resets() {
+    let e = Env::default();
+    let c = setup(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    c.client
+        .set_emergency_rate_limit(&c.governance, &3_000, &3_600);
+
+    c.client
+        .emergency_withdraw(&c.admin, &c.governance, &3_000, &reason(&e));
+    assert!(c
+        .client
+        .try_emergency_withdraw(&c.admin, &c.governance, &1, &reason(&e))
+        .is_err());
+
+    // Still inside the window one second before it closes.
+    e.ledger().with_mut(|li| li.timestamp = 1_000 + 3_599);
+    assert_eq!(c.client.get_emergency_window_usage().used, 3_000);
+
+    e.ledger().with_mut(|li| li.timestamp = 1_000 + 3_600);
+    let usage = c.client.get_emergency_window_usage();
+    assert_eq!(usage.used, 0);
+    assert_eq!(usage.window_start, 4_600);
+
+    c.client
+        .emergency_withdraw(&c.admin, &c.governance, &3_000, &reason(&e));
+    assert_eq!(c.client.get_emergency_window_usage().used, 3_000);
+    assert_eq!(c.client.get_identity_state().bonded_amount, 4_000);
+}
+
+#[test]
+#[should_panic(expected = "not governance")]
+fn test_emergency_rate_limit_requires_governance() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client.set_emergency_rate_limit(&c.admin, &3_000, &3_600);
+}
+
+#[test]
+#[should_panic(expected = "emergency window must be positive")]
+fn test_emergency_rate_limit_rejects_zero_window() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client.set_emergency_rate_limit(&c.governance, &3_000, &0);
+}
+
+/// Perform `n` emergency withdrawals of 100, advancing the clock 10s before each.
+fn withdraw_n(e: &Env, c: &Ctx<'_>, n: u64) {
+    for _ in 0..n {
+        e.ledger().with_mut(|li| li.timestamp += 10);
+        c.client
+            .emergency_withdraw(&c.admin, &c.governance, &100, &reason(e));
+    }
+}
+
+#[test]
+fn test_get_emergency_records_range() {
+    let e = Env::default();
+    let c = setup(&e);
+    withdraw_n(&e, &c, 5);
+
+    let records = c.client.get_emergency_records(&2, &4);
+    assert_eq!(records.len(), 3);
+    assert_eq!(records.get(0).unwrap().id, 2);
+    assert_eq!(records.get(2).unwrap().id, 4);
+
+    // Ids beyond the latest record are ignored.
+    let tail = c.client.get_emergency_records(&4, &20);
+    assert_eq!(tail.len(), 2);
+    assert_eq!(tail.get(1).unwrap().id, 5);
+}
+
+#[test]
+#[should_panic(expected = "record range too large")]
+fn test_get_emergency_records_span_capped() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client.get_emergency_records(&1, &51);
+}
+
+#[test]
+#[should_panic(expected = "invalid record range")]
+fn test_get_emergency_records_inverted_range() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client.get_emergency_records(&3, &2);
+}
+
+#[test]
+fn test_get_emergency_records_since() {
+    let e = Env::default();
+    let c = setup(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    withdraw_n(&e, &c, 5); // timestamps 1010..=1050
+
+    let records = c.client.get_emergency_records_since(&1_030);
+    assert_eq!(records.len(), 3);
+    assert_eq!(records.get(0).unwrap().id, 3);
+    assert_eq!(records.get(0).unwrap().timestamp, 1_030);
+    assert_eq!(records.get(2).unwrap().id, 5);
+
+    assert_eq!(c.client.get_emergency_records_since(&2_000).len(), 0);
+    assert_eq!(c.client.get_emergency_records_since(&0).len(), 5);
+}
+
+#[test]
+fn test_emergency_records_are_persistent_with_ttl() {
+    let e = Env::default();
+    let c = setup(&e);
+    withdraw_n(&e, &c, 1);
+
+    let ttl = e.as_contract(&c.contract_id, || {
+        e.storage()
+            .persistent()
+            .get_ttl(&crate::emergency::EmergencyKey::Record(1))
+    });
+    assert!(ttl >= 518_400);
+}
+
+#[test]
+fn test_emergency_config_starts_disabled_and_keeps_mode() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    let governance = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    client.set_emergency_config(&admin, &governance, &treasury, &500_u32);
+    assert!(!client.is_emergency_mode());
+
+    client.set_emergency_mode(&admin, &governance, &true);
+    client.set_emergency_config(&admin, &governance, &treasury, &100_u32);
+    assert!(client.is_emergency_mode());
+    assert_eq!(client.get_emergency_config().emergency_fee_bps, 100);
+}
+
+#[test]
+fn test_replacing_governance_requires_current_governance() {
+    let e = Env::default();
+    let c = setup(&e);
+    let new_governance = Address::generate(&e);
+
+    // The admin alone cannot swap out the co-signer.
+    let args = (
+        c.admin.clone(),
+        new_governance.clone(),
+        c.treasury.clone(),
+        500_u32,
+    );
+    e.mock_auths(&[MockAuth {
+        address: &c.admin,
+        invoke: &MockAuthInvoke {
+            contract: &c.contract_id,
+            fn_name: "set_emergency_config",
+            args: args.into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(c
+        .client
+        .try_set_emergency_config(&c.admin, &new_governance, &c.treasury, &500_u32)
+        .is_err());
+    assert_eq!(c.client.get_emergency_config().governance, c.governance);
+
+    e.mock_all_auths();
+    c.client
+        .set_emergency_config(&c.admin, &new_governance, &c.treasury, &500_u32);
+    assert!(e
+        .auths()
+        .iter()
+        .any(|(address, _)| *address == c.governance));
+    assert_eq!(c.client.get_emergency_config().governance, new_governance);
+}
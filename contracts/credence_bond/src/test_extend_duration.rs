@@ -0,0 +1,76 @@
+//! Tests for `extend_duration`: owner-only authorization, max-duration cap,
+//! and the `duration_extended` event.
+
+#![cfg(test)]
+
+use crate::test_helpers;
+use crate::validation::MAX_BOND_DURATION;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol, TryFromVal};
+
+#[test]
+fn test_extend_duration_by_owner() {
+    let e = Env::default();
+    let (client, _admin, identity, _token, contract_id) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+
+    let bond = client.extend_duration(&identity, &86400_u64);
+    assert_eq!(bond.bond_duration, 172_800);
+
+    let expected_topics = vec![
+        &e,
+        Symbol::new(&e, "duration_extended").into_val(&e),
+        identity.into_val(&e),
+    ];
+    let (_, _, data) = e
+        .events()
+        .all()
+        .iter()
+        .find(|(contract, topics, _)| contract == &contract_id && topics == &expected_topics)
+        .expect("duration_extended event");
+    let (old_end, new_end) = <(u64, u64)>::try_from_val(&e, &data).unwrap();
+    assert_eq!(old_end, 86_400);
+    assert_eq!(new_end, 172_800);
+    assert_eq!(client.get_identity_state().bond_duration, 172_800);
+}
+
+#[test]
+#[should_panic(expected = "not bond owner")]
+fn test_extend_duration_third_party_rejected() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+
+    let stranger = Address::generate(&e);
+    client.extend_duration(&stranger, &86400_u64);
+}
+
+#[test]
+fn test_extend_duration_requires_owner_auth() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+
+    e.set_auths(&[]);
+    assert!(client.try_extend_duration(&identity, &86400_u64).is_err());
+    assert_eq!(client.get_identity_state().bond_duration, 86400);
+}
+
+#[test]
+#[should_panic(expected = "bond duration too long")]
+fn test_extend_duration_beyond_max_rejected() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &1000_i128, &MAX_BOND_DURATION, &false, &0_u64);
+    client.extend_duration(&identity, &1_u64);
+}
+
+#[test]
+fn test_extend_duration_up_to_max() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &false, &0_u64);
+
+    let bond = client.extend_duration(&identity, &(MAX_BOND_DURATION - 86400));
+    assert_eq!(bond.bond_duration, MAX_BOND_DURATION);
+}
@@ -5,8 +5,8 @@
 
 use crate::test_helpers;
 use crate::CredenceBondClient;
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{vec, Address, Env, IntoVal, Symbol, TryFromVal};
 
 fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
     // Shared helper configures token + approvals so create_bond works with fees.
@@ -117,3 +117,56 @@ fn test_fee_accumulates_in_pool() {
     let collected = client.collect_fees(&admin);
     assert_eq!(collected, 10 + 20);
 }
+
+#[test]
+fn test_fees_paid_ledger_per_identity() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &100_u32);
+    assert_eq!(client.get_fees_paid(&identity), 0);
+    assert_eq!(client.get_total_fees_collected(), 0);
+
+    client.create_bond(&identity, &10_000_i128, &86400_u64, &false, &0_u64);
+    client.create_bond(&identity, &5_000_i128, &86400_u64, &false, &0_u64);
+
+    assert_eq!(client.get_fees_paid(&identity), 150);
+    assert_eq!(client.get_total_fees_collected(), 150);
+    assert_eq!(client.get_fees_paid(&Address::generate(&e)), 0);
+}
+
+#[test]
+fn test_fees_paid_unchanged_when_fee_waived() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &0_u32);
+    client.create_bond(&identity, &10_000_i128, &86400_u64, &false, &0_u64);
+    assert_eq!(client.get_fees_paid(&identity), 0);
+    assert_eq!(client.get_total_fees_collected(), 0);
+}
+
+#[test]
+fn test_fee_recorded_event() {
+    let e = Env::default();
+    let (client, admin, identity) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &100_u32);
+    client.create_bond(&identity, &10_000_i128, &86400_u64, &false, &0_u64);
+
+    let expected_topics = vec![
+        &e,
+        Symbol::new(&e, "fee_recorded").into_val(&e),
+        identity.into_val(&e),
+    ];
+    let (_, _, data) = e
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| topics == &expected_topics)
+        .expect("fee_recorded event");
+    let (gross, fee, source) = <(i128, i128, Symbol)>::try_from_val(&e, &data).unwrap();
+    assert_eq!(gross, 10_000);
+    assert_eq!(fee, 100);
+    assert_eq!(source, Symbol::new(&e, "create_bond"));
+}
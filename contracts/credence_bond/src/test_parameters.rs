@@ -9,10 +9,13 @@
 //! 6. Cooldown period parameters (withdrawal, slash)
 //! 7. Tier threshold parameters (bronze, silver, gold, platinum)
 //! 8. State persistence and retrieval
+//! 9. Bond amount and duration limits
+//! 10. Cooldown execution window
 
 #![cfg(test)]
 
 use crate::parameters::*;
+use crate::test_helpers;
 use crate::{CredenceBond, CredenceBondClient};
 use soroban_sdk::testutils::Address as _;
 use soroban_sdk::{Address, Env};
@@ -721,3 +724,208 @@ fn test_max_values_for_all_parameters() {
     assert_eq!(client.get_gold_threshold(), MAX_GOLD_THRESHOLD);
     assert_eq!(client.get_platinum_threshold(), MAX_PLATINUM_THRESHOLD);
 }
+
+// ============================================================================
+// Category 10: Bond Amount and Duration Limits
+// ============================================================================
+
+#[test]
+fn test_default_bond_limits() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+
+    assert_eq!(client.get_min_bond_amount(), DEFAULT_MIN_BOND_AMOUNT);
+    assert_eq!(client.get_max_bond_amount(), DEFAULT_MAX_BOND_AMOUNT);
+    assert_eq!(client.get_min_bond_duration(), DEFAULT_MIN_BOND_DURATION);
+    assert_eq!(client.get_max_bond_duration(), DEFAULT_MAX_BOND_DURATION);
+}
+
+#[test]
+fn test_set_bond_limits() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+
+    client.set_min_bond_amount(&admin, &500);
+    client.set_max_bond_amount(&admin, &1_000_000);
+    client.set_min_bond_duration(&admin, &7_200);
+    client.set_max_bond_duration(&admin, &MAX_BOND_DURATION_CEILING);
+
+    assert_eq!(client.get_min_bond_amount(), 500);
+    assert_eq!(client.get_max_bond_amount(), 1_000_000);
+    assert_eq!(client.get_min_bond_duration(), 7_200);
+    assert_eq!(client.get_max_bond_duration(), MAX_BOND_DURATION_CEILING);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_set_min_bond_amount_non_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let attacker = Address::generate(&e);
+    client.set_min_bond_amount(&attacker, &500);
+}
+
+#[test]
+fn test_bond_limit_setters_require_admin_auth() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    let min_amount = client.get_min_bond_amount();
+    let max_duration = client.get_max_bond_duration();
+
+    e.set_auths(&[]);
+    assert!(client.try_set_min_bond_amount(&admin, &500).is_err());
+    assert!(client.try_set_max_bond_amount(&admin, &500).is_err());
+    assert!(client.try_set_min_bond_duration(&admin, &7_200).is_err());
+    assert!(client.try_set_max_bond_duration(&admin, &7_200).is_err());
+    assert!(client.try_set_protocol_fee_bps(&admin, &10).is_err());
+    assert_eq!(client.get_min_bond_amount(), min_amount);
+    assert_eq!(client.get_max_bond_duration(), max_duration);
+}
+
+#[test]
+#[should_panic(expected = "min_bond_amount out of bounds")]
+fn test_set_min_bond_amount_negative() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_min_bond_amount(&admin, &-1);
+}
+
+#[test]
+#[should_panic(expected = "min_bond_amount out of bounds")]
+fn test_set_min_bond_amount_above_max() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_max_bond_amount(&admin, &1_000);
+    client.set_min_bond_amount(&admin, &1_001);
+}
+
+#[test]
+#[should_panic(expected = "max_bond_amount out of bounds")]
+fn test_set_max_bond_amount_below_min() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_min_bond_amount(&admin, &1_000);
+    client.set_max_bond_amount(&admin, &999);
+}
+
+#[test]
+#[should_panic(expected = "min_bond_duration out of bounds")]
+fn test_set_min_bond_duration_below_floor() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_min_bond_duration(&admin, &(MIN_BOND_DURATION_FLOOR - 1));
+}
+
+#[test]
+#[should_panic(expected = "max_bond_duration out of bounds")]
+fn test_set_max_bond_duration_above_ceiling() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_max_bond_duration(&admin, &(MAX_BOND_DURATION_CEILING + 1));
+}
+
+#[test]
+#[should_panic(expected = "max_bond_duration out of bounds")]
+fn test_set_max_bond_duration_below_min() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_max_bond_duration(&admin, &(DEFAULT_MIN_BOND_DURATION - 1));
+}
+
+#[test]
+#[should_panic(expected = "bond amount too low: minimum is 2000")]
+fn test_raised_min_bond_amount_rejects_previously_valid_bond() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.set_min_bond_amount(&admin, &2_000);
+    client.create_bond(&identity, &1_000_i128, &86_400_u64, &false, &0_u64);
+}
+
+#[test]
+#[should_panic(expected = "bond amount too high: maximum is 500")]
+fn test_lowered_max_bond_amount_rejects_bond() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.set_max_bond_amount(&admin, &500);
+    client.create_bond(&identity, &1_000_i128, &86_400_u64, &false, &0_u64);
+}
+
+#[test]
+#[should_panic(expected = "bond duration too short: minimum is 172800 seconds")]
+fn test_raised_min_bond_duration_rejects_previously_valid_bond() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.set_min_bond_duration(&admin, &172_800);
+    client.create_bond(&identity, &1_000_i128, &86_400_u64, &false, &0_u64);
+}
+
+#[test]
+fn test_create_bond_with_rolling_enforces_bond_limits() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.set_min_bond_amount(&admin, &2_000);
+    client.set_min_bond_duration(&admin, &172_800);
+
+    assert!(client
+        .try_create_bond_with_rolling(&identity, &1_000_i128, &172_800_u64, &true, &0_u64)
+        .is_err());
+    assert!(client
+        .try_create_bond_with_rolling(&identity, &2_000_i128, &86_400_u64, &true, &0_u64)
+        .is_err());
+    let bond = client.create_bond_with_rolling(&identity, &2_000_i128, &172_800_u64, &true, &0_u64);
+    assert_eq!(bond.bond_duration, 172_800);
+}
+
+#[test]
+fn test_raised_max_bond_duration_allows_longer_bond() {
+    let e = Env::default();
+    let (client, admin, identity, ..) = test_helpers::setup_with_token(&e);
+    let two_years = 2 * DEFAULT_MAX_BOND_DURATION;
+    client.set_max_bond_duration(&admin, &two_years);
+    let bond = client.create_bond(&identity, &1_000_i128, &two_years, &false, &0_u64);
+    assert_eq!(bond.bond_duration, two_years);
+}
+
+// ============================================================================
+// Category 11: Cooldown Execution Window
+// ============================================================================
+
+#[test]
+fn test_default_cooldown_execution_window_secs() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    assert_eq!(
+        client.get_cooldown_exec_window_secs(),
+        DEFAULT_COOLDOWN_EXECUTION_WINDOW_SECS
+    );
+}
+
+#[test]
+fn test_set_cooldown_execution_window_secs() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_cooldown_exec_window_secs(&admin, &86_400);
+    assert_eq!(client.get_cooldown_exec_window_secs(), 86_400);
+    client.set_cooldown_exec_window_secs(&admin, &MAX_COOLDOWN_EXECUTION_WINDOW_SECS);
+    assert_eq!(
+        client.get_cooldown_exec_window_secs(),
+        MAX_COOLDOWN_EXECUTION_WINDOW_SECS
+    );
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_set_cooldown_execution_window_secs_non_admin() {
+    let e = Env::default();
+    let (client, _admin) = setup(&e);
+    let attacker = Address::generate(&e);
+    client.set_cooldown_exec_window_secs(&attacker, &86_400);
+}
+
+#[test]
+#[should_panic(expected = "cooldown_execution_window_secs out of bounds")]
+fn test_set_cooldown_execution_window_secs_above_max() {
+    let e = Env::default();
+    let (client, admin) = setup(&e);
+    client.set_cooldown_exec_window_secs(&admin, &(MAX_COOLDOWN_EXECUTION_WINDOW_SECS + 1));
+}
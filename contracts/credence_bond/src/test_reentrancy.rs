@@ -5,14 +5,16 @@
 //! - Reentrancy in `withdraw_bond` is blocked
 //! - Reentrancy in `slash_bond` is blocked
 //! - Reentrancy in `collect_fees` is blocked
+//! - A token re-entering `withdraw_bond` during transfer is blocked
+//! - Guarded withdrawals refuse to run while the lock is held
 //! - State locks are correctly acquired and released
 //! - Normal (non-reentrant) operations succeed
 //! - Sequential operations work after lock release
 
 use super::*;
 use crate::test_helpers;
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::Env;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Env, Symbol};
 
 // ---------------------------------------------------------------------------
 // Each attacker contract lives in its own submodule to avoid Soroban macro
@@ -175,26 +177,45 @@ mod cross_attacker {
     }
 }
 
+mod token_attacker {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl, Address, Env, Symbol};
+
+    /// Token whose `transfer` re-enters `withdraw_bond` on the bond contract.
+    #[contract]
+    pub struct TokenAttacker;
+
+    #[contractimpl]
+    impl TokenAttacker {
+        pub fn transfer(e: Env, _from: Address, _to: Address, amount: i128) {
+            let bond_addr: Address = e
+                .storage()
+                .instance()
+                .get(&Symbol::new(&e, "target"))
+                .unwrap();
+            let client = CredenceBondClient::new(&e, &bond_addr);
+            client.withdraw_bond(&amount);
+        }
+
+        pub fn setup(e: Env, target: Address) {
+            e.storage()
+                .instance()
+                .set(&Symbol::new(&e, "target"), &target);
+        }
+    }
+}
+
 use benign_callback::BenignCallback;
 use cross_attacker::{CrossAttacker, CrossAttackerClient};
 use fee_attacker::{FeeAttacker, FeeAttackerClient};
 use slash_attacker::{SlashAttacker, SlashAttackerClient};
+use token_attacker::{TokenAttacker, TokenAttackerClient};
 use withdraw_attacker::{WithdrawAttacker, WithdrawAttackerClient};
 
 // ---------------------------------------------------------------------------
 // Helper: set up a bond contract with admin, identity, and a bond.
 // ---------------------------------------------------------------------------
 fn setup_bond(e: &Env) -> (Address, Address, Address) {
-    let contract_id = e.register(CredenceBond, ());
-    let client = CredenceBondClient::new(e, &contract_id);
-
-    let admin = Address::generate(e);
-    let identity = Address::generate(e);
-
-    client.initialize(&admin);
-    client.create_bond(&identity, &10_000_i128, &86400_u64);
-
-    (contract_id, admin, identity)
     let (client, admin, identity, _token_id, bond_id) = test_helpers::setup_with_token(e);
     client.create_bond(&identity, &10_000_i128, &86400_u64, &false, &0_u64);
     (bond_id, admin, identity)
@@ -246,10 +267,10 @@ fn test_slash_reentrancy_blocked() {
 fn test_fee_collection_reentrancy_blocked() {
     let e = Env::default();
     e.mock_all_auths();
-    let (bond_id, admin, _identity) = setup_bond(&e);
+    let (bond_id, admin, identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    client.deposit_fees(&500_i128);
+    client.deposit_fees(&identity, &500_i128);
 
     let attacker_id = e.register(FeeAttacker, ());
     let attacker_client = FeeAttackerClient::new(&e, &attacker_id);
@@ -313,10 +334,10 @@ fn test_lock_released_after_slash() {
 fn test_lock_released_after_fee_collection() {
     let e = Env::default();
     e.mock_all_auths();
-    let (bond_id, admin, _identity) = setup_bond(&e);
+    let (bond_id, admin, identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    client.deposit_fees(&200_i128);
+    client.deposit_fees(&identity, &200_i128);
 
     let benign_id = e.register(BenignCallback, ());
     client.set_callback(&benign_id);
@@ -369,10 +390,10 @@ fn test_normal_slash_succeeds() {
 fn test_normal_fee_collection_succeeds() {
     let e = Env::default();
     e.mock_all_auths();
-    let (bond_id, admin, _identity) = setup_bond(&e);
+    let (bond_id, admin, identity) = setup_bond(&e);
     let client = CredenceBondClient::new(&e, &bond_id);
 
-    client.deposit_fees(&750_i128);
+    client.deposit_fees(&identity, &750_i128);
     let collected = client.collect_fees(&admin);
     assert_eq!(collected, 750_i128);
 }
@@ -390,7 +411,7 @@ fn test_sequential_operations_succeed() {
     client.slash_bond(&admin, &1_000_i128);
     assert!(!client.is_locked());
 
-    client.deposit_fees(&100_i128);
+    client.deposit_fees(&identity, &100_i128);
     let fees = client.collect_fees(&admin);
     assert_eq!(fees, 100_i128);
     assert!(!client.is_locked());
@@ -462,3 +483,99 @@ fn test_cross_function_reentrancy_blocked() {
 
     client.withdraw_bond_full(&identity);
 }
+
+/// Mark the reentrancy lock as held, as it is while a guarded call is in an
+/// external call. The host refuses real re-entry before the guard runs, so this
+/// is how the guard's own check is exercised.
+fn hold_lock(e: &Env, bond_id: &Address) {
+    e.as_contract(bond_id, || {
+        e.storage().instance().set(&Symbol::new(e, "lock"), &true);
+    });
+}
+
+// ===========================================================================
+// 16. Malicious token re-entering withdraw_bond during transfer is blocked
+//     (by the host's re-entry ban; the guard itself is covered by 18-20)
+// ===========================================================================
+#[test]
+fn test_withdraw_bond_token_reentrancy_blocked() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (bond_id, admin, _identity) = setup_bond(&e);
+    let client = CredenceBondClient::new(&e, &bond_id);
+
+    let attacker_id = e.register(TokenAttacker, ());
+    TokenAttackerClient::new(&e, &attacker_id).setup(&bond_id);
+    client.set_token(&admin, &attacker_id);
+    e.ledger().with_mut(|li| li.timestamp = 86_401);
+
+    assert!(client.try_withdraw_bond(&1_000_i128).is_err());
+    assert_eq!(client.get_identity_state().bonded_amount, 10_000);
+    assert!(!client.is_locked());
+}
+
+// ===========================================================================
+// 17. Lock is not left held when a guarded call panics
+// ===========================================================================
+#[test]
+fn test_lock_released_after_failed_withdraw() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (bond_id, _admin, _identity) = setup_bond(&e);
+    let client = CredenceBondClient::new(&e, &bond_id);
+
+    // Lock-up has not elapsed, so the guarded body panics.
+    assert!(client.try_withdraw_bond(&1_000_i128).is_err());
+    assert!(!client.is_locked());
+
+    e.ledger().with_mut(|li| li.timestamp = 86_401);
+    let bond = client.withdraw_bond(&1_000_i128);
+    assert_eq!(bond.bonded_amount, 9_000);
+    assert!(!client.is_locked());
+}
+
+// ===========================================================================
+// 18. Guarded withdrawal refuses to run while the lock is held
+// ===========================================================================
+#[test]
+#[should_panic(expected = "reentrancy detected")]
+fn test_withdraw_bond_rejected_while_locked() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (bond_id, _admin, _identity) = setup_bond(&e);
+    let client = CredenceBondClient::new(&e, &bond_id);
+    e.ledger().with_mut(|li| li.timestamp = 86_401);
+
+    hold_lock(&e, &bond_id);
+    client.withdraw_bond(&1_000_i128);
+}
+
+// ===========================================================================
+// 19. Guarded early withdrawal refuses to run while the lock is held
+// ===========================================================================
+#[test]
+#[should_panic(expected = "reentrancy detected")]
+fn test_withdraw_early_rejected_while_locked() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (bond_id, _admin, _identity) = setup_bond(&e);
+    let client = CredenceBondClient::new(&e, &bond_id);
+
+    hold_lock(&e, &bond_id);
+    client.withdraw_early(&1_000_i128);
+}
+
+// ===========================================================================
+// 20. Guarded cooldown withdrawal refuses to run while the lock is held
+// ===========================================================================
+#[test]
+#[should_panic(expected = "reentrancy detected")]
+fn test_execute_cooldown_withdrawal_rejected_while_locked() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let (bond_id, _admin, identity) = setup_bond(&e);
+    let client = CredenceBondClient::new(&e, &bond_id);
+
+    hold_lock(&e, &bond_id);
+    client.execute_cooldown_withdrawal(&identity);
+}
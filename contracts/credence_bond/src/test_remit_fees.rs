@@ -0,0 +1,165 @@
+//! Tests for forwarding accrued bond creation fees to the credence_treasury contract.
+
+#![cfg(test)]
+
+use crate::test_helpers;
+use crate::CredenceBondClient;
+use credence_treasury::{CredenceTreasury, CredenceTreasuryClient, FundSource};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::TokenClient;
+use soroban_sdk::{Address, Env};
+
+struct Ctx<'a> {
+    client: CredenceBondClient<'a>,
+    admin: Address,
+    identity: Address,
+    token: Address,
+    bond_id: Address,
+    treasury: CredenceTreasuryClient<'a>,
+}
+
+/// Bond contract charging a 1% creation fee, wired to an initialized treasury
+/// contract that accepts deposits from the bond contract.
+fn setup(e: &Env) -> Ctx<'_> {
+    let (client, admin, identity, token, bond_id) = test_helpers::setup_with_token(e);
+    let treasury_id = e.register(CredenceTreasury, ());
+    let treasury = CredenceTreasuryClient::new(e, &treasury_id);
+    treasury.initialize(&admin);
+    treasury.add_depositor(&bond_id);
+
+    client.set_fee_config(&admin, &treasury_id, &100_u32);
+    client.set_treasury_contract(&admin, &treasury_id);
+    Ctx {
+        client,
+        admin,
+        identity,
+        token,
+        bond_id,
+        treasury,
+    }
+}
+
+#[test]
+fn test_remit_fees_moves_tokens_and_credits_treasury() {
+    let e = Env::default();
+    let c = setup(&e);
+    let token = TokenClient::new(&e, &c.token);
+    c.client
+        .create_bond(&c.identity, &10_000_i128, &86400_u64, &false, &0_u64);
+    assert_eq!(token.balance(&c.bond_id), 10_000);
+
+    let remitted = c.client.remit_fees(&c.admin);
+    assert_eq!(remitted, 100);
+
+    assert_eq!(token.balance(&c.bond_id), 9_900);
+    assert_eq!(token.balance(&c.treasury.address), 100);
+    assert_eq!(c.treasury.get_balance(), 100);
+    assert_eq!(
+        c.treasury.get_balance_by_source(&FundSource::ProtocolFee),
+        100
+    );
+    assert_eq!(
+        c.treasury.get_balance_by_source(&FundSource::SlashedFunds),
+        0
+    );
+}
+
+#[test]
+fn test_remit_fees_moves_full_pool_once() {
+    let e = Env::default();
+    let c = setup(&e);
+    let token = TokenClient::new(&e, &c.token);
+    c.client
+        .create_bond(&c.identity, &10_000_i128, &86400_u64, &false, &0_u64);
+
+    assert_eq!(c.client.remit_fees(&c.admin), 100);
+    // Nothing left to remit; balances stay put.
+    assert_eq!(c.client.remit_fees(&c.admin), 0);
+    assert_eq!(token.balance(&c.treasury.address), 100);
+    assert_eq!(c.treasury.get_balance(), 100);
+}
+
+#[test]
+fn test_remit_fees_nothing_accrued() {
+    let e = Env::default();
+    let c = setup(&e);
+    assert_eq!(c.client.remit_fees(&c.admin), 0);
+    assert_eq!(c.treasury.get_balance(), 0);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_remit_fees_non_admin() {
+    let e = Env::default();
+    let c = setup(&e);
+    let attacker = Address::generate(&e);
+    c.client.remit_fees(&attacker);
+}
+
+#[test]
+#[should_panic(expected = "treasury contract not set")]
+fn test_remit_fees_requires_treasury_contract() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    client.remit_fees(&admin);
+}
+
+#[test]
+fn test_remit_fees_rejected_when_not_depositor() {
+    let e = Env::default();
+    let c = setup(&e);
+    let token = TokenClient::new(&e, &c.token);
+    c.treasury.remove_depositor(&c.bond_id);
+    c.client
+        .create_bond(&c.identity, &10_000_i128, &86400_u64, &false, &0_u64);
+
+    assert!(c.client.try_remit_fees(&c.admin).is_err());
+    // The whole call reverts: no tokens move and the fee pool is intact.
+    assert_eq!(token.balance(&c.bond_id), 10_000);
+    assert_eq!(token.balance(&c.treasury.address), 0);
+    c.treasury.add_depositor(&c.bond_id);
+    assert_eq!(c.client.remit_fees(&c.admin), 100);
+}
+
+#[test]
+fn test_set_treasury_contract() {
+    let e = Env::default();
+    let c = setup(&e);
+    assert_eq!(
+        c.client.get_treasury_contract(),
+        Some(c.treasury.address.clone())
+    );
+}
+
+#[test]
+fn test_unauthenticated_deposit_fees_does_not_change_remit() {
+    let e = Env::default();
+    let c = setup(&e);
+    let token = TokenClient::new(&e, &c.token);
+    c.client
+        .create_bond(&c.identity, &10_000_i128, &86400_u64, &false, &0_u64);
+
+    e.set_auths(&[]);
+    let attacker = Address::generate(&e);
+    assert!(c.client.try_deposit_fees(&attacker, &5_000).is_err());
+    assert!(c.client.try_deposit_fees(&c.identity, &5_000).is_err());
+    e.mock_all_auths();
+
+    assert_eq!(c.client.remit_fees(&c.admin), 100);
+    assert_eq!(token.balance(&c.bond_id), 9_900);
+    assert_eq!(c.treasury.get_balance(), 100);
+}
+
+#[test]
+fn test_deposit_fees_moves_tokens_before_crediting() {
+    let e = Env::default();
+    let c = setup(&e);
+    let token = TokenClient::new(&e, &c.token);
+    let before = token.balance(&c.identity);
+
+    c.client.deposit_fees(&c.identity, &250);
+    assert_eq!(token.balance(&c.identity), before - 250);
+    assert_eq!(token.balance(&c.bond_id), 250);
+    assert_eq!(c.client.remit_fees(&c.admin), 250);
+    assert_eq!(token.balance(&c.bond_id), 0);
+}
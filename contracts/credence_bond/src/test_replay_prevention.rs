@@ -30,9 +30,21 @@ fn nonce_increments_after_add_attestation() {
     let (client, attester) = setup(&e);
     let subject = soroban_sdk::Address::generate(&e);
     assert_eq!(client.get_nonce(&attester), 0);
-    client.add_attestation(&attester, &subject, &String::from_str(&e, "d"), &0u64);
+    client.add_attestation(
+        &attester,
+        &subject,
+        &String::from_str(&e, "d"),
+        &AttestationCategory::Identity,
+        &0u64,
+    );
     assert_eq!(client.get_nonce(&attester), 1);
-    client.add_attestation(&attester, &subject, &String::from_str(&e, "d2"), &1u64);
+    client.add_attestation(
+        &attester,
+        &subject,
+        &String::from_str(&e, "d2"),
+        &AttestationCategory::Identity,
+        &1u64,
+    );
     assert_eq!(client.get_nonce(&attester), 2);
 }
 
@@ -43,8 +55,20 @@ fn replay_add_attestation_rejected() {
     let (client, attester) = setup(&e);
     let subject = soroban_sdk::Address::generate(&e);
     let data = String::from_str(&e, "once");
-    client.add_attestation(&attester, &subject, &data, &0u64);
-    client.add_attestation(&attester, &subject, &data, &0u64);
+    client.add_attestation(
+        &attester,
+        &subject,
+        &data,
+        &AttestationCategory::Identity,
+        &0u64,
+    );
+    client.add_attestation(
+        &attester,
+        &subject,
+        &data,
+        &AttestationCategory::Identity,
+        &0u64,
+    );
 }
 
 #[test]
@@ -53,7 +77,13 @@ fn wrong_nonce_rejected() {
     let e = Env::default();
     let (client, attester) = setup(&e);
     let subject = soroban_sdk::Address::generate(&e);
-    client.add_attestation(&attester, &subject, &String::from_str(&e, "x"), &1u64);
+    client.add_attestation(
+        &attester,
+        &subject,
+        &String::from_str(&e, "x"),
+        &AttestationCategory::Identity,
+        &1u64,
+    );
 }
 
 #[test]
@@ -65,6 +95,7 @@ fn nonce_increments_after_revoke() {
         &attester,
         &subject,
         &String::from_str(&e, "rev"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
     let nonce_before = client.get_nonce(&attester);
@@ -82,6 +113,7 @@ fn replay_revoke_rejected() {
         &attester,
         &subject,
         &String::from_str(&e, "r"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
     let used_nonce = client.get_nonce(&attester) - 1;
@@ -1,10 +1,12 @@
-//! Tests for Rolling Bond: auto-renewal, withdrawal request with notice period, renewal events.
+//! Tests for Rolling Bond: auto-renewal, withdrawal request with notice period, renewal events,
+//! and early withdrawal against the notice period.
 
 #![cfg(test)]
 
 use crate::test_helpers;
 use crate::{CredenceBond, CredenceBondClient};
 use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::token::TokenClient;
 use soroban_sdk::{Address, Env};
 
 fn setup(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
@@ -101,3 +103,156 @@ fn test_withdraw_after_notice_period() {
     let bond = client.withdraw(&500);
     assert_eq!(bond.bonded_amount, 500);
 }
+
+/// Rolling bond of 10_000_000 with a 1000s notice period and a 10% early exit rate.
+/// Returns (client, identity, token client, treasury).
+fn setup_rolling_early_exit(
+    e: &Env,
+) -> (CredenceBondClient<'_>, Address, TokenClient<'_>, Address) {
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, admin, identity, token, _bond_id) = test_helpers::setup_with_token(e);
+    let treasury = Address::generate(e);
+    client.set_early_exit_config(&admin, &treasury, &1_000);
+    client.create_bond(&identity, &10_000_000_i128, &86400_u64, &true, &1_000_u64);
+    (client, identity, TokenClient::new(e, &token), treasury)
+}
+
+#[test]
+fn test_withdraw_early_rolling_without_request_charges_full_rate() {
+    let e = Env::default();
+    let (client, _identity, token, treasury) = setup_rolling_early_exit(&e);
+    let bond = client.withdraw_early(&1_000_000);
+    assert_eq!(bond.bonded_amount, 9_000_000);
+    assert_eq!(token.balance(&treasury), 100_000);
+}
+
+#[test]
+fn test_withdraw_early_rolling_prorates_remaining_notice() {
+    let e = Env::default();
+    let (client, _identity, token, treasury) = setup_rolling_early_exit(&e);
+    client.request_withdrawal();
+
+    // Three quarters of the notice period left.
+    e.ledger().with_mut(|li| li.timestamp = 1250);
+    let bond = client.withdraw_early(&1_000_000);
+    assert_eq!(token.balance(&treasury), 75_000);
+    // The pending request is cleared, so the holder may request again.
+    assert_eq!(bond.withdrawal_requested_at, 0);
+    let bond = client.request_withdrawal();
+    assert_eq!(bond.withdrawal_requested_at, 1250);
+}
+
+#[test]
+fn test_withdraw_early_rolling_after_period_end() {
+    // A rolling bond past its period end (not yet renewed) can still exit early.
+    let e = Env::default();
+    let (client, _identity, token, treasury) = setup_rolling_early_exit(&e);
+    e.ledger().with_mut(|li| li.timestamp = 1000 + 86400 + 5);
+    client.withdraw_early(&1_000_000);
+    assert_eq!(token.balance(&treasury), 100_000);
+}
+
+#[test]
+#[should_panic(expected = "notice period elapsed; use withdraw")]
+fn test_withdraw_early_rolling_after_notice_elapsed() {
+    let e = Env::default();
+    let (client, _identity, _token, _treasury) = setup_rolling_early_exit(&e);
+    client.request_withdrawal();
+    e.ledger().with_mut(|li| li.timestamp = 2000);
+    client.withdraw_early(&1_000_000);
+}
+
+#[test]
+fn test_withdraw_early_rolling_then_withdraw_requires_new_request() {
+    let e = Env::default();
+    let (client, _identity, _token, _treasury) = setup_rolling_early_exit(&e);
+    client.request_withdrawal();
+    e.ledger().with_mut(|li| li.timestamp = 1500);
+    client.withdraw_early(&1_000_000);
+
+    // The earlier notice no longer counts once the early exit cleared it.
+    e.ledger().with_mut(|li| li.timestamp = 2500);
+    assert!(client.try_withdraw(&1_000_000).is_err());
+}
+
+#[test]
+fn test_renewal_info_starts_empty() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &true, &10_u64);
+    let info = client.get_renewal_info(&identity);
+    assert_eq!(info.renewal_count, 0);
+    assert_eq!(info.last_renewed_at, 0);
+}
+
+#[test]
+fn test_renewal_info_counts_each_renewal() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &true, &10_u64);
+
+    e.ledger().with_mut(|li| li.timestamp = 87400);
+    client.renew_if_rolling();
+    e.ledger().with_mut(|li| li.timestamp = 87400 + 86400);
+    client.renew_if_rolling();
+
+    let info = client.get_renewal_info(&identity);
+    assert_eq!(info.renewal_count, 2);
+    assert_eq!(info.last_renewed_at, 87400 + 86400);
+}
+
+#[test]
+fn test_renewal_info_not_advanced_by_no_op() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &true, &10_u64);
+    e.ledger().with_mut(|li| li.timestamp = 44200);
+    client.renew_if_rolling();
+    assert_eq!(client.get_renewal_info(&identity).renewal_count, 0);
+}
+
+#[test]
+fn test_renewal_info_catches_up_on_missed_periods() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &true, &10_u64);
+
+    // Three and a half periods pass before anyone renews.
+    let now = 1000 + 3 * 86400 + 43200;
+    e.ledger().with_mut(|li| li.timestamp = now);
+    let bond = client.renew_if_rolling();
+    assert_eq!(bond.bond_start, now);
+
+    let info = client.get_renewal_info(&identity);
+    assert_eq!(info.renewal_count, 3);
+    assert_eq!(info.last_renewed_at, now);
+}
+
+#[test]
+fn test_renewal_info_reset_for_new_bond() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+    let (client, _admin, identity) = setup(&e);
+    client.create_bond(&identity, &1000_i128, &86400_u64, &true, &10_u64);
+    e.ledger().with_mut(|li| li.timestamp = 87400);
+    client.renew_if_rolling();
+    assert_eq!(client.get_renewal_info(&identity).renewal_count, 1);
+
+    client.create_bond(&identity, &1000_i128, &86400_u64, &true, &10_u64);
+    assert_eq!(client.get_renewal_info(&identity).renewal_count, 0);
+}
+
+#[test]
+fn test_elapsed_periods_unit() {
+    use crate::rolling_bond::elapsed_periods;
+    assert_eq!(elapsed_periods(100, 100, 50), 0);
+    assert_eq!(elapsed_periods(150, 100, 50), 1);
+    assert_eq!(elapsed_periods(249, 100, 50), 2);
+    assert_eq!(elapsed_periods(250, 100, 50), 3);
+    assert_eq!(elapsed_periods(250, 100, 0), 1);
+    assert_eq!(elapsed_periods(u64::MAX, 0, 1), u32::MAX);
+}
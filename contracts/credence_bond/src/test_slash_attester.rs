@@ -0,0 +1,306 @@
+//! Tests for slashing attester stake: governance gating, clamping to stake,
+//! attestation revocation, slash-history tagging, and treasury transfer.
+
+#![cfg(test)]
+
+use crate::slash_history::SlashKind;
+use crate::test_helpers;
+use crate::{AttestationCategory, CredenceBondClient};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{vec, Address, Env, String, Symbol};
+
+struct Ctx<'a> {
+    client: CredenceBondClient<'a>,
+    admin: Address,
+    attester: Address,
+    governors: [Address; 2],
+    treasury: Address,
+    token: Address,
+    attestation_id: u64,
+}
+
+/// Attester with 5_000 deposited and one active attestation; two governors
+/// with a 100% quorum; fee treasury configured.
+fn setup(e: &Env) -> Ctx<'_> {
+    let (client, admin, _identity, token, _contract_id) = test_helpers::setup_with_token(e);
+    let attester = Address::generate(e);
+    client.register_attester(&attester);
+    StellarAssetClient::new(e, &token).mint(&attester, &5_000);
+    client.deposit_attester_stake(&attester, &5_000);
+
+    let subject = Address::generate(e);
+    let attestation_id = client
+        .add_attestation(
+            &attester,
+            &subject,
+            &String::from_str(e, "kyc"),
+            &AttestationCategory::Identity,
+            &client.get_nonce(&attester),
+        )
+        .id;
+
+    let governors = [Address::generate(e), Address::generate(e)];
+    client.initialize_governance(
+        &admin,
+        &vec![e, governors[0].clone(), governors[1].clone()],
+        &10_000_u32,
+        &2_u32,
+    );
+    let treasury = Address::generate(e);
+    client.set_fee_config(&admin, &treasury, &0_u32);
+
+    Ctx {
+        client,
+        admin,
+        attester,
+        governors,
+        treasury,
+        token,
+        attestation_id,
+    }
+}
+
+/// Propose a slash of `amount` of the attester's stake as admin and have both
+/// governors vote `approve`.
+fn propose(c: &Ctx<'_>, amount: i128, approve: bool) -> u64 {
+    let id = c
+        .client
+        .propose_attester_slash(&c.admin, &c.attester, &amount);
+    for g in c.governors.iter() {
+        c.client.governance_vote(g, &id, &approve);
+    }
+    id
+}
+
+fn reason(e: &Env) -> Symbol {
+    Symbol::new(e, "fraud")
+}
+
+#[test]
+fn test_slash_attester_moves_stake_to_treasury() {
+    let e = Env::default();
+    let c = setup(&e);
+    let proposal = propose(&c, 2_000, true);
+
+    let slashed = c.client.slash_attester(
+        &c.admin,
+        &c.attester,
+        &2_000,
+        &c.attestation_id,
+        &reason(&e),
+        &proposal,
+    );
+    assert_eq!(slashed, 2_000);
+    assert_eq!(c.client.get_attester_stake(&c.attester), 3_000);
+    assert_eq!(c.client.get_attester_deposit(&c.attester), 3_000);
+    assert_eq!(TokenClient::new(&e, &c.token).balance(&c.treasury), 2_000);
+}
+
+#[test]
+fn test_slash_attester_revokes_attestation_and_records_history() {
+    let e = Env::default();
+    let c = setup(&e);
+    let proposal = propose(&c, 1_000, true);
+    c.client.slash_attester(
+        &c.admin,
+        &c.attester,
+        &1_000,
+        &c.attestation_id,
+        &reason(&e),
+        &proposal,
+    );
+
+    assert!(c.client.get_attestation(&c.attestation_id).revoked);
+    let history = c.client.get_slash_history(&c.attester);
+    assert_eq!(history.len(), 1);
+    let record = history.get(0).unwrap();
+    assert_eq!(record.kind, SlashKind::Attester);
+    assert_eq!(record.slash_amount, 1_000);
+    assert_eq!(record.reason, reason(&e));
+    assert_eq!(record.total_slashed_after, 1_000);
+}
+
+#[test]
+fn test_slash_attester_clamps_to_stake() {
+    let e = Env::default();
+    let c = setup(&e);
+    let proposal = propose(&c, 9_000, true);
+
+    let slashed = c.client.slash_attester(
+        &c.admin,
+        &c.attester,
+        &9_000,
+        &c.attestation_id,
+        &reason(&e),
+        &proposal,
+    );
+    assert_eq!(slashed, 5_000);
+    assert_eq!(c.client.get_attester_stake(&c.attester), 0);
+    assert_eq!(TokenClient::new(&e, &c.token).balance(&c.treasury), 5_000);
+    assert_eq!(
+        c.client
+            .get_slash_history(&c.attester)
+            .get(0)
+            .unwrap()
+            .slash_amount,
+        5_000
+    );
+}
+
+#[test]
+fn test_slash_attester_only_transfers_deposited_tokens() {
+    let e = Env::default();
+    let c = setup(&e);
+    // Admin override lifts the stake figure above the real deposit.
+    c.client
+        .admin_set_attester_stake(&c.admin, &c.attester, &8_000);
+    let proposal = propose(&c, 8_000, true);
+
+    let slashed = c.client.slash_attester(
+        &c.admin,
+        &c.attester,
+        &8_000,
+        &c.attestation_id,
+        &reason(&e),
+        &proposal,
+    );
+    assert_eq!(slashed, 8_000);
+    assert_eq!(c.client.get_attester_deposit(&c.attester), 0);
+    assert_eq!(TokenClient::new(&e, &c.token).balance(&c.treasury), 5_000);
+}
+
+#[test]
+#[should_panic(expected = "proposal not approved")]
+fn test_slash_attester_requires_approval() {
+    let e = Env::default();
+    let c = setup(&e);
+    let proposal = propose(&c, 1_000, false);
+    c.client.slash_attester(
+        &c.admin,
+        &c.attester,
+        &1_000,
+        &c.attestation_id,
+        &reason(&e),
+        &proposal,
+    );
+}
+
+#[test]
+#[should_panic(expected = "slash amount exceeds proposal")]
+fn test_slash_attester_amount_above_proposal() {
+    let e = Env::default();
+    let c = setup(&e);
+    let proposal = propose(&c, 1_000, true);
+    c.client.slash_attester(
+        &c.admin,
+        &c.attester,
+        &1_001,
+        &c.attestation_id,
+        &reason(&e),
+        &proposal,
+    );
+}
+
+#[test]
+#[should_panic(expected = "only proposer can execute")]
+fn test_slash_attester_non_proposer() {
+    let e = Env::default();
+    let c = setup(&e);
+    let proposal = propose(&c, 1_000, true);
+    c.client.slash_attester(
+        &c.governors[0],
+        &c.attester,
+        &1_000,
+        &c.attestation_id,
+        &reason(&e),
+        &proposal,
+    );
+}
+
+#[test]
+#[should_panic(expected = "attestation not issued by attester")]
+fn test_slash_attester_wrong_attestation() {
+    let e = Env::default();
+    let c = setup(&e);
+    let other = Address::generate(&e);
+    let proposal = c.client.propose_attester_slash(&c.admin, &other, &1_000);
+    for g in c.governors.iter() {
+        c.client.governance_vote(g, &proposal, &true);
+    }
+    c.client.slash_attester(
+        &c.admin,
+        &other,
+        &1_000,
+        &c.attestation_id,
+        &reason(&e),
+        &proposal,
+    );
+}
+
+#[test]
+#[should_panic(expected = "proposal already closed")]
+fn test_slash_attester_proposal_single_use() {
+    let e = Env::default();
+    let c = setup(&e);
+    let proposal = propose(&c, 1_000, true);
+    for _ in 0..2 {
+        c.client.slash_attester(
+            &c.admin,
+            &c.attester,
+            &500,
+            &c.attestation_id,
+            &reason(&e),
+            &proposal,
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "proposal not for attester")]
+fn test_slash_attester_proposal_bound_to_attester() {
+    let e = Env::default();
+    let c = setup(&e);
+    let other = Address::generate(&e);
+    c.client.register_attester(&other);
+    let proposal = c.client.propose_attester_slash(&c.admin, &other, &1_000);
+    for g in c.governors.iter() {
+        c.client.governance_vote(g, &proposal, &true);
+    }
+    c.client.slash_attester(
+        &c.admin,
+        &c.attester,
+        &1_000,
+        &c.attestation_id,
+        &reason(&e),
+        &proposal,
+    );
+}
+
+#[test]
+#[should_panic(expected = "proposal not for attester")]
+fn test_slash_attester_rejects_bond_proposal() {
+    let e = Env::default();
+    let c = setup(&e);
+    let proposal = c.client.propose_slash(&c.admin, &1_000);
+    for g in c.governors.iter() {
+        c.client.governance_vote(g, &proposal, &true);
+    }
+    c.client.slash_attester(
+        &c.admin,
+        &c.attester,
+        &1_000,
+        &c.attestation_id,
+        &reason(&e),
+        &proposal,
+    );
+}
+
+#[test]
+#[should_panic(expected = "proposal targets an attester")]
+fn test_attester_proposal_cannot_slash_bond() {
+    let e = Env::default();
+    let c = setup(&e);
+    let proposal = propose(&c, 1_000, true);
+    c.client.execute_slash_with_governance(&c.admin, &proposal);
+}
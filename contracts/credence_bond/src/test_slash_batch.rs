@@ -0,0 +1,148 @@
+//! Tests for atomic batch slashing (`slash_batch`).
+
+#![cfg(test)]
+
+use crate::test_helpers;
+use crate::{CredenceBondClient, SlashParams};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, Symbol, Vec};
+
+fn setup_with_bond(e: &Env, amount: i128) -> (CredenceBondClient<'_>, Address, Address) {
+    let (client, admin, identity, _token_id, _bond_id) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &amount, &86400_u64, &false, &0_u64);
+    (client, admin, identity)
+}
+
+fn item(e: &Env, identity: &Address, amount: i128, reason: &str) -> SlashParams {
+    SlashParams {
+        identity: identity.clone(),
+        amount,
+        reason: Symbol::new(e, reason),
+    }
+}
+
+#[test]
+fn test_slash_batch_applies_all_items() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000);
+
+    let items = vec![
+        &e,
+        item(&e, &identity, 100, "fraud"),
+        item(&e, &identity, 250, "downtime"),
+    ];
+    let bond = client.slash_batch(&admin, &items);
+    assert_eq!(bond.slashed_amount, 350);
+
+    let history = client.get_slash_history(&identity);
+    assert_eq!(history.len(), 2);
+    let first = history.get(0).unwrap();
+    assert_eq!(first.slash_amount, 100);
+    assert_eq!(first.reason, Symbol::new(&e, "fraud"));
+    assert_eq!(first.total_slashed_after, 100);
+    let second = history.get(1).unwrap();
+    assert_eq!(second.slash_amount, 250);
+    assert_eq!(second.reason, Symbol::new(&e, "downtime"));
+    assert_eq!(second.total_slashed_after, 350);
+}
+
+#[test]
+fn test_slash_batch_up_to_available_balance() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000);
+    client.slash(&admin, &400);
+
+    let items = vec![&e, item(&e, &identity, 600, "fraud")];
+    let bond = client.slash_batch(&admin, &items);
+    assert_eq!(bond.slashed_amount, 1000);
+}
+
+#[test]
+#[should_panic(expected = "slash amount must be positive")]
+fn test_slash_batch_invalid_item_aborts_batch() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000);
+
+    let items = vec![
+        &e,
+        item(&e, &identity, 100, "fraud"),
+        item(&e, &identity, 0, "noop"),
+    ];
+    client.slash_batch(&admin, &items);
+}
+
+#[test]
+fn test_slash_batch_failure_leaves_state_untouched() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000);
+
+    let items = vec![
+        &e,
+        item(&e, &identity, 100, "fraud"),
+        item(&e, &identity, -5, "bad"),
+    ];
+    assert!(client.try_slash_batch(&admin, &items).is_err());
+    assert_eq!(client.get_identity_state().slashed_amount, 0);
+    assert_eq!(client.get_slash_history(&identity).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "slash exceeds available balance")]
+fn test_slash_batch_total_exceeds_available() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000);
+
+    let items = vec![
+        &e,
+        item(&e, &identity, 600, "fraud"),
+        item(&e, &identity, 500, "fraud"),
+    ];
+    client.slash_batch(&admin, &items);
+}
+
+#[test]
+#[should_panic(expected = "no bond for identity")]
+fn test_slash_batch_wrong_identity() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000);
+    let other = Address::generate(&e);
+
+    let items = vec![
+        &e,
+        item(&e, &identity, 100, "fraud"),
+        item(&e, &other, 100, "fraud"),
+    ];
+    client.slash_batch(&admin, &items);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_slash_batch_non_admin() {
+    let e = Env::default();
+    let (client, _admin, identity) = setup_with_bond(&e, 1000);
+    let attacker = Address::generate(&e);
+
+    let items = vec![&e, item(&e, &identity, 100, "fraud")];
+    client.slash_batch(&attacker, &items);
+}
+
+#[test]
+#[should_panic(expected = "empty slash batch")]
+fn test_slash_batch_empty() {
+    let e = Env::default();
+    let (client, admin, _identity) = setup_with_bond(&e, 1000);
+    client.slash_batch(&admin, &Vec::new(&e));
+}
+
+#[test]
+#[should_panic(expected = "slash batch too large")]
+fn test_slash_batch_too_large() {
+    let e = Env::default();
+    let (client, admin, identity) = setup_with_bond(&e, 1000);
+
+    let mut items = Vec::new(&e);
+    for _ in 0..=crate::slashing::MAX_SLASH_BATCH_SIZE {
+        items.push_back(item(&e, &identity, 1, "fraud"));
+    }
+    client.slash_batch(&admin, &items);
+}
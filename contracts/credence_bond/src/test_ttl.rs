@@ -0,0 +1,196 @@
+//! Tests for the public TTL extension endpoints.
+
+#![cfg(test)]
+
+use crate::slash_history::{self, SlashKind, SlashStorageKey};
+use crate::test_helpers;
+use crate::ttl::BUMP_TARGET;
+use crate::{AttestationCategory, CredenceBond, CredenceBondClient, DataKey, SlashParams};
+use soroban_sdk::testutils::storage::{Instance as _, Persistent as _};
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{vec, Address, Env, IntoVal, String, Symbol, Val};
+
+fn persistent_ttl<K: IntoVal<Env, Val>>(e: &Env, contract: &Address, key: &K) -> u32 {
+    e.as_contract(contract, || e.storage().persistent().get_ttl(key))
+}
+
+fn instance_ttl(e: &Env, contract: &Address) -> u32 {
+    e.as_contract(contract, || e.storage().instance().get_ttl())
+}
+
+/// Bond with a creation fee and one slash, so the identity has a fee ledger
+/// entry and slash history in persistent storage.
+fn setup_bond(e: &Env) -> (CredenceBondClient<'_>, Address, Address) {
+    let (client, admin, identity, _token, bond_id) = test_helpers::setup_with_token(e);
+    client.set_fee_config(&admin, &Address::generate(e), &100_u32);
+    client.create_bond(&identity, &10_000_i128, &86_400_u64, &false, &0_u64);
+    let item = SlashParams {
+        identity: identity.clone(),
+        amount: 1_000,
+        reason: Symbol::new(e, "fraud"),
+    };
+    client.slash_batch(&admin, &vec![e, item]);
+    (client, identity, bond_id)
+}
+
+#[test]
+fn test_extend_bond_ttl_bumps_instance_and_identity_entries() {
+    let e = Env::default();
+    let (client, identity, bond_id) = setup_bond(&e);
+    let fee_key = DataKey::FeePaid(identity.clone());
+    let count_key = SlashStorageKey::SlashCount(identity.clone());
+    let record_key = SlashStorageKey::SlashRecord(identity.clone(), 0);
+    assert!(persistent_ttl(&e, &bond_id, &fee_key) < BUMP_TARGET);
+
+    client.extend_bond_ttl(&identity);
+
+    assert_eq!(instance_ttl(&e, &bond_id), BUMP_TARGET);
+    assert_eq!(persistent_ttl(&e, &bond_id, &fee_key), BUMP_TARGET);
+    assert_eq!(persistent_ttl(&e, &bond_id, &count_key), BUMP_TARGET);
+    assert_eq!(persistent_ttl(&e, &bond_id, &record_key), BUMP_TARGET);
+}
+
+/// Append `count` extra slash records for `identity` directly to storage.
+fn seed_slash_records(e: &Env, bond_id: &Address, identity: &Address, count: u32) {
+    e.as_contract(bond_id, || {
+        for _ in 0..count {
+            slash_history::append_slash_history(
+                e,
+                identity,
+                SlashKind::Bond,
+                1,
+                Symbol::new(e, "fraud"),
+                1,
+            );
+        }
+    });
+}
+
+#[test]
+fn test_extend_bond_ttl_bumps_only_recent_slash_records() {
+    let e = Env::default();
+    let (client, identity, bond_id) = setup_bond(&e);
+    seed_slash_records(
+        &e,
+        &bond_id,
+        &identity,
+        slash_history::RECENT_RECORDS_BUMPED,
+    );
+    let oldest = SlashStorageKey::SlashRecord(identity.clone(), 0);
+    let latest =
+        SlashStorageKey::SlashRecord(identity.clone(), slash_history::RECENT_RECORDS_BUMPED);
+
+    client.extend_bond_ttl(&identity);
+
+    assert!(persistent_ttl(&e, &bond_id, &oldest) < BUMP_TARGET);
+    assert_eq!(persistent_ttl(&e, &bond_id, &latest), BUMP_TARGET);
+}
+
+#[test]
+fn test_extend_slash_history_ttl_range() {
+    let e = Env::default();
+    let (client, identity, bond_id) = setup_bond(&e);
+    seed_slash_records(&e, &bond_id, &identity, 4);
+
+    assert_eq!(client.extend_slash_history_ttl(&identity, &0, &2), 2);
+    assert_eq!(
+        persistent_ttl(
+            &e,
+            &bond_id,
+            &SlashStorageKey::SlashRecord(identity.clone(), 1)
+        ),
+        BUMP_TARGET
+    );
+    assert!(
+        persistent_ttl(
+            &e,
+            &bond_id,
+            &SlashStorageKey::SlashRecord(identity.clone(), 2)
+        ) < BUMP_TARGET
+    );
+    // The range stops at the last record.
+    assert_eq!(client.extend_slash_history_ttl(&identity, &3, &10), 2);
+    assert_eq!(
+        client.extend_slash_history_ttl(&Address::generate(&e), &0, &10),
+        0
+    );
+}
+
+#[test]
+#[should_panic(expected = "no bond")]
+fn test_extend_bond_ttl_wrong_identity() {
+    let e = Env::default();
+    let (client, _identity, _bond_id) = setup_bond(&e);
+    client.extend_bond_ttl(&Address::generate(&e));
+}
+
+#[test]
+#[should_panic(expected = "no bond")]
+fn test_extend_bond_ttl_without_bond() {
+    let e = Env::default();
+    let (client, _admin, identity, ..) = test_helpers::setup_with_token(&e);
+    client.extend_bond_ttl(&identity);
+}
+
+#[test]
+fn test_extend_attestation_ttl_bumps_trust_scores() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceBond, ());
+    let client = CredenceBondClient::new(&e, &contract_id);
+    client.initialize(&Address::generate(&e));
+    let attester = Address::generate(&e);
+    let subject = Address::generate(&e);
+    client.register_attester(&attester);
+    let att = client.add_attestation(
+        &attester,
+        &subject,
+        &String::from_str(&e, "kyc"),
+        &AttestationCategory::Identity,
+        &client.get_nonce(&attester),
+    );
+
+    client.extend_attestation_ttl(&att.id);
+
+    assert_eq!(instance_ttl(&e, &contract_id), BUMP_TARGET);
+    assert_eq!(
+        persistent_ttl(&e, &contract_id, &DataKey::TrustScore(subject.clone())),
+        BUMP_TARGET
+    );
+    assert_eq!(
+        persistent_ttl(
+            &e,
+            &contract_id,
+            &DataKey::CategoryTrustScore(subject, AttestationCategory::Identity)
+        ),
+        BUMP_TARGET
+    );
+}
+
+#[test]
+#[should_panic(expected = "attestation not found")]
+fn test_extend_attestation_ttl_missing() {
+    let e = Env::default();
+    let (client, ..) = test_helpers::setup_with_token(&e);
+    client.extend_attestation_ttl(&42);
+}
+
+#[test]
+fn test_extend_ttl_batch_skips_missing_keys() {
+    let e = Env::default();
+    let (client, identity, bond_id) = setup_bond(&e);
+    let fee_key = DataKey::FeePaid(identity);
+    let missing = DataKey::FeePaid(Address::generate(&e));
+
+    let bumped = client.extend_ttl_batch(&vec![&e, fee_key.clone(), missing]);
+    assert_eq!(bumped, 1);
+    assert_eq!(persistent_ttl(&e, &bond_id, &fee_key), BUMP_TARGET);
+    assert_eq!(instance_ttl(&e, &bond_id), BUMP_TARGET);
+}
+
+#[test]
+fn test_extend_ttl_batch_empty() {
+    let e = Env::default();
+    let (client, _identity, _bond_id) = setup_bond(&e);
+    assert_eq!(client.extend_ttl_batch(&vec![&e]), 0);
+}
@@ -0,0 +1,284 @@
+//! Tests for in-place contract upgrades and the upgrade timelock.
+
+#![cfg(test)]
+
+use crate::test_helpers;
+use crate::upgrade::{PendingDelay, MIN_UPGRADE_DELAY_SECS};
+use crate::{CredenceBondClient, DataKey, IdentityBond};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{vec, Address, Bytes, BytesN, Env, IntoVal, Symbol, TryFromVal};
+
+/// Minimal contract exposing `add(a: u64, b: u64) -> u64`, used as the upgrade target.
+const UPGRADE_TARGET_WASM: &[u8] = include_bytes!("../testdata/upgrade_target.wasm");
+
+struct Ctx<'a> {
+    client: CredenceBondClient<'a>,
+    admin: Address,
+    identity: Address,
+    governance: Address,
+    contract_id: Address,
+}
+
+fn setup(e: &Env) -> Ctx<'_> {
+    let (client, admin, identity, _token, contract_id) = test_helpers::setup_with_token(e);
+    client.create_bond(&identity, &10_000_i128, &86400_u64, &false, &0_u64);
+    let governance = Address::generate(e);
+    client.set_emergency_config(&admin, &governance, &Address::generate(e), &0_u32);
+    Ctx {
+        client,
+        admin,
+        identity,
+        governance,
+        contract_id,
+    }
+}
+
+fn upload_target(e: &Env) -> BytesN<32> {
+    e.deployer()
+        .upload_contract_wasm(Bytes::from_slice(e, UPGRADE_TARGET_WASM))
+}
+
+/// Propose `hash` and advance time past the default timelock.
+fn propose_and_wait(e: &Env, c: &Ctx, hash: &BytesN<32>) {
+    c.client.propose_upgrade(&c.admin, &c.governance, hash);
+    e.ledger()
+        .with_mut(|li| li.timestamp += MIN_UPGRADE_DELAY_SECS);
+}
+
+#[test]
+fn test_upgrade_swaps_wasm_and_keeps_storage() {
+    let e = Env::default();
+    let c = setup(&e);
+    let hash = upload_target(&e);
+
+    propose_and_wait(&e, &c, &hash);
+    c.client.upgrade(&c.admin, &c.governance, &hash);
+
+    let topics = vec![&e, Symbol::new(&e, "upgrade_executed").into_val(&e)];
+    let (_, _, data) = e
+        .events()
+        .all()
+        .iter()
+        .find(|(_, t, _)| t == &topics)
+        .expect("upgrade_executed event");
+    let (event_hash, from_version) = <(BytesN<32>, u32)>::try_from_val(&e, &data).unwrap();
+    assert_eq!(event_hash, hash);
+    assert_eq!(from_version, crate::CONTRACT_VERSION);
+
+    // The new wasm now answers calls on the same address.
+    let sum: u64 = e.invoke_contract(
+        &c.contract_id,
+        &Symbol::new(&e, "add"),
+        vec![&e, 2_u64.into_val(&e), 3_u64.into_val(&e)],
+    );
+    assert_eq!(sum, 5);
+
+    // Storage written by the old code survives the swap.
+    let (admin, bond) = e.as_contract(&c.contract_id, || {
+        let admin: Address = e.storage().instance().get(&DataKey::Admin).unwrap();
+        let bond: IdentityBond = e.storage().instance().get(&DataKey::Bond).unwrap();
+        (admin, bond)
+    });
+    assert_eq!(admin, c.admin);
+    assert_eq!(bond.identity, c.identity);
+    assert_eq!(bond.bonded_amount, 10_000);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_upgrade_rejects_non_admin() {
+    let e = Env::default();
+    let c = setup(&e);
+    let hash = upload_target(&e);
+    c.client
+        .upgrade(&Address::generate(&e), &c.governance, &hash);
+}
+
+#[test]
+#[should_panic(expected = "not governance")]
+fn test_upgrade_rejects_wrong_governance() {
+    let e = Env::default();
+    let c = setup(&e);
+    let hash = upload_target(&e);
+    c.client.upgrade(&c.admin, &Address::generate(&e), &hash);
+}
+
+#[test]
+#[should_panic(expected = "emergency config not set")]
+fn test_upgrade_requires_governance_config() {
+    let e = Env::default();
+    let (client, admin, ..) = test_helpers::setup_with_token(&e);
+    let hash = upload_target(&e);
+    client.upgrade(&admin, &Address::generate(&e), &hash);
+}
+
+#[test]
+fn test_upgrade_timelock_flow() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let c = setup(&e);
+    let hash = upload_target(&e);
+    assert_eq!(c.client.get_upgrade_delay(), MIN_UPGRADE_DELAY_SECS);
+
+    let pending = c.client.propose_upgrade(&c.admin, &c.governance, &hash);
+    let executable_at = 1_000 + MIN_UPGRADE_DELAY_SECS;
+    assert_eq!(pending.proposed_at, 1_000);
+    assert_eq!(pending.executable_at, executable_at);
+    assert_eq!(c.client.get_pending_upgrade(), Some(pending));
+
+    e.ledger().with_mut(|li| li.timestamp = executable_at - 1);
+    assert!(c
+        .client
+        .try_upgrade(&c.admin, &c.governance, &hash)
+        .is_err());
+
+    e.ledger().with_mut(|li| li.timestamp = executable_at);
+    c.client.upgrade(&c.admin, &c.governance, &hash);
+    let pending: Option<crate::upgrade::PendingUpgrade> = e.as_contract(&c.contract_id, || {
+        e.storage()
+            .instance()
+            .get(&crate::upgrade::UpgradeKey::Pending)
+    });
+    assert_eq!(pending, None);
+}
+
+#[test]
+#[should_panic(expected = "upgrade not proposed")]
+fn test_upgrade_requires_proposal() {
+    let e = Env::default();
+    let c = setup(&e);
+    let hash = upload_target(&e);
+    c.client.upgrade(&c.admin, &c.governance, &hash);
+}
+
+#[test]
+#[should_panic(expected = "upgrade timelock not elapsed")]
+fn test_upgrade_before_timelock() {
+    let e = Env::default();
+    let c = setup(&e);
+    let hash = upload_target(&e);
+    c.client.propose_upgrade(&c.admin, &c.governance, &hash);
+    c.client.upgrade(&c.admin, &c.governance, &hash);
+}
+
+#[test]
+#[should_panic(expected = "upgrade hash mismatch")]
+fn test_upgrade_hash_must_match_proposal() {
+    let e = Env::default();
+    let c = setup(&e);
+    let hash = upload_target(&e);
+    propose_and_wait(&e, &c, &hash);
+    c.client
+        .upgrade(&c.admin, &c.governance, &BytesN::from_array(&e, &[9; 32]));
+}
+
+#[test]
+fn test_cancel_upgrade() {
+    let e = Env::default();
+    let c = setup(&e);
+    let hash = upload_target(&e);
+    c.client.propose_upgrade(&c.admin, &c.governance, &hash);
+
+    c.client.cancel_upgrade(&c.admin, &c.governance);
+    assert_eq!(c.client.get_pending_upgrade(), None);
+    e.ledger()
+        .with_mut(|li| li.timestamp += MIN_UPGRADE_DELAY_SECS);
+    assert!(c
+        .client
+        .try_upgrade(&c.admin, &c.governance, &hash)
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "no pending upgrade")]
+fn test_cancel_upgrade_without_proposal() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client.cancel_upgrade(&c.admin, &c.governance);
+}
+
+#[test]
+#[should_panic(expected = "upgrade delay out of bounds")]
+fn test_set_upgrade_delay_above_max() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client.set_upgrade_delay(
+        &c.admin,
+        &c.governance,
+        &(crate::upgrade::MAX_UPGRADE_DELAY_SECS + 1),
+    );
+}
+
+#[test]
+#[should_panic(expected = "upgrade delay out of bounds")]
+fn test_set_upgrade_delay_below_min() {
+    let e = Env::default();
+    let c = setup(&e);
+    c.client
+        .set_upgrade_delay(&c.admin, &c.governance, &(MIN_UPGRADE_DELAY_SECS - 1));
+}
+
+#[test]
+fn test_upgrade_delay_increase_applies_at_once() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let c = setup(&e);
+    let hash = upload_target(&e);
+    let delay = 2 * MIN_UPGRADE_DELAY_SECS;
+
+    c.client.set_upgrade_delay(&c.admin, &c.governance, &delay);
+    assert_eq!(c.client.get_upgrade_delay(), delay);
+    assert_eq!(c.client.get_pending_upgrade_delay(), None);
+    let pending = c.client.propose_upgrade(&c.admin, &c.governance, &hash);
+    assert_eq!(pending.executable_at, 1_000 + delay);
+}
+
+#[test]
+fn test_upgrade_delay_reduction_waits_out_current_delay() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let c = setup(&e);
+    let hash = upload_target(&e);
+    let long = 3 * MIN_UPGRADE_DELAY_SECS;
+    c.client.set_upgrade_delay(&c.admin, &c.governance, &long);
+
+    c.client
+        .set_upgrade_delay(&c.admin, &c.governance, &MIN_UPGRADE_DELAY_SECS);
+    assert_eq!(c.client.get_upgrade_delay(), long);
+    assert_eq!(
+        c.client.get_pending_upgrade_delay(),
+        Some(PendingDelay {
+            delay_secs: MIN_UPGRADE_DELAY_SECS,
+            effective_at: 1_000 + long,
+        })
+    );
+
+    // A proposal made before the reduction applies keeps the long delay.
+    let pending = c.client.propose_upgrade(&c.admin, &c.governance, &hash);
+    assert_eq!(pending.executable_at, 1_000 + long);
+    e.ledger()
+        .with_mut(|li| li.timestamp = 1_000 + MIN_UPGRADE_DELAY_SECS);
+    assert!(c
+        .client
+        .try_upgrade(&c.admin, &c.governance, &hash)
+        .is_err());
+
+    e.ledger().with_mut(|li| li.timestamp = 1_000 + long);
+    assert_eq!(c.client.get_upgrade_delay(), MIN_UPGRADE_DELAY_SECS);
+    assert_eq!(c.client.get_pending_upgrade_delay(), None);
+}
+
+#[test]
+fn test_raising_upgrade_delay_drops_queued_reduction() {
+    let e = Env::default();
+    let c = setup(&e);
+    let long = 3 * MIN_UPGRADE_DELAY_SECS;
+    c.client.set_upgrade_delay(&c.admin, &c.governance, &long);
+    c.client
+        .set_upgrade_delay(&c.admin, &c.governance, &MIN_UPGRADE_DELAY_SECS);
+
+    c.client.set_upgrade_delay(&c.admin, &c.governance, &long);
+    assert_eq!(c.client.get_pending_upgrade_delay(), None);
+    e.ledger().with_mut(|li| li.timestamp += long);
+    assert_eq!(c.client.get_upgrade_delay(), long);
+}
@@ -34,6 +34,7 @@ fn default_weight_is_one() {
         &attester,
         &subject,
         &String::from_str(&e, "data"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
     assert_eq!(att.weight, 1);
@@ -43,13 +44,14 @@ fn default_weight_is_one() {
 fn weight_increases_with_stake() {
     let e = Env::default();
     let (client, admin, attester) = setup(&e);
-    client.set_attester_stake(&admin, &attester, &1_000_000i128);
+    client.admin_set_attester_stake(&admin, &attester, &1_000_000i128);
     client.set_weight_config(&admin, &100u32, &100_000u32);
     let subject = soroban_sdk::Address::generate(&e);
     let att = client.add_attestation(
         &attester,
         &subject,
         &String::from_str(&e, "data"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
     assert!(att.weight >= 1);
@@ -59,13 +61,14 @@ fn weight_increases_with_stake() {
 fn weight_capped_by_config() {
     let e = Env::default();
     let (client, admin, attester) = setup(&e);
-    client.set_attester_stake(&admin, &attester, &1_000_000_000_000i128);
+    client.admin_set_attester_stake(&admin, &attester, &1_000_000_000_000i128);
     client.set_weight_config(&admin, &100_000u32, &500u32);
     let subject = soroban_sdk::Address::generate(&e);
     let att = client.add_attestation(
         &attester,
         &subject,
         &String::from_str(&e, "capped"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
     assert!(att.weight <= 500);
@@ -120,7 +123,7 @@ fn set_attester_stake_negative_panics() {
     let admin = soroban_sdk::Address::generate(&e);
     client.initialize(&admin);
     let attester = soroban_sdk::Address::generate(&e);
-    client.set_attester_stake(&admin, &attester, &(-1i128));
+    client.admin_set_attester_stake(&admin, &attester, &(-1i128));
 }
 
 #[test]
@@ -128,7 +131,7 @@ fn weight_capped_by_max_attestation_weight() {
     let e = Env::default();
     let (client, admin, attester) = setup(&e);
     // Use stake high enough to exceed MAX_ATTESTATION_WEIGHT but avoid overflow: 200M * 100 / 10_000 = 2M
-    client.set_attester_stake(&admin, &attester, &200_000_000i128);
+    client.admin_set_attester_stake(&admin, &attester, &200_000_000i128);
     let max_requested = MAX_ATTESTATION_WEIGHT + 1000u32;
     client.set_weight_config(&admin, &100u32, &max_requested);
     let subject = soroban_sdk::Address::generate(&e);
@@ -136,6 +139,7 @@ fn weight_capped_by_max_attestation_weight() {
         &attester,
         &subject,
         &String::from_str(&e, "max_cap"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
     assert!(att.weight <= MAX_ATTESTATION_WEIGHT);
@@ -147,20 +151,22 @@ fn weight_updates_when_stake_changes() {
     let (client, admin, attester) = setup(&e);
     client.set_weight_config(&admin, &100u32, &100_000u32);
 
-    client.set_attester_stake(&admin, &attester, &10_000i128);
+    client.admin_set_attester_stake(&admin, &attester, &10_000i128);
     let subject = soroban_sdk::Address::generate(&e);
     let att1 = client.add_attestation(
         &attester,
         &subject,
         &String::from_str(&e, "first"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
 
-    client.set_attester_stake(&admin, &attester, &1_000_000i128);
+    client.admin_set_attester_stake(&admin, &attester, &1_000_000i128);
     let att2 = client.add_attestation(
         &attester,
         &subject,
         &String::from_str(&e, "second"),
+        &AttestationCategory::Identity,
         &client.get_nonce(&attester),
     );
 
@@ -179,3 +185,115 @@ fn set_weight_config_caps_max_at_protocol_limit() {
     let (_mult, max) = client.get_weight_config();
     assert_eq!(max, MAX_ATTESTATION_WEIGHT);
 }
+
+fn attest(
+    client: &CredenceBondClient,
+    e: &Env,
+    attester: &soroban_sdk::Address,
+    subject: &soroban_sdk::Address,
+    data: &str,
+) -> Attestation {
+    client.add_attestation(
+        attester,
+        subject,
+        &String::from_str(e, data),
+        &AttestationCategory::Identity,
+        &client.get_nonce(attester),
+    )
+}
+
+#[test]
+fn trust_score_sums_weights_across_attesters() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    let attester2 = soroban_sdk::Address::generate(&e);
+    client.register_attester(&attester2);
+    client.set_weight_config(&admin, &100u32, &100_000u32);
+    client.admin_set_attester_stake(&admin, &attester, &1_000_000i128); // weight 10_000
+    client.admin_set_attester_stake(&admin, &attester2, &250_000i128); // weight 2_500
+    let subject = soroban_sdk::Address::generate(&e);
+    assert_eq!(client.get_trust_score(&subject), 0);
+
+    let a1 = attest(&client, &e, &attester, &subject, "kyc");
+    let a2 = attest(&client, &e, &attester2, &subject, "kyc");
+    assert_eq!(a1.weight, 10_000);
+    assert_eq!(a2.weight, 2_500);
+    assert_eq!(client.get_trust_score(&subject), 12_500);
+
+    // Another subject is unaffected.
+    let other = soroban_sdk::Address::generate(&e);
+    assert_eq!(client.get_trust_score(&other), 0);
+}
+
+#[test]
+fn trust_score_decreases_on_revoke() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    let attester2 = soroban_sdk::Address::generate(&e);
+    client.register_attester(&attester2);
+    client.set_weight_config(&admin, &100u32, &100_000u32);
+    client.admin_set_attester_stake(&admin, &attester, &1_000_000i128);
+    client.admin_set_attester_stake(&admin, &attester2, &250_000i128);
+    let subject = soroban_sdk::Address::generate(&e);
+
+    let a1 = attest(&client, &e, &attester, &subject, "kyc");
+    attest(&client, &e, &attester2, &subject, "kyc");
+    client.revoke_attestation(&attester, &a1.id, &client.get_nonce(&attester));
+    assert_eq!(client.get_trust_score(&subject), 2_500);
+}
+
+#[test]
+fn trust_score_uses_stored_weight_after_stake_change() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    client.set_weight_config(&admin, &100u32, &100_000u32);
+    client.admin_set_attester_stake(&admin, &attester, &1_000_000i128);
+    let subject = soroban_sdk::Address::generate(&e);
+
+    let a1 = attest(&client, &e, &attester, &subject, "kyc");
+    client.admin_set_attester_stake(&admin, &attester, &0i128);
+    attest(&client, &e, &attester, &subject, "employment"); // weight 1
+    assert_eq!(client.get_trust_score(&subject), 10_001);
+
+    // Revoking removes exactly the weight recorded at attestation time.
+    client.revoke_attestation(&attester, &a1.id, &client.get_nonce(&attester));
+    assert_eq!(client.get_trust_score(&subject), 1);
+}
+
+#[test]
+fn trust_score_saturates_at_configured_max() {
+    let e = Env::default();
+    let (client, admin, attester) = setup(&e);
+    let attester2 = soroban_sdk::Address::generate(&e);
+    client.register_attester(&attester2);
+    client.set_weight_config(&admin, &100u32, &100_000u32);
+    client.admin_set_attester_stake(&admin, &attester, &1_000_000i128);
+    client.admin_set_attester_stake(&admin, &attester2, &1_000_000i128);
+    client.set_max_trust_score(&admin, &15_000u64);
+    assert_eq!(client.get_max_trust_score(), 15_000);
+    let subject = soroban_sdk::Address::generate(&e);
+
+    let a1 = attest(&client, &e, &attester, &subject, "kyc");
+    attest(&client, &e, &attester2, &subject, "kyc");
+    assert_eq!(client.get_trust_score(&subject), 15_000);
+
+    // The uncapped sum is kept, so revoking drops below the cap correctly.
+    client.revoke_attestation(&attester, &a1.id, &client.get_nonce(&attester));
+    assert_eq!(client.get_trust_score(&subject), 10_000);
+}
+
+#[test]
+#[should_panic(expected = "max trust score must be positive")]
+fn trust_score_max_zero_rejected() {
+    let e = Env::default();
+    let (client, admin, _attester) = setup(&e);
+    client.set_max_trust_score(&admin, &0u64);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn trust_score_max_non_admin_rejected() {
+    let e = Env::default();
+    let (client, _admin, attester) = setup(&e);
+    client.set_max_trust_score(&attester, &100u64);
+}
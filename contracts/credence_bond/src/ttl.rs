@@ -0,0 +1,28 @@
+//! TTL maintenance for bond data.
+//!
+//! Lets anyone keep bond records, attestations and per-identity ledgers alive
+//! without a state-changing call. Thresholds match `dispute_resolution`.
+
+use soroban_sdk::{Env, IntoVal, Val};
+
+/// Minimum ledger TTL before an entry's TTL is bumped (~1 day at 5 s/ledger).
+pub const BUMP_THRESHOLD: u32 = 17_280;
+/// Target ledger TTL after a bump (~30 days).
+pub const BUMP_TARGET: u32 = 518_400;
+
+/// Bump the contract instance, which holds the bond and attestation records.
+pub fn extend_instance(e: &Env) {
+    e.storage()
+        .instance()
+        .extend_ttl(BUMP_THRESHOLD, BUMP_TARGET);
+}
+
+/// Bump a persistent entry if it exists. Returns whether it was bumped.
+pub fn extend_persistent<K: IntoVal<Env, Val>>(e: &Env, key: &K) -> bool {
+    let storage = e.storage().persistent();
+    if !storage.has(key) {
+        return false;
+    }
+    storage.extend_ttl(key, BUMP_THRESHOLD, BUMP_TARGET);
+    true
+}
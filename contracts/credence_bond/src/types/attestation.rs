@@ -3,8 +3,11 @@
 //! Defines the Attestation type used for credibility attestations: verifier (attester),
 //! subject (identity), timestamp, weight. Supports serialization via ContractType
 //! and validation methods for storage efficiency and safety.
+//!
+//! Payloads are either stored inline as a string or as a 32-byte content hash with a
+//! short off-chain URI (`AttestationPayload`).
 
-use soroban_sdk::{contracttype, Address, String};
+use soroban_sdk::{contracttype, Address, BytesN, String};
 
 /// Maximum allowed attestation weight (prevents overflow and caps influence).
 pub const MAX_ATTESTATION_WEIGHT: u32 = 1_000_000;
@@ -12,6 +15,30 @@ pub const MAX_ATTESTATION_WEIGHT: u32 = 1_000_000;
 /// Default weight when attester has no stake configured.
 pub const DEFAULT_ATTESTATION_WEIGHT: u32 = 1;
 
+/// Maximum length in bytes of the off-chain URI of a hashed attestation.
+pub const MAX_ATTESTATION_URI_LEN: u32 = 256;
+
+/// Kind of claim an attestation makes, so consumers can tell KYC from performance
+/// from endorsement attestations. `Custom` carries an application-defined code.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AttestationCategory {
+    Identity,
+    Performance,
+    Endorsement,
+    Custom(u32),
+}
+
+/// Attestation payload: inline data, or a content hash plus off-chain URI.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AttestationPayload {
+    /// Opaque payload stored on-chain (e.g. claim type).
+    Inline(String),
+    /// SHA-256 (or similar) hash of the payload and the URI where it can be fetched.
+    Hashed(BytesN<32>, String),
+}
+
 /// Attestation record: a verifier's credibility attestation for an identity.
 ///
 /// # Fields
@@ -20,11 +47,14 @@ pub const DEFAULT_ATTESTATION_WEIGHT: u32 = 1;
 /// * `identity` - Address of the subject (identity) being attested.
 /// * `timestamp` - Ledger timestamp when the attestation was added.
 /// * `weight` - Credibility weight (e.g. derived from attester bond); capped by protocol.
-/// * `attestation_data` - Opaque attestation payload (e.g. claim type or hash).
+/// * `category` - Kind of claim (identity, performance, endorsement, custom).
+/// * `payload` - Inline payload or content hash with off-chain URI.
 /// * `revoked` - Whether this attestation has been revoked.
+/// * `contested` - Whether the subject currently contests this attestation.
+/// * `contested_at` - Ledger timestamp of the latest contest (0 if never contested).
 ///
 /// # Serialization
-/// Uses `#[contracttype]` for Soroban instance storage; space-efficient (u64, u32, bool, Address, String).
+/// Uses `#[contracttype]` for Soroban instance storage; space-efficient (u64, u32, bool, Address, payload).
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Attestation {
@@ -33,8 +63,11 @@ pub struct Attestation {
     pub identity: Address,
     pub timestamp: u64,
     pub weight: u32,
-    pub attestation_data: String,
+    pub category: AttestationCategory,
+    pub payload: AttestationPayload,
     pub revoked: bool,
+    pub contested: bool,
+    pub contested_at: u64,
 }
 
 impl Attestation {
@@ -61,20 +94,54 @@ impl Attestation {
         Self::validate_weight(self.weight);
     }
 
+    /// Validates the off-chain URI of a hashed payload.
+    ///
+    /// # Errors
+    /// Panics if `uri` is empty or longer than `MAX_ATTESTATION_URI_LEN`.
+    #[inline]
+    pub fn validate_uri(uri: &String) {
+        if uri.is_empty() {
+            panic!("attestation uri must not be empty");
+        }
+        if uri.len() > MAX_ATTESTATION_URI_LEN {
+            panic!("attestation uri too long");
+        }
+    }
+
     /// Returns true if this attestation is currently active (not revoked).
     #[must_use]
     #[inline]
     pub fn is_active(&self) -> bool {
         !self.revoked
     }
+
+    /// Returns true if this attestation counts towards the subject's trust score
+    /// (active and not contested).
+    #[must_use]
+    #[inline]
+    pub fn counts_towards_trust(&self) -> bool {
+        !self.revoked && !self.contested
+    }
 }
 
-/// Key used to detect duplicate attestations: same verifier, identity, and data.
+/// Key used to detect duplicate attestations: same verifier, identity, category and data.
 /// Stored in instance storage to prevent adding the same attestation twice.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AttestationDedupKey {
     pub verifier: Address,
     pub identity: Address,
+    pub category: AttestationCategory,
     pub attestation_data: String,
 }
+
+/// Duplicate-detection key for hashed attestations: same verifier, identity, category and hash.
+/// The URI is not part of the key, so re-hosting a payload cannot bypass the check.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HashedAttestationDedupKey {
+    pub verifier: Address,
+    pub identity: Address,
+    pub category: AttestationCategory,
+    pub data_hash: BytesN<32>,
+}
@@ -1,9 +1,11 @@
 //! Protocol data types for bonds and attestations.
 //!
-//! Includes Attestation (with weight), validation, and deduplication key types.
+//! Includes Attestation (with weight and payload), validation, and deduplication key types.
 
 pub mod attestation;
 
 pub use attestation::{
-    Attestation, AttestationDedupKey, DEFAULT_ATTESTATION_WEIGHT, MAX_ATTESTATION_WEIGHT,
+    Attestation, AttestationCategory, AttestationDedupKey, AttestationPayload,
+    HashedAttestationDedupKey, DEFAULT_ATTESTATION_WEIGHT, MAX_ATTESTATION_URI_LEN,
+    MAX_ATTESTATION_WEIGHT,
 };
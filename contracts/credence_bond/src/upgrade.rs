@@ -0,0 +1,183 @@
+//! Contract Upgrade
+//!
+//! Replaces the contract's wasm in place so bugfixes keep existing storage instead of
+//! requiring a redeploy and manual migration. Every upgrade action needs both the
+//! contract admin and the governance address from the emergency config to sign.
+//!
+//! Every upgrade follows a timelock:
+//!   1. Admin and governance call `propose_upgrade` with the new wasm hash.
+//!   2. Once the delay has elapsed, they call `upgrade` with the same hash.
+//!   3. Until then the proposal may be withdrawn via `cancel_upgrade`.
+//!
+//! The delay defaults to `MIN_UPGRADE_DELAY_SECS` and can never go below it.
+//! Raising it takes effect at once; lowering it only takes effect after the
+//! current delay has elapsed, so a shorter timelock cannot be used to rush an
+//! upgrade through.
+
+use soroban_sdk::{contracttype, Address, BytesN, Env, Symbol};
+
+use crate::emergency;
+
+/// Minimum and default upgrade timelock in seconds (1 day).
+pub const MIN_UPGRADE_DELAY_SECS: u64 = 86_400;
+
+/// Maximum upgrade timelock in seconds (30 days).
+pub const MAX_UPGRADE_DELAY_SECS: u64 = 2_592_000;
+
+/// An upgrade waiting for its timelock to elapse.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingUpgrade {
+    pub wasm_hash: BytesN<32>,
+    pub proposed_at: u64,
+    pub executable_at: u64,
+}
+
+/// A reduction of the upgrade timelock waiting for the current delay to elapse.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingDelay {
+    pub delay_secs: u64,
+    pub effective_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum UpgradeKey {
+    Delay,
+    Pending,
+    PendingDelay,
+}
+
+fn require_governance(e: &Env, governance: &Address) {
+    if emergency::get_config(e).governance != *governance {
+        panic!("not governance");
+    }
+}
+
+/// Current upgrade timelock in seconds, including a queued reduction whose
+/// wait has elapsed.
+#[must_use]
+pub fn get_delay(e: &Env) -> u64 {
+    let queued: Option<PendingDelay> = e.storage().instance().get(&UpgradeKey::PendingDelay);
+    match queued {
+        Some(pending) if e.ledger().timestamp() >= pending.effective_at => pending.delay_secs,
+        _ => e
+            .storage()
+            .instance()
+            .get(&UpgradeKey::Delay)
+            .unwrap_or(MIN_UPGRADE_DELAY_SECS),
+    }
+}
+
+/// Reduction of the upgrade timelock that has not taken effect yet.
+#[must_use]
+pub fn get_pending_delay(e: &Env) -> Option<PendingDelay> {
+    e.storage()
+        .instance()
+        .get::<_, PendingDelay>(&UpgradeKey::PendingDelay)
+        .filter(|pending| e.ledger().timestamp() < pending.effective_at)
+}
+
+/// Set the upgrade timelock. An increase, or keeping the current value, applies
+/// at once and drops any queued reduction. A reduction is queued and applies
+/// once the current delay has elapsed. Caller is responsible for admin checks.
+///
+/// # Panics
+/// - "not governance" if `governance` differs from the configured address
+/// - "upgrade delay out of bounds" if `delay_secs` is below `MIN_UPGRADE_DELAY_SECS`
+///   or above `MAX_UPGRADE_DELAY_SECS`
+pub fn set_delay(e: &Env, governance: &Address, delay_secs: u64) {
+    require_governance(e, governance);
+    if !(MIN_UPGRADE_DELAY_SECS..=MAX_UPGRADE_DELAY_SECS).contains(&delay_secs) {
+        panic!("upgrade delay out of bounds");
+    }
+    let old = get_delay(e);
+    e.storage().instance().remove(&UpgradeKey::PendingDelay);
+    if delay_secs >= old {
+        e.storage().instance().set(&UpgradeKey::Delay, &delay_secs);
+        e.events().publish(
+            (Symbol::new(e, "upgrade_delay_updated"),),
+            (old, delay_secs),
+        );
+        return;
+    }
+
+    let pending = PendingDelay {
+        delay_secs,
+        effective_at: e.ledger().timestamp().saturating_add(old),
+    };
+    e.storage().instance().set(&UpgradeKey::Delay, &old);
+    e.storage()
+        .instance()
+        .set(&UpgradeKey::PendingDelay, &pending);
+    e.events().publish(
+        (Symbol::new(e, "upgrade_delay_queued"),),
+        (old, delay_secs, pending.effective_at),
+    );
+}
+
+#[must_use]
+pub fn get_pending(e: &Env) -> Option<PendingUpgrade> {
+    e.storage().instance().get(&UpgradeKey::Pending)
+}
+
+/// Record `wasm_hash` as the pending upgrade, replacing any earlier proposal.
+/// Caller is responsible for admin checks.
+///
+/// # Panics
+/// - "not governance" if `governance` differs from the configured address
+pub fn propose(e: &Env, governance: &Address, wasm_hash: &BytesN<32>) -> PendingUpgrade {
+    require_governance(e, governance);
+    let now = e.ledger().timestamp();
+    let pending = PendingUpgrade {
+        wasm_hash: wasm_hash.clone(),
+        proposed_at: now,
+        executable_at: now.saturating_add(get_delay(e)),
+    };
+    e.storage().instance().set(&UpgradeKey::Pending, &pending);
+    e.events().publish(
+        (Symbol::new(e, "upgrade_proposed"),),
+        (wasm_hash.clone(), pending.executable_at),
+    );
+    pending
+}
+
+/// Drop the pending upgrade. Caller is responsible for admin checks.
+///
+/// # Panics
+/// - "not governance" if `governance` differs from the configured address
+/// - "no pending upgrade" if nothing was proposed
+pub fn cancel(e: &Env, governance: &Address) {
+    require_governance(e, governance);
+    let pending = get_pending(e).unwrap_or_else(|| panic!("no pending upgrade"));
+    e.storage().instance().remove(&UpgradeKey::Pending);
+    e.events()
+        .publish((Symbol::new(e, "upgrade_cancelled"),), pending.wasm_hash);
+}
+
+/// Swap the contract wasm for `wasm_hash`. The hash must match a proposal whose
+/// timelock has elapsed. The event carries the version being replaced.
+/// Caller is responsible for admin checks.
+///
+/// # Panics
+/// - "not governance" if `governance` differs from the configured address
+/// - "upgrade not proposed" if no upgrade is pending
+/// - "upgrade hash mismatch" if the pending proposal is for a different hash
+/// - "upgrade timelock not elapsed" if the delay has not passed yet
+pub fn execute(e: &Env, governance: &Address, wasm_hash: &BytesN<32>) {
+    require_governance(e, governance);
+    let pending = get_pending(e).unwrap_or_else(|| panic!("upgrade not proposed"));
+    if pending.wasm_hash != *wasm_hash {
+        panic!("upgrade hash mismatch");
+    }
+    if e.ledger().timestamp() < pending.executable_at {
+        panic!("upgrade timelock not elapsed");
+    }
+    e.storage().instance().remove(&UpgradeKey::Pending);
+    e.deployer().update_current_contract_wasm(wasm_hash.clone());
+    e.events().publish(
+        (Symbol::new(e, "upgrade_executed"),),
+        (wasm_hash.clone(), crate::CONTRACT_VERSION),
+    );
+}
@@ -1,37 +1,69 @@
-//! Duration Validation Module
+//! Bond Validation Module
 //!
-//! Provides validation logic for bond durations including minimum and maximum limit
-//! enforcement. All bond creations must pass duration validation before proceeding.
+//! Provides validation logic for bond amounts and durations against the
+//! governance-configured limits in [`crate::parameters`]. All bond creations must
+//! pass validation before proceeding.
 //!
 //! ## Constraints
-//! - **Minimum Duration**: Bonds must have a duration of at least 1 day (86_400 seconds)
-//!   to prevent trivially short bonds that offer no meaningful commitment.
-//! - **Maximum Duration**: Bonds are capped at 365 days (31_536_000 seconds) to limit
+//! - **Minimum Duration**: Defaults to 1 day (86_400 seconds) to prevent trivially
+//!   short bonds that offer no meaningful commitment.
+//! - **Maximum Duration**: Defaults to 365 days (31_536_000 seconds) to limit
 //!   excessive lock-up risk and contract state lifetime.
+//! - **Amount Range**: Defaults to any non-negative amount; governance may raise the
+//!   minimum or cap the maximum.
 //!
 //! ## Error Messages
-//! - `"bond duration too short: minimum is 86400 seconds (1 day)"` — when duration < MIN
-//! - `"bond duration too long: maximum is 31536000 seconds (365 days)"` — when duration > MAX
+//! - `"bond duration too short: minimum is <min> seconds"` — when duration < configured min
+//! - `"bond duration too long: maximum is <max> seconds"` — when duration > configured max
+//! - `"bond amount too low: minimum is <min>"` — when amount < configured min
+//! - `"bond amount too high: maximum is <max>"` — when amount > configured max
 
-/// Minimum bond duration in seconds (1 day = 86_400 seconds).
+use soroban_sdk::Env;
+
+use crate::parameters;
+
+/// Default minimum bond duration in seconds (1 day = 86_400 seconds).
 pub const MIN_BOND_DURATION: u64 = 86_400;
 
-/// Maximum bond duration in seconds (365 days = 31_536_000 seconds).
+/// Default maximum bond duration in seconds (365 days = 31_536_000 seconds).
 pub const MAX_BOND_DURATION: u64 = 31_536_000;
 
-/// Validate that a bond duration falls within the allowed range.
+/// Validate that a bond duration falls within the configured range.
 ///
 /// # Arguments
+/// * `e` - Soroban environment (reads the stored duration limits).
 /// * `duration` - The bond duration in seconds to validate.
 ///
 /// # Panics
-/// * `"bond duration too short: minimum is 86400 seconds (1 day)"` if `duration` < `MIN_BOND_DURATION`
-/// * `"bond duration too long: maximum is 31536000 seconds (365 days)"` if `duration` > `MAX_BOND_DURATION`
-pub fn validate_bond_duration(duration: u64) {
-    if duration < MIN_BOND_DURATION {
-        panic!("bond duration too short: minimum is 86400 seconds (1 day)");
+/// * `"bond duration too short: minimum is <min> seconds"` if `duration` < configured minimum
+/// * `"bond duration too long: maximum is <max> seconds"` if `duration` > configured maximum
+pub fn validate_bond_duration(e: &Env, duration: u64) {
+    let min = parameters::get_min_bond_duration(e);
+    if duration < min {
+        panic!("bond duration too short: minimum is {} seconds", min);
+    }
+    let max = parameters::get_max_bond_duration(e);
+    if duration > max {
+        panic!("bond duration too long: maximum is {} seconds", max);
+    }
+}
+
+/// Validate that a bond amount falls within the configured range.
+///
+/// # Arguments
+/// * `e` - Soroban environment (reads the stored amount limits).
+/// * `amount` - The bond amount in token units to validate.
+///
+/// # Panics
+/// * `"bond amount too low: minimum is <min>"` if `amount` < configured minimum
+/// * `"bond amount too high: maximum is <max>"` if `amount` > configured maximum
+pub fn validate_bond_amount(e: &Env, amount: i128) {
+    let min = parameters::get_min_bond_amount(e);
+    if amount < min {
+        panic!("bond amount too low: minimum is {}", min);
     }
-    if duration > MAX_BOND_DURATION {
-        panic!("bond duration too long: maximum is 31536000 seconds (365 days)");
+    let max = parameters::get_max_bond_amount(e);
+    if amount > max {
+        panic!("bond amount too high: maximum is {}", max);
     }
 }
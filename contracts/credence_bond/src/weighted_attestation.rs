@@ -5,15 +5,33 @@
 //! a configurable multiplier (basis points) and a protocol cap. When attester bond changes,
 //! new attestations use the new weight; existing attestations retain their stored weight.
 //!
+//! ## Attester Stake
+//! Attesters back their weight with real collateral via `deposit_attester_stake` /
+//! `withdraw_attester_stake`, which move tokens and update `DataKey::AttesterStake`.
+//! Deposited tokens are tracked separately (`DataKey::AttesterDeposit`) so that only
+//! real collateral can ever be withdrawn; `admin_set_attester_stake` remains as a
+//! migration override for the stake figure only. While an attester has active
+//! attestations, withdrawals may not take the stake below the configured minimum.
+//! Deposits and active attestation counts are per attester, so they live in
+//! persistent storage and are bumped on every write.
+//!
+//! ## Trust Score
+//! Each subject has an aggregate trust score: the sum of the weights of its active
+//! (non-revoked) attestations, overall and per attestation category. The raw sums are
+//! maintained incrementally on add/revoke in persistent storage and saturate at a
+//! configurable maximum when read.
+//!
 //! ## Security
 //! - Maximum weight is capped by `MAX_ATTESTATION_WEIGHT` to limit influence.
-//! - Negative stake is rejected in `set_attester_stake`.
+//! - Negative stake is rejected in `admin_set_attester_stake`.
+//! - Withdrawals are limited to deposited tokens, never the admin-set stake figure.
 //! - Weight config is admin-only (enforced by contract entrypoints).
 
-use soroban_sdk::Env;
+use soroban_sdk::{Address, Env};
 
 use crate::math;
-use crate::types::attestation::MAX_ATTESTATION_WEIGHT;
+use crate::ttl::{BUMP_TARGET, BUMP_THRESHOLD};
+use crate::types::attestation::{AttestationCategory, MAX_ATTESTATION_WEIGHT};
 use crate::DataKey;
 
 /// Default weight multiplier in basis points (1 = 0.01%). Formula: weight = stake * multiplier_bps / 10_000.
@@ -22,6 +40,9 @@ pub const DEFAULT_WEIGHT_MULTIPLIER_BPS: u32 = 100;
 /// Default maximum attestation weight when no config is set.
 pub const DEFAULT_MAX_WEIGHT: u32 = 100_000;
 
+/// Default maximum trust score when no config is set.
+pub const DEFAULT_MAX_TRUST_SCORE: u64 = 10_000_000;
+
 /// Storage key for weight config (multiplier_bps, max weight). Stored as (u32, u32).
 fn weight_config_key(e: &Env) -> soroban_sdk::Symbol {
     soroban_sdk::Symbol::new(e, "weight_cfg")
@@ -91,3 +112,221 @@ pub fn compute_weight(e: &Env, attester: &soroban_sdk::Address) -> u32 {
     let capped = core::cmp::min(w, max_weight);
     core::cmp::min(capped, MAX_ATTESTATION_WEIGHT).max(DEFAULT_ATTESTATION_WEIGHT)
 }
+
+/// Storage key for the trust score cap. Stored as u64.
+fn trust_max_key(e: &Env) -> soroban_sdk::Symbol {
+    soroban_sdk::Symbol::new(e, "trust_max")
+}
+
+/// Returns the configured trust score cap. Uses the default if not set.
+#[must_use]
+pub fn get_max_trust_score(e: &Env) -> u64 {
+    e.storage()
+        .instance()
+        .get::<_, u64>(&trust_max_key(e))
+        .unwrap_or(DEFAULT_MAX_TRUST_SCORE)
+}
+
+/// Sets the trust score cap (admin only; caller must enforce).
+///
+/// # Errors
+/// Panics if max_score is 0.
+pub fn set_max_trust_score(e: &Env, max_score: u64) {
+    if max_score == 0 {
+        panic!("max trust score must be positive");
+    }
+    e.storage().instance().set(&trust_max_key(e), &max_score);
+}
+
+/// Raw (uncapped) sum of active attestation weights for a subject.
+#[must_use]
+pub fn get_raw_trust_score(e: &Env, subject: &Address) -> u64 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::TrustScore(subject.clone()))
+        .unwrap_or(0)
+}
+
+/// Uncapped sum of active attestation weights for a subject within one category.
+#[must_use]
+pub fn get_raw_category_trust_score(
+    e: &Env,
+    subject: &Address,
+    category: &AttestationCategory,
+) -> u64 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::CategoryTrustScore(
+            subject.clone(),
+            category.clone(),
+        ))
+        .unwrap_or(0)
+}
+
+/// Trust score for a subject restricted to one category, saturated at the configured maximum.
+#[must_use]
+pub fn get_category_trust_score(e: &Env, subject: &Address, category: &AttestationCategory) -> u64 {
+    core::cmp::min(
+        get_raw_category_trust_score(e, subject, category),
+        get_max_trust_score(e),
+    )
+}
+
+/// Trust score for a subject, saturated at the configured maximum.
+#[must_use]
+pub fn get_trust_score(e: &Env, subject: &Address) -> u64 {
+    core::cmp::min(get_raw_trust_score(e, subject), get_max_trust_score(e))
+}
+
+/// Adds an attestation's weight to the subject's raw score.
+pub fn increase_trust_score(
+    e: &Env,
+    subject: &Address,
+    category: &AttestationCategory,
+    weight: u32,
+) {
+    let raw = get_raw_trust_score(e, subject).saturating_add(weight as u64);
+    e.storage()
+        .persistent()
+        .set(&DataKey::TrustScore(subject.clone()), &raw);
+    let raw = get_raw_category_trust_score(e, subject, category).saturating_add(weight as u64);
+    e.storage().persistent().set(
+        &DataKey::CategoryTrustScore(subject.clone(), category.clone()),
+        &raw,
+    );
+}
+
+/// Removes a revoked attestation's weight from the subject's raw score.
+pub fn decrease_trust_score(
+    e: &Env,
+    subject: &Address,
+    category: &AttestationCategory,
+    weight: u32,
+) {
+    let raw = get_raw_trust_score(e, subject).saturating_sub(weight as u64);
+    e.storage()
+        .persistent()
+        .set(&DataKey::TrustScore(subject.clone()), &raw);
+    let raw = get_raw_category_trust_score(e, subject, category).saturating_sub(weight as u64);
+    e.storage().persistent().set(
+        &DataKey::CategoryTrustScore(subject.clone(), category.clone()),
+        &raw,
+    );
+}
+
+/// Storage key for the minimum stake an attester with active attestations must keep.
+fn min_stake_key(e: &Env) -> soroban_sdk::Symbol {
+    soroban_sdk::Symbol::new(e, "min_att_stake")
+}
+
+/// Minimum stake required while an attester has active attestations. 0 if unset.
+#[must_use]
+pub fn get_min_attester_stake(e: &Env) -> i128 {
+    e.storage()
+        .instance()
+        .get::<_, i128>(&min_stake_key(e))
+        .unwrap_or(0)
+}
+
+/// Sets the minimum attester stake (admin only; caller must enforce).
+///
+/// # Errors
+/// Panics if amount < 0.
+pub fn set_min_attester_stake(e: &Env, amount: i128) {
+    if amount < 0 {
+        panic!("attester stake cannot be negative");
+    }
+    e.storage().instance().set(&min_stake_key(e), &amount);
+}
+
+/// Tokens the attester has actually deposited and not yet withdrawn.
+#[must_use]
+pub fn get_attester_deposit(e: &Env, attester: &Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::AttesterDeposit(attester.clone()))
+        .unwrap_or(0)
+}
+
+fn set_attester_deposit(e: &Env, attester: &Address, amount: i128) {
+    let key = DataKey::AttesterDeposit(attester.clone());
+    let storage = e.storage().persistent();
+    storage.set(&key, &amount);
+    storage.extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+}
+
+/// Credits a token deposit to both the deposit balance and the stake.
+/// Returns the new stake.
+pub fn credit_deposit(e: &Env, attester: &Address, amount: i128) -> i128 {
+    let deposit = math::add_i128(
+        get_attester_deposit(e, attester),
+        amount,
+        "attester deposit overflow",
+    );
+    set_attester_deposit(e, attester, deposit);
+    let stake = math::add_i128(
+        get_attester_stake(e, attester),
+        amount,
+        "attester stake overflow",
+    );
+    set_attester_stake(e, attester, stake);
+    stake
+}
+
+/// Debits a withdrawal from the deposit balance and the stake, enforcing the
+/// minimum stake while the attester has active attestations. Returns the new stake.
+///
+/// # Errors
+/// Panics if amount exceeds the deposit or would leave the stake below the minimum
+/// while attestations are active.
+pub fn debit_withdrawal(e: &Env, attester: &Address, amount: i128) -> i128 {
+    let deposit = get_attester_deposit(e, attester);
+    if amount > deposit {
+        panic!("insufficient attester deposit");
+    }
+    let stake = get_attester_stake(e, attester)
+        .saturating_sub(amount)
+        .max(0);
+    if get_active_attestation_count(e, attester) > 0 && stake < get_min_attester_stake(e) {
+        panic!("attester stake below minimum while attestations are active");
+    }
+    set_attester_deposit(e, attester, deposit - amount);
+    set_attester_stake(e, attester, stake);
+    stake
+}
+
+/// Removes up to `amount` from the attester's stake for a slash. Returns
+/// `(slashed, backed)`: the amount taken from the stake (clamped to it) and the part
+/// of that amount backed by deposited tokens, which is removed from the deposit.
+pub fn debit_slash(e: &Env, attester: &Address, amount: i128) -> (i128, i128) {
+    let stake = get_attester_stake(e, attester);
+    let slashed = amount.min(stake).max(0);
+    let deposit = get_attester_deposit(e, attester);
+    let backed = slashed.min(deposit);
+    set_attester_stake(e, attester, stake - slashed);
+    set_attester_deposit(e, attester, deposit - backed);
+    (slashed, backed)
+}
+
+/// Number of non-revoked attestations issued by an attester.
+#[must_use]
+pub fn get_active_attestation_count(e: &Env, attester: &Address) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::AttesterActiveCount(attester.clone()))
+        .unwrap_or(0)
+}
+
+/// Adjusts the attester's active attestation count on add (`true`) or revoke (`false`).
+pub fn track_active_attestation(e: &Env, attester: &Address, added: bool) {
+    let count = get_active_attestation_count(e, attester);
+    let count = if added {
+        count.saturating_add(1)
+    } else {
+        count.saturating_sub(1)
+    };
+    let key = DataKey::AttesterActiveCount(attester.clone());
+    let storage = e.storage().persistent();
+    storage.set(&key, &count);
+    storage.extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+}
@@ -1,4 +1,4 @@
-use credence_bond::{CredenceBond, CredenceBondClient};
+use credence_bond::{AttestationCategory, CredenceBond, CredenceBondClient};
 use soroban_sdk::testutils::Ledger;
 use soroban_sdk::{testutils::Address as _, Address, Env, String};
 
@@ -38,7 +38,13 @@ fn unauthorized_cannot_add_attestation() {
     let fake = String::from_str(&env, "fake");
     let nonce = client.get_nonce(&attacker);
 
-    client.add_attestation(&attacker, &user, &fake, &nonce);
+    client.add_attestation(
+        &attacker,
+        &user,
+        &fake,
+        &AttestationCategory::Identity,
+        &nonce,
+    );
 }
 
 #[test]
@@ -50,7 +56,13 @@ fn authorized_attester_can_add_attestation() {
 
     let valid = String::from_str(&env, "valid");
     let nonce = client.get_nonce(&attacker);
-    let att = client.add_attestation(&attacker, &user, &valid, &nonce);
+    let att = client.add_attestation(
+        &attacker,
+        &user,
+        &valid,
+        &AttestationCategory::Identity,
+        &nonce,
+    );
 
     assert_eq!(att.identity, user);
 }
@@ -65,7 +77,13 @@ fn wrong_attester_cannot_revoke() {
 
     let valid = String::from_str(&env, "valid");
     let nonce = client.get_nonce(&attacker);
-    let att = client.add_attestation(&attacker, &user, &valid, &nonce);
+    let att = client.add_attestation(
+        &attacker,
+        &user,
+        &valid,
+        &AttestationCategory::Identity,
+        &nonce,
+    );
 
     let other = Address::generate(&env);
     let other_nonce = client.get_nonce(&other);
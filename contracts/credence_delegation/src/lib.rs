@@ -1,6 +1,10 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, Symbol};
+use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, Symbol, Vec};
+
+mod pausable;
+
+pub use pausable::{PauseInfo, PauseScope};
 
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -56,6 +60,7 @@ impl CredenceDelegation {
         expires_at: u64,
     ) -> Delegation {
         owner.require_auth();
+        pausable::require_not_paused(&e, Self::pause_scope_for(&delegation_type));
 
         if expires_at <= e.ledger().timestamp() {
             panic!("expiry must be in the future");
@@ -86,6 +91,7 @@ impl CredenceDelegation {
         delegation_type: DelegationType,
     ) {
         owner.require_auth();
+        pausable::require_not_paused(&e, Self::pause_scope_for(&delegation_type));
 
         let key = DataKey::Delegation(owner.clone(), delegate.clone(), delegation_type.clone());
 
@@ -107,6 +113,7 @@ impl CredenceDelegation {
 
     pub fn revoke_attestation(e: Env, attester: Address, subject: Address) {
         attester.require_auth();
+        pausable::require_not_paused(&e, PauseScope::Attestations);
 
         let key = DataKey::Delegation(
             attester.clone(),
@@ -131,6 +138,100 @@ impl CredenceDelegation {
             .publish((Symbol::new(&e, "attestation_revoked"),), d);
     }
 
+    /// Pause delegation changes until explicitly unpaused, recording why.
+    /// Admin only.
+    pub fn pause(e: Env, admin: Address, reason: Symbol, detail: Option<String>) -> PauseInfo {
+        Self::require_admin(&e, &admin);
+        let info = pausable::pause(&e, &admin, 0, reason.clone(), detail);
+        e.events()
+            .publish((Symbol::new(&e, "paused"),), (admin, 0_u64, reason));
+        info
+    }
+
+    /// Pause delegation changes for `duration_secs`, recording why. The pause
+    /// lifts on its own once the ledger timestamp reaches `until`. Admin only.
+    pub fn pause_for(
+        e: Env,
+        admin: Address,
+        duration_secs: u64,
+        reason: Symbol,
+        detail: Option<String>,
+    ) -> PauseInfo {
+        Self::require_admin(&e, &admin);
+        if duration_secs == 0 {
+            panic!("duration must be positive");
+        }
+        let until = e
+            .ledger()
+            .timestamp()
+            .checked_add(duration_secs)
+            .unwrap_or_else(|| panic!("pause duration overflow"));
+        let info = pausable::pause(&e, &admin, until, reason.clone(), detail);
+        e.events()
+            .publish((Symbol::new(&e, "paused"),), (admin, until, reason));
+        info
+    }
+
+    /// Lift the pause, including a timed pause before it expires. Also clears
+    /// the record of a timed pause that has already lapsed. Admin only.
+    pub fn unpause(e: Env, admin: Address) {
+        Self::require_admin(&e, &admin);
+        if !pausable::unpause(&e) {
+            panic!("not paused");
+        }
+        e.events().publish((Symbol::new(&e, "unpaused"),), admin);
+    }
+
+    /// Whether everything is paused. A timed pause counts as lifted once its
+    /// expiry has passed.
+    pub fn is_paused(e: Env) -> bool {
+        pausable::is_paused(&e)
+    }
+
+    /// Pause a single scope until it is unpaused. Pausing `All` is the same as
+    /// `pause` with reason `unspecified`. Admin only.
+    pub fn pause_scope(e: Env, admin: Address, scope: PauseScope) {
+        if scope == PauseScope::All {
+            let reason = Symbol::new(&e, "unspecified");
+            Self::pause(e, admin, reason, None);
+            return;
+        }
+        Self::require_admin(&e, &admin);
+        pausable::pause_scope(&e, scope);
+        e.events()
+            .publish((Symbol::new(&e, "scope_paused"),), (admin, scope));
+    }
+
+    /// Lift the pause on a single scope. Unpausing `All` is the same as
+    /// `unpause`. Admin only.
+    pub fn unpause_scope(e: Env, admin: Address, scope: PauseScope) {
+        if scope == PauseScope::All {
+            return Self::unpause(e, admin);
+        }
+        Self::require_admin(&e, &admin);
+        if !pausable::unpause_scope(&e, scope) {
+            panic!("not paused");
+        }
+        e.events()
+            .publish((Symbol::new(&e, "scope_unpaused"),), (admin, scope));
+    }
+
+    /// The current pause of everything. `paused` is `false` once a timed pause
+    /// has lapsed; `None` when not paused or after `unpause`.
+    pub fn get_pause_info(e: Env) -> Option<PauseInfo> {
+        pausable::pause_info(&e)
+    }
+
+    /// The most recent pause of everything, kept after `unpause`.
+    pub fn get_last_pause_info(e: Env) -> Option<PauseInfo> {
+        pausable::last_pause_info(&e)
+    }
+
+    /// Currently paused scopes; `All` is listed first when everything is paused.
+    pub fn get_paused_scopes(e: Env) -> Vec<PauseScope> {
+        pausable::paused_scopes(&e)
+    }
+
     /// Retrieve a stored delegation.
     pub fn get_delegation(
         e: Env,
@@ -176,6 +277,27 @@ impl CredenceDelegation {
             None => AttestationStatus::NotFound,
         }
     }
+
+    /// Attestation delegations fall under `Attestations`, management
+    /// delegations under `Admin`.
+    fn pause_scope_for(delegation_type: &DelegationType) -> PauseScope {
+        match delegation_type {
+            DelegationType::Attestation => PauseScope::Attestations,
+            DelegationType::Management => PauseScope::Admin,
+        }
+    }
+
+    fn require_admin(e: &Env, admin: &Address) {
+        admin.require_auth();
+        let stored: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+        if *admin != stored {
+            panic!("not admin");
+        }
+    }
 }
 
 #[cfg(test)]
@@ -2,7 +2,7 @@
 
 use super::*;
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::Env;
+use soroban_sdk::{Env, String, Vec};
 
 // ---------------------------------------------------------------------------
 // Helpers
@@ -344,3 +344,225 @@ fn test_revoke_attestation_does_not_affect_management() {
     // Management delegation is unaffected
     assert!(client.is_valid_delegate(&attester, &subject, &DelegationType::Management));
 }
+
+// ---------------------------------------------------------------------------
+// Pause
+// ---------------------------------------------------------------------------
+
+fn incident(e: &Env) -> Symbol {
+    Symbol::new(e, "incident")
+}
+
+fn setup_with_admin() -> (Env, CredenceDelegationClient<'static>, Address) {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register(CredenceDelegation, ());
+    let client = CredenceDelegationClient::new(&e, &contract_id);
+    let admin = Address::generate(&e);
+    client.initialize(&admin);
+    (e, client, admin)
+}
+
+#[test]
+fn test_pause_blocks_delegation_changes() {
+    let (e, client, admin) = setup_with_admin();
+    let owner = Address::generate(&e);
+    let delegate = Address::generate(&e);
+    client.pause(&admin, &incident(&e), &None);
+    assert!(client.is_paused());
+
+    let result = client.try_delegate(&owner, &delegate, &DelegationType::Attestation, &86400_u64);
+    assert!(result.is_err());
+
+    client.unpause(&admin);
+    assert!(!client.is_paused());
+    client.delegate(&owner, &delegate, &DelegationType::Attestation, &86400_u64);
+}
+
+#[test]
+#[should_panic(expected = "contract is paused")]
+fn test_pause_blocks_revoke_attestation() {
+    let (e, client, admin) = setup_with_admin();
+    let attester = Address::generate(&e);
+    let subject = Address::generate(&e);
+    client.delegate(
+        &attester,
+        &subject,
+        &DelegationType::Attestation,
+        &86400_u64,
+    );
+    client.pause(&admin, &incident(&e), &None);
+    client.revoke_attestation(&attester, &subject);
+}
+
+#[test]
+fn test_pause_for_lifts_at_expiry() {
+    let (e, client, admin) = setup_with_admin();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let owner = Address::generate(&e);
+    let delegate = Address::generate(&e);
+
+    let info = client.pause_for(&admin, &600_u64, &incident(&e), &None);
+    assert_eq!(info.until, 1_600);
+
+    e.ledger().with_mut(|li| li.timestamp = 1_599);
+    assert!(client.is_paused());
+    assert!(client
+        .try_delegate(&owner, &delegate, &DelegationType::Management, &86400_u64)
+        .is_err());
+
+    e.ledger().with_mut(|li| li.timestamp = 1_600);
+    assert!(!client.is_paused());
+    client.delegate(&owner, &delegate, &DelegationType::Management, &86400_u64);
+}
+
+#[test]
+fn test_unpause_clears_timed_pause_early() {
+    let (e, client, admin) = setup_with_admin();
+    client.pause_for(&admin, &600_u64, &incident(&e), &None);
+    client.unpause(&admin);
+    assert!(!client.is_paused());
+}
+
+#[test]
+fn test_unpause_clears_lapsed_pause() {
+    let (e, client, admin) = setup_with_admin();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.pause_for(&admin, &600_u64, &incident(&e), &None);
+
+    e.ledger().with_mut(|li| li.timestamp = 1_600);
+    assert!(!client.get_pause_info().unwrap().paused);
+    client.unpause(&admin);
+    assert!(client.get_pause_info().is_none());
+}
+
+#[test]
+#[should_panic(expected = "not paused")]
+fn test_unpause_rejects_when_not_paused() {
+    let (_e, client, admin) = setup_with_admin();
+    client.unpause(&admin);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_pause_rejects_non_admin() {
+    let (e, client, _admin) = setup_with_admin();
+    let stranger = Address::generate(&e);
+    client.pause(&stranger, &incident(&e), &None);
+}
+
+#[test]
+#[should_panic(expected = "duration must be positive")]
+fn test_pause_for_rejects_zero_duration() {
+    let (e, client, admin) = setup_with_admin();
+    client.pause_for(&admin, &0_u64, &incident(&e), &None);
+}
+
+#[test]
+fn test_pause_admin_scope_leaves_attestations_working() {
+    let (e, client, admin) = setup_with_admin();
+    let attester = Address::generate(&e);
+    let subject = Address::generate(&e);
+    client.pause_scope(&admin, &PauseScope::Admin);
+
+    assert!(!client.is_paused());
+    assert_eq!(
+        client.get_paused_scopes(),
+        Vec::from_array(&e, [PauseScope::Admin])
+    );
+    client.delegate(
+        &attester,
+        &subject,
+        &DelegationType::Attestation,
+        &86400_u64,
+    );
+    client.revoke_attestation(&attester, &subject);
+    assert!(matches!(
+        client.get_attestation_status(&attester, &subject),
+        AttestationStatus::Revoked
+    ));
+}
+
+#[test]
+fn test_pause_attestations_scope_blocks_only_attestations() {
+    let (e, client, admin) = setup_with_admin();
+    let owner = Address::generate(&e);
+    let delegate = Address::generate(&e);
+    client.pause_scope(&admin, &PauseScope::Attestations);
+
+    assert!(client
+        .try_delegate(&owner, &delegate, &DelegationType::Attestation, &86400_u64)
+        .is_err());
+    client.delegate(&owner, &delegate, &DelegationType::Management, &86400_u64);
+
+    client.unpause_scope(&admin, &PauseScope::Attestations);
+    assert!(client.get_paused_scopes().is_empty());
+    client.delegate(&owner, &delegate, &DelegationType::Attestation, &86400_u64);
+}
+
+#[test]
+fn test_pause_all_scope_covers_every_scope() {
+    let (e, client, admin) = setup_with_admin();
+    let owner = Address::generate(&e);
+    let delegate = Address::generate(&e);
+    client.pause_scope(&admin, &PauseScope::Admin);
+    client.pause_scope(&admin, &PauseScope::All);
+
+    assert!(client.is_paused());
+    assert_eq!(
+        client.get_paused_scopes(),
+        Vec::from_array(&e, [PauseScope::All, PauseScope::Admin])
+    );
+    assert!(client
+        .try_delegate(&owner, &delegate, &DelegationType::Attestation, &86400_u64)
+        .is_err());
+
+    // Lifting All leaves the individually paused scope in place.
+    client.unpause_scope(&admin, &PauseScope::All);
+    assert!(!client.is_paused());
+    assert_eq!(
+        client.get_paused_scopes(),
+        Vec::from_array(&e, [PauseScope::Admin])
+    );
+    assert!(client
+        .try_delegate(&owner, &delegate, &DelegationType::Management, &86400_u64)
+        .is_err());
+}
+
+#[test]
+#[should_panic(expected = "not paused")]
+fn test_unpause_scope_rejects_unpaused_scope() {
+    let (_e, client, admin) = setup_with_admin();
+    client.unpause_scope(&admin, &PauseScope::Admin);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_pause_scope_rejects_non_admin() {
+    let (e, client, _admin) = setup_with_admin();
+    let stranger = Address::generate(&e);
+    client.pause_scope(&stranger, &PauseScope::Attestations);
+}
+
+#[test]
+fn test_pause_info_records_reason() {
+    let (e, client, admin) = setup_with_admin();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let detail = String::from_str(&e, "suspicious revocations");
+    assert!(client.get_pause_info().is_none());
+
+    client.pause_for(&admin, &600_u64, &incident(&e), &Some(detail.clone()));
+    let info = client.get_pause_info().unwrap();
+    assert!(info.paused);
+    assert_eq!(info.since, 1_000);
+    assert_eq!(info.until, 1_600);
+    assert_eq!(info.reason, incident(&e));
+    assert_eq!(info.detail, Some(detail));
+    assert_eq!(info.paused_by, admin);
+
+    client.unpause(&admin);
+    assert!(client.get_pause_info().is_none());
+    let last = client.get_last_pause_info().unwrap();
+    assert!(!last.paused);
+    assert_eq!(last.reason, incident(&e));
+}
@@ -150,6 +150,16 @@ pub enum ContractError {
     /// Contracts: bond
     InvalidPenaltyBps = 211,
 
+    /// A token transfer out of the contract was rejected by the token contract.
+    /// Replaces: panic!("transfer failed")
+    /// Contracts: bond
+    TransferFailed = 212,
+
+    /// Emergency withdrawal would exceed the per-window cap.
+    /// Replaces: panic!("emergency rate limit exceeded")
+    /// Contracts: bond
+    EmergencyRateLimitExceeded = 213,
+
     // --- Attestation (300-399) ---
     /// An attestation already exists from this attester for this bond.
     /// Replaces: panic!("duplicate attestation")
@@ -301,7 +311,9 @@ impl ErrorExt for ContractError {
             | ContractError::InvalidNonce
             | ContractError::NegativeStake
             | ContractError::EarlyExitConfigNotSet
-            | ContractError::InvalidPenaltyBps => ErrorCategory::Bond,
+            | ContractError::InvalidPenaltyBps
+            | ContractError::TransferFailed
+            | ContractError::EmergencyRateLimitExceeded => ErrorCategory::Bond,
 
             ContractError::DuplicateAttestation
             | ContractError::AttestationNotFound
@@ -359,6 +371,10 @@ impl ErrorExt for ContractError {
                 "Early-exit configuration has not been set for this bond"
             }
             ContractError::InvalidPenaltyBps => "Penalty bps must be in range 0-10000",
+            ContractError::TransferFailed => "Token transfer was rejected by the token contract",
+            ContractError::EmergencyRateLimitExceeded => {
+                "Emergency withdrawal exceeds the per-window limit"
+            }
             ContractError::DuplicateAttestation => "Attestation already exists from this attester",
             ContractError::AttestationNotFound => "No attestation found for the given key",
             ContractError::AttestationAlreadyRevoked => "Attestation has already been revoked",
@@ -26,6 +26,8 @@ mod tests {
             ContractError::NegativeStake,
             ContractError::EarlyExitConfigNotSet,
             ContractError::InvalidPenaltyBps,
+            ContractError::TransferFailed,
+            ContractError::EmergencyRateLimitExceeded,
             ContractError::DuplicateAttestation,
             ContractError::AttestationNotFound,
             ContractError::AttestationAlreadyRevoked,
@@ -83,6 +85,8 @@ mod tests {
         assert_eq!(ContractError::NegativeStake as u32, 209);
         assert_eq!(ContractError::EarlyExitConfigNotSet as u32, 210);
         assert_eq!(ContractError::InvalidPenaltyBps as u32, 211);
+        assert_eq!(ContractError::TransferFailed as u32, 212);
+        assert_eq!(ContractError::EmergencyRateLimitExceeded as u32, 213);
     }
 
     #[test]
@@ -207,6 +211,14 @@ mod tests {
             ContractError::InvalidPenaltyBps.category(),
             ErrorCategory::Bond
         );
+        assert_eq!(
+            ContractError::TransferFailed.category(),
+            ErrorCategory::Bond
+        );
+        assert_eq!(
+            ContractError::EmergencyRateLimitExceeded.category(),
+            ErrorCategory::Bond
+        );
     }
 
     #[test]
@@ -342,7 +354,7 @@ mod tests {
     fn test_all_variants_count() {
         assert_eq!(
             all_variants().len(),
-            42,
+            44,
             "Update all_variants() and this count when adding new errors"
         );
     }
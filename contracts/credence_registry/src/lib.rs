@@ -7,13 +7,14 @@
 //!
 //! ## Features
 //! - Register identity-to-bond mappings
+//! - Let allowlisted bond contracts register their own bonders
 //! - Lookup bond contract by identity
 //! - Reverse lookup identity by bond contract
 //! - Track registration status
 //! - Emit events for all registry operations
 //!
 //! ## Security
-//! - Admin-controlled registration
+//! - Admin-controlled registration and registrar allowlist
 //! - Prevents duplicate registrations
 //! - validates addresses before registration
 //! - emits events for audit trail
@@ -30,22 +31,207 @@ pub struct RegistryEntry {
     pub bond_contract: Address,
     /// Timestamp when this entry was registered
     pub registered_at: u64,
+    /// Timestamp of the last `update_registration`; `registered_at` until then
+    pub updated_at: u64,
     /// Whether this registration is currently active
     pub active: bool,
 }
 
+/// `RegistryEntry` as written to instance storage by versions before
+/// `migrate_entries`, which had no `updated_at`
+#[contracttype]
+#[derive(Clone, Debug)]
+struct LegacyRegistryEntry {
+    identity: Address,
+    bond_contract: Address,
+    registered_at: u64,
+    active: bool,
+}
+
+impl From<LegacyRegistryEntry> for RegistryEntry {
+    fn from(legacy: LegacyRegistryEntry) -> Self {
+        RegistryEntry {
+            identity: legacy.identity,
+            bond_contract: legacy.bond_contract,
+            registered_at: legacy.registered_at,
+            updated_at: legacy.registered_at,
+            active: legacy.active,
+        }
+    }
+}
+
+/// Contract revision reported by `get_contract_info`. Bump on every release.
+pub const CONTRACT_VERSION: u32 = 1;
+
+/// Number of identities stored per page of the identity list.
+pub const IDENTITY_PAGE_SIZE: u32 = 100;
+
+/// Largest identity list `get_all_identities` will return in one call.
+pub const MAX_ALL_IDENTITIES: u32 = 2 * IDENTITY_PAGE_SIZE;
+
+/// Minimum ledger TTL before a persistent entry is bumped (~1 day at 5 s/ledger).
+const BUMP_THRESHOLD: u32 = 17_280;
+/// Target TTL after a bump (~30 days).
+const BUMP_TARGET: u32 = 518_400;
+
+/// Describes a deployed contract and the revision it runs
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractInfo {
+    /// Contract name
+    pub name: Symbol,
+    /// Contract revision, bumped on every release
+    pub version: u32,
+    /// Token handled by the contract; always `None` for the registry
+    pub token: Option<Address>,
+    /// Admin address
+    pub admin: Address,
+}
+
 /// Storage keys for the registry contract
 #[contracttype]
 #[derive(Clone)]
 enum DataKey {
     /// Admin address
     Admin,
-    /// Identity to bond contract mapping: Identity -> RegistryEntry
+    /// Identity to bond contract mapping: Identity -> RegistryEntry (persistent)
     IdentityToBond(Address),
-    /// Reverse mapping: BondContract -> Identity
+    /// Reverse mapping: BondContract -> Identity (persistent)
     BondToIdentity(Address),
-    /// List of all registered identities
+    /// Number of registered identities
+    IdentityCount,
+    /// Page of registered identities, in registration order (persistent)
+    IdentityPage(u32),
+    /// Identity list kept by earlier versions; drained into the pages by
+    /// `migrate_identity_list`
     RegisteredIdentities,
+    /// Bond contracts allowed to register identities: Registrar -> bool
+    Registrar(Address),
+}
+
+fn identity_count(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::IdentityCount)
+        .unwrap_or(0)
+}
+
+fn bump(e: &Env, key: &DataKey) {
+    e.storage()
+        .persistent()
+        .extend_ttl(key, BUMP_THRESHOLD, BUMP_TARGET);
+}
+
+/// Read the entry of `identity`. Entries written before `migrate_entries`
+/// are still found in instance storage.
+fn load_entry(e: &Env, identity: &Address) -> Option<RegistryEntry> {
+    let key = DataKey::IdentityToBond(identity.clone());
+    if let Some(entry) = e.storage().persistent().get(&key) {
+        bump(e, &key);
+        return Some(entry);
+    }
+    e.storage()
+        .instance()
+        .get::<_, LegacyRegistryEntry>(&key)
+        .map(RegistryEntry::from)
+}
+
+fn save_entry(e: &Env, entry: &RegistryEntry) {
+    let key = DataKey::IdentityToBond(entry.identity.clone());
+    e.storage().persistent().set(&key, entry);
+    bump(e, &key);
+}
+
+/// Read the identity mapped to `bond_contract`, falling back to instance
+/// storage like `load_entry`.
+fn load_identity(e: &Env, bond_contract: &Address) -> Option<Address> {
+    let key = DataKey::BondToIdentity(bond_contract.clone());
+    if let Some(identity) = e.storage().persistent().get(&key) {
+        bump(e, &key);
+        return Some(identity);
+    }
+    e.storage().instance().get(&key)
+}
+
+fn save_identity(e: &Env, bond_contract: &Address, identity: &Address) {
+    let key = DataKey::BondToIdentity(bond_contract.clone());
+    e.storage().persistent().set(&key, identity);
+    bump(e, &key);
+}
+
+/// Delete the reverse mapping of `bond_contract`, including a legacy one.
+fn remove_identity(e: &Env, bond_contract: &Address) {
+    let key = DataKey::BondToIdentity(bond_contract.clone());
+    e.storage().persistent().remove(&key);
+    if e.storage().instance().has(&key) {
+        e.storage().instance().remove(&key);
+    }
+}
+
+/// Identities listed by an earlier version and not yet moved to the pages.
+/// They come after the paged identities in registration order.
+fn legacy_identities(e: &Env) -> Vec<Address> {
+    e.storage()
+        .instance()
+        .get(&DataKey::RegisteredIdentities)
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+/// Append `identity` to the last page of the identity list.
+fn append_to_pages(e: &Env, identity: &Address) {
+    let count = identity_count(e);
+    let page_key = DataKey::IdentityPage(count / IDENTITY_PAGE_SIZE);
+    let mut page: Vec<Address> = e
+        .storage()
+        .persistent()
+        .get(&page_key)
+        .unwrap_or_else(|| Vec::new(e));
+
+    page.push_back(identity.clone());
+    e.storage().persistent().set(&page_key, &page);
+    bump(e, &page_key);
+    e.storage()
+        .instance()
+        .set(&DataKey::IdentityCount, &(count + 1));
+}
+
+/// Add `identity` to the end of the identity list. While a legacy list is
+/// still being migrated, new identities queue behind it to keep the order.
+fn list_identity(e: &Env, identity: &Address) {
+    let mut legacy = legacy_identities(e);
+    if legacy.is_empty() {
+        append_to_pages(e, identity);
+        return;
+    }
+    legacy.push_back(identity.clone());
+    e.storage()
+        .instance()
+        .set(&DataKey::RegisteredIdentities, &legacy);
+}
+
+/// Store the forward mapping for `identity`, list it and emit
+/// `identity_registered`. Callers check for duplicates first.
+fn insert_entry(e: &Env, identity: &Address, bond_contract: &Address) -> RegistryEntry {
+    let now = e.ledger().timestamp();
+    let entry = RegistryEntry {
+        identity: identity.clone(),
+        bond_contract: bond_contract.clone(),
+        registered_at: now,
+        updated_at: now,
+        active: true,
+    };
+
+    // Store forward mapping (identity -> bond)
+    save_entry(e, &entry);
+
+    // Add to registered identities list
+    list_identity(e, identity);
+
+    // Emit event
+    e.events()
+        .publish((Symbol::new(e, "identity_registered"),), entry.clone());
+
+    entry
 }
 
 #[contract]
@@ -69,12 +255,6 @@ impl CredenceRegistry {
 
         e.storage().instance().set(&DataKey::Admin, &admin);
 
-        // Initialize empty registered identities list
-        let identities: Vec<Address> = Vec::new(&e);
-        e.storage()
-            .instance()
-            .set(&DataKey::RegisteredIdentities, &identities);
-
         e.events()
             .publish((Symbol::new(&e, "registry_initialized"),), admin.clone());
     }
@@ -92,6 +272,7 @@ impl CredenceRegistry {
     /// * If caller is not admin
     /// * If identity is already registered
     /// * If bond contract is already associated with another identity
+    /// * If bond contract is a registrar
     ///
     /// # Events
     /// Emits `identity_registered` with the `RegistryEntry`
@@ -106,46 +287,165 @@ impl CredenceRegistry {
         admin.require_auth();
 
         // Check if identity is already registered
-        let identity_key = DataKey::IdentityToBond(identity.clone());
-        if e.storage().instance().has(&identity_key) {
+        if load_entry(&e, &identity).is_some() {
             panic!("identity already registered");
         }
 
         // Check if bond contract is already associated with another identity
-        let bond_key = DataKey::BondToIdentity(bond_contract.clone());
-        if e.storage().instance().has(&bond_key) {
+        if load_identity(&e, &bond_contract).is_some() {
             panic!("bond contract already registered");
         }
 
-        // Create registry entry
-        let entry = RegistryEntry {
-            identity: identity.clone(),
-            bond_contract: bond_contract.clone(),
-            registered_at: e.ledger().timestamp(),
-            active: true,
-        };
-
-        // Store forward mapping (identity -> bond)
-        e.storage().instance().set(&identity_key, &entry);
+        // A registrar holds many identities' bonds and has no single owner
+        if Self::is_registrar(e.clone(), bond_contract.clone()) {
+            panic!("bond contract is a registrar");
+        }
 
         // Store reverse mapping (bond -> identity)
-        e.storage().instance().set(&bond_key, &identity);
+        save_identity(&e, &bond_contract, &identity);
 
-        // Add to registered identities list
-        let mut identities: Vec<Address> = e
+        insert_entry(&e, &identity, &bond_contract)
+    }
+
+    /// Allow or disallow `registrar` to register identities through
+    /// `register_by_registrar`.
+    ///
+    /// # Arguments
+    /// * `registrar` - The bond contract address
+    /// * `allowed` - Whether it may register identities
+    ///
+    /// # Panics
+    /// * If caller is not admin
+    ///
+    /// # Events
+    /// Emits `registrar_set` with `(registrar, allowed)`
+    pub fn set_registrar(e: Env, registrar: Address, allowed: bool) {
+        // Verify admin authorization
+        let admin: Address = e
             .storage()
             .instance()
-            .get(&DataKey::RegisteredIdentities)
-            .unwrap_or_else(|| Vec::new(&e));
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
 
-        identities.push_back(identity.clone());
+        admin.require_auth();
+
+        let key = DataKey::Registrar(registrar.clone());
+        if allowed {
+            e.storage().instance().set(&key, &true);
+        } else {
+            e.storage().instance().remove(&key);
+        }
+
+        e.events()
+            .publish((Symbol::new(&e, "registrar_set"),), (registrar, allowed));
+    }
+
+    /// Check if `registrar` may register identities.
+    pub fn is_registrar(e: Env, registrar: Address) -> bool {
         e.storage()
             .instance()
-            .set(&DataKey::RegisteredIdentities, &identities);
+            .get(&DataKey::Registrar(registrar))
+            .unwrap_or(false)
+    }
 
-        // Emit event
-        e.events()
-            .publish((Symbol::new(&e, "identity_registered"),), entry.clone());
+    /// Register `identity` on behalf of a registrar bond contract, which
+    /// becomes the entry's `bond_contract`. A registrar holds many
+    /// identities' bonds, so it gets no reverse mapping. If `identity` is
+    /// already registered, active or not, its entry is returned unchanged.
+    ///
+    /// # Arguments
+    /// * `registrar` - The calling bond contract
+    /// * `identity` - The identity address to register
+    ///
+    /// # Returns
+    /// The new or existing `RegistryEntry`
+    ///
+    /// # Panics
+    /// * If `registrar` has not authorized the call
+    /// * If `registrar` is not allowlisted with `set_registrar`
+    ///
+    /// # Events
+    /// Emits `identity_registered` with the `RegistryEntry` when one is created
+    pub fn register_by_registrar(e: Env, registrar: Address, identity: Address) -> RegistryEntry {
+        registrar.require_auth();
+
+        if !Self::is_registrar(e.clone(), registrar.clone()) {
+            panic!("not a registrar");
+        }
+
+        if let Some(entry) = load_entry(&e, &identity) {
+            return entry;
+        }
+
+        insert_entry(&e, &identity, &registrar)
+    }
+
+    /// Point a registered identity at a new bond contract, e.g. after the
+    /// identity redeploys it. The old contract's reverse mapping is deleted
+    /// and `registered_at` and `active` are kept.
+    ///
+    /// # Arguments
+    /// * `identity` - The registered identity address
+    /// * `new_bond_contract` - The bond contract address to map it to
+    ///
+    /// # Returns
+    /// The updated `RegistryEntry`
+    ///
+    /// # Panics
+    /// * If caller is not admin
+    /// * If identity is not registered
+    /// * If identity is already mapped to `new_bond_contract`
+    /// * If `new_bond_contract` is associated with another identity
+    /// * If `new_bond_contract` is a registrar
+    ///
+    /// # Events
+    /// Emits `registration_updated` with `(identity, old_bond_contract, new_bond_contract)`
+    pub fn update_registration(
+        e: Env,
+        identity: Address,
+        new_bond_contract: Address,
+    ) -> RegistryEntry {
+        // Verify admin authorization
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+
+        admin.require_auth();
+
+        let mut entry =
+            load_entry(&e, &identity).unwrap_or_else(|| panic!("identity not registered"));
+
+        if entry.bond_contract == new_bond_contract {
+            panic!("bond contract unchanged");
+        }
+
+        // Check if bond contract is already associated with another identity
+        if load_identity(&e, &new_bond_contract).is_some() {
+            panic!("bond contract already registered");
+        }
+
+        // A registrar holds many identities' bonds and has no single owner
+        if Self::is_registrar(e.clone(), new_bond_contract.clone()) {
+            panic!("bond contract is a registrar");
+        }
+
+        // Registrar entries have no reverse mapping to delete
+        let old_bond_contract = entry.bond_contract.clone();
+        if load_identity(&e, &old_bond_contract) == Some(identity.clone()) {
+            remove_identity(&e, &old_bond_contract);
+        }
+        save_identity(&e, &new_bond_contract, &identity);
+
+        entry.bond_contract = new_bond_contract.clone();
+        entry.updated_at = e.ledger().timestamp();
+        save_entry(&e, &entry);
+
+        e.events().publish(
+            (Symbol::new(&e, "registration_updated"),),
+            (identity, old_bond_contract, new_bond_contract),
+        );
 
         entry
     }
@@ -161,11 +461,7 @@ impl CredenceRegistry {
     /// # Panics
     /// * If identity is not registered
     pub fn get_bond_contract(e: Env, identity: Address) -> RegistryEntry {
-        let key = DataKey::IdentityToBond(identity.clone());
-        e.storage()
-            .instance()
-            .get(&key)
-            .unwrap_or_else(|| panic!("identity not registered"))
+        load_entry(&e, &identity).unwrap_or_else(|| panic!("identity not registered"))
     }
 
     /// Reverse lookup: get the identity for a given bond contract.
@@ -179,11 +475,7 @@ impl CredenceRegistry {
     /// # Panics
     /// * If bond contract is not registered
     pub fn get_identity(e: Env, bond_contract: Address) -> Address {
-        let key = DataKey::BondToIdentity(bond_contract.clone());
-        e.storage()
-            .instance()
-            .get(&key)
-            .unwrap_or_else(|| panic!("bond contract not registered"))
+        load_identity(&e, &bond_contract).unwrap_or_else(|| panic!("bond contract not registered"))
     }
 
     /// Check if an identity is registered.
@@ -194,8 +486,7 @@ impl CredenceRegistry {
     /// # Returns
     /// `true` if the identity is registered and active, `false` otherwise
     pub fn is_registered(e: Env, identity: Address) -> bool {
-        let key = DataKey::IdentityToBond(identity);
-        match e.storage().instance().get::<_, RegistryEntry>(&key) {
+        match load_entry(&e, &identity) {
             Some(entry) => entry.active,
             None => false,
         }
@@ -223,19 +514,15 @@ impl CredenceRegistry {
 
         admin.require_auth();
 
-        let key = DataKey::IdentityToBond(identity.clone());
-        let mut entry: RegistryEntry = e
-            .storage()
-            .instance()
-            .get(&key)
-            .unwrap_or_else(|| panic!("identity not registered"));
+        let mut entry =
+            load_entry(&e, &identity).unwrap_or_else(|| panic!("identity not registered"));
 
         if !entry.active {
             panic!("already deactivated");
         }
 
         entry.active = false;
-        e.storage().instance().set(&key, &entry);
+        save_entry(&e, &entry);
 
         e.events()
             .publish((Symbol::new(&e, "identity_deactivated"),), entry);
@@ -263,33 +550,185 @@ impl CredenceRegistry {
 
         admin.require_auth();
 
-        let key = DataKey::IdentityToBond(identity.clone());
-        let mut entry: RegistryEntry = e
-            .storage()
-            .instance()
-            .get(&key)
-            .unwrap_or_else(|| panic!("identity not registered"));
+        let mut entry =
+            load_entry(&e, &identity).unwrap_or_else(|| panic!("identity not registered"));
 
         if entry.active {
             panic!("already active");
         }
 
         entry.active = true;
-        e.storage().instance().set(&key, &entry);
+        save_entry(&e, &entry);
 
         e.events()
             .publish((Symbol::new(&e, "identity_reactivated"),), entry);
     }
 
+    /// Move up to `limit` identities from the list kept by earlier versions
+    /// in instance storage to the identity pages, oldest first. Call this
+    /// after upgrading until it returns 0; the legacy list is removed once
+    /// it is empty. Until then, reads of the identity list include the
+    /// identities still waiting, in registration order.
+    ///
+    /// # Arguments
+    /// * `limit` - Largest number of identities to move in this call
+    ///
+    /// # Returns
+    /// The number of identities still waiting to be moved
+    ///
+    /// # Panics
+    /// * If caller is not admin
+    ///
+    /// # Events
+    /// Emits `identities_migrated` with `(moved, remaining)`
+    pub fn migrate_identity_list(e: Env, limit: u32) -> u32 {
+        // Verify admin authorization
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("not initialized"));
+
+        admin.require_auth();
+
+        let legacy = legacy_identities(&e);
+        let moved = limit.min(legacy.len());
+        for identity in legacy.slice(0..moved).iter() {
+            append_to_pages(&e, &identity);
+        }
+
+        let remaining = legacy.slice(moved..legacy.len());
+        if remaining.is_empty() {
+            e.storage()
+                .instance()
+                .remove(&DataKey::RegisteredIdentities);
+        } else {
+            e.storage()
+                .instance()
+                .set(&DataKey::RegisteredIdentities, &remaining);
+        }
+
+        e.events().publish(
+            (Symbol::new(&e, "identities_migrated"),),
+            (moved, remaining.len()),
+        );
+
+        remaining.len()
+    }
+
+    /// Move the entries of `identities`, and their reverse mappings, from
+    /// instance storage to persistent storage. Entries written by older
+    /// versions of this contract live in instance storage; call this in
+    /// batches small enough to fit a transaction until every identity is
+    /// migrated. Identities without a legacy entry are skipped, so batches
+    /// can safely be retried. The identity list is moved separately by
+    /// `migrate_identity_list`.
+    ///
+    /// # Arguments
+    /// * `admin` - The current admin address
+    /// * `identities` - The identities to migrate
+    ///
+    /// # Returns
+    /// The number of entries moved
+    ///
+    /// # Panics
+    /// * If `admin` is not the admin or has not authorized the call
+    ///
+    /// # Events
+    /// Emits `entries_migrated` with the number of entries moved
+    pub fn migrate_entries(e: Env, admin: Address, identities: Vec<Address>) -> u32 {
+        if admin != Self::get_admin(e.clone()) {
+            panic!("not admin");
+        }
+        admin.require_auth();
+
+        let mut migrated = 0;
+        for identity in identities.iter() {
+            let key = DataKey::IdentityToBond(identity.clone());
+            let entry: RegistryEntry =
+                match e.storage().instance().get::<_, LegacyRegistryEntry>(&key) {
+                    Some(legacy) => legacy.into(),
+                    None => continue,
+                };
+            e.storage().instance().remove(&key);
+            // A write since the upgrade already holds the current entry.
+            if !e.storage().persistent().has(&key) {
+                save_entry(&e, &entry);
+            }
+
+            let bond_key = DataKey::BondToIdentity(entry.bond_contract.clone());
+            if let Some(owner) = e.storage().instance().get::<_, Address>(&bond_key) {
+                e.storage().instance().remove(&bond_key);
+                save_identity(&e, &entry.bond_contract, &owner);
+            }
+            migrated += 1;
+        }
+
+        e.events()
+            .publish((Symbol::new(&e, "entries_migrated"),), migrated);
+
+        migrated
+    }
+
     /// Get all registered identities.
     ///
     /// # Returns
-    /// A `Vec` of all registered identity addresses
+    /// A `Vec` of all registered identity addresses, in registration order
+    ///
+    /// # Panics
+    /// * If more than `MAX_ALL_IDENTITIES` are registered; use
+    ///   `get_identities_page` instead
     pub fn get_all_identities(e: Env) -> Vec<Address> {
-        e.storage()
-            .instance()
-            .get(&DataKey::RegisteredIdentities)
-            .unwrap_or_else(|| Vec::new(&e))
+        let count = Self::get_identity_count(e.clone());
+        if count > MAX_ALL_IDENTITIES {
+            panic!("too many identities, use get_identities_page");
+        }
+
+        let mut identities = Vec::new(&e);
+        for page in 0..count.div_ceil(IDENTITY_PAGE_SIZE) {
+            identities.append(&Self::get_identities_page(e.clone(), page));
+        }
+        identities
+    }
+
+    /// Get one page of the registered identities.
+    ///
+    /// # Arguments
+    /// * `page` - Zero-based page number; page `n` holds identities
+    ///   `n * IDENTITY_PAGE_SIZE` up to `(n + 1) * IDENTITY_PAGE_SIZE - 1`
+    ///
+    /// # Returns
+    /// Up to `IDENTITY_PAGE_SIZE` identity addresses in registration order;
+    /// empty past the last page
+    pub fn get_identities_page(e: Env, page: u32) -> Vec<Address> {
+        let key = DataKey::IdentityPage(page);
+        let mut identities = match e.storage().persistent().get(&key) {
+            Some(identities) => {
+                bump(&e, &key);
+                identities
+            }
+            None => Vec::new(&e),
+        };
+
+        // Identities still on the legacy list follow the paged ones
+        let paged = identity_count(&e);
+        let start = page.saturating_mul(IDENTITY_PAGE_SIZE);
+        let end = start.saturating_add(IDENTITY_PAGE_SIZE);
+        if end > paged {
+            let legacy = legacy_identities(&e);
+            let from = start.max(paged) - paged;
+            let to = (end - paged).min(legacy.len());
+            if from < to {
+                identities.append(&legacy.slice(from..to));
+            }
+        }
+        identities
+    }
+
+    /// Get the number of registered identities, active or not, including
+    /// any not yet moved off the legacy list.
+    pub fn get_identity_count(e: Env) -> u32 {
+        identity_count(&e) + legacy_identities(&e).len()
     }
 
     /// Get the admin address.
@@ -306,6 +745,22 @@ impl CredenceRegistry {
             .unwrap_or_else(|| panic!("not initialized"))
     }
 
+    /// Get the contract name, version and admin.
+    ///
+    /// # Returns
+    /// A `ContractInfo` with no token, since the registry holds no funds
+    ///
+    /// # Panics
+    /// * If contract is not initialized
+    pub fn get_contract_info(e: Env) -> ContractInfo {
+        ContractInfo {
+            name: Symbol::new(&e, "credence_registry"),
+            version: CONTRACT_VERSION,
+            token: None,
+            admin: Self::get_admin(e),
+        }
+    }
+
     /// Transfer admin rights to a new address.
     ///
     /// # Arguments
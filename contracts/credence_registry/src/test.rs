@@ -1,7 +1,9 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env};
+use soroban_sdk::testutils::storage::Persistent as _;
+use soroban_sdk::testutils::{Events, Ledger};
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, IntoVal};
 
 /// Helper to create a test environment with initialized registry
 fn setup_registry() -> (Env, Address, Address) {
@@ -35,6 +37,18 @@ fn test_initialize() {
     assert_eq!(stored_admin, admin);
 }
 
+#[test]
+fn test_get_contract_info() {
+    let (env, contract_id, admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    let info = client.get_contract_info();
+    assert_eq!(info.name, Symbol::new(&env, "credence_registry"));
+    assert_eq!(info.version, CONTRACT_VERSION);
+    assert_eq!(info.token, None);
+    assert_eq!(info.admin, admin);
+}
+
 #[test]
 #[should_panic(expected = "already initialized")]
 fn test_initialize_twice_should_fail() {
@@ -422,3 +436,477 @@ fn test_timestamp_on_registration() {
     // Timestamp should be >= before registration
     assert!(entry.registered_at >= before_timestamp);
 }
+
+#[test]
+fn test_register_by_registrar() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    let registrar = Address::generate(&env);
+    let identity = Address::generate(&env);
+    assert!(!client.is_registrar(&registrar));
+    client.set_registrar(&registrar, &true);
+    assert!(client.is_registrar(&registrar));
+
+    let entry = client.register_by_registrar(&registrar, &identity);
+    assert_eq!(entry.identity, identity);
+    assert_eq!(entry.bond_contract, registrar);
+    assert!(client.is_registered(&identity));
+    assert_eq!(client.get_all_identities().len(), 1);
+}
+
+#[test]
+fn test_register_by_registrar_is_idempotent() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    let registrar = Address::generate(&env);
+    let identity = Address::generate(&env);
+    let bond_contract = Address::generate(&env);
+    client.set_registrar(&registrar, &true);
+    client.register(&identity, &bond_contract);
+
+    // An existing entry is returned as is, not replaced or duplicated.
+    let entry = client.register_by_registrar(&registrar, &identity);
+    assert_eq!(entry.bond_contract, bond_contract);
+    assert_eq!(client.get_all_identities().len(), 1);
+}
+
+#[test]
+fn test_registrar_serves_many_identities() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    let registrar = Address::generate(&env);
+    let identity1 = Address::generate(&env);
+    let identity2 = Address::generate(&env);
+    client.set_registrar(&registrar, &true);
+    client.register_by_registrar(&registrar, &identity1);
+    client.register_by_registrar(&registrar, &identity2);
+
+    assert!(client.is_registered(&identity1));
+    assert!(client.is_registered(&identity2));
+    assert!(client.try_get_identity(&registrar).is_err());
+}
+
+#[test]
+#[should_panic(expected = "bond contract is a registrar")]
+fn test_register_registrar_as_bond_contract() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    let registrar = Address::generate(&env);
+    client.set_registrar(&registrar, &true);
+    client.register_by_registrar(&registrar, &Address::generate(&env));
+    client.register(&Address::generate(&env), &registrar);
+}
+
+#[test]
+#[should_panic(expected = "not a registrar")]
+fn test_register_by_removed_registrar() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    let registrar = Address::generate(&env);
+    client.set_registrar(&registrar, &true);
+    client.set_registrar(&registrar, &false);
+    client.register_by_registrar(&registrar, &Address::generate(&env));
+}
+
+#[test]
+fn test_identities_paginated() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    let mut identities = Vec::new(&env);
+    for _ in 0..250 {
+        let identity = Address::generate(&env);
+        client.register(&identity, &Address::generate(&env));
+        identities.push_back(identity);
+    }
+
+    assert_eq!(client.get_identity_count(), 250);
+    assert_eq!(client.get_identities_page(&0), identities.slice(0..100));
+    assert_eq!(client.get_identities_page(&1), identities.slice(100..200));
+    assert_eq!(client.get_identities_page(&2), identities.slice(200..250));
+    assert_eq!(client.get_identities_page(&3).len(), 0);
+}
+
+#[test]
+fn test_get_all_identities_spans_pages() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    for _ in 0..MAX_ALL_IDENTITIES {
+        client.register(&Address::generate(&env), &Address::generate(&env));
+    }
+
+    let identities = client.get_all_identities();
+    assert_eq!(identities.len(), MAX_ALL_IDENTITIES);
+    assert_eq!(
+        identities.slice(0..IDENTITY_PAGE_SIZE),
+        client.get_identities_page(&0)
+    );
+}
+
+#[test]
+#[should_panic(expected = "too many identities")]
+fn test_get_all_identities_above_limit() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    for _ in 0..=MAX_ALL_IDENTITIES {
+        client.register(&Address::generate(&env), &Address::generate(&env));
+    }
+    client.get_all_identities();
+}
+
+/// Store `count` identities the way earlier versions of the registry did:
+/// entries, reverse mappings and one identity `Vec`, all in instance storage.
+fn seed_legacy_identities(env: &Env, contract_id: &Address, count: u32) -> Vec<Address> {
+    let mut identities = Vec::new(env);
+    env.as_contract(contract_id, || {
+        for _ in 0..count {
+            let identity = Address::generate(env);
+            let bond_contract = Address::generate(env);
+            let entry = LegacyRegistryEntry {
+                identity: identity.clone(),
+                bond_contract: bond_contract.clone(),
+                registered_at: env.ledger().timestamp(),
+                active: true,
+            };
+            env.storage()
+                .instance()
+                .set(&DataKey::IdentityToBond(identity.clone()), &entry);
+            env.storage()
+                .instance()
+                .set(&DataKey::BondToIdentity(bond_contract), &identity);
+            identities.push_back(identity);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::RegisteredIdentities, &identities);
+    });
+    identities
+}
+
+#[test]
+fn test_legacy_identity_list_readable_before_migration() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    let mut identities = seed_legacy_identities(&env, &contract_id, 150);
+    let identity = Address::generate(&env);
+    client.register(&identity, &Address::generate(&env));
+    identities.push_back(identity);
+
+    assert_eq!(client.get_identity_count(), 151);
+    assert_eq!(client.get_identities_page(&0), identities.slice(0..100));
+    assert_eq!(client.get_identities_page(&1), identities.slice(100..151));
+    assert_eq!(client.get_all_identities(), identities);
+}
+
+#[test]
+fn test_migrate_identity_list() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    let mut identities = seed_legacy_identities(&env, &contract_id, 150);
+    let identity = Address::generate(&env);
+    client.register(&identity, &Address::generate(&env));
+    identities.push_back(identity);
+
+    assert_eq!(client.migrate_identity_list(&120), 31);
+    assert_eq!(client.get_identity_count(), 151);
+    assert_eq!(client.get_all_identities(), identities);
+
+    assert_eq!(client.migrate_identity_list(&120), 0);
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().instance().has(&DataKey::RegisteredIdentities));
+    });
+    assert_eq!(client.get_identities_page(&1), identities.slice(100..151));
+
+    let identity = Address::generate(&env);
+    client.register(&identity, &Address::generate(&env));
+    identities.push_back(identity);
+    assert_eq!(client.get_identity_count(), 152);
+    assert_eq!(client.get_all_identities(), identities);
+}
+
+#[test]
+fn test_migrate_identity_list_removes_empty_legacy_list() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    seed_legacy_identities(&env, &contract_id, 0);
+    assert_eq!(client.migrate_identity_list(&10), 0);
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().instance().has(&DataKey::RegisteredIdentities));
+    });
+}
+
+/// Write `identity`'s entry, reverse mapping and list position the way
+/// versions before persistent storage did.
+fn register_legacy(env: &Env, contract_id: &Address, identity: &Address, bond_contract: &Address) {
+    env.as_contract(contract_id, || {
+        let mut identities: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::RegisteredIdentities)
+            .unwrap_or_else(|| Vec::new(env));
+        identities.push_back(identity.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::RegisteredIdentities, &identities);
+
+        let entry = LegacyRegistryEntry {
+            identity: identity.clone(),
+            bond_contract: bond_contract.clone(),
+            registered_at: env.ledger().timestamp(),
+            active: true,
+        };
+        env.storage()
+            .instance()
+            .set(&DataKey::IdentityToBond(identity.clone()), &entry);
+        env.storage()
+            .instance()
+            .set(&DataKey::BondToIdentity(bond_contract.clone()), identity);
+    });
+}
+
+#[test]
+fn test_entries_stored_in_persistent_storage() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    let identity = Address::generate(&env);
+    let bond_contract = Address::generate(&env);
+    client.register(&identity, &bond_contract);
+
+    env.as_contract(&contract_id, || {
+        let forward = DataKey::IdentityToBond(identity.clone());
+        let reverse = DataKey::BondToIdentity(bond_contract.clone());
+        assert!(env.storage().persistent().has(&forward));
+        assert!(env.storage().persistent().has(&reverse));
+        assert!(!env.storage().instance().has(&forward));
+        assert_eq!(env.storage().persistent().get_ttl(&forward), BUMP_TARGET);
+    });
+}
+
+#[test]
+fn test_registrations_do_not_grow_instance_footprint() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    client.register(&Address::generate(&env), &Address::generate(&env));
+    client.get_admin();
+    let before = env.cost_estimate().resources().read_bytes;
+
+    for _ in 0..20 {
+        client.register(&Address::generate(&env), &Address::generate(&env));
+    }
+    client.get_admin();
+    assert_eq!(env.cost_estimate().resources().read_bytes, before);
+}
+
+#[test]
+fn test_legacy_entries_readable_before_migration() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    let identity = Address::generate(&env);
+    let bond_contract = Address::generate(&env);
+    register_legacy(&env, &contract_id, &identity, &bond_contract);
+
+    assert!(client.is_registered(&identity));
+    assert_eq!(client.get_identity(&bond_contract), identity);
+    assert!(client
+        .try_register(&identity, &Address::generate(&env))
+        .is_err());
+}
+
+#[test]
+fn test_migrate_entries() {
+    let (env, contract_id, admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    let identity1 = Address::generate(&env);
+    let identity2 = Address::generate(&env);
+    let bond_contract1 = Address::generate(&env);
+    let bond_contract2 = Address::generate(&env);
+    register_legacy(&env, &contract_id, &identity1, &bond_contract1);
+    register_legacy(&env, &contract_id, &identity2, &bond_contract2);
+    let unknown = Address::generate(&env);
+
+    let batch = vec![&env, identity1.clone(), unknown.clone()];
+    assert_eq!(client.migrate_entries(&admin, &batch), 1);
+    // Retrying a batch moves nothing twice.
+    assert_eq!(client.migrate_entries(&admin, &batch), 0);
+    assert_eq!(
+        client.migrate_entries(&admin, &vec![&env, identity2.clone()]),
+        1
+    );
+
+    env.as_contract(&contract_id, || {
+        for (identity, bond_contract) in
+            [(&identity1, &bond_contract1), (&identity2, &bond_contract2)]
+        {
+            let forward = DataKey::IdentityToBond(identity.clone());
+            let reverse = DataKey::BondToIdentity(bond_contract.clone());
+            assert!(!env.storage().instance().has(&forward));
+            assert!(!env.storage().instance().has(&reverse));
+            assert!(env.storage().persistent().has(&forward));
+            assert!(env.storage().persistent().has(&reverse));
+        }
+    });
+    assert_eq!(
+        client.get_bond_contract(&identity1).bond_contract,
+        bond_contract1
+    );
+    assert_eq!(client.get_identity(&bond_contract2), identity2);
+    assert_eq!(
+        client.get_all_identities(),
+        vec![&env, identity1, identity2]
+    );
+}
+
+#[test]
+fn test_migrate_from_baseline_storage() {
+    let (env, contract_id, admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    let identities = seed_legacy_identities(&env, &contract_id, 3);
+    assert_eq!(client.migrate_identity_list(&10), 0);
+    assert_eq!(client.migrate_entries(&admin, &identities), 3);
+
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().instance().has(&DataKey::RegisteredIdentities));
+        for identity in identities.iter() {
+            let entry: RegistryEntry = env
+                .storage()
+                .persistent()
+                .get(&DataKey::IdentityToBond(identity.clone()))
+                .unwrap();
+            let reverse = DataKey::BondToIdentity(entry.bond_contract);
+            assert!(!env
+                .storage()
+                .instance()
+                .has(&DataKey::IdentityToBond(identity)));
+            assert!(!env.storage().instance().has(&reverse));
+            assert!(env.storage().persistent().has(&reverse));
+        }
+    });
+    assert_eq!(client.get_identity_count(), 3);
+    assert_eq!(client.get_all_identities(), identities);
+}
+
+#[test]
+#[should_panic(expected = "not admin")]
+fn test_migrate_entries_non_admin() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    client.migrate_entries(&Address::generate(&env), &Vec::new(&env));
+}
+
+#[test]
+fn test_update_registration() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    let identity = Address::generate(&env);
+    let old_bond_contract = Address::generate(&env);
+    let new_bond_contract = Address::generate(&env);
+    let registered = client.register(&identity, &old_bond_contract);
+    assert_eq!(registered.updated_at, registered.registered_at);
+
+    env.ledger().with_mut(|li| li.timestamp += 1_000);
+    let entry = client.update_registration(&identity, &new_bond_contract);
+    assert_eq!(
+        env.events().all().last().unwrap().1,
+        (Symbol::new(&env, "registration_updated"),).into_val(&env)
+    );
+
+    assert_eq!(entry.bond_contract, new_bond_contract);
+    assert_eq!(entry.registered_at, registered.registered_at);
+    assert_eq!(entry.updated_at, registered.registered_at + 1_000);
+    assert!(entry.active);
+    assert_eq!(
+        client.get_bond_contract(&identity).bond_contract,
+        new_bond_contract
+    );
+    assert_eq!(client.get_identity(&new_bond_contract), identity);
+    assert!(client.try_get_identity(&old_bond_contract).is_err());
+
+    // The old contract is free to be registered again.
+    client.register(&Address::generate(&env), &old_bond_contract);
+}
+
+#[test]
+#[should_panic(expected = "bond contract already registered")]
+fn test_update_registration_to_taken_contract() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    let identity = Address::generate(&env);
+    let taken = Address::generate(&env);
+    client.register(&identity, &Address::generate(&env));
+    client.register(&Address::generate(&env), &taken);
+    client.update_registration(&identity, &taken);
+}
+
+#[test]
+#[should_panic(expected = "bond contract is a registrar")]
+fn test_update_registration_to_registrar() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    let identity = Address::generate(&env);
+    let registrar = Address::generate(&env);
+    client.register(&identity, &Address::generate(&env));
+    client.set_registrar(&registrar, &true);
+    client.register_by_registrar(&registrar, &Address::generate(&env));
+    client.update_registration(&identity, &registrar);
+}
+
+#[test]
+#[should_panic(expected = "bond contract unchanged")]
+fn test_update_registration_same_contract() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    let identity = Address::generate(&env);
+    let bond_contract = Address::generate(&env);
+    client.register(&identity, &bond_contract);
+    client.update_registration(&identity, &bond_contract);
+}
+
+#[test]
+#[should_panic(expected = "identity not registered")]
+fn test_update_registration_not_registered() {
+    let (env, contract_id, _admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    client.update_registration(&Address::generate(&env), &Address::generate(&env));
+}
+
+#[test]
+fn test_update_legacy_registration() {
+    let (env, contract_id, admin) = setup_registry();
+    let client = CredenceRegistryClient::new(&env, &contract_id);
+
+    let identity = Address::generate(&env);
+    let old_bond_contract = Address::generate(&env);
+    let new_bond_contract = Address::generate(&env);
+    register_legacy(&env, &contract_id, &identity, &old_bond_contract);
+    client.update_registration(&identity, &new_bond_contract);
+
+    // Migrating afterwards must not bring back the old reverse mapping.
+    client.migrate_entries(&admin, &vec![&env, identity.clone()]);
+    assert_eq!(
+        client.get_bond_contract(&identity).bond_contract,
+        new_bond_contract
+    );
+    assert_eq!(client.get_identity(&new_bond_contract), identity);
+    assert!(client.try_get_identity(&old_bond_contract).is_err());
+}
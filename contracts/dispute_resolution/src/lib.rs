@@ -6,22 +6,64 @@
 //!
 //! | Key                          | Tier         | Lifecycle      |
 //! |------------------------------|--------------|----------------|
+//! | `DataKey::Admin`             | `instance()` | Entire contract|
+//! | `DataKey::PendingAdmin`      | `instance()` | Until accepted |
 //! | `DataKey::DisputeCounter`    | `instance()` | Entire contract|
+//! | `DataKey::TieBreak`          | `instance()` | Entire contract|
+//! | `DataKey::MinVotes`          | `instance()` | Entire contract|
+//! | `DataKey::CancellationFeeBps`| `instance()` | Entire contract|
+//! | `DataKey::ForfeitBps`        | `instance()` | Entire contract|
+//! | `DataKey::AppealWindow`      | `instance()` | Entire contract|
+//! | `DataKey::DefaultMinStake`   | `instance()` | Entire contract|
+//! | `DataKey::Treasury`          | `instance()` | Entire contract|
+//! | `DataKey::ClaimWindow`       | `instance()` | Entire contract|
+//! | `DataKey::AntiSnipe`         | `instance()` | Entire contract|
+//! | `DataKey::ResolverFee`       | `instance()` | Entire contract|
+//! | `PauseKey::Current`/`Last`  | `instance()` | Entire contract|
+//! | `DataKey::MaxResolutionWindow`| `instance()` | Entire contract|
+//! | `DataKey::MinVotingPeriod`   | `instance()` | Entire contract|
+//! | `DataKey::Stats`             | `instance()` | Entire contract|
+//! | `DataKey::StakeAllowlistEnabled`| `instance()` | Entire contract|
+//! | `DataKey::ResolutionHook`    | `instance()` | Entire contract|
 //! | `DataKey::Dispute(id)`       | `persistent()`| Per dispute   |
 //! | `DataKey::Vote(id, address)` | `persistent()`| Per vote      |
+//! | `DataKey::Arbitrator(address)`| `persistent()`| Per arbitrator|
+//! | `DataKey::Evidence(id, index)`| `persistent()`| Per evidence  |
+//! | `DataKey::EvidenceCount(id)` | `persistent()`| Per dispute   |
+//! | `DataKey::Appeal(id)`        | `persistent()`| Per appeal    |
+//! | `DataKey::AppealVote(id, address)`| `persistent()`| Per appeal vote|
+//! | `DataKey::MinStake(token)`   | `persistent()`| Per token     |
+//! | `DataKey::AllowedStakeToken(token)`| `persistent()`| Per token |
+//! | `DataKey::HookPending(id)`   | `persistent()`| Until delivered|
+//! | `DataKey::RewardPool(id)`    | `persistent()`| Per dispute   |
+//! | `DataKey::RewardClaimed(id, address)`| `persistent()`| Per claim |
+//! | `DataKey::DisputerIndex(address)`| `persistent()`| Per disputer |
+//! | `DataKey::SlashRequestIndex(slash_id)`| `persistent()`| Per slash request |
+//! | `DataKey::OpenDisputes`      | `persistent()`| Entire contract|
+//! | `DataKey::Panel(id)`         | `persistent()`| Per dispute   |
+//! | `DataKey::ArbitratorStats(address)`| `persistent()`| Per arbitrator|
+//! | `DataKey::AccuracyRecorded(id, address)`| `persistent()`| Per vote |
+//! | `DataKey::Commit(id, address)`| `persistent()`| Per commitment|
+//! | `DataKey::AppealCommit(id, address)`| `persistent()`| Per appeal commitment|
 //!
 //! **Why two tiers?**
 //! `instance()` storage shares the contract's rent TTL and is intended for a
-//! small, bounded set of global values (here: a single u64 counter).
+//! small, bounded set of global values (here: the admin, a u64 counter and a
+//! few governance parameters).
 //! `persistent()` storage is independently rentable — each dispute and each
 //! vote has its own TTL that can be bumped cheaply, preventing unbounded
 //! growth of the instance footprint.
 
 #![no_std]
 use soroban_sdk::{
-    contract, contracterror, contractevent, contractimpl, contracttype, Address, Env,
+    contract, contracterror, contractevent, contractimpl, contracttype, symbol_short, vec, Address,
+    Bytes, BytesN, Env, IntoVal, String, Symbol, Val, Vec,
 };
 
+mod pausable;
+
+pub use pausable::PauseInfo;
+
 // ─── TTL constants ────────────────────────────────────────────────────────────
 
 /// Minimum ledger sequence TTL before a bump is requested (~1 day at 5 s/ledger).
@@ -33,9 +75,13 @@ const BUMP_TARGET: u32 = 518_400;
 
 /// Keys for each logical piece of contract state.
 ///
-/// * `DisputeCounter` lives in `instance()` — one entry, tiny, always needed.
-/// * `Dispute(id)` and `Vote(id, addr)` live in `persistent()` — unbounded
-///   sets that must not bloat the instance footprint.
+/// * `Admin`, `PendingAdmin`, `DisputeCounter`, `TieBreak`, `MinVotes`, `CancellationFeeBps`,
+///   `ForfeitBps`, `AppealWindow`, `DefaultMinStake`, `Treasury`, `ClaimWindow`, `AntiSnipe`,
+///   `ResolverFee`, `MaxResolutionWindow`, `MinVotingPeriod`, `Stats`,
+///   `StakeAllowlistEnabled` and `ResolutionHook` live in `instance()` — one
+///   entry each, tiny, always needed.
+/// * Every other key lives in `persistent()` — unbounded sets that must not
+///   bloat the instance footprint.
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
@@ -43,8 +89,78 @@ pub enum DataKey {
     DisputeCounter,
     /// Full dispute record keyed by its ID. Stored in `persistent()`.
     Dispute(u64),
-    /// Boolean vote record keyed by (dispute_id, arbitrator). Stored in `persistent()`.
+    /// `VoteRecord` keyed by (dispute_id, arbitrator). Stored in `persistent()`.
     Vote(u64, Address),
+    /// Address allowed to manage the arbitrator registry. Stored in `instance()`.
+    Admin,
+    /// Address nominated by `transfer_admin`, awaiting `accept_admin`. Stored in `instance()`.
+    PendingAdmin,
+    /// Voting weight of a registered arbitrator. Stored in `persistent()`.
+    Arbitrator(Address),
+    /// Rule applied when both sides hold equal vote weight. Stored in `instance()`.
+    TieBreak,
+    /// Minimum raw vote count before a dispute can resolve. Stored in `instance()`.
+    MinVotes,
+    /// Share of the stake kept on `cancel_dispute`, in basis points. Stored in `instance()`.
+    CancellationFeeBps,
+    /// Share of the settled stake forfeited when the disputer loses, in basis points. Stored in `instance()`.
+    ForfeitBps,
+    /// Evidence record keyed by (dispute_id, index). Stored in `persistent()`.
+    Evidence(u64, u32),
+    /// Per-side evidence counters for a dispute. Stored in `persistent()`.
+    EvidenceCount(u64),
+    /// Seconds after resolution during which an appeal may be filed. Stored in `instance()`.
+    AppealWindow,
+    /// First-round result of an appealed dispute. Stored in `persistent()`.
+    Appeal(u64),
+    /// Appeal-round `VoteRecord` keyed by (dispute_id, arbitrator). Stored in `persistent()`.
+    AppealVote(u64, Address),
+    /// Minimum stake applied to tokens without their own entry. Stored in `instance()`.
+    DefaultMinStake,
+    /// Minimum stake for disputes staked in a specific token. Stored in `persistent()`.
+    MinStake(Address),
+    /// Set once the first stake token is allowlisted. Stored in `instance()`.
+    StakeAllowlistEnabled,
+    /// Flag marking a token as accepted for dispute stakes. Stored in `persistent()`.
+    AllowedStakeToken(Address),
+    /// `ResolutionHook` notified of terminal outcomes. Stored in `instance()`.
+    ResolutionHook,
+    /// Outcome whose hook call failed and awaits `retry_resolution_hook`. Stored in `persistent()`.
+    HookPending(u64),
+    /// `credence_treasury` contract receiving swept forfeited stakes. Stored in `instance()`.
+    Treasury,
+    /// Forfeited stake claimable by winning arbitrators. Stored in `persistent()`.
+    RewardPool(u64),
+    /// Flag set once an address has claimed from a reward pool. Stored in `persistent()`.
+    RewardClaimed(u64, Address),
+    /// Seconds arbitrators have to claim before a pool can be swept. Stored in `instance()`.
+    ClaimWindow,
+    /// Late-vote deadline extension settings. Stored in `instance()`.
+    AntiSnipe,
+    /// IDs of every dispute opened by an address, oldest first. Stored in `persistent()`.
+    DisputerIndex(Address),
+    /// Latest dispute opened against a slash request. Stored in `persistent()`.
+    SlashRequestIndex(u64),
+    /// Unordered IDs of disputes in a voting round. Stored in `persistent()`.
+    OpenDisputes,
+    /// Incentive paid to whoever resolves or expires a dispute. Stored in `instance()`.
+    ResolverFee,
+    /// Longest voting period `create_dispute` accepts. Stored in `instance()`.
+    MaxResolutionWindow,
+    /// Shortest voting period `create_dispute` accepts. Stored in `instance()`.
+    MinVotingPeriod,
+    /// Aggregate `DisputeStats` over terminal disputes. Stored in `instance()`.
+    Stats,
+    /// `ArbitratorStats` for one arbitrator. Stored in `persistent()`.
+    ArbitratorStats(Address),
+    /// Flag set once a vote has been counted in `ArbitratorStats`. Stored in `persistent()`.
+    AccuracyRecorded(u64, Address),
+    /// Arbitrators assigned to a panel-mode dispute. Stored in `persistent()`.
+    Panel(u64),
+    /// `VoteCommit` keyed by (dispute_id, arbitrator). Stored in `persistent()`.
+    Commit(u64, Address),
+    /// `VoteCommit` cast in an appeal round. Stored in `persistent()`.
+    AppealCommit(u64, Address),
 }
 
 // ─── Domain types ─────────────────────────────────────────────────────────────
@@ -56,6 +172,8 @@ pub enum DisputeStatus {
     Resolved,
     Rejected,
     Expired,
+    Cancelled,
+    UnderAppeal,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -66,6 +184,36 @@ pub enum DisputeOutcome {
     FavorSlasher,
 }
 
+/// How `resolve_dispute` decides a dispute whose sides hold equal weight.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum TieBreakRule {
+    /// Ties go to the slasher; the stake stays forfeited. Default.
+    FavorSlasher,
+    /// Ties go to the disputer; the stake is returned.
+    FavorDisputer,
+    /// Ties go to the side with more raw votes, then to the slasher.
+    MajorityCount,
+    /// Reopen voting for the given number of seconds (counted from the
+    /// resolution attempt) instead of resolving. Applied once per dispute; a
+    /// second tie favors the slasher.
+    ExtendDeadline(u64),
+}
+
+/// Incentive paid out of the dispute stake to the caller of
+/// `resolve_dispute`. Always capped at the stake. Expiry refunds the stake
+/// in full, so `expire_dispute` pays no fee.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum ResolverFee {
+    /// No incentive. Default.
+    None,
+    /// Fixed token amount.
+    Flat(i128),
+    /// Share of the stake in basis points.
+    Bps(u32),
+}
+
 #[contracterror]
 #[derive(Clone, Debug, PartialEq)]
 pub enum Error {
@@ -78,10 +226,85 @@ pub enum Error {
     InsufficientStake = 7,
     InvalidDeadline = 8,
     TransferFailed = 9,
+    AlreadyInitialized = 10,
+    NotInitialized = 11,
+    AlreadyArbitrator = 12,
+    NotArbitrator = 13,
+    InvalidWeight = 14,
+    QuorumNotReached = 15,
+    QuorumReached = 16,
+    InvalidQuorum = 17,
+    DisputeHasVotes = 18,
+    InvalidFee = 19,
+    EvidenceLimitReached = 20,
+    EvidenceNotFound = 21,
+    InvalidEvidence = 22,
+    AppealNotAllowed = 23,
+    AppealWindowClosed = 24,
+    AlreadyAppealed = 25,
+    InvalidAmount = 26,
+    NoReward = 27,
+    AlreadyClaimed = 28,
+    RewardLocked = 29,
+    AlreadySwept = 30,
+    TreasuryNotSet = 31,
+    DuplicateDispute = 32,
+    NoPendingAdmin = 33,
+    VoteNotFound = 34,
+    VoteUnchanged = 35,
+    TallyOverflow = 36,
+    InvalidPanel = 37,
+    NotOnPanel = 38,
+    InvalidDetails = 39,
+    ContractPaused = 40,
+    NotPaused = 41,
+    NotCommitReveal = 42,
+    CommitRevealRequired = 43,
+    InvalidReveal = 44,
+    StatsOverflow = 45,
+    OutcomeNotFinal = 46,
+    AlreadyRecorded = 47,
+    TokenNotAllowed = 48,
+    HookNotPending = 49,
+    HookFailed = 50,
 }
 
 // ─── Events ───────────────────────────────────────────────────────────────────
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminInitialized {
+    pub admin: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferProposed {
+    pub admin: Address,
+    pub pending_admin: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferred {
+    pub previous_admin: Address,
+    pub new_admin: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Paused {
+    pub admin: Address,
+    pub until: u64,
+    pub reason: Symbol,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unpaused {
+    pub admin: Address,
+}
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct DisputeCreated {
@@ -90,6 +313,9 @@ pub struct DisputeCreated {
     pub slash_request_id: u64,
     pub stake: i128,
     pub deadline: u64,
+    pub reason: Symbol,
+    pub details_hash: Option<BytesN<32>>,
+    pub details_uri: String,
 }
 
 #[contractevent]
@@ -98,6 +324,50 @@ pub struct VoteCast {
     pub dispute_id: u64,
     pub arbitrator: Address,
     pub favor_disputer: bool,
+    pub weight: u64,
+    pub vote_count: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitRevealEnabled {
+    pub dispute_id: u64,
+    pub reveal_period: u64,
+    pub reveal_deadline: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteCommitted {
+    pub dispute_id: u64,
+    pub arbitrator: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteRevealed {
+    pub dispute_id: u64,
+    pub arbitrator: Address,
+    pub favor_disputer: bool,
+    pub weight: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccuracyRecorded {
+    pub dispute_id: u64,
+    pub arbitrator: Address,
+    pub with_majority: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteChanged {
+    pub dispute_id: u64,
+    pub arbitrator: Address,
+    pub old_favor_disputer: bool,
+    pub new_favor_disputer: bool,
+    pub weight: u64,
 }
 
 #[contractevent]
@@ -107,6 +377,69 @@ pub struct DisputeResolved {
     pub outcome: DisputeOutcome,
     pub votes_for_disputer: u64,
     pub votes_for_slasher: u64,
+    pub count_for_disputer: u64,
+    pub count_for_slasher: u64,
+    pub appealed: bool,
+    /// Stake returned to the disputer by this resolution.
+    pub refunded: i128,
+    /// Stake kept for the slasher-side reward pool by this resolution.
+    pub forfeited: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardClaimable {
+    pub dispute_id: u64,
+    pub amount: i128,
+    pub total_weight: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardClaimed {
+    pub dispute_id: u64,
+    pub claimant: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolverPaid {
+    pub dispute_id: u64,
+    pub resolver: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeadlineExtended {
+    pub dispute_id: u64,
+    pub new_deadline: u64,
+    pub extensions: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolutionHookFailed {
+    pub dispute_id: u64,
+    pub hook: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForfeitSwept {
+    pub dispute_id: u64,
+    pub treasury: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeAppealed {
+    pub dispute_id: u64,
+    pub appellant: Address,
+    pub additional_stake: i128,
+    pub new_deadline: u64,
 }
 
 #[contractevent]
@@ -114,6 +447,66 @@ pub struct DisputeResolved {
 pub struct DisputeExpired {
     pub dispute_id: u64,
     pub expired_at: u64,
+    pub refunded: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EvidenceSubmitted {
+    pub dispute_id: u64,
+    pub index: u32,
+    pub submitter: Address,
+    pub evidence_hash: BytesN<32>,
+    pub uri: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PanelAssigned {
+    pub dispute_id: u64,
+    pub panel: Vec<Address>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeSlasherSet {
+    pub dispute_id: u64,
+    pub slasher: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeCancelled {
+    pub dispute_id: u64,
+    pub disputer: Address,
+    pub refunded: i128,
+    pub fee: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeExtended {
+    pub dispute_id: u64,
+    pub new_deadline: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitratorAdded {
+    pub arbitrator: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitratorRemoved {
+    pub arbitrator: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArbitratorWeightSet {
+    pub arbitrator: Address,
+    pub weight: u64,
 }
 
 // ─── Data structures ──────────────────────────────────────────────────────────
@@ -123,7 +516,10 @@ pub struct DisputeExpired {
 /// **Note:** The `id` field was removed — it was redundant because the dispute
 /// ID is already used as the `DataKey::Dispute(id)` storage key. Callers that
 /// need the ID already hold it as a local variable or return value.
-#[derive(Clone)]
+///
+/// `votes_for_*` accumulate arbitrator weight; `count_for_*` hold the raw
+/// number of votes on each side for quorum checks.
+#[derive(Clone, Debug, PartialEq)]
 #[contracttype]
 pub struct Dispute {
     pub disputer: Address,
@@ -135,14 +531,248 @@ pub struct Dispute {
     pub deadline: u64,
     pub votes_for_disputer: u64,
     pub votes_for_slasher: u64,
+    pub count_for_disputer: u64,
+    pub count_for_slasher: u64,
     pub created_at: u64,
+    /// Set once the deadline has been extended by `TieBreakRule::ExtendDeadline`.
+    pub extended: bool,
+    /// Slasher behind the disputed request, if recorded via `set_dispute_slasher`.
+    pub slasher: Option<Address>,
+    /// Length of the original voting period; reused for an appeal round.
+    pub voting_period: u64,
+    /// Ledger timestamp of the most recent resolution, `0` until resolved.
+    pub resolved_at: u64,
+    /// Extra stake posted by `appeal_dispute`, `0` if never appealed.
+    pub appeal_stake: i128,
+    /// Set once the dispute has entered its (single) appeal round.
+    pub appealed: bool,
+    /// Late-vote extensions applied in the current round.
+    pub snipe_extensions: u32,
+    /// Size of the assigned arbitrator panel, `0` for open voting.
+    pub panel_size: u32,
+    /// Length of the reveal window after `deadline`, `0` for open ballots.
+    pub reveal_period: u64,
+    /// End of the reveal window, `0` for open ballots.
+    pub reveal_deadline: u64,
+    /// Commitments made in the current round.
+    pub commit_count: u32,
+    /// Category of the dispute, `DEFAULT_DISPUTE_REASON` if not given.
+    pub reason: Symbol,
+    /// Hash of the off-chain statement of grounds, if any.
+    pub details_hash: Option<BytesN<32>>,
+    /// Location of the statement of grounds; empty when there is none.
+    pub details_uri: String,
+    /// Stake already returned to the disputer by earlier resolutions.
+    pub stake_returned: i128,
+    /// Resolver fees already paid out of the stake by earlier resolutions.
+    pub fees_paid: i128,
+}
+
+/// Why a dispute was raised: a short category plus an optional off-chain
+/// statement of grounds.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct DisputeDetails {
+    pub reason: Symbol,
+    pub details_hash: Option<BytesN<32>>,
+    pub details_uri: String,
+}
+
+/// External contract told about terminal outcomes: `resolve_dispute` and
+/// `expire_dispute` call `contract.fn_name(dispute_id, outcome)`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ResolutionHook {
+    pub contract: Address,
+    pub fn_name: Symbol,
+}
+
+/// Anti-sniping rule: a vote cast within `window_secs` of the deadline pushes
+/// it back by `extension_secs`, at most `max_extensions` times per round.
+/// Disabled while `max_extensions == 0`.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[contracttype]
+pub struct AntiSnipeConfig {
+    pub window_secs: u64,
+    pub extension_secs: u64,
+    pub max_extensions: u32,
+}
+
+/// Running totals over disputes that reached a terminal state.
+///
+/// An appeal reopens a dispute, so its first-round resolution is taken back
+/// out of the totals until the appeal concludes. `total_forfeited` is stake
+/// kept from disputers (reward pools and cancellation fees); resolver fees
+/// count as neither refunded nor forfeited.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[contracttype]
+pub struct DisputeStats {
+    pub total_resolved: u64,
+    pub total_favor_disputer: u64,
+    pub total_favor_slasher: u64,
+    pub total_expired: u64,
+    pub total_cancelled: u64,
+    pub total_refunded: i128,
+    pub total_forfeited: i128,
+}
+
+/// How often an arbitrator's recorded votes matched the final outcome.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[contracttype]
+pub struct ArbitratorStats {
+    pub votes_total: u64,
+    pub votes_with_majority: u64,
+}
+
+/// Where a dispute stands in its voting round.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum VotingPhase {
+    /// Open ballot before the deadline: `cast_vote`.
+    Voting,
+    /// Commit-reveal before the deadline: `commit_vote`.
+    Commit,
+    /// Commit-reveal between the deadline and the reveal deadline: `reveal_vote`.
+    Reveal,
+    /// No votes accepted: awaiting resolution, or not in a voting round.
+    Closed,
+}
+
+/// A sealed vote: `sha256(favor_disputer as u8 || salt)` and the weight it
+/// will carry once revealed.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct VoteCommit {
+    pub commitment: BytesN<32>,
+    pub weight: u64,
+}
+
+/// A single arbitrator's vote and the weight it carried.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct VoteRecord {
+    pub favor_disputer: bool,
+    pub weight: u64,
+}
+
+/// Forfeited stake owed to the arbitrators who sided with the slasher.
+///
+/// Each voter claims `amount * weight / total_weight`, rounded down, so the
+/// sum of claims never exceeds `amount`. Whatever is left after the claim
+/// window is swept to the treasury.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct RewardPool {
+    pub amount: i128,
+    pub total_weight: u64,
+    pub claimed: i128,
+    /// Whether the winning votes were cast in the appeal round.
+    pub appeal_round: bool,
+    /// First ledger timestamp at which claims are accepted.
+    pub claimable_at: u64,
+    /// Set once the unclaimed remainder has been sent to the treasury.
+    pub swept: bool,
+}
+
+/// First-round result preserved when a dispute is appealed.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct AppealRecord {
+    pub appellant: Address,
+    pub outcome: DisputeOutcome,
+    pub votes_for_disputer: u64,
+    pub votes_for_slasher: u64,
+    pub count_for_disputer: u64,
+    pub count_for_slasher: u64,
+    pub resolved_at: u64,
+    pub appealed_at: u64,
+}
+
+/// A piece of evidence attached to a dispute. The payload lives off-chain at
+/// `uri`; `evidence_hash` commits to its contents.
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct Evidence {
+    pub submitter: Address,
+    pub evidence_hash: BytesN<32>,
+    pub uri: String,
+    pub submitted_at: u64,
+}
+
+/// Number of evidence entries each side has submitted on a dispute.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[contracttype]
+pub struct EvidenceTally {
+    pub disputer: u32,
+    pub slasher: u32,
 }
 
 // ─── Constants ────────────────────────────────────────────────────────────────
 
-/// Minimum token amount required to open a dispute.
+/// Minimum stake used when neither a per-token nor a default minimum is set.
 pub const MIN_STAKE: i128 = 100;
 
+/// Weight given to an arbitrator when first registered.
+pub const DEFAULT_ARBITRATOR_WEIGHT: u64 = 1;
+
+/// Votes required before a dispute can resolve when no quorum is configured.
+pub const DEFAULT_MIN_VOTES_TO_RESOLVE: u64 = 1;
+
+/// Basis-point denominator for the cancellation fee and forfeit share.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Forfeit share used when none is configured: the whole stake.
+pub const DEFAULT_FORFEIT_BPS: u32 = BPS_DENOMINATOR;
+
+/// Maximum evidence entries each side may attach to a single dispute.
+pub const MAX_EVIDENCE_PER_SIDE: u32 = 10;
+
+/// Maximum byte length of an evidence URI.
+pub const MAX_EVIDENCE_URI_LEN: u32 = 256;
+
+/// Appeal window used when none is configured (~1 day).
+pub const DEFAULT_APPEAL_WINDOW_SECS: u64 = 86_400;
+
+/// Longest appeal window `set_appeal_window` accepts (~30 days).
+pub const MAX_APPEAL_WINDOW_SECS: u64 = 2_592_000;
+
+/// Longest single deadline extension a tie-break or anti-snipe rule may
+/// apply (~30 days).
+pub const MAX_DEADLINE_EXTENSION_SECS: u64 = 2_592_000;
+
+/// An appeal must post at least this multiple of the original stake.
+pub const APPEAL_STAKE_MULTIPLIER: i128 = 2;
+
+/// Claim window used when none is configured (~30 days).
+pub const DEFAULT_CLAIM_WINDOW_SECS: u64 = 2_592_000;
+
+/// Longest claim window `set_claim_window` accepts (~90 days).
+pub const MAX_CLAIM_WINDOW_SECS: u64 = 7_776_000;
+
+/// Longest voting period accepted when none is configured (~30 days).
+pub const DEFAULT_MAX_RESOLUTION_WINDOW_SECS: u64 = 2_592_000;
+
+/// Shortest voting period enforced when none is configured (no extra bound).
+pub const DEFAULT_MIN_VOTING_PERIOD_SECS: u64 = 0;
+
+/// Reason recorded by `create_dispute`, which takes no reason of its own.
+pub const DEFAULT_DISPUTE_REASON: Symbol = symbol_short!("general");
+
+/// Smallest arbitrator panel `assign_panel` accepts.
+pub const MIN_PANEL_SIZE: u32 = 3;
+
+/// Largest arbitrator panel `assign_panel` accepts.
+pub const MAX_PANEL_SIZE: u32 = 15;
+
+/// Maximum number of dispute IDs returned by one paginated query.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// `credence_treasury::FundSource::ProtocolFee`, passed to `receive_fee`.
+const FUND_SOURCE_PROTOCOL_FEE: u32 = 0;
+
+/// `credence_treasury::FundSource::SlashedFunds`, passed to `receive_fee`.
+const FUND_SOURCE_SLASHED: u32 = 1;
+
 // ─── Contract ─────────────────────────────────────────────────────────────────
 
 #[contract]
@@ -174,227 +804,2648 @@ impl DisputeContract {
             .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
     }
 
-    // ── Public interface ──────────────────────────────────────────────────────
+    /// Require `admin` to sign and match the stored registry admin.
+    fn require_admin(env: &Env, admin: &Address) -> Result<(), Error> {
+        admin.require_auth();
+        let stored: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        if stored != *admin {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
 
-    /// Open a new dispute against a slash request.
-    ///
-    /// The disputer's `stake` is transferred from their account to the contract
-    /// and held until the dispute is resolved or expired.
-    ///
-    /// # Errors
-    /// * `InsufficientStake` — `stake < MIN_STAKE`
-    /// * `InvalidDeadline` — `resolution_deadline == 0`
-    pub fn create_dispute(
-        env: Env,
-        disputer: Address,
-        slash_request_id: u64,
-        stake: i128,
-        token: Address,
-        resolution_deadline: u64,
-    ) -> Result<u64, Error> {
-        disputer.require_auth();
+    /// Apply `update` to the stored `DisputeStats`; `update` returns `None`
+    /// when a checked operation fails.
+    fn update_stats(
+        env: &Env,
+        update: impl FnOnce(DisputeStats) -> Option<DisputeStats>,
+    ) -> Result<(), Error> {
+        let stats = update(Self::get_dispute_stats(env.clone())).ok_or(Error::StatsOverflow)?;
+        env.storage().instance().set(&DataKey::Stats, &stats);
+        Ok(())
+    }
 
-        if stake < MIN_STAKE {
-            return Err(Error::InsufficientStake);
+    /// Reject new activity while the contract is paused.
+    fn require_not_paused(env: &Env) -> Result<(), Error> {
+        if pausable::is_paused(env) {
+            return Err(Error::ContractPaused);
         }
+        Ok(())
+    }
 
-        if resolution_deadline == 0 {
-            return Err(Error::InvalidDeadline);
-        }
+    /// Weight of `arbitrator` if registered, bumping the entry's TTL if so.
+    fn load_arbitrator(env: &Env, arbitrator: &Address) -> Option<u64> {
+        let key = DataKey::Arbitrator(arbitrator.clone());
+        let storage = env.storage().persistent();
+        let weight: u64 = storage.get(&key)?;
+        storage.extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+        Some(weight)
+    }
 
-        let current_time = env.ledger().timestamp();
-        let deadline = current_time + resolution_deadline;
+    /// Whether `dispute` is in a voting round (first round or appeal).
+    fn is_voting(dispute: &Dispute) -> bool {
+        matches!(
+            dispute.status,
+            DisputeStatus::Open | DisputeStatus::UnderAppeal
+        )
+    }
 
-        // Transfer stake into the contract — one storage-read-free cross-contract call.
-        let token_client = soroban_sdk::token::Client::new(&env, &token);
-        let contract_address = env.current_contract_address();
-        token_client.transfer_from(&contract_address, &disputer, &contract_address, &stake);
+    /// Storage key recording `arbitrator`'s vote in the dispute's current round.
+    fn vote_key(dispute_id: u64, dispute: &Dispute, arbitrator: Address) -> DataKey {
+        if dispute.appealed {
+            DataKey::AppealVote(dispute_id, arbitrator)
+        } else {
+            DataKey::Vote(dispute_id, arbitrator)
+        }
+    }
 
-        // Increment the global counter (instance storage — always loaded with the contract).
-        let counter: u64 = env
-            .storage()
-            .instance()
+    /// Storage key recording `arbitrator`'s commitment in the current round.
+    fn commit_key(dispute_id: u64, dispute: &Dispute, arbitrator: Address) -> DataKey {
+        if dispute.appealed {
+            DataKey::AppealCommit(dispute_id, arbitrator)
+        } else {
+            DataKey::Commit(dispute_id, arbitrator)
+        }
+    }
+
+    /// Timestamp after which the current round can be resolved or expired:
+    /// the reveal deadline for commit-reveal disputes, else the deadline.
+    fn voting_closes(dispute: &Dispute) -> u64 {
+        if dispute.reveal_period > 0 {
+            dispute.reveal_deadline
+        } else {
+            dispute.deadline
+        }
+    }
+
+    /// Last timestamp at which a resolved `dispute` can still be appealed.
+    fn appeal_closes(env: &Env, dispute: &Dispute) -> u64 {
+        dispute
+            .resolved_at
+            .saturating_add(Self::get_appeal_window(env.clone()))
+    }
+
+    /// Start a fresh round ending at `deadline`, moving the reveal window
+    /// along with it for commit-reveal disputes.
+    fn set_round_deadline(dispute: &mut Dispute, deadline: u64) {
+        dispute.deadline = deadline;
+        if dispute.reveal_period > 0 {
+            dispute.reveal_deadline = deadline.saturating_add(dispute.reveal_period);
+        }
+    }
+
+    /// Hash a vote the way `commit_vote` expects it.
+    fn vote_commitment(env: &Env, favor_disputer: bool, salt: &BytesN<32>) -> BytesN<32> {
+        let mut preimage = Bytes::new(env);
+        preimage.push_back(favor_disputer as u8);
+        preimage.append(&Bytes::from(salt.clone()));
+        env.crypto().sha256(&preimage).to_bytes()
+    }
+
+    /// Push the deadline back if a vote lands inside the anti-sniping window.
+    fn apply_anti_snipe(env: &Env, dispute_id: u64, dispute: &mut Dispute) {
+        let snipe = Self::get_anti_snipe_config(env.clone());
+        let now = env.ledger().timestamp();
+        if dispute.snipe_extensions < snipe.max_extensions
+            && dispute.deadline.saturating_sub(now) <= snipe.window_secs
+        {
+            Self::set_round_deadline(
+                dispute,
+                dispute.deadline.saturating_add(snipe.extension_secs),
+            );
+            dispute.snipe_extensions += 1;
+
+            DeadlineExtended {
+                dispute_id,
+                new_deadline: dispute.deadline,
+                extensions: dispute.snipe_extensions,
+            }
+            .publish(env);
+        }
+    }
+
+    /// Return `amount` of the dispute token to the disputer.
+    ///
+    /// Callers must persist the dispute's terminal status first so a
+    /// re-entrant or repeated call fails the status check instead of paying
+    /// twice.
+    fn refund_disputer(env: &Env, dispute: &Dispute, amount: i128) {
+        if amount <= 0 {
+            return;
+        }
+        soroban_sdk::token::Client::new(env, &dispute.token).transfer(
+            &env.current_contract_address(),
+            &dispute.disputer,
+            &amount,
+        );
+    }
+
+    /// Split `settled` stake of a lost dispute into `(refund, forfeited)`.
+    /// The refund rounds down, so the two always add up to `settled`.
+    fn split_forfeit(env: &Env, settled: i128) -> (i128, i128) {
+        let keep = BPS_DENOMINATOR - Self::get_forfeit_bps(env.clone());
+        let refund = settled * keep as i128 / BPS_DENOMINATOR as i128;
+        (refund, settled - refund)
+    }
+
+    /// Make a forfeited `amount` claimable by the slasher-side voters.
+    fn open_reward_pool(
+        env: &Env,
+        dispute_id: u64,
+        amount: i128,
+        total_weight: u64,
+        appeal_round: bool,
+        claimable_at: u64,
+    ) {
+        let key = DataKey::RewardPool(dispute_id);
+        let pool = RewardPool {
+            amount,
+            total_weight,
+            claimed: 0,
+            appeal_round,
+            claimable_at,
+            swept: false,
+        };
+        env.storage().persistent().set(&key, &pool);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+
+        RewardClaimable {
+            dispute_id,
+            amount,
+            total_weight,
+        }
+        .publish(env);
+    }
+
+    /// Resolver incentive owed on a dispute with the given `stake`.
+    fn resolver_fee_for(env: &Env, stake: i128) -> i128 {
+        let fee = match Self::get_resolver_fee(env.clone()) {
+            ResolverFee::None => 0,
+            ResolverFee::Flat(amount) => amount,
+            ResolverFee::Bps(bps) => stake * bps as i128 / BPS_DENOMINATOR as i128,
+        };
+        fee.min(stake)
+    }
+
+    /// Transfer the resolver incentive, if any, and announce it.
+    fn pay_resolver(env: &Env, token: &Address, dispute_id: u64, resolver: Address, amount: i128) {
+        if amount <= 0 {
+            return;
+        }
+        soroban_sdk::token::Client::new(env, token).transfer(
+            &env.current_contract_address(),
+            &resolver,
+            &amount,
+        );
+        ResolverPaid {
+            dispute_id,
+            resolver,
+            amount,
+        }
+        .publish(env);
+    }
+
+    /// Transfer `amount` of `token` to `treasury` and credit it through the
+    /// treasury's `receive_fee` under `source`. The dispute contract must be
+    /// registered as a depositor on the treasury.
+    fn send_to_treasury(env: &Env, treasury: &Address, token: &Address, amount: i128, source: u32) {
+        let contract = env.current_contract_address();
+        soroban_sdk::token::Client::new(env, token).transfer(&contract, treasury, &amount);
+
+        let args: Vec<Val> = Vec::from_array(
+            env,
+            [
+                contract.into_val(env),
+                amount.into_val(env),
+                source.into_val(env),
+            ],
+        );
+        env.invoke_contract::<Val>(treasury, &Symbol::new(env, "receive_fee"), args);
+    }
+
+    /// Call the resolution hook, if any, with `(dispute_id, outcome)`.
+    ///
+    /// Runs after all state is saved. A failing hook cannot revert the
+    /// caller: the failure is recorded under `HookPending` for
+    /// `retry_resolution_hook` and announced instead.
+    fn notify_resolution(env: &Env, dispute_id: u64, outcome: &DisputeOutcome) {
+        let Some(hook) = Self::get_resolution_hook(env.clone()) else {
+            return;
+        };
+        if !Self::invoke_hook(env, &hook, dispute_id, outcome) {
+            let key = DataKey::HookPending(dispute_id);
+            env.storage().persistent().set(&key, &true);
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+
+            ResolutionHookFailed {
+                dispute_id,
+                hook: hook.contract,
+            }
+            .publish(env);
+        }
+    }
+
+    /// Returns `true` if the hook ran without error.
+    fn invoke_hook(
+        env: &Env,
+        hook: &ResolutionHook,
+        dispute_id: u64,
+        outcome: &DisputeOutcome,
+    ) -> bool {
+        let args: Vec<Val> =
+            Vec::from_array(env, [dispute_id.into_val(env), outcome.into_val(env)]);
+        matches!(
+            env.try_invoke_contract::<Val, soroban_sdk::Error>(&hook.contract, &hook.fn_name, args),
+            Ok(Ok(_))
+        )
+    }
+
+    /// Read a vote direction, bumping the entry's TTL if present.
+    fn read_vote(env: &Env, key: &DataKey) -> Option<bool> {
+        let storage = env.storage().persistent();
+        let vote: VoteRecord = storage.get(key)?;
+        storage.extend_ttl(key, BUMP_THRESHOLD, BUMP_TARGET);
+        Some(vote.favor_disputer)
+    }
+
+    /// Add `dispute_id` to the open-dispute worklist.
+    fn push_open(env: &Env, dispute_id: u64) {
+        let storage = env.storage().persistent();
+        let mut open: Vec<u64> = storage.get(&DataKey::OpenDisputes).unwrap_or(vec![env]);
+        open.push_back(dispute_id);
+        storage.set(&DataKey::OpenDisputes, &open);
+        storage.extend_ttl(&DataKey::OpenDisputes, BUMP_THRESHOLD, BUMP_TARGET);
+    }
+
+    /// Swap-remove `dispute_id` from the open-dispute worklist.
+    fn remove_open(env: &Env, dispute_id: u64) {
+        let storage = env.storage().persistent();
+        let mut open: Vec<u64> = storage.get(&DataKey::OpenDisputes).unwrap_or(vec![env]);
+        if let Some(index) = open.first_index_of(dispute_id) {
+            let last = open.pop_back_unchecked();
+            if index < open.len() {
+                open.set(index, last);
+            }
+            storage.set(&DataKey::OpenDisputes, &open);
+            storage.extend_ttl(&DataKey::OpenDisputes, BUMP_THRESHOLD, BUMP_TARGET);
+        }
+    }
+
+    /// Whether `dispute` has collected enough raw votes to be resolved.
+    fn has_quorum(env: &Env, dispute: &Dispute) -> bool {
+        let required = if dispute.panel_size > 0 {
+            dispute.panel_size as u64 / 2 + 1
+        } else {
+            Self::get_min_votes_to_resolve(env.clone())
+        };
+        dispute.count_for_disputer + dispute.count_for_slasher >= required
+    }
+
+    /// Reject voters outside the panel of a panel-mode dispute.
+    fn require_panelist(
+        env: &Env,
+        dispute_id: u64,
+        dispute: &Dispute,
+        arbitrator: &Address,
+    ) -> Result<(), Error> {
+        if dispute.panel_size == 0 {
+            return Ok(());
+        }
+        let key = DataKey::Panel(dispute_id);
+        let storage = env.storage().persistent();
+        let panel: Vec<Address> = storage.get(&key).ok_or(Error::NotOnPanel)?;
+        storage.extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+        if !panel.contains(arbitrator) {
+            return Err(Error::NotOnPanel);
+        }
+        Ok(())
+    }
+
+    /// Decide the winning side from weights, falling back to the tie-break rule.
+    fn decide_outcome(dispute: &Dispute, rule: &TieBreakRule) -> DisputeOutcome {
+        if dispute.votes_for_disputer > dispute.votes_for_slasher {
+            return DisputeOutcome::FavorDisputer;
+        }
+        if dispute.votes_for_disputer < dispute.votes_for_slasher {
+            return DisputeOutcome::FavorSlasher;
+        }
+        match rule {
+            TieBreakRule::FavorSlasher | TieBreakRule::ExtendDeadline(_) => {
+                DisputeOutcome::FavorSlasher
+            }
+            TieBreakRule::FavorDisputer => DisputeOutcome::FavorDisputer,
+            TieBreakRule::MajorityCount => {
+                if dispute.count_for_disputer > dispute.count_for_slasher {
+                    DisputeOutcome::FavorDisputer
+                } else {
+                    DisputeOutcome::FavorSlasher
+                }
+            }
+        }
+    }
+
+    // ── Public interface ──────────────────────────────────────────────────────
+
+    /// Set the admin that manages the arbitrator registry and contract
+    /// configuration. Callable once.
+    ///
+    /// Until this is called every setting falls back to its `DEFAULT_*`
+    /// constant, so dispute creation, resolution and expiry keep working.
+    ///
+    /// # Errors
+    /// * `AlreadyInitialized` — an admin is already set
+    pub fn initialize(env: Env, admin: Address) -> Result<(), Error> {
+        admin.require_auth();
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+
+        AdminInitialized { admin }.publish(&env);
+        Ok(())
+    }
+
+    /// Returns the current admin.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    pub fn get_admin(env: Env) -> Result<Address, Error> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)
+    }
+
+    /// Returns the address nominated by `transfer_admin`, if any.
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    /// Pause new disputes, votes, evidence and appeals until explicitly
+    /// unpaused, recording why. Resolution, expiry, cancellation and reward
+    /// claims stay available so in-flight disputes can conclude.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the current admin
+    pub fn pause(
+        env: Env,
+        admin: Address,
+        reason: Symbol,
+        detail: Option<String>,
+    ) -> Result<PauseInfo, Error> {
+        Self::require_admin(&env, &admin)?;
+        let info = pausable::pause(&env, &admin, 0, reason.clone(), detail);
+        Paused {
+            admin,
+            until: 0,
+            reason,
+        }
+        .publish(&env);
+        Ok(info)
+    }
+
+    /// Pause for `duration_secs`, recording why. The pause lifts on its own
+    /// once the ledger timestamp reaches `until`.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the current admin
+    /// * `InvalidDeadline` — `duration_secs` is 0 or overflows the timestamp
+    pub fn pause_for(
+        env: Env,
+        admin: Address,
+        duration_secs: u64,
+        reason: Symbol,
+        detail: Option<String>,
+    ) -> Result<PauseInfo, Error> {
+        Self::require_admin(&env, &admin)?;
+        if duration_secs == 0 {
+            return Err(Error::InvalidDeadline);
+        }
+        let until = env
+            .ledger()
+            .timestamp()
+            .checked_add(duration_secs)
+            .ok_or(Error::InvalidDeadline)?;
+        let info = pausable::pause(&env, &admin, until, reason.clone(), detail);
+        Paused {
+            admin,
+            until,
+            reason,
+        }
+        .publish(&env);
+        Ok(info)
+    }
+
+    /// Lift the pause, including a timed pause before it expires. A timed
+    /// pause that has already lapsed can also be cleared, so `get_pause_info`
+    /// returns `None` again.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the current admin
+    /// * `NotPaused` — no pause is recorded
+    pub fn unpause(env: Env, admin: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        if !pausable::unpause(&env) {
+            return Err(Error::NotPaused);
+        }
+        Unpaused { admin }.publish(&env);
+        Ok(())
+    }
+
+    /// Whether the contract is paused. A timed pause counts as lifted once
+    /// its expiry has passed.
+    pub fn is_paused(env: Env) -> bool {
+        pausable::is_paused(&env)
+    }
+
+    /// The current pause. `paused` is `false` once a timed pause has lapsed;
+    /// `None` when not paused or after `unpause`.
+    pub fn get_pause_info(env: Env) -> Option<PauseInfo> {
+        pausable::pause_info(&env)
+    }
+
+    /// The most recent pause, kept after `unpause`.
+    pub fn get_last_pause_info(env: Env) -> Option<PauseInfo> {
+        pausable::last_pause_info(&env)
+    }
+
+    /// Nominate `new_admin` as the next admin. The transfer completes only
+    /// when `new_admin` calls `accept_admin`; nominating again replaces the
+    /// pending address.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the current admin
+    pub fn transfer_admin(env: Env, admin: Address, new_admin: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &new_admin);
+
+        AdminTransferProposed {
+            admin,
+            pending_admin: new_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Accept a pending admin nomination, replacing the current admin.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `NoPendingAdmin` — no transfer is pending
+    /// * `Unauthorized` — `new_admin` is not the nominated address
+    pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), Error> {
+        new_admin.require_auth();
+        let previous_admin = Self::get_admin(env.clone())?;
+        let pending = Self::get_pending_admin(env.clone()).ok_or(Error::NoPendingAdmin)?;
+        if pending != new_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        let storage = env.storage().instance();
+        storage.set(&DataKey::Admin, &new_admin);
+        storage.remove(&DataKey::PendingAdmin);
+
+        AdminTransferred {
+            previous_admin,
+            new_admin,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Register an arbitrator allowed to vote on disputes, with
+    /// `DEFAULT_ARBITRATOR_WEIGHT`.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    /// * `AlreadyArbitrator` — `arbitrator` is already registered
+    pub fn add_arbitrator(env: Env, admin: Address, arbitrator: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let key = DataKey::Arbitrator(arbitrator.clone());
+        let storage = env.storage().persistent();
+        if storage.has(&key) {
+            return Err(Error::AlreadyArbitrator);
+        }
+        storage.set(&key, &DEFAULT_ARBITRATOR_WEIGHT);
+        storage.extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+
+        ArbitratorAdded { arbitrator }.publish(&env);
+        Ok(())
+    }
+
+    /// Set the voting weight of a registered arbitrator. Only affects votes
+    /// cast afterwards.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    /// * `InvalidWeight` — `weight == 0`
+    /// * `NotArbitrator` — `arbitrator` is not registered
+    pub fn set_arbitrator_weight(
+        env: Env,
+        admin: Address,
+        arbitrator: Address,
+        weight: u64,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        if weight == 0 {
+            return Err(Error::InvalidWeight);
+        }
+
+        let key = DataKey::Arbitrator(arbitrator.clone());
+        let storage = env.storage().persistent();
+        if !storage.has(&key) {
+            return Err(Error::NotArbitrator);
+        }
+        storage.set(&key, &weight);
+        storage.extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+
+        ArbitratorWeightSet { arbitrator, weight }.publish(&env);
+        Ok(())
+    }
+
+    /// Returns the voting weight of `arbitrator`, or `0` if not registered.
+    pub fn get_arbitrator_weight(env: Env, arbitrator: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Arbitrator(arbitrator))
+            .unwrap_or(0)
+    }
+
+    /// Set the rule used to resolve disputes whose sides hold equal weight.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    /// * `InvalidDeadline` — `ExtendDeadline(0)`, or an extension above
+    ///   `MAX_DEADLINE_EXTENSION_SECS`
+    pub fn set_tie_break_rule(env: Env, admin: Address, rule: TieBreakRule) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        if let TieBreakRule::ExtendDeadline(extension) = rule {
+            if extension == 0 || extension > MAX_DEADLINE_EXTENSION_SECS {
+                return Err(Error::InvalidDeadline);
+            }
+        }
+        env.storage().instance().set(&DataKey::TieBreak, &rule);
+        Ok(())
+    }
+
+    /// Set the minimum number of votes a dispute needs before it can resolve.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    /// * `InvalidQuorum` — `min_votes == 0`
+    pub fn set_min_votes_to_resolve(env: Env, admin: Address, min_votes: u64) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        if min_votes == 0 {
+            return Err(Error::InvalidQuorum);
+        }
+        env.storage().instance().set(&DataKey::MinVotes, &min_votes);
+        Ok(())
+    }
+
+    /// Returns the configured quorum (`DEFAULT_MIN_VOTES_TO_RESOLVE` if unset).
+    pub fn get_min_votes_to_resolve(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinVotes)
+            .unwrap_or(DEFAULT_MIN_VOTES_TO_RESOLVE)
+    }
+
+    /// Set the share of the stake charged when a disputer cancels. The fee
+    /// is sent to the treasury as a protocol fee, so a non-zero fee needs a
+    /// treasury to be configured first.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    /// * `InvalidFee` — `fee_bps > BPS_DENOMINATOR`
+    /// * `TreasuryNotSet` — non-zero `fee_bps` with no treasury configured
+    pub fn set_cancellation_fee_bps(env: Env, admin: Address, fee_bps: u32) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        if fee_bps > BPS_DENOMINATOR {
+            return Err(Error::InvalidFee);
+        }
+        if fee_bps > 0 && Self::get_treasury(env.clone()).is_none() {
+            return Err(Error::TreasuryNotSet);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::CancellationFeeBps, &fee_bps);
+        Ok(())
+    }
+
+    /// Returns the cancellation fee in basis points (`0` if unset).
+    pub fn get_cancellation_fee_bps(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::CancellationFeeBps)
+            .unwrap_or(0)
+    }
+
+    /// Set the share of the stake forfeited when a dispute resolves in favor
+    /// of the slasher; the rest is returned to the disputer. Applies to
+    /// resolutions from now on, including those of already-open disputes.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    /// * `InvalidFee` — `bps > BPS_DENOMINATOR`
+    pub fn set_forfeit_bps(env: Env, admin: Address, bps: u32) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        if bps > BPS_DENOMINATOR {
+            return Err(Error::InvalidFee);
+        }
+        env.storage().instance().set(&DataKey::ForfeitBps, &bps);
+        Ok(())
+    }
+
+    /// Returns the forfeit share in basis points (`DEFAULT_FORFEIT_BPS` if unset).
+    pub fn get_forfeit_bps(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ForfeitBps)
+            .unwrap_or(DEFAULT_FORFEIT_BPS)
+    }
+
+    /// Returns the configured tie-break rule (`FavorSlasher` if unset).
+    pub fn get_tie_break_rule(env: Env) -> TieBreakRule {
+        env.storage()
+            .instance()
+            .get(&DataKey::TieBreak)
+            .unwrap_or(TieBreakRule::FavorSlasher)
+    }
+
+    /// Deregister an arbitrator. Votes they already cast keep counting.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    /// * `NotArbitrator` — `arbitrator` is not registered
+    pub fn remove_arbitrator(env: Env, admin: Address, arbitrator: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let key = DataKey::Arbitrator(arbitrator.clone());
+        let storage = env.storage().persistent();
+        if !storage.has(&key) {
+            return Err(Error::NotArbitrator);
+        }
+        storage.remove(&key);
+
+        ArbitratorRemoved { arbitrator }.publish(&env);
+        Ok(())
+    }
+
+    /// Returns `true` if `arbitrator` is registered and may vote.
+    pub fn is_arbitrator(env: Env, arbitrator: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::Arbitrator(arbitrator))
+    }
+
+    /// Open a new dispute against a slash request.
+    ///
+    /// Equivalent to `create_dispute_with_reason` with
+    /// `DEFAULT_DISPUTE_REASON` and no details.
+    ///
+    /// # Errors
+    /// See `create_dispute_with_reason`.
+    pub fn create_dispute(
+        env: Env,
+        disputer: Address,
+        slash_request_id: u64,
+        stake: i128,
+        token: Address,
+        resolution_deadline: u64,
+    ) -> Result<u64, Error> {
+        let details = DisputeDetails {
+            reason: DEFAULT_DISPUTE_REASON,
+            details_hash: None,
+            details_uri: String::from_str(&env, ""),
+        };
+        Self::create_dispute_with_reason(
+            env,
+            disputer,
+            slash_request_id,
+            stake,
+            token,
+            resolution_deadline,
+            details,
+        )
+    }
+
+    /// Open a new dispute against a slash request, recording why.
+    ///
+    /// The disputer's `stake` is transferred from their account to the contract
+    /// and held until the dispute is resolved or expired. `details` is copied
+    /// onto the dispute record.
+    ///
+    /// # Errors
+    /// * `ContractPaused` — new activity is paused
+    /// * `InsufficientStake` — `stake` is below `get_min_stake(token)`
+    /// * `InvalidDeadline` — `resolution_deadline` is 0, below
+    ///   `get_min_voting_period`, above `get_max_resolution_window`, or
+    ///   overflows the ledger timestamp
+    /// * `InvalidDetails` — `details_hash` is given with an empty
+    ///   `details_uri`, or `details_uri` is longer than `MAX_EVIDENCE_URI_LEN`
+    /// * `TokenNotAllowed` — a stake token allowlist is in use and `token`
+    ///   is not on it
+    /// * `DuplicateDispute` — another dispute against `slash_request_id` is
+    ///   still in a voting round
+    pub fn create_dispute_with_reason(
+        env: Env,
+        disputer: Address,
+        slash_request_id: u64,
+        stake: i128,
+        token: Address,
+        resolution_deadline: u64,
+        details: DisputeDetails,
+    ) -> Result<u64, Error> {
+        disputer.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let DisputeDetails {
+            reason,
+            details_hash,
+            details_uri,
+        } = details;
+
+        if !Self::is_stake_token_allowed(env.clone(), token.clone()) {
+            return Err(Error::TokenNotAllowed);
+        }
+
+        if stake < Self::get_min_stake(env.clone(), token.clone()) {
+            return Err(Error::InsufficientStake);
+        }
+
+        if resolution_deadline == 0
+            || resolution_deadline < Self::get_min_voting_period(env.clone())
+            || resolution_deadline > Self::get_max_resolution_window(env.clone())
+        {
+            return Err(Error::InvalidDeadline);
+        }
+
+        if (details_hash.is_some() && details_uri.is_empty())
+            || details_uri.len() > MAX_EVIDENCE_URI_LEN
+        {
+            return Err(Error::InvalidDetails);
+        }
+
+        let slash_key = DataKey::SlashRequestIndex(slash_request_id);
+        if let Some(existing) = env.storage().persistent().get::<_, u64>(&slash_key) {
+            if Self::is_voting(&Self::load_dispute(&env, existing)?) {
+                return Err(Error::DuplicateDispute);
+            }
+        }
+
+        let current_time = env.ledger().timestamp();
+        let deadline = current_time
+            .checked_add(resolution_deadline)
+            .ok_or(Error::InvalidDeadline)?;
+
+        // Transfer stake into the contract — one storage-read-free cross-contract call.
+        let token_client = soroban_sdk::token::Client::new(&env, &token);
+        let contract_address = env.current_contract_address();
+        token_client.transfer_from(&contract_address, &disputer, &contract_address, &stake);
+
+        // Increment the global counter (instance storage — always loaded with the contract).
+        let counter: u64 = env
+            .storage()
+            .instance()
             .get(&DataKey::DisputeCounter)
             .unwrap_or(0);
-        let dispute_id = counter + 1;
+        let dispute_id = counter + 1;
+        env.storage()
+            .instance()
+            .set(&DataKey::DisputeCounter, &dispute_id);
+
+        // Write the dispute record to persistent storage with a fresh TTL.
+        let dispute = Dispute {
+            disputer: disputer.clone(),
+            slash_request_id,
+            stake,
+            token,
+            status: DisputeStatus::Open,
+            outcome: DisputeOutcome::None,
+            deadline,
+            votes_for_disputer: 0,
+            votes_for_slasher: 0,
+            count_for_disputer: 0,
+            count_for_slasher: 0,
+            created_at: current_time,
+            extended: false,
+            slasher: None,
+            voting_period: resolution_deadline,
+            resolved_at: 0,
+            appeal_stake: 0,
+            appealed: false,
+            snipe_extensions: 0,
+            panel_size: 0,
+            reveal_period: 0,
+            reveal_deadline: 0,
+            commit_count: 0,
+            stake_returned: 0,
+            fees_paid: 0,
+            reason: reason.clone(),
+            details_hash: details_hash.clone(),
+            details_uri: details_uri.clone(),
+        };
+        Self::save_dispute(&env, dispute_id, &dispute);
+
+        let storage = env.storage().persistent();
+        storage.set(&slash_key, &dispute_id);
+        storage.extend_ttl(&slash_key, BUMP_THRESHOLD, BUMP_TARGET);
+
+        Self::push_open(&env, dispute_id);
+
+        let disputer_key = DataKey::DisputerIndex(disputer.clone());
+        let mut ids: Vec<u64> = storage.get(&disputer_key).unwrap_or(vec![&env]);
+        ids.push_back(dispute_id);
+        storage.set(&disputer_key, &ids);
+        storage.extend_ttl(&disputer_key, BUMP_THRESHOLD, BUMP_TARGET);
+
+        DisputeCreated {
+            dispute_id,
+            disputer,
+            slash_request_id,
+            stake,
+            deadline,
+            reason,
+            details_hash,
+            details_uri,
+        }
+        .publish(&env);
+
+        Ok(dispute_id)
+    }
+
+    /// Retrieve a dispute record by ID.
+    ///
+    /// Panics with `"Dispute not found"` if the ID does not exist, preserving
+    /// the original public API contract expected by callers and tests. New
+    /// callers should prefer `find_dispute`.
+    pub fn get_dispute(env: &Env, dispute_id: u64) -> Dispute {
+        Self::load_dispute(env, dispute_id).expect("Dispute not found")
+    }
+
+    /// Retrieve a dispute record by ID without panicking.
+    ///
+    /// # Errors
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    pub fn find_dispute(env: Env, dispute_id: u64) -> Result<Dispute, Error> {
+        Self::load_dispute(&env, dispute_id)
+    }
+
+    /// Cast an arbitrator vote on an open dispute.
+    ///
+    /// The arbitrator's current weight is added to the chosen side and the
+    /// side's raw vote count is incremented. A vote close to the deadline may
+    /// extend it under the anti-sniping rule.
+    ///
+    /// # Errors
+    /// * `ContractPaused` — new activity is paused
+    /// * `Unauthorized` — `arbitrator` is not registered
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `DisputeNotOpen` — dispute is no longer accepting votes
+    /// * `DeadlineExpired` — voting period has closed
+    /// * `CommitRevealRequired` — the dispute uses `commit_vote`/`reveal_vote`
+    /// * `NotOnPanel` — the dispute has a panel and `arbitrator` is not on it
+    /// * `AlreadyVoted` — `arbitrator` has already voted in the current round
+    pub fn cast_vote(
+        env: Env,
+        arbitrator: Address,
+        dispute_id: u64,
+        favor_disputer: bool,
+    ) -> Result<(), Error> {
+        arbitrator.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let weight = Self::load_arbitrator(&env, &arbitrator).ok_or(Error::Unauthorized)?;
+
+        // Single persistent-storage read: load-or-error (replaces has() + get()).
+        let mut dispute = Self::load_dispute(&env, dispute_id)?;
+
+        if !Self::is_voting(&dispute) {
+            return Err(Error::DisputeNotOpen);
+        }
+
+        if dispute.reveal_period > 0 {
+            return Err(Error::CommitRevealRequired);
+        }
+
+        if env.ledger().timestamp() > dispute.deadline {
+            return Err(Error::DeadlineExpired);
+        }
+
+        Self::require_panelist(&env, dispute_id, &dispute, &arbitrator)?;
+
+        let vote_key = Self::vote_key(dispute_id, &dispute, arbitrator.clone());
+        let vote_storage = env.storage().persistent();
+
+        if vote_storage.has(&vote_key) {
+            return Err(Error::AlreadyVoted);
+        }
+
+        // Record the vote in persistent storage with a fresh TTL.
+        let record = VoteRecord {
+            favor_disputer,
+            weight,
+        };
+        vote_storage.set(&vote_key, &record);
+        vote_storage.extend_ttl(&vote_key, BUMP_THRESHOLD, BUMP_TARGET);
+
+        if favor_disputer {
+            dispute.votes_for_disputer = dispute.votes_for_disputer.saturating_add(weight);
+            dispute.count_for_disputer += 1;
+        } else {
+            dispute.votes_for_slasher = dispute.votes_for_slasher.saturating_add(weight);
+            dispute.count_for_slasher += 1;
+        }
+
+        Self::apply_anti_snipe(&env, dispute_id, &mut dispute);
+
+        // Persist updated vote tallies back to the dispute record.
+        Self::save_dispute(&env, dispute_id, &dispute);
+
+        VoteCast {
+            dispute_id,
+            arbitrator,
+            favor_disputer,
+            weight,
+            vote_count: dispute.count_for_disputer + dispute.count_for_slasher,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Flip an existing vote while the dispute is still accepting votes.
+    ///
+    /// The weight recorded with the original vote moves from the old side to
+    /// the new one, and one raw vote count moves with it. Like a fresh vote,
+    /// a change close to the deadline may extend it under the anti-sniping
+    /// rule.
+    ///
+    /// # Errors
+    /// * `ContractPaused` — new activity is paused
+    /// * `Unauthorized` — `arbitrator` is not registered
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `DisputeNotOpen` — dispute is no longer accepting votes
+    /// * `DeadlineExpired` — voting period has closed
+    /// * `VoteNotFound` — `arbitrator` has not voted in the current round
+    /// * `VoteUnchanged` — `favor_disputer` matches the existing vote
+    /// * `TallyOverflow` — the tallies are inconsistent with the vote
+    pub fn change_vote(
+        env: Env,
+        arbitrator: Address,
+        dispute_id: u64,
+        favor_disputer: bool,
+    ) -> Result<(), Error> {
+        arbitrator.require_auth();
+        Self::require_not_paused(&env)?;
+
+        Self::load_arbitrator(&env, &arbitrator).ok_or(Error::Unauthorized)?;
+
+        let mut dispute = Self::load_dispute(&env, dispute_id)?;
+
+        if !Self::is_voting(&dispute) {
+            return Err(Error::DisputeNotOpen);
+        }
+
+        if env.ledger().timestamp() > dispute.deadline {
+            return Err(Error::DeadlineExpired);
+        }
+
+        let vote_key = Self::vote_key(dispute_id, &dispute, arbitrator.clone());
+        let vote_storage = env.storage().persistent();
+        let mut record: VoteRecord = vote_storage.get(&vote_key).ok_or(Error::VoteNotFound)?;
+
+        if record.favor_disputer == favor_disputer {
+            return Err(Error::VoteUnchanged);
+        }
+
+        let weight = record.weight;
+        let (from_votes, from_count, to_votes, to_count) = if favor_disputer {
+            (
+                dispute.votes_for_slasher,
+                dispute.count_for_slasher,
+                dispute.votes_for_disputer,
+                dispute.count_for_disputer,
+            )
+        } else {
+            (
+                dispute.votes_for_disputer,
+                dispute.count_for_disputer,
+                dispute.votes_for_slasher,
+                dispute.count_for_slasher,
+            )
+        };
+        let from_votes = from_votes.checked_sub(weight).ok_or(Error::TallyOverflow)?;
+        let from_count = from_count.checked_sub(1).ok_or(Error::TallyOverflow)?;
+        let to_votes = to_votes.checked_add(weight).ok_or(Error::TallyOverflow)?;
+        let to_count = to_count.checked_add(1).ok_or(Error::TallyOverflow)?;
+
+        if favor_disputer {
+            dispute.votes_for_slasher = from_votes;
+            dispute.count_for_slasher = from_count;
+            dispute.votes_for_disputer = to_votes;
+            dispute.count_for_disputer = to_count;
+        } else {
+            dispute.votes_for_disputer = from_votes;
+            dispute.count_for_disputer = from_count;
+            dispute.votes_for_slasher = to_votes;
+            dispute.count_for_slasher = to_count;
+        }
+
+        record.favor_disputer = favor_disputer;
+        vote_storage.set(&vote_key, &record);
+        vote_storage.extend_ttl(&vote_key, BUMP_THRESHOLD, BUMP_TARGET);
+
+        Self::apply_anti_snipe(&env, dispute_id, &mut dispute);
+
+        Self::save_dispute(&env, dispute_id, &dispute);
+
+        VoteChanged {
+            dispute_id,
+            arbitrator,
+            old_favor_disputer: !favor_disputer,
+            new_favor_disputer: favor_disputer,
+            weight,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Switch an open dispute to commit-reveal voting.
+    ///
+    /// Until `deadline` arbitrators submit sealed votes with `commit_vote`;
+    /// for `reveal_period` seconds afterwards they open them with
+    /// `reveal_vote`. Only revealed votes are tallied; unrevealed commitments
+    /// count as abstentions. Appeal rounds reuse the same reveal period.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `DisputeNotOpen` — dispute is no longer open
+    /// * `DisputeHasVotes` — at least one arbitrator has voted
+    /// * `InvalidDeadline` — `reveal_period` is 0 or overflows the deadline
+    pub fn enable_commit_reveal(
+        env: Env,
+        admin: Address,
+        dispute_id: u64,
+        reveal_period: u64,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let mut dispute = Self::load_dispute(&env, dispute_id)?;
+        if dispute.status != DisputeStatus::Open {
+            return Err(Error::DisputeNotOpen);
+        }
+        if dispute.count_for_disputer + dispute.count_for_slasher > 0 {
+            return Err(Error::DisputeHasVotes);
+        }
+        if reveal_period == 0 {
+            return Err(Error::InvalidDeadline);
+        }
+
+        dispute.reveal_period = reveal_period;
+        dispute.reveal_deadline = dispute
+            .deadline
+            .checked_add(reveal_period)
+            .ok_or(Error::InvalidDeadline)?;
+        Self::save_dispute(&env, dispute_id, &dispute);
+
+        CommitRevealEnabled {
+            dispute_id,
+            reveal_period,
+            reveal_deadline: dispute.reveal_deadline,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Submit a sealed vote on a commit-reveal dispute.
+    ///
+    /// `commitment` is `sha256(favor_disputer as u8 || salt)` with a 32-byte
+    /// secret `salt`. The arbitrator's current weight is locked in with the
+    /// commitment.
+    ///
+    /// # Errors
+    /// * `ContractPaused` — new activity is paused
+    /// * `Unauthorized` — `arbitrator` is not registered
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `DisputeNotOpen` — dispute is no longer accepting votes
+    /// * `NotCommitReveal` — the dispute uses open voting
+    /// * `DeadlineExpired` — the commit phase has closed
+    /// * `NotOnPanel` — the dispute has a panel and `arbitrator` is not on it
+    /// * `AlreadyVoted` — `arbitrator` already committed in the current round
+    pub fn commit_vote(
+        env: Env,
+        arbitrator: Address,
+        dispute_id: u64,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        arbitrator.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let weight = Self::load_arbitrator(&env, &arbitrator).ok_or(Error::Unauthorized)?;
+
+        let mut dispute = Self::load_dispute(&env, dispute_id)?;
+
+        if !Self::is_voting(&dispute) {
+            return Err(Error::DisputeNotOpen);
+        }
+
+        if dispute.reveal_period == 0 {
+            return Err(Error::NotCommitReveal);
+        }
+
+        if env.ledger().timestamp() > dispute.deadline {
+            return Err(Error::DeadlineExpired);
+        }
+
+        Self::require_panelist(&env, dispute_id, &dispute, &arbitrator)?;
+
+        let commit_key = Self::commit_key(dispute_id, &dispute, arbitrator.clone());
+        let storage = env.storage().persistent();
+        if storage.has(&commit_key) {
+            return Err(Error::AlreadyVoted);
+        }
+
+        storage.set(&commit_key, &VoteCommit { commitment, weight });
+        storage.extend_ttl(&commit_key, BUMP_THRESHOLD, BUMP_TARGET);
+
+        dispute.commit_count += 1;
+        Self::save_dispute(&env, dispute_id, &dispute);
+
+        VoteCommitted {
+            dispute_id,
+            arbitrator,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Open a sealed vote during the reveal window and add it to the tally.
+    ///
+    /// Reveals are accepted while the contract is paused so that in-flight
+    /// disputes can conclude.
+    ///
+    /// # Errors
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `DisputeNotOpen` — dispute is no longer accepting votes
+    /// * `NotCommitReveal` — the dispute uses open voting
+    /// * `DeadlineNotReached` — the commit phase is still running
+    /// * `DeadlineExpired` — the reveal window has closed
+    /// * `VoteNotFound` — `arbitrator` made no commitment this round
+    /// * `AlreadyVoted` — the commitment was already revealed
+    /// * `InvalidReveal` — `favor_disputer` and `salt` do not match the commitment
+    pub fn reveal_vote(
+        env: Env,
+        arbitrator: Address,
+        dispute_id: u64,
+        favor_disputer: bool,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        arbitrator.require_auth();
+
+        let mut dispute = Self::load_dispute(&env, dispute_id)?;
+
+        if !Self::is_voting(&dispute) {
+            return Err(Error::DisputeNotOpen);
+        }
+
+        if dispute.reveal_period == 0 {
+            return Err(Error::NotCommitReveal);
+        }
+
+        let now = env.ledger().timestamp();
+        if now <= dispute.deadline {
+            return Err(Error::DeadlineNotReached);
+        }
+        if now > dispute.reveal_deadline {
+            return Err(Error::DeadlineExpired);
+        }
+
+        let commit_key = Self::commit_key(dispute_id, &dispute, arbitrator.clone());
+        let vote_key = Self::vote_key(dispute_id, &dispute, arbitrator.clone());
+        let storage = env.storage().persistent();
+        let commit: VoteCommit = storage.get(&commit_key).ok_or(Error::VoteNotFound)?;
+
+        if storage.has(&vote_key) {
+            return Err(Error::AlreadyVoted);
+        }
+
+        if Self::vote_commitment(&env, favor_disputer, &salt) != commit.commitment {
+            return Err(Error::InvalidReveal);
+        }
+
+        let weight = commit.weight;
+        storage.set(
+            &vote_key,
+            &VoteRecord {
+                favor_disputer,
+                weight,
+            },
+        );
+        storage.extend_ttl(&vote_key, BUMP_THRESHOLD, BUMP_TARGET);
+
+        if favor_disputer {
+            dispute.votes_for_disputer = dispute.votes_for_disputer.saturating_add(weight);
+            dispute.count_for_disputer += 1;
+        } else {
+            dispute.votes_for_slasher = dispute.votes_for_slasher.saturating_add(weight);
+            dispute.count_for_slasher += 1;
+        }
+
+        Self::save_dispute(&env, dispute_id, &dispute);
+
+        VoteRevealed {
+            dispute_id,
+            arbitrator,
+            favor_disputer,
+            weight,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Returns the phase of a dispute's current voting round.
+    ///
+    /// # Errors
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    pub fn get_voting_phase(env: Env, dispute_id: u64) -> Result<VotingPhase, Error> {
+        let dispute = Self::load_dispute(&env, dispute_id)?;
+        let now = env.ledger().timestamp();
+        let phase = if !Self::is_voting(&dispute) {
+            VotingPhase::Closed
+        } else if now <= dispute.deadline {
+            if dispute.reveal_period > 0 {
+                VotingPhase::Commit
+            } else {
+                VotingPhase::Voting
+            }
+        } else if dispute.reveal_period > 0 && now <= dispute.reveal_deadline {
+            VotingPhase::Reveal
+        } else {
+            VotingPhase::Closed
+        };
+        Ok(phase)
+    }
+
+    /// Returns `true` if `arbitrator` committed a sealed vote in the
+    /// dispute's current round.
+    pub fn has_committed(env: Env, dispute_id: u64, arbitrator: Address) -> bool {
+        match Self::load_dispute(&env, dispute_id) {
+            Ok(dispute) => env
+                .storage()
+                .persistent()
+                .has(&Self::commit_key(dispute_id, &dispute, arbitrator)),
+            Err(_) => false,
+        }
+    }
+
+    /// Resolve a dispute after its deadline has passed.
+    ///
+    /// Whichever side holds more vote weight wins; equal weights are settled
+    /// by the configured `TieBreakRule`. Under `ExtendDeadline` the first tie
+    /// extends the deadline and leaves the dispute open. On a `FavorDisputer`
+    /// outcome the staked tokens (including any appeal stake) are returned to
+    /// the disputer; otherwise `get_forfeit_bps` of them remain in the
+    /// contract (forfeited to the slasher side) and the rest is returned.
+    /// Stake returned after a lost first round counts towards the refund of
+    /// the appeal round. Resolving an appeal round is final.
+    ///
+    /// `resolver` receives the configured `ResolverFee` out of the stake; the
+    /// refund or forfeited amount is reduced accordingly. Fees paid in the
+    /// first round have already left the contract and are deducted too.
+    ///
+    /// # Errors
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `DisputeNotOpen` — dispute is already resolved/expired
+    /// * `DeadlineNotReached` — voting period is still active, or the dispute
+    ///   is younger than `get_min_voting_period`
+    /// * `QuorumNotReached` — fewer votes than `min_votes_to_resolve`; use
+    ///   `expire_dispute` instead
+    pub fn resolve_dispute(env: Env, resolver: Address, dispute_id: u64) -> Result<(), Error> {
+        resolver.require_auth();
+
+        let mut dispute = Self::load_dispute(&env, dispute_id)?;
+
+        if !Self::is_voting(&dispute) {
+            return Err(Error::DisputeNotOpen);
+        }
+
+        if env.ledger().timestamp() <= Self::voting_closes(&dispute) {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        // Guards disputes created before the minimum was raised.
+        let earliest = dispute
+            .created_at
+            .saturating_add(Self::get_min_voting_period(env.clone()));
+        if env.ledger().timestamp() < earliest {
+            return Err(Error::DeadlineNotReached);
+        }
+
+        if !Self::has_quorum(&env, &dispute) {
+            return Err(Error::QuorumNotReached);
+        }
+
+        let rule = Self::get_tie_break_rule(env.clone());
+        if let TieBreakRule::ExtendDeadline(extension) = rule {
+            if dispute.votes_for_disputer == dispute.votes_for_slasher && !dispute.extended {
+                Self::set_round_deadline(
+                    &mut dispute,
+                    env.ledger().timestamp().saturating_add(extension),
+                );
+                dispute.extended = true;
+                Self::save_dispute(&env, dispute_id, &dispute);
+
+                DisputeExtended {
+                    dispute_id,
+                    new_deadline: dispute.deadline,
+                }
+                .publish(&env);
+
+                return Ok(());
+            }
+        }
+
+        let fee = Self::resolver_fee_for(&env, dispute.stake);
+        let outcome = Self::decide_outcome(&dispute, &rule);
+        let favor_disputer = outcome == DisputeOutcome::FavorDisputer;
+
+        let settled = dispute.stake + dispute.appeal_stake - dispute.fees_paid - fee;
+        let refund_total = if favor_disputer {
+            settled
+        } else {
+            Self::split_forfeit(&env, settled).0
+        };
+        let already_returned = dispute.stake_returned;
+        let refunded = (refund_total - already_returned).max(0);
+        let forfeited = if favor_disputer {
+            0
+        } else {
+            settled - already_returned - refunded
+        };
+
+        dispute.status = DisputeStatus::Resolved;
+        Self::remove_open(&env, dispute_id);
+        dispute.outcome = outcome.clone();
+        dispute.resolved_at = env.ledger().timestamp();
+        dispute.stake_returned = already_returned + refunded;
+        dispute.fees_paid += fee;
+
+        Self::save_dispute(&env, dispute_id, &dispute);
+
+        Self::update_stats(&env, |mut stats| {
+            stats.total_resolved = stats.total_resolved.checked_add(1)?;
+            if favor_disputer {
+                stats.total_favor_disputer = stats.total_favor_disputer.checked_add(1)?;
+            } else {
+                stats.total_favor_slasher = stats.total_favor_slasher.checked_add(1)?;
+            }
+            stats.total_refunded = stats
+                .total_refunded
+                .checked_add(already_returned + refunded)?;
+            stats.total_forfeited = stats.total_forfeited.checked_add(forfeited)?;
+            Some(stats)
+        })?;
+
+        Self::refund_disputer(&env, &dispute, refunded);
+        if !favor_disputer {
+            Self::open_reward_pool(
+                &env,
+                dispute_id,
+                forfeited,
+                dispute.votes_for_slasher,
+                dispute.appealed,
+                if dispute.appealed {
+                    dispute.resolved_at
+                } else {
+                    Self::appeal_closes(&env, &dispute).saturating_add(1)
+                },
+            );
+        }
+
+        DisputeResolved {
+            dispute_id,
+            outcome,
+            votes_for_disputer: dispute.votes_for_disputer,
+            votes_for_slasher: dispute.votes_for_slasher,
+            count_for_disputer: dispute.count_for_disputer,
+            count_for_slasher: dispute.count_for_slasher,
+            appealed: dispute.appealed,
+            refunded,
+            forfeited,
+        }
+        .publish(&env);
+
+        Self::pay_resolver(&env, &dispute.token, dispute_id, resolver, fee);
+        Self::notify_resolution(&env, dispute_id, &dispute.outcome);
+
+        Ok(())
+    }
+
+    /// Appeal a dispute the disputer lost, reopening voting for one more round.
+    ///
+    /// `additional_stake` (at least `APPEAL_STAKE_MULTIPLIER` × the original
+    /// stake) is pulled from the appellant, the first-round result is kept in
+    /// an `AppealRecord`, tallies are cleared and a fresh deadline of the
+    /// original voting period is set. A dispute can be appealed only once.
+    ///
+    /// # Errors
+    /// * `ContractPaused` — new activity is paused
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `Unauthorized` — `appellant` is not the disputer
+    /// * `AlreadyAppealed` — the dispute has already been appealed
+    /// * `AppealNotAllowed` — dispute is not resolved in favor of the slasher
+    /// * `AppealWindowClosed` — the appeal window has elapsed
+    /// * `InsufficientStake` — `additional_stake` is below the required multiple
+    pub fn appeal_dispute(
+        env: Env,
+        appellant: Address,
+        dispute_id: u64,
+        additional_stake: i128,
+    ) -> Result<(), Error> {
+        appellant.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let mut dispute = Self::load_dispute(&env, dispute_id)?;
+
+        if dispute.disputer != appellant {
+            return Err(Error::Unauthorized);
+        }
+
+        if dispute.appealed {
+            return Err(Error::AlreadyAppealed);
+        }
+
+        if dispute.status != DisputeStatus::Resolved
+            || dispute.outcome != DisputeOutcome::FavorSlasher
+        {
+            return Err(Error::AppealNotAllowed);
+        }
+
+        let now = env.ledger().timestamp();
+        if now > Self::appeal_closes(&env, &dispute) {
+            return Err(Error::AppealWindowClosed);
+        }
+
+        if additional_stake < dispute.stake * APPEAL_STAKE_MULTIPLIER {
+            return Err(Error::InsufficientStake);
+        }
+
+        let token_client = soroban_sdk::token::Client::new(&env, &dispute.token);
+        let contract_address = env.current_contract_address();
+        token_client.transfer_from(
+            &contract_address,
+            &appellant,
+            &contract_address,
+            &additional_stake,
+        );
+
+        let record = AppealRecord {
+            appellant: appellant.clone(),
+            outcome: dispute.outcome.clone(),
+            votes_for_disputer: dispute.votes_for_disputer,
+            votes_for_slasher: dispute.votes_for_slasher,
+            count_for_disputer: dispute.count_for_disputer,
+            count_for_slasher: dispute.count_for_slasher,
+            resolved_at: dispute.resolved_at,
+            appealed_at: now,
+        };
+        // The first-round verdict is no longer final; take it out of the totals.
+        let forfeited = Self::get_reward_pool(env.clone(), dispute_id)
+            .map(|pool| pool.amount)
+            .unwrap_or(0);
+        let returned = dispute.stake_returned;
+        Self::update_stats(&env, |mut stats| {
+            stats.total_resolved = stats.total_resolved.checked_sub(1)?;
+            stats.total_favor_slasher = stats.total_favor_slasher.checked_sub(1)?;
+            stats.total_refunded = stats.total_refunded.checked_sub(returned)?;
+            stats.total_forfeited = stats.total_forfeited.checked_sub(forfeited)?;
+            Some(stats)
+        })?;
+
+        let record_key = DataKey::Appeal(dispute_id);
+        env.storage().persistent().set(&record_key, &record);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::RewardPool(dispute_id));
+        env.storage()
+            .persistent()
+            .extend_ttl(&record_key, BUMP_THRESHOLD, BUMP_TARGET);
+
+        dispute.status = DisputeStatus::UnderAppeal;
+        Self::push_open(&env, dispute_id);
+        dispute.outcome = DisputeOutcome::None;
+        let deadline = now.saturating_add(dispute.voting_period);
+        Self::set_round_deadline(&mut dispute, deadline);
+        dispute.commit_count = 0;
+        dispute.votes_for_disputer = 0;
+        dispute.votes_for_slasher = 0;
+        dispute.count_for_disputer = 0;
+        dispute.count_for_slasher = 0;
+        dispute.extended = false;
+        dispute.snipe_extensions = 0;
+        dispute.appeal_stake = additional_stake;
+        dispute.appealed = true;
+
+        Self::save_dispute(&env, dispute_id, &dispute);
+
+        DisputeAppealed {
+            dispute_id,
+            appellant,
+            additional_stake,
+            new_deadline: dispute.deadline,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Claim a share of the forfeited stake of a dispute resolved in favor of
+    /// the slasher.
+    ///
+    /// Arbitrators who voted for the slasher in the deciding round receive
+    /// `amount * weight / total_weight`. Claims open once the dispute can no
+    /// longer be appealed and close when the pool is swept.
+    ///
+    /// # Errors
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `NoReward` — no forfeited stake, or `claimant` has no share in it
+    /// * `RewardLocked` — the appeal window is still open
+    /// * `AlreadySwept` — the unclaimed remainder went to the treasury
+    /// * `AlreadyClaimed` — `claimant` already claimed from this dispute
+    pub fn claim_arbitrator_reward(
+        env: Env,
+        claimant: Address,
+        dispute_id: u64,
+    ) -> Result<i128, Error> {
+        claimant.require_auth();
+
+        let dispute = Self::load_dispute(&env, dispute_id)?;
+        let pool_key = DataKey::RewardPool(dispute_id);
+        let storage = env.storage().persistent();
+        let mut pool: RewardPool = storage.get(&pool_key).ok_or(Error::NoReward)?;
+
+        if env.ledger().timestamp() < pool.claimable_at {
+            return Err(Error::RewardLocked);
+        }
+
+        if pool.swept {
+            return Err(Error::AlreadySwept);
+        }
+
+        let claimed_key = DataKey::RewardClaimed(dispute_id, claimant.clone());
+        if storage.has(&claimed_key) {
+            return Err(Error::AlreadyClaimed);
+        }
+
+        let vote_key = if pool.appeal_round {
+            DataKey::AppealVote(dispute_id, claimant.clone())
+        } else {
+            DataKey::Vote(dispute_id, claimant.clone())
+        };
+        let vote: VoteRecord = storage.get(&vote_key).ok_or(Error::NoReward)?;
+        if vote.favor_disputer || pool.total_weight == 0 {
+            return Err(Error::NoReward);
+        }
+        let amount = pool.amount * vote.weight as i128 / pool.total_weight as i128;
+
+        pool.claimed += amount;
+        storage.set(&pool_key, &pool);
+        storage.extend_ttl(&pool_key, BUMP_THRESHOLD, BUMP_TARGET);
+        storage.set(&claimed_key, &true);
+        storage.extend_ttl(&claimed_key, BUMP_THRESHOLD, BUMP_TARGET);
+
+        if amount > 0 {
+            let token_client = soroban_sdk::token::Client::new(&env, &dispute.token);
+            token_client.transfer(&env.current_contract_address(), &claimant, &amount);
+        }
+
+        RewardClaimed {
+            dispute_id,
+            claimant,
+            amount,
+        }
+        .publish(&env);
+
+        Ok(amount)
+    }
+
+    /// Count `arbitrator`'s vote on a settled dispute towards their accuracy
+    /// record. Anyone may call this, once per (arbitrator, dispute).
+    ///
+    /// The vote is compared against the final outcome: the deciding round of
+    /// a resolved dispute once it can no longer be appealed, or the
+    /// first-round verdict when an appeal expired without quorum. Returns
+    /// whether the vote sided with the outcome.
+    ///
+    /// # Errors
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `OutcomeNotFinal` — the dispute is undecided or still appealable
+    /// * `VoteNotFound` — `arbitrator` did not vote in the deciding round
+    /// * `AlreadyRecorded` — this vote was already counted
+    /// * `StatsOverflow` — a counter would overflow
+    pub fn record_accuracy(env: Env, arbitrator: Address, dispute_id: u64) -> Result<bool, Error> {
+        let dispute = Self::load_dispute(&env, dispute_id)?;
+
+        let vote_key = match dispute.status {
+            DisputeStatus::Resolved
+                if dispute.appealed
+                    || env.ledger().timestamp() > Self::appeal_closes(&env, &dispute) =>
+            {
+                Self::vote_key(dispute_id, &dispute, arbitrator.clone())
+            }
+            DisputeStatus::Expired if dispute.appealed => {
+                DataKey::Vote(dispute_id, arbitrator.clone())
+            }
+            _ => return Err(Error::OutcomeNotFinal),
+        };
+
+        let storage = env.storage().persistent();
+        let vote: VoteRecord = storage.get(&vote_key).ok_or(Error::VoteNotFound)?;
+
+        let recorded_key = DataKey::AccuracyRecorded(dispute_id, arbitrator.clone());
+        if storage.has(&recorded_key) {
+            return Err(Error::AlreadyRecorded);
+        }
+
+        let with_majority =
+            vote.favor_disputer == (dispute.outcome == DisputeOutcome::FavorDisputer);
+        let mut stats = Self::load_arbitrator_stats(&env, arbitrator.clone());
+        stats.votes_total = stats
+            .votes_total
+            .checked_add(1)
+            .ok_or(Error::StatsOverflow)?;
+        if with_majority {
+            stats.votes_with_majority = stats
+                .votes_with_majority
+                .checked_add(1)
+                .ok_or(Error::StatsOverflow)?;
+        }
+
+        let stats_key = DataKey::ArbitratorStats(arbitrator.clone());
+        storage.set(&stats_key, &stats);
+        storage.extend_ttl(&stats_key, BUMP_THRESHOLD, BUMP_TARGET);
+        storage.set(&recorded_key, &true);
+        storage.extend_ttl(&recorded_key, BUMP_THRESHOLD, BUMP_TARGET);
+
+        AccuracyRecorded {
+            dispute_id,
+            arbitrator,
+            with_majority,
+        }
+        .publish(&env);
+
+        Ok(with_majority)
+    }
+
+    /// Returns `(votes_total, votes_with_majority)` recorded for `arbitrator`.
+    pub fn get_arbitrator_stats(env: Env, arbitrator: Address) -> (u64, u64) {
+        let stats = Self::load_arbitrator_stats(&env, arbitrator);
+        (stats.votes_total, stats.votes_with_majority)
+    }
+
+    fn load_arbitrator_stats(env: &Env, arbitrator: Address) -> ArbitratorStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ArbitratorStats(arbitrator))
+            .unwrap_or_default()
+    }
+
+    /// Returns the reward pool of a forfeited dispute, if any.
+    pub fn get_reward_pool(env: Env, dispute_id: u64) -> Option<RewardPool> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RewardPool(dispute_id))
+    }
+
+    /// Send the unclaimed part of a dispute's forfeited stake to the treasury
+    /// and credit it through the treasury's `receive_fee` as slashed funds.
+    ///
+    /// Allowed once the claim window has elapsed, or as soon as claims open
+    /// when no arbitrator is entitled to a share. A pool can be swept once;
+    /// later claims are rejected. The dispute contract must be registered as
+    /// a depositor on the treasury. Returns the amount swept.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    /// * `TreasuryNotSet` — no treasury configured
+    /// * `NoReward` — the dispute has no forfeited stake
+    /// * `AlreadySwept` — the pool was already swept
+    /// * `RewardLocked` — arbitrators can still claim
+    pub fn sweep_forfeited(env: Env, admin: Address, dispute_id: u64) -> Result<i128, Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let treasury = Self::get_treasury(env.clone()).ok_or(Error::TreasuryNotSet)?;
+        let dispute = Self::load_dispute(&env, dispute_id)?;
+
+        let pool_key = DataKey::RewardPool(dispute_id);
+        let storage = env.storage().persistent();
+        let mut pool: RewardPool = storage.get(&pool_key).ok_or(Error::NoReward)?;
+
+        if pool.swept {
+            return Err(Error::AlreadySwept);
+        }
+
+        let sweepable_at = if pool.total_weight == 0 {
+            pool.claimable_at
+        } else {
+            pool.claimable_at
+                .saturating_add(Self::get_claim_window(env.clone()))
+        };
+        if env.ledger().timestamp() < sweepable_at {
+            return Err(Error::RewardLocked);
+        }
+
+        let amount = pool.amount - pool.claimed;
+
+        // State update before the external calls.
+        pool.swept = true;
+        storage.set(&pool_key, &pool);
+        storage.extend_ttl(&pool_key, BUMP_THRESHOLD, BUMP_TARGET);
+
+        if amount > 0 {
+            Self::send_to_treasury(&env, &treasury, &dispute.token, amount, FUND_SOURCE_SLASHED);
+        }
+
+        ForfeitSwept {
+            dispute_id,
+            treasury,
+            amount,
+        }
+        .publish(&env);
+
+        Ok(amount)
+    }
+
+    /// Set the incentive paid to callers of `resolve_dispute`.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    /// * `InvalidAmount` — negative `Flat` amount
+    /// * `InvalidFee` — `Bps` above `BPS_DENOMINATOR`
+    pub fn set_resolver_fee(env: Env, admin: Address, fee: ResolverFee) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        match fee {
+            ResolverFee::Flat(amount) if amount < 0 => return Err(Error::InvalidAmount),
+            ResolverFee::Bps(bps) if bps > BPS_DENOMINATOR => return Err(Error::InvalidFee),
+            _ => {}
+        }
+        env.storage().instance().set(&DataKey::ResolverFee, &fee);
+        Ok(())
+    }
+
+    /// Returns the resolver incentive (`ResolverFee::None` if unset).
+    pub fn get_resolver_fee(env: Env) -> ResolverFee {
+        env.storage()
+            .instance()
+            .get(&DataKey::ResolverFee)
+            .unwrap_or(ResolverFee::None)
+    }
+
+    /// Configure the anti-sniping rule. `max_extensions == 0` disables it.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    /// * `InvalidDeadline` — extensions enabled with `extension_secs == 0`, or
+    ///   `extension_secs` above `MAX_DEADLINE_EXTENSION_SECS`
+    pub fn set_anti_snipe_config(
+        env: Env,
+        admin: Address,
+        window_secs: u64,
+        extension_secs: u64,
+        max_extensions: u32,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        if (max_extensions > 0 && extension_secs == 0)
+            || extension_secs > MAX_DEADLINE_EXTENSION_SECS
+        {
+            return Err(Error::InvalidDeadline);
+        }
+        let config = AntiSnipeConfig {
+            window_secs,
+            extension_secs,
+            max_extensions,
+        };
+        env.storage().instance().set(&DataKey::AntiSnipe, &config);
+        Ok(())
+    }
+
+    /// Returns the anti-sniping rule (disabled if unset).
+    pub fn get_anti_snipe_config(env: Env) -> AntiSnipeConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::AntiSnipe)
+            .unwrap_or_default()
+    }
+
+    /// Set the longest `resolution_deadline` accepted by `create_dispute`.
+    /// Existing disputes keep their deadlines.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    /// * `InvalidDeadline` — `window_secs` is 0 or below `get_min_voting_period`
+    pub fn set_max_resolution_window(
+        env: Env,
+        admin: Address,
+        window_secs: u64,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        if window_secs == 0 || window_secs < Self::get_min_voting_period(env.clone()) {
+            return Err(Error::InvalidDeadline);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxResolutionWindow, &window_secs);
+        Ok(())
+    }
+
+    /// Set the shortest `resolution_deadline` accepted by `create_dispute`.
+    /// `resolve_dispute` also waits this long after creation, which covers
+    /// disputes opened before the minimum was raised. `0` disables the bound.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    /// * `InvalidDeadline` — `period_secs` exceeds `get_max_resolution_window`
+    pub fn set_min_voting_period(env: Env, admin: Address, period_secs: u64) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        if period_secs > Self::get_max_resolution_window(env.clone()) {
+            return Err(Error::InvalidDeadline);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::MinVotingPeriod, &period_secs);
+        Ok(())
+    }
+
+    /// Returns the minimum voting period in seconds
+    /// (`DEFAULT_MIN_VOTING_PERIOD_SECS` if unset).
+    pub fn get_min_voting_period(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinVotingPeriod)
+            .unwrap_or(DEFAULT_MIN_VOTING_PERIOD_SECS)
+    }
+
+    /// Returns the maximum voting period in seconds
+    /// (`DEFAULT_MAX_RESOLUTION_WINDOW_SECS` if unset).
+    pub fn get_max_resolution_window(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxResolutionWindow)
+            .unwrap_or(DEFAULT_MAX_RESOLUTION_WINDOW_SECS)
+    }
+
+    /// Set how long arbitrators have to claim before a pool can be swept.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    /// * `InvalidDeadline` — `window_secs == 0` or above `MAX_CLAIM_WINDOW_SECS`
+    pub fn set_claim_window(env: Env, admin: Address, window_secs: u64) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        if window_secs == 0 || window_secs > MAX_CLAIM_WINDOW_SECS {
+            return Err(Error::InvalidDeadline);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ClaimWindow, &window_secs);
+        Ok(())
+    }
+
+    /// Returns the claim window in seconds (`DEFAULT_CLAIM_WINDOW_SECS` if unset).
+    pub fn get_claim_window(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ClaimWindow)
+            .unwrap_or(DEFAULT_CLAIM_WINDOW_SECS)
+    }
+
+    /// Set the `credence_treasury` contract that receives swept stakes and
+    /// cancellation fees.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    pub fn set_treasury(env: Env, admin: Address, treasury: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().set(&DataKey::Treasury, &treasury);
+        Ok(())
+    }
+
+    /// Returns the configured treasury, if any.
+    pub fn get_treasury(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Treasury)
+    }
+
+    /// Have `contract.fn_name(dispute_id: u64, outcome: DisputeOutcome)`
+    /// called whenever a dispute is resolved or expires. Replaces any
+    /// previous hook.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    pub fn set_resolution_hook(
+        env: Env,
+        admin: Address,
+        contract: Address,
+        fn_name: Symbol,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().set(
+            &DataKey::ResolutionHook,
+            &ResolutionHook { contract, fn_name },
+        );
+        Ok(())
+    }
+
+    /// Stop notifying the resolution hook.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    pub fn clear_resolution_hook(env: Env, admin: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        env.storage().instance().remove(&DataKey::ResolutionHook);
+        Ok(())
+    }
+
+    /// Returns the configured resolution hook, if any.
+    pub fn get_resolution_hook(env: Env) -> Option<ResolutionHook> {
+        env.storage().instance().get(&DataKey::ResolutionHook)
+    }
+
+    /// Returns `true` if the hook call for `dispute_id` failed and has not
+    /// been delivered since.
+    pub fn is_hook_pending(env: Env, dispute_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::HookPending(dispute_id))
+    }
+
+    /// Deliver a failed resolution notification again, to the hook that is
+    /// configured now. Anyone may call this.
+    ///
+    /// # Errors
+    /// * `HookNotPending` — no failed notification for `dispute_id`, or no
+    ///   hook is configured any more
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `HookFailed` — the hook failed again; it stays pending
+    pub fn retry_resolution_hook(env: Env, dispute_id: u64) -> Result<(), Error> {
+        let key = DataKey::HookPending(dispute_id);
+        if !env.storage().persistent().has(&key) {
+            return Err(Error::HookNotPending);
+        }
+        let hook = Self::get_resolution_hook(env.clone()).ok_or(Error::HookNotPending)?;
+        let dispute = Self::load_dispute(&env, dispute_id)?;
+
+        // Returning an error below rolls this back.
+        env.storage().persistent().remove(&key);
+        if !Self::invoke_hook(&env, &hook, dispute_id, &dispute.outcome) {
+            return Err(Error::HookFailed);
+        }
+        Ok(())
+    }
+
+    /// Retrieve the first-round result of an appealed dispute.
+    ///
+    /// # Errors
+    /// * `AppealNotAllowed` — the dispute has not been appealed
+    pub fn get_appeal_record(env: Env, dispute_id: u64) -> Result<AppealRecord, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Appeal(dispute_id))
+            .ok_or(Error::AppealNotAllowed)
+    }
+
+    /// Set the minimum stake for disputes staked in `token`. Disputes that are
+    /// already open keep the stake they were created with.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    /// * `InvalidAmount` — `amount <= 0`
+    pub fn set_min_stake(
+        env: Env,
+        admin: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let key = DataKey::MinStake(token);
+        env.storage().persistent().set(&key, &amount);
         env.storage()
-            .instance()
-            .set(&DataKey::DisputeCounter, &dispute_id);
+            .persistent()
+            .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+        Ok(())
+    }
 
-        // Write the dispute record to persistent storage with a fresh TTL.
-        let dispute = Dispute {
-            disputer: disputer.clone(),
-            slash_request_id,
-            stake,
-            token,
-            status: DisputeStatus::Open,
-            outcome: DisputeOutcome::None,
-            deadline,
-            votes_for_disputer: 0,
-            votes_for_slasher: 0,
-            created_at: current_time,
-        };
-        Self::save_dispute(&env, dispute_id, &dispute);
+    /// Set the minimum stake for tokens without their own minimum.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    /// * `InvalidAmount` — `amount <= 0`
+    pub fn set_default_min_stake(env: Env, admin: Address, amount: i128) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::DefaultMinStake, &amount);
+        Ok(())
+    }
 
-        DisputeCreated {
-            dispute_id,
-            disputer,
-            slash_request_id,
-            stake,
-            deadline,
+    /// Returns the minimum stake for `token`: its own minimum if set, else the
+    /// default minimum, else `MIN_STAKE`.
+    pub fn get_min_stake(env: Env, token: Address) -> i128 {
+        if let Some(amount) = env.storage().persistent().get(&DataKey::MinStake(token)) {
+            return amount;
         }
-        .publish(&env);
+        env.storage()
+            .instance()
+            .get(&DataKey::DefaultMinStake)
+            .unwrap_or(MIN_STAKE)
+    }
 
-        Ok(dispute_id)
+    /// Accept `token` for new dispute stakes. The first call switches the
+    /// contract from accepting any token to accepting only allowlisted ones.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    pub fn allow_stake_token(env: Env, admin: Address, token: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .instance()
+            .set(&DataKey::StakeAllowlistEnabled, &true);
+        let key = DataKey::AllowedStakeToken(token);
+        env.storage().persistent().set(&key, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+        Ok(())
     }
 
-    /// Retrieve a dispute record by ID.
+    /// Stop accepting `token` for new dispute stakes. Disputes already staked
+    /// in `token` can still be voted on, appealed, resolved and refunded.
+    /// Has no effect before the first `allow_stake_token`; once enabled, the
+    /// allowlist stays in force even if it becomes empty.
     ///
-    /// Panics with `"Dispute not found"` if the ID does not exist, preserving
-    /// the original public API contract expected by callers and tests.
-    pub fn get_dispute(env: &Env, dispute_id: u64) -> Dispute {
-        Self::load_dispute(env, dispute_id).expect("Dispute not found")
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    pub fn disallow_stake_token(env: Env, admin: Address, token: Address) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AllowedStakeToken(token));
+        Ok(())
     }
 
-    /// Cast an arbitrator vote on an open dispute.
+    /// Whether `create_dispute` accepts stakes in `token`. Every token is
+    /// accepted until the admin first allowlists one.
+    pub fn is_stake_token_allowed(env: Env, token: Address) -> bool {
+        let enabled: bool = env
+            .storage()
+            .instance()
+            .get(&DataKey::StakeAllowlistEnabled)
+            .unwrap_or(false);
+        !enabled
+            || env
+                .storage()
+                .persistent()
+                .has(&DataKey::AllowedStakeToken(token))
+    }
+
+    /// Set how long after resolution a losing disputer may appeal.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    /// * `InvalidDeadline` — `window_secs == 0` or above `MAX_APPEAL_WINDOW_SECS`
+    pub fn set_appeal_window(env: Env, admin: Address, window_secs: u64) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+        if window_secs == 0 || window_secs > MAX_APPEAL_WINDOW_SECS {
+            return Err(Error::InvalidDeadline);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::AppealWindow, &window_secs);
+        Ok(())
+    }
+
+    /// Returns the appeal window in seconds (`DEFAULT_APPEAL_WINDOW_SECS` if unset).
+    pub fn get_appeal_window(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::AppealWindow)
+            .unwrap_or(DEFAULT_APPEAL_WINDOW_SECS)
+    }
+
+    /// Mark a dispute as `Expired` when the deadline passed without quorum.
+    ///
+    /// No judgement occurred, so the full stake is refunded to the disputer.
+    /// If an appeal round misses quorum, the first-round outcome stands and
+    /// only the appeal stake is refunded, also in full.
+    ///
+    /// No `ResolverFee` is paid on expiry; `resolver` only authorizes the call.
     ///
     /// # Errors
     /// * `DisputeNotFound` — unknown `dispute_id`
-    /// * `DisputeNotOpen` — dispute is no longer accepting votes
-    /// * `DeadlineExpired` — voting period has closed
-    /// * `AlreadyVoted` — `arbitrator` has already cast a vote on this dispute
-    pub fn cast_vote(
-        env: Env,
-        arbitrator: Address,
-        dispute_id: u64,
-        favor_disputer: bool,
-    ) -> Result<(), Error> {
-        arbitrator.require_auth();
+    /// * `DisputeNotOpen` — dispute is already resolved/expired
+    /// * `DeadlineNotReached` — deadline has not yet passed
+    /// * `QuorumReached` — enough votes were cast; use `resolve_dispute` instead
+    pub fn expire_dispute(env: Env, resolver: Address, dispute_id: u64) -> Result<(), Error> {
+        resolver.require_auth();
 
-        // Single persistent-storage read: load-or-error (replaces has() + get()).
         let mut dispute = Self::load_dispute(&env, dispute_id)?;
 
-        if dispute.status != DisputeStatus::Open {
+        if !Self::is_voting(&dispute) {
             return Err(Error::DisputeNotOpen);
         }
 
-        if env.ledger().timestamp() > dispute.deadline {
-            return Err(Error::DeadlineExpired);
+        if env.ledger().timestamp() <= Self::voting_closes(&dispute) {
+            return Err(Error::DeadlineNotReached);
         }
 
-        let vote_key = DataKey::Vote(dispute_id, arbitrator.clone());
-        let vote_storage = env.storage().persistent();
-
-        if vote_storage.has(&vote_key) {
-            return Err(Error::AlreadyVoted);
+        if Self::has_quorum(&env, &dispute) {
+            return Err(Error::QuorumReached);
         }
 
-        // Record the vote in persistent storage with a fresh TTL.
-        vote_storage.set(&vote_key, &favor_disputer);
-        vote_storage.extend_ttl(&vote_key, BUMP_THRESHOLD, BUMP_TARGET);
+        let first_round = if dispute.appealed {
+            Some(Self::get_appeal_record(env.clone(), dispute_id)?)
+        } else {
+            None
+        };
+        let refunded = match &first_round {
+            Some(record) => {
+                dispute.outcome = record.outcome.clone();
+                dispute.appeal_stake
+            }
+            None => dispute.stake,
+        };
 
-        if favor_disputer {
-            dispute.votes_for_disputer += 1;
+        dispute.status = DisputeStatus::Expired;
+        Self::remove_open(&env, dispute_id);
+
+        Self::save_dispute(&env, dispute_id, &dispute);
+
+        let forfeited = if first_round.is_some() {
+            dispute.stake - dispute.fees_paid - dispute.stake_returned
         } else {
-            dispute.votes_for_slasher += 1;
+            0
+        };
+        let returned = dispute.stake_returned;
+        Self::update_stats(&env, |mut stats| {
+            stats.total_expired = stats.total_expired.checked_add(1)?;
+            stats.total_refunded = stats.total_refunded.checked_add(returned + refunded)?;
+            stats.total_forfeited = stats.total_forfeited.checked_add(forfeited)?;
+            Some(stats)
+        })?;
+
+        // The first-round verdict stands, so its slasher-side voters are paid.
+        if let Some(record) = first_round {
+            Self::open_reward_pool(
+                &env,
+                dispute_id,
+                forfeited,
+                record.votes_for_slasher,
+                false,
+                env.ledger().timestamp(),
+            );
         }
 
-        // Persist updated vote tallies back to the dispute record.
-        Self::save_dispute(&env, dispute_id, &dispute);
+        Self::refund_disputer(&env, &dispute, refunded);
 
-        VoteCast {
+        DisputeExpired {
             dispute_id,
-            arbitrator,
-            favor_disputer,
+            expired_at: env.ledger().timestamp(),
+            refunded,
         }
         .publish(&env);
 
+        Self::notify_resolution(&env, dispute_id, &dispute.outcome);
+
         Ok(())
     }
 
-    /// Resolve a dispute after its deadline has passed.
+    /// Withdraw an open dispute that has not received any votes.
     ///
-    /// Whichever side holds the majority vote wins. On a `FavorDisputer`
-    /// outcome the staked tokens are returned to the disputer; otherwise they
-    /// remain in the contract (forfeited to the slasher side).
+    /// The stake is refunded to the disputer minus the cancellation fee, which
+    /// is sent to the treasury as a protocol fee.
     ///
     /// # Errors
     /// * `DisputeNotFound` — unknown `dispute_id`
-    /// * `DisputeNotOpen` — dispute is already resolved/expired
-    /// * `DeadlineNotReached` — voting period is still active
-    pub fn resolve_dispute(env: Env, dispute_id: u64) -> Result<(), Error> {
+    /// * `Unauthorized` — `disputer` did not open this dispute
+    /// * `DisputeNotOpen` — dispute is already resolved/expired/cancelled
+    /// * `DisputeHasVotes` — at least one arbitrator has voted or committed
+    /// * `TreasuryNotSet` — a fee is due but no treasury is configured
+    pub fn cancel_dispute(env: Env, disputer: Address, dispute_id: u64) -> Result<(), Error> {
+        disputer.require_auth();
+
         let mut dispute = Self::load_dispute(&env, dispute_id)?;
 
+        if dispute.disputer != disputer {
+            return Err(Error::Unauthorized);
+        }
+
         if dispute.status != DisputeStatus::Open {
             return Err(Error::DisputeNotOpen);
         }
 
-        if env.ledger().timestamp() <= dispute.deadline {
-            return Err(Error::DeadlineNotReached);
+        if dispute.count_for_disputer + dispute.count_for_slasher + dispute.commit_count as u64 > 0
+        {
+            return Err(Error::DisputeHasVotes);
         }
 
-        let token_client = soroban_sdk::token::Client::new(&env, &dispute.token);
-        let contract_address = env.current_contract_address();
-
-        let outcome = if dispute.votes_for_disputer > dispute.votes_for_slasher {
-            token_client.transfer(&contract_address, &dispute.disputer, &dispute.stake);
-            DisputeOutcome::FavorDisputer
+        let fee_bps = Self::get_cancellation_fee_bps(env.clone());
+        let fee = dispute.stake * fee_bps as i128 / BPS_DENOMINATOR as i128;
+        let refunded = dispute.stake - fee;
+        let treasury = if fee > 0 {
+            Some(Self::get_treasury(env.clone()).ok_or(Error::TreasuryNotSet)?)
         } else {
-            DisputeOutcome::FavorSlasher
+            None
         };
 
-        dispute.status = DisputeStatus::Resolved;
-        dispute.outcome = outcome.clone();
+        dispute.status = DisputeStatus::Cancelled;
+        Self::remove_open(&env, dispute_id);
 
         Self::save_dispute(&env, dispute_id, &dispute);
 
-        DisputeResolved {
+        Self::update_stats(&env, |mut stats| {
+            stats.total_cancelled = stats.total_cancelled.checked_add(1)?;
+            stats.total_refunded = stats.total_refunded.checked_add(refunded)?;
+            stats.total_forfeited = stats.total_forfeited.checked_add(fee)?;
+            Some(stats)
+        })?;
+
+        Self::refund_disputer(&env, &dispute, refunded);
+        if let Some(treasury) = treasury {
+            Self::send_to_treasury(
+                &env,
+                &treasury,
+                &dispute.token,
+                fee,
+                FUND_SOURCE_PROTOCOL_FEE,
+            );
+        }
+
+        DisputeCancelled {
             dispute_id,
-            outcome,
-            votes_for_disputer: dispute.votes_for_disputer,
-            votes_for_slasher: dispute.votes_for_slasher,
+            disputer,
+            refunded,
+            fee,
         }
         .publish(&env);
 
         Ok(())
     }
 
-    /// Mark a dispute as `Expired` when no arbitrators resolved it after the
-    /// deadline.
+    /// Restrict voting on an open dispute to a fixed panel of registered
+    /// arbitrators. Quorum becomes a majority of the panel, replacing
+    /// `min_votes_to_resolve`, and the panel also sits any appeal round.
+    /// A panel may be replaced until the first vote is cast.
     ///
     /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
     /// * `DisputeNotFound` — unknown `dispute_id`
-    /// * `DisputeNotOpen` — dispute is already resolved/expired
-    /// * `DeadlineNotReached` — deadline has not yet passed
-    pub fn expire_dispute(env: Env, dispute_id: u64) -> Result<(), Error> {
-        let mut dispute = Self::load_dispute(&env, dispute_id)?;
+    /// * `DisputeNotOpen` — dispute is no longer open
+    /// * `DisputeHasVotes` — at least one arbitrator has voted or committed
+    /// * `InvalidPanel` — size outside `MIN_PANEL_SIZE..=MAX_PANEL_SIZE`, or
+    ///   a duplicate member
+    /// * `NotArbitrator` — a member is not a registered arbitrator
+    pub fn assign_panel(
+        env: Env,
+        admin: Address,
+        dispute_id: u64,
+        panel: Vec<Address>,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
 
+        let mut dispute = Self::load_dispute(&env, dispute_id)?;
         if dispute.status != DisputeStatus::Open {
             return Err(Error::DisputeNotOpen);
         }
+        if dispute.count_for_disputer + dispute.count_for_slasher + dispute.commit_count as u64 > 0
+        {
+            return Err(Error::DisputeHasVotes);
+        }
 
-        if env.ledger().timestamp() <= dispute.deadline {
-            return Err(Error::DeadlineNotReached);
+        let size = panel.len();
+        if !(MIN_PANEL_SIZE..=MAX_PANEL_SIZE).contains(&size) {
+            return Err(Error::InvalidPanel);
+        }
+        for (i, member) in panel.iter().enumerate() {
+            if Self::load_arbitrator(&env, &member).is_none() {
+                return Err(Error::NotArbitrator);
+            }
+            if panel.slice(i as u32 + 1..).contains(&member) {
+                return Err(Error::InvalidPanel);
+            }
         }
 
-        dispute.status = DisputeStatus::Expired;
+        let key = DataKey::Panel(dispute_id);
+        env.storage().persistent().set(&key, &panel);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
 
+        dispute.panel_size = size;
         Self::save_dispute(&env, dispute_id, &dispute);
 
-        DisputeExpired {
+        PanelAssigned { dispute_id, panel }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Returns the arbitrator panel of a panel-mode dispute, or `None` for
+    /// open voting.
+    pub fn get_panel(env: Env, dispute_id: u64) -> Option<Vec<Address>> {
+        let key = DataKey::Panel(dispute_id);
+        let storage = env.storage().persistent();
+        let panel = storage.get(&key)?;
+        storage.extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+        Some(panel)
+    }
+
+    /// Record the slasher behind a dispute so they can submit evidence.
+    ///
+    /// # Errors
+    /// * `NotInitialized` — `initialize` has not been called
+    /// * `Unauthorized` — `admin` is not the registry admin
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `DisputeNotOpen` — dispute is no longer open
+    pub fn set_dispute_slasher(
+        env: Env,
+        admin: Address,
+        dispute_id: u64,
+        slasher: Address,
+    ) -> Result<(), Error> {
+        Self::require_admin(&env, &admin)?;
+
+        let mut dispute = Self::load_dispute(&env, dispute_id)?;
+        if dispute.status != DisputeStatus::Open {
+            return Err(Error::DisputeNotOpen);
+        }
+
+        dispute.slasher = Some(slasher.clone());
+        Self::save_dispute(&env, dispute_id, &dispute);
+
+        DisputeSlasherSet {
             dispute_id,
-            expired_at: env.ledger().timestamp(),
+            slasher,
         }
         .publish(&env);
 
         Ok(())
     }
 
-    /// Returns `true` if `arbitrator` has already cast a vote on `dispute_id`.
-    pub fn has_voted(env: Env, dispute_id: u64, arbitrator: Address) -> bool {
+    /// Attach evidence to a dispute in a voting round. Callable by the disputer and, once
+    /// recorded, the slasher, each up to `MAX_EVIDENCE_PER_SIDE` times.
+    ///
+    /// Returns the index of the new evidence entry.
+    ///
+    /// # Errors
+    /// * `ContractPaused` — new activity is paused
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `Unauthorized` — `submitter` is neither the disputer nor the slasher
+    /// * `DisputeNotOpen` — dispute is no longer open
+    /// * `DeadlineExpired` — voting period has closed
+    /// * `InvalidEvidence` — `uri` is empty or longer than `MAX_EVIDENCE_URI_LEN`
+    /// * `EvidenceLimitReached` — `submitter`'s side has hit the cap
+    pub fn submit_evidence(
+        env: Env,
+        submitter: Address,
+        dispute_id: u64,
+        evidence_hash: BytesN<32>,
+        uri: String,
+    ) -> Result<u32, Error> {
+        submitter.require_auth();
+        Self::require_not_paused(&env)?;
+
+        let dispute = Self::load_dispute(&env, dispute_id)?;
+
+        let from_disputer = submitter == dispute.disputer;
+        if !from_disputer && dispute.slasher.as_ref() != Some(&submitter) {
+            return Err(Error::Unauthorized);
+        }
+
+        if !Self::is_voting(&dispute) {
+            return Err(Error::DisputeNotOpen);
+        }
+
+        if env.ledger().timestamp() > dispute.deadline {
+            return Err(Error::DeadlineExpired);
+        }
+
+        if uri.is_empty() || uri.len() > MAX_EVIDENCE_URI_LEN {
+            return Err(Error::InvalidEvidence);
+        }
+
+        let count_key = DataKey::EvidenceCount(dispute_id);
+        let storage = env.storage().persistent();
+        let mut tally: EvidenceTally = storage.get(&count_key).unwrap_or_default();
+        let side = if from_disputer {
+            &mut tally.disputer
+        } else {
+            &mut tally.slasher
+        };
+        if *side >= MAX_EVIDENCE_PER_SIDE {
+            return Err(Error::EvidenceLimitReached);
+        }
+        *side += 1;
+
+        let index = tally.disputer + tally.slasher - 1;
+        let evidence = Evidence {
+            submitter: submitter.clone(),
+            evidence_hash: evidence_hash.clone(),
+            uri: uri.clone(),
+            submitted_at: env.ledger().timestamp(),
+        };
+        let evidence_key = DataKey::Evidence(dispute_id, index);
+        storage.set(&evidence_key, &evidence);
+        storage.extend_ttl(&evidence_key, BUMP_THRESHOLD, BUMP_TARGET);
+        storage.set(&count_key, &tally);
+        storage.extend_ttl(&count_key, BUMP_THRESHOLD, BUMP_TARGET);
+
+        EvidenceSubmitted {
+            dispute_id,
+            index,
+            submitter,
+            evidence_hash,
+            uri,
+        }
+        .publish(&env);
+
+        Ok(index)
+    }
+
+    /// Retrieve an evidence entry by dispute and index.
+    ///
+    /// # Errors
+    /// * `EvidenceNotFound` — no evidence at `index`
+    pub fn get_evidence(env: Env, dispute_id: u64, index: u32) -> Result<Evidence, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Evidence(dispute_id, index))
+            .ok_or(Error::EvidenceNotFound)
+    }
+
+    /// Returns the number of evidence entries on `dispute_id` across both sides.
+    pub fn get_evidence_count(env: Env, dispute_id: u64) -> u32 {
+        let tally: EvidenceTally = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EvidenceCount(dispute_id))
+            .unwrap_or_default();
+        tally.disputer + tally.slasher
+    }
+
+    /// Returns up to `limit` (capped at `MAX_PAGE_SIZE`) IDs of disputes opened
+    /// by `disputer`, oldest first, skipping the first `offset`.
+    pub fn get_disputes_by_disputer(
+        env: Env,
+        disputer: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        let ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::DisputerIndex(disputer))
+            .unwrap_or(vec![&env]);
+        let end = offset
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(ids.len());
+        if offset >= end {
+            return vec![&env];
+        }
+        ids.slice(offset..end)
+    }
+
+    /// Returns up to `limit` (capped at `MAX_PAGE_SIZE`) IDs of disputes in a
+    /// voting round, skipping the first `offset`. Order is unspecified and
+    /// shifts as disputes close.
+    pub fn get_open_disputes(env: Env, offset: u32, limit: u32) -> Vec<u64> {
+        let open: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::OpenDisputes)
+            .unwrap_or(vec![&env]);
+        let end = offset
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(open.len());
+        if offset >= end {
+            return vec![&env];
+        }
+        open.slice(offset..end)
+    }
+
+    /// Returns the number of disputes in a voting round.
+    pub fn get_open_dispute_count(env: Env) -> u32 {
         env.storage()
             .persistent()
-            .has(&DataKey::Vote(dispute_id, arbitrator))
+            .get::<_, Vec<u64>>(&DataKey::OpenDisputes)
+            .map_or(0, |open| open.len())
+    }
+
+    /// Returns the most recent dispute opened against `slash_request_id`.
+    pub fn get_dispute_for_slash_request(env: Env, slash_request_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SlashRequestIndex(slash_request_id))
+    }
+
+    /// Returns `true` if `arbitrator` has already voted in the current round
+    /// of `dispute_id` (the appeal round once appealed).
+    pub fn has_voted(env: Env, dispute_id: u64, arbitrator: Address) -> bool {
+        Self::get_vote(env, dispute_id, arbitrator).is_some()
+    }
+
+    /// Returns how `arbitrator` voted in the current round of `dispute_id`
+    /// (`true` = for the disputer), or `None` if they have not voted. Bumps
+    /// the vote entry's TTL on read.
+    pub fn get_vote(env: Env, dispute_id: u64, arbitrator: Address) -> Option<bool> {
+        let dispute = Self::load_dispute(&env, dispute_id).ok()?;
+        Self::read_vote(&env, &Self::vote_key(dispute_id, &dispute, arbitrator))
+    }
+
+    /// Returns `arbitrator`'s vote in the current round of `dispute_id`,
+    /// including the weight it carried. Bumps the vote entry's TTL on read.
+    ///
+    /// # Errors
+    /// * `DisputeNotFound` — unknown `dispute_id`
+    /// * `VoteNotFound` — `arbitrator` has not voted in the current round
+    pub fn find_vote(env: Env, dispute_id: u64, arbitrator: Address) -> Result<VoteRecord, Error> {
+        let dispute = Self::load_dispute(&env, dispute_id)?;
+        let key = Self::vote_key(dispute_id, &dispute, arbitrator);
+        let storage = env.storage().persistent();
+        let vote: VoteRecord = storage.get(&key).ok_or(Error::VoteNotFound)?;
+        storage.extend_ttl(&key, BUMP_THRESHOLD, BUMP_TARGET);
+        Ok(vote)
+    }
+
+    /// Batched `get_vote`: one entry per address in `arbitrators`, in order.
+    pub fn get_votes(env: Env, dispute_id: u64, arbitrators: Vec<Address>) -> Vec<Option<bool>> {
+        let mut votes = vec![&env];
+        let Ok(dispute) = Self::load_dispute(&env, dispute_id) else {
+            for _ in arbitrators.iter() {
+                votes.push_back(None);
+            }
+            return votes;
+        };
+        for arbitrator in arbitrators.iter() {
+            let key = Self::vote_key(dispute_id, &dispute, arbitrator);
+            votes.push_back(Self::read_vote(&env, &key));
+        }
+        votes
+    }
+
+    /// Returns aggregate outcome statistics over terminal disputes.
+    pub fn get_dispute_stats(env: Env) -> DisputeStats {
+        env.storage()
+            .instance()
+            .get(&DataKey::Stats)
+            .unwrap_or_default()
     }
 
     /// Returns the total number of disputes ever created (monotonically
@@ -0,0 +1,105 @@
+//! Pause state for the dispute contract.
+//!
+//! A pause is either open-ended (`until == 0`) or lifts automatically once
+//! the ledger timestamp reaches `until`. Each pause records a [`PauseInfo`]
+//! with who paused and why; the most recent one stays readable after
+//! unpausing.
+
+use soroban_sdk::{contracttype, Address, Env, String, Symbol};
+
+/// Details of a pause.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseInfo {
+    /// Whether this pause is in effect right now
+    pub paused: bool,
+    /// Timestamp when the pause started
+    pub since: u64,
+    /// Timestamp at which the pause lifts (0 = until unpaused)
+    pub until: u64,
+    /// Short reason code
+    pub reason: Symbol,
+    /// Optional longer explanation
+    pub detail: Option<String>,
+    /// Address that paused
+    pub paused_by: Address,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum PauseKey {
+    /// Pause currently recorded
+    Current,
+    /// Most recent pause, kept after unpausing
+    Last,
+}
+
+/// Whether the contract is currently paused.
+pub fn is_paused(e: &Env) -> bool {
+    match e
+        .storage()
+        .instance()
+        .get::<_, PauseInfo>(&PauseKey::Current)
+    {
+        Some(info) => is_live(e, &info),
+        None => false,
+    }
+}
+
+/// The recorded pause, with `paused` reflecting whether it is still in
+/// effect. `None` once unpaused.
+pub fn pause_info(e: &Env) -> Option<PauseInfo> {
+    with_live_flag(e, e.storage().instance().get(&PauseKey::Current))
+}
+
+/// The most recent pause, kept after unpausing.
+pub fn last_pause_info(e: &Env) -> Option<PauseInfo> {
+    let last = with_live_flag(e, e.storage().instance().get(&PauseKey::Last))?;
+    let current: Option<PauseInfo> = e.storage().instance().get(&PauseKey::Current);
+    Some(PauseInfo {
+        paused: last.paused && current.is_some(),
+        ..last
+    })
+}
+
+fn is_live(e: &Env, info: &PauseInfo) -> bool {
+    info.until == 0 || e.ledger().timestamp() < info.until
+}
+
+fn with_live_flag(e: &Env, info: Option<PauseInfo>) -> Option<PauseInfo> {
+    info.map(|info| PauseInfo {
+        paused: is_live(e, &info),
+        ..info
+    })
+}
+
+/// Pause until `until`, or indefinitely when `until` is 0.
+pub fn pause(
+    e: &Env,
+    paused_by: &Address,
+    until: u64,
+    reason: Symbol,
+    detail: Option<String>,
+) -> PauseInfo {
+    let info = PauseInfo {
+        paused: true,
+        since: e.ledger().timestamp(),
+        until,
+        reason,
+        detail,
+        paused_by: paused_by.clone(),
+    };
+    e.storage().instance().set(&PauseKey::Current, &info);
+    e.storage().instance().set(&PauseKey::Last, &info);
+    info
+}
+
+/// Clear the recorded pause, including a timed pause that has lapsed.
+/// Returns `false` if there was none.
+pub fn unpause(e: &Env) -> bool {
+    if !e.storage().instance().has(&PauseKey::Current) {
+        return false;
+    }
+    e.storage().instance().remove(&PauseKey::Current);
+    true
+}
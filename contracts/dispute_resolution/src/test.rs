@@ -2,7 +2,7 @@
 
 use super::*;
 use soroban_sdk::testutils::{Address as _, Ledger};
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Symbol, Vec};
 
 fn setup_token<'a>(
     env: &'a Env,
@@ -22,6 +22,18 @@ fn setup_token<'a>(
     token_admin_client.mint(recipient, &amount);
     (token_id, token_admin_client, token_client)
 }
+
+fn setup_admin(env: &Env, client: &DisputeContractClient) -> Address {
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    admin
+}
+
+fn new_arbitrator(env: &Env, client: &DisputeContractClient, admin: &Address) -> Address {
+    let arbitrator = Address::generate(env);
+    client.add_arbitrator(admin, &arbitrator);
+    arbitrator
+}
 // ── create_dispute ────────────────────────────────────────────────────────────
 
 #[test]
@@ -156,6 +168,7 @@ fn test_cast_vote_favor_disputer() {
 
     let contract_id = env.register(DisputeContract, ());
     let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
 
     let disputer = Address::generate(&env);
     let token_admin = Address::generate(&env);
@@ -164,7 +177,7 @@ fn test_cast_vote_favor_disputer() {
     token_client.approve(&disputer, &contract_id, &500, &1000);
     let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &3600);
 
-    client.cast_vote(&Address::generate(&env), &dispute_id, &true);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
 
     let dispute = client.get_dispute(&dispute_id);
     assert_eq!(dispute.votes_for_disputer, 1);
@@ -178,6 +191,7 @@ fn test_cast_vote_favor_slasher() {
 
     let contract_id = env.register(DisputeContract, ());
     let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
 
     let disputer = Address::generate(&env);
     let token_admin = Address::generate(&env);
@@ -186,7 +200,7 @@ fn test_cast_vote_favor_slasher() {
     token_client.approve(&disputer, &contract_id, &500, &1000);
     let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &3600);
 
-    client.cast_vote(&Address::generate(&env), &dispute_id, &false);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
 
     let dispute = client.get_dispute(&dispute_id);
     assert_eq!(dispute.votes_for_disputer, 0);
@@ -201,9 +215,10 @@ fn test_cast_vote_fails_already_voted() {
 
     let contract_id = env.register(DisputeContract, ());
     let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
 
     let disputer = Address::generate(&env);
-    let arbitrator = Address::generate(&env);
+    let arbitrator = new_arbitrator(&env, &client, &admin);
     let token_admin = Address::generate(&env);
     let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
 
@@ -222,6 +237,7 @@ fn test_cast_vote_fails_after_deadline() {
 
     let contract_id = env.register(DisputeContract, ());
     let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
 
     let disputer = Address::generate(&env);
     let token_admin = Address::generate(&env);
@@ -231,7 +247,7 @@ fn test_cast_vote_fails_after_deadline() {
     let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
 
     env.ledger().set_timestamp(env.ledger().timestamp() + 200);
-    client.cast_vote(&Address::generate(&env), &dispute_id, &true);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
 }
 
 #[test]
@@ -242,8 +258,9 @@ fn test_cast_vote_fails_dispute_not_found() {
 
     let contract_id = env.register(DisputeContract, ());
     let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
 
-    client.cast_vote(&Address::generate(&env), &999, &true);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &999, &true);
 }
 
 #[test]
@@ -253,9 +270,10 @@ fn test_has_voted_true_and_false() {
 
     let contract_id = env.register(DisputeContract, ());
     let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
 
     let disputer = Address::generate(&env);
-    let arbitrator = Address::generate(&env);
+    let arbitrator = new_arbitrator(&env, &client, &admin);
     let other = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
@@ -269,6 +287,38 @@ fn test_has_voted_true_and_false() {
     assert!(!client.has_voted(&dispute_id, &other));
 }
 
+#[test]
+fn test_get_vote_returns_direction() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let for_disputer = new_arbitrator(&env, &client, &admin);
+    let for_slasher = new_arbitrator(&env, &client, &admin);
+    let abstained = new_arbitrator(&env, &client, &admin);
+
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    client.cast_vote(&for_disputer, &dispute_id, &true);
+    client.cast_vote(&for_slasher, &dispute_id, &false);
+
+    assert_eq!(client.get_vote(&dispute_id, &for_disputer), Some(true));
+    assert_eq!(client.get_vote(&dispute_id, &for_slasher), Some(false));
+    assert_eq!(client.get_vote(&dispute_id, &abstained), None);
+    assert_eq!(client.get_vote(&999, &for_disputer), None);
+
+    let arbitrators = soroban_sdk::vec![&env, abstained, for_slasher, for_disputer];
+    assert_eq!(
+        client.get_votes(&dispute_id, &arbitrators),
+        soroban_sdk::vec![&env, None, Some(false), Some(true)]
+    );
+    assert_eq!(
+        client.get_votes(&999, &arbitrators),
+        soroban_sdk::vec![&env, None, None, None]
+    );
+}
+
 #[test]
 fn test_multiple_arbitrators_vote() {
     let env = Env::default();
@@ -276,6 +326,7 @@ fn test_multiple_arbitrators_vote() {
 
     let contract_id = env.register(DisputeContract, ());
     let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
 
     let disputer = Address::generate(&env);
     let token_admin = Address::generate(&env);
@@ -285,10 +336,10 @@ fn test_multiple_arbitrators_vote() {
     let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &3600);
 
     for _ in 0..3 {
-        client.cast_vote(&Address::generate(&env), &dispute_id, &true);
+        client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
     }
     for _ in 0..2 {
-        client.cast_vote(&Address::generate(&env), &dispute_id, &false);
+        client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
     }
 
     let dispute = client.get_dispute(&dispute_id);
@@ -296,6 +347,383 @@ fn test_multiple_arbitrators_vote() {
     assert_eq!(dispute.votes_for_slasher, 2);
 }
 
+#[test]
+fn test_create_dispute_max_resolution_window_boundary() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &1000, &1000);
+
+    let max = client.get_max_resolution_window();
+    assert_eq!(max, DEFAULT_MAX_RESOLUTION_WINDOW_SECS);
+    assert_eq!(
+        client.try_create_dispute(&disputer, &1, &500, &token_id, &(max + 1)),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        client.try_create_dispute(&disputer, &1, &500, &token_id, &u64::MAX),
+        Err(Ok(Error::InvalidDeadline))
+    );
+
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &max);
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.deadline, env.ledger().timestamp() + max);
+}
+
+#[test]
+fn test_set_max_resolution_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    assert_eq!(
+        client.try_set_max_resolution_window(&admin, &0),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        client.try_set_max_resolution_window(&Address::generate(&env), &60),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.set_max_resolution_window(&admin, &60);
+    assert_eq!(client.get_max_resolution_window(), 60);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &1000, &1000);
+    assert_eq!(
+        client.try_create_dispute(&disputer, &1, &500, &token_id, &61),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    client.create_dispute(&disputer, &1, &500, &token_id, &60);
+}
+
+#[test]
+fn test_create_dispute_deadline_overflow_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_max_resolution_window(&admin, &u64::MAX);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+
+    env.ledger().set_timestamp(1_000);
+    assert_eq!(
+        client.try_create_dispute(&disputer, &1, &500, &token_id, &u64::MAX),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(token_client.balance(&disputer), 1000);
+    assert_eq!(client.get_dispute_count(), 0);
+}
+
+// ── change_vote ───────────────────────────────────────────────────────────────
+
+#[test]
+fn test_change_vote_moves_weight_and_count() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+    client.set_arbitrator_weight(&admin, &arbitrator, &3);
+    client.cast_vote(&arbitrator, &dispute_id, &false);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+
+    client.change_vote(&arbitrator, &dispute_id, &true);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.votes_for_disputer, 3);
+    assert_eq!(dispute.count_for_disputer, 1);
+    assert_eq!(dispute.votes_for_slasher, 1);
+    assert_eq!(dispute.count_for_slasher, 1);
+    assert_eq!(client.get_vote(&dispute_id, &arbitrator), Some(true));
+
+    client.change_vote(&arbitrator, &dispute_id, &false);
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.votes_for_disputer, 0);
+    assert_eq!(dispute.count_for_disputer, 0);
+    assert_eq!(dispute.votes_for_slasher, 4);
+    assert_eq!(dispute.count_for_slasher, 2);
+}
+
+#[test]
+fn test_change_vote_uses_weight_at_vote_time() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+    client.cast_vote(&arbitrator, &dispute_id, &false);
+    client.set_arbitrator_weight(&admin, &arbitrator, &5);
+    client.change_vote(&arbitrator, &dispute_id, &true);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.votes_for_disputer, 1);
+    assert_eq!(dispute.votes_for_slasher, 0);
+}
+
+#[test]
+fn test_change_vote_flips_outcome() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+    client.cast_vote(&arbitrator, &dispute_id, &false);
+    client.change_vote(&arbitrator, &dispute_id, &true);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 4000);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+    assert_eq!(
+        client.get_dispute(&dispute_id).outcome,
+        DisputeOutcome::FavorDisputer
+    );
+}
+
+#[test]
+fn test_change_vote_rejections() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+
+    assert_eq!(
+        client.try_change_vote(&arbitrator, &dispute_id, &true),
+        Err(Ok(Error::VoteNotFound))
+    );
+    assert_eq!(
+        client.try_change_vote(&Address::generate(&env), &dispute_id, &true),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.cast_vote(&arbitrator, &dispute_id, &true);
+    assert_eq!(
+        client.try_change_vote(&arbitrator, &dispute_id, &true),
+        Err(Ok(Error::VoteUnchanged))
+    );
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 4000);
+    assert_eq!(
+        client.try_change_vote(&arbitrator, &dispute_id, &false),
+        Err(Ok(Error::DeadlineExpired))
+    );
+
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+    assert_eq!(
+        client.try_change_vote(&arbitrator, &dispute_id, &false),
+        Err(Ok(Error::DisputeNotOpen))
+    );
+}
+
+#[test]
+fn test_create_dispute_defaults_reason() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.reason, DEFAULT_DISPUTE_REASON);
+    assert_eq!(dispute.details_hash, None);
+    assert!(dispute.details_uri.is_empty());
+}
+
+#[test]
+fn test_create_dispute_with_reason_stores_details() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+
+    let reason = Symbol::new(&env, "wrong_slash");
+    let hash = BytesN::from_array(&env, &[7; 32]);
+    let uri = String::from_str(&env, "ipfs://grounds");
+    let details = DisputeDetails {
+        reason: reason.clone(),
+        details_hash: Some(hash.clone()),
+        details_uri: uri.clone(),
+    };
+    let dispute_id =
+        client.create_dispute_with_reason(&disputer, &1, &500, &token_id, &3600, &details);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.reason, reason);
+    assert_eq!(dispute.details_hash, Some(hash));
+    assert_eq!(dispute.details_uri, uri);
+    assert_eq!(token_client.balance(&contract_id), 500);
+}
+
+#[test]
+fn test_create_dispute_with_reason_validates_details() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+
+    let hash_without_uri = DisputeDetails {
+        reason: Symbol::new(&env, "wrong_slash"),
+        details_hash: Some(BytesN::from_array(&env, &[7; 32])),
+        details_uri: String::from_str(&env, ""),
+    };
+    assert_eq!(
+        client.try_create_dispute_with_reason(
+            &disputer,
+            &1,
+            &500,
+            &token_id,
+            &3600,
+            &hash_without_uri
+        ),
+        Err(Ok(Error::InvalidDetails))
+    );
+
+    let long_uri = DisputeDetails {
+        details_hash: None,
+        details_uri: String::from_bytes(&env, &[b'a'; MAX_EVIDENCE_URI_LEN as usize + 1]),
+        ..hash_without_uri.clone()
+    };
+    assert_eq!(
+        client.try_create_dispute_with_reason(&disputer, &1, &500, &token_id, &3600, &long_uri),
+        Err(Ok(Error::InvalidDetails))
+    );
+
+    let no_details = DisputeDetails {
+        details_hash: None,
+        ..hash_without_uri
+    };
+    client.create_dispute_with_reason(&disputer, &1, &500, &token_id, &3600, &no_details);
+}
+
+#[test]
+fn test_create_dispute_enforces_min_voting_period() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    assert_eq!(
+        client.get_min_voting_period(),
+        DEFAULT_MIN_VOTING_PERIOD_SECS
+    );
+
+    client.set_min_voting_period(&admin, &3600);
+    assert_eq!(client.get_min_voting_period(), 3600);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &1000, &1000);
+
+    assert_eq!(
+        client.try_create_dispute(&disputer, &1, &500, &token_id, &3599),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    client.create_dispute(&disputer, &1, &500, &token_id, &3600);
+}
+
+#[test]
+fn test_set_min_voting_period_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    assert_eq!(
+        client.try_set_min_voting_period(&admin, &(DEFAULT_MAX_RESOLUTION_WINDOW_SECS + 1)),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        client.try_set_min_voting_period(&Address::generate(&env), &60),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_min_voting_period(&admin, &600);
+    assert_eq!(
+        client.try_set_max_resolution_window(&admin, &599),
+        Err(Ok(Error::InvalidDeadline))
+    );
+
+    client.set_min_voting_period(&admin, &0);
+    assert_eq!(client.get_min_voting_period(), 0);
+}
+
+#[test]
+fn test_resolve_waits_for_min_voting_period_on_existing_dispute() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let start = env.ledger().timestamp();
+
+    // Created with a short deadline before the minimum was raised.
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+    client.set_min_voting_period(&admin, &3600);
+
+    env.ledger().set_timestamp(start + 200);
+    assert_eq!(
+        client.try_resolve_dispute(&Address::generate(&env), &dispute_id),
+        Err(Ok(Error::DeadlineNotReached))
+    );
+
+    env.ledger().set_timestamp(start + 3600);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+    assert_eq!(
+        client.get_dispute(&dispute_id).status,
+        DisputeStatus::Resolved
+    );
+}
+
 // ── resolve_dispute ───────────────────────────────────────────────────────────
 
 #[test]
@@ -305,6 +733,7 @@ fn test_resolve_dispute_favor_disputer_stake_returned() {
 
     let contract_id = env.register(DisputeContract, ());
     let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
 
     let disputer = Address::generate(&env);
     let token_admin = Address::generate(&env);
@@ -314,12 +743,12 @@ fn test_resolve_dispute_favor_disputer_stake_returned() {
     token_client.approve(&disputer, &contract_id, &stake, &1000);
     let dispute_id = client.create_dispute(&disputer, &1, &stake, &token_id, &100);
 
-    client.cast_vote(&Address::generate(&env), &dispute_id, &true);
-    client.cast_vote(&Address::generate(&env), &dispute_id, &false);
-    client.cast_vote(&Address::generate(&env), &dispute_id, &true);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
 
     env.ledger().set_timestamp(env.ledger().timestamp() + 200);
-    client.resolve_dispute(&dispute_id);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
 
     let dispute = client.get_dispute(&dispute_id);
     assert_eq!(dispute.status, DisputeStatus::Resolved);
@@ -334,6 +763,7 @@ fn test_resolve_dispute_favor_slasher_stake_forfeited() {
 
     let contract_id = env.register(DisputeContract, ());
     let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
 
     let disputer = Address::generate(&env);
     let token_admin = Address::generate(&env);
@@ -343,11 +773,11 @@ fn test_resolve_dispute_favor_slasher_stake_forfeited() {
     token_client.approve(&disputer, &contract_id, &stake, &1000);
     let dispute_id = client.create_dispute(&disputer, &1, &stake, &token_id, &100);
 
-    client.cast_vote(&Address::generate(&env), &dispute_id, &false);
-    client.cast_vote(&Address::generate(&env), &dispute_id, &false);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
 
     env.ledger().set_timestamp(env.ledger().timestamp() + 200);
-    client.resolve_dispute(&dispute_id);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
 
     let dispute = client.get_dispute(&dispute_id);
     assert_eq!(dispute.status, DisputeStatus::Resolved);
@@ -372,7 +802,7 @@ fn test_resolve_dispute_fails_before_deadline() {
     token_client.approve(&disputer, &contract_id, &500, &1000);
     let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &3600);
 
-    client.resolve_dispute(&dispute_id);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
 }
 
 #[test]
@@ -384,7 +814,7 @@ fn test_resolve_dispute_fails_not_found() {
     let contract_id = env.register(DisputeContract, ());
     let client = DisputeContractClient::new(&env, &contract_id);
 
-    client.resolve_dispute(&999);
+    client.resolve_dispute(&Address::generate(&env), &999);
 }
 
 #[test]
@@ -395,6 +825,7 @@ fn test_resolve_dispute_fails_already_resolved() {
 
     let contract_id = env.register(DisputeContract, ());
     let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
 
     let disputer = Address::generate(&env);
     let token_admin = Address::generate(&env);
@@ -402,126 +833,3795 @@ fn test_resolve_dispute_fails_already_resolved() {
 
     token_client.approve(&disputer, &contract_id, &500, &1000);
     let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
 
     env.ledger().set_timestamp(env.ledger().timestamp() + 200);
-    client.resolve_dispute(&dispute_id);
-    client.resolve_dispute(&dispute_id);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
 }
 
-// ── expire_dispute ────────────────────────────────────────────────────────────
+// ── forfeit share ────────────────────────────────────────────────────────────
 
 #[test]
-fn test_expire_dispute_success() {
+fn test_forfeit_bps_default_and_validation() {
     let env = Env::default();
     env.mock_all_auths();
 
     let contract_id = env.register(DisputeContract, ());
     let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    assert_eq!(client.get_forfeit_bps(), DEFAULT_FORFEIT_BPS);
+    client.set_forfeit_bps(&admin, &2_500);
+    assert_eq!(client.get_forfeit_bps(), 2_500);
+    assert_eq!(
+        client.try_set_forfeit_bps(&admin, &(BPS_DENOMINATOR + 1)),
+        Err(Ok(Error::InvalidFee))
+    );
+    assert_eq!(
+        client.try_set_forfeit_bps(&Address::generate(&env), &0),
+        Err(Ok(Error::Unauthorized))
+    );
+}
 
-    let disputer = Address::generate(&env);
-    let token_admin = Address::generate(&env);
-    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+#[test]
+fn test_full_forfeit_keeps_whole_stake() {
+    let env = Env::default();
+    env.mock_all_auths();
 
-    token_client.approve(&disputer, &contract_id, &500, &1000);
-    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_forfeit_bps(&admin, &BPS_DENOMINATOR);
+    let (dispute_id, disputer, token_client) = lose_dispute(&env, &client, &contract_id, &admin);
 
-    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
-    client.expire_dispute(&dispute_id);
+    assert_eq!(token_client.balance(&disputer), 4500);
+    assert_eq!(client.get_reward_pool(&dispute_id).unwrap().amount, 500);
+    assert_eq!(client.get_dispute(&dispute_id).stake_returned, 0);
+}
+
+#[test]
+fn test_zero_forfeit_returns_whole_stake() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_forfeit_bps(&admin, &0);
+    let (dispute_id, disputer, token_client) = lose_dispute(&env, &client, &contract_id, &admin);
 
     let dispute = client.get_dispute(&dispute_id);
-    assert_eq!(dispute.status, DisputeStatus::Expired);
+    assert_eq!(dispute.outcome, DisputeOutcome::FavorSlasher);
+    assert_eq!(dispute.stake_returned, 500);
+    assert_eq!(token_client.balance(&disputer), 5000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(client.get_reward_pool(&dispute_id).unwrap().amount, 0);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #4)")]
-fn test_expire_dispute_fails_before_deadline() {
+fn test_partial_forfeit_rounds_refund_down() {
     let env = Env::default();
     env.mock_all_auths();
 
     let contract_id = env.register(DisputeContract, ());
     let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_forfeit_bps(&admin, &3_333);
 
     let disputer = Address::generate(&env);
     let token_admin = Address::generate(&env);
     let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &101, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &101, &token_id, &100);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
 
-    token_client.approve(&disputer, &contract_id, &500, &1000);
-    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &3600);
+    // 101 * 6667 / 10000 = 67.3667 → 67 back, 34 forfeited.
+    assert_eq!(token_client.balance(&disputer), 1000 - 101 + 67);
+    assert_eq!(client.get_reward_pool(&dispute_id).unwrap().amount, 34);
+    assert_eq!(token_client.balance(&contract_id), 34);
 
-    client.expire_dispute(&dispute_id);
+    let stats = client.get_dispute_stats();
+    assert_eq!(stats.total_refunded, 67);
+    assert_eq!(stats.total_forfeited, 34);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #1)")]
-fn test_expire_dispute_fails_not_found() {
+fn test_forfeit_share_ignores_disputer_win() {
     let env = Env::default();
     env.mock_all_auths();
 
     let contract_id = env.register(DisputeContract, ());
     let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_forfeit_bps(&admin, &5_000);
+
+    let (dispute_id, disputer) = open_dispute_by(&env, &client, &contract_id);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
 
-    client.expire_dispute(&999);
+    let token = soroban_sdk::token::Client::new(&env, &client.get_dispute(&dispute_id).token);
+    assert_eq!(token.balance(&disputer), 1000);
+    assert_eq!(client.get_reward_pool(&dispute_id), None);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #3)")]
-fn test_expire_already_resolved_dispute_fails() {
+fn test_partial_forfeit_credited_on_appeal() {
     let env = Env::default();
     env.mock_all_auths();
 
     let contract_id = env.register(DisputeContract, ());
     let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_forfeit_bps(&admin, &5_000);
+    let (dispute_id, disputer, token_client) = lose_dispute(&env, &client, &contract_id, &admin);
+    assert_eq!(token_client.balance(&disputer), 4750);
 
-    let disputer = Address::generate(&env);
-    let token_admin = Address::generate(&env);
-    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
-
-    token_client.approve(&disputer, &contract_id, &500, &1000);
-    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+    let stats = client.get_dispute_stats();
+    assert_eq!(stats.total_refunded, 0);
+    assert_eq!(stats.total_forfeited, 0);
 
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
     env.ledger().set_timestamp(env.ledger().timestamp() + 200);
-    client.resolve_dispute(&dispute_id);
-    client.expire_dispute(&dispute_id);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    // Half of 1500 comes back in total, 250 of it after the first round.
+    assert_eq!(token_client.balance(&disputer), 5000 - 1500 + 750);
+    assert_eq!(client.get_reward_pool(&dispute_id).unwrap().amount, 750);
+    assert_eq!(token_client.balance(&contract_id), 750);
+    assert_eq!(client.get_dispute(&dispute_id).stake_returned, 750);
+
+    let stats = client.get_dispute_stats();
+    assert_eq!(stats.total_refunded, 750);
+    assert_eq!(stats.total_forfeited, 750);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #3)")]
-fn test_cannot_vote_on_expired_dispute() {
+fn test_partial_forfeit_won_appeal_returns_remainder() {
     let env = Env::default();
     env.mock_all_auths();
 
     let contract_id = env.register(DisputeContract, ());
     let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_forfeit_bps(&admin, &5_000);
+    let (dispute_id, disputer, token_client) = lose_dispute(&env, &client, &contract_id, &admin);
 
-    let disputer = Address::generate(&env);
-    let token_admin = Address::generate(&env);
-    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
-
-    token_client.approve(&disputer, &contract_id, &500, &1000);
-    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
-
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
     env.ledger().set_timestamp(env.ledger().timestamp() + 200);
-    client.expire_dispute(&dispute_id);
-    client.cast_vote(&Address::generate(&env), &dispute_id, &true);
-}
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
 
-// ── get_dispute_count ─────────────────────────────────────────────────────────
+    assert_eq!(token_client.balance(&disputer), 5000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(client.get_dispute_stats().total_refunded, 1500);
+}
 
 #[test]
-fn test_get_dispute_count_empty() {
+fn test_partial_forfeit_expired_appeal_keeps_first_round_split() {
     let env = Env::default();
+    env.mock_all_auths();
+
     let contract_id = env.register(DisputeContract, ());
     let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_forfeit_bps(&admin, &5_000);
+    let (dispute_id, disputer, token_client) = lose_dispute(&env, &client, &contract_id, &admin);
 
-    assert_eq!(client.get_dispute_count(), 0);
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.expire_dispute(&Address::generate(&env), &dispute_id);
+
+    assert_eq!(token_client.balance(&disputer), 4750);
+    assert_eq!(client.get_reward_pool(&dispute_id).unwrap().amount, 250);
+    assert_eq!(token_client.balance(&contract_id), 250);
+
+    let stats = client.get_dispute_stats();
+    assert_eq!(stats.total_refunded, 1250);
+    assert_eq!(stats.total_forfeited, 250);
 }
 
+// ── expire_dispute ────────────────────────────────────────────────────────────
+
 #[test]
-#[should_panic(expected = "Dispute not found")]
-fn test_get_dispute_not_found_panics() {
+fn test_expire_dispute_success() {
     let env = Env::default();
+    env.mock_all_auths();
+
     let contract_id = env.register(DisputeContract, ());
     let client = DisputeContractClient::new(&env, &contract_id);
 
-    client.get_dispute(&999);
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.expire_dispute(&Address::generate(&env), &dispute_id);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, DisputeStatus::Expired);
+    assert_eq!(token_client.balance(&disputer), 1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #4)")]
+fn test_expire_dispute_fails_before_deadline() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &3600);
+
+    client.expire_dispute(&Address::generate(&env), &dispute_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")]
+fn test_expire_dispute_fails_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    client.expire_dispute(&Address::generate(&env), &999);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_expire_already_resolved_dispute_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+    client.expire_dispute(&Address::generate(&env), &dispute_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+fn test_cannot_vote_on_expired_dispute() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.expire_dispute(&Address::generate(&env), &dispute_id);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+}
+
+#[test]
+fn test_expire_dispute_refunds_only_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.expire_dispute(&Address::generate(&env), &dispute_id);
+    assert_eq!(token_client.balance(&disputer), 1000);
+
+    assert_eq!(
+        client.try_expire_dispute(&Address::generate(&env), &dispute_id),
+        Err(Ok(Error::DisputeNotOpen))
+    );
+    assert_eq!(
+        client.try_resolve_dispute(&Address::generate(&env), &dispute_id),
+        Err(Ok(Error::DisputeNotOpen))
+    );
+    assert_eq!(token_client.balance(&disputer), 1000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+// ── cancel_dispute ────────────────────────────────────────────────────────────
+
+#[test]
+fn test_cancel_dispute_refunds_stake() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &3600);
+
+    client.cancel_dispute(&disputer, &dispute_id);
+
+    assert_eq!(
+        client.get_dispute(&dispute_id).status,
+        DisputeStatus::Cancelled
+    );
+    assert_eq!(token_client.balance(&disputer), 1000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_cancel_dispute_sends_fee_to_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let treasury_id = env.register(StubTreasury, ());
+    let treasury = StubTreasuryClient::new(&env, &treasury_id);
+    client.set_treasury(&admin, &treasury_id);
+    client.set_cancellation_fee_bps(&admin, &200);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &3600);
+
+    client.cancel_dispute(&disputer, &dispute_id);
+
+    assert_eq!(token_client.balance(&disputer), 990);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(token_client.balance(&treasury_id), 10);
+    assert_eq!(treasury.balance_by_source(&0), 10);
+}
+
+#[test]
+fn test_set_cancellation_fee_requires_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    assert_eq!(
+        client.try_set_cancellation_fee_bps(&admin, &200),
+        Err(Ok(Error::TreasuryNotSet))
+    );
+    client.set_cancellation_fee_bps(&admin, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+fn test_set_cancellation_fee_fails_above_denominator() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    client.set_cancellation_fee_bps(&admin, &(BPS_DENOMINATOR + 1));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+fn test_cancel_dispute_fails_with_votes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &3600);
+
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+    client.cancel_dispute(&disputer, &dispute_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_cancel_dispute_fails_not_disputer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    client.cancel_dispute(&Address::generate(&env), &dispute_id);
+}
+
+#[test]
+fn test_cancelled_dispute_rejects_votes_and_resolution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+
+    client.cancel_dispute(&disputer, &dispute_id);
+
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+    assert_eq!(
+        client.try_cast_vote(&arbitrator, &dispute_id, &true),
+        Err(Ok(Error::DisputeNotOpen))
+    );
+    assert_eq!(
+        client.try_cancel_dispute(&disputer, &dispute_id),
+        Err(Ok(Error::DisputeNotOpen))
+    );
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    assert_eq!(
+        client.try_resolve_dispute(&Address::generate(&env), &dispute_id),
+        Err(Ok(Error::DisputeNotOpen))
+    );
+    assert_eq!(
+        client.try_expire_dispute(&Address::generate(&env), &dispute_id),
+        Err(Ok(Error::DisputeNotOpen))
+    );
+}
+
+// ── evidence ──────────────────────────────────────────────────────────────────
+
+fn open_dispute_by(
+    env: &Env,
+    client: &DisputeContractClient,
+    contract_id: &Address,
+) -> (u64, Address) {
+    let disputer = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let (token_id, _, token_client) = setup_token(env, &token_admin, &disputer, 1000);
+
+    token_client.approve(&disputer, contract_id, &500, &1000);
+    let slash_request_id = client.get_dispute_count() + 1;
+    let dispute_id = client.create_dispute(&disputer, &slash_request_id, &500, &token_id, &100);
+    (dispute_id, disputer)
+}
+
+#[test]
+fn test_submit_evidence_disputer_and_slasher() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, disputer) = open_dispute_by(&env, &client, &contract_id);
+    let slasher = Address::generate(&env);
+    client.set_dispute_slasher(&admin, &dispute_id, &slasher);
+
+    let hash = BytesN::from_array(&env, &[1u8; 32]);
+    let uri = String::from_str(&env, "ipfs://disputer");
+    assert_eq!(
+        client.submit_evidence(&disputer, &dispute_id, &hash, &uri),
+        0
+    );
+    assert_eq!(
+        client.submit_evidence(
+            &slasher,
+            &dispute_id,
+            &BytesN::from_array(&env, &[2u8; 32]),
+            &String::from_str(&env, "ipfs://slasher"),
+        ),
+        1
+    );
+
+    assert_eq!(client.get_evidence_count(&dispute_id), 2);
+    let evidence = client.get_evidence(&dispute_id, &0);
+    assert_eq!(evidence.submitter, disputer);
+    assert_eq!(evidence.evidence_hash, hash);
+    assert_eq!(evidence.uri, uri);
+    assert_eq!(client.get_evidence(&dispute_id, &1).submitter, slasher);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_submit_evidence_fails_third_party() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let (dispute_id, _) = open_dispute_by(&env, &client, &contract_id);
+
+    client.submit_evidence(
+        &Address::generate(&env),
+        &dispute_id,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &String::from_str(&env, "ipfs://x"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")]
+fn test_submit_evidence_fails_after_deadline() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let (dispute_id, disputer) = open_dispute_by(&env, &client, &contract_id);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.submit_evidence(
+        &disputer,
+        &dispute_id,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &String::from_str(&env, "ipfs://x"),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #22)")]
+fn test_submit_evidence_fails_empty_uri() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let (dispute_id, disputer) = open_dispute_by(&env, &client, &contract_id);
+
+    client.submit_evidence(
+        &disputer,
+        &dispute_id,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &String::from_str(&env, ""),
+    );
+}
+
+#[test]
+fn test_submit_evidence_cap_is_per_side() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, disputer) = open_dispute_by(&env, &client, &contract_id);
+    let slasher = Address::generate(&env);
+    client.set_dispute_slasher(&admin, &dispute_id, &slasher);
+
+    let hash = BytesN::from_array(&env, &[1u8; 32]);
+    let uri = String::from_str(&env, "ipfs://x");
+    for _ in 0..MAX_EVIDENCE_PER_SIDE {
+        client.submit_evidence(&disputer, &dispute_id, &hash, &uri);
+    }
+    assert_eq!(
+        client.try_submit_evidence(&disputer, &dispute_id, &hash, &uri),
+        Err(Ok(Error::EvidenceLimitReached))
+    );
+
+    client.submit_evidence(&slasher, &dispute_id, &hash, &uri);
+    assert_eq!(
+        client.get_evidence_count(&dispute_id),
+        MAX_EVIDENCE_PER_SIDE + 1
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+fn test_get_evidence_fails_not_found() {
+    let env = Env::default();
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    client.get_evidence(&1, &0);
+}
+
+// ── appeal_dispute ────────────────────────────────────────────────────────────
+
+/// Open a 500-stake dispute and resolve it in favor of the slasher.
+fn lose_dispute<'a>(
+    env: &'a Env,
+    client: &DisputeContractClient,
+    contract_id: &Address,
+    admin: &Address,
+) -> (u64, Address, soroban_sdk::token::Client<'a>) {
+    let disputer = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let (token_id, _, token_client) = setup_token(env, &token_admin, &disputer, 5000);
+
+    token_client.approve(&disputer, contract_id, &5000, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    client.cast_vote(&new_arbitrator(env, client, admin), &dispute_id, &false);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(env), &dispute_id);
+    (dispute_id, disputer, token_client)
+}
+
+#[test]
+fn test_appeal_reopens_voting_and_preserves_first_round() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, disputer, token_client) = lose_dispute(&env, &client, &contract_id, &admin);
+
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, DisputeStatus::UnderAppeal);
+    assert_eq!(dispute.outcome, DisputeOutcome::None);
+    assert_eq!(dispute.votes_for_slasher, 0);
+    assert_eq!(dispute.count_for_slasher, 0);
+    assert_eq!(dispute.deadline, env.ledger().timestamp() + 100);
+    assert_eq!(dispute.appeal_stake, 1000);
+    assert!(dispute.appealed);
+    assert_eq!(token_client.balance(&contract_id), 1500);
+
+    let record = client.get_appeal_record(&dispute_id);
+    assert_eq!(record.appellant, disputer);
+    assert_eq!(record.outcome, DisputeOutcome::FavorSlasher);
+    assert_eq!(record.votes_for_slasher, 1);
+    assert_eq!(record.count_for_slasher, 1);
+}
+
+#[test]
+fn test_appeal_won_refunds_both_stakes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, disputer, token_client) = lose_dispute(&env, &client, &contract_id, &admin);
+
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, DisputeStatus::Resolved);
+    assert_eq!(dispute.outcome, DisputeOutcome::FavorDisputer);
+    assert_eq!(token_client.balance(&disputer), 5000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_appeal_lost_forfeits_both_stakes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, disputer, token_client) = lose_dispute(&env, &client, &contract_id, &admin);
+
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, DisputeStatus::Resolved);
+    assert_eq!(dispute.outcome, DisputeOutcome::FavorSlasher);
+    assert_eq!(token_client.balance(&disputer), 3500);
+    assert_eq!(token_client.balance(&contract_id), 1500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+fn test_second_appeal_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, disputer, _) = lose_dispute(&env, &client, &contract_id, &admin);
+
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+}
+
+#[test]
+fn test_first_round_arbitrator_can_vote_in_appeal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 5000);
+    token_client.approve(&disputer, &contract_id, &5000, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    client.cast_vote(&arbitrator, &dispute_id, &false);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+    assert!(!client.has_voted(&dispute_id, &arbitrator));
+
+    client.cast_vote(&arbitrator, &dispute_id, &true);
+    assert!(client.has_voted(&dispute_id, &arbitrator));
+    assert_eq!(
+        client.try_cast_vote(&arbitrator, &dispute_id, &true),
+        Err(Ok(Error::AlreadyVoted))
+    );
+}
+
+#[test]
+fn test_appeal_without_quorum_expires_refunding_appeal_stake() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, disputer, token_client) = lose_dispute(&env, &client, &contract_id, &admin);
+
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.expire_dispute(&Address::generate(&env), &dispute_id);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, DisputeStatus::Expired);
+    assert_eq!(dispute.outcome, DisputeOutcome::FavorSlasher);
+    assert_eq!(token_client.balance(&disputer), 4500);
+    assert_eq!(token_client.balance(&contract_id), 500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+fn test_appeal_fails_insufficient_stake() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, disputer, _) = lose_dispute(&env, &client, &contract_id, &admin);
+
+    client.appeal_dispute(&disputer, &dispute_id, &999);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")]
+fn test_appeal_fails_after_window() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, disputer, _) = lose_dispute(&env, &client, &contract_id, &admin);
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + DEFAULT_APPEAL_WINDOW_SECS + 1);
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_appeal_fails_not_disputer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, _, _) = lose_dispute(&env, &client, &contract_id, &admin);
+
+    client.appeal_dispute(&Address::generate(&env), &dispute_id, &1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")]
+fn test_appeal_fails_when_disputer_won() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 5000);
+    token_client.approve(&disputer, &contract_id, &5000, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")]
+fn test_appeal_fails_while_open() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let (dispute_id, disputer) = open_dispute_by(&env, &client, &contract_id);
+
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+}
+
+#[test]
+fn test_appeal_window_default_and_update() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    assert_eq!(client.get_appeal_window(), DEFAULT_APPEAL_WINDOW_SECS);
+    client.set_appeal_window(&admin, &3600);
+    assert_eq!(client.get_appeal_window(), 3600);
+    assert_eq!(
+        client.try_set_appeal_window(&admin, &0),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        client.try_set_appeal_window(&admin, &(MAX_APPEAL_WINDOW_SECS + 1)),
+        Err(Ok(Error::InvalidDeadline))
+    );
+}
+
+// ── min stake ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_min_stake_falls_back_to_constant() {
+    let env = Env::default();
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.get_min_stake(&Address::generate(&env)), MIN_STAKE);
+}
+
+#[test]
+fn test_per_token_min_stake_overrides_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let token_a = Address::generate(&env);
+    let token_b = Address::generate(&env);
+
+    client.set_default_min_stake(&admin, &50);
+    client.set_min_stake(&admin, &token_a, &1_000_000);
+
+    assert_eq!(client.get_min_stake(&token_a), 1_000_000);
+    assert_eq!(client.get_min_stake(&token_b), 50);
+}
+
+#[test]
+fn test_create_dispute_enforces_per_token_min_stake() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 10_000);
+    token_client.approve(&disputer, &contract_id, &10_000, &1000);
+
+    client.set_min_stake(&admin, &token_id, &5_000);
+    assert_eq!(
+        client.try_create_dispute(&disputer, &1, &500, &token_id, &3600),
+        Err(Ok(Error::InsufficientStake))
+    );
+    client.create_dispute(&disputer, &1, &5_000, &token_id, &3600);
+}
+
+#[test]
+fn test_lower_default_min_stake_allows_small_dispute() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_default_min_stake(&admin, &10);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &10, &1000);
+
+    let dispute_id = client.create_dispute(&disputer, &1, &10, &token_id, &3600);
+    assert_eq!(client.get_dispute(&dispute_id).stake, 10);
+}
+
+#[test]
+fn test_raising_min_stake_does_not_affect_open_dispute() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+
+    client.set_min_stake(&admin, &token_id, &10_000);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.stake, 500);
+    assert_eq!(dispute.outcome, DisputeOutcome::FavorDisputer);
+    assert_eq!(token_client.balance(&disputer), 1000);
+}
+
+#[test]
+fn test_set_min_stake_rejects_non_positive() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    assert_eq!(
+        client.try_set_min_stake(&admin, &Address::generate(&env), &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_default_min_stake(&admin, &-1),
+        Err(Ok(Error::InvalidAmount))
+    );
+}
+
+// ── stake token allowlist ────────────────────────────────────────────────────
+
+#[test]
+fn test_any_stake_token_allowed_until_allowlist_used() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let token = Address::generate(&env);
+
+    assert!(client.is_stake_token_allowed(&token));
+    client.disallow_stake_token(&admin, &token);
+    assert!(client.is_stake_token_allowed(&token));
+
+    let (dispute_id, _) = open_dispute_by(&env, &client, &contract_id);
+    assert_eq!(client.get_dispute(&dispute_id).stake, 500);
+}
+
+#[test]
+fn test_create_dispute_rejects_token_off_allowlist() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &1000, &1000);
+
+    let other_token = Address::generate(&env);
+    client.allow_stake_token(&admin, &other_token);
+    assert!(client.is_stake_token_allowed(&other_token));
+    assert!(!client.is_stake_token_allowed(&token_id));
+    assert_eq!(
+        client.try_create_dispute(&disputer, &1, &500, &token_id, &3600),
+        Err(Ok(Error::TokenNotAllowed))
+    );
+
+    client.allow_stake_token(&admin, &token_id);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &3600);
+    assert_eq!(client.get_dispute(&dispute_id).token, token_id);
+}
+
+#[test]
+fn test_disallowed_token_dispute_still_settles() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &1000, &1000);
+
+    client.allow_stake_token(&admin, &token_id);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    client.disallow_stake_token(&admin, &token_id);
+    assert!(!client.is_stake_token_allowed(&token_id));
+
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    assert_eq!(
+        client.get_dispute(&dispute_id).outcome,
+        DisputeOutcome::FavorDisputer
+    );
+    assert_eq!(token_client.balance(&disputer), 1000);
+    assert_eq!(
+        client.try_create_dispute(&disputer, &2, &500, &token_id, &100),
+        Err(Ok(Error::TokenNotAllowed))
+    );
+}
+
+#[test]
+fn test_stake_allowlist_requires_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let token = Address::generate(&env);
+    assert_eq!(
+        client.try_allow_stake_token(&Address::generate(&env), &token),
+        Err(Ok(Error::NotInitialized))
+    );
+
+    setup_admin(&env, &client);
+    let stranger = Address::generate(&env);
+    assert_eq!(
+        client.try_allow_stake_token(&stranger, &token),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_disallow_stake_token(&stranger, &token),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+// ── arbitrator rewards ────────────────────────────────────────────────────────
+
+#[test]
+fn test_rewards_split_pro_rata_without_exceeding_stake() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    let heavy = new_arbitrator(&env, &client, &admin);
+    client.set_arbitrator_weight(&admin, &heavy, &2);
+    let light = new_arbitrator(&env, &client, &admin);
+    let loser = new_arbitrator(&env, &client, &admin);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+
+    client.cast_vote(&heavy, &dispute_id, &false);
+    client.cast_vote(&light, &dispute_id, &false);
+    client.cast_vote(&loser, &dispute_id, &true);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    let pool = client.get_reward_pool(&dispute_id).unwrap();
+    assert_eq!(pool.amount, 500);
+    assert_eq!(pool.total_weight, 3);
+
+    assert_eq!(
+        client.try_claim_arbitrator_reward(&heavy, &dispute_id),
+        Err(Ok(Error::RewardLocked))
+    );
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + DEFAULT_APPEAL_WINDOW_SECS + 1);
+    assert_eq!(client.claim_arbitrator_reward(&heavy, &dispute_id), 333);
+    assert_eq!(client.claim_arbitrator_reward(&light, &dispute_id), 166);
+    assert_eq!(
+        client.try_claim_arbitrator_reward(&loser, &dispute_id),
+        Err(Ok(Error::NoReward))
+    );
+    assert_eq!(
+        client.try_claim_arbitrator_reward(&light, &dispute_id),
+        Err(Ok(Error::AlreadyClaimed))
+    );
+
+    let pool = client.get_reward_pool(&dispute_id).unwrap();
+    assert_eq!(pool.claimed, 499);
+    assert!(pool.claimed <= pool.amount);
+    assert_eq!(token_client.balance(&heavy), 333);
+    assert_eq!(token_client.balance(&light), 166);
+    assert_eq!(token_client.balance(&contract_id), 1);
+}
+
+#[test]
+fn test_no_reward_when_disputer_wins() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    client.cast_vote(&arbitrator, &dispute_id, &true);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    assert_eq!(client.get_reward_pool(&dispute_id), None);
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + DEFAULT_APPEAL_WINDOW_SECS + 1);
+    assert_eq!(
+        client.try_claim_arbitrator_reward(&arbitrator, &dispute_id),
+        Err(Ok(Error::NoReward))
+    );
+}
+
+#[test]
+fn test_lost_appeal_rewards_appeal_round_voters() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let first_round = new_arbitrator(&env, &client, &admin);
+    let appeal_round = new_arbitrator(&env, &client, &admin);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 5000);
+    token_client.approve(&disputer, &contract_id, &5000, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    client.cast_vote(&first_round, &dispute_id, &false);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+    assert_eq!(client.get_reward_pool(&dispute_id), None);
+
+    client.cast_vote(&appeal_round, &dispute_id, &false);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    assert_eq!(
+        client.claim_arbitrator_reward(&appeal_round, &dispute_id),
+        1500
+    );
+    assert_eq!(
+        client.try_claim_arbitrator_reward(&first_round, &dispute_id),
+        Err(Ok(Error::NoReward))
+    );
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_expired_appeal_rewards_first_round_voters() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 5000);
+    token_client.approve(&disputer, &contract_id, &5000, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    client.cast_vote(&arbitrator, &dispute_id, &false);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.expire_dispute(&Address::generate(&env), &dispute_id);
+
+    assert_eq!(
+        client.claim_arbitrator_reward(&arbitrator, &dispute_id),
+        500
+    );
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_set_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let treasury = Address::generate(&env);
+
+    assert_eq!(client.get_treasury(), None);
+    client.set_treasury(&admin, &treasury);
+    assert_eq!(client.get_treasury(), Some(treasury));
+}
+
+// ── sweep_forfeited ───────────────────────────────────────────────────────────
+
+/// Records `receive_fee` credits the way `credence_treasury` does.
+#[contract]
+pub struct StubTreasury;
+
+#[contractimpl]
+impl StubTreasury {
+    pub fn receive_fee(env: Env, from: Address, amount: i128, source: u32) {
+        from.require_auth();
+        let total: i128 = env.storage().instance().get(&source).unwrap_or(0);
+        env.storage().instance().set(&source, &(total + amount));
+    }
+
+    pub fn balance_by_source(env: Env, source: u32) -> i128 {
+        env.storage().instance().get(&source).unwrap_or(0)
+    }
+}
+
+/// Resolve a 500-stake dispute for the slasher with two weight-1 voters.
+fn forfeit_dispute<'a>(
+    env: &'a Env,
+    client: &DisputeContractClient,
+    contract_id: &Address,
+    admin: &Address,
+) -> (u64, Address, soroban_sdk::token::Client<'a>) {
+    let arbitrator = new_arbitrator(env, client, admin);
+    let disputer = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let (token_id, _, token_client) = setup_token(env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+
+    client.cast_vote(&arbitrator, &dispute_id, &false);
+    client.cast_vote(&new_arbitrator(env, client, admin), &dispute_id, &false);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(env), &dispute_id);
+    (dispute_id, arbitrator, token_client)
+}
+
+#[test]
+fn test_sweep_forfeited_sends_unclaimed_to_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let treasury_id = env.register(StubTreasury, ());
+    let treasury = StubTreasuryClient::new(&env, &treasury_id);
+    client.set_treasury(&admin, &treasury_id);
+
+    let (dispute_id, arbitrator, token_client) =
+        forfeit_dispute(&env, &client, &contract_id, &admin);
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + DEFAULT_APPEAL_WINDOW_SECS + 1);
+    assert_eq!(
+        client.claim_arbitrator_reward(&arbitrator, &dispute_id),
+        250
+    );
+    assert_eq!(
+        client.try_sweep_forfeited(&admin, &dispute_id),
+        Err(Ok(Error::RewardLocked))
+    );
+
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + DEFAULT_CLAIM_WINDOW_SECS);
+    assert_eq!(client.sweep_forfeited(&admin, &dispute_id), 250);
+
+    assert_eq!(token_client.balance(&treasury_id), 250);
+    assert_eq!(token_client.balance(&arbitrator), 250);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(treasury.balance_by_source(&1), 250);
+    assert!(client.get_reward_pool(&dispute_id).unwrap().swept);
+}
+
+#[test]
+fn test_sweep_forfeited_twice_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let treasury_id = env.register(StubTreasury, ());
+    client.set_treasury(&admin, &treasury_id);
+
+    let (dispute_id, arbitrator, token_client) =
+        forfeit_dispute(&env, &client, &contract_id, &admin);
+
+    env.ledger().set_timestamp(
+        env.ledger().timestamp() + DEFAULT_APPEAL_WINDOW_SECS + DEFAULT_CLAIM_WINDOW_SECS + 1,
+    );
+    assert_eq!(client.sweep_forfeited(&admin, &dispute_id), 500);
+    assert_eq!(
+        client.try_sweep_forfeited(&admin, &dispute_id),
+        Err(Ok(Error::AlreadySwept))
+    );
+    assert_eq!(
+        client.try_claim_arbitrator_reward(&arbitrator, &dispute_id),
+        Err(Ok(Error::AlreadySwept))
+    );
+    assert_eq!(token_client.balance(&treasury_id), 500);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_sweep_forfeited_fails_without_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, _, _) = forfeit_dispute(&env, &client, &contract_id, &admin);
+
+    assert_eq!(
+        client.try_sweep_forfeited(&admin, &dispute_id),
+        Err(Ok(Error::TreasuryNotSet))
+    );
+}
+
+#[test]
+fn test_sweep_forfeited_fails_when_disputer_won() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_treasury(&admin, &env.register(StubTreasury, ()));
+
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    assert_eq!(
+        client.try_sweep_forfeited(&admin, &dispute_id),
+        Err(Ok(Error::NoReward))
+    );
+}
+
+#[test]
+fn test_claim_window_default_and_update() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    assert_eq!(client.get_claim_window(), DEFAULT_CLAIM_WINDOW_SECS);
+    client.set_claim_window(&admin, &3600);
+    assert_eq!(client.get_claim_window(), 3600);
+    assert_eq!(
+        client.try_set_claim_window(&admin, &0),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        client.try_set_claim_window(&admin, &(MAX_CLAIM_WINDOW_SECS + 1)),
+        Err(Ok(Error::InvalidDeadline))
+    );
+}
+
+// ── anti-sniping ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_late_vote_extends_deadline_and_blocks_resolution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_anti_snipe_config(&admin, &60, &300, &2);
+
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    let deadline = client.get_dispute(&dispute_id).deadline;
+
+    env.ledger().set_timestamp(deadline - 10);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.deadline, deadline + 300);
+    assert_eq!(dispute.snipe_extensions, 1);
+
+    env.ledger().set_timestamp(deadline + 1);
+    assert_eq!(
+        client.try_resolve_dispute(&Address::generate(&env), &dispute_id),
+        Err(Ok(Error::DeadlineNotReached))
+    );
+    assert_eq!(
+        client.try_expire_dispute(&Address::generate(&env), &dispute_id),
+        Err(Ok(Error::DeadlineNotReached))
+    );
+
+    env.ledger().set_timestamp(deadline + 301);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+    assert_eq!(
+        client.get_dispute(&dispute_id).status,
+        DisputeStatus::Resolved
+    );
+}
+
+#[test]
+fn test_early_vote_does_not_extend_deadline() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_anti_snipe_config(&admin, &10, &300, &2);
+
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    let deadline = client.get_dispute(&dispute_id).deadline;
+
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.deadline, deadline);
+    assert_eq!(dispute.snipe_extensions, 0);
+}
+
+#[test]
+fn test_late_vote_extensions_capped() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_anti_snipe_config(&admin, &60, &30, &2);
+
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    let deadline = client.get_dispute(&dispute_id).deadline;
+
+    env.ledger().set_timestamp(deadline - 10);
+    for _ in 0..3 {
+        client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+    }
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.snipe_extensions, 2);
+    assert_eq!(dispute.deadline, deadline + 60);
+}
+
+#[test]
+fn test_anti_snipe_disabled_by_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    assert_eq!(client.get_anti_snipe_config().max_extensions, 0);
+
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    let deadline = client.get_dispute(&dispute_id).deadline;
+    env.ledger().set_timestamp(deadline - 1);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+
+    assert_eq!(client.get_dispute(&dispute_id).deadline, deadline);
+}
+
+#[test]
+fn test_set_anti_snipe_config_rejects_zero_extension() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    assert_eq!(
+        client.try_set_anti_snipe_config(&admin, &60, &0, &1),
+        Err(Ok(Error::InvalidDeadline))
+    );
+}
+
+#[test]
+fn test_set_anti_snipe_config_rejects_oversized_extension() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    assert_eq!(
+        client.try_set_anti_snipe_config(&admin, &60, &u64::MAX, &1),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        client.try_set_tie_break_rule(&admin, &TieBreakRule::ExtendDeadline(u64::MAX)),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    client.set_anti_snipe_config(&admin, &60, &MAX_DEADLINE_EXTENSION_SECS, &1);
+}
+
+// ── indexes ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_disputes_indexed_by_disputer_with_pagination() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 2000);
+    token_client.approve(&disputer, &contract_id, &1500, &1000);
+
+    let first = client.create_dispute(&disputer, &1, &500, &token_id, &3600);
+    open_dispute(&env, &client, &contract_id);
+    let second = client.create_dispute(&disputer, &3, &500, &token_id, &3600);
+    let third = client.create_dispute(&disputer, &4, &500, &token_id, &3600);
+
+    let all = client.get_disputes_by_disputer(&disputer, &0, &10);
+    assert_eq!(all, soroban_sdk::vec![&env, first, second, third]);
+    assert_eq!(
+        client.get_disputes_by_disputer(&disputer, &1, &1),
+        soroban_sdk::vec![&env, second]
+    );
+    assert_eq!(client.get_disputes_by_disputer(&disputer, &3, &10).len(), 0);
+    assert_eq!(
+        client
+            .get_disputes_by_disputer(&Address::generate(&env), &0, &10)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_dispute_for_slash_request() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.get_dispute_for_slash_request(&1), None);
+    let (dispute_id, _) = open_dispute_by(&env, &client, &contract_id);
+    assert_eq!(client.get_dispute_for_slash_request(&1), Some(dispute_id));
+}
+
+#[test]
+fn test_duplicate_dispute_rejected_while_open() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 2000);
+    token_client.approve(&disputer, &contract_id, &1000, &1000);
+
+    client.create_dispute(&disputer, &7, &500, &token_id, &3600);
+    assert_eq!(
+        client.try_create_dispute(&disputer, &7, &500, &token_id, &3600),
+        Err(Ok(Error::DuplicateDispute))
+    );
+}
+
+#[test]
+fn test_new_dispute_allowed_after_previous_closed() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 2000);
+    token_client.approve(&disputer, &contract_id, &1000, &1000);
+
+    let first = client.create_dispute(&disputer, &7, &500, &token_id, &3600);
+    client.cancel_dispute(&disputer, &first);
+
+    let second = client.create_dispute(&disputer, &7, &500, &token_id, &3600);
+    assert_eq!(client.get_dispute_for_slash_request(&7), Some(second));
+}
+
+#[test]
+fn test_open_disputes_paged_after_resolving_some() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    let mut ids = soroban_sdk::vec![&env];
+    for _ in 0..5 {
+        ids.push_back(open_dispute(&env, &client, &contract_id));
+    }
+    assert_eq!(client.get_open_dispute_count(), 5);
+
+    // Resolve the second and fourth disputes out of creation order.
+    for id in [ids.get(3).unwrap(), ids.get(1).unwrap()] {
+        client.cast_vote(&new_arbitrator(&env, &client, &admin), &id, &true);
+    }
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &ids.get(3).unwrap());
+    client.resolve_dispute(&Address::generate(&env), &ids.get(1).unwrap());
+
+    assert_eq!(client.get_open_dispute_count(), 3);
+    let mut seen = client.get_open_disputes(&0, &2);
+    assert_eq!(seen.len(), 2);
+    seen.append(&client.get_open_disputes(&2, &2));
+    assert_eq!(seen.len(), 3);
+    for id in [
+        ids.get(0).unwrap(),
+        ids.get(2).unwrap(),
+        ids.get(4).unwrap(),
+    ] {
+        assert!(seen.contains(id));
+    }
+    assert_eq!(client.get_open_disputes(&3, &2).len(), 0);
+}
+
+#[test]
+fn test_open_disputes_track_cancel_expire_and_appeal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    let (cancelled, disputer) = open_dispute_by(&env, &client, &contract_id);
+    let expired = open_dispute(&env, &client, &contract_id);
+    client.cancel_dispute(&disputer, &cancelled);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.expire_dispute(&Address::generate(&env), &expired);
+    assert_eq!(client.get_open_dispute_count(), 0);
+
+    let (appealed, appellant, _) = lose_dispute(&env, &client, &contract_id, &admin);
+    assert_eq!(client.get_open_dispute_count(), 0);
+    client.appeal_dispute(&appellant, &appealed, &1000);
+    assert_eq!(
+        client.get_open_disputes(&0, &10),
+        soroban_sdk::vec![&env, appealed]
+    );
+}
+
+// ── resolver fee ──────────────────────────────────────────────────────────────
+
+#[test]
+fn test_resolver_fee_defaults_to_none() {
+    let env = Env::default();
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    assert_eq!(client.get_resolver_fee(), ResolverFee::None);
+}
+
+#[test]
+fn test_set_resolver_fee_validates_config() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    assert_eq!(
+        client.try_set_resolver_fee(&admin, &ResolverFee::Flat(-1)),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_resolver_fee(&admin, &ResolverFee::Bps(10_001)),
+        Err(Ok(Error::InvalidFee))
+    );
+    assert_eq!(
+        client.try_set_resolver_fee(&Address::generate(&env), &ResolverFee::Bps(100)),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.set_resolver_fee(&admin, &ResolverFee::Bps(10_000));
+    assert_eq!(client.get_resolver_fee(), ResolverFee::Bps(10_000));
+}
+
+#[test]
+fn test_resolver_fee_bps_deducted_from_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_resolver_fee(&admin, &ResolverFee::Bps(200));
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    let resolver = Address::generate(&env);
+    client.resolve_dispute(&resolver, &dispute_id);
+
+    assert_eq!(token_client.balance(&resolver), 10);
+    assert_eq!(token_client.balance(&disputer), 990);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_resolver_fee_flat_deducted_from_forfeit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_resolver_fee(&admin, &ResolverFee::Flat(25));
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    let resolver = Address::generate(&env);
+    client.resolve_dispute(&resolver, &dispute_id);
+
+    assert_eq!(token_client.balance(&resolver), 25);
+    assert_eq!(token_client.balance(&disputer), 500);
+    assert_eq!(token_client.balance(&contract_id), 475);
+    assert_eq!(client.get_reward_pool(&dispute_id).unwrap().amount, 475);
+}
+
+#[test]
+fn test_resolver_fee_capped_at_tiny_stake() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_default_min_stake(&admin, &1);
+    client.set_resolver_fee(&admin, &ResolverFee::Flat(50));
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 10);
+    token_client.approve(&disputer, &contract_id, &3, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &3, &token_id, &100);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    let resolver = Address::generate(&env);
+    client.resolve_dispute(&resolver, &dispute_id);
+
+    assert_eq!(token_client.balance(&resolver), 3);
+    assert_eq!(token_client.balance(&disputer), 7);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_resolver_fee_bps_rounds_down_on_tiny_stake() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_default_min_stake(&admin, &1);
+    client.set_resolver_fee(&admin, &ResolverFee::Bps(100));
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 10);
+    token_client.approve(&disputer, &contract_id, &1, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &1, &token_id, &100);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    let resolver = Address::generate(&env);
+    client.resolve_dispute(&resolver, &dispute_id);
+
+    assert_eq!(token_client.balance(&resolver), 0);
+    assert_eq!(token_client.balance(&disputer), 10);
+}
+
+#[test]
+fn test_resolver_fee_not_paid_on_expire() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_resolver_fee(&admin, &ResolverFee::Flat(40));
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    let resolver = Address::generate(&env);
+    client.expire_dispute(&resolver, &dispute_id);
+
+    assert_eq!(token_client.balance(&resolver), 0);
+    assert_eq!(token_client.balance(&disputer), 1000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+/// Dispute with a flat resolver fee of 100 whose first round the disputer
+/// lost, then appealed with 1000 more; returns the first-round resolver too.
+fn lose_and_appeal_with_fee<'a>(
+    env: &'a Env,
+    client: &DisputeContractClient,
+    contract_id: &Address,
+    admin: &Address,
+) -> (u64, Address, Address, soroban_sdk::token::Client<'a>) {
+    client.set_resolver_fee(admin, &ResolverFee::Flat(100));
+    let disputer = Address::generate(env);
+    let token_admin = Address::generate(env);
+    let (token_id, _, token_client) = setup_token(env, &token_admin, &disputer, 5000);
+    token_client.approve(&disputer, contract_id, &5000, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    client.cast_vote(&new_arbitrator(env, client, admin), &dispute_id, &false);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    let first_resolver = Address::generate(env);
+    client.resolve_dispute(&first_resolver, &dispute_id);
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+    assert_eq!(token_client.balance(contract_id), 1400);
+    (dispute_id, disputer, first_resolver, token_client)
+}
+
+#[test]
+fn test_resolver_fee_appeal_won_leaves_contract_empty() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, disputer, first_resolver, token_client) =
+        lose_and_appeal_with_fee(&env, &client, &contract_id, &admin);
+
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    let second_resolver = Address::generate(&env);
+    client.resolve_dispute(&second_resolver, &dispute_id);
+
+    assert_eq!(token_client.balance(&first_resolver), 100);
+    assert_eq!(token_client.balance(&second_resolver), 100);
+    assert_eq!(token_client.balance(&disputer), 4800);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    assert_eq!(client.get_dispute(&dispute_id).fees_paid, 200);
+}
+
+#[test]
+fn test_resolver_fee_appeal_lost_pool_matches_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, _, _, token_client) =
+        lose_and_appeal_with_fee(&env, &client, &contract_id, &admin);
+
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    let pool = client.get_reward_pool(&dispute_id).unwrap();
+    assert_eq!(pool.amount, 1300);
+    assert_eq!(token_client.balance(&contract_id), pool.amount);
+}
+
+#[test]
+fn test_resolver_fee_not_charged_twice_on_appeal_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, disputer, _, token_client) =
+        lose_and_appeal_with_fee(&env, &client, &contract_id, &admin);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    let expirer = Address::generate(&env);
+    client.expire_dispute(&expirer, &dispute_id);
+
+    let pool = client.get_reward_pool(&dispute_id).unwrap();
+    assert_eq!(token_client.balance(&expirer), 0);
+    assert_eq!(token_client.balance(&disputer), 4500);
+    assert_eq!(pool.amount, 400);
+    assert_eq!(token_client.balance(&contract_id), pool.amount);
+}
+
+#[test]
+fn test_resolver_fee_not_paid_on_failed_resolve() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_resolver_fee(&admin, &ResolverFee::Flat(40));
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+
+    let resolver = Address::generate(&env);
+    assert_eq!(
+        client.try_resolve_dispute(&resolver, &dispute_id),
+        Err(Ok(Error::DeadlineNotReached))
+    );
+    assert_eq!(token_client.balance(&resolver), 0);
+    assert_eq!(token_client.balance(&contract_id), 500);
+}
+
+// ── admin ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_get_admin_uninitialized() {
+    let env = Env::default();
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.try_get_admin(), Err(Ok(Error::NotInitialized)));
+    assert_eq!(client.get_pending_admin(), None);
+}
+
+#[test]
+fn test_uninitialized_contract_uses_defaults() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &MIN_STAKE, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &MIN_STAKE, &token_id, &100);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.expire_dispute(&Address::generate(&env), &dispute_id);
+
+    assert_eq!(token_client.balance(&disputer), 1000);
+    assert_eq!(client.get_min_votes_to_resolve(), 1);
+    assert_eq!(client.get_resolver_fee(), ResolverFee::None);
+}
+
+#[test]
+fn test_transfer_admin_two_step() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    assert_eq!(client.get_admin(), admin);
+
+    let new_admin = Address::generate(&env);
+    client.transfer_admin(&admin, &new_admin);
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    client.accept_admin(&new_admin);
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+
+    assert_eq!(
+        client.try_set_min_votes_to_resolve(&admin, &2),
+        Err(Ok(Error::Unauthorized))
+    );
+    client.set_min_votes_to_resolve(&new_admin, &2);
+    assert_eq!(client.get_min_votes_to_resolve(), 2);
+}
+
+#[test]
+fn test_transfer_admin_requires_current_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    let outsider = Address::generate(&env);
+    assert_eq!(
+        client.try_transfer_admin(&outsider, &outsider),
+        Err(Ok(Error::NotInitialized))
+    );
+
+    setup_admin(&env, &client);
+    assert_eq!(
+        client.try_transfer_admin(&outsider, &outsider),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_accept_admin_rejects_wrong_caller() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let nominee = Address::generate(&env);
+    let outsider = Address::generate(&env);
+
+    assert_eq!(
+        client.try_accept_admin(&nominee),
+        Err(Ok(Error::NoPendingAdmin))
+    );
+
+    client.transfer_admin(&admin, &nominee);
+    assert_eq!(
+        client.try_accept_admin(&outsider),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.get_admin(), admin);
+}
+
+// ── panels ────────────────────────────────────────────────────────────────────
+
+fn new_panel(
+    env: &Env,
+    client: &DisputeContractClient,
+    admin: &Address,
+    size: u32,
+) -> Vec<Address> {
+    let mut panel = Vec::new(env);
+    for _ in 0..size {
+        panel.push_back(new_arbitrator(env, client, admin));
+    }
+    panel
+}
+
+#[test]
+fn test_assign_panel_restricts_voters() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+
+    assert_eq!(client.get_panel(&dispute_id), None);
+    let panel = new_panel(&env, &client, &admin, 3);
+    client.assign_panel(&admin, &dispute_id, &panel);
+
+    assert_eq!(client.get_panel(&dispute_id), Some(panel.clone()));
+    assert_eq!(client.get_dispute(&dispute_id).panel_size, 3);
+
+    let outsider = new_arbitrator(&env, &client, &admin);
+    assert_eq!(
+        client.try_cast_vote(&outsider, &dispute_id, &true),
+        Err(Ok(Error::NotOnPanel))
+    );
+    client.cast_vote(&panel.get(0).unwrap(), &dispute_id, &true);
+}
+
+#[test]
+fn test_panel_quorum_is_majority() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+
+    let panel = new_panel(&env, &client, &admin, 5);
+    client.assign_panel(&admin, &dispute_id, &panel);
+
+    client.cast_vote(&panel.get(0).unwrap(), &dispute_id, &true);
+    client.cast_vote(&panel.get(1).unwrap(), &dispute_id, &true);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    assert_eq!(
+        client.try_resolve_dispute(&Address::generate(&env), &dispute_id),
+        Err(Ok(Error::QuorumNotReached))
+    );
+    client.expire_dispute(&Address::generate(&env), &dispute_id);
+    assert_eq!(
+        client.get_dispute(&dispute_id).status,
+        DisputeStatus::Expired
+    );
+}
+
+#[test]
+fn test_panel_resolves_with_majority_votes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+
+    let panel = new_panel(&env, &client, &admin, 3);
+    client.assign_panel(&admin, &dispute_id, &panel);
+    client.cast_vote(&panel.get(0).unwrap(), &dispute_id, &true);
+    client.cast_vote(&panel.get(2).unwrap(), &dispute_id, &false);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+    assert_eq!(
+        client.get_dispute(&dispute_id).status,
+        DisputeStatus::Resolved
+    );
+}
+
+#[test]
+fn test_assign_panel_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+
+    assert_eq!(
+        client.try_assign_panel(&admin, &dispute_id, &new_panel(&env, &client, &admin, 2)),
+        Err(Ok(Error::InvalidPanel))
+    );
+    assert_eq!(
+        client.try_assign_panel(&admin, &dispute_id, &new_panel(&env, &client, &admin, 16)),
+        Err(Ok(Error::InvalidPanel))
+    );
+
+    let mut duplicate = new_panel(&env, &client, &admin, 2);
+    duplicate.push_back(duplicate.get(0).unwrap());
+    assert_eq!(
+        client.try_assign_panel(&admin, &dispute_id, &duplicate),
+        Err(Ok(Error::InvalidPanel))
+    );
+
+    let mut unregistered = new_panel(&env, &client, &admin, 2);
+    unregistered.push_back(Address::generate(&env));
+    assert_eq!(
+        client.try_assign_panel(&admin, &dispute_id, &unregistered),
+        Err(Ok(Error::NotArbitrator))
+    );
+
+    assert_eq!(
+        client.try_assign_panel(
+            &Address::generate(&env),
+            &dispute_id,
+            &new_panel(&env, &client, &admin, 3)
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.assign_panel(&admin, &dispute_id, &new_panel(&env, &client, &admin, 15));
+}
+
+#[test]
+fn test_assign_panel_rejected_after_votes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+    assert_eq!(
+        client.try_assign_panel(&admin, &dispute_id, &new_panel(&env, &client, &admin, 3)),
+        Err(Ok(Error::DisputeHasVotes))
+    );
+}
+
+// ── pause ─────────────────────────────────────────────────────────────────────
+
+fn incident(env: &Env) -> Symbol {
+    Symbol::new(env, "incident")
+}
+
+#[test]
+fn test_pause_blocks_new_activity() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, disputer) = open_dispute_by(&env, &client, &contract_id);
+    let voter = new_arbitrator(&env, &client, &admin);
+    let late_voter = new_arbitrator(&env, &client, &admin);
+    client.cast_vote(&voter, &dispute_id, &true);
+
+    client.pause(&admin, &incident(&env), &None);
+    assert!(client.is_paused());
+
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    assert_eq!(
+        client.try_create_dispute(&disputer, &99, &500, &token_id, &100),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        client.try_cast_vote(&late_voter, &dispute_id, &true),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        client.try_change_vote(&voter, &dispute_id, &false),
+        Err(Ok(Error::ContractPaused))
+    );
+    assert_eq!(
+        client.try_submit_evidence(
+            &disputer,
+            &dispute_id,
+            &BytesN::from_array(&env, &[1; 32]),
+            &String::from_str(&env, "ipfs://evidence"),
+        ),
+        Err(Ok(Error::ContractPaused))
+    );
+
+    client.unpause(&admin);
+    assert!(!client.is_paused());
+    client.cast_vote(&late_voter, &dispute_id, &true);
+}
+
+#[test]
+fn test_pause_blocks_appeal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, disputer, _) = lose_dispute(&env, &client, &contract_id, &admin);
+
+    client.pause(&admin, &incident(&env), &None);
+    assert_eq!(
+        client.try_appeal_dispute(&disputer, &dispute_id, &1000),
+        Err(Ok(Error::ContractPaused))
+    );
+}
+
+#[test]
+fn test_pause_lets_in_flight_disputes_conclude() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let voted = open_dispute(&env, &client, &contract_id);
+    let unvoted = open_dispute(&env, &client, &contract_id);
+    let (cancelled, disputer) = open_dispute_by(&env, &client, &contract_id);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &voted, &true);
+
+    client.pause(&admin, &incident(&env), &None);
+    client.cancel_dispute(&disputer, &cancelled);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &voted);
+    client.expire_dispute(&Address::generate(&env), &unvoted);
+
+    assert_eq!(client.get_dispute(&voted).status, DisputeStatus::Resolved);
+    assert_eq!(client.get_dispute(&unvoted).status, DisputeStatus::Expired);
+    assert_eq!(
+        client.get_dispute(&cancelled).status,
+        DisputeStatus::Cancelled
+    );
+}
+
+#[test]
+fn test_pause_for_lifts_at_expiry() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    env.ledger().set_timestamp(1_000);
+
+    let info = client.pause_for(&admin, &600, &incident(&env), &None);
+    assert_eq!(info.until, 1_600);
+
+    env.ledger().set_timestamp(1_599);
+    assert!(client.is_paused());
+
+    env.ledger().set_timestamp(1_600);
+    assert!(!client.is_paused());
+    open_dispute(&env, &client, &contract_id);
+}
+
+#[test]
+fn test_unpause_clears_timed_pause_early() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    client.pause_for(&admin, &600, &incident(&env), &None);
+    client.unpause(&admin);
+    assert!(!client.is_paused());
+    assert_eq!(client.try_unpause(&admin), Err(Ok(Error::NotPaused)));
+}
+
+#[test]
+fn test_unpause_clears_lapsed_timed_pause() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    env.ledger().set_timestamp(1_000);
+
+    client.pause_for(&admin, &600, &incident(&env), &None);
+    env.ledger().set_timestamp(1_600);
+    assert!(!client.is_paused());
+    assert!(!client.get_pause_info().unwrap().paused);
+
+    client.unpause(&admin);
+    assert!(client.get_pause_info().is_none());
+    assert_eq!(client.get_last_pause_info().unwrap().until, 1_600);
+    assert_eq!(client.try_unpause(&admin), Err(Ok(Error::NotPaused)));
+}
+
+#[test]
+fn test_pause_rejects_non_admin_and_zero_duration() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(
+        client.try_pause(&stranger, &incident(&env), &None),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        client.try_pause_for(&admin, &0, &incident(&env), &None),
+        Err(Ok(Error::InvalidDeadline))
+    );
+
+    client.pause(&admin, &incident(&env), &None);
+    assert_eq!(client.try_unpause(&stranger), Err(Ok(Error::Unauthorized)));
+}
+
+#[test]
+fn test_pause_info_records_reason() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    env.ledger().set_timestamp(1_000);
+    let detail = String::from_str(&env, "slashing pipeline incident");
+    assert!(client.get_pause_info().is_none());
+
+    client.pause_for(&admin, &600, &incident(&env), &Some(detail.clone()));
+    let info = client.get_pause_info().unwrap();
+    assert!(info.paused);
+    assert_eq!(info.since, 1_000);
+    assert_eq!(info.until, 1_600);
+    assert_eq!(info.reason, incident(&env));
+    assert_eq!(info.detail, Some(detail));
+    assert_eq!(info.paused_by, admin);
+
+    client.unpause(&admin);
+    assert!(client.get_pause_info().is_none());
+    let last = client.get_last_pause_info().unwrap();
+    assert!(!last.paused);
+    assert_eq!(last.reason, incident(&env));
+}
+
+// ── commit-reveal ─────────────────────────────────────────────────────────────
+
+fn seal(env: &Env, favor_disputer: bool, salt: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = soroban_sdk::Bytes::new(env);
+    preimage.push_back(favor_disputer as u8);
+    preimage.append(&soroban_sdk::Bytes::from(salt.clone()));
+    env.crypto().sha256(&preimage).to_bytes()
+}
+
+/// Open a dispute (deadline +100) with a 50 second reveal window.
+fn open_sealed_dispute(
+    env: &Env,
+    client: &DisputeContractClient,
+    contract_id: &Address,
+    admin: &Address,
+) -> (u64, Address) {
+    let (dispute_id, disputer) = open_dispute_by(env, client, contract_id);
+    client.enable_commit_reveal(admin, &dispute_id, &50);
+    (dispute_id, disputer)
+}
+
+#[test]
+fn test_commit_reveal_hides_votes_until_reveal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let start = env.ledger().timestamp();
+    let (dispute_id, _) = open_sealed_dispute(&env, &client, &contract_id, &admin);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.reveal_period, 50);
+    assert_eq!(dispute.reveal_deadline, start + 150);
+    assert_eq!(client.get_voting_phase(&dispute_id), VotingPhase::Commit);
+
+    let a = new_arbitrator(&env, &client, &admin);
+    let b = new_arbitrator(&env, &client, &admin);
+    let c = new_arbitrator(&env, &client, &admin);
+    let (salt_a, salt_b, salt_c) = (
+        BytesN::from_array(&env, &[1; 32]),
+        BytesN::from_array(&env, &[2; 32]),
+        BytesN::from_array(&env, &[3; 32]),
+    );
+    client.commit_vote(&a, &dispute_id, &seal(&env, true, &salt_a));
+    client.commit_vote(&b, &dispute_id, &seal(&env, true, &salt_b));
+    client.commit_vote(&c, &dispute_id, &seal(&env, false, &salt_c));
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.commit_count, 3);
+    assert_eq!(dispute.count_for_disputer + dispute.count_for_slasher, 0);
+    assert!(client.has_committed(&dispute_id, &a));
+    assert_eq!(client.get_vote(&dispute_id, &a), None);
+
+    env.ledger().set_timestamp(start + 101);
+    assert_eq!(client.get_voting_phase(&dispute_id), VotingPhase::Reveal);
+    assert_eq!(
+        client.try_resolve_dispute(&Address::generate(&env), &dispute_id),
+        Err(Ok(Error::DeadlineNotReached))
+    );
+
+    client.reveal_vote(&a, &dispute_id, &true, &salt_a);
+    client.reveal_vote(&b, &dispute_id, &true, &salt_b);
+    client.reveal_vote(&c, &dispute_id, &false, &salt_c);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.count_for_disputer, 2);
+    assert_eq!(dispute.count_for_slasher, 1);
+    assert_eq!(client.get_vote(&dispute_id, &a), Some(true));
+
+    env.ledger().set_timestamp(start + 151);
+    assert_eq!(client.get_voting_phase(&dispute_id), VotingPhase::Closed);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+    assert_eq!(
+        client.get_dispute(&dispute_id).outcome,
+        DisputeOutcome::FavorDisputer
+    );
+}
+
+#[test]
+fn test_reveal_rejects_wrong_salt_or_direction() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, _) = open_sealed_dispute(&env, &client, &contract_id, &admin);
+
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+    let salt = BytesN::from_array(&env, &[9; 32]);
+    client.commit_vote(&arbitrator, &dispute_id, &seal(&env, false, &salt));
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 101);
+    assert_eq!(
+        client.try_reveal_vote(
+            &arbitrator,
+            &dispute_id,
+            &false,
+            &BytesN::from_array(&env, &[8; 32])
+        ),
+        Err(Ok(Error::InvalidReveal))
+    );
+    assert_eq!(
+        client.try_reveal_vote(&arbitrator, &dispute_id, &true, &salt),
+        Err(Ok(Error::InvalidReveal))
+    );
+
+    client.reveal_vote(&arbitrator, &dispute_id, &false, &salt);
+    assert_eq!(
+        client.try_reveal_vote(&arbitrator, &dispute_id, &false, &salt),
+        Err(Ok(Error::AlreadyVoted))
+    );
+}
+
+#[test]
+fn test_reveal_timing_enforced() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let start = env.ledger().timestamp();
+    let (dispute_id, _) = open_sealed_dispute(&env, &client, &contract_id, &admin);
+
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+    let salt = BytesN::from_array(&env, &[4; 32]);
+    client.commit_vote(&arbitrator, &dispute_id, &seal(&env, true, &salt));
+
+    assert_eq!(
+        client.try_reveal_vote(&arbitrator, &dispute_id, &true, &salt),
+        Err(Ok(Error::DeadlineNotReached))
+    );
+
+    env.ledger().set_timestamp(start + 101);
+    assert_eq!(
+        client.try_commit_vote(
+            &new_arbitrator(&env, &client, &admin),
+            &dispute_id,
+            &seal(&env, true, &salt)
+        ),
+        Err(Ok(Error::DeadlineExpired))
+    );
+
+    env.ledger().set_timestamp(start + 151);
+    assert_eq!(
+        client.try_reveal_vote(&arbitrator, &dispute_id, &true, &salt),
+        Err(Ok(Error::DeadlineExpired))
+    );
+}
+
+#[test]
+fn test_unrevealed_commit_counts_as_abstention() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_min_votes_to_resolve(&admin, &2);
+    let start = env.ledger().timestamp();
+    let (dispute_id, disputer) = open_sealed_dispute(&env, &client, &contract_id, &admin);
+
+    let revealer = new_arbitrator(&env, &client, &admin);
+    let silent = new_arbitrator(&env, &client, &admin);
+    let salt = BytesN::from_array(&env, &[5; 32]);
+    client.commit_vote(&revealer, &dispute_id, &seal(&env, false, &salt));
+    client.commit_vote(&silent, &dispute_id, &seal(&env, false, &salt));
+
+    env.ledger().set_timestamp(start + 101);
+    client.reveal_vote(&revealer, &dispute_id, &false, &salt);
+
+    env.ledger().set_timestamp(start + 151);
+    assert_eq!(
+        client.try_resolve_dispute(&Address::generate(&env), &dispute_id),
+        Err(Ok(Error::QuorumNotReached))
+    );
+    client.expire_dispute(&Address::generate(&env), &dispute_id);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, DisputeStatus::Expired);
+    assert_eq!(dispute.count_for_slasher, 1);
+    assert_eq!(client.get_vote(&dispute_id, &silent), None);
+    let token = soroban_sdk::token::Client::new(&env, &dispute.token);
+    assert_eq!(token.balance(&disputer), 1000);
+}
+
+#[test]
+fn test_commit_reveal_mode_rejections() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (sealed, disputer) = open_sealed_dispute(&env, &client, &contract_id, &admin);
+    let open = open_dispute(&env, &client, &contract_id);
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+    let salt = BytesN::from_array(&env, &[6; 32]);
+
+    assert_eq!(
+        client.try_cast_vote(&arbitrator, &sealed, &true),
+        Err(Ok(Error::CommitRevealRequired))
+    );
+    assert_eq!(
+        client.try_commit_vote(&arbitrator, &open, &seal(&env, true, &salt)),
+        Err(Ok(Error::NotCommitReveal))
+    );
+    assert_eq!(
+        client.try_commit_vote(&Address::generate(&env), &sealed, &seal(&env, true, &salt)),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.commit_vote(&arbitrator, &sealed, &seal(&env, true, &salt));
+    assert_eq!(
+        client.try_commit_vote(&arbitrator, &sealed, &seal(&env, false, &salt)),
+        Err(Ok(Error::AlreadyVoted))
+    );
+    assert_eq!(
+        client.try_cancel_dispute(&disputer, &sealed),
+        Err(Ok(Error::DisputeHasVotes))
+    );
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 101);
+    assert_eq!(
+        client.try_reveal_vote(
+            &new_arbitrator(&env, &client, &admin),
+            &sealed,
+            &true,
+            &salt
+        ),
+        Err(Ok(Error::VoteNotFound))
+    );
+    assert_eq!(
+        client.try_reveal_vote(&arbitrator, &open, &true, &salt),
+        Err(Ok(Error::NotCommitReveal))
+    );
+}
+
+#[test]
+fn test_appeal_round_reuses_commit_reveal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 5000);
+    token_client.approve(&disputer, &contract_id, &5000, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    client.enable_commit_reveal(&admin, &dispute_id, &50);
+
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+    let salt = BytesN::from_array(&env, &[7; 32]);
+    client.commit_vote(&arbitrator, &dispute_id, &seal(&env, false, &salt));
+    env.ledger().set_timestamp(env.ledger().timestamp() + 101);
+    client.reveal_vote(&arbitrator, &dispute_id, &false, &salt);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 50);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+    let now = env.ledger().timestamp();
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.deadline, now + 100);
+    assert_eq!(dispute.reveal_deadline, now + 150);
+    assert_eq!(dispute.commit_count, 0);
+    assert!(!client.has_committed(&dispute_id, &arbitrator));
+    assert_eq!(client.get_voting_phase(&dispute_id), VotingPhase::Commit);
+
+    client.commit_vote(&arbitrator, &dispute_id, &seal(&env, true, &salt));
+}
+
+#[test]
+fn test_enable_commit_reveal_validation() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+
+    assert_eq!(client.get_voting_phase(&dispute_id), VotingPhase::Voting);
+    assert_eq!(
+        client.try_enable_commit_reveal(&admin, &dispute_id, &0),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert_eq!(
+        client.try_enable_commit_reveal(&Address::generate(&env), &dispute_id, &50),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+    assert_eq!(
+        client.try_enable_commit_reveal(&admin, &dispute_id, &50),
+        Err(Ok(Error::DisputeHasVotes))
+    );
+}
+
+// ── stats ─────────────────────────────────────────────────────────────────────
+
+#[test]
+fn test_dispute_stats_aggregate_terminal_states() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    assert_eq!(client.get_dispute_stats(), DisputeStats::default());
+
+    // Lost, appealed and then won on appeal: counted once, as a disputer win.
+    let (appealed, disputer, _) = lose_dispute(&env, &client, &contract_id, &admin);
+    assert_eq!(client.get_dispute_stats().total_favor_slasher, 1);
+    assert_eq!(client.get_dispute_stats().total_forfeited, 500);
+    client.appeal_dispute(&disputer, &appealed, &1000);
+    assert_eq!(client.get_dispute_stats(), DisputeStats::default());
+
+    let won = open_dispute(&env, &client, &contract_id);
+    let lost = open_dispute(&env, &client, &contract_id);
+    let expired = open_dispute(&env, &client, &contract_id);
+    let (cancelled, canceller) = open_dispute_by(&env, &client, &contract_id);
+
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &appealed, &true);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &won, &true);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &lost, &false);
+
+    client.set_treasury(&admin, &env.register(StubTreasury, ()));
+    client.set_cancellation_fee_bps(&admin, &1000);
+    client.cancel_dispute(&canceller, &cancelled);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    let resolver = Address::generate(&env);
+    client.resolve_dispute(&resolver, &appealed);
+    client.resolve_dispute(&resolver, &won);
+    client.resolve_dispute(&resolver, &lost);
+    client.expire_dispute(&resolver, &expired);
+
+    assert_eq!(
+        client.get_dispute_stats(),
+        DisputeStats {
+            total_resolved: 3,
+            total_favor_disputer: 2,
+            total_favor_slasher: 1,
+            total_expired: 1,
+            total_cancelled: 1,
+            total_refunded: 1500 + 500 + 500 + 450,
+            total_forfeited: 500 + 50,
+        }
+    );
+}
+
+#[test]
+fn test_dispute_stats_appeal_expiry_keeps_first_round_forfeit() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_resolver_fee(&admin, &ResolverFee::Flat(20));
+
+    let (dispute_id, disputer, _) = lose_dispute(&env, &client, &contract_id, &admin);
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.expire_dispute(&Address::generate(&env), &dispute_id);
+
+    let stats = client.get_dispute_stats();
+    assert_eq!(stats.total_resolved, 0);
+    assert_eq!(stats.total_favor_slasher, 0);
+    assert_eq!(stats.total_expired, 1);
+    assert_eq!(stats.total_refunded, 1000);
+    assert_eq!(stats.total_forfeited, 480);
+}
+
+// ── arbitrator accuracy ──────────────────────────────────────────────────────
+
+#[test]
+fn test_record_accuracy_majority_and_minority() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, _) = open_dispute_by(&env, &client, &contract_id);
+
+    let majority = new_arbitrator(&env, &client, &admin);
+    let other = new_arbitrator(&env, &client, &admin);
+    let minority = new_arbitrator(&env, &client, &admin);
+    client.cast_vote(&majority, &dispute_id, &false);
+    client.cast_vote(&other, &dispute_id, &false);
+    client.cast_vote(&minority, &dispute_id, &true);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + DEFAULT_APPEAL_WINDOW_SECS + 1);
+
+    assert!(client.record_accuracy(&majority, &dispute_id));
+    assert!(!client.record_accuracy(&minority, &dispute_id));
+    assert_eq!(client.get_arbitrator_stats(&majority), (1, 1));
+    assert_eq!(client.get_arbitrator_stats(&minority), (1, 0));
+    assert_eq!(client.get_arbitrator_stats(&other), (0, 0));
+}
+
+#[test]
+fn test_record_accuracy_only_once_per_dispute() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+
+    let (first, _) = open_dispute_by(&env, &client, &contract_id);
+    let (second, _) = open_dispute_by(&env, &client, &contract_id);
+    client.cast_vote(&arbitrator, &first, &true);
+    client.cast_vote(&arbitrator, &second, &false);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &first);
+    client.resolve_dispute(&Address::generate(&env), &second);
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + DEFAULT_APPEAL_WINDOW_SECS + 1);
+
+    client.record_accuracy(&arbitrator, &first);
+    assert_eq!(
+        client.try_record_accuracy(&arbitrator, &first),
+        Err(Ok(Error::AlreadyRecorded))
+    );
+    assert_eq!(client.get_arbitrator_stats(&arbitrator), (1, 1));
+
+    client.record_accuracy(&arbitrator, &second);
+    assert_eq!(
+        client.try_record_accuracy(&arbitrator, &second),
+        Err(Ok(Error::AlreadyRecorded))
+    );
+    assert_eq!(client.get_arbitrator_stats(&arbitrator), (2, 2));
+}
+
+#[test]
+fn test_record_accuracy_requires_final_outcome() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, _) = open_dispute_by(&env, &client, &contract_id);
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+    client.cast_vote(&arbitrator, &dispute_id, &true);
+
+    assert_eq!(
+        client.try_record_accuracy(&arbitrator, &dispute_id),
+        Err(Ok(Error::OutcomeNotFinal))
+    );
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+    assert_eq!(
+        client.try_record_accuracy(&arbitrator, &dispute_id),
+        Err(Ok(Error::OutcomeNotFinal))
+    );
+
+    assert_eq!(
+        client.try_record_accuracy(&arbitrator, &99),
+        Err(Ok(Error::DisputeNotFound))
+    );
+    assert_eq!(client.get_arbitrator_stats(&arbitrator), (0, 0));
+}
+
+#[test]
+fn test_record_accuracy_fails_without_vote() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, _, _) = lose_dispute(&env, &client, &contract_id, &admin);
+    env.ledger()
+        .set_timestamp(env.ledger().timestamp() + DEFAULT_APPEAL_WINDOW_SECS + 1);
+
+    let bystander = new_arbitrator(&env, &client, &admin);
+    assert_eq!(
+        client.try_record_accuracy(&bystander, &dispute_id),
+        Err(Ok(Error::VoteNotFound))
+    );
+}
+
+#[test]
+fn test_record_accuracy_uses_deciding_round() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let (dispute_id, disputer, _) = lose_dispute(&env, &client, &contract_id, &admin);
+
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+    let appeal_arbitrator = new_arbitrator(&env, &client, &admin);
+    client.cast_vote(&appeal_arbitrator, &dispute_id, &true);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    // An appealed resolution is final straight away.
+    assert!(client.record_accuracy(&appeal_arbitrator, &dispute_id));
+    assert_eq!(client.get_arbitrator_stats(&appeal_arbitrator), (1, 1));
+}
+
+#[test]
+fn test_record_accuracy_after_expired_appeal_uses_first_round() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 5000);
+    token_client.approve(&disputer, &contract_id, &5000, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+    client.cast_vote(&arbitrator, &dispute_id, &false);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    client.appeal_dispute(&disputer, &dispute_id, &1000);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.expire_dispute(&Address::generate(&env), &dispute_id);
+
+    assert!(client.record_accuracy(&arbitrator, &dispute_id));
+    assert_eq!(client.get_arbitrator_stats(&arbitrator), (1, 1));
+}
+
+// ── resolution hook ──────────────────────────────────────────────────────────
+
+/// Records the resolution notifications it receives; panics while `failing`.
+#[contract]
+pub struct HookReceiver;
+
+#[contractimpl]
+impl HookReceiver {
+    pub fn set_failing(env: Env, failing: bool) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("failing"), &failing);
+    }
+
+    pub fn on_resolved(env: Env, dispute_id: u64, outcome: DisputeOutcome) {
+        if env
+            .storage()
+            .instance()
+            .get(&symbol_short!("failing"))
+            .unwrap_or(false)
+        {
+            panic!("hook rejected");
+        }
+        let mut calls: Vec<(u64, DisputeOutcome)> = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("calls"))
+            .unwrap_or(Vec::new(&env));
+        calls.push_back((dispute_id, outcome));
+        env.storage()
+            .instance()
+            .set(&symbol_short!("calls"), &calls);
+    }
+
+    pub fn calls(env: Env) -> Vec<(u64, DisputeOutcome)> {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("calls"))
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+fn setup_hook<'a>(
+    env: &'a Env,
+    client: &DisputeContractClient,
+    admin: &Address,
+) -> HookReceiverClient<'a> {
+    let hook_id = env.register(HookReceiver, ());
+    client.set_resolution_hook(admin, &hook_id, &Symbol::new(env, "on_resolved"));
+    HookReceiverClient::new(env, &hook_id)
+}
+
+#[test]
+fn test_resolution_hook_called_on_resolve_and_expire() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let hook = setup_hook(&env, &client, &admin);
+
+    let (resolved, _) = open_dispute_by(&env, &client, &contract_id);
+    let (expired, _) = open_dispute_by(&env, &client, &contract_id);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &resolved, &true);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+
+    client.resolve_dispute(&Address::generate(&env), &resolved);
+    client.expire_dispute(&Address::generate(&env), &expired);
+
+    let calls = hook.calls();
+    assert_eq!(calls.len(), 2);
+    assert_eq!(
+        calls.get(0).unwrap(),
+        (resolved, DisputeOutcome::FavorDisputer)
+    );
+    assert_eq!(calls.get(1).unwrap(), (expired, DisputeOutcome::None));
+    assert!(!client.is_hook_pending(&resolved));
+}
+
+#[test]
+fn test_resolution_hook_not_called_on_tie_extension() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let hook = setup_hook(&env, &client, &admin);
+    client.set_tie_break_rule(&admin, &TieBreakRule::ExtendDeadline(100));
+
+    let (dispute_id, _) = open_dispute_by(&env, &client, &contract_id);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    assert_eq!(client.get_dispute(&dispute_id).status, DisputeStatus::Open);
+    assert_eq!(hook.calls().len(), 0);
+}
+
+#[test]
+fn test_failing_resolution_hook_does_not_block_resolution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let hook = setup_hook(&env, &client, &admin);
+    hook.set_failing(&true);
+
+    let (dispute_id, disputer, token_client) = lose_dispute(&env, &client, &contract_id, &admin);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, DisputeStatus::Resolved);
+    assert_eq!(dispute.outcome, DisputeOutcome::FavorSlasher);
+    assert_eq!(token_client.balance(&disputer), 4500);
+    assert!(client.is_hook_pending(&dispute_id));
+    assert_eq!(hook.calls().len(), 0);
+
+    assert_eq!(
+        client.try_retry_resolution_hook(&dispute_id),
+        Err(Ok(Error::HookFailed))
+    );
+    assert!(client.is_hook_pending(&dispute_id));
+
+    hook.set_failing(&false);
+    client.retry_resolution_hook(&dispute_id);
+    assert!(!client.is_hook_pending(&dispute_id));
+    assert_eq!(
+        hook.calls(),
+        Vec::from_array(&env, [(dispute_id, DisputeOutcome::FavorSlasher)])
+    );
+    assert_eq!(
+        client.try_retry_resolution_hook(&dispute_id),
+        Err(Ok(Error::HookNotPending))
+    );
+}
+
+#[test]
+fn test_resolution_hook_admin_only_and_clearable() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let hook_id = Address::generate(&env);
+    let fn_name = Symbol::new(&env, "on_resolved");
+
+    assert_eq!(
+        client.try_set_resolution_hook(&Address::generate(&env), &hook_id, &fn_name),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.get_resolution_hook(), None);
+
+    client.set_resolution_hook(&admin, &hook_id, &fn_name);
+    assert_eq!(
+        client.get_resolution_hook(),
+        Some(ResolutionHook {
+            contract: hook_id,
+            fn_name
+        })
+    );
+
+    client.clear_resolution_hook(&admin);
+    assert_eq!(client.get_resolution_hook(), None);
+}
+
+// ── arbitrator registry ───────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "Error(Contract, #10)")]
+fn test_initialize_twice_fails() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    client.initialize(&admin);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+fn test_add_arbitrator_fails_not_initialized() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    client.add_arbitrator(&Address::generate(&env), &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_add_arbitrator_fails_non_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    setup_admin(&env, &client);
+
+    client.add_arbitrator(&Address::generate(&env), &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #12)")]
+fn test_add_arbitrator_fails_already_registered() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+
+    client.add_arbitrator(&admin, &arbitrator);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_remove_arbitrator_fails_not_registered() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    client.remove_arbitrator(&admin, &Address::generate(&env));
+}
+
+#[test]
+fn test_is_arbitrator_add_and_remove() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    let arbitrator = Address::generate(&env);
+    assert!(!client.is_arbitrator(&arbitrator));
+    client.add_arbitrator(&admin, &arbitrator);
+    assert!(client.is_arbitrator(&arbitrator));
+    client.remove_arbitrator(&admin, &arbitrator);
+    assert!(!client.is_arbitrator(&arbitrator));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_cast_vote_fails_unregistered_voter() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    setup_admin(&env, &client);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &3600);
+
+    client.cast_vote(&Address::generate(&env), &dispute_id, &true);
+}
+
+#[test]
+fn test_cast_vote_registered_voter_accepted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &3600);
+
+    assert_eq!(
+        client.try_cast_vote(&arbitrator, &dispute_id, &true),
+        Ok(Ok(()))
+    );
+    assert_eq!(client.get_dispute(&dispute_id).votes_for_disputer, 1);
+}
+
+#[test]
+fn test_remove_arbitrator_mid_dispute_keeps_tally() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+
+    token_client.approve(&disputer, &contract_id, &1000, &1000);
+    let first = client.create_dispute(&disputer, &1, &500, &token_id, &3600);
+    let second = client.create_dispute(&disputer, &2, &500, &token_id, &3600);
+
+    client.cast_vote(&arbitrator, &first, &true);
+    client.remove_arbitrator(&admin, &arbitrator);
+
+    let dispute = client.get_dispute(&first);
+    assert_eq!(dispute.votes_for_disputer, 1);
+    assert!(client.has_voted(&first, &arbitrator));
+
+    assert_eq!(
+        client.try_cast_vote(&arbitrator, &second, &true),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.get_dispute(&second).votes_for_disputer, 0);
+}
+
+// ── weighted voting ───────────────────────────────────────────────────────────
+
+fn open_dispute(env: &Env, client: &DisputeContractClient, contract_id: &Address) -> u64 {
+    open_dispute_by(env, client, contract_id).0
+}
+
+#[test]
+fn test_new_arbitrator_has_default_weight() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+
+    assert_eq!(
+        client.get_arbitrator_weight(&arbitrator),
+        DEFAULT_ARBITRATOR_WEIGHT
+    );
+    assert_eq!(client.get_arbitrator_weight(&Address::generate(&env)), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+fn test_set_arbitrator_weight_fails_zero() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+
+    client.set_arbitrator_weight(&admin, &arbitrator, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+fn test_set_arbitrator_weight_fails_not_registered() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    client.set_arbitrator_weight(&admin, &Address::generate(&env), &5);
+}
+
+#[test]
+fn test_weighted_vote_outweighs_more_voters() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let heavy = new_arbitrator(&env, &client, &admin);
+    client.set_arbitrator_weight(&admin, &heavy, &5);
+
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    client.cast_vote(&heavy, &dispute_id, &true);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.votes_for_disputer, 5);
+    assert_eq!(dispute.votes_for_slasher, 2);
+    assert_eq!(dispute.count_for_disputer, 1);
+    assert_eq!(dispute.count_for_slasher, 2);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+    assert_eq!(
+        client.get_dispute(&dispute_id).outcome,
+        DisputeOutcome::FavorDisputer
+    );
+}
+
+#[test]
+fn test_weight_change_does_not_alter_cast_votes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+
+    let first = open_dispute(&env, &client, &contract_id);
+    let second = open_dispute(&env, &client, &contract_id);
+
+    client.cast_vote(&arbitrator, &first, &true);
+    client.set_arbitrator_weight(&admin, &arbitrator, &3);
+    client.cast_vote(&arbitrator, &second, &true);
+
+    assert_eq!(client.get_dispute(&first).votes_for_disputer, 1);
+    assert_eq!(client.get_dispute(&second).votes_for_disputer, 3);
+}
+
+#[test]
+fn test_weight_tie_defaults_to_slasher() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    assert_eq!(client.get_tie_break_rule(), TieBreakRule::FavorSlasher);
+    assert_eq!(
+        client.get_dispute(&dispute_id).outcome,
+        DisputeOutcome::FavorSlasher
+    );
+}
+
+#[test]
+fn test_weight_tie_uses_favor_disputer_rule() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_tie_break_rule(&admin, &TieBreakRule::FavorDisputer);
+
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    assert_eq!(
+        client.get_dispute(&dispute_id).outcome,
+        DisputeOutcome::FavorDisputer
+    );
+}
+
+#[test]
+fn test_weight_tie_uses_majority_count_rule() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_tie_break_rule(&admin, &TieBreakRule::MajorityCount);
+
+    let heavy = new_arbitrator(&env, &client, &admin);
+    client.set_arbitrator_weight(&admin, &heavy, &2);
+
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    client.cast_vote(&heavy, &dispute_id, &false);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    assert_eq!(
+        client.get_dispute(&dispute_id).outcome,
+        DisputeOutcome::FavorDisputer
+    );
+}
+
+#[test]
+fn test_weight_tie_extends_deadline_once() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_tie_break_rule(&admin, &TieBreakRule::ExtendDeadline(500));
+
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    let resolve_time = env.ledger().timestamp();
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, DisputeStatus::Open);
+    assert_eq!(dispute.outcome, DisputeOutcome::None);
+    assert!(dispute.extended);
+    assert_eq!(dispute.deadline, resolve_time + 500);
+
+    // Still tied after the extension: falls back to the slasher.
+    env.ledger().set_timestamp(resolve_time + 600);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, DisputeStatus::Resolved);
+    assert_eq!(dispute.outcome, DisputeOutcome::FavorSlasher);
+}
+
+#[test]
+fn test_extended_dispute_accepts_tie_breaking_vote() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_tie_break_rule(&admin, &TieBreakRule::ExtendDeadline(500));
+
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &true);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 600);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+    assert_eq!(
+        client.get_dispute(&dispute_id).outcome,
+        DisputeOutcome::FavorDisputer
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")]
+fn test_set_tie_break_rule_fails_zero_extension() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    client.set_tie_break_rule(&admin, &TieBreakRule::ExtendDeadline(0));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+fn test_set_tie_break_rule_fails_non_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    setup_admin(&env, &client);
+
+    client.set_tie_break_rule(&Address::generate(&env), &TieBreakRule::FavorDisputer);
+}
+
+// ── quorum ────────────────────────────────────────────────────────────────────
+
+#[test]
+#[should_panic(expected = "Error(Contract, #15)")]
+fn test_resolve_zero_votes_fails_quorum() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+}
+
+#[test]
+fn test_below_quorum_expires_with_refund() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_min_votes_to_resolve(&admin, &3);
+
+    let disputer = Address::generate(&env);
+    let token_admin = Address::generate(&env);
+    let (token_id, _, token_client) = setup_token(&env, &token_admin, &disputer, 1000);
+    token_client.approve(&disputer, &contract_id, &500, &1000);
+    let dispute_id = client.create_dispute(&disputer, &1, &500, &token_id, &100);
+
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    assert_eq!(
+        client.try_resolve_dispute(&Address::generate(&env), &dispute_id),
+        Err(Ok(Error::QuorumNotReached))
+    );
+
+    client.expire_dispute(&Address::generate(&env), &dispute_id);
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, DisputeStatus::Expired);
+    assert_eq!(dispute.outcome, DisputeOutcome::None);
+    assert_eq!(token_client.balance(&disputer), 1000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_exact_quorum_resolves() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    client.set_min_votes_to_resolve(&admin, &2);
+
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+    client.cast_vote(&new_arbitrator(&env, &client, &admin), &dispute_id, &false);
+
+    env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+    assert_eq!(
+        client.try_expire_dispute(&Address::generate(&env), &dispute_id),
+        Err(Ok(Error::QuorumReached))
+    );
+
+    client.resolve_dispute(&Address::generate(&env), &dispute_id);
+    let dispute = client.get_dispute(&dispute_id);
+    assert_eq!(dispute.status, DisputeStatus::Resolved);
+    assert_eq!(dispute.outcome, DisputeOutcome::FavorSlasher);
+}
+
+#[test]
+fn test_min_votes_default_and_update() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    assert_eq!(
+        client.get_min_votes_to_resolve(),
+        DEFAULT_MIN_VOTES_TO_RESOLVE
+    );
+    client.set_min_votes_to_resolve(&admin, &5);
+    assert_eq!(client.get_min_votes_to_resolve(), 5);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+fn test_set_min_votes_fails_zero() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+
+    client.set_min_votes_to_resolve(&admin, &0);
+}
+
+// ── get_dispute_count ─────────────────────────────────────────────────────────
+
+#[test]
+fn test_get_dispute_count_empty() {
+    let env = Env::default();
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    assert_eq!(client.get_dispute_count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Dispute not found")]
+fn test_get_dispute_not_found_panics() {
+    let env = Env::default();
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    client.get_dispute(&999);
+}
+
+#[test]
+fn test_find_dispute_returns_error_for_unknown_id() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+
+    assert_eq!(
+        client.try_find_dispute(&999),
+        Err(Ok(Error::DisputeNotFound))
+    );
+
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    assert_eq!(
+        client.find_dispute(&dispute_id),
+        client.get_dispute(&dispute_id)
+    );
+}
+
+#[test]
+fn test_find_vote() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(DisputeContract, ());
+    let client = DisputeContractClient::new(&env, &contract_id);
+    let admin = setup_admin(&env, &client);
+    let dispute_id = open_dispute(&env, &client, &contract_id);
+    let arbitrator = new_arbitrator(&env, &client, &admin);
+    client.set_arbitrator_weight(&admin, &arbitrator, &4);
+
+    assert_eq!(
+        client.try_find_vote(&999, &arbitrator),
+        Err(Ok(Error::DisputeNotFound))
+    );
+    assert_eq!(
+        client.try_find_vote(&dispute_id, &arbitrator),
+        Err(Ok(Error::VoteNotFound))
+    );
+
+    client.cast_vote(&arbitrator, &dispute_id, &false);
+    assert_eq!(
+        client.find_vote(&dispute_id, &arbitrator),
+        VoteRecord {
+            favor_disputer: false,
+            weight: 4,
+        }
+    );
 }
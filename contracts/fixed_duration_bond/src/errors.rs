@@ -1,16 +1,66 @@
-/// All panic messages used by the fixed_duration_bond contract.
+use soroban_sdk::contracterror;
+
+/// All errors returned by the fixed_duration_bond contract.
 ///
-/// Using string constants avoids typos in `#[should_panic(expected = "...")]` tests.
-pub const ERR_ALREADY_INITIALIZED: &str = "already initialized";
-pub const ERR_NOT_INITIALIZED: &str = "not initialized";
-pub const ERR_UNAUTHORIZED: &str = "unauthorized";
-pub const ERR_INVALID_AMOUNT: &str = "amount must be positive";
-pub const ERR_INVALID_DURATION: &str = "duration must be positive";
-pub const ERR_DURATION_OVERFLOW: &str = "bond expiry timestamp would overflow";
-pub const ERR_BOND_ACTIVE: &str = "bond already active for this owner";
-pub const ERR_NO_BOND: &str = "no active bond found";
-pub const ERR_LOCK_PERIOD_NOT_ELAPSED: &str = "lock period has not elapsed yet";
-pub const ERR_INSUFFICIENT_BALANCE: &str = "insufficient bond balance";
-pub const ERR_TOKEN_NOT_SET: &str = "token not set";
-pub const ERR_NO_FEES: &str = "no fees to collect";
-pub const ERR_PENALTY_NOT_CONFIGURED: &str = "early-exit penalty not configured";
+/// Codes sit in the 800–899 block, next to the shared `credence_errors`
+/// layout (1–799), so clients can tell them apart. Codes are wire-stable:
+/// never renumber a variant, only append.
+#[contracterror]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum FixedBondError {
+    /// `initialize` was already called.
+    AlreadyInitialized = 800,
+    /// The contract has no admin yet.
+    NotInitialized = 801,
+    /// Caller is not the admin, or not the nominated admin.
+    Unauthorized = 802,
+    /// Amount must be positive.
+    InvalidAmount = 803,
+    /// Duration must be positive.
+    InvalidDuration = 804,
+    /// Bond expiry timestamp would overflow.
+    DurationOverflow = 805,
+    /// Bond duration exceeds the configured maximum.
+    DurationTooLong = 806,
+    /// No active bond found for the owner and id.
+    BondNotFound = 807,
+    /// Owner has several active bonds; a bond id is required.
+    MultipleActiveBonds = 808,
+    /// Lock period has not elapsed yet.
+    LockPeriodNotElapsed = 809,
+    /// Bond has matured; use rollover instead.
+    BondMatured = 810,
+    /// Bond has matured; use withdraw instead of an early exit.
+    EarlyExitAfterMaturity = 811,
+    /// Amount exceeds the remaining bond balance.
+    InsufficientBalance = 812,
+    /// Bond token is not set.
+    TokenNotSet = 813,
+    /// No accrued fees to collect.
+    NoFeesToCollect = 814,
+    /// Early-exit penalty is not configured for the bond.
+    PenaltyNotConfigured = 815,
+    /// Basis points must not exceed 10000.
+    InvalidBps = 816,
+    /// Bond is already owned by the proposed new owner.
+    SelfTransfer = 817,
+    /// No pending transfer for the bond and caller.
+    NoPendingTransfer = 818,
+    /// Transfer proposal has expired.
+    TransferExpired = 819,
+    /// Arithmetic overflow in an amount or counter.
+    Overflow = 820,
+    /// No admin handover is pending.
+    NoPendingAdmin = 821,
+    /// Contract is paused.
+    ContractPaused = 822,
+    /// Contract is not paused.
+    NotPaused = 823,
+    /// No fee config, so there is no treasury to pay.
+    FeeConfigNotSet = 824,
+    /// Bond is not past its maturity grace window yet.
+    GracePeriodNotElapsed = 825,
+    /// Token is not on the supported-token allowlist.
+    UnsupportedToken = 826,
+}
@@ -0,0 +1,214 @@
+//! Event payloads published by the fixed_duration_bond contract.
+//!
+//! Every event keeps the topics it had as a tuple event: `(name, owner)` for
+//! bond events and `(name,)` for pool and fee events, so existing topic
+//! filters still match. Only the data changed, from a positional tuple to one
+//! of the structs below, which serialises as a map keyed by field name.
+//! Indexers should read fields by name; new fields may be added over time.
+
+use soroban_sdk::{contracttype, Address, Symbol};
+
+/// Topics `("bond_created", owner)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FixedBondCreated {
+    pub owner: Address,
+    pub bond_id: u64,
+    pub token: Address,
+    /// Principal locked, after the creation fee.
+    pub amount: i128,
+    /// Creation fee accrued to the treasury.
+    pub fee: i128,
+    pub expiry: u64,
+}
+
+/// Topics `("bond_withdrawn", owner)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FixedBondWithdrawn {
+    pub owner: Address,
+    pub bond_id: u64,
+    /// Principal paid out.
+    pub amount: i128,
+    pub yield_paid: i128,
+    /// Address that received the funds (owner or beneficiary).
+    pub recipient: Address,
+}
+
+/// Topics `("bond_partial_withdrawn", owner)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FixedBondPartialWithdrawn {
+    pub owner: Address,
+    pub bond_id: u64,
+    /// Principal paid out.
+    pub amount: i128,
+    /// Principal still locked.
+    pub remaining: i128,
+    pub yield_paid: i128,
+    pub recipient: Address,
+}
+
+/// Topics `("bond_early_exit", owner)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FixedBondEarlyExit {
+    pub owner: Address,
+    pub bond_id: u64,
+    /// Amount paid out after the penalty.
+    pub net: i128,
+    pub penalty: i128,
+    pub recipient: Address,
+}
+
+/// Topics `("bond_extended", owner)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FixedBondExtended {
+    pub owner: Address,
+    pub bond_id: u64,
+    pub old_expiry: u64,
+    pub new_expiry: u64,
+}
+
+/// Topics `("bond_rolled_over", owner)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FixedBondRolledOver {
+    pub owner: Address,
+    pub bond_id: u64,
+    pub old_expiry: u64,
+    pub new_expiry: u64,
+    /// Rollover fee accrued to the treasury.
+    pub fee: i128,
+}
+
+/// Topics `("bond_penalty_set", owner)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FixedBondPenaltySet {
+    pub owner: Address,
+    pub bond_id: u64,
+    pub old_penalty_bps: u32,
+    pub new_penalty_bps: u32,
+}
+
+/// Topics `("beneficiary_set", owner)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BeneficiarySet {
+    pub owner: Address,
+    pub bond_id: u64,
+    /// Address future withdrawals are paid to.
+    pub recipient: Address,
+}
+
+/// Topics `("bond_transfer_proposed", owner)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferProposed {
+    pub owner: Address,
+    pub bond_id: u64,
+    pub new_owner: Address,
+    pub expires_at: u64,
+}
+
+/// Topics `("bond_transfer_cancelled", owner)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferCancelled {
+    pub owner: Address,
+    pub bond_id: u64,
+}
+
+/// Topics `("bond_transferred", owner)`, where `owner` is the previous owner.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FixedBondTransferred {
+    pub owner: Address,
+    pub bond_id: u64,
+    pub new_owner: Address,
+    pub new_bond_id: u64,
+}
+
+/// Topics `("yield_pool_funded",)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldPoolFunded {
+    pub admin: Address,
+    pub token: Address,
+    pub amount: i128,
+    /// Pool balance in `token` after funding.
+    pub balance: i128,
+}
+
+/// Topics `("fees_collected",)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeesCollected {
+    pub admin: Address,
+    /// Treasury from the fee config that received the fees.
+    pub treasury: Address,
+    pub token: Address,
+    pub amount: i128,
+    /// Fees in `token` collected over the contract's lifetime, including these.
+    pub total_collected: i128,
+}
+
+/// Topics `("admin_proposed",)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposed {
+    pub admin: Address,
+    pub pending_admin: Address,
+}
+
+/// Topics `("admin_proposal_cancelled",)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposalCancelled {
+    pub admin: Address,
+    pub pending_admin: Address,
+}
+
+/// Topics `("admin_transferred",)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferred {
+    pub previous_admin: Address,
+    pub new_admin: Address,
+}
+
+/// Topics `("paused",)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Paused {
+    pub admin: Address,
+    pub reason: Symbol,
+}
+
+/// Topics `("unpaused",)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unpaused {
+    pub admin: Address,
+}
+
+/// Topics `("bond_matured_unclaimed", owner)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondMaturedUnclaimed {
+    pub owner: Address,
+    pub bond_id: u64,
+    pub bond_expiry: u64,
+    pub flagged_by: Address,
+}
+
+/// Topics `("registry_failed", owner)`. Registering the owner in the
+/// credence_registry failed; the bond was created regardless.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistryFailed {
+    pub owner: Address,
+    pub registry: Address,
+}
@@ -6,21 +6,42 @@
 //!
 //! ## Key design decisions
 //!
-//! - **One active bond per owner**: avoids complex multi-bond accounting.
+//! - **Several bonds per owner**: each bond is keyed by `(owner, bond_id)`
+//!   with ids counted per owner from 1, so one wallet can hold positions of
+//!   different lengths. The `*_single` entrypoints act on the owner's only
+//!   active bond for callers that predate bond ids.
 //! - **Checks-Effects-Interactions**: storage is updated *before* token transfers.
-//! - **Overflow-safe expiry**: `bond_start.checked_add(duration)` panics on overflow.
+//! - **Overflow-safe expiry**: `bond_start.checked_add(duration)` fails on overflow.
+//! - **Structured errors**: entrypoints return `FixedBondError` codes (800–899)
+//!   instead of panicking, so clients can match on them.
 //! - **Auth-gated mutations**: `owner.require_auth()` on create/withdraw.
 //! - **Admin-only admin ops**: fee config, penalty config, fee collection.
 
 #![no_std]
 
 mod errors;
+pub mod events;
+mod pausable;
+mod ttl;
 mod types;
 
-use errors::*;
-use types::{DataKey, FeeConfig, FixedBond};
+pub use errors::FixedBondError;
+use events::{
+    AdminProposalCancelled, AdminProposed, AdminTransferred, BeneficiarySet, BondMaturedUnclaimed,
+    FeesCollected, FixedBondCreated, FixedBondEarlyExit, FixedBondExtended,
+    FixedBondPartialWithdrawn, FixedBondPenaltySet, FixedBondRolledOver, FixedBondTransferred,
+    FixedBondWithdrawn, Paused, RegistryFailed, TransferCancelled, TransferProposed, Unpaused,
+    YieldPoolFunded,
+};
+pub use pausable::PauseInfo;
+use types::{
+    BondStatus, ContractInfo, DataKey, FeeConfig, FixedBond, MaturedFlag, PendingTransfer,
+    YieldTier,
+};
 
-use soroban_sdk::{contract, contractimpl, token::TokenClient, Address, Env, Symbol};
+use soroban_sdk::{
+    contract, contractimpl, token::TokenClient, Address, Env, IntoVal, String, Symbol, Val, Vec,
+};
 
 #[cfg(test)]
 mod test_helpers;
@@ -28,31 +49,467 @@ mod test_helpers;
 #[cfg(test)]
 mod tests;
 
+/// Contract revision reported by `get_contract_info`. Bump on every release.
+pub const CONTRACT_VERSION: u32 = 1;
+
+/// Length of the year yield rates are quoted over (365 days).
+pub const SECONDS_PER_YEAR: u64 = 31_536_000;
+
+/// Default time a transfer proposal stays open (7 days).
+pub const DEFAULT_TRANSFER_WINDOW: u64 = 604_800;
+
+/// Default time after maturity before a bond can be flagged (30 days).
+pub const DEFAULT_MATURITY_GRACE: u64 = 2_592_000;
+
+/// Owners stored per page of the owner index.
+pub const OWNER_PAGE_SIZE: u32 = 64;
+
+/// Most owners `get_bond_owners` returns in one call.
+pub const MAX_OWNERS_PER_QUERY: u32 = 100;
+
 // ─── Helpers ───────────────────────────────────────────────────────────────
 
-fn require_admin(e: &Env, caller: &Address) {
+fn require_admin(e: &Env, caller: &Address) -> Result<(), FixedBondError> {
     caller.require_auth();
     let stored: Address = e
         .storage()
         .instance()
         .get(&DataKey::Admin)
-        .unwrap_or_else(|| panic!("{}", ERR_NOT_INITIALIZED));
+        .ok_or(FixedBondError::NotInitialized)?;
     if stored != *caller {
-        panic!("{}", ERR_UNAUTHORIZED);
+        return Err(FixedBondError::Unauthorized);
+    }
+    Ok(())
+}
+
+fn require_not_paused(e: &Env) -> Result<(), FixedBondError> {
+    if pausable::is_paused(e) {
+        return Err(FixedBondError::ContractPaused);
     }
+    Ok(())
 }
 
-fn get_token(e: &Env) -> Address {
+/// Default token, set at `initialize`.
+fn get_token(e: &Env) -> Result<Address, FixedBondError> {
     e.storage()
         .instance()
         .get(&DataKey::Token)
-        .unwrap_or_else(|| panic!("{}", ERR_TOKEN_NOT_SET))
+        .ok_or(FixedBondError::TokenNotSet)
+}
+
+/// Tokens new bonds may use. Contracts initialized before the allowlist
+/// existed support only their default token.
+fn supported_tokens(e: &Env) -> Vec<Address> {
+    match e.storage().instance().get(&DataKey::SupportedTokens) {
+        Some(tokens) => tokens,
+        None => {
+            let mut tokens = Vec::new(e);
+            if let Ok(token) = get_token(e) {
+                tokens.push_back(token);
+            }
+            tokens
+        }
+    }
+}
+
+/// Fails if `token` is not on the allowlist.
+fn require_supported_token(e: &Env, token: &Address) -> Result<(), FixedBondError> {
+    if !supported_tokens(e).contains(token) {
+        return Err(FixedBondError::UnsupportedToken);
+    }
+    Ok(())
+}
+
+fn bond_count(e: &Env, owner: &Address) -> u64 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::BondCount(owner.clone()))
+        .unwrap_or(0)
+}
+
+/// Read a bond and bump its TTL so it outlives the bond's expiry.
+fn load_bond(e: &Env, owner: &Address, bond_id: u64) -> Result<FixedBond, FixedBondError> {
+    let bond: FixedBond = e
+        .storage()
+        .persistent()
+        .get(&DataKey::Bond(owner.clone(), bond_id))
+        .ok_or(FixedBondError::BondNotFound)?;
+    ttl::extend_bond(e, &bond);
+    Ok(bond)
+}
+
+/// Write a bond and bump it, the owner's entries and the instance so they
+/// outlive the bond's expiry.
+fn save_bond(e: &Env, bond: &FixedBond) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::Bond(bond.owner.clone(), bond.bond_id), bond);
+    ttl::extend_bond(e, bond);
+    extend_owner_ttl(e, &bond.owner, bond.bond_expiry);
+}
+
+/// Bump the instance and `owner`'s counters and index entries so they
+/// outlive `expiry`.
+fn extend_owner_ttl(e: &Env, owner: &Address, expiry: u64) {
+    ttl::extend_instance_until(e, expiry);
+    ttl::extend_until(e, &DataKey::BondCount(owner.clone()), expiry);
+    ttl::extend_until(e, &DataKey::OwnerActiveBonds(owner.clone()), expiry);
+    let position_key = DataKey::OwnerPosition(owner.clone());
+    if let Some(position) = e.storage().persistent().get::<_, u32>(&position_key) {
+        ttl::extend_until(e, &position_key, expiry);
+        ttl::extend_until(e, &DataKey::OwnerPage(position / OWNER_PAGE_SIZE), expiry);
+    }
+}
+
+/// Fails if `duration_secs` exceeds the configured maximum.
+fn check_max_duration(e: &Env, duration_secs: u64) -> Result<(), FixedBondError> {
+    let max: u64 = e
+        .storage()
+        .instance()
+        .get(&DataKey::MaxDuration)
+        .unwrap_or(0);
+    if max > 0 && duration_secs > max {
+        return Err(FixedBondError::DurationTooLong);
+    }
+    Ok(())
+}
+
+/// Address that withdrawals of `bond` are paid to.
+fn payout_address(bond: &FixedBond) -> Address {
+    bond.beneficiary
+        .clone()
+        .unwrap_or_else(|| bond.owner.clone())
+}
+
+/// Id of the owner's only active bond.
+/// Fails if the owner has no active bond or more than one.
+fn single_active_bond_id(e: &Env, owner: &Address) -> Result<u64, FixedBondError> {
+    let mut found = None;
+    for bond_id in 1..=bond_count(e, owner) {
+        if load_bond(e, owner, bond_id)?.active {
+            if found.is_some() {
+                return Err(FixedBondError::MultipleActiveBonds);
+            }
+            found = Some(bond_id);
+        }
+    }
+    found.ok_or(FixedBondError::BondNotFound)
+}
+
+fn accrued_fees(e: &Env, token: &Address) -> i128 {
+    e.storage()
+        .instance()
+        .get(&DataKey::AccruedFees(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Drop the unclaimed-maturity flag once the bond is settled or re-locked.
+fn clear_matured_flag(e: &Env, owner: &Address, bond_id: u64) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::MaturedFlag(owner.clone(), bond_id));
+}
+
+fn total_fees_collected(e: &Env, token: &Address) -> i128 {
+    e.storage()
+        .instance()
+        .get(&DataKey::TotalFeesCollected(token.clone()))
+        .unwrap_or(0)
+}
+
+/// Accumulate a fee in `token`; the treasury receives it at `collect_fees`.
+fn accrue_fee(e: &Env, token: &Address, fee: i128) -> Result<(), FixedBondError> {
+    let total = accrued_fees(e, token)
+        .checked_add(fee)
+        .ok_or(FixedBondError::Overflow)?;
+    e.storage()
+        .instance()
+        .set(&DataKey::AccruedFees(token.clone()), &total);
+    Ok(())
+}
+
+fn total_locked(e: &Env, token: &Address) -> i128 {
+    e.storage()
+        .instance()
+        .get(&DataKey::TotalLocked(token.clone()))
+        .unwrap_or(0)
+}
+
+fn active_bond_count(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::ActiveBondCount)
+        .unwrap_or(0)
+}
+
+fn owner_count(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::OwnerCount)
+        .unwrap_or(0)
+}
+
+fn owner_page(e: &Env, page: u32) -> Vec<Address> {
+    e.storage()
+        .persistent()
+        .get(&DataKey::OwnerPage(page))
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+fn set_owner_page(e: &Env, page: u32, owners: &Vec<Address>) {
+    if owners.is_empty() {
+        e.storage().persistent().remove(&DataKey::OwnerPage(page));
+    } else {
+        e.storage()
+            .persistent()
+            .set(&DataKey::OwnerPage(page), owners);
+    }
+}
+
+/// Append `owner` to the owner index.
+fn index_owner(e: &Env, owner: &Address) {
+    let position = owner_count(e);
+    let page = position / OWNER_PAGE_SIZE;
+    let mut owners = owner_page(e, page);
+    owners.push_back(owner.clone());
+    set_owner_page(e, page, &owners);
+    e.storage()
+        .persistent()
+        .set(&DataKey::OwnerPosition(owner.clone()), &position);
+    e.storage()
+        .instance()
+        .set(&DataKey::OwnerCount, &(position + 1));
+}
+
+/// Remove `owner` from the owner index by moving the last entry into its
+/// slot, so pages stay dense.
+fn unindex_owner(e: &Env, owner: &Address) {
+    let position_key = DataKey::OwnerPosition(owner.clone());
+    let position: u32 = match e.storage().persistent().get(&position_key) {
+        Some(position) => position,
+        None => return,
+    };
+    e.storage().persistent().remove(&position_key);
+
+    let last = owner_count(e) - 1;
+    let mut last_page = owner_page(e, last / OWNER_PAGE_SIZE);
+    let moved = last_page.pop_back_unchecked();
+    set_owner_page(e, last / OWNER_PAGE_SIZE, &last_page);
+
+    if position != last {
+        let page = position / OWNER_PAGE_SIZE;
+        let mut owners = owner_page(e, page);
+        owners.set(position % OWNER_PAGE_SIZE, moved.clone());
+        set_owner_page(e, page, &owners);
+        e.storage()
+            .persistent()
+            .set(&DataKey::OwnerPosition(moved), &position);
+    }
+    e.storage().instance().set(&DataKey::OwnerCount, &last);
+}
+
+/// Update the TVL aggregates and the owner index: `principal_delta` is
+/// added to the total locked principal in `token` and `bonds_delta` to the
+/// active bond count, globally and for `owner`.
+fn track_locked(
+    e: &Env,
+    owner: &Address,
+    token: &Address,
+    principal_delta: i128,
+    bonds_delta: i32,
+) -> Result<(), FixedBondError> {
+    let total = total_locked(e, token)
+        .checked_add(principal_delta)
+        .ok_or(FixedBondError::Overflow)?;
+    let count = active_bond_count(e)
+        .checked_add_signed(bonds_delta)
+        .ok_or(FixedBondError::Overflow)?;
+    e.storage()
+        .instance()
+        .set(&DataKey::TotalLocked(token.clone()), &total);
+    e.storage()
+        .instance()
+        .set(&DataKey::ActiveBondCount, &count);
+
+    if bonds_delta == 0 {
+        return Ok(());
+    }
+    let key = DataKey::OwnerActiveBonds(owner.clone());
+    let before: u32 = e.storage().persistent().get(&key).unwrap_or(0);
+    let after = before
+        .checked_add_signed(bonds_delta)
+        .ok_or(FixedBondError::Overflow)?;
+    if after == 0 {
+        e.storage().persistent().remove(&key);
+        unindex_owner(e, owner);
+    } else {
+        e.storage().persistent().set(&key, &after);
+        if before == 0 {
+            index_owner(e, owner);
+        }
+    }
+    Ok(())
+}
+
+fn default_penalty_bps(e: &Env) -> u32 {
+    e.storage()
+        .instance()
+        .get(&DataKey::PenaltyBps)
+        .unwrap_or(0)
+}
+
+fn yield_rates(e: &Env) -> Vec<YieldTier> {
+    e.storage()
+        .instance()
+        .get(&DataKey::YieldRates)
+        .unwrap_or_else(|| Vec::new(e))
+}
+
+/// Rate of the highest tier whose minimum `duration_secs` reaches, or 0.
+fn yield_rate_for(e: &Env, duration_secs: u64) -> u32 {
+    let mut rate = 0;
+    for tier in yield_rates(e).iter() {
+        if tier.min_duration_secs > duration_secs {
+            break;
+        }
+        rate = tier.rate_bps;
+    }
+    rate
+}
+
+fn yield_pool(e: &Env, token: &Address) -> i128 {
+    e.storage()
+        .instance()
+        .get(&DataKey::YieldPool(token.clone()))
+        .unwrap_or(0)
+}
+
+/// `principal * rate_bps * term / (10000 * year)`, rounded down.
+///
+/// Large principals or very long terms trade precision for range, and a
+/// result beyond `i128` saturates; payable yield is capped by the pool anyway.
+fn accrued_yield(principal: i128, rate_bps: u32, term_secs: u64) -> i128 {
+    let scale = 10_000_i128 * SECONDS_PER_YEAR as i128;
+    let factor = rate_bps as i128 * term_secs as i128;
+    match principal.checked_mul(factor) {
+        Some(product) => product / scale,
+        None => (principal / scale).checked_mul(factor).unwrap_or(i128::MAX),
+    }
+}
+
+/// Yield owed on `principal` of `bond`, capped by the pool balance in the
+/// bond's token.
+fn payable_yield(e: &Env, bond: &FixedBond, principal: i128) -> i128 {
+    accrued_yield(principal, bond.yield_bps, bond.bond_duration).min(yield_pool(e, &bond.token))
+}
+
+/// Deduct `amount` from the yield pool in `token`.
+fn draw_yield(e: &Env, token: &Address, amount: i128) {
+    if amount > 0 {
+        e.storage().instance().set(
+            &DataKey::YieldPool(token.clone()),
+            &(yield_pool(e, token) - amount),
+        );
+    }
 }
 
 /// Apply basis-point fee: returns `(fee, net)`.
-fn apply_bps(amount: i128, bps: u32) -> (i128, i128) {
+///
+/// Fails if the fee would exceed `amount`, which only a rate above 10000 bps
+/// can cause; setters reject those, so this guards values stored earlier.
+fn apply_bps(amount: i128, bps: u32) -> Result<(i128, i128), FixedBondError> {
     let fee = amount * (bps as i128) / 10_000_i128;
-    (fee, amount - fee)
+    if fee > amount {
+        return Err(FixedBondError::InvalidBps);
+    }
+    Ok((fee, amount - fee))
+}
+
+fn flat_penalty(e: &Env) -> bool {
+    e.storage()
+        .instance()
+        .get(&DataKey::FlatPenalty)
+        .unwrap_or(false)
+}
+
+/// Early-exit `(penalty, net)` for an active, unmatured `bond` at `now`.
+/// Unless flat mode is on, the penalty scales with the time left:
+/// `amount * penalty_bps * remaining / duration / 10000`.
+///
+/// Fails if the bond is inactive, has matured, or has no valid penalty set.
+fn early_exit_quote(e: &Env, bond: &FixedBond) -> Result<(i128, i128), FixedBondError> {
+    if !bond.active {
+        return Err(FixedBondError::BondNotFound);
+    }
+    let now = e.ledger().timestamp();
+    if now >= bond.bond_expiry {
+        return Err(FixedBondError::EarlyExitAfterMaturity);
+    }
+    if bond.penalty_bps == 0 {
+        return Err(FixedBondError::PenaltyNotConfigured);
+    }
+    if bond.penalty_bps > 10_000 {
+        return Err(FixedBondError::InvalidBps);
+    }
+    if flat_penalty(e) {
+        return apply_bps(bond.amount, bond.penalty_bps);
+    }
+
+    let remaining = (bond.bond_expiry - now) as i128;
+    let duration = bond.bond_duration as i128;
+    let penalty = match bond
+        .amount
+        .checked_mul(bond.penalty_bps as i128)
+        .and_then(|v| v.checked_mul(remaining))
+    {
+        Some(product) => product / duration / 10_000,
+        // Only reachable for amounts near i128::MAX; trade precision for range.
+        None => apply_bps(bond.amount, bond.penalty_bps)?.0 / duration * remaining,
+    };
+    Ok((penalty, bond.amount - penalty))
+}
+
+/// Register `owner` in the configured credence_registry, if any, with this
+/// contract as their bond contract. The registry returns an existing entry
+/// unchanged, so owners already registered are fine. A failing registry,
+/// e.g. one that has not allowlisted this contract, must not block bond
+/// creation, so its failure is reported as `registry_failed` instead.
+fn register_owner(e: &Env, owner: &Address) {
+    let registry: Option<Address> = e.storage().instance().get(&DataKey::Registry);
+    if let Some(registry) = registry {
+        let args: Vec<Val> = Vec::from_array(
+            e,
+            [e.current_contract_address().into_val(e), owner.into_val(e)],
+        );
+        let result = e.try_invoke_contract::<Val, soroban_sdk::Error>(
+            &registry,
+            &Symbol::new(e, "register_by_registrar"),
+            args,
+        );
+        if !matches!(result, Ok(Ok(_))) {
+            e.events().publish(
+                (Symbol::new(e, "registry_failed"), owner.clone()),
+                RegistryFailed {
+                    owner: owner.clone(),
+                    registry,
+                },
+            );
+        }
+    }
+}
+
+/// Snapshot of `bond` at the current ledger time. The early-exit figures
+/// come from `early_exit_quote`, the same maths `withdraw_early` applies.
+fn bond_status(e: &Env, bond: FixedBond) -> BondStatus {
+    let (early_exit_penalty, early_exit_net) = early_exit_quote(e, &bond).unwrap_or((0, 0));
+    let seconds_remaining = bond.bond_expiry.saturating_sub(e.ledger().timestamp());
+    BondStatus {
+        bond,
+        matured: seconds_remaining == 0,
+        seconds_remaining,
+        early_exit_penalty,
+        early_exit_net,
+    }
 }
 
 // ─── Contract ──────────────────────────────────────────────────────────────
@@ -65,238 +522,1020 @@ impl FixedDurationBond {
     // ── Admin setup ────────────────────────────────────────────────────────
 
     /// One-time initialization. Stores `admin` and `token`.
-    /// Panics if called again after initialization.
-    pub fn initialize(e: Env, admin: Address, token: Address) {
+    /// Fails if called again after initialization.
+    pub fn initialize(e: Env, admin: Address, token: Address) -> Result<(), FixedBondError> {
         if e.storage().instance().has(&DataKey::Admin) {
-            panic!("{}", ERR_ALREADY_INITIALIZED);
+            return Err(FixedBondError::AlreadyInitialized);
         }
         e.storage().instance().set(&DataKey::Admin, &admin);
         e.storage().instance().set(&DataKey::Token, &token);
+        e.storage()
+            .instance()
+            .set(&DataKey::SupportedTokens, &Vec::from_array(&e, [token]));
+        Ok(())
+    }
+
+    /// Allow new bonds in `token`. Adding a supported token is a no-op.
+    pub fn add_supported_token(
+        e: Env,
+        admin: Address,
+        token: Address,
+    ) -> Result<(), FixedBondError> {
+        require_admin(&e, &admin)?;
+        let mut tokens = supported_tokens(&e);
+        if !tokens.contains(&token) {
+            tokens.push_back(token);
+            e.storage()
+                .instance()
+                .set(&DataKey::SupportedTokens, &tokens);
+        }
+        Ok(())
+    }
+
+    /// Stop new bonds and rollovers in `token`. Existing bonds in it can
+    /// still be withdrawn, exited early or transferred, and its fees and
+    /// yield pool stay usable.
+    /// Fails if `token` is not supported.
+    pub fn remove_supported_token(
+        e: Env,
+        admin: Address,
+        token: Address,
+    ) -> Result<(), FixedBondError> {
+        require_admin(&e, &admin)?;
+        let mut tokens = supported_tokens(&e);
+        let index = tokens
+            .first_index_of(&token)
+            .ok_or(FixedBondError::UnsupportedToken)?;
+        tokens.remove(index);
+        e.storage()
+            .instance()
+            .set(&DataKey::SupportedTokens, &tokens);
+        Ok(())
+    }
+
+    /// Returns the tokens new bonds may be created in.
+    pub fn get_supported_tokens(e: Env) -> Vec<Address> {
+        supported_tokens(&e)
+    }
+
+    /// Current admin.
+    /// Fails if the contract is not initialized.
+    pub fn get_admin(e: Env) -> Result<Address, FixedBondError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(FixedBondError::NotInitialized)
+    }
+
+    /// Address proposed as the next admin, if any.
+    pub fn get_pending_admin(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    /// Propose `new_admin` as the next admin. Nothing changes until
+    /// `new_admin` calls `accept_admin`; proposing again replaces the
+    /// pending address.
+    /// Fails if `current_admin` is not the admin.
+    pub fn propose_admin(
+        e: Env,
+        current_admin: Address,
+        new_admin: Address,
+    ) -> Result<(), FixedBondError> {
+        require_admin(&e, &current_admin)?;
+        e.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &new_admin);
+
+        e.events().publish(
+            (Symbol::new(&e, "admin_proposed"),),
+            AdminProposed {
+                admin: current_admin,
+                pending_admin: new_admin,
+            },
+        );
+        Ok(())
+    }
+
+    /// Withdraw the pending admin proposal.
+    /// Fails if `current_admin` is not the admin or nothing is pending.
+    pub fn cancel_admin_proposal(e: Env, current_admin: Address) -> Result<(), FixedBondError> {
+        require_admin(&e, &current_admin)?;
+        let pending_admin =
+            Self::get_pending_admin(e.clone()).ok_or(FixedBondError::NoPendingAdmin)?;
+        e.storage().instance().remove(&DataKey::PendingAdmin);
+
+        e.events().publish(
+            (Symbol::new(&e, "admin_proposal_cancelled"),),
+            AdminProposalCancelled {
+                admin: current_admin,
+                pending_admin,
+            },
+        );
+        Ok(())
+    }
+
+    /// Accept a pending proposal, making `new_admin` the admin. The previous
+    /// admin loses all admin rights immediately.
+    /// Fails if nothing is pending or `new_admin` is not the proposed address.
+    pub fn accept_admin(e: Env, new_admin: Address) -> Result<(), FixedBondError> {
+        new_admin.require_auth();
+        let previous_admin = Self::get_admin(e.clone())?;
+        let pending = Self::get_pending_admin(e.clone()).ok_or(FixedBondError::NoPendingAdmin)?;
+        if pending != new_admin {
+            return Err(FixedBondError::Unauthorized);
+        }
+
+        e.storage().instance().set(&DataKey::Admin, &new_admin);
+        e.storage().instance().remove(&DataKey::PendingAdmin);
+
+        e.events().publish(
+            (Symbol::new(&e, "admin_transferred"),),
+            AdminTransferred {
+                previous_admin,
+                new_admin,
+            },
+        );
+        Ok(())
     }
 
     /// Set (or update) the optional bond-creation fee.
     /// `fee_bps` = 0 effectively disables the fee.
-    pub fn set_fee_config(e: Env, admin: Address, treasury: Address, fee_bps: u32) {
-        require_admin(&e, &admin);
+    /// Fails if `fee_bps` exceeds 10000.
+    pub fn set_fee_config(
+        e: Env,
+        admin: Address,
+        treasury: Address,
+        fee_bps: u32,
+    ) -> Result<(), FixedBondError> {
+        require_admin(&e, &admin)?;
+        if fee_bps > 10_000 {
+            return Err(FixedBondError::InvalidBps);
+        }
         let cfg = FeeConfig { treasury, fee_bps };
         e.storage().instance().set(&DataKey::FeeConfig, &cfg);
+        Ok(())
     }
 
     /// Set the default early-exit penalty applied when `withdraw_early` is called.
     /// Pass 0 to disable early-exit withdrawal for newly created bonds.
-    pub fn set_penalty_config(e: Env, admin: Address, base_penalty_bps: u32) {
-        require_admin(&e, &admin);
+    /// Fails if `base_penalty_bps` exceeds 10000.
+    pub fn set_penalty_config(
+        e: Env,
+        admin: Address,
+        base_penalty_bps: u32,
+    ) -> Result<(), FixedBondError> {
+        require_admin(&e, &admin)?;
+        if base_penalty_bps > 10_000 {
+            return Err(FixedBondError::InvalidBps);
+        }
         e.storage()
             .instance()
             .set(&DataKey::PenaltyBps, &base_penalty_bps);
+        Ok(())
+    }
+
+    /// Override the early-exit penalty of one active bond, e.g. for
+    /// negotiated exit terms. As with the default, 0 means the bond cannot
+    /// be exited early at all, not that exiting is free. A later `rollover`
+    /// resets the penalty to the default.
+    ///
+    /// Fails if `penalty_bps` exceeds 10000 or there is no such active bond.
+    pub fn set_bond_penalty(
+        e: Env,
+        admin: Address,
+        owner: Address,
+        bond_id: u64,
+        penalty_bps: u32,
+    ) -> Result<FixedBond, FixedBondError> {
+        require_admin(&e, &admin)?;
+        if penalty_bps > 10_000 {
+            return Err(FixedBondError::InvalidBps);
+        }
+        let mut bond = load_bond(&e, &owner, bond_id)?;
+        if !bond.active {
+            return Err(FixedBondError::BondNotFound);
+        }
+
+        let old_penalty_bps = bond.penalty_bps;
+        bond.penalty_bps = penalty_bps;
+        save_bond(&e, &bond);
+
+        e.events().publish(
+            (Symbol::new(&e, "bond_penalty_set"), owner.clone()),
+            FixedBondPenaltySet {
+                owner,
+                bond_id,
+                old_penalty_bps,
+                new_penalty_bps: penalty_bps,
+            },
+        );
+
+        Ok(bond)
+    }
+
+    /// Switch between a flat early-exit penalty (`true`) and one pro-rated
+    /// by the time left to maturity (`false`, the default).
+    pub fn set_flat_penalty(e: Env, admin: Address, flat: bool) -> Result<(), FixedBondError> {
+        require_admin(&e, &admin)?;
+        e.storage().instance().set(&DataKey::FlatPenalty, &flat);
+        Ok(())
+    }
+
+    /// Returns `true` if early exits pay the full, flat penalty.
+    pub fn is_flat_penalty(e: Env) -> bool {
+        flat_penalty(&e)
+    }
+
+    /// Set the fee charged when a matured bond is rolled over, in basis
+    /// points of its principal. 0 (the default) waives it entirely.
+    pub fn set_rollover_fee_bps(
+        e: Env,
+        admin: Address,
+        fee_bps: u32,
+    ) -> Result<(), FixedBondError> {
+        require_admin(&e, &admin)?;
+        if fee_bps > 10_000 {
+            return Err(FixedBondError::InvalidBps);
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::RolloverFeeBps, &fee_bps);
+        Ok(())
+    }
+
+    /// Returns the rollover fee in basis points.
+    pub fn get_rollover_fee_bps(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::RolloverFeeBps)
+            .unwrap_or(0)
+    }
+
+    /// Set how long a transfer proposal can be accepted for, in seconds.
+    /// Proposals already made keep their expiry.
+    pub fn set_transfer_window(
+        e: Env,
+        admin: Address,
+        window_secs: u64,
+    ) -> Result<(), FixedBondError> {
+        require_admin(&e, &admin)?;
+        if window_secs == 0 {
+            return Err(FixedBondError::InvalidDuration);
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::TransferWindow, &window_secs);
+        Ok(())
+    }
+
+    /// Returns the transfer proposal window in seconds.
+    pub fn get_transfer_window(e: Env) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::TransferWindow)
+            .unwrap_or(DEFAULT_TRANSFER_WINDOW)
+    }
+
+    /// Set how long after maturity a bond must stay unclaimed before
+    /// `flag_matured` accepts it, in seconds.
+    /// Fails if `grace_secs` is 0.
+    pub fn set_maturity_grace(
+        e: Env,
+        admin: Address,
+        grace_secs: u64,
+    ) -> Result<(), FixedBondError> {
+        require_admin(&e, &admin)?;
+        if grace_secs == 0 {
+            return Err(FixedBondError::InvalidDuration);
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::MaturityGrace, &grace_secs);
+        Ok(())
+    }
+
+    /// Returns the maturity grace window in seconds.
+    pub fn get_maturity_grace(e: Env) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::MaturityGrace)
+            .unwrap_or(DEFAULT_MATURITY_GRACE)
+    }
+
+    /// Set the credence_registry contract that owners are registered in
+    /// when they create a bond, or `None` to stop registering. The registry
+    /// must allowlist this contract with `set_registrar`, or bond creation
+    /// fails.
+    pub fn set_registry(
+        e: Env,
+        admin: Address,
+        registry: Option<Address>,
+    ) -> Result<(), FixedBondError> {
+        require_admin(&e, &admin)?;
+        match registry {
+            Some(registry) => e.storage().instance().set(&DataKey::Registry, &registry),
+            None => e.storage().instance().remove(&DataKey::Registry),
+        }
+        Ok(())
+    }
+
+    /// Returns the credence_registry contract owners are registered in.
+    pub fn get_registry(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::Registry)
+    }
+
+    /// Set the maximum total lock period of a bond, including extensions.
+    /// Pass 0 to remove the limit. Existing bonds keep their duration.
+    pub fn set_max_duration(
+        e: Env,
+        admin: Address,
+        max_duration_secs: u64,
+    ) -> Result<(), FixedBondError> {
+        require_admin(&e, &admin)?;
+        e.storage()
+            .instance()
+            .set(&DataKey::MaxDuration, &max_duration_secs);
+        Ok(())
+    }
+
+    /// Returns the maximum total lock period in seconds (0 = unlimited).
+    pub fn get_max_duration(e: Env) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::MaxDuration)
+            .unwrap_or(0)
+    }
+
+    /// Add `amount` of `token`, pulled from `admin`, to the pool that yield
+    /// on bonds in that token is paid from. The admin must have approved the
+    /// contract.
+    /// Fails if `token` is not supported.
+    pub fn fund_yield_pool(
+        e: Env,
+        admin: Address,
+        token: Address,
+        amount: i128,
+    ) -> Result<i128, FixedBondError> {
+        require_admin(&e, &admin)?;
+        if amount <= 0 {
+            return Err(FixedBondError::InvalidAmount);
+        }
+        require_supported_token(&e, &token)?;
+
+        let contract = e.current_contract_address();
+        TokenClient::new(&e, &token).transfer_from(&contract, &admin, &contract, &amount);
+
+        let balance = yield_pool(&e, &token)
+            .checked_add(amount)
+            .ok_or(FixedBondError::Overflow)?;
+        e.storage()
+            .instance()
+            .set(&DataKey::YieldPool(token.clone()), &balance);
+
+        e.events().publish(
+            (Symbol::new(&e, "yield_pool_funded"),),
+            YieldPoolFunded {
+                admin,
+                token,
+                amount,
+                balance,
+            },
+        );
+        Ok(balance)
+    }
+
+    /// Set the annual yield rate for bonds locked at least
+    /// `min_duration_secs`, replacing any rate for the same minimum. A bond
+    /// takes the rate of the highest tier its duration reaches when it is
+    /// created or rolled over; later changes do not affect it.
+    pub fn set_yield_rate(
+        e: Env,
+        admin: Address,
+        min_duration_secs: u64,
+        rate_bps: u32,
+    ) -> Result<(), FixedBondError> {
+        require_admin(&e, &admin)?;
+        if rate_bps > 10_000 {
+            return Err(FixedBondError::InvalidBps);
+        }
+
+        let tier = YieldTier {
+            min_duration_secs,
+            rate_bps,
+        };
+        let mut rates = yield_rates(&e);
+        let mut index = rates.len();
+        for (i, existing) in rates.iter().enumerate() {
+            if existing.min_duration_secs >= min_duration_secs {
+                index = i as u32;
+                break;
+            }
+        }
+        match rates.get(index) {
+            Some(existing) if existing.min_duration_secs == min_duration_secs => {
+                rates.set(index, tier)
+            }
+            _ => rates.insert(index, tier),
+        }
+        e.storage().instance().set(&DataKey::YieldRates, &rates);
+        Ok(())
+    }
+
+    /// Returns the yield rate table, sorted by minimum duration.
+    pub fn get_yield_rates(e: Env) -> Vec<YieldTier> {
+        yield_rates(&e)
+    }
+
+    /// Returns the sum of the principal of all active bonds in `token`.
+    pub fn get_total_locked(e: Env, token: Address) -> i128 {
+        total_locked(&e, &token)
+    }
+
+    /// Returns the number of active bonds across all owners.
+    pub fn get_active_bond_count(e: Env) -> u32 {
+        active_bond_count(&e)
+    }
+
+    /// Returns the creation and rollover fees in `token` accrued since the
+    /// last `collect_fees` for it.
+    pub fn get_total_fees_accrued(e: Env, token: Address) -> i128 {
+        accrued_fees(&e, &token)
+    }
+
+    /// Returns up to `limit` owners with at least one active bond, starting
+    /// at position `offset` of the owner index. `limit` is capped at
+    /// `MAX_OWNERS_PER_QUERY`. Removing an owner moves the last one into its
+    /// slot, so positions are not stable across writes.
+    pub fn get_bond_owners(e: Env, offset: u32, limit: u32) -> Vec<Address> {
+        let end = offset
+            .saturating_add(limit.min(MAX_OWNERS_PER_QUERY))
+            .min(owner_count(&e));
+        let mut owners = Vec::new(&e);
+        let mut position = offset;
+        while position < end {
+            let page = owner_page(&e, position / OWNER_PAGE_SIZE);
+            let page_end = end.min((position / OWNER_PAGE_SIZE + 1) * OWNER_PAGE_SIZE);
+            for p in position..page_end {
+                owners.push_back(page.get_unchecked(p % OWNER_PAGE_SIZE));
+            }
+            position = page_end;
+        }
+        owners
+    }
+
+    /// Returns the number of owners with at least one active bond.
+    pub fn get_owner_count(e: Env) -> u32 {
+        owner_count(&e)
+    }
+
+    /// Returns the unallocated balance of the yield pool in `token`.
+    pub fn get_yield_pool(e: Env, token: Address) -> i128 {
+        yield_pool(&e, &token)
+    }
+
+    /// Returns the fees in `token` paid to the treasury by `collect_fees`
+    /// over the contract's lifetime.
+    pub fn get_total_fees_collected(e: Env, token: Address) -> i128 {
+        total_fees_collected(&e, &token)
     }
 
-    /// Collect all accrued creation fees to the admin or treasury.
-    /// Transfers the fee balance to `recipient` and resets the counter.
-    pub fn collect_fees(e: Env, admin: Address, recipient: Address) -> i128 {
-        require_admin(&e, &admin);
-        let accrued: i128 = e
+    /// Transfer all accrued creation and rollover fees in `token` to the
+    /// treasury in the fee config and reset its counter. Each token is
+    /// collected separately, so assets are never mixed. Returns the amount
+    /// collected.
+    /// Fails if no fee config is set or nothing has accrued in `token`.
+    pub fn collect_fees(e: Env, admin: Address, token: Address) -> Result<i128, FixedBondError> {
+        require_admin(&e, &admin)?;
+        let treasury = e
             .storage()
             .instance()
-            .get(&DataKey::AccruedFees)
-            .unwrap_or(0_i128);
+            .get::<_, FeeConfig>(&DataKey::FeeConfig)
+            .ok_or(FixedBondError::FeeConfigNotSet)?
+            .treasury;
+        let accrued = accrued_fees(&e, &token);
         if accrued == 0 {
-            panic!("{}", ERR_NO_FEES);
+            return Err(FixedBondError::NoFeesToCollect);
         }
+        let total_collected = total_fees_collected(&e, &token)
+            .checked_add(accrued)
+            .ok_or(FixedBondError::Overflow)?;
         // CEI: clear state before transfer.
-        e.storage().instance().set(&DataKey::AccruedFees, &0_i128);
+        e.storage()
+            .instance()
+            .set(&DataKey::AccruedFees(token.clone()), &0_i128);
+        e.storage().instance().set(
+            &DataKey::TotalFeesCollected(token.clone()),
+            &total_collected,
+        );
 
-        let token = get_token(&e);
         let contract = e.current_contract_address();
-        TokenClient::new(&e, &token).transfer(&contract, &recipient, &accrued);
+        TokenClient::new(&e, &token).transfer(&contract, &treasury, &accrued);
 
         e.events().publish(
             (Symbol::new(&e, "fees_collected"),),
-            (admin, recipient, accrued),
+            FeesCollected {
+                admin,
+                treasury,
+                token,
+                amount: accrued,
+                total_collected,
+            },
         );
-        accrued
+        Ok(accrued)
+    }
+
+    /// Pause bond creation, withdrawals, early exits, extensions and
+    /// rollovers until `unpause`, recording why. Queries, admin calls and fee
+    /// collection stay available.
+    /// Fails if `admin` is not the admin.
+    pub fn pause(
+        e: Env,
+        admin: Address,
+        reason: Symbol,
+        detail: Option<String>,
+    ) -> Result<PauseInfo, FixedBondError> {
+        require_admin(&e, &admin)?;
+        let info = pausable::pause(&e, &admin, reason.clone(), detail);
+        e.events()
+            .publish((Symbol::new(&e, "paused"),), Paused { admin, reason });
+        Ok(info)
+    }
+
+    /// Lift the pause.
+    /// Fails if `admin` is not the admin or the contract is not paused.
+    pub fn unpause(e: Env, admin: Address) -> Result<(), FixedBondError> {
+        require_admin(&e, &admin)?;
+        if !pausable::is_paused(&e) {
+            return Err(FixedBondError::NotPaused);
+        }
+        pausable::unpause(&e);
+        e.events()
+            .publish((Symbol::new(&e, "unpaused"),), Unpaused { admin });
+        Ok(())
+    }
+
+    /// Whether the contract is paused.
+    pub fn is_paused(e: Env) -> bool {
+        pausable::is_paused(&e)
+    }
+
+    /// The pause in effect, if any.
+    pub fn get_pause_info(e: Env) -> Option<PauseInfo> {
+        pausable::pause_info(&e)
+    }
+
+    /// The most recent pause, kept after `unpause`.
+    pub fn get_last_pause_info(e: Env) -> Option<PauseInfo> {
+        pausable::last_pause_info(&e)
     }
 
     // ── Bond lifecycle ─────────────────────────────────────────────────────
 
-    /// Lock `amount` USDC for `duration_secs` seconds.
+    /// Lock `amount` of the default token for `duration_secs` seconds and
+    /// return the new bond's id. An owner may hold any number of bonds at
+    /// once.
     ///
     /// Requirements:
     /// - `amount` > 0
-    /// - `duration_secs` > 0
-    /// - No currently active bond for `owner`
+    /// - `duration_secs` > 0 and within the configured maximum
     /// - Caller has approved the contract to spend `amount`
     ///
     /// A creation fee (if configured) is deducted from `amount`; the remaining
     /// principal is stored as `FixedBond.amount`.
-    pub fn create_bond(e: Env, owner: Address, amount: i128, duration_secs: u64) -> FixedBond {
+    pub fn create_bond(
+        e: Env,
+        owner: Address,
+        amount: i128,
+        duration_secs: u64,
+    ) -> Result<u64, FixedBondError> {
+        Self::create_bond_with_beneficiary(e, owner, amount, duration_secs, None)
+    }
+
+    /// `create_bond` with withdrawals paid to `beneficiary` instead of the
+    /// owner. `None` is the same as `create_bond`.
+    pub fn create_bond_with_beneficiary(
+        e: Env,
+        owner: Address,
+        amount: i128,
+        duration_secs: u64,
+        beneficiary: Option<Address>,
+    ) -> Result<u64, FixedBondError> {
+        let token = get_token(&e)?;
+        Self::create_bond_with_token(e, owner, token, amount, duration_secs, beneficiary)
+    }
+
+    /// `create_bond_with_beneficiary` in `token` instead of the default
+    /// token. Principal, yield, fees and penalties of the bond all stay in
+    /// `token`.
+    ///
+    /// Fails if `token` is not on the supported-token allowlist.
+    pub fn create_bond_with_token(
+        e: Env,
+        owner: Address,
+        token: Address,
+        amount: i128,
+        duration_secs: u64,
+        beneficiary: Option<Address>,
+    ) -> Result<u64, FixedBondError> {
         owner.require_auth();
+        require_not_paused(&e)?;
+        require_supported_token(&e, &token)?;
 
         if amount <= 0 {
-            panic!("{}", ERR_INVALID_AMOUNT);
+            return Err(FixedBondError::InvalidAmount);
         }
         if duration_secs == 0 {
-            panic!("{}", ERR_INVALID_DURATION);
-        }
-
-        // Reject if owner already has an active bond.
-        if let Some(existing) = e
-            .storage()
-            .persistent()
-            .get::<_, FixedBond>(&DataKey::Bond(owner.clone()))
-        {
-            if existing.active {
-                panic!("{}", ERR_BOND_ACTIVE);
-            }
+            return Err(FixedBondError::InvalidDuration);
         }
+        check_max_duration(&e, duration_secs)?;
 
         let bond_start = e.ledger().timestamp();
         let bond_expiry = bond_start
             .checked_add(duration_secs)
-            .expect(ERR_DURATION_OVERFLOW);
+            .ok_or(FixedBondError::DurationOverflow)?;
 
         // Pull tokens in first (caller must have approved).
-        let token = get_token(&e);
         let contract = e.current_contract_address();
         TokenClient::new(&e, &token).transfer_from(&contract, &owner, &contract, &amount);
 
         // Apply optional creation fee.
-        let net_amount = if let Some(cfg) = e
+        let (fee, net_amount) = match e
             .storage()
             .instance()
             .get::<_, FeeConfig>(&DataKey::FeeConfig)
         {
-            if cfg.fee_bps > 0 {
-                let (fee, net) = apply_bps(amount, cfg.fee_bps);
-                // Accumulate fee; treasury receives it at collect_fees.
-                let prev_fees: i128 = e
-                    .storage()
-                    .instance()
-                    .get(&DataKey::AccruedFees)
-                    .unwrap_or(0);
-                e.storage()
-                    .instance()
-                    .set(&DataKey::AccruedFees, &(prev_fees + fee));
-                net
-            } else {
-                amount
-            }
-        } else {
-            amount
+            Some(cfg) if cfg.fee_bps > 0 => apply_bps(amount, cfg.fee_bps)?,
+            _ => (0, amount),
         };
+        if fee > 0 {
+            accrue_fee(&e, &token, fee)?;
+        }
 
         // Read default penalty for early exits.
-        let penalty_bps: u32 = e
-            .storage()
-            .instance()
-            .get(&DataKey::PenaltyBps)
-            .unwrap_or(0);
+        let penalty_bps = default_penalty_bps(&e);
+
+        let bond_id = bond_count(&e, &owner) + 1;
+        e.storage()
+            .persistent()
+            .set(&DataKey::BondCount(owner.clone()), &bond_id);
 
         let bond = FixedBond {
             owner: owner.clone(),
+            bond_id,
+            token: token.clone(),
             amount: net_amount,
             bond_start,
             bond_duration: duration_secs,
             bond_expiry,
             penalty_bps,
+            yield_bps: yield_rate_for(&e, duration_secs),
+            beneficiary,
             active: true,
         };
-
-        e.storage()
-            .persistent()
-            .set(&DataKey::Bond(owner.clone()), &bond);
+        // Index the owner first so save_bond also bumps the new entries.
+        track_locked(&e, &owner, &token, net_amount, 1)?;
+        save_bond(&e, &bond);
+        register_owner(&e, &owner);
 
         e.events().publish(
-            (Symbol::new(&e, "bond_created"), owner),
-            (net_amount, bond_expiry),
+            (Symbol::new(&e, "bond_created"), owner.clone()),
+            FixedBondCreated {
+                owner,
+                bond_id,
+                token,
+                amount: net_amount,
+                fee,
+                expiry: bond_expiry,
+            },
         );
 
-        bond
+        Ok(bond_id)
     }
 
-    /// Withdraw the full bonded amount after the lock period has elapsed.
+    /// Withdraw the full bonded amount of bond `bond_id` after the lock period
+    /// has elapsed, plus its yield (see `get_pending_yield`). Yield is capped
+    /// by the pool balance; the principal is always paid in full.
     ///
-    /// Panics if there is no active bond or the lock period has not yet elapsed.
-    /// Deactivates the bond after successful transfer.
-    pub fn withdraw(e: Env, owner: Address) -> FixedBond {
+    /// Fails if there is no such active bond or the lock period has not yet
+    /// elapsed. Deactivates the bond after successful transfer.
+    pub fn withdraw(e: Env, owner: Address, bond_id: u64) -> Result<FixedBond, FixedBondError> {
         owner.require_auth();
+        require_not_paused(&e)?;
 
-        let mut bond: FixedBond = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Bond(owner.clone()))
-            .unwrap_or_else(|| panic!("{}", ERR_NO_BOND));
+        let mut bond = load_bond(&e, &owner, bond_id)?;
 
         if !bond.active {
-            panic!("{}", ERR_NO_BOND);
+            return Err(FixedBondError::BondNotFound);
         }
 
         let now = e.ledger().timestamp();
         if now < bond.bond_expiry {
-            panic!("{}", ERR_LOCK_PERIOD_NOT_ELAPSED);
+            return Err(FixedBondError::LockPeriodNotElapsed);
         }
 
+        let yield_paid = payable_yield(&e, &bond, bond.amount);
+
         // CEI: mark inactive before transfer.
         bond.active = false;
-        e.storage()
-            .persistent()
-            .set(&DataKey::Bond(owner.clone()), &bond);
+        save_bond(&e, &bond);
+        clear_matured_flag(&e, &owner, bond_id);
+        track_locked(&e, &owner, &bond.token, -bond.amount, -1)?;
+        draw_yield(&e, &bond.token, yield_paid);
 
-        let token = get_token(&e);
         let contract = e.current_contract_address();
-        TokenClient::new(&e, &token).transfer(&contract, &owner, &bond.amount);
+        let to = payout_address(&bond);
+        TokenClient::new(&e, &bond.token).transfer(&contract, &to, &(bond.amount + yield_paid));
 
-        e.events()
-            .publish((Symbol::new(&e, "bond_withdrawn"), owner), bond.amount);
+        e.events().publish(
+            (Symbol::new(&e, "bond_withdrawn"), owner.clone()),
+            FixedBondWithdrawn {
+                owner,
+                bond_id,
+                amount: bond.amount,
+                yield_paid,
+                recipient: to,
+            },
+        );
 
-        bond
+        Ok(bond)
     }
 
-    /// Withdraw before the lock period elapses, paying a penalty fee.
+    /// Withdraw `amount` of a matured bond's principal, leaving the rest
+    /// locked. The bond stays active while a positive balance remains and
+    /// is deactivated once it reaches zero. Yield on the withdrawn part is
+    /// paid with it.
     ///
-    /// Panics if:
-    /// - No active bond exists for `owner`.
-    /// - The bond has already matured (use `withdraw` instead).
-    /// - `penalty_bps` is 0 (early exit not enabled for this bond).
+    /// Fails if there is no such active bond, the lock period has not yet
+    /// elapsed, `amount` is not positive, or `amount` exceeds the remaining
+    /// balance.
+    pub fn withdraw_partial(
+        e: Env,
+        owner: Address,
+        bond_id: u64,
+        amount: i128,
+    ) -> Result<FixedBond, FixedBondError> {
+        owner.require_auth();
+        require_not_paused(&e)?;
+
+        if amount <= 0 {
+            return Err(FixedBondError::InvalidAmount);
+        }
+
+        let mut bond = load_bond(&e, &owner, bond_id)?;
+
+        if !bond.active {
+            return Err(FixedBondError::BondNotFound);
+        }
+
+        if e.ledger().timestamp() < bond.bond_expiry {
+            return Err(FixedBondError::LockPeriodNotElapsed);
+        }
+
+        if amount > bond.amount {
+            return Err(FixedBondError::InsufficientBalance);
+        }
+
+        let yield_paid = payable_yield(&e, &bond, amount);
+
+        // CEI: reduce the balance before transfer.
+        bond.amount -= amount;
+        bond.active = bond.amount > 0;
+        save_bond(&e, &bond);
+        if !bond.active {
+            clear_matured_flag(&e, &owner, bond_id);
+        }
+        track_locked(
+            &e,
+            &owner,
+            &bond.token,
+            -amount,
+            if bond.active { 0 } else { -1 },
+        )?;
+        draw_yield(&e, &bond.token, yield_paid);
+
+        let contract = e.current_contract_address();
+        let to = payout_address(&bond);
+        TokenClient::new(&e, &bond.token).transfer(&contract, &to, &(amount + yield_paid));
+
+        e.events().publish(
+            (Symbol::new(&e, "bond_partial_withdrawn"), owner.clone()),
+            FixedBondPartialWithdrawn {
+                owner,
+                bond_id,
+                amount,
+                remaining: bond.amount,
+                yield_paid,
+                recipient: to,
+            },
+        );
+
+        Ok(bond)
+    }
+
+    /// Pay future withdrawals of bond `bond_id` to `beneficiary`, or back
+    /// to the owner if `None`. Only the owner can change it.
     ///
-    /// Net amount = `bond.amount - penalty`. Penalty goes to the configured
-    /// treasury; if no fee config is set, the penalty is burned (not transferred).
-    pub fn withdraw_early(e: Env, owner: Address) -> FixedBond {
+    /// Fails if there is no such active bond.
+    pub fn set_beneficiary(
+        e: Env,
+        owner: Address,
+        bond_id: u64,
+        beneficiary: Option<Address>,
+    ) -> Result<FixedBond, FixedBondError> {
         owner.require_auth();
 
-        let mut bond: FixedBond = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Bond(owner.clone()))
-            .unwrap_or_else(|| panic!("{}", ERR_NO_BOND));
+        let mut bond = load_bond(&e, &owner, bond_id)?;
+        if !bond.active {
+            return Err(FixedBondError::BondNotFound);
+        }
+
+        bond.beneficiary = beneficiary;
+        save_bond(&e, &bond);
+
+        e.events().publish(
+            (Symbol::new(&e, "beneficiary_set"), owner.clone()),
+            BeneficiarySet {
+                owner,
+                bond_id,
+                recipient: payout_address(&bond),
+            },
+        );
+
+        Ok(bond)
+    }
+
+    /// Offer bond `bond_id` to `new_owner`, replacing any earlier proposal
+    /// for it. The bond moves only once `new_owner` calls `accept_transfer`
+    /// within the transfer window.
+    ///
+    /// Fails if there is no such active bond or `new_owner` is the owner.
+    pub fn propose_transfer(
+        e: Env,
+        owner: Address,
+        bond_id: u64,
+        new_owner: Address,
+    ) -> Result<PendingTransfer, FixedBondError> {
+        owner.require_auth();
 
+        let bond = load_bond(&e, &owner, bond_id)?;
         if !bond.active {
-            panic!("{}", ERR_NO_BOND);
+            return Err(FixedBondError::BondNotFound);
+        }
+        if new_owner == owner {
+            return Err(FixedBondError::SelfTransfer);
         }
 
-        let now = e.ledger().timestamp();
-        if now >= bond.bond_expiry {
-            panic!("bond has matured; use withdraw instead");
+        let expires_at = e
+            .ledger()
+            .timestamp()
+            .saturating_add(Self::get_transfer_window(e.clone()));
+        let pending = PendingTransfer {
+            new_owner: new_owner.clone(),
+            expires_at,
+        };
+        let pending_key = DataKey::PendingTransfer(owner.clone(), bond_id);
+        e.storage().persistent().set(&pending_key, &pending);
+        ttl::extend_until(&e, &pending_key, expires_at);
+
+        e.events().publish(
+            (Symbol::new(&e, "bond_transfer_proposed"), owner.clone()),
+            TransferProposed {
+                owner,
+                bond_id,
+                new_owner,
+                expires_at,
+            },
+        );
+
+        Ok(pending)
+    }
+
+    /// Withdraw the pending transfer proposal for bond `bond_id`.
+    ///
+    /// Fails if there is none.
+    pub fn cancel_transfer(e: Env, owner: Address, bond_id: u64) -> Result<(), FixedBondError> {
+        owner.require_auth();
+
+        let key = DataKey::PendingTransfer(owner.clone(), bond_id);
+        if !e.storage().persistent().has(&key) {
+            return Err(FixedBondError::NoPendingTransfer);
         }
+        e.storage().persistent().remove(&key);
 
-        if bond.penalty_bps == 0 {
-            panic!("{}", ERR_PENALTY_NOT_CONFIGURED);
+        e.events().publish(
+            (Symbol::new(&e, "bond_transfer_cancelled"), owner.clone()),
+            TransferCancelled { owner, bond_id },
+        );
+        Ok(())
+    }
+
+    /// Accept bond `bond_id` of `owner` as proposed to `new_owner`. The bond
+    /// is re-keyed under `new_owner` with the next id in their sequence,
+    /// which is returned; terms, balance and yield carry over, and any
+    /// beneficiary is cleared. The old record stays behind, inactive with a
+    /// zero balance.
+    ///
+    /// Fails if no proposal names `new_owner`, it has expired, or the bond
+    /// is no longer active.
+    pub fn accept_transfer(
+        e: Env,
+        new_owner: Address,
+        owner: Address,
+        bond_id: u64,
+    ) -> Result<u64, FixedBondError> {
+        new_owner.require_auth();
+
+        let key = DataKey::PendingTransfer(owner.clone(), bond_id);
+        let pending: PendingTransfer = e
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(FixedBondError::NoPendingTransfer)?;
+        if pending.new_owner != new_owner {
+            return Err(FixedBondError::NoPendingTransfer);
+        }
+        if e.ledger().timestamp() > pending.expires_at {
+            return Err(FixedBondError::TransferExpired);
         }
 
-        let (penalty, net_amount) = apply_bps(bond.amount, bond.penalty_bps);
+        let mut bond = load_bond(&e, &owner, bond_id)?;
+        if !bond.active {
+            return Err(FixedBondError::BondNotFound);
+        }
+        e.storage().persistent().remove(&key);
 
-        // CEI: mark inactive before transfers.
+        let mut moved = bond.clone();
+        bond.amount = 0;
         bond.active = false;
+        save_bond(&e, &bond);
+        clear_matured_flag(&e, &owner, bond_id);
+
+        let new_bond_id = bond_count(&e, &new_owner) + 1;
         e.storage()
             .persistent()
-            .set(&DataKey::Bond(owner.clone()), &bond);
+            .set(&DataKey::BondCount(new_owner.clone()), &new_bond_id);
+        moved.owner = new_owner.clone();
+        moved.bond_id = new_bond_id;
+        moved.beneficiary = None;
+        // Index the new owner before unindexing the old one so an owner page
+        // is never removed and recreated (losing its TTL) in one call.
+        track_locked(&e, &new_owner, &moved.token, moved.amount, 1)?;
+        track_locked(&e, &owner, &moved.token, -moved.amount, -1)?;
+        save_bond(&e, &moved);
+
+        e.events().publish(
+            (Symbol::new(&e, "bond_transferred"), owner.clone()),
+            FixedBondTransferred {
+                owner,
+                bond_id,
+                new_owner,
+                new_bond_id,
+            },
+        );
+
+        Ok(new_bond_id)
+    }
+
+    /// `withdraw` for an owner with exactly one active bond.
+    /// Fails if the owner has several active bonds.
+    pub fn withdraw_single(e: Env, owner: Address) -> Result<FixedBond, FixedBondError> {
+        let bond_id = single_active_bond_id(&e, &owner)?;
+        Self::withdraw(e, owner, bond_id)
+    }
+
+    /// Withdraw bond `bond_id` before the lock period elapses, paying a
+    /// penalty fee scaled by the time left to maturity, or the full
+    /// `penalty_bps` in flat mode (see `set_flat_penalty`).
+    ///
+    /// Fails if:
+    /// - No such active bond exists for `owner`.
+    /// - The bond has already matured (use `withdraw` instead).
+    /// - `penalty_bps` is 0 (early exit not enabled for this bond).
+    ///
+    /// Net amount = `bond.amount - penalty`; no yield is paid. Penalty goes to the configured
+    /// treasury; if no fee config is set, the penalty is burned (not transferred).
+    pub fn withdraw_early(
+        e: Env,
+        owner: Address,
+        bond_id: u64,
+    ) -> Result<FixedBond, FixedBondError> {
+        owner.require_auth();
+        require_not_paused(&e)?;
+
+        let mut bond = load_bond(&e, &owner, bond_id)?;
+        let (penalty, net_amount) = early_exit_quote(&e, &bond)?;
+
+        // CEI: mark inactive before transfers.
+        bond.active = false;
+        save_bond(&e, &bond);
+        track_locked(&e, &owner, &bond.token, -bond.amount, -1)?;
 
-        let token = get_token(&e);
         let contract = e.current_contract_address();
-        let token_client = TokenClient::new(&e, &token);
+        let token_client = TokenClient::new(&e, &bond.token);
 
-        // Return net amount to owner.
-        token_client.transfer(&contract, &owner, &net_amount);
+        // Return net amount to the owner or their beneficiary.
+        let to = payout_address(&bond);
+        token_client.transfer(&contract, &to, &net_amount);
 
         // Send penalty to treasury if configured.
         if penalty > 0 {
@@ -310,47 +1549,352 @@ impl FixedDurationBond {
         }
 
         e.events().publish(
-            (Symbol::new(&e, "bond_early_exit"), owner),
-            (net_amount, penalty),
+            (Symbol::new(&e, "bond_early_exit"), owner.clone()),
+            FixedBondEarlyExit {
+                owner,
+                bond_id,
+                net: net_amount,
+                penalty,
+                recipient: to,
+            },
+        );
+
+        Ok(bond)
+    }
+
+    /// Push back the expiry of an active, not yet matured bond by
+    /// `additional_secs`. The total `bond_duration` must stay within the
+    /// configured maximum.
+    ///
+    /// Fails if there is no such active bond, the bond has matured,
+    /// `additional_secs` is 0, or the new duration or expiry is out of range.
+    pub fn extend_duration(
+        e: Env,
+        owner: Address,
+        bond_id: u64,
+        additional_secs: u64,
+    ) -> Result<FixedBond, FixedBondError> {
+        owner.require_auth();
+        require_not_paused(&e)?;
+
+        if additional_secs == 0 {
+            return Err(FixedBondError::InvalidDuration);
+        }
+
+        let mut bond = load_bond(&e, &owner, bond_id)?;
+
+        if !bond.active {
+            return Err(FixedBondError::BondNotFound);
+        }
+
+        if e.ledger().timestamp() >= bond.bond_expiry {
+            return Err(FixedBondError::BondMatured);
+        }
+
+        let new_duration = bond
+            .bond_duration
+            .checked_add(additional_secs)
+            .ok_or(FixedBondError::DurationOverflow)?;
+        check_max_duration(&e, new_duration)?;
+        let old_expiry = bond.bond_expiry;
+        let new_expiry = old_expiry
+            .checked_add(additional_secs)
+            .ok_or(FixedBondError::DurationOverflow)?;
+
+        bond.bond_duration = new_duration;
+        bond.bond_expiry = new_expiry;
+        save_bond(&e, &bond);
+
+        e.events().publish(
+            (Symbol::new(&e, "bond_extended"), owner.clone()),
+            FixedBondExtended {
+                owner,
+                bond_id,
+                old_expiry,
+                new_expiry,
+            },
+        );
+
+        Ok(bond)
+    }
+
+    /// Re-lock a matured, still active bond for `new_duration_secs` from now
+    /// instead of withdrawing and creating a new one.
+    ///
+    /// `bond_start` resets to the current time and `penalty_bps` and
+    /// `yield_bps` are refreshed from the current configuration. Yield earned
+    /// over the finished term is added to the principal, then the rollover
+    /// fee (see `set_rollover_fee_bps`) is deducted from it and accrued with
+    /// the creation fees.
+    ///
+    /// Fails if there is no such active bond, the lock period has not yet
+    /// elapsed, `new_duration_secs` is 0, above the maximum or overflows, or
+    /// the bond's token is no longer supported.
+    pub fn rollover(
+        e: Env,
+        owner: Address,
+        bond_id: u64,
+        new_duration_secs: u64,
+    ) -> Result<FixedBond, FixedBondError> {
+        owner.require_auth();
+        require_not_paused(&e)?;
+
+        if new_duration_secs == 0 {
+            return Err(FixedBondError::InvalidDuration);
+        }
+        check_max_duration(&e, new_duration_secs)?;
+
+        let mut bond = load_bond(&e, &owner, bond_id)?;
+
+        if !bond.active {
+            return Err(FixedBondError::BondNotFound);
+        }
+
+        let now = e.ledger().timestamp();
+        if now < bond.bond_expiry {
+            return Err(FixedBondError::LockPeriodNotElapsed);
+        }
+        require_supported_token(&e, &bond.token)?;
+
+        let new_expiry = now
+            .checked_add(new_duration_secs)
+            .ok_or(FixedBondError::DurationOverflow)?;
+
+        let earned = payable_yield(&e, &bond, bond.amount);
+        draw_yield(&e, &bond.token, earned);
+
+        let (fee, net) = apply_bps(bond.amount + earned, Self::get_rollover_fee_bps(e.clone()))?;
+        if fee > 0 {
+            accrue_fee(&e, &bond.token, fee)?;
+        }
+
+        let old_expiry = bond.bond_expiry;
+        track_locked(&e, &owner, &bond.token, net - bond.amount, 0)?;
+        bond.amount = net;
+        bond.bond_start = now;
+        bond.bond_duration = new_duration_secs;
+        bond.bond_expiry = new_expiry;
+        bond.penalty_bps = default_penalty_bps(&e);
+        bond.yield_bps = yield_rate_for(&e, new_duration_secs);
+        save_bond(&e, &bond);
+        clear_matured_flag(&e, &owner, bond_id);
+
+        e.events().publish(
+            (Symbol::new(&e, "bond_rolled_over"), owner.clone()),
+            FixedBondRolledOver {
+                owner,
+                bond_id,
+                old_expiry,
+                new_expiry,
+                fee,
+            },
+        );
+
+        Ok(bond)
+    }
+
+    /// `withdraw_early` for an owner with exactly one active bond.
+    /// Fails if the owner has several active bonds.
+    pub fn withdraw_early_single(e: Env, owner: Address) -> Result<FixedBond, FixedBondError> {
+        let bond_id = single_active_bond_id(&e, &owner)?;
+        Self::withdraw_early(e, owner, bond_id)
+    }
+
+    /// Mark bond `bond_id` of `owner` as matured but unclaimed, so indexers
+    /// and wallets can nudge the owner. Anyone may call this once
+    /// `bond_expiry + grace` has passed; it moves no funds and leaves the
+    /// bond active. Flagging again returns the existing flag unchanged.
+    /// Withdrawing, rolling over or transferring the bond clears the flag.
+    ///
+    /// Fails if there is no such active bond or the grace window is still
+    /// open.
+    pub fn flag_matured(
+        e: Env,
+        caller: Address,
+        owner: Address,
+        bond_id: u64,
+    ) -> Result<MaturedFlag, FixedBondError> {
+        caller.require_auth();
+
+        let bond = load_bond(&e, &owner, bond_id)?;
+        if !bond.active {
+            return Err(FixedBondError::BondNotFound);
+        }
+        let key = DataKey::MaturedFlag(owner.clone(), bond_id);
+        if let Some(flag) = e.storage().persistent().get(&key) {
+            return Ok(flag);
+        }
+
+        let now = e.ledger().timestamp();
+        let flaggable_at = bond
+            .bond_expiry
+            .saturating_add(Self::get_maturity_grace(e.clone()));
+        if now < flaggable_at {
+            return Err(FixedBondError::GracePeriodNotElapsed);
+        }
+
+        let flag = MaturedFlag {
+            flagged_by: caller.clone(),
+            flagged_at: now,
+        };
+        e.storage().persistent().set(&key, &flag);
+        ttl::extend_until(&e, &key, bond.bond_expiry);
+
+        e.events().publish(
+            (Symbol::new(&e, "bond_matured_unclaimed"), owner.clone()),
+            BondMaturedUnclaimed {
+                owner,
+                bond_id,
+                bond_expiry: bond.bond_expiry,
+                flagged_by: caller,
+            },
         );
+        Ok(flag)
+    }
 
-        bond
+    /// Keep bond `bond_id` of `owner` from being archived: bumps the bond
+    /// record and the owner's bond counter, index entries, pending transfer
+    /// and maturity flag to outlive the bond's expiry, and the contract
+    /// instance. Callable by anyone; only the caller pays.
+    ///
+    /// Fails if there is no such bond.
+    pub fn extend_bond_ttl(e: Env, owner: Address, bond_id: u64) -> Result<(), FixedBondError> {
+        let bond = load_bond(&e, &owner, bond_id)?;
+        let expiry = bond.bond_expiry;
+        extend_owner_ttl(&e, &owner, expiry);
+        ttl::extend_until(
+            &e,
+            &DataKey::PendingTransfer(owner.clone(), bond_id),
+            expiry,
+        );
+        ttl::extend_until(&e, &DataKey::MaturedFlag(owner, bond_id), expiry);
+        Ok(())
     }
 
     // ── Queries ────────────────────────────────────────────────────────────
 
-    /// Returns the bond state for `owner`.
-    /// Panics if no bond record exists.
-    pub fn get_bond(e: Env, owner: Address) -> FixedBond {
+    /// Returns the contract name, version, token and admin.
+    /// Fails if the contract is not initialized.
+    pub fn get_contract_info(e: Env) -> Result<ContractInfo, FixedBondError> {
+        Ok(ContractInfo {
+            name: Symbol::new(&e, "fixed_duration_bond"),
+            version: CONTRACT_VERSION,
+            token: e.storage().instance().get(&DataKey::Token),
+            admin: e
+                .storage()
+                .instance()
+                .get(&DataKey::Admin)
+                .ok_or(FixedBondError::NotInitialized)?,
+        })
+    }
+
+    /// Returns bond `bond_id` of `owner`.
+    /// Fails if no bond record exists.
+    pub fn get_bond(e: Env, owner: Address, bond_id: u64) -> Result<FixedBond, FixedBondError> {
+        Ok(load_bond(&e, &owner, bond_id)?)
+    }
+
+    /// Returns bond `bond_id` of `owner` together with its maturity and
+    /// current early-exit quote, or `None` if no bond record exists.
+    pub fn get_bond_status(e: Env, owner: Address, bond_id: u64) -> Option<BondStatus> {
+        let bond = load_bond(&e, &owner, bond_id).ok()?;
+        Some(bond_status(&e, bond))
+    }
+
+    /// `get_bond_status` for the owner's only active bond, or `None` if the
+    /// owner has no active bond.
+    /// Fails if the owner has several active bonds.
+    pub fn get_bond_status_single(
+        e: Env,
+        owner: Address,
+    ) -> Result<Option<BondStatus>, FixedBondError> {
+        match single_active_bond_id(&e, &owner) {
+            Ok(bond_id) => Ok(Self::get_bond_status(e, owner, bond_id)),
+            Err(FixedBondError::BondNotFound) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// `get_bond` for an owner with exactly one active bond.
+    /// Fails if the owner has no active bond or several.
+    pub fn get_bond_single(e: Env, owner: Address) -> Result<FixedBond, FixedBondError> {
+        let bond_id = single_active_bond_id(&e, &owner)?;
+        Ok(load_bond(&e, &owner, bond_id)?)
+    }
+
+    /// Returns the ids of every bond `owner` has created, withdrawn or not,
+    /// in creation order.
+    pub fn get_owner_bonds(e: Env, owner: Address) -> Vec<u64> {
+        let mut ids = Vec::new(&e);
+        for bond_id in 1..=bond_count(&e, &owner) {
+            ids.push_back(bond_id);
+        }
+        ids
+    }
+
+    /// Returns the open transfer proposal for bond `bond_id`, if any.
+    /// Expired proposals are returned until replaced or cancelled.
+    pub fn get_pending_transfer(e: Env, owner: Address, bond_id: u64) -> Option<PendingTransfer> {
         e.storage()
             .persistent()
-            .get(&DataKey::Bond(owner))
-            .unwrap_or_else(|| panic!("{}", ERR_NO_BOND))
+            .get(&DataKey::PendingTransfer(owner, bond_id))
     }
 
-    /// Returns `true` if the bond's lock period has elapsed.
-    pub fn is_matured(e: Env, owner: Address) -> bool {
-        let bond: FixedBond = e
-            .storage()
+    /// Returns the unclaimed-maturity flag of bond `bond_id`, if any.
+    pub fn get_matured_flag(e: Env, owner: Address, bond_id: u64) -> Option<MaturedFlag> {
+        e.storage()
             .persistent()
-            .get(&DataKey::Bond(owner))
-            .unwrap_or_else(|| panic!("{}", ERR_NO_BOND));
-        e.ledger().timestamp() >= bond.bond_expiry
+            .get(&DataKey::MaturedFlag(owner, bond_id))
+    }
+
+    /// Returns `true` if the bond's lock period has elapsed.
+    pub fn is_matured(e: Env, owner: Address, bond_id: u64) -> Result<bool, FixedBondError> {
+        let bond = load_bond(&e, &owner, bond_id)?;
+        Ok(e.ledger().timestamp() >= bond.bond_expiry)
+    }
+
+    /// Yield `withdraw` would pay on bond `bond_id` at maturity, given the
+    /// current pool balance. 0 for inactive bonds.
+    pub fn get_pending_yield(e: Env, owner: Address, bond_id: u64) -> Result<i128, FixedBondError> {
+        let bond = load_bond(&e, &owner, bond_id)?;
+        if !bond.active {
+            return Ok(0);
+        }
+        Ok(payable_yield(&e, &bond, bond.amount))
+    }
+
+    /// `(penalty, net)` that `withdraw_early` would apply to bond `bond_id`
+    /// right now. Fails in the same cases as `withdraw_early`.
+    pub fn preview_early_exit(
+        e: Env,
+        owner: Address,
+        bond_id: u64,
+    ) -> Result<(i128, i128), FixedBondError> {
+        let bond = load_bond(&e, &owner, bond_id)?;
+        Ok(early_exit_quote(&e, &bond)?)
+    }
+
+    /// `is_matured` for an owner with exactly one active bond.
+    pub fn is_matured_single(e: Env, owner: Address) -> Result<bool, FixedBondError> {
+        let bond_id = single_active_bond_id(&e, &owner)?;
+        Self::is_matured(e, owner, bond_id)
     }
 
     /// Returns the number of seconds remaining until maturity.
     /// Returns 0 if already matured.
-    pub fn get_time_remaining(e: Env, owner: Address) -> u64 {
-        let bond: FixedBond = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Bond(owner))
-            .unwrap_or_else(|| panic!("{}", ERR_NO_BOND));
+    pub fn get_time_remaining(e: Env, owner: Address, bond_id: u64) -> Result<u64, FixedBondError> {
+        let bond = load_bond(&e, &owner, bond_id)?;
         let now = e.ledger().timestamp();
         if now >= bond.bond_expiry {
-            0_u64
+            Ok(0_u64)
         } else {
-            bond.bond_expiry - now
+            Ok(bond.bond_expiry - now)
         }
     }
+
+    /// `get_time_remaining` for an owner with exactly one active bond.
+    pub fn get_time_remaining_single(e: Env, owner: Address) -> Result<u64, FixedBondError> {
+        let bond_id = single_active_bond_id(&e, &owner)?;
+        Self::get_time_remaining(e, owner, bond_id)
+    }
 }
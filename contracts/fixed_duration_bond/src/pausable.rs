@@ -0,0 +1,63 @@
+//! Pause state for the fixed bond contract.
+//!
+//! A pause lasts until the admin lifts it. Each pause records a [`PauseInfo`]
+//! with who paused and why; the most recent one stays readable after
+//! unpausing.
+
+use soroban_sdk::{contracttype, Address, Env, String, Symbol};
+
+/// Details of a pause.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PauseInfo {
+    /// Timestamp when the pause started
+    pub since: u64,
+    /// Short reason code
+    pub reason: Symbol,
+    /// Optional longer explanation
+    pub detail: Option<String>,
+    /// Address that paused
+    pub paused_by: Address,
+}
+
+#[contracttype]
+#[derive(Clone)]
+enum PauseKey {
+    /// Pause currently in effect
+    Current,
+    /// Most recent pause, kept after unpausing
+    Last,
+}
+
+/// Whether the contract is currently paused.
+pub fn is_paused(e: &Env) -> bool {
+    e.storage().instance().has(&PauseKey::Current)
+}
+
+/// The pause in effect, if any.
+pub fn pause_info(e: &Env) -> Option<PauseInfo> {
+    e.storage().instance().get(&PauseKey::Current)
+}
+
+/// The most recent pause, kept after unpausing.
+pub fn last_pause_info(e: &Env) -> Option<PauseInfo> {
+    e.storage().instance().get(&PauseKey::Last)
+}
+
+/// Pause until [`unpause`] is called.
+pub fn pause(e: &Env, paused_by: &Address, reason: Symbol, detail: Option<String>) -> PauseInfo {
+    let info = PauseInfo {
+        since: e.ledger().timestamp(),
+        reason,
+        detail,
+        paused_by: paused_by.clone(),
+    };
+    e.storage().instance().set(&PauseKey::Current, &info);
+    e.storage().instance().set(&PauseKey::Last, &info);
+    info
+}
+
+/// Lift the pause.
+pub fn unpause(e: &Env) {
+    e.storage().instance().remove(&PauseKey::Current);
+}
@@ -61,3 +61,29 @@ pub fn setup_with_mint(
 
     (client, admin, owner, stellar_asset, contract_id)
 }
+
+/// Mint `DEFAULT_MINT` to `holder` and approve the contract to spend it.
+pub fn fund(e: &Env, token: &Address, contract_id: &Address, holder: &Address) {
+    let asset_admin = StellarAssetClient::new(e, token);
+    asset_admin.set_authorized(holder, &true);
+    asset_admin.mint(holder, &DEFAULT_MINT);
+
+    let expiry_ledger = e.ledger().sequence().saturating_add(10_000);
+    TokenClient::new(e, token).approve(holder, contract_id, &DEFAULT_MINT, &expiry_ledger);
+}
+
+/// Deploy a second token, allowlist it, and fund and approve `holder`.
+pub fn add_second_token(
+    e: &Env,
+    client: &FixedDurationBondClient,
+    admin: &Address,
+    contract_id: &Address,
+    holder: &Address,
+) -> Address {
+    let token = e
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    fund(e, &token, contract_id, holder);
+    client.add_supported_token(admin, &token);
+    token
+}
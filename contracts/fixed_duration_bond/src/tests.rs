@@ -2,11 +2,22 @@
 
 #![cfg(test)]
 
+use crate::events::{
+    AdminTransferred, BondMaturedUnclaimed, FeesCollected, FixedBondCreated, FixedBondEarlyExit,
+    FixedBondWithdrawn, Paused, RegistryFailed, TransferCancelled,
+};
 use crate::test_helpers::*;
-use crate::{FixedDurationBond, FixedDurationBondClient};
-use soroban_sdk::testutils::{Address as _, Ledger};
+use crate::ttl::{BUMP_TARGET, SECONDS_PER_LEDGER};
+use crate::types::{DataKey, FixedBond, YieldTier};
+use crate::{
+    accrued_yield, FixedBondError, FixedDurationBond, FixedDurationBondClient, CONTRACT_VERSION,
+    DEFAULT_MATURITY_GRACE, MAX_OWNERS_PER_QUERY, OWNER_PAGE_SIZE, SECONDS_PER_YEAR,
+};
+use credence_registry::{CredenceRegistry, CredenceRegistryClient};
+use soroban_sdk::testutils::storage::Persistent as _;
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
 use soroban_sdk::token::TokenClient;
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{vec, Address, Env, IntoVal, String, Symbol, Val, Vec};
 
 // ═══════════════════════════════════════════════════════════════════
 // 1. Initialization
@@ -24,8 +35,7 @@ fn test_initialize_success() {
 }
 
 #[test]
-#[should_panic(expected = "already initialized")]
-fn test_initialize_twice_panics() {
+fn test_initialize_twice_fails() {
     let e = Env::default();
     e.mock_all_auths();
     let contract_id = e.register(FixedDurationBond, ());
@@ -33,7 +43,31 @@ fn test_initialize_twice_panics() {
     let admin = Address::generate(&e);
     let token = Address::generate(&e);
     client.initialize(&admin, &token);
-    client.initialize(&admin, &token);
+    assert_eq!(
+        client.try_initialize(&admin, &token),
+        Err(Ok(FixedBondError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_get_contract_info() {
+    let e = Env::default();
+    let (client, admin, _owner, token, _contract_id) = setup(&e);
+    let info = client.get_contract_info();
+    assert_eq!(info.name, Symbol::new(&e, "fixed_duration_bond"));
+    assert_eq!(info.version, CONTRACT_VERSION);
+    assert_eq!(info.token, Some(token));
+    assert_eq!(info.admin, admin);
+}
+
+#[test]
+fn test_get_contract_info_uninitialized_fails() {
+    let e = Env::default();
+    let contract_id = e.register(FixedDurationBond, ());
+    assert_eq!(
+        FixedDurationBondClient::new(&e, &contract_id).try_get_contract_info(),
+        Err(Ok(FixedBondError::NotInitialized))
+    );
 }
 
 // ═══════════════════════════════════════════════════════════════════
@@ -45,7 +79,8 @@ fn test_create_bond_success() {
     let e = Env::default();
     let (client, _admin, owner, _token, _cid) = setup(&e);
 
-    let bond = client.create_bond(&owner, &1_000_000_i128, &ONE_DAY);
+    let bond_id = client.create_bond(&owner, &1_000_000_i128, &ONE_DAY);
+    let bond = client.get_bond(&owner, &bond_id);
 
     assert!(bond.active);
     assert_eq!(bond.amount, 1_000_000);
@@ -60,7 +95,8 @@ fn test_create_bond_stores_expiry_correctly() {
     e.ledger().with_mut(|li| li.timestamp = 1_000_000);
     let (client, _admin, owner, _token, _cid) = setup(&e);
 
-    let bond = client.create_bond(&owner, &5_000_000_i128, &ONE_WEEK);
+    let bond_id = client.create_bond(&owner, &5_000_000_i128, &ONE_WEEK);
+    let bond = client.get_bond(&owner, &bond_id);
 
     assert_eq!(bond.bond_start, 1_000_000);
     assert_eq!(bond.bond_expiry, 1_000_000 + ONE_WEEK);
@@ -70,7 +106,8 @@ fn test_create_bond_stores_expiry_correctly() {
 fn test_create_bond_with_min_positive_amount() {
     let e = Env::default();
     let (client, _admin, owner, _token, _cid) = setup(&e);
-    let bond = client.create_bond(&owner, &1_i128, &ONE_DAY);
+    let bond_id = client.create_bond(&owner, &1_i128, &ONE_DAY);
+    let bond = client.get_bond(&owner, &bond_id);
     assert_eq!(bond.amount, 1);
     assert!(bond.active);
 }
@@ -80,7 +117,8 @@ fn test_create_bond_usdc_amount() {
     let e = Env::default();
     let (client, _admin, owner, _token, _cid) = setup(&e);
     let usdc = 100_000_000_i128; // 100 USDC (6 decimals)
-    let bond = client.create_bond(&owner, &usdc, &ONE_DAY);
+    let bond_id = client.create_bond(&owner, &usdc, &ONE_DAY);
+    let bond = client.get_bond(&owner, &bond_id);
     assert_eq!(bond.amount, usdc);
 }
 
@@ -89,45 +127,78 @@ fn test_create_bond_usdc_amount() {
 // ═══════════════════════════════════════════════════════════════════
 
 #[test]
-#[should_panic(expected = "amount must be positive")]
-fn test_create_bond_zero_amount_panics() {
+fn test_create_bond_zero_amount_fails() {
     let e = Env::default();
     let (client, _admin, owner, _token, _cid) = setup(&e);
-    client.create_bond(&owner, &0_i128, &ONE_DAY);
+    assert_eq!(
+        client.try_create_bond(&owner, &0_i128, &ONE_DAY),
+        Err(Ok(FixedBondError::InvalidAmount))
+    );
 }
 
 #[test]
-#[should_panic(expected = "amount must be positive")]
-fn test_create_bond_negative_amount_panics() {
+fn test_create_bond_negative_amount_fails() {
     let e = Env::default();
     let (client, _admin, owner, _token, _cid) = setup(&e);
-    client.create_bond(&owner, &(-1_i128), &ONE_DAY);
+    assert_eq!(
+        client.try_create_bond(&owner, &(-1_i128), &ONE_DAY),
+        Err(Ok(FixedBondError::InvalidAmount))
+    );
 }
 
 #[test]
-#[should_panic(expected = "duration must be positive")]
-fn test_create_bond_zero_duration_panics() {
+fn test_create_bond_zero_duration_fails() {
     let e = Env::default();
     let (client, _admin, owner, _token, _cid) = setup(&e);
-    client.create_bond(&owner, &1_000_i128, &0_u64);
+    assert_eq!(
+        client.try_create_bond(&owner, &1_000_i128, &0_u64),
+        Err(Ok(FixedBondError::InvalidDuration))
+    );
 }
 
 #[test]
-#[should_panic(expected = "bond expiry timestamp would overflow")]
-fn test_create_bond_overflow_panics() {
+fn test_create_bond_overflow_fails() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = u64::MAX - 500);
     let (client, _admin, owner, _token, _cid) = setup(&e);
-    client.create_bond(&owner, &1_000_i128, &1_000_u64);
+    assert_eq!(
+        client.try_create_bond(&owner, &1_000_i128, &1_000_u64),
+        Err(Ok(FixedBondError::DurationOverflow))
+    );
 }
 
 #[test]
-#[should_panic(expected = "bond already active for this owner")]
-fn test_create_bond_duplicate_active_panics() {
+fn test_create_bond_concurrent_bonds_get_own_ids() {
     let e = Env::default();
     let (client, _admin, owner, _token, _cid) = setup(&e);
-    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
-    client.create_bond(&owner, &2_000_i128, &ONE_DAY);
+
+    let short_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    let long_id = client.create_bond(&owner, &2_000_i128, &ONE_WEEK);
+    assert_eq!((short_id, long_id), (1, 2));
+
+    let short = client.get_bond(&owner, &short_id);
+    let long = client.get_bond(&owner, &long_id);
+    assert!(short.active && long.active);
+    assert_eq!((short.bond_id, short.amount), (1, 1_000));
+    assert_eq!(
+        (long.bond_id, long.amount, long.bond_duration),
+        (2, 2_000, ONE_WEEK)
+    );
+    assert_eq!(client.get_owner_bonds(&owner), vec![&e, 1_u64, 2_u64]);
+}
+
+#[test]
+fn test_bond_ids_are_per_owner() {
+    let e = Env::default();
+    let (client, _admin, owner, token, contract_id) = setup(&e);
+    let other = Address::generate(&e);
+    fund(&e, &token, &contract_id, &other);
+
+    assert_eq!(client.create_bond(&owner, &1_000_i128, &ONE_DAY), 1);
+    assert_eq!(client.create_bond(&other, &1_000_i128, &ONE_DAY), 1);
+    assert_eq!(client.create_bond(&owner, &1_000_i128, &ONE_DAY), 2);
+    assert_eq!(client.get_owner_bonds(&other), vec![&e, 1_u64]);
+    assert_eq!(client.get_owner_bonds(&Address::generate(&e)).len(), 0);
 }
 
 // ═══════════════════════════════════════════════════════════════════
@@ -138,17 +209,17 @@ fn test_create_bond_duplicate_active_panics() {
 fn test_is_matured_false_before_expiry() {
     let e = Env::default();
     let (client, _admin, owner, _token, _cid) = setup(&e);
-    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
-    assert!(!client.is_matured(&owner));
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    assert!(!client.is_matured(&owner, &bond_id));
 }
 
 #[test]
 fn test_is_matured_true_after_expiry() {
     let e = Env::default();
     let (client, _admin, owner, _token, _cid) = setup(&e);
-    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
     e.ledger().with_mut(|li| li.timestamp += ONE_DAY + 1);
-    assert!(client.is_matured(&owner));
+    assert!(client.is_matured(&owner, &bond_id));
 }
 
 #[test]
@@ -156,9 +227,9 @@ fn test_is_matured_true_at_exact_expiry() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 1_000);
     let (client, _admin, owner, _token, _cid) = setup(&e);
-    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
     e.ledger().with_mut(|li| li.timestamp = 1_000 + ONE_DAY);
-    assert!(client.is_matured(&owner));
+    assert!(client.is_matured(&owner, &bond_id));
 }
 
 #[test]
@@ -166,9 +237,9 @@ fn test_get_time_remaining_before_expiry() {
     let e = Env::default();
     e.ledger().with_mut(|li| li.timestamp = 0);
     let (client, _admin, owner, _token, _cid) = setup(&e);
-    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
     e.ledger().with_mut(|li| li.timestamp = ONE_DAY / 2);
-    let remaining = client.get_time_remaining(&owner);
+    let remaining = client.get_time_remaining(&owner, &bond_id);
     assert_eq!(remaining, ONE_DAY - ONE_DAY / 2);
 }
 
@@ -176,9 +247,9 @@ fn test_get_time_remaining_before_expiry() {
 fn test_get_time_remaining_zero_after_maturity() {
     let e = Env::default();
     let (client, _admin, owner, _token, _cid) = setup(&e);
-    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
     e.ledger().with_mut(|li| li.timestamp += ONE_DAY + 100);
-    assert_eq!(client.get_time_remaining(&owner), 0_u64);
+    assert_eq!(client.get_time_remaining(&owner, &bond_id), 0_u64);
 }
 
 // ═══════════════════════════════════════════════════════════════════
@@ -191,10 +262,10 @@ fn test_withdraw_success_after_maturity() {
     let (client, _admin, owner, token_addr, contract_id) = setup(&e);
 
     let amount = 5_000_000_i128;
-    client.create_bond(&owner, &amount, &ONE_DAY);
+    let bond_id = client.create_bond(&owner, &amount, &ONE_DAY);
 
     e.ledger().with_mut(|li| li.timestamp += ONE_DAY + 1);
-    let bond = client.withdraw(&owner);
+    let bond = client.withdraw(&owner, &bond_id);
 
     assert!(!bond.active);
     let tok = TokenClient::new(&e, &token_addr);
@@ -203,42 +274,151 @@ fn test_withdraw_success_after_maturity() {
 }
 
 #[test]
-#[should_panic(expected = "lock period has not elapsed yet")]
-fn test_withdraw_before_maturity_panics() {
+fn test_withdraw_before_maturity_fails() {
     let e = Env::default();
     let (client, _admin, owner, _token, _cid) = setup(&e);
-    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
-    client.withdraw(&owner);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    assert_eq!(
+        client.try_withdraw(&owner, &bond_id),
+        Err(Ok(FixedBondError::LockPeriodNotElapsed))
+    );
 }
 
 #[test]
-#[should_panic(expected = "no active bond found")]
-fn test_withdraw_no_bond_panics() {
+fn test_withdraw_no_bond_fails() {
     let e = Env::default();
     let (client, _admin, _owner, _token, _cid) = setup(&e);
     let other = Address::generate(&e);
-    client.withdraw(&other);
+    assert_eq!(
+        client.try_withdraw(&other, &1),
+        Err(Ok(FixedBondError::BondNotFound))
+    );
 }
 
 #[test]
-#[should_panic(expected = "no active bond found")]
-fn test_withdraw_already_withdrawn_panics() {
+fn test_withdraw_already_withdrawn_fails() {
     let e = Env::default();
     let (client, _admin, owner, _token, _cid) = setup(&e);
-    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
     e.ledger().with_mut(|li| li.timestamp += ONE_DAY + 1);
-    client.withdraw(&owner);
-    client.withdraw(&owner); // second call should panic
+    client.withdraw(&owner, &bond_id);
+    // Second withdrawal of the same bond.
+    assert_eq!(
+        client.try_withdraw(&owner, &bond_id),
+        Err(Ok(FixedBondError::BondNotFound))
+    );
 }
 
 #[test]
 fn test_withdraw_deactivates_bond() {
     let e = Env::default();
     let (client, _admin, owner, _token, _cid) = setup(&e);
-    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
     e.ledger().with_mut(|li| li.timestamp += ONE_DAY + 1);
-    let bond = client.withdraw(&owner);
+    let bond = client.withdraw(&owner, &bond_id);
+    assert!(!bond.active);
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// 4b. Partial withdrawal (after lock)
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_withdraw_partial_keeps_remainder_locked() {
+    let e = Env::default();
+    let (client, _admin, owner, token_addr, contract_id) = setup(&e);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+
+    let bond = client.withdraw_partial(&owner, &bond_id, &4_000_i128);
+    assert!(bond.active);
+    assert_eq!(bond.amount, 6_000);
+    assert_eq!(client.get_bond(&owner, &bond_id).amount, 6_000);
+
+    let tok = TokenClient::new(&e, &token_addr);
+    assert_eq!(tok.balance(&owner), DEFAULT_MINT - 6_000);
+    assert_eq!(tok.balance(&contract_id), 6_000);
+}
+
+#[test]
+fn test_withdraw_partial_full_balance_deactivates() {
+    let e = Env::default();
+    let (client, _admin, owner, token_addr, contract_id) = setup(&e);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+
+    client.withdraw_partial(&owner, &bond_id, &2_500_i128);
+    let bond = client.withdraw_partial(&owner, &bond_id, &7_500_i128);
     assert!(!bond.active);
+    assert_eq!(bond.amount, 0);
+
+    let tok = TokenClient::new(&e, &token_addr);
+    assert_eq!(tok.balance(&owner), DEFAULT_MINT);
+    assert_eq!(tok.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_withdraw_after_partial_pays_remainder() {
+    let e = Env::default();
+    let (client, _admin, owner, token_addr, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+
+    client.withdraw_partial(&owner, &bond_id, &1_000_i128);
+    let bond = client.withdraw(&owner, &bond_id);
+    assert_eq!(bond.amount, 9_000);
+    assert_eq!(
+        TokenClient::new(&e, &token_addr).balance(&owner),
+        DEFAULT_MINT
+    );
+}
+
+#[test]
+fn test_withdraw_partial_before_maturity_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    assert_eq!(
+        client.try_withdraw_partial(&owner, &bond_id, &1_000_i128),
+        Err(Ok(FixedBondError::LockPeriodNotElapsed))
+    );
+}
+
+#[test]
+fn test_withdraw_partial_above_balance_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    assert_eq!(
+        client.try_withdraw_partial(&owner, &bond_id, &10_001_i128),
+        Err(Ok(FixedBondError::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_withdraw_partial_zero_amount_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    assert_eq!(
+        client.try_withdraw_partial(&owner, &bond_id, &0_i128),
+        Err(Ok(FixedBondError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_withdraw_partial_after_full_withdrawal_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    client.withdraw(&owner, &bond_id);
+    assert_eq!(
+        client.try_withdraw_partial(&owner, &bond_id, &1_i128),
+        Err(Ok(FixedBondError::BondNotFound))
+    );
 }
 
 // ═══════════════════════════════════════════════════════════════════
@@ -254,8 +434,8 @@ fn test_withdraw_early_deducts_penalty() {
     client.set_penalty_config(&admin, &1_000_u32);
 
     let amount = 10_000_i128;
-    client.create_bond(&owner, &amount, &ONE_DAY);
-    client.withdraw_early(&owner);
+    let bond_id = client.create_bond(&owner, &amount, &ONE_DAY);
+    client.withdraw_early(&owner, &bond_id);
 
     let tok = TokenClient::new(&e, &token_addr);
     let expected_net = 9_000_i128; // 10000 - 10%
@@ -272,41 +452,122 @@ fn test_withdraw_early_sends_penalty_to_treasury() {
     client.set_penalty_config(&admin, &500_u32); // 5% penalty
 
     let amount = 10_000_i128;
-    client.create_bond(&owner, &amount, &ONE_DAY);
-    client.withdraw_early(&owner);
+    let bond_id = client.create_bond(&owner, &amount, &ONE_DAY);
+    client.withdraw_early(&owner, &bond_id);
 
     let tok = TokenClient::new(&e, &token_addr);
     assert_eq!(tok.balance(&treasury), 500); // 5% of 10000
 }
 
 #[test]
-#[should_panic(expected = "early-exit penalty not configured")]
-fn test_withdraw_early_no_penalty_panics() {
+fn test_withdraw_early_no_penalty_fails() {
     let e = Env::default();
     let (client, _admin, owner, _token, _cid) = setup(&e);
-    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
-    client.withdraw_early(&owner);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    assert_eq!(
+        client.try_withdraw_early(&owner, &bond_id),
+        Err(Ok(FixedBondError::PenaltyNotConfigured))
+    );
 }
 
 #[test]
-#[should_panic(expected = "bond has matured; use withdraw instead")]
-fn test_withdraw_early_after_maturity_panics() {
+fn test_withdraw_early_after_maturity_fails() {
     let e = Env::default();
     let (client, admin, owner, _token, _cid) = setup(&e);
     client.set_penalty_config(&admin, &500_u32);
-    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
     e.ledger().with_mut(|li| li.timestamp += ONE_DAY + 1);
-    client.withdraw_early(&owner);
+    assert_eq!(
+        client.try_withdraw_early(&owner, &bond_id),
+        Err(Ok(FixedBondError::EarlyExitAfterMaturity))
+    );
 }
 
 #[test]
-#[should_panic(expected = "no active bond found")]
-fn test_withdraw_early_no_bond_panics() {
+fn test_withdraw_early_no_bond_fails() {
     let e = Env::default();
     let (client, admin, _owner, _token, _cid) = setup(&e);
     client.set_penalty_config(&admin, &500_u32);
     let other = Address::generate(&e);
-    client.withdraw_early(&other);
+    assert_eq!(
+        client.try_withdraw_early(&other, &1),
+        Err(Ok(FixedBondError::BondNotFound))
+    );
+}
+
+#[test]
+fn test_early_exit_penalty_full_at_creation() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    client.set_penalty_config(&admin, &1_000_u32);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    assert_eq!(client.preview_early_exit(&owner, &bond_id), (1_000, 9_000));
+}
+
+#[test]
+fn test_early_exit_penalty_one_second_before_expiry() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, _cid) = setup(&e);
+    client.set_penalty_config(&admin, &1_000_u32);
+    let amount = ONE_DAY as i128 * 10;
+    let bond_id = client.create_bond(&owner, &amount, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY - 1);
+
+    // 10% of the principal, scaled by 1 second out of ONE_DAY.
+    assert_eq!(client.preview_early_exit(&owner, &bond_id), (1, amount - 1));
+    client.withdraw_early(&owner, &bond_id);
+    let tok = TokenClient::new(&e, &token_addr);
+    assert_eq!(tok.balance(&owner), DEFAULT_MINT - 1);
+}
+
+#[test]
+fn test_early_exit_penalty_halves_at_midpoint() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    client.set_penalty_config(&admin, &1_000_u32);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY / 2);
+    assert_eq!(client.preview_early_exit(&owner, &bond_id), (500, 9_500));
+}
+
+#[test]
+fn test_flat_penalty_mode_charges_full_penalty() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, _cid) = setup(&e);
+    client.set_penalty_config(&admin, &1_000_u32);
+    assert!(!client.is_flat_penalty());
+    client.set_flat_penalty(&admin, &true);
+    assert!(client.is_flat_penalty());
+
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY - 1);
+    assert_eq!(client.preview_early_exit(&owner, &bond_id), (1_000, 9_000));
+    client.withdraw_early(&owner, &bond_id);
+    let tok = TokenClient::new(&e, &token_addr);
+    assert_eq!(tok.balance(&owner), DEFAULT_MINT - 1_000);
+}
+
+#[test]
+fn test_preview_early_exit_after_maturity_fails() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    client.set_penalty_config(&admin, &500_u32);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    assert_eq!(
+        client.try_preview_early_exit(&owner, &bond_id),
+        Err(Ok(FixedBondError::EarlyExitAfterMaturity))
+    );
+}
+
+#[test]
+fn test_set_flat_penalty_unauthorized_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    assert_eq!(
+        client.try_set_flat_penalty(&owner, &true),
+        Err(Ok(FixedBondError::Unauthorized))
+    );
 }
 
 // ═══════════════════════════════════════════════════════════════════
@@ -322,7 +583,8 @@ fn test_fee_deducted_from_bond_amount() {
     client.set_fee_config(&admin, &treasury, &100_u32); // 1% fee
 
     let gross = 10_000_i128;
-    let bond = client.create_bond(&owner, &gross, &ONE_DAY);
+    let bond_id = client.create_bond(&owner, &gross, &ONE_DAY);
+    let bond = client.get_bond(&owner, &bond_id);
     assert_eq!(bond.amount, 9_900); // net after 1%
 }
 
@@ -338,27 +600,103 @@ fn test_collect_fees() {
 
     let tok = TokenClient::new(&e, &token_addr);
     let before = tok.balance(&treasury);
-    client.collect_fees(&admin, &treasury);
+    client.collect_fees(&admin, &token_addr);
     assert_eq!(tok.balance(&treasury) - before, 100); // 1% of 10000
 }
 
 #[test]
-#[should_panic(expected = "no fees to collect")]
-fn test_collect_fees_when_none_panics() {
+fn test_collect_fees_when_none_fails() {
     let e = Env::default();
-    let (client, admin, _owner, _token, _cid) = setup(&e);
-    let recipient = Address::generate(&e);
-    client.collect_fees(&admin, &recipient);
+    let (client, admin, _owner, token_addr, _cid) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &0_u32);
+    assert_eq!(
+        client.try_collect_fees(&admin, &token_addr),
+        Err(Ok(FixedBondError::NoFeesToCollect))
+    );
+}
+
+#[test]
+fn test_collect_fees_without_fee_config_fails() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, _cid) = setup(&e);
+    // Rollover fees accrue even before a treasury is configured.
+    client.set_rollover_fee_bps(&admin, &100_u32);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    client.rollover(&owner, &bond_id, &ONE_DAY);
+    assert_eq!(client.get_total_fees_accrued(&token_addr), 100);
+
+    assert_eq!(
+        client.try_collect_fees(&admin, &token_addr),
+        Err(Ok(FixedBondError::FeeConfigNotSet))
+    );
+    assert_eq!(client.get_total_fees_accrued(&token_addr), 100);
+
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &0_u32);
+    assert_eq!(client.collect_fees(&admin, &token_addr), 100);
+    assert_eq!(TokenClient::new(&e, &token_addr).balance(&treasury), 100);
+}
+
+#[test]
+fn test_collect_fees_pays_current_treasury_and_tracks_total() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, _cid) = setup(&e);
+    let old_treasury = Address::generate(&e);
+    let new_treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &old_treasury, &100_u32);
+    client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    client.collect_fees(&admin, &token_addr);
+
+    client.set_fee_config(&admin, &new_treasury, &100_u32);
+    client.create_bond(&owner, &20_000_i128, &ONE_DAY);
+    client.collect_fees(&admin, &token_addr);
+
+    let tok = TokenClient::new(&e, &token_addr);
+    assert_eq!(tok.balance(&old_treasury), 100);
+    assert_eq!(tok.balance(&new_treasury), 200);
+    assert_eq!(client.get_total_fees_collected(&token_addr), 300);
+    assert_eq!(client.get_total_fees_accrued(&token_addr), 0);
 }
 
 #[test]
-#[should_panic(expected = "unauthorized")]
-fn test_set_fee_config_unauthorized_panics() {
+fn test_set_fee_config_unauthorized_fails() {
     let e = Env::default();
     let (client, _admin, _owner, _token, _cid) = setup(&e);
     let impostor = Address::generate(&e);
     let treasury = Address::generate(&e);
-    client.set_fee_config(&impostor, &treasury, &100_u32);
+    assert_eq!(
+        client.try_set_fee_config(&impostor, &treasury, &100_u32),
+        Err(Ok(FixedBondError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_set_fee_config_full_bps_allowed() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, _cid) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &10_000_u32);
+
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    assert_eq!(client.get_bond(&owner, &bond_id).amount, 0);
+    assert_eq!(client.get_total_fees_accrued(&token_addr), 1_000);
+}
+
+#[test]
+fn test_set_fee_config_above_max_bps_fails() {
+    let e = Env::default();
+    let (client, admin, _owner, _token, _cid) = setup(&e);
+    let treasury = Address::generate(&e);
+    assert_eq!(
+        client.try_set_fee_config(&admin, &treasury, &10_001_u32),
+        Err(Ok(FixedBondError::InvalidBps))
+    );
+    assert_eq!(
+        client.try_set_fee_config(&admin, &treasury, &100_000_u32),
+        Err(Ok(FixedBondError::InvalidBps))
+    );
 }
 
 // ═══════════════════════════════════════════════════════════════════
@@ -370,12 +708,13 @@ fn test_rebond_after_withdraw() {
     let e = Env::default();
     let (client, _admin, owner, _token, _cid) = setup(&e);
 
-    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
     e.ledger().with_mut(|li| li.timestamp += ONE_DAY + 1);
-    client.withdraw(&owner);
+    client.withdraw(&owner, &bond_id);
 
     // Should be able to create a new bond after the first is withdrawn.
-    let bond2 = client.create_bond(&owner, &2_000_i128, &ONE_WEEK);
+    let bond_id = client.create_bond(&owner, &2_000_i128, &ONE_WEEK);
+    let bond2 = client.get_bond(&owner, &bond_id);
     assert!(bond2.active);
     assert_eq!(bond2.amount, 2_000);
 }
@@ -389,17 +728,153 @@ fn test_penalty_stored_on_bond() {
     let e = Env::default();
     let (client, admin, owner, _token, _cid) = setup(&e);
     client.set_penalty_config(&admin, &250_u32); // 2.5%
-    let bond = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    let bond = client.get_bond(&owner, &bond_id);
     assert_eq!(bond.penalty_bps, 250);
 }
 
 #[test]
-#[should_panic(expected = "unauthorized")]
-fn test_set_penalty_config_unauthorized_panics() {
+fn test_set_penalty_config_unauthorized_fails() {
     let e = Env::default();
     let (client, _admin, _owner, _token, _cid) = setup(&e);
     let impostor = Address::generate(&e);
-    client.set_penalty_config(&impostor, &500_u32);
+    assert_eq!(
+        client.try_set_penalty_config(&impostor, &500_u32),
+        Err(Ok(FixedBondError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_set_penalty_config_full_bps_allowed() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    client.set_penalty_config(&admin, &10_000_u32);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    assert_eq!(client.get_bond(&owner, &bond_id).penalty_bps, 10_000);
+}
+
+#[test]
+fn test_set_penalty_config_above_max_bps_fails() {
+    let e = Env::default();
+    let (client, admin, _owner, _token, _cid) = setup(&e);
+    assert_eq!(
+        client.try_set_penalty_config(&admin, &10_001_u32),
+        Err(Ok(FixedBondError::InvalidBps))
+    );
+}
+
+#[test]
+fn test_rejected_penalty_config_leaves_existing_bond_exit_unchanged() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    client.set_penalty_config(&admin, &1_000_u32);
+    client.set_flat_penalty(&admin, &true);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_WEEK);
+
+    assert!(client.try_set_penalty_config(&admin, &100_000_u32).is_err());
+    assert_eq!(client.preview_early_exit(&owner, &bond_id), (100, 900));
+    let bond = client.withdraw_early(&owner, &bond_id);
+    assert_eq!(bond.penalty_bps, 1_000);
+    assert!(!bond.active);
+}
+
+#[test]
+fn test_early_exit_with_stored_penalty_above_max_fails() {
+    let e = Env::default();
+    let (client, admin, owner, _token, contract_id) = setup(&e);
+    client.set_penalty_config(&admin, &1_000_u32);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_WEEK);
+
+    // A bond stored before the setters validated their input.
+    e.as_contract(&contract_id, || {
+        let key = DataKey::Bond(owner.clone(), bond_id);
+        let mut bond: FixedBond = e.storage().persistent().get(&key).unwrap();
+        bond.penalty_bps = 100_000;
+        e.storage().persistent().set(&key, &bond);
+    });
+    assert_eq!(
+        client.try_withdraw_early(&owner, &bond_id),
+        Err(Ok(FixedBondError::InvalidBps))
+    );
+    client.set_flat_penalty(&admin, &true);
+    assert_eq!(
+        client.try_preview_early_exit(&owner, &bond_id),
+        Err(Ok(FixedBondError::InvalidBps))
+    );
+}
+
+#[test]
+fn test_set_bond_penalty_overrides_one_bond() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    client.set_penalty_config(&admin, &1_000_u32);
+    let custom = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    let standard = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+
+    let bond = client.set_bond_penalty(&admin, &owner, &custom, &200_u32);
+    assert_eq!(bond.penalty_bps, 200);
+    assert_eq!(client.get_bond(&owner, &standard).penalty_bps, 1_000);
+    assert_eq!(client.preview_early_exit(&owner, &custom), (200, 9_800));
+    assert_eq!(client.withdraw_early(&owner, &custom).amount, 10_000);
+}
+
+#[test]
+fn test_set_bond_penalty_zero_disables_early_exit() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    client.set_penalty_config(&admin, &1_000_u32);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    client.set_bond_penalty(&admin, &owner, &bond_id, &0_u32);
+    assert_eq!(
+        client.try_withdraw_early(&owner, &bond_id),
+        Err(Ok(FixedBondError::PenaltyNotConfigured))
+    );
+}
+
+#[test]
+fn test_set_bond_penalty_enables_early_exit() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    client.set_bond_penalty(&admin, &owner, &bond_id, &500_u32);
+    client.withdraw_early(&owner, &bond_id);
+    let tok = TokenClient::new(&e, &token_addr);
+    assert_eq!(tok.balance(&owner), DEFAULT_MINT - 500);
+}
+
+#[test]
+fn test_set_bond_penalty_above_max_fails() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    assert_eq!(
+        client.try_set_bond_penalty(&admin, &owner, &bond_id, &10_001_u32),
+        Err(Ok(FixedBondError::InvalidBps))
+    );
+}
+
+#[test]
+fn test_set_bond_penalty_withdrawn_bond_fails() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    client.withdraw(&owner, &bond_id);
+    assert_eq!(
+        client.try_set_bond_penalty(&admin, &owner, &bond_id, &500_u32),
+        Err(Ok(FixedBondError::BondNotFound))
+    );
+}
+
+#[test]
+fn test_set_bond_penalty_unauthorized_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    assert_eq!(
+        client.try_set_bond_penalty(&owner, &owner, &bond_id, &0_u32),
+        Err(Ok(FixedBondError::Unauthorized))
+    );
 }
 
 // ═══════════════════════════════════════════════════════════════════
@@ -410,18 +885,2021 @@ fn test_set_penalty_config_unauthorized_panics() {
 fn test_get_bond_returns_correct_state() {
     let e = Env::default();
     let (client, _admin, owner, _token, _cid) = setup(&e);
-    client.create_bond(&owner, &3_333_i128, &ONE_WEEK);
-    let b = client.get_bond(&owner);
+    let bond_id = client.create_bond(&owner, &3_333_i128, &ONE_WEEK);
+    let b = client.get_bond(&owner, &bond_id);
     assert_eq!(b.amount, 3_333);
     assert_eq!(b.bond_duration, ONE_WEEK);
     assert!(b.active);
 }
 
 #[test]
-#[should_panic(expected = "no active bond found")]
-fn test_get_bond_nonexistent_panics() {
+fn test_get_bond_nonexistent_fails() {
     let e = Env::default();
     let (client, _admin, _owner, _token, _cid) = setup(&e);
     let stranger = Address::generate(&e);
-    client.get_bond(&stranger);
+    assert_eq!(
+        client.try_get_bond(&stranger, &1),
+        Err(Ok(FixedBondError::BondNotFound))
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// 10. Concurrent bonds
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_concurrent_bonds_withdraw_independently() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, contract_id) = setup(&e);
+    client.set_penalty_config(&admin, &1_000_u32);
+
+    let short_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    let long_id = client.create_bond(&owner, &10_000_i128, &ONE_WEEK);
+
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    assert!(client.is_matured(&owner, &short_id));
+    assert!(!client.is_matured(&owner, &long_id));
+    assert_eq!(
+        client.get_time_remaining(&owner, &long_id),
+        ONE_WEEK - ONE_DAY
+    );
+
+    client.withdraw(&owner, &short_id);
+    assert!(!client.get_bond(&owner, &short_id).active);
+    assert!(client.get_bond(&owner, &long_id).active);
+
+    // Six of seven days left: 10% * 6/7 of 10_000, rounded down.
+    client.withdraw_early(&owner, &long_id);
+    let tok = TokenClient::new(&e, &token_addr);
+    assert_eq!(tok.balance(&owner), DEFAULT_MINT - 857);
+    assert_eq!(tok.balance(&contract_id), 857);
+}
+
+#[test]
+fn test_single_wrappers_use_only_active_bond() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+
+    let first = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    client.withdraw(&owner, &first);
+
+    let second = client.create_bond(&owner, &2_000_i128, &ONE_WEEK);
+    assert_eq!(client.get_bond_single(&owner).bond_id, second);
+    assert!(!client.is_matured_single(&owner));
+    assert_eq!(client.get_time_remaining_single(&owner), ONE_WEEK);
+
+    e.ledger().with_mut(|li| li.timestamp += ONE_WEEK);
+    let bond = client.withdraw_single(&owner);
+    assert_eq!(bond.bond_id, second);
+    assert!(!bond.active);
+}
+
+#[test]
+fn test_withdraw_early_single_uses_only_active_bond() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    client.set_penalty_config(&admin, &500_u32);
+
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    let bond = client.withdraw_early_single(&owner);
+    assert_eq!(bond.bond_id, bond_id);
+    assert!(!bond.active);
+}
+
+#[test]
+fn test_single_wrapper_with_several_active_bonds_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    assert_eq!(
+        client.try_withdraw_single(&owner),
+        Err(Ok(FixedBondError::MultipleActiveBonds))
+    );
+}
+
+#[test]
+fn test_single_wrapper_without_active_bond_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    client.withdraw(&owner, &bond_id);
+    assert_eq!(
+        client.try_get_bond_single(&owner),
+        Err(Ok(FixedBondError::BondNotFound))
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// 11. Duration extension
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_extend_duration_moves_expiry() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+
+    e.ledger().with_mut(|li| li.timestamp = 1_000 + ONE_DAY - 1);
+    let bond = client.extend_duration(&owner, &bond_id, &ONE_WEEK);
+    assert_eq!(bond.bond_start, 1_000);
+    assert_eq!(bond.bond_duration, ONE_DAY + ONE_WEEK);
+    assert_eq!(bond.bond_expiry, 1_000 + ONE_DAY + ONE_WEEK);
+    assert!(!client.is_matured(&owner, &bond_id));
+    assert_eq!(client.get_time_remaining(&owner, &bond_id), ONE_WEEK + 1);
+}
+
+#[test]
+fn test_extend_duration_within_max() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    client.set_max_duration(&admin, &ONE_WEEK);
+    assert_eq!(client.get_max_duration(), ONE_WEEK);
+
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    let bond = client.extend_duration(&owner, &bond_id, &(ONE_WEEK - ONE_DAY));
+    assert_eq!(bond.bond_duration, ONE_WEEK);
+}
+
+#[test]
+fn test_extend_duration_beyond_max_fails() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    client.set_max_duration(&admin, &ONE_WEEK);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    assert_eq!(
+        client.try_extend_duration(&owner, &bond_id, &(ONE_WEEK - ONE_DAY + 1)),
+        Err(Ok(FixedBondError::DurationTooLong))
+    );
+}
+
+#[test]
+fn test_create_bond_beyond_max_duration_fails() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    client.set_max_duration(&admin, &ONE_DAY);
+    assert_eq!(
+        client.try_create_bond(&owner, &1_000_i128, &(ONE_DAY + 1)),
+        Err(Ok(FixedBondError::DurationTooLong))
+    );
+}
+
+#[test]
+fn test_extend_duration_after_maturity_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    assert_eq!(
+        client.try_extend_duration(&owner, &bond_id, &ONE_DAY),
+        Err(Ok(FixedBondError::BondMatured))
+    );
+}
+
+#[test]
+fn test_extend_duration_zero_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    assert_eq!(
+        client.try_extend_duration(&owner, &bond_id, &0_u64),
+        Err(Ok(FixedBondError::InvalidDuration))
+    );
+}
+
+#[test]
+fn test_extend_duration_overflow_fails() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = u64::MAX - ONE_WEEK);
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    assert_eq!(
+        client.try_extend_duration(&owner, &bond_id, &ONE_WEEK),
+        Err(Ok(FixedBondError::DurationOverflow))
+    );
+}
+
+#[test]
+fn test_extend_duration_withdrawn_bond_fails() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    client.set_penalty_config(&admin, &500_u32);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    client.withdraw_early(&owner, &bond_id);
+    assert_eq!(
+        client.try_extend_duration(&owner, &bond_id, &ONE_DAY),
+        Err(Ok(FixedBondError::BondNotFound))
+    );
+}
+
+#[test]
+fn test_set_max_duration_unauthorized_fails() {
+    let e = Env::default();
+    let (client, _admin, _owner, _token, _cid) = setup(&e);
+    assert_eq!(
+        client.try_set_max_duration(&Address::generate(&e), &ONE_WEEK),
+        Err(Ok(FixedBondError::Unauthorized))
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// 12. Rollover
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_rollover_restarts_term() {
+    let e = Env::default();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+
+    client.set_penalty_config(&admin, &300_u32);
+    let rolled_at = 1_000 + ONE_DAY + 50;
+    e.ledger().with_mut(|li| li.timestamp = rolled_at);
+    let bond = client.rollover(&owner, &bond_id, &ONE_WEEK);
+
+    assert!(bond.active);
+    assert_eq!(bond.amount, 10_000);
+    assert_eq!(bond.bond_start, rolled_at);
+    assert_eq!(bond.bond_duration, ONE_WEEK);
+    assert_eq!(bond.bond_expiry, rolled_at + ONE_WEEK);
+    assert_eq!(bond.penalty_bps, 300);
+    assert!(!client.is_matured(&owner, &bond_id));
+}
+
+#[test]
+fn test_rollover_fee_accrues() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, _cid) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &100_u32); // 1% creation fee
+    client.set_rollover_fee_bps(&admin, &50_u32); // 0.5% rollover fee
+    assert_eq!(client.get_rollover_fee_bps(), 50);
+
+    let bond_id = client.create_bond(&owner, &10_100_i128, &ONE_DAY);
+    assert_eq!(client.get_bond(&owner, &bond_id).amount, 9_999);
+
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    let bond = client.rollover(&owner, &bond_id, &ONE_DAY);
+    assert_eq!(bond.amount, 9_950); // 9999 - 49
+
+    client.collect_fees(&admin, &token_addr);
+    let tok = TokenClient::new(&e, &token_addr);
+    assert_eq!(tok.balance(&treasury), 101 + 49);
+}
+
+#[test]
+fn test_rollover_fee_waived_by_default() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, _cid) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &100_u32);
+
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    let bond = client.rollover(&owner, &bond_id, &ONE_DAY);
+    assert_eq!(bond.amount, 9_900);
+    assert_eq!(client.collect_fees(&admin, &token_addr), 100);
+}
+
+#[test]
+fn test_rollover_before_maturity_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY - 1);
+    assert_eq!(
+        client.try_rollover(&owner, &bond_id, &ONE_DAY),
+        Err(Ok(FixedBondError::LockPeriodNotElapsed))
+    );
+}
+
+#[test]
+fn test_rollover_withdrawn_bond_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    client.withdraw(&owner, &bond_id);
+    assert_eq!(
+        client.try_rollover(&owner, &bond_id, &ONE_DAY),
+        Err(Ok(FixedBondError::BondNotFound))
+    );
+}
+
+#[test]
+fn test_rollover_beyond_max_duration_fails() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    client.set_max_duration(&admin, &ONE_WEEK);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    assert_eq!(
+        client.try_rollover(&owner, &bond_id, &(ONE_WEEK + 1)),
+        Err(Ok(FixedBondError::DurationTooLong))
+    );
+}
+
+#[test]
+fn test_set_rollover_fee_above_max_fails() {
+    let e = Env::default();
+    let (client, admin, _owner, _token, _cid) = setup(&e);
+    assert_eq!(
+        client.try_set_rollover_fee_bps(&admin, &10_001_u32),
+        Err(Ok(FixedBondError::InvalidBps))
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// 13. Yield
+// ═══════════════════════════════════════════════════════════════════
+
+/// Seed the yield pool with `amount` from the admin.
+fn seed_yield_pool(
+    e: &Env,
+    client: &FixedDurationBondClient,
+    admin: &Address,
+    token: &Address,
+    contract_id: &Address,
+    amount: i128,
+) {
+    fund(e, token, contract_id, admin);
+    client.fund_yield_pool(admin, token, &amount);
+}
+
+#[test]
+fn test_withdraw_pays_yield_from_pool() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, contract_id) = setup(&e);
+    seed_yield_pool(&e, &client, &admin, &token_addr, &contract_id, 100_000);
+    client.set_yield_rate(&admin, &0, &500_u32); // 5% a year
+
+    let bond_id = client.create_bond(&owner, &1_000_000_i128, &SECONDS_PER_YEAR);
+    assert_eq!(client.get_bond(&owner, &bond_id).yield_bps, 500);
+    assert_eq!(client.get_pending_yield(&owner, &bond_id), 50_000);
+
+    // Staying past maturity earns nothing extra.
+    e.ledger()
+        .with_mut(|li| li.timestamp += 2 * SECONDS_PER_YEAR);
+    client.withdraw(&owner, &bond_id);
+
+    let tok = TokenClient::new(&e, &token_addr);
+    assert_eq!(tok.balance(&owner), DEFAULT_MINT + 50_000);
+    assert_eq!(client.get_yield_pool(&token_addr), 50_000);
+    assert_eq!(client.get_pending_yield(&owner, &bond_id), 0);
+}
+
+#[test]
+fn test_yield_is_pro_rated_by_duration() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, contract_id) = setup(&e);
+    seed_yield_pool(&e, &client, &admin, &token_addr, &contract_id, 1_000_000);
+    client.set_yield_rate(&admin, &0, &1_000_u32);
+
+    let bond_id = client.create_bond(&owner, &(SECONDS_PER_YEAR as i128), &ONE_WEEK);
+    // 10% a year over one week of a principal equal to a year's seconds.
+    assert_eq!(
+        client.get_pending_yield(&owner, &bond_id),
+        SECONDS_PER_YEAR as i128 * 1_000 * ONE_WEEK as i128 / (10_000 * SECONDS_PER_YEAR as i128)
+    );
+}
+
+#[test]
+fn test_yield_rate_tiers_by_duration() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    client.set_yield_rate(&admin, &SECONDS_PER_YEAR, &500_u32);
+    client.set_yield_rate(&admin, &ONE_WEEK, &300_u32);
+    client.set_yield_rate(&admin, &ONE_DAY, &100_u32);
+    client.set_yield_rate(&admin, &ONE_WEEK, &250_u32); // replaces the week tier
+
+    assert_eq!(
+        client.get_yield_rates(),
+        vec![
+            &e,
+            YieldTier {
+                min_duration_secs: ONE_DAY,
+                rate_bps: 100
+            },
+            YieldTier {
+                min_duration_secs: ONE_WEEK,
+                rate_bps: 250
+            },
+            YieldTier {
+                min_duration_secs: SECONDS_PER_YEAR,
+                rate_bps: 500
+            },
+        ]
+    );
+
+    let rate = |duration: u64| {
+        let bond_id = client.create_bond(&owner, &1_000_i128, &duration);
+        client.get_bond(&owner, &bond_id).yield_bps
+    };
+    assert_eq!(rate(ONE_DAY - 1), 0);
+    assert_eq!(rate(ONE_DAY), 100);
+    assert_eq!(rate(2 * ONE_WEEK), 250);
+    assert_eq!(rate(2 * SECONDS_PER_YEAR), 500);
+}
+
+#[test]
+fn test_yield_capped_when_pool_runs_dry() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, contract_id) = setup(&e);
+    seed_yield_pool(&e, &client, &admin, &token_addr, &contract_id, 30_000);
+    client.set_yield_rate(&admin, &0, &500_u32);
+
+    let first = client.create_bond(&owner, &1_000_000_i128, &SECONDS_PER_YEAR);
+    let second = client.create_bond(&owner, &1_000_000_i128, &SECONDS_PER_YEAR);
+    assert_eq!(client.get_pending_yield(&owner, &first), 30_000);
+
+    e.ledger().with_mut(|li| li.timestamp += SECONDS_PER_YEAR);
+    client.withdraw(&owner, &first);
+    assert_eq!(client.get_yield_pool(&token_addr), 0);
+    assert_eq!(client.get_pending_yield(&owner, &second), 0);
+    client.withdraw(&owner, &second);
+
+    let tok = TokenClient::new(&e, &token_addr);
+    assert_eq!(tok.balance(&owner), DEFAULT_MINT + 30_000);
+    assert_eq!(tok.balance(&contract_id), 0);
+}
+
+#[test]
+fn test_withdraw_early_pays_no_yield() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, contract_id) = setup(&e);
+    seed_yield_pool(&e, &client, &admin, &token_addr, &contract_id, 100_000);
+    client.set_yield_rate(&admin, &0, &500_u32);
+    client.set_penalty_config(&admin, &1_000_u32);
+
+    let bond_id = client.create_bond(&owner, &10_000_i128, &SECONDS_PER_YEAR);
+    client.withdraw_early(&owner, &bond_id);
+
+    let tok = TokenClient::new(&e, &token_addr);
+    assert_eq!(tok.balance(&owner), DEFAULT_MINT - 1_000);
+    assert_eq!(client.get_yield_pool(&token_addr), 100_000);
+}
+
+#[test]
+fn test_withdraw_partial_pays_yield_on_withdrawn_part() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, contract_id) = setup(&e);
+    seed_yield_pool(&e, &client, &admin, &token_addr, &contract_id, 100_000);
+    client.set_yield_rate(&admin, &0, &1_000_u32);
+
+    let bond_id = client.create_bond(&owner, &100_000_i128, &SECONDS_PER_YEAR);
+    e.ledger().with_mut(|li| li.timestamp += SECONDS_PER_YEAR);
+
+    client.withdraw_partial(&owner, &bond_id, &40_000_i128);
+    assert_eq!(client.get_yield_pool(&token_addr), 96_000);
+    assert_eq!(client.get_pending_yield(&owner, &bond_id), 6_000);
+    client.withdraw(&owner, &bond_id);
+
+    let tok = TokenClient::new(&e, &token_addr);
+    assert_eq!(tok.balance(&owner), DEFAULT_MINT + 10_000);
+    assert_eq!(client.get_yield_pool(&token_addr), 90_000);
+}
+
+#[test]
+fn test_rollover_compounds_yield() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, contract_id) = setup(&e);
+    seed_yield_pool(&e, &client, &admin, &token_addr, &contract_id, 100_000);
+    client.set_yield_rate(&admin, &0, &1_000_u32);
+
+    let bond_id = client.create_bond(&owner, &100_000_i128, &SECONDS_PER_YEAR);
+    e.ledger().with_mut(|li| li.timestamp += SECONDS_PER_YEAR);
+    client.set_yield_rate(&admin, &0, &2_000_u32);
+
+    let bond = client.rollover(&owner, &bond_id, &SECONDS_PER_YEAR);
+    assert_eq!(bond.amount, 110_000);
+    assert_eq!(bond.yield_bps, 2_000);
+    assert_eq!(client.get_yield_pool(&token_addr), 90_000);
+    assert_eq!(client.get_pending_yield(&owner, &bond_id), 22_000);
+}
+
+#[test]
+fn test_accrued_yield_handles_large_principal() {
+    let huge = i128::MAX / 2;
+    let owed = accrued_yield(huge, 10_000, SECONDS_PER_YEAR);
+    assert!(owed > 0 && owed <= huge);
+    assert_eq!(accrued_yield(1_000_000, 500, SECONDS_PER_YEAR), 50_000);
+    assert_eq!(accrued_yield(1_000_000, 0, SECONDS_PER_YEAR), 0);
+}
+
+#[test]
+fn test_accrued_yield_saturates_on_very_long_term() {
+    assert_eq!(accrued_yield(i128::MAX / 2, 10_000, u64::MAX), i128::MAX);
+    assert_eq!(accrued_yield(10_i128.pow(30), 10_000, u64::MAX), i128::MAX);
+}
+
+#[test]
+fn test_withdraw_very_long_term_pays_pool_capped_yield() {
+    let e = Env::default();
+    let principal = 10_i128.pow(30);
+    let (client, admin, owner, token_addr, contract_id) = setup_with_mint(&e, principal);
+    seed_yield_pool(&e, &client, &admin, &token_addr, &contract_id, 100_000);
+    client.set_yield_rate(&admin, &0, &10_000_u32);
+
+    let term = 1_000_000_000_000_000_000_u64;
+    let bond_id = client.create_bond(&owner, &principal, &term);
+    assert_eq!(client.get_pending_yield(&owner, &bond_id), 100_000);
+
+    e.ledger().with_mut(|li| li.timestamp += term);
+    client.withdraw(&owner, &bond_id);
+
+    let tok = TokenClient::new(&e, &token_addr);
+    assert_eq!(tok.balance(&owner), principal + 100_000);
+    assert_eq!(client.get_yield_pool(&token_addr), 0);
+}
+
+#[test]
+fn test_fund_yield_pool_unauthorized_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, token_addr, _cid) = setup(&e);
+    assert_eq!(
+        client.try_fund_yield_pool(&owner, &token_addr, &1_000_i128),
+        Err(Ok(FixedBondError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_set_yield_rate_above_max_fails() {
+    let e = Env::default();
+    let (client, admin, _owner, _token, _cid) = setup(&e);
+    assert_eq!(
+        client.try_set_yield_rate(&admin, &0, &10_001_u32),
+        Err(Ok(FixedBondError::InvalidBps))
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// 14. Beneficiary
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_withdraw_pays_beneficiary_set_at_creation() {
+    let e = Env::default();
+    let (client, _admin, owner, token_addr, _cid) = setup(&e);
+    let cold = Address::generate(&e);
+
+    let bond_id =
+        client.create_bond_with_beneficiary(&owner, &1_000_i128, &ONE_DAY, &Some(cold.clone()));
+    assert_eq!(
+        client.get_bond(&owner, &bond_id).beneficiary,
+        Some(cold.clone())
+    );
+
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    client.withdraw(&owner, &bond_id);
+
+    let tok = TokenClient::new(&e, &token_addr);
+    assert_eq!(tok.balance(&cold), 1_000);
+    assert_eq!(tok.balance(&owner), DEFAULT_MINT - 1_000);
+}
+
+#[test]
+fn test_withdraw_partial_pays_beneficiary() {
+    let e = Env::default();
+    let (client, _admin, owner, token_addr, _cid) = setup(&e);
+    let cold = Address::generate(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    client.set_beneficiary(&owner, &bond_id, &Some(cold.clone()));
+
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    client.withdraw_partial(&owner, &bond_id, &400_i128);
+
+    let tok = TokenClient::new(&e, &token_addr);
+    assert_eq!(tok.balance(&cold), 400);
+}
+
+#[test]
+fn test_withdraw_early_pays_beneficiary() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, _cid) = setup(&e);
+    client.set_penalty_config(&admin, &1_000_u32);
+    let cold = Address::generate(&e);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    client.set_beneficiary(&owner, &bond_id, &Some(cold.clone()));
+
+    client.withdraw_early(&owner, &bond_id);
+
+    let tok = TokenClient::new(&e, &token_addr);
+    assert_eq!(tok.balance(&cold), 9_000);
+}
+
+#[test]
+fn test_clear_beneficiary_pays_owner() {
+    let e = Env::default();
+    let (client, _admin, owner, token_addr, _cid) = setup(&e);
+    let cold = Address::generate(&e);
+    let bond_id =
+        client.create_bond_with_beneficiary(&owner, &1_000_i128, &ONE_DAY, &Some(cold.clone()));
+
+    let bond = client.set_beneficiary(&owner, &bond_id, &None);
+    assert_eq!(bond.beneficiary, None);
+
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    client.withdraw(&owner, &bond_id);
+
+    let tok = TokenClient::new(&e, &token_addr);
+    assert_eq!(tok.balance(&cold), 0);
+    assert_eq!(tok.balance(&owner), DEFAULT_MINT);
+}
+
+#[test]
+fn test_set_beneficiary_requires_owner_auth() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let cold = Address::generate(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+
+    client.set_beneficiary(&owner, &bond_id, &Some(cold));
+    let auths = e.auths();
+    assert_eq!(auths.len(), 1);
+    assert_eq!(auths[0].0, owner);
+}
+
+#[test]
+fn test_set_beneficiary_withdrawn_bond_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    client.withdraw(&owner, &bond_id);
+    assert_eq!(
+        client.try_set_beneficiary(&owner, &bond_id, &Some(Address::generate(&e))),
+        Err(Ok(FixedBondError::BondNotFound))
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// 15. Ownership transfer
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_transfer_moves_bond_to_new_owner() {
+    let e = Env::default();
+    let (client, _admin, owner, token_addr, contract_id) = setup(&e);
+    let new_owner = Address::generate(&e);
+    fund(&e, &token_addr, &contract_id, &new_owner);
+    client.create_bond(&new_owner, &500_i128, &ONE_DAY);
+
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_WEEK);
+    let expiry = client.get_bond(&owner, &bond_id).bond_expiry;
+    client.propose_transfer(&owner, &bond_id, &new_owner);
+    let new_id = client.accept_transfer(&new_owner, &owner, &bond_id);
+    assert_eq!(new_id, 2);
+
+    let moved = client.get_bond(&new_owner, &new_id);
+    assert_eq!(moved.owner, new_owner);
+    assert_eq!(moved.amount, 1_000);
+    assert_eq!(moved.bond_expiry, expiry);
+    assert!(moved.active);
+
+    let old = client.get_bond(&owner, &bond_id);
+    assert!(!old.active);
+    assert_eq!(old.amount, 0);
+    assert_eq!(client.get_pending_transfer(&owner, &bond_id), None);
+
+    e.ledger().with_mut(|li| li.timestamp += ONE_WEEK);
+    client.withdraw(&new_owner, &new_id);
+    let tok = TokenClient::new(&e, &token_addr);
+    assert_eq!(tok.balance(&new_owner), DEFAULT_MINT - 500 + 1_000);
+}
+
+#[test]
+fn test_transfer_clears_beneficiary() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let new_owner = Address::generate(&e);
+    let cold = Address::generate(&e);
+    let bond_id = client.create_bond_with_beneficiary(&owner, &1_000_i128, &ONE_DAY, &Some(cold));
+
+    client.propose_transfer(&owner, &bond_id, &new_owner);
+    let new_id = client.accept_transfer(&new_owner, &owner, &bond_id);
+    assert_eq!(client.get_bond(&new_owner, &new_id).beneficiary, None);
+}
+
+#[test]
+fn test_propose_transfer_uses_window() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    assert_eq!(client.get_transfer_window(), ONE_WEEK);
+    client.set_transfer_window(&admin, &ONE_DAY);
+
+    let new_owner = Address::generate(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_WEEK);
+    let now = e.ledger().timestamp();
+    let pending = client.propose_transfer(&owner, &bond_id, &new_owner);
+    assert_eq!(pending.expires_at, now + ONE_DAY);
+    assert_eq!(client.get_pending_transfer(&owner, &bond_id), Some(pending));
+
+    // Still acceptable on the last second of the window.
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    client.accept_transfer(&new_owner, &owner, &bond_id);
+}
+
+#[test]
+fn test_accept_transfer_after_window_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let new_owner = Address::generate(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &(2 * ONE_WEEK));
+    client.propose_transfer(&owner, &bond_id, &new_owner);
+    e.ledger().with_mut(|li| li.timestamp += ONE_WEEK + 1);
+    assert_eq!(
+        client.try_accept_transfer(&new_owner, &owner, &bond_id),
+        Err(Ok(FixedBondError::TransferExpired))
+    );
+}
+
+#[test]
+fn test_accept_cancelled_transfer_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let new_owner = Address::generate(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_WEEK);
+    client.propose_transfer(&owner, &bond_id, &new_owner);
+    client.cancel_transfer(&owner, &bond_id);
+    assert_eq!(
+        client.try_accept_transfer(&new_owner, &owner, &bond_id),
+        Err(Ok(FixedBondError::NoPendingTransfer))
+    );
+}
+
+#[test]
+fn test_accept_transfer_by_other_address_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_WEEK);
+    client.propose_transfer(&owner, &bond_id, &Address::generate(&e));
+    assert_eq!(
+        client.try_accept_transfer(&Address::generate(&e), &owner, &bond_id),
+        Err(Ok(FixedBondError::NoPendingTransfer))
+    );
+}
+
+#[test]
+fn test_accept_transfer_of_withdrawn_bond_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let new_owner = Address::generate(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    client.propose_transfer(&owner, &bond_id, &new_owner);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    client.withdraw(&owner, &bond_id);
+    assert_eq!(
+        client.try_accept_transfer(&new_owner, &owner, &bond_id),
+        Err(Ok(FixedBondError::BondNotFound))
+    );
+}
+
+#[test]
+fn test_propose_transfer_to_self_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    assert_eq!(
+        client.try_propose_transfer(&owner, &bond_id, &owner),
+        Err(Ok(FixedBondError::SelfTransfer))
+    );
+}
+
+#[test]
+fn test_cancel_without_proposal_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    assert_eq!(
+        client.try_cancel_transfer(&owner, &bond_id),
+        Err(Ok(FixedBondError::NoPendingTransfer))
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// 16. Aggregates
+// ═══════════════════════════════════════════════════════════════════
+
+/// Sum of active principal and count of active bonds, read bond by bond.
+fn manual_totals(
+    client: &FixedDurationBondClient,
+    owners: &soroban_sdk::Vec<Address>,
+) -> (i128, u32) {
+    let mut locked = 0_i128;
+    let mut count = 0_u32;
+    for owner in owners.iter() {
+        for bond_id in client.get_owner_bonds(&owner).iter() {
+            let bond = client.get_bond(&owner, &bond_id);
+            if bond.active {
+                locked += bond.amount;
+                count += 1;
+            }
+        }
+    }
+    (locked, count)
+}
+
+#[test]
+fn test_aggregates_track_lifecycle() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, _cid) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &100_u32); // 1%
+    client.set_penalty_config(&admin, &1_000_u32);
+
+    let a = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    let b = client.create_bond(&owner, &20_000_i128, &ONE_WEEK);
+    assert_eq!(client.get_total_locked(&token_addr), 9_900 + 19_800);
+    assert_eq!(client.get_active_bond_count(), 2);
+    assert_eq!(client.get_total_fees_accrued(&token_addr), 300);
+
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    client.withdraw_partial(&owner, &a, &4_900_i128);
+    assert_eq!(client.get_total_locked(&token_addr), 5_000 + 19_800);
+    assert_eq!(client.get_active_bond_count(), 2);
+
+    client.withdraw_partial(&owner, &a, &5_000_i128);
+    assert_eq!(client.get_total_locked(&token_addr), 19_800);
+    assert_eq!(client.get_active_bond_count(), 1);
+
+    client.withdraw_early(&owner, &b);
+    assert_eq!(client.get_total_locked(&token_addr), 0);
+    assert_eq!(client.get_active_bond_count(), 0);
+
+    client.collect_fees(&admin, &token_addr);
+    assert_eq!(client.get_total_fees_accrued(&token_addr), 0);
+}
+
+#[test]
+fn test_aggregates_follow_rollover_and_transfer() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, _cid) = setup(&e);
+    client.set_rollover_fee_bps(&admin, &500_u32);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    client.rollover(&owner, &bond_id, &ONE_DAY);
+    assert_eq!(client.get_total_locked(&token_addr), 9_500);
+    assert_eq!(client.get_active_bond_count(), 1);
+    assert_eq!(client.get_total_fees_accrued(&token_addr), 500);
+
+    let new_owner = Address::generate(&e);
+    client.propose_transfer(&owner, &bond_id, &new_owner);
+    client.accept_transfer(&new_owner, &owner, &bond_id);
+    assert_eq!(client.get_total_locked(&token_addr), 9_500);
+    assert_eq!(client.get_active_bond_count(), 1);
+}
+
+#[test]
+fn test_aggregates_match_manual_sums_after_random_operations() {
+    let e = Env::default();
+    let (client, admin, first, token_addr, contract_id) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &50_u32);
+    client.set_penalty_config(&admin, &700_u32);
+    client.set_rollover_fee_bps(&admin, &25_u32);
+
+    let mut owners = vec![&e, first];
+    for _ in 0..2 {
+        let owner = Address::generate(&e);
+        fund(&e, &token_addr, &contract_id, &owner);
+        owners.push_back(owner);
+    }
+
+    // Deterministic pseudo-random sequence (LCG) so failures reproduce.
+    let mut seed: u64 = 0x2128;
+    let mut next = |bound: u64| {
+        seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+        (seed >> 33) % bound
+    };
+
+    for _ in 0..120 {
+        let owner = owners.get(next(owners.len() as u64) as u32).unwrap();
+        let count = client.get_owner_bonds(&owner).len() as u64;
+        let pick = if count == 0 { 0 } else { next(count) + 1 };
+        let active = pick > 0 && client.get_bond(&owner, &pick).active;
+        let matured = active && client.is_matured(&owner, &pick);
+
+        match next(6) {
+            0 | 1 => {
+                let amount = 1_000 + next(50_000) as i128;
+                client.create_bond(&owner, &amount, &(ONE_DAY * (1 + next(7))));
+            }
+            2 if matured => {
+                client.withdraw(&owner, &pick);
+            }
+            3 if matured => {
+                let balance = client.get_bond(&owner, &pick).amount;
+                let amount = 1 + next(balance as u64) as i128;
+                client.withdraw_partial(&owner, &pick, &amount);
+            }
+            4 if active && !matured => {
+                client.withdraw_early(&owner, &pick);
+            }
+            5 if matured => {
+                client.rollover(&owner, &pick, &(ONE_DAY * (1 + next(7))));
+            }
+            _ => e.ledger().with_mut(|li| li.timestamp += next(2 * ONE_DAY)),
+        }
+
+        let (locked, bonds) = manual_totals(&client, &owners);
+        assert_eq!(client.get_total_locked(&token_addr), locked);
+        assert_eq!(client.get_active_bond_count(), bonds);
+    }
+
+    let fees = client.get_total_fees_accrued(&token_addr);
+    if fees > 0 {
+        assert_eq!(client.collect_fees(&admin, &token_addr), fees);
+    }
+    assert_eq!(client.get_total_fees_accrued(&token_addr), 0);
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// 17. Owner index
+// ═══════════════════════════════════════════════════════════════════
+
+/// Create one bond each for `n` new, funded owners.
+fn bond_many_owners(
+    e: &Env,
+    client: &FixedDurationBondClient,
+    token: &Address,
+    contract_id: &Address,
+    n: u32,
+) -> soroban_sdk::Vec<Address> {
+    let mut owners = soroban_sdk::Vec::new(e);
+    for _ in 0..n {
+        let owner = Address::generate(e);
+        fund(e, token, contract_id, &owner);
+        client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+        owners.push_back(owner);
+    }
+    owners
+}
+
+#[test]
+fn test_owner_indexed_once_on_first_bond() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    assert_eq!(client.get_owner_count(), 0);
+
+    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    client.create_bond(&owner, &1_000_i128, &ONE_WEEK);
+    assert_eq!(client.get_owner_count(), 1);
+    assert_eq!(client.get_bond_owners(&0, &10), vec![&e, owner]);
+}
+
+#[test]
+fn test_owner_removed_when_last_bond_deactivates() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, contract_id) = setup(&e);
+    client.set_penalty_config(&admin, &500_u32);
+    let others = bond_many_owners(&e, &client, &token_addr, &contract_id, 2);
+    let short_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    let long_id = client.create_bond(&owner, &1_000_i128, &ONE_WEEK);
+    assert_eq!(client.get_owner_count(), 3);
+
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    client.withdraw(&owner, &short_id);
+    assert_eq!(client.get_owner_count(), 3);
+
+    client.withdraw_early(&owner, &long_id);
+    assert_eq!(client.get_owner_count(), 2);
+    let listed = client.get_bond_owners(&0, &10);
+    assert!(!listed.contains(&owner));
+    assert!(others.iter().all(|o| listed.contains(&o)));
+
+    // Bonding again puts the owner back.
+    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    assert_eq!(client.get_owner_count(), 3);
+}
+
+#[test]
+fn test_get_bond_owners_paginates_across_pages() {
+    let e = Env::default();
+    let (client, _admin, _owner, token_addr, contract_id) = setup(&e);
+    let n = OWNER_PAGE_SIZE * 2 + 7;
+    let owners = bond_many_owners(&e, &client, &token_addr, &contract_id, n);
+    assert_eq!(client.get_owner_count(), n);
+
+    // Limit is capped.
+    let first = client.get_bond_owners(&0, &u32::MAX);
+    assert_eq!(first.len(), MAX_OWNERS_PER_QUERY);
+    let rest = client.get_bond_owners(&MAX_OWNERS_PER_QUERY, &MAX_OWNERS_PER_QUERY);
+    assert_eq!(rest.len(), n - MAX_OWNERS_PER_QUERY);
+
+    let mut all = first;
+    all.append(&rest);
+    assert_eq!(all, owners);
+
+    assert_eq!(
+        client.get_bond_owners(&(OWNER_PAGE_SIZE - 1), &2),
+        owners.slice(OWNER_PAGE_SIZE - 1..OWNER_PAGE_SIZE + 1)
+    );
+    assert_eq!(client.get_bond_owners(&n, &10).len(), 0);
+    assert_eq!(client.get_bond_owners(&u32::MAX, &u32::MAX).len(), 0);
+}
+
+#[test]
+fn test_removal_moves_last_owner_into_gap() {
+    let e = Env::default();
+    let (client, _admin, _owner, token_addr, contract_id) = setup(&e);
+    let n = OWNER_PAGE_SIZE + 3;
+    let owners = bond_many_owners(&e, &client, &token_addr, &contract_id, n);
+
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    let gone = owners.get_unchecked(2);
+    client.withdraw(&gone, &1);
+
+    assert_eq!(client.get_owner_count(), n - 1);
+    let listed = client.get_bond_owners(&0, &MAX_OWNERS_PER_QUERY);
+    assert_eq!(listed.get_unchecked(2), owners.get_unchecked(n - 1));
+    assert!(!listed.contains(&gone));
+
+    // Every remaining owner can still be removed cleanly.
+    for owner in listed.iter() {
+        client.withdraw(&owner, &1);
+    }
+    assert_eq!(client.get_owner_count(), 0);
+    assert_eq!(client.get_bond_owners(&0, &10).len(), 0);
+}
+
+#[test]
+fn test_transfer_updates_owner_index() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let new_owner = Address::generate(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+
+    client.propose_transfer(&owner, &bond_id, &new_owner);
+    client.accept_transfer(&new_owner, &owner, &bond_id);
+    assert_eq!(client.get_bond_owners(&0, &10), vec![&e, new_owner]);
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// 18. Events
+// ═══════════════════════════════════════════════════════════════════
+
+/// Topics and data of the last event the bond contract published.
+fn last_bond_event(e: &Env, contract_id: &Address) -> (Vec<Val>, Val) {
+    let (_, topics, data) = e
+        .events()
+        .all()
+        .iter()
+        .filter(|(id, _, _)| id == contract_id)
+        .last()
+        .expect("no contract event");
+    (topics, data)
+}
+
+#[test]
+fn test_bond_created_event() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, contract_id) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &100_u32);
+
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    let (topics, data) = last_bond_event(&e, &contract_id);
+    assert_eq!(
+        topics,
+        (Symbol::new(&e, "bond_created"), owner.clone()).into_val(&e)
+    );
+    let event: FixedBondCreated = data.into_val(&e);
+    assert_eq!(
+        event,
+        FixedBondCreated {
+            owner,
+            bond_id,
+            token: token_addr,
+            amount: 9_900,
+            fee: 100,
+            expiry: e.ledger().timestamp() + ONE_DAY,
+        }
+    );
+}
+
+#[test]
+fn test_bond_withdrawn_event_names_recipient() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, contract_id) = setup(&e);
+    let beneficiary = Address::generate(&e);
+    let bond_id = client.create_bond_with_beneficiary(
+        &owner,
+        &1_000_i128,
+        &ONE_DAY,
+        &Some(beneficiary.clone()),
+    );
+
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    client.withdraw(&owner, &bond_id);
+    let (topics, data) = last_bond_event(&e, &contract_id);
+    assert_eq!(
+        topics,
+        (Symbol::new(&e, "bond_withdrawn"), owner.clone()).into_val(&e)
+    );
+    let event: FixedBondWithdrawn = data.into_val(&e);
+    assert_eq!(
+        event,
+        FixedBondWithdrawn {
+            owner,
+            bond_id,
+            amount: 1_000,
+            yield_paid: 0,
+            recipient: beneficiary,
+        }
+    );
+}
+
+#[test]
+fn test_bond_early_exit_event() {
+    let e = Env::default();
+    let (client, admin, owner, _token, contract_id) = setup(&e);
+    client.set_penalty_config(&admin, &1_000_u32);
+    client.set_flat_penalty(&admin, &true);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_WEEK);
+
+    client.withdraw_early(&owner, &bond_id);
+    let event: FixedBondEarlyExit = last_bond_event(&e, &contract_id).1.into_val(&e);
+    assert_eq!(
+        event,
+        FixedBondEarlyExit {
+            owner: owner.clone(),
+            bond_id,
+            net: 900,
+            penalty: 100,
+            recipient: owner,
+        }
+    );
+}
+
+#[test]
+fn test_transfer_cancelled_event() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, contract_id) = setup(&e);
+    let new_owner = Address::generate(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    client.propose_transfer(&owner, &bond_id, &new_owner);
+
+    client.cancel_transfer(&owner, &bond_id);
+    let (topics, data) = last_bond_event(&e, &contract_id);
+    assert_eq!(
+        topics,
+        (Symbol::new(&e, "bond_transfer_cancelled"), owner.clone()).into_val(&e)
+    );
+    let event: TransferCancelled = data.into_val(&e);
+    assert_eq!(event, TransferCancelled { owner, bond_id });
+}
+
+#[test]
+fn test_fees_collected_event() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, contract_id) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &100_u32);
+    client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+
+    client.collect_fees(&admin, &token_addr);
+    let (topics, data) = last_bond_event(&e, &contract_id);
+    assert_eq!(topics, (Symbol::new(&e, "fees_collected"),).into_val(&e));
+    let event: FeesCollected = data.into_val(&e);
+    assert_eq!(
+        event,
+        FeesCollected {
+            admin,
+            treasury,
+            token: token_addr,
+            amount: 100,
+            total_collected: 100,
+        }
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// 19. Admin rotation
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_admin_rotation_hands_over_admin_rights() {
+    let e = Env::default();
+    let (client, admin, _owner, _token, contract_id) = setup(&e);
+    let new_admin = Address::generate(&e);
+
+    client.propose_admin(&admin, &new_admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+    // Nothing changes until the proposal is accepted.
+    assert_eq!(client.get_admin(), admin);
+
+    client.accept_admin(&new_admin);
+    let event: AdminTransferred = last_bond_event(&e, &contract_id).1.into_val(&e);
+    assert_eq!(
+        event,
+        AdminTransferred {
+            previous_admin: admin.clone(),
+            new_admin: new_admin.clone(),
+        }
+    );
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+
+    client.set_max_duration(&new_admin, &ONE_WEEK);
+    assert_eq!(
+        client.try_set_max_duration(&admin, &ONE_DAY),
+        Err(Ok(FixedBondError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_propose_admin_by_non_admin_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    assert_eq!(
+        client.try_propose_admin(&owner, &owner),
+        Err(Ok(FixedBondError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_accept_admin_without_proposal_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    assert_eq!(
+        client.try_accept_admin(&owner),
+        Err(Ok(FixedBondError::NoPendingAdmin))
+    );
+}
+
+#[test]
+fn test_accept_admin_by_other_address_fails() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    let new_admin = Address::generate(&e);
+    client.propose_admin(&admin, &new_admin);
+    assert_eq!(
+        client.try_accept_admin(&owner),
+        Err(Ok(FixedBondError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_propose_admin_overwrites_pending() {
+    let e = Env::default();
+    let (client, admin, _owner, _token, _cid) = setup(&e);
+    let first = Address::generate(&e);
+    let second = Address::generate(&e);
+
+    client.propose_admin(&admin, &first);
+    client.propose_admin(&admin, &second);
+    assert_eq!(
+        client.try_accept_admin(&first),
+        Err(Ok(FixedBondError::Unauthorized))
+    );
+    client.accept_admin(&second);
+    assert_eq!(client.get_admin(), second);
+}
+
+#[test]
+fn test_cancel_admin_proposal() {
+    let e = Env::default();
+    let (client, admin, _owner, _token, _cid) = setup(&e);
+    let new_admin = Address::generate(&e);
+
+    client.propose_admin(&admin, &new_admin);
+    client.cancel_admin_proposal(&admin);
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(
+        client.try_accept_admin(&new_admin),
+        Err(Ok(FixedBondError::NoPendingAdmin))
+    );
+    assert_eq!(
+        client.try_cancel_admin_proposal(&admin),
+        Err(Ok(FixedBondError::NoPendingAdmin))
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// 20. Pausing
+// ═══════════════════════════════════════════════════════════════════
+
+fn pause(e: &Env, client: &FixedDurationBondClient, admin: &Address) {
+    client.pause(admin, &Symbol::new(e, "incident"), &None);
+}
+
+#[test]
+fn test_pause_records_info_and_emits_event() {
+    let e = Env::default();
+    let (client, admin, _owner, _token, contract_id) = setup(&e);
+    let reason = Symbol::new(&e, "incident");
+    let detail = Some(String::from_str(&e, "oracle outage"));
+
+    let info = client.pause(&admin, &reason, &detail);
+    let event: Paused = last_bond_event(&e, &contract_id).1.into_val(&e);
+    assert_eq!(
+        event,
+        Paused {
+            admin: admin.clone(),
+            reason: reason.clone(),
+        }
+    );
+    assert!(client.is_paused());
+    assert_eq!(info.paused_by, admin);
+    assert_eq!(info.detail, detail);
+    assert_eq!(client.get_pause_info(), Some(info.clone()));
+
+    client.unpause(&admin);
+    assert!(!client.is_paused());
+    assert_eq!(client.get_pause_info(), None);
+    assert_eq!(client.get_last_pause_info(), Some(info));
+}
+
+#[test]
+fn test_pause_by_non_admin_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    assert_eq!(
+        client.try_pause(&owner, &Symbol::new(&e, "incident"), &None),
+        Err(Ok(FixedBondError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_unpause_when_not_paused_fails() {
+    let e = Env::default();
+    let (client, admin, _owner, _token, _cid) = setup(&e);
+    assert_eq!(
+        client.try_unpause(&admin),
+        Err(Ok(FixedBondError::NotPaused))
+    );
+}
+
+#[test]
+fn test_create_bond_when_paused_fails() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    pause(&e, &client, &admin);
+    assert_eq!(
+        client.try_create_bond(&owner, &1_000_i128, &ONE_DAY),
+        Err(Ok(FixedBondError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_create_bond_with_beneficiary(&owner, &1_000_i128, &ONE_DAY, &None),
+        Err(Ok(FixedBondError::ContractPaused))
+    );
+
+    client.unpause(&admin);
+    assert_eq!(client.create_bond(&owner, &1_000_i128, &ONE_DAY), 1);
+}
+
+#[test]
+fn test_withdraw_when_paused_fails() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+
+    pause(&e, &client, &admin);
+    assert_eq!(
+        client.try_withdraw(&owner, &bond_id),
+        Err(Ok(FixedBondError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_withdraw_single(&owner),
+        Err(Ok(FixedBondError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_withdraw_partial(&owner, &bond_id, &100_i128),
+        Err(Ok(FixedBondError::ContractPaused))
+    );
+
+    client.unpause(&admin);
+    assert_eq!(client.withdraw(&owner, &bond_id).amount, 1_000);
+}
+
+#[test]
+fn test_withdraw_early_when_paused_fails() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    client.set_penalty_config(&admin, &1_000_u32);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_WEEK);
+
+    pause(&e, &client, &admin);
+    assert_eq!(
+        client.try_withdraw_early(&owner, &bond_id),
+        Err(Ok(FixedBondError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_withdraw_early_single(&owner),
+        Err(Ok(FixedBondError::ContractPaused))
+    );
+}
+
+#[test]
+fn test_extend_and_rollover_when_paused_fail() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    let unmatured = client.create_bond(&owner, &1_000_i128, &ONE_WEEK);
+    let matured = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+
+    pause(&e, &client, &admin);
+    assert_eq!(
+        client.try_extend_duration(&owner, &unmatured, &ONE_DAY),
+        Err(Ok(FixedBondError::ContractPaused))
+    );
+    assert_eq!(
+        client.try_rollover(&owner, &matured, &ONE_DAY),
+        Err(Ok(FixedBondError::ContractPaused))
+    );
+}
+
+#[test]
+fn test_queries_and_fee_collection_work_while_paused() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, _cid) = setup(&e);
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &100_u32);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+
+    pause(&e, &client, &admin);
+    assert_eq!(client.get_bond(&owner, &bond_id).amount, 9_900);
+    assert!(!client.is_matured(&owner, &bond_id));
+    assert_eq!(client.collect_fees(&admin, &token_addr), 100);
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// 21. Unclaimed-maturity flag
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_flag_matured_after_grace() {
+    let e = Env::default();
+    let (client, _admin, owner, token_addr, contract_id) = setup(&e);
+    let keeper = Address::generate(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    let expiry = client.get_bond(&owner, &bond_id).bond_expiry;
+
+    e.ledger().with_mut(|li| li.timestamp = expiry);
+    assert_eq!(
+        client.try_flag_matured(&keeper, &owner, &bond_id),
+        Err(Ok(FixedBondError::GracePeriodNotElapsed))
+    );
+    e.ledger()
+        .with_mut(|li| li.timestamp = expiry + DEFAULT_MATURITY_GRACE - 1);
+    assert_eq!(
+        client.try_flag_matured(&keeper, &owner, &bond_id),
+        Err(Ok(FixedBondError::GracePeriodNotElapsed))
+    );
+
+    e.ledger().with_mut(|li| li.timestamp += 1);
+    let flag = client.flag_matured(&keeper, &owner, &bond_id);
+    let event: BondMaturedUnclaimed = last_bond_event(&e, &contract_id).1.into_val(&e);
+    assert_eq!(
+        event,
+        BondMaturedUnclaimed {
+            owner: owner.clone(),
+            bond_id,
+            bond_expiry: expiry,
+            flagged_by: keeper.clone(),
+        }
+    );
+    assert_eq!(flag.flagged_by, keeper);
+    assert_eq!(flag.flagged_at, expiry + DEFAULT_MATURITY_GRACE);
+    assert_eq!(client.get_matured_flag(&owner, &bond_id), Some(flag));
+
+    // No funds move and the bond stays active.
+    let bond = client.get_bond(&owner, &bond_id);
+    assert!(bond.active);
+    assert_eq!(bond.amount, 1_000);
+    assert_eq!(client.get_total_locked(&token_addr), 1_000);
+}
+
+#[test]
+fn test_flag_matured_is_idempotent() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, contract_id) = setup(&e);
+    let first = Address::generate(&e);
+    let second = Address::generate(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    e.ledger()
+        .with_mut(|li| li.timestamp += ONE_DAY + DEFAULT_MATURITY_GRACE);
+
+    let flag = client.flag_matured(&first, &owner, &bond_id);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    assert_eq!(client.flag_matured(&second, &owner, &bond_id), flag);
+    let repeat_events = e
+        .events()
+        .all()
+        .iter()
+        .filter(|(id, _, _)| *id == contract_id)
+        .count();
+    assert_eq!(repeat_events, 0);
+    assert_eq!(client.get_matured_flag(&owner, &bond_id), Some(flag));
+}
+
+#[test]
+fn test_set_maturity_grace() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    assert_eq!(client.get_maturity_grace(), DEFAULT_MATURITY_GRACE);
+    client.set_maturity_grace(&admin, &ONE_WEEK);
+    assert_eq!(client.get_maturity_grace(), ONE_WEEK);
+
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY + ONE_WEEK);
+    client.flag_matured(&owner, &owner, &bond_id);
+}
+
+#[test]
+fn test_set_maturity_grace_invalid_fails() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    assert_eq!(
+        client.try_set_maturity_grace(&admin, &0),
+        Err(Ok(FixedBondError::InvalidDuration))
+    );
+    assert_eq!(
+        client.try_set_maturity_grace(&owner, &ONE_DAY),
+        Err(Ok(FixedBondError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_flag_matured_inactive_bond_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    e.ledger()
+        .with_mut(|li| li.timestamp += ONE_DAY + DEFAULT_MATURITY_GRACE);
+    client.withdraw(&owner, &bond_id);
+    assert_eq!(
+        client.try_flag_matured(&owner, &owner, &bond_id),
+        Err(Ok(FixedBondError::BondNotFound))
+    );
+}
+
+#[test]
+fn test_withdraw_and_rollover_clear_matured_flag() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let withdrawn = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    let rolled = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    e.ledger()
+        .with_mut(|li| li.timestamp += ONE_DAY + DEFAULT_MATURITY_GRACE);
+    client.flag_matured(&owner, &owner, &withdrawn);
+    client.flag_matured(&owner, &owner, &rolled);
+
+    client.withdraw(&owner, &withdrawn);
+    client.rollover(&owner, &rolled, &ONE_DAY);
+    assert_eq!(client.get_matured_flag(&owner, &withdrawn), None);
+    assert_eq!(client.get_matured_flag(&owner, &rolled), None);
+    // The re-locked bond must mature and wait out the grace window again.
+    assert_eq!(
+        client.try_flag_matured(&owner, &owner, &rolled),
+        Err(Ok(FixedBondError::GracePeriodNotElapsed))
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// 22. Multiple tokens
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_supported_tokens_start_with_default_token() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, contract_id) = setup(&e);
+    assert_eq!(client.get_supported_tokens(), vec![&e, token_addr.clone()]);
+
+    let other = add_second_token(&e, &client, &admin, &contract_id, &owner);
+    client.add_supported_token(&admin, &other); // no-op
+    assert_eq!(client.get_supported_tokens(), vec![&e, token_addr, other]);
+}
+
+#[test]
+fn test_manage_supported_tokens_unauthorized_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, token_addr, _cid) = setup(&e);
+    let other = Address::generate(&e);
+    assert_eq!(
+        client.try_add_supported_token(&owner, &other),
+        Err(Ok(FixedBondError::Unauthorized))
+    );
+    assert_eq!(
+        client.try_remove_supported_token(&owner, &token_addr),
+        Err(Ok(FixedBondError::Unauthorized))
+    );
+}
+
+#[test]
+fn test_create_bond_in_unsupported_token_fails() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    let other = e
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    assert_eq!(
+        client.try_create_bond_with_token(&owner, &other, &1_000_i128, &ONE_DAY, &None),
+        Err(Ok(FixedBondError::UnsupportedToken))
+    );
+    assert_eq!(
+        client.try_remove_supported_token(&admin, &other),
+        Err(Ok(FixedBondError::UnsupportedToken))
+    );
+}
+
+#[test]
+fn test_bond_in_second_token_pays_out_in_that_token() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, contract_id) = setup(&e);
+    let other = add_second_token(&e, &client, &admin, &contract_id, &owner);
+    let usdc = TokenClient::new(&e, &token_addr);
+    let alt = TokenClient::new(&e, &other);
+    let usdc_before = usdc.balance(&owner);
+    let alt_before = alt.balance(&owner);
+
+    let bond_id = client.create_bond_with_token(&owner, &other, &1_000_i128, &ONE_DAY, &None);
+    assert_eq!(client.get_bond(&owner, &bond_id).token, other);
+    assert_eq!(alt.balance(&owner), alt_before - 1_000);
+    assert_eq!(client.get_total_locked(&other), 1_000);
+    assert_eq!(client.get_total_locked(&token_addr), 0);
+
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    client.withdraw(&owner, &bond_id);
+    assert_eq!(alt.balance(&owner), alt_before);
+    assert_eq!(usdc.balance(&owner), usdc_before);
+}
+
+#[test]
+fn test_fees_accrue_and_collect_per_token() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, contract_id) = setup(&e);
+    let other = add_second_token(&e, &client, &admin, &contract_id, &owner);
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &100_u32);
+
+    client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    client.create_bond_with_token(&owner, &other, &50_000_i128, &ONE_DAY, &None);
+    assert_eq!(client.get_total_fees_accrued(&token_addr), 100);
+    assert_eq!(client.get_total_fees_accrued(&other), 500);
+
+    assert_eq!(client.collect_fees(&admin, &other), 500);
+    assert_eq!(TokenClient::new(&e, &other).balance(&treasury), 500);
+    assert_eq!(TokenClient::new(&e, &token_addr).balance(&treasury), 0);
+    assert_eq!(client.get_total_fees_accrued(&token_addr), 100);
+    assert_eq!(client.get_total_fees_collected(&other), 500);
+    assert_eq!(client.get_total_fees_collected(&token_addr), 0);
+}
+
+#[test]
+fn test_early_exit_penalty_paid_in_bond_token() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, contract_id) = setup(&e);
+    let other = add_second_token(&e, &client, &admin, &contract_id, &owner);
+    let treasury = Address::generate(&e);
+    client.set_fee_config(&admin, &treasury, &0_u32);
+    client.set_penalty_config(&admin, &1_000_u32);
+    client.set_flat_penalty(&admin, &true);
+
+    let bond_id = client.create_bond_with_token(&owner, &other, &1_000_i128, &ONE_WEEK, &None);
+    client.withdraw_early(&owner, &bond_id);
+    assert_eq!(TokenClient::new(&e, &other).balance(&treasury), 100);
+    assert_eq!(TokenClient::new(&e, &token_addr).balance(&treasury), 0);
+}
+
+#[test]
+fn test_yield_pools_are_per_token() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, contract_id) = setup(&e);
+    let other = add_second_token(&e, &client, &admin, &contract_id, &owner);
+    client.set_yield_rate(&admin, &0, &1_000_u32);
+    seed_yield_pool(&e, &client, &admin, &token_addr, &contract_id, 100_000);
+
+    // Nothing in the second token's pool, so its bond earns no yield.
+    let bond_id = client.create_bond_with_token(&owner, &other, &1_000_000_i128, &ONE_DAY, &None);
+    assert_eq!(client.get_pending_yield(&owner, &bond_id), 0);
+    assert_eq!(client.get_yield_pool(&other), 0);
+    assert_eq!(client.get_yield_pool(&token_addr), 100_000);
+}
+
+#[test]
+fn test_removed_token_bonds_can_still_withdraw() {
+    let e = Env::default();
+    let (client, admin, owner, _token, contract_id) = setup(&e);
+    let other = add_second_token(&e, &client, &admin, &contract_id, &owner);
+    let matured = client.create_bond_with_token(&owner, &other, &1_000_i128, &ONE_DAY, &None);
+    let rolled = client.create_bond_with_token(&owner, &other, &1_000_i128, &ONE_DAY, &None);
+
+    client.remove_supported_token(&admin, &other);
+    assert!(!client.get_supported_tokens().contains(&other));
+    assert_eq!(
+        client.try_create_bond_with_token(&owner, &other, &1_000_i128, &ONE_DAY, &None),
+        Err(Ok(FixedBondError::UnsupportedToken))
+    );
+
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+    assert_eq!(
+        client.try_rollover(&owner, &rolled, &ONE_DAY),
+        Err(Ok(FixedBondError::UnsupportedToken))
+    );
+    let before = TokenClient::new(&e, &other).balance(&owner);
+    client.withdraw(&owner, &matured);
+    client.withdraw(&owner, &rolled);
+    assert_eq!(TokenClient::new(&e, &other).balance(&owner), before + 2_000);
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// 23. Storage TTL
+// ═══════════════════════════════════════════════════════════════════
+
+fn persistent_ttl(e: &Env, contract_id: &Address, key: &DataKey) -> u32 {
+    e.as_contract(contract_id, || e.storage().persistent().get_ttl(key))
+}
+
+/// Advance both the clock and the ledger sequence by `secs`.
+fn advance(e: &Env, secs: u64) {
+    e.ledger().with_mut(|li| {
+        li.timestamp += secs;
+        li.sequence_number += (secs / SECONDS_PER_LEDGER) as u32;
+    });
+}
+
+#[test]
+fn test_create_bond_ttl_covers_lock_period() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, contract_id) = setup(&e);
+    let duration = 60 * ONE_DAY;
+    let bond_id = client.create_bond(&owner, &1_000_i128, &duration);
+
+    let lock_ledgers = (duration / SECONDS_PER_LEDGER) as u32;
+    let key = DataKey::Bond(owner.clone(), bond_id);
+    assert_eq!(
+        persistent_ttl(&e, &contract_id, &key),
+        lock_ledgers + BUMP_TARGET
+    );
+    let count_key = DataKey::BondCount(owner);
+    assert_eq!(
+        persistent_ttl(&e, &contract_id, &count_key),
+        lock_ledgers + BUMP_TARGET
+    );
+}
+
+#[test]
+fn test_create_bond_ttl_capped_at_network_max() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, contract_id) = setup(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &(2 * SECONDS_PER_YEAR));
+
+    let max_ttl = e.as_contract(&contract_id, || e.storage().max_ttl());
+    let key = DataKey::Bond(owner, bond_id);
+    assert_eq!(persistent_ttl(&e, &contract_id, &key), max_ttl);
+}
+
+#[test]
+fn test_bond_survives_until_maturity() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, contract_id) = setup(&e);
+    let duration = 60 * ONE_DAY;
+    let bond_id = client.create_bond(&owner, &1_000_i128, &duration);
+
+    // Far past the default 4096-ledger TTL that unbumped entries get.
+    advance(&e, duration);
+    assert!(client.get_bond(&owner, &bond_id).active);
+    assert_eq!(client.get_active_bond_count(), 1);
+    assert_eq!(client.get_bond_owners(&0, &10), vec![&e, owner.clone()]);
+    assert!(persistent_ttl(&e, &contract_id, &DataKey::Bond(owner, bond_id)) > 0);
+}
+
+#[test]
+fn test_extend_bond_ttl_after_maturity() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, contract_id) = setup(&e);
+    let duration = 60 * ONE_DAY;
+    let bond_id = client.create_bond(&owner, &1_000_i128, &duration);
+
+    advance(
+        &e,
+        duration + (BUMP_TARGET as u64 - 100) * SECONDS_PER_LEDGER,
+    );
+    let key = DataKey::Bond(owner.clone(), bond_id);
+    assert_eq!(persistent_ttl(&e, &contract_id, &key), 100);
+
+    // Anyone may pay to keep an unclaimed bond live.
+    e.set_auths(&[]);
+    client.extend_bond_ttl(&owner, &bond_id);
+    assert_eq!(persistent_ttl(&e, &contract_id, &key), BUMP_TARGET);
+    let count_key = DataKey::BondCount(owner);
+    assert_eq!(persistent_ttl(&e, &contract_id, &count_key), BUMP_TARGET);
+}
+
+#[test]
+fn test_extend_bond_ttl_no_bond_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _contract_id) = setup(&e);
+    assert_eq!(
+        client.try_extend_bond_ttl(&owner, &0),
+        Err(Ok(FixedBondError::BondNotFound))
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// 24. Bond status
+// ═══════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_bond_status_matches_individual_queries() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    client.set_penalty_config(&admin, &1_000_u32);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY / 4);
+
+    let status = client.get_bond_status(&owner, &bond_id).unwrap();
+    assert_eq!(status.bond, client.get_bond(&owner, &bond_id));
+    assert_eq!(status.matured, client.is_matured(&owner, &bond_id));
+    assert_eq!(
+        status.seconds_remaining,
+        client.get_time_remaining(&owner, &bond_id)
+    );
+    assert_eq!(
+        (status.early_exit_penalty, status.early_exit_net),
+        client.preview_early_exit(&owner, &bond_id)
+    );
+    assert_eq!(
+        (status.early_exit_penalty, status.early_exit_net),
+        (750, 9_250)
+    );
+}
+
+#[test]
+fn test_bond_status_net_matches_withdraw_early_payout() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, _cid) = setup(&e);
+    client.set_penalty_config(&admin, &500_u32);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY / 3);
+
+    let status = client.get_bond_status(&owner, &bond_id).unwrap();
+    let tok = TokenClient::new(&e, &token_addr);
+    let before = tok.balance(&owner);
+    client.withdraw_early(&owner, &bond_id);
+    assert_eq!(tok.balance(&owner), before + status.early_exit_net);
+}
+
+#[test]
+fn test_bond_status_after_maturity() {
+    let e = Env::default();
+    let (client, admin, owner, _token, _cid) = setup(&e);
+    client.set_penalty_config(&admin, &1_000_u32);
+    let bond_id = client.create_bond(&owner, &10_000_i128, &ONE_DAY);
+    e.ledger().with_mut(|li| li.timestamp += ONE_DAY);
+
+    let status = client.get_bond_status(&owner, &bond_id).unwrap();
+    assert!(status.matured);
+    assert_eq!(status.seconds_remaining, 0);
+    assert_eq!((status.early_exit_penalty, status.early_exit_net), (0, 0));
+}
+
+#[test]
+fn test_bond_status_no_bond_is_none() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    assert_eq!(client.get_bond_status(&owner, &1), None);
+    assert_eq!(client.get_bond_status_single(&owner), None);
+}
+
+#[test]
+fn test_bond_status_single() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    assert_eq!(
+        client.get_bond_status_single(&owner),
+        client.get_bond_status(&owner, &bond_id)
+    );
+
+    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    assert_eq!(
+        client.try_get_bond_status_single(&owner),
+        Err(Ok(FixedBondError::MultipleActiveBonds))
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════
+// 25. Registry
+// ═══════════════════════════════════════════════════════════════════
+
+/// Deploy a credence_registry that allowlists the bond contract as a
+/// registrar, and point the bond contract at it.
+fn setup_registry(
+    e: &Env,
+    client: &FixedDurationBondClient,
+    admin: &Address,
+    contract_id: &Address,
+) -> CredenceRegistryClient<'static> {
+    let registry_id = e.register(CredenceRegistry, ());
+    let registry = CredenceRegistryClient::new(e, &registry_id);
+    registry.initialize(admin);
+    registry.set_registrar(contract_id, &true);
+    client.set_registry(admin, &Some(registry_id.clone()));
+    assert_eq!(client.get_registry(), Some(registry_id));
+    registry
+}
+
+#[test]
+fn test_create_bond_registers_owner() {
+    let e = Env::default();
+    let (client, admin, owner, _token, contract_id) = setup(&e);
+    let registry = setup_registry(&e, &client, &admin, &contract_id);
+    assert!(!registry.is_registered(&owner));
+
+    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    assert!(registry.is_registered(&owner));
+    assert_eq!(
+        registry.get_bond_contract(&owner).bond_contract,
+        contract_id
+    );
+}
+
+#[test]
+fn test_create_bond_tolerates_registered_owner() {
+    let e = Env::default();
+    let (client, admin, owner, _token, contract_id) = setup(&e);
+    let registry = setup_registry(&e, &client, &admin, &contract_id);
+    let other_bond_contract = Address::generate(&e);
+    registry.register(&owner, &other_bond_contract);
+
+    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    assert_eq!(
+        registry.get_bond_contract(&owner).bond_contract,
+        other_bond_contract
+    );
+    assert_eq!(registry.get_all_identities().len(), 1);
+}
+
+#[test]
+fn test_create_bond_registers_each_owner() {
+    let e = Env::default();
+    let (client, admin, owner, token_addr, contract_id) = setup(&e);
+    let registry = setup_registry(&e, &client, &admin, &contract_id);
+    let other = Address::generate(&e);
+    fund(&e, &token_addr, &contract_id, &other);
+
+    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    client.create_bond(&other, &1_000_i128, &ONE_DAY);
+    assert!(registry.is_registered(&owner));
+    assert!(registry.is_registered(&other));
+}
+
+#[test]
+fn test_clear_registry_stops_registration() {
+    let e = Env::default();
+    let (client, admin, owner, _token, contract_id) = setup(&e);
+    let registry = setup_registry(&e, &client, &admin, &contract_id);
+    client.set_registry(&admin, &None);
+    assert_eq!(client.get_registry(), None);
+
+    client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    assert!(!registry.is_registered(&owner));
+}
+
+#[test]
+fn test_create_bond_survives_registry_failure() {
+    let e = Env::default();
+    let (client, admin, owner, _token, contract_id) = setup(&e);
+    let registry = setup_registry(&e, &client, &admin, &contract_id);
+    registry.set_registrar(&contract_id, &false);
+
+    let bond_id = client.create_bond(&owner, &1_000_i128, &ONE_DAY);
+    assert!(client.get_bond(&owner, &bond_id).active);
+    assert!(!registry.is_registered(&owner));
+
+    let failed_topics: Vec<Val> = (Symbol::new(&e, "registry_failed"), owner.clone()).into_val(&e);
+    let (_, _, data) = e
+        .events()
+        .all()
+        .iter()
+        .find(|(id, topics, _)| *id == contract_id && *topics == failed_topics)
+        .expect("no registry_failed event");
+    let failed: RegistryFailed = data.into_val(&e);
+    assert_eq!(
+        failed,
+        RegistryFailed {
+            owner,
+            registry: registry.address.clone(),
+        }
+    );
+}
+
+#[test]
+fn test_set_registry_non_admin_fails() {
+    let e = Env::default();
+    let (client, _admin, owner, _token, _cid) = setup(&e);
+    assert_eq!(
+        client.try_set_registry(&owner, &Some(Address::generate(&e))),
+        Err(Ok(FixedBondError::Unauthorized))
+    );
 }
@@ -0,0 +1,55 @@
+//! TTL maintenance for bond entries.
+//!
+//! Bond records live in persistent storage, so each must stay live until its
+//! owner can withdraw. Entries a bond depends on, including the contract
+//! instance, are bumped to cover the time left until `bond_expiry` plus
+//! `BUMP_TARGET`, capped by the network maximum. Bumps never shorten a TTL,
+//! so shared entries end up covering the latest expiry that touched them.
+//! Thresholds match `dispute_resolution`.
+
+use crate::types::{DataKey, FixedBond};
+use soroban_sdk::{Env, IntoVal, Val};
+
+/// Minimum ledger TTL before an entry's TTL is bumped (~1 day at 5 s/ledger).
+pub const BUMP_THRESHOLD: u32 = 17_280;
+/// Target ledger TTL after a bump (~30 days).
+pub const BUMP_TARGET: u32 = 518_400;
+/// Assumed ledger close time, used to turn lock time into ledgers.
+pub const SECONDS_PER_LEDGER: u64 = 5;
+
+/// `(threshold, target)` for an entry that must stay live past `expiry`.
+fn ttl_until(e: &Env, expiry: u64) -> (u32, u32) {
+    let remaining = expiry.saturating_sub(e.ledger().timestamp());
+    let ledgers = u32::try_from(remaining.div_ceil(SECONDS_PER_LEDGER)).unwrap_or(u32::MAX);
+    let target = ledgers
+        .saturating_add(BUMP_TARGET)
+        .min(e.storage().max_ttl());
+    let threshold = ledgers.saturating_add(BUMP_THRESHOLD).min(target);
+    (threshold, target)
+}
+
+/// Bump the contract instance, which holds the configuration and totals,
+/// so it outlives `expiry`.
+pub fn extend_instance_until(e: &Env, expiry: u64) {
+    let (threshold, target) = ttl_until(e, expiry);
+    e.storage().instance().extend_ttl(threshold, target);
+}
+
+/// Bump the record of `bond` so it outlives the bond's expiry.
+pub fn extend_bond(e: &Env, bond: &FixedBond) {
+    let (threshold, target) = ttl_until(e, bond.bond_expiry);
+    e.storage().persistent().extend_ttl(
+        &DataKey::Bond(bond.owner.clone(), bond.bond_id),
+        threshold,
+        target,
+    );
+}
+
+/// Bump a persistent entry that must outlive `expiry`, if it exists.
+pub fn extend_until<K: IntoVal<Env, Val>>(e: &Env, key: &K, expiry: u64) {
+    let storage = e.storage().persistent();
+    if storage.has(key) {
+        let (threshold, target) = ttl_until(e, expiry);
+        storage.extend_ttl(key, threshold, target);
+    }
+}
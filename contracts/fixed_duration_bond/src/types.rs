@@ -1,13 +1,17 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, Symbol};
 
 // ─── Bond state ────────────────────────────────────────────────────────────
 
-/// A single fixed-duration USDC bond owned by one address.
+/// A single fixed-duration bond owned by one address.
 #[contracttype]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FixedBond {
     /// The address that locked the funds.
     pub owner: Address,
+    /// Per-owner bond id, starting at 1.
+    pub bond_id: u64,
+    /// Token the bond is denominated in; every payout uses it.
+    pub token: Address,
     /// Net bonded amount (after creation fee, if any).
     pub amount: i128,
     /// Ledger timestamp at the moment the bond was created.
@@ -18,6 +22,10 @@ pub struct FixedBond {
     pub bond_expiry: u64,
     /// Early-exit penalty in basis points (0 = disabled for this bond).
     pub penalty_bps: u32,
+    /// Annual yield rate in basis points, fixed at creation or rollover.
+    pub yield_bps: u32,
+    /// Where withdrawals are paid; `None` pays the owner.
+    pub beneficiary: Option<Address>,
     /// false once the bond has been withdrawn.
     pub active: bool,
 }
@@ -34,6 +42,73 @@ pub struct FeeConfig {
     pub fee_bps: u32,
 }
 
+// ─── Yield ─────────────────────────────────────────────────────────────────
+
+/// Annual yield paid to bonds locked for at least `min_duration_secs`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct YieldTier {
+    /// Shortest lock period the rate applies to.
+    pub min_duration_secs: u64,
+    /// Annual rate in basis points.
+    pub rate_bps: u32,
+}
+
+// ─── Ownership transfer ────────────────────────────────────────────────────
+
+/// A proposed hand-over of one bond, waiting for `new_owner` to accept.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingTransfer {
+    /// Address that may accept the bond.
+    pub new_owner: Address,
+    /// Last ledger timestamp at which the proposal can be accepted.
+    pub expires_at: u64,
+}
+
+/// Record that a bond stayed unclaimed past its maturity grace window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaturedFlag {
+    /// Address that called `flag_matured`.
+    pub flagged_by: Address,
+    /// Ledger timestamp of the first `flag_matured` call.
+    pub flagged_at: u64,
+}
+
+/// Everything a wallet needs to render one bond, from `get_bond_status`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondStatus {
+    pub bond: FixedBond,
+    /// `true` once the lock period has elapsed.
+    pub matured: bool,
+    /// Seconds until maturity; 0 once matured.
+    pub seconds_remaining: u64,
+    /// Penalty `withdraw_early` would deduct right now; 0 if the bond cannot
+    /// exit early (inactive, matured or no penalty configured).
+    pub early_exit_penalty: i128,
+    /// Amount `withdraw_early` would pay right now; 0 if the bond cannot
+    /// exit early.
+    pub early_exit_net: i128,
+}
+
+// ─── Contract metadata ─────────────────────────────────────────────────────
+
+/// What a deployed contract is and which revision it runs.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractInfo {
+    /// Contract name.
+    pub name: Symbol,
+    /// Contract revision, bumped on every release.
+    pub version: u32,
+    /// Bond token, if configured.
+    pub token: Option<Address>,
+    /// Contract admin.
+    pub admin: Address,
+}
+
 // ─── Storage keys ──────────────────────────────────────────────────────────
 
 #[contracttype]
@@ -46,8 +121,48 @@ pub enum DataKey {
     FeeConfig,
     /// Default early-exit penalty in basis points.
     PenaltyBps,
-    /// Per-owner active bond.
-    Bond(Address),
-    /// Accrued creation fees held in the contract, in strobes/units.
-    AccruedFees,
+    /// Bond keyed by (owner, bond_id).
+    Bond(Address, u64),
+    /// Number of bonds ever created by an owner; the latest bond id.
+    BondCount(Address),
+    /// Accrued fees held in the contract, per token.
+    AccruedFees(Address),
+    /// Maximum total lock period in seconds (0 = unlimited).
+    MaxDuration,
+    /// Fee charged on `rollover`, in basis points of the principal.
+    RolloverFeeBps,
+    /// Admin-funded balance that yield is paid from, per token.
+    YieldPool(Address),
+    /// Yield rate table (Vec<YieldTier>), sorted by `min_duration_secs`.
+    YieldRates,
+    /// Charge the full early-exit penalty regardless of time remaining.
+    FlatPenalty,
+    /// Sum of the principal of all active bonds, per token.
+    TotalLocked(Address),
+    /// Number of active bonds across all owners.
+    ActiveBondCount,
+    /// Number of active bonds held by an owner.
+    OwnerActiveBonds(Address),
+    /// Number of owners in the owner index.
+    OwnerCount,
+    /// One page of the owner index (Vec<Address>, up to `OWNER_PAGE_SIZE`).
+    OwnerPage(u32),
+    /// Position of an owner in the owner index.
+    OwnerPosition(Address),
+    /// Pending ownership transfer of bond (owner, bond_id).
+    PendingTransfer(Address, u64),
+    /// How long a transfer proposal stays open, in seconds.
+    TransferWindow,
+    /// Address proposed as the next admin, until it accepts.
+    PendingAdmin,
+    /// Fees paid to the treasury by `collect_fees`, all time, per token.
+    TotalFeesCollected(Address),
+    /// Seconds after maturity before a bond can be flagged as unclaimed.
+    MaturityGrace,
+    /// Unclaimed-maturity flag of bond (owner, bond_id).
+    MaturedFlag(Address, u64),
+    /// Tokens new bonds may be created in (Vec<Address>).
+    SupportedTokens,
+    /// credence_registry contract new bond owners are registered in.
+    Registry,
 }